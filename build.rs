@@ -0,0 +1,34 @@
+// Only does something with `--features c-reference`: compiles compare/goto.c (the C
+// computed-goto reference) into a static lib the benchmark links against. Uses the
+// system C compiler directly ($CC, or `cc`), no extra build dependencies
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_C_REFERENCE").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=compare/goto.c");
+    println!("cargo:rerun-if-env-changed=CC");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let obj = out.join("goto.o");
+    let lib = out.join("librg_goto.a");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    run(Command::new(&cc).args(["-O3", "-fPIC", "-c", "compare/goto.c", "-o"]).arg(&obj));
+    run(Command::new("ar").arg("crs").arg(&lib).arg(&obj));
+
+    println!("cargo:rustc-link-search=native={}", out.display());
+    println!("cargo:rustc-link-lib=static=rg_goto");
+}
+
+fn run(cmd: &mut Command) {
+    let status = cmd
+        .status()
+        .unwrap_or_else(|e| panic!("c-reference: failed to run {cmd:?}: {e}"));
+    assert!(status.success(), "c-reference: {cmd:?} failed ({status})");
+}
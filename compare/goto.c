@@ -0,0 +1,323 @@
+/*
+ * Reference implementation of the rust-goto ISA with GCC/Clang computed goto
+ * (`&&label` + `goto *table[op]`), the canonical threaded-dispatch technique the
+ * Rust variants are trying to get out of LLVM without it.
+ *
+ * Two ways to use it:
+ *   - linked into the benchmark with `--features c-reference` (build.rs compiles
+ *     this file), called through rg_run_goto()
+ *   - as a standalone tool speaking the --external protocol (compare/README.md):
+ *       cc -O3 -DRG_STANDALONE compare/goto.c -o compare/goto
+ *       cargo run --release -- --external c-goto=./compare/goto
+ *
+ * Semantics follow handle!() in src/lib.rs: wrapping arithmetic, x / 0 = x % 0 = 0,
+ * unknown opcode returns -1, and so does overflowing either stack or RET/POP on an
+ * empty one, or a LOAD/STORE/MEMCPY/MEMSET outside the mem_words words of memory the
+ * caller hands in. `read_only` is the protection bitmap (src/memory.rs), one bit per
+ * word of memory, or NULL when nothing is read-only: a STORE/MEMCPY/MEMSET writing
+ * into a protected word returns -1 too.
+ */
+
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+#define NREGS 16
+#define STACK_DEPTH 1024 /* STACK_DEPTH in src/lib.rs */
+
+enum {
+    OP_HALT = 0, OP_LOADI, OP_ADD, OP_SUB, OP_MUL, OP_DIV,
+    OP_MOD, OP_INC, OP_DEC, OP_JMPNZ, OP_MOV,
+    OP_CALL, OP_RET, OP_PUSH, OP_POP, OP_LOAD, OP_STORE,
+    OP_MEMCPY, OP_MEMSET, OP_CMP, OP_JMP, OP_JEQ, OP_JNE,
+    OP_JLT, OP_JGE,
+    /* needs a host posting events (Vm::resume), here it's -1 through op_bad */
+    OP_WAITEVENT,
+    /* superinstructions, see src/fuse.rs */
+    OP_ADDADD, OP_MULSUB, OP_DECJNZ,
+    OP_CLZ, OP_CTZ, OP_POPCNT,
+    /* calls the handler of a Vm (Vm::set_trap_handler), here it's -1 through op_bad */
+    OP_TRAP,
+    /* asks a Vm's resolver (Vm::set_resolver) and patches itself, here it's -1 too */
+    OP_RESOLVE_CALL,
+};
+
+int64_t rg_run_goto(const uint32_t *code, const int64_t *args, size_t nargs, int64_t *mem, size_t mem_words,
+                    const uint64_t *read_only)
+{
+    static void *const table[256] = {
+        [0 ... 255] = &&op_bad,
+        [OP_HALT] = &&op_halt, [OP_LOADI] = &&op_loadi, [OP_ADD] = &&op_add,
+        [OP_SUB] = &&op_sub,   [OP_MUL] = &&op_mul,     [OP_DIV] = &&op_div,
+        [OP_MOD] = &&op_mod,   [OP_INC] = &&op_inc,     [OP_DEC] = &&op_dec,
+        [OP_JMPNZ] = &&op_jmpnz, [OP_MOV] = &&op_mov,
+        [OP_CALL] = &&op_call, [OP_RET] = &&op_ret,
+        [OP_PUSH] = &&op_push, [OP_POP] = &&op_pop,
+        [OP_LOAD] = &&op_load, [OP_STORE] = &&op_store,
+        [OP_MEMCPY] = &&op_memcpy, [OP_MEMSET] = &&op_memset,
+        [OP_CMP] = &&op_cmp,   [OP_JMP] = &&op_jmp,
+        [OP_JEQ] = &&op_jeq,   [OP_JNE] = &&op_jne,
+        [OP_JLT] = &&op_jlt,   [OP_JGE] = &&op_jge,
+        [OP_ADDADD] = &&op_addadd, [OP_MULSUB] = &&op_mulsub,
+        [OP_DECJNZ] = &&op_decjnz,
+        [OP_CLZ] = &&op_clz,   [OP_CTZ] = &&op_ctz,     [OP_POPCNT] = &&op_popcnt,
+    };
+    /* register operands are full bytes, keep every index in bounds */
+    int64_t regs[256] = {0};
+    /* return addresses and pushed values apart, like the Rust side */
+    const uint32_t *calls[STACK_DEPTH];
+    int64_t values[STACK_DEPTH];
+    size_t ncalls = 0, nvalues = 0;
+    const uint32_t *pc = code;
+    uint32_t instr, dst, a, b;
+    uint64_t addr, from, len;
+
+    for (size_t i = 0; i < nargs && i < NREGS; i++)
+        regs[i] = args[i];
+
+#define READ_ONLY(addr) (read_only && (read_only[(addr) / 64] >> ((addr) % 64) & 1))
+
+#define DISPATCH()                       \
+    do {                                 \
+        instr = *pc++;                   \
+        dst = (instr >> 8) & 0xFF;       \
+        a = (instr >> 16) & 0xFF;        \
+        b = instr >> 24;                 \
+        goto *table[instr & 0xFF];       \
+    } while (0)
+#define WRAP(x, o, y) ((int64_t)((uint64_t)(x) o (uint64_t)(y)))
+
+    DISPATCH();
+
+op_halt:
+    return regs[dst];
+op_loadi:
+    regs[dst] = (int64_t)(a | (b << 8));
+    DISPATCH();
+op_add:
+    regs[dst] = WRAP(regs[a], +, regs[b]);
+    DISPATCH();
+op_sub:
+    regs[dst] = WRAP(regs[a], -, regs[b]);
+    DISPATCH();
+op_mul:
+    regs[dst] = WRAP(regs[a], *, regs[b]);
+    DISPATCH();
+op_div: {
+    int64_t d = regs[b];
+    /* INT64_MIN / -1 is UB in C, -1 is just a wrapping negation */
+    regs[dst] = d == 0 ? 0 : d == -1 ? WRAP(0, -, regs[a]) : regs[a] / d;
+    DISPATCH();
+}
+op_mod: {
+    int64_t d = regs[b];
+    regs[dst] = (d == 0 || d == -1) ? 0 : regs[a] % d;
+    DISPATCH();
+}
+op_inc:
+    regs[dst] = WRAP(regs[dst], +, 1);
+    DISPATCH();
+op_dec:
+    regs[dst] = WRAP(regs[dst], -, 1);
+    DISPATCH();
+op_jmpnz:
+    if (regs[dst] != 0)
+        pc = code + (a | (b << 8));
+    DISPATCH();
+op_mov:
+    regs[dst] = regs[a];
+    DISPATCH();
+/* the builtins are undefined on 0, Rust's leading_zeros/trailing_zeros give 64 */
+op_clz:
+    regs[dst] = regs[a] ? __builtin_clzll((uint64_t)regs[a]) : 64;
+    DISPATCH();
+op_ctz:
+    regs[dst] = regs[a] ? __builtin_ctzll((uint64_t)regs[a]) : 64;
+    DISPATCH();
+op_popcnt:
+    regs[dst] = __builtin_popcountll((uint64_t)regs[a]);
+    DISPATCH();
+/* -1, 0 or 1, what JLT/JEQ/JGE test for */
+op_cmp:
+    regs[dst] = (regs[a] > regs[b]) - (regs[a] < regs[b]);
+    DISPATCH();
+op_jmp:
+    pc = code + (a | (b << 8));
+    DISPATCH();
+op_jeq:
+    if (regs[dst] == 0)
+        pc = code + (a | (b << 8));
+    DISPATCH();
+op_jne:
+    if (regs[dst] != 0)
+        pc = code + (a | (b << 8));
+    DISPATCH();
+op_jlt:
+    if (regs[dst] < 0)
+        pc = code + (a | (b << 8));
+    DISPATCH();
+op_jge:
+    if (regs[dst] >= 0)
+        pc = code + (a | (b << 8));
+    DISPATCH();
+op_call:
+    if (ncalls == STACK_DEPTH)
+        return -1;
+    calls[ncalls++] = pc;
+    pc = code + (a | (b << 8));
+    DISPATCH();
+op_ret:
+    if (ncalls == 0)
+        return -1;
+    pc = calls[--ncalls];
+    DISPATCH();
+op_push:
+    if (nvalues == STACK_DEPTH)
+        return -1;
+    values[nvalues++] = regs[dst];
+    DISPATCH();
+op_pop:
+    if (nvalues == 0)
+        return -1;
+    regs[dst] = values[--nvalues];
+    DISPATCH();
+/* base register + unsigned 8-bit offset, negative addresses wrap and miss */
+op_load:
+    addr = (uint64_t)regs[a] + b;
+    if (addr >= mem_words)
+        return -1;
+    regs[dst] = mem[addr];
+    DISPATCH();
+op_store:
+    addr = (uint64_t)regs[a] + b;
+    if (addr >= mem_words || READ_ONLY(addr))
+        return -1;
+    mem[addr] = regs[dst];
+    DISPATCH();
+/* len words at word address addr, all of them inside memory, or -1 */
+#define IN_MEMORY(addr, len) ((addr) <= mem_words && (len) <= mem_words - (addr))
+#define WRITABLE(addr, len)                               \
+    do {                                                  \
+        if (!IN_MEMORY(addr, len))                        \
+            return -1;                                    \
+        for (uint64_t i = 0; read_only && i < (len); i++) \
+            if (READ_ONLY((addr) + i))                    \
+                return -1;                                \
+    } while (0)
+op_memcpy:
+    addr = (uint64_t)regs[dst];
+    from = (uint64_t)regs[a];
+    len = (uint64_t)regs[b];
+    if (!IN_MEMORY(from, len))
+        return -1;
+    WRITABLE(addr, len);
+    memmove(mem + addr, mem + from, len * sizeof *mem);
+    DISPATCH();
+op_memset:
+    addr = (uint64_t)regs[dst];
+    len = (uint64_t)regs[b];
+    WRITABLE(addr, len);
+    for (uint64_t i = 0; i < len; i++)
+        mem[addr + i] = regs[a];
+    DISPATCH();
+#undef WRITABLE
+#undef IN_MEMORY
+#undef READ_ONLY
+/* both halves of the pair, registers as nibbles, then step over the second half */
+#define LO(x) ((x) & 0xF)
+#define HI(x) ((x) >> 4)
+op_addadd:
+    regs[LO(dst)] = WRAP(regs[LO(a)], +, regs[LO(b)]);
+    regs[HI(dst)] = WRAP(regs[HI(a)], +, regs[HI(b)]);
+    pc++;
+    DISPATCH();
+op_mulsub:
+    regs[LO(dst)] = WRAP(regs[LO(a)], *, regs[LO(b)]);
+    regs[HI(dst)] = WRAP(regs[HI(a)], -, regs[HI(b)]);
+    pc++;
+    DISPATCH();
+#undef HI
+#undef LO
+op_decjnz:
+    regs[dst] = WRAP(regs[dst], -, 1);
+    if (regs[dst] != 0)
+        pc = code + (a | (b << 8));
+    else
+        pc++;
+    DISPATCH();
+/* unknown opcodes, among them 0xFF after the last instruction of a program that
+   traps when it runs off the end (FallOffEnd::Trap) */
+op_bad:
+    return -1;
+
+#undef WRAP
+#undef DISPATCH
+}
+
+#ifdef RG_STANDALONE
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+
+int main(int argc, char **argv)
+{
+    if (argc < 3) {
+        fprintf(stderr, "usage: %s <program-file> <iters> [args...]\n", argv[0]);
+        return 2;
+    }
+
+    FILE *f = fopen(argv[1], "r");
+    if (!f) {
+        perror(argv[1]);
+        return 1;
+    }
+    /* memory for every run, zeroed each time like the Rust side does */
+    size_t mem_words;
+    if (fscanf(f, " memory %zu", &mem_words) != 1) {
+        fprintf(stderr, "%s: expected a `memory <words>` line first\n", argv[1]);
+        return 1;
+    }
+    size_t len = 0, cap = 64;
+    uint32_t *code = malloc(cap * sizeof *code);
+    unsigned int word;
+    while (fscanf(f, "%x", &word) == 1) {
+        if (len == cap)
+            code = realloc(code, (cap *= 2) * sizeof *code);
+        code[len++] = word;
+    }
+    fclose(f);
+
+    long long iters = atoll(argv[2]);
+    size_t nargs = (size_t)(argc - 3);
+    int64_t args[NREGS] = {0};
+    if (nargs > NREGS) {
+        fprintf(stderr, "too many arguments\n");
+        return 2;
+    }
+    for (size_t i = 0; i < nargs; i++)
+        args[i] = atoll(argv[3 + i]);
+
+    int64_t *mem = calloc(mem_words ? mem_words : 1, sizeof *mem);
+    if (!mem) {
+        fprintf(stderr, "can't allocate %zu words of memory\n", mem_words);
+        return 1;
+    }
+
+    struct timespec start, end;
+    volatile int64_t result = 0;
+    clock_gettime(CLOCK_MONOTONIC, &start);
+    for (long long i = 0; i < iters; i++) {
+        if (mem_words)
+            memset(mem, 0, mem_words * sizeof *mem);
+        result = rg_run_goto(code, args, nargs, mem, mem_words, NULL);
+    }
+    clock_gettime(CLOCK_MONOTONIC, &end);
+
+    long long ns = (end.tv_sec - start.tv_sec) * 1000000000LL + (end.tv_nsec - start.tv_nsec);
+    printf("%lld %lld\n", ns, (long long)result);
+    free(mem);
+    free(code);
+    return 0;
+}
+#endif
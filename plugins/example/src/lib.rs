@@ -0,0 +1,76 @@
+// Example plugin for rust-goto (see ../rg_plugin.h for the ABI)
+//
+// replaces the MUL handler with its own copy, compiled separately from the host, and
+// adds an extension opcode SQUARE (0x80): dst = a * a
+//
+//   cargo build --release -p rg-example-plugin
+//   cargo run --release --features plugins -- --plugin target/release/librg_example_plugin.so
+
+use std::ffi::c_char;
+
+const ABI_VERSION: u32 = 12;
+const OP_MUL: u8 = 4;
+const OP_SQUARE: u8 = 0x80;
+
+type Handler = unsafe extern "C" fn(regs: *mut i64, nregs: usize, dst: u8, a: u8, b: u8) -> i32;
+
+#[repr(C)]
+pub struct PluginOp {
+    opcode: u8,
+    handler: Handler,
+}
+
+#[repr(C)]
+pub struct Plugin {
+    abi_version: u32,
+    name: *const c_char,
+    ops: *const PluginOp,
+    nops: usize,
+    run: Option<
+        unsafe extern "C" fn(
+            code: *const u32,
+            args: *const i64,
+            nargs: usize,
+            mem: *mut i64,
+            mem_words: usize,
+            read_only: *const u64,
+        ) -> i64,
+    >,
+}
+
+// only ever points at static data
+unsafe impl Sync for Plugin {}
+
+unsafe extern "C" fn mul(regs: *mut i64, nregs: usize, dst: u8, a: u8, b: u8) -> i32 {
+    let regs = unsafe { std::slice::from_raw_parts_mut(regs, nregs) };
+    let (Some(&x), Some(&y)) = (regs.get(a as usize), regs.get(b as usize)) else { return 1 };
+    let Some(d) = regs.get_mut(dst as usize) else { return 1 };
+    *d = x.wrapping_mul(y);
+    0
+}
+
+unsafe extern "C" fn square(regs: *mut i64, nregs: usize, dst: u8, a: u8, _b: u8) -> i32 {
+    let regs = unsafe { std::slice::from_raw_parts_mut(regs, nregs) };
+    let Some(&x) = regs.get(a as usize) else { return 1 };
+    let Some(d) = regs.get_mut(dst as usize) else { return 1 };
+    *d = x.wrapping_mul(x);
+    0
+}
+
+static OPS: [PluginOp; 2] = [
+    PluginOp { opcode: OP_MUL, handler: mul },
+    PluginOp { opcode: OP_SQUARE, handler: square },
+];
+
+static PLUGIN: Plugin = Plugin {
+    abi_version: ABI_VERSION,
+    name: c"example".as_ptr(),
+    ops: OPS.as_ptr(),
+    nops: OPS.len(),
+    run: None,
+};
+
+#[unsafe(no_mangle)]
+pub extern "C" fn rg_plugin_describe() -> *const Plugin {
+    &PLUGIN
+}
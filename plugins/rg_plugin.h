@@ -0,0 +1,60 @@
+/*
+ * rust-goto plugin ABI, version 12
+ *
+ * A plugin is a shared library (cdylib / .so) exporting
+ *
+ *     const struct rg_plugin *rg_plugin_describe(void);
+ *
+ * loaded at runtime with `rust-goto --plugin path/to/libfoo.so` (build with
+ * `--features plugins`). It can provide:
+ *
+ *   - handlers: replacements for built-in opcodes, or new opcodes (34 to 254 are
+ *     free, 255 is what follows the last instruction, see below). They run inside the host's central loop, so a plugin compiled with
+ *     a different compiler or different flags can be compared handler by handler.
+ *     A handler gets the register file and the instruction operands and returns 0,
+ *     anything else stops the program with -1. Handlers can't change the pc, control
+ *     flow stays with the host. That includes the superinstructions (26..28, see
+ *     src/fuse.rs): the second half of the pair still follows the replaced one and
+ *     runs after it, so a replacement only does the first half.
+ *   - run: optionally, a whole interpreter for the ISA (same contract as the
+ *     rg_run_goto in compare/goto.c), benchmarked as its own row. `mem` is the
+ *     program's linear memory, zeroed but for its data segments, `mem_words` long
+ *     (0 if it has no LOAD, STORE, MEMCPY or MEMSET). `read_only` is NULL, or one bit
+ *     per word of it (bit addr % 64 of read_only[addr / 64]) marking the words a
+ *     STORE, MEMCPY or MEMSET must not write: those return -1 instead. An unknown
+ *     opcode returns -1 too, and one can come up in a verified program: a program
+ *     that traps when it runs off its end has the word 0xFF after its last
+ *     instruction (FallOffEnd::Trap in src/program.rs).
+ *
+ * Everything the descriptor points to has to stay valid while the library is
+ * loaded, which in practice means static data.
+ */
+
+#ifndef RG_PLUGIN_H
+#define RG_PLUGIN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define RG_PLUGIN_ABI_VERSION 12
+
+typedef int32_t (*rg_handler_fn)(int64_t *regs, size_t nregs, uint8_t dst, uint8_t a, uint8_t b);
+
+struct rg_plugin_op {
+    uint8_t opcode;
+    rg_handler_fn handler;
+};
+
+struct rg_plugin {
+    uint32_t abi_version;              /* RG_PLUGIN_ABI_VERSION */
+    const char *name;                  /* NUL-terminated */
+    const struct rg_plugin_op *ops;    /* may be NULL if nops == 0 */
+    size_t nops;
+    /* may be NULL */
+    int64_t (*run)(const uint32_t *code, const int64_t *args, size_t nargs, int64_t *mem, size_t mem_words,
+                   const uint64_t *read_only);
+};
+
+const struct rg_plugin *rg_plugin_describe(void);
+
+#endif
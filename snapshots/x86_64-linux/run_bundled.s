@@ -0,0 +1,2373 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	8(%rdi), %rax
+	movq	%rax, 320(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 16(%rsp)
+	movaps	%xmm1, 32(%rsp)
+	movaps	%xmm2, 48(%rsp)
+	movaps	%xmm3, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	addq	$24, %rbx
+.Ltmp{2}:
+	leaq	152(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%ebx, %ebx
+	jmp	.LBB{4}
+.LBB{5}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+.LBB{6}:
+	movzbl	%r12b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+.LBB{4}:
+	movq	%rbx, %r12
+	andq	$-4, %r12
+	movq	320(%rsp), %rax
+	movl	4(%rax,%r12,4), %ebp
+	movl	8(%rax,%r12,4), %ecx
+	movq	%rcx, 328(%rsp)
+	movl	12(%rax,%r12,4), %eax
+	movq	%rax, 336(%rsp)
+	movq	%rbx, (%rsp)
+	movq	%rbx, %r8
+	andq	$3, %r8
+	je	.LBB{7}
+.LBB{8}:
+	cmpl	$2, %r8d
+	jae	.LBB{9}
+.LBB{10}:
+	movq	$-1, %r15
+	cmpb	$31, %bpl
+	ja	.LBB{11}
+	movq	%r8, %r13
+	movl	%ebp, %eax
+	shrl	$8, %eax
+	movzbl	%al, %edi
+	movl	%ebp, %r14d
+	shrl	$16, %r14d
+	movl	%ebp, %ebx
+	shrl	$24, %ebx
+	orq	$2, %r12
+	movzbl	%bpl, %ecx
+	leaq	.LJTI{12}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{13}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{15}
+	jmp	.LBB{16}
+.LBB{7}:
+	movq	%r8, 8(%rsp)
+	movq	320(%rsp), %rax
+	leaq	(%rax,%r12,4), %rax
+	movl	(%rax), %ecx
+	movq	$-1, %r15
+	cmpb	$31, %cl
+	ja	.LBB{11}
+	movl	%ecx, %eax
+	shrl	$8, %eax
+	movzbl	%al, %edi
+	movl	%ecx, %r14d
+	shrl	$16, %r14d
+	movl	%ecx, %ebx
+	shrl	$24, %ebx
+	movq	(%rsp), %r13
+	orq	$1, %r13
+	movzbl	%cl, %edx
+	leaq	.LJTI{17}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{18}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jne	.LBB{20}
+	jmp	.LBB{10}
+.LBB{21}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jns	.LBB{16}
+	jmp	.LBB{15}
+.LBB{22}:
+	leaq	.Lanon{23}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{25}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{26}
+	movq	16(%rsp,%rax,8), %rax
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{11}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{11}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	%rdx, %r8
+	addq	%rcx, %r8
+	setb	%dil
+	cmpq	%rsi, %r8
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{11}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{27}
+	cmpq	%r8, %rcx
+	jae	.LBB{27}
+	movq	176(%rsp), %r8
+	movq	%rdx, %r9
+	movq	%rcx, %r10
+	jmp	.LBB{28}
+.LBB{29}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{27}
+.LBB{28}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{29}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{29}
+	jmp	.LBB{11}
+.LBB{30}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{23}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{31}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rcx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{32}
+.LBB{33}:
+	leaq	.Lanon{23}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %esi
+	cmpl	$15, %esi
+	ja	.LBB{34}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{26}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{11}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{11}
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{35}
+	cmpq	%rdi, %rax
+	jae	.LBB{35}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{36}
+.LBB{37}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{35}
+.LBB{36}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{37}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{37}
+	jmp	.LBB{11}
+.LBB{38}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{11}
+	movq	16(%rsp,%rdi,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{39}
+.Ltmp{40}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{41}:
+.LBB{39}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{16}
+.LBB{42}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{46}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{48}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	cmpl	$268435455, %ebp
+	ja	.LBB{49}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rbx,8), %rcx
+	addq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{50}
+.LBB{51}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jne	.LBB{15}
+	jmp	.LBB{32}
+.LBB{53}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{54}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{55}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	cmpq	%r12, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{16}
+.LBB{56}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{16}
+	jmp	.LBB{15}
+.LBB{57}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{50}
+.LBB{58}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{59}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{60}
+	cmpq	$-1, %rcx
+	je	.LBB{61}
+.LBB{60}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{62}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{63}
+	jmp	.LBB{45}
+.LBB{64}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{11}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{65}
+.Ltmp{66}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{67}:
+.LBB{65}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+	jmp	.LBB{15}
+.LBB{68}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{14}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{69}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{70}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{71}
+	jmp	.LBB{45}
+.LBB{72}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{73}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{50}
+.LBB{74}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{23}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{75}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{11}
+.LBB{75}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{16}
+.LBB{76}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	incq	16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{77}:
+	cmpl	$16, %edi
+	jae	.LBB{14}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	js	.LBB{16}
+.LBB{15}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	cmpq	%r12, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{16}
+.LBB{78}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{50}
+.LBB{79}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	addq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rcx,8), %rdx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{32}:
+	movq	(%rsp), %rbx
+	orq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{80}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{50}:
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{35}:
+	testq	%rcx, %rcx
+	movq	%r13, %r8
+	je	.LBB{9}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{81}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{81}
+.LBB{9}:
+	cmpl	$3, %r8d
+	je	.LBB{82}
+.LBB{16}:
+	movq	$-1, %r15
+	movq	328(%rsp), %r8
+	cmpb	$31, %r8b
+	ja	.LBB{11}
+	movl	%r8d, %eax
+	shrl	$8, %eax
+	movzbl	%al, %edi
+	movl	%r8d, %r14d
+	shrl	$16, %r14d
+	movl	%r8d, %ebx
+	shrl	$24, %ebx
+	movq	(%rsp), %r12
+	orq	$3, %r12
+	movzbl	%r8b, %ecx
+	leaq	.LJTI{83}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{84}:
+	movq	%r12, (%rsp)
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{86}:
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jns	.LBB{87}
+	jmp	.LBB{88}
+.LBB{89}:
+	leaq	.Lanon{90}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{25}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{26}
+	movq	16(%rsp,%rax,8), %rax
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{11}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{11}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	%rdx, %r8
+	addq	%rcx, %r8
+	setb	%dil
+	cmpq	%rsi, %r8
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{11}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{91}
+	cmpq	%r8, %rcx
+	jae	.LBB{91}
+	movq	176(%rsp), %r8
+	movq	%rdx, %r9
+	movq	%rcx, %r10
+	jmp	.LBB{92}
+.LBB{93}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{91}
+.LBB{92}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{93}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{93}
+	jmp	.LBB{11}
+.LBB{94}:
+	movq	%r12, (%rsp)
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{90}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{95}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rcx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{96}
+.LBB{97}:
+	leaq	.Lanon{90}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %esi
+	cmpl	$15, %esi
+	ja	.LBB{34}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{26}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{11}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{11}
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{98}
+	cmpq	%rdi, %rax
+	jae	.LBB{98}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{99}
+.LBB{100}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{98}
+.LBB{99}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{100}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{100}
+	jmp	.LBB{11}
+.LBB{101}:
+	movq	%r12, (%rsp)
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{11}
+	movq	16(%rsp,%rdi,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{102}
+.Ltmp{103}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{104}:
+.LBB{102}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{82}
+.LBB{105}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movq	%r12, (%rsp)
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{106}:
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{88}
+	jmp	.LBB{87}
+.LBB{107}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	movq	%r12, (%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{108}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movq	%r12, (%rsp)
+	cmpl	$268435455, %r8d
+	ja	.LBB{49}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rbx,8), %rcx
+	addq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{109}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jne	.LBB{88}
+	jmp	.LBB{96}
+.LBB{110}:
+	movq	%r12, (%rsp)
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{111}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	movq	%r12, (%rsp)
+	cmpq	%r12, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{82}
+.LBB{112}:
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{87}
+	jmp	.LBB{88}
+.LBB{113}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movq	%r12, (%rsp)
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{114}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	movq	%r12, (%rsp)
+	je	.LBB{115}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{116}
+	cmpq	$-1, %rcx
+	je	.LBB{117}
+.LBB{116}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{118}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{119}
+	jmp	.LBB{45}
+.LBB{120}:
+	movq	%r12, %r13
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{11}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{121}
+.Ltmp{122}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{123}:
+.LBB{121}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	movq	%r13, %r12
+	jmp	.LBB{88}
+.LBB{124}:
+	movq	%r12, (%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{85}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{125}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	movq	%r12, (%rsp)
+	jb	.LBB{126}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{127}
+	jmp	.LBB{45}
+.LBB{128}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movq	%r12, (%rsp)
+	movl	%ebx, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{129}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	movq	%r12, (%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{130}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{90}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	movq	%r12, (%rsp)
+	jae	.LBB{131}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{11}
+.LBB{131}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{82}
+.LBB{132}:
+	movq	%r12, (%rsp)
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	incq	16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{133}:
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	js	.LBB{87}
+.LBB{88}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%r12, (%rsp)
+	cmpq	%r12, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{82}
+.LBB{134}:
+	cmpl	$16, %edi
+	jae	.LBB{85}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{88}
+.LBB{87}:
+	movq	%r12, (%rsp)
+	jmp	.LBB{82}
+.LBB{135}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	movq	%r12, (%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{136}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	addq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rcx,8), %rdx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{96}:
+	incq	%r12
+	movq	%r12, %rbx
+	jmp	.LBB{4}
+.LBB{137}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	movq	%r12, (%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{98}:
+	movq	%r12, (%rsp)
+	testq	%rcx, %rcx
+	je	.LBB{82}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{138}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{138}
+	jmp	.LBB{82}
+.LBB{139}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jns	.LBB{10}
+	jmp	.LBB{20}
+.LBB{140}:
+	leaq	.Lanon{141}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{25}
+	movl	%ebx, %esi
+	cmpl	$268435455, %ecx
+	ja	.LBB{34}
+	movq	16(%rsp,%rax,8), %rax
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{11}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{11}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	%rdx, %r8
+	addq	%rcx, %r8
+	setb	%dil
+	cmpq	%rsi, %r8
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{11}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{142}
+	cmpq	%r8, %rcx
+	jae	.LBB{142}
+	movq	176(%rsp), %r8
+	movq	%rdx, %r9
+	movq	%rcx, %r10
+	jmp	.LBB{143}
+.LBB{144}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{142}
+.LBB{143}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{144}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{144}
+	jmp	.LBB{11}
+.LBB{145}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{141}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{146}
+.LBB{147}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rcx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{148}
+.LBB{149}:
+	leaq	.Lanon{141}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r14b, %esi
+	cmpl	$15, %esi
+	ja	.LBB{34}
+	movl	%ebx, %r8d
+	cmpl	$268435455, %ecx
+	ja	.LBB{150}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{11}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{11}
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{151}
+	cmpq	%rdi, %rax
+	jae	.LBB{151}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{152}
+.LBB{153}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{151}
+.LBB{152}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{153}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{153}
+	jmp	.LBB{11}
+.LBB{154}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{11}
+	movq	16(%rsp,%rdi,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{155}
+.Ltmp{156}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{157}:
+.LBB{155}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	8(%rsp), %r8
+	jmp	.LBB{10}
+.LBB{158}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %edx
+	cmpl	$268435455, %ecx
+	movq	8(%rsp), %r8
+	ja	.LBB{159}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	subq	16(%rsp,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{160}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	movq	8(%rsp), %r8
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{161}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	cmpl	$268435455, %ecx
+	movq	8(%rsp), %r8
+	ja	.LBB{49}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rbx,8), %rcx
+	addq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{162}
+.LBB{163}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jne	.LBB{20}
+	jmp	.LBB{148}
+.LBB{164}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{146}
+.LBB{165}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jmp	.LBB{10}
+.LBB{166}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	movq	8(%rsp), %r8
+	cmpq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{10}
+.LBB{167}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jne	.LBB{10}
+	jmp	.LBB{20}
+.LBB{168}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %edx
+	cmpl	$268435455, %ecx
+	movq	8(%rsp), %r8
+	ja	.LBB{159}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rdx,8), %rcx
+	imulq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{162}
+.LBB{169}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{159}
+	movq	16(%rsp,%rdx,8), %rcx
+	testq	%rcx, %rcx
+	movq	8(%rsp), %r8
+	je	.LBB{170}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{171}
+	cmpq	$-1, %rcx
+	je	.LBB{172}
+.LBB{171}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{173}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{174}
+	jmp	.LBB{45}
+.LBB{175}:
+	movq	208(%rsp), %rax
+	cmpq	$1024, %rax
+	je	.LBB{11}
+	movq	%rax, %r15
+	cmpq	192(%rsp), %rax
+	jne	.LBB{176}
+.Ltmp{177}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{178}:
+.LBB{176}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{179}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	8(%rsp), %r8
+	cmpq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{10}
+.LBB{180}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{19}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{146}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jmp	.LBB{10}
+.LBB{181}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{159}
+	movq	16(%rsp,%rdx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	movq	8(%rsp), %r8
+	jb	.LBB{182}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{183}
+	jmp	.LBB{45}
+.LBB{184}:
+	movzbl	%r14b, %eax
+	cmpb	$15, %r14b
+	ja	.LBB{43}
+	movl	%ebx, %edx
+	cmpl	$268435455, %ecx
+	movq	8(%rsp), %r8
+	ja	.LBB{159}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	cmpq	16(%rsp,%rdx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{185}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	movq	8(%rsp), %r8
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{162}
+.LBB{186}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{141}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{187}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{11}
+.LBB{187}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	8(%rsp), %r8
+	jmp	.LBB{10}
+.LBB{188}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	incq	16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	jmp	.LBB{10}
+.LBB{189}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	movq	8(%rsp), %r8
+	js	.LBB{10}
+.LBB{20}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	cmpq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{10}
+.LBB{190}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	movq	8(%rsp), %r8
+	ja	.LBB{24}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{162}
+.LBB{191}:
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	addq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %ecx
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rcx,8), %rdx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{148}:
+	movq	(%rsp), %rbx
+	orq	$2, %rbx
+	jmp	.LBB{4}
+.LBB{192}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	movq	8(%rsp), %r8
+	ja	.LBB{24}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{162}:
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{151}:
+	testq	%rcx, %rcx
+	movq	8(%rsp), %r8
+	je	.LBB{8}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{193}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{193}
+	jmp	.LBB{8}
+.LBB{126}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{194}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{127}
+	jmp	.LBB{45}
+.LBB{70}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{195}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{71}
+	jmp	.LBB{45}
+.LBB{91}:
+	movq	%r12, (%rsp)
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{196}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	jmp	.LBB{82}
+.LBB{27}:
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{196}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	%r13, %r8
+	cmpl	$3, %r8d
+	jne	.LBB{16}
+	jmp	.LBB{82}
+.LBB{115}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{119}
+	jmp	.LBB{45}
+.LBB{59}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{63}
+	jmp	.LBB{45}
+.LBB{182}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{197}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{183}
+	jmp	.LBB{45}
+.LBB{142}:
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{196}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	8(%rsp), %r8
+	jmp	.LBB{8}
+.LBB{118}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{45}
+.LBB{119}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{170}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{174}
+	jmp	.LBB{45}
+.LBB{62}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{45}
+.LBB{63}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{194}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{45}
+.LBB{127}:
+	movq	%rdx, 16(%rsp,%rdi,8)
+.LBB{82}:
+	movq	$-1, %r15
+	movq	336(%rsp), %r8
+	cmpb	$31, %r8b
+	ja	.LBB{11}
+	movl	%r8d, %eax
+	shrl	$8, %eax
+	movzbl	%al, %edi
+	movl	%r8d, %r12d
+	shrl	$16, %r12d
+	movl	%r8d, %r14d
+	shrl	$24, %r14d
+	movq	(%rsp), %rsi
+	leaq	1(%rsi), %rbx
+	movzbl	%r8b, %ecx
+	leaq	.LJTI{198}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{199}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{6}
+	jmp	.LBB{4}
+.LBB{201}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jns	.LBB{4}
+	jmp	.LBB{6}
+.LBB{202}:
+	leaq	.Lanon{203}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r12b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{25}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{26}
+	movq	16(%rsp,%rax,8), %rax
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{11}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{11}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	%rdx, %r8
+	addq	%rcx, %r8
+	setb	%dil
+	cmpq	%rsi, %r8
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{11}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{204}
+	cmpq	%r8, %rcx
+	jae	.LBB{204}
+	movq	176(%rsp), %r8
+	movq	%rdx, %r9
+	movq	%rcx, %r10
+	jmp	.LBB{205}
+.LBB{206}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{204}
+.LBB{205}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{206}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{206}
+	jmp	.LBB{11}
+.LBB{207}:
+	movzbl	%r12b, %ecx
+	leaq	.Lanon{203}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{208}:
+	movl	%r14d, %ecx
+	andb	$15, %cl
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r12b
+	movzbl	%r12b, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rcx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{209}
+.LBB{210}:
+	leaq	.Lanon{203}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{24}
+	movzbl	%r12b, %esi
+	cmpl	$15, %esi
+	ja	.LBB{34}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{26}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{11}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{11}
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{211}
+	cmpq	%rdi, %rax
+	jae	.LBB{211}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{212}
+.LBB{213}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{211}
+.LBB{212}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{213}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{213}
+	jmp	.LBB{11}
+.LBB{214}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{11}
+	movq	16(%rsp,%rdi,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{215}
+.Ltmp{216}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{217}:
+.LBB{215}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{218}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{219}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{220}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	cmpl	$268435456, %r8d
+	jae	.LBB{221}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%r14,8), %rcx
+	addq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{222}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jne	.LBB{6}
+	movq	(%rsp), %rbx
+	addq	$2, %rbx
+	jmp	.LBB{4}
+.LBB{223}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	shll	$8, %r14d
+	movzbl	%r12b, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{224}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	decq	16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{225}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{226}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jne	.LBB{4}
+	jmp	.LBB{6}
+.LBB{227}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rcx,8), %rcx
+	imulq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{228}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{229}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{230}
+	cmpq	$-1, %rcx
+	je	.LBB{231}
+.LBB{230}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{232}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{233}
+	jmp	.LBB{45}
+.LBB{234}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{11}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{5}
+.Ltmp{235}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{236}:
+	jmp	.LBB{5}
+.LBB{237}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{11}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{200}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{238}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{239}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{240}
+	jmp	.LBB{45}
+.LBB{241}:
+	movzbl	%r12b, %eax
+	cmpb	$15, %r12b
+	ja	.LBB{43}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %r8d
+	ja	.LBB{44}
+	cmpl	$15, %edi
+	ja	.LBB{45}
+	movq	16(%rsp,%rax,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{242}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{243}:
+	movzbl	%r12b, %ecx
+	leaq	.Lanon{203}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{26}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{244}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{11}
+.LBB{244}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{11}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{245}:
+	cmpl	$16, %edi
+	jae	.LBB{52}
+	incq	16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{246}:
+	cmpl	$16, %edi
+	jae	.LBB{200}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	jns	.LBB{6}
+	jmp	.LBB{4}
+.LBB{247}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{248}:
+	movl	%r14d, %ecx
+	andb	$15, %cl
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	addq	16(%rsp,%rdx,8), %rcx
+	movl	%eax, %edx
+	andl	$15, %edx
+	movq	%rcx, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r12b
+	movzbl	%r12b, %ecx
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rcx,8), %rdx
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{209}:
+	addq	$2, %rsi
+	movq	%rsi, %rbx
+	jmp	.LBB{4}
+.LBB{249}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{47}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{25}
+	cmpl	$15, %edi
+	ja	.LBB{24}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{211}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{250}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{250}
+	jmp	.LBB{4}
+.LBB{239}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{251}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{240}
+	jmp	.LBB{45}
+.LBB{204}:
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{196}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	jmp	.LBB{4}
+.LBB{229}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{233}
+	jmp	.LBB{45}
+.LBB{232}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{45}
+.LBB{233}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{251}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{45}
+.LBB{240}:
+	movq	%rdx, 16(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{195}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{45}
+.LBB{71}:
+	movq	%rdx, 16(%rsp,%rdi,8)
+	jmp	.LBB{16}
+.LBB{173}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{45}
+.LBB{174}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{197}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{45}
+.LBB{183}:
+	movq	%rdx, 16(%rsp,%rdi,8)
+	jmp	.LBB{10}
+.LBB{231}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{233}
+	jmp	.LBB{45}
+.LBB{117}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{119}
+	jmp	.LBB{45}
+.LBB{61}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{63}
+	jmp	.LBB{45}
+.LBB{172}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{174}
+.LBB{45}:
+	movq	%rdi, %rax
+	jmp	.LBB{43}
+.LBB{252}:
+	cmpl	$16, %edi
+	jb	.LBB{253}
+.LBB{200}:
+	leaq	.Lanon{203}(%rip), %rdx
+	jmp	.LBB{24}
+.LBB{254}:
+	cmpl	$16, %edi
+	jb	.LBB{253}
+.LBB{85}:
+	leaq	.Lanon{90}(%rip), %rdx
+	jmp	.LBB{24}
+.LBB{255}:
+	cmpl	$16, %edi
+	jb	.LBB{253}
+.LBB{14}:
+	leaq	.Lanon{23}(%rip), %rdx
+	jmp	.LBB{24}
+.LBB{256}:
+	cmpl	$16, %edi
+	jae	.LBB{19}
+.LBB{253}:
+	movq	16(%rsp,%rdi,8), %r15
+.LBB{11}:
+	movq	152(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{257}
+	movq	160(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{257}:
+	movq	176(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{258}
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{258}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{259}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{259}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{260}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{260}:
+	movq	%r15, %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 16(%rsp)
+	leaq	16(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{261}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{262}(%rip), %rdi
+	leaq	.Lanon{263}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{196}:
+.Ltmp{264}:
+	leaq	.Lanon{265}(%rip), %rdi
+	leaq	.Lanon{266}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{267}:
+	jmp	.LBB{268}
+.LBB{25}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{44}:
+	movq	%rcx, %rax
+.LBB{43}:
+.Ltmp{269}:
+	leaq	.Lanon{47}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rax, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{270}:
+	jmp	.LBB{268}
+.LBB{26}:
+	movq	%rcx, %rdi
+	jmp	.LBB{24}
+.LBB{52}:
+	leaq	.Lanon{47}(%rip), %rdx
+	jmp	.LBB{24}
+.LBB{19}:
+	leaq	.Lanon{141}(%rip), %rdx
+	jmp	.LBB{24}
+.LBB{34}:
+	movq	%rsi, %rdi
+.LBB{24}:
+.Ltmp{271}:
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{272}:
+.LBB{268}:
+	ud2
+.LBB{159}:
+	movq	%rdx, %rax
+	jmp	.LBB{43}
+.LBB{49}:
+	movq	%rbx, %rax
+	jmp	.LBB{43}
+.LBB{221}:
+	movq	%r14, %rax
+	jmp	.LBB{43}
+.LBB{150}:
+	movq	%r8, %rdi
+	jmp	.LBB{24}
+.LBB{273}:
+.Ltmp{274}:
+	movq	%rax, %rbx
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{275}:
+.Ltmp{276}:
+	jmp	.LBB{277}
+.LBB{278}:
+.Ltmp{279}:
+.LBB{277}:
+	movq	%rax, %rbx
+	leaq	152(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,716 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$328, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	8(%rdi), %r13
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	movaps	%xmm0, 160(%rsp)
+	shlq	$3, %rdx
+	leaq	160(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	160(%rsp), %xmm0
+	movaps	176(%rsp), %xmm1
+	movaps	192(%rsp), %xmm2
+	movaps	208(%rsp), %xmm3
+	movaps	%xmm0, 32(%rsp)
+	movaps	%xmm1, 48(%rsp)
+	movaps	%xmm2, 64(%rsp)
+	movaps	%xmm3, 80(%rsp)
+	movaps	224(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 144(%rsp)
+	movq	$0, 160(%rsp)
+	movq	$8, 168(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 176(%rsp)
+	movq	$8, 192(%rsp)
+	movq	$0, 200(%rsp)
+	addq	$24, %rbx
+.Ltmp{2}:
+	leaq	288(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	296(%rsp), %rax
+	movq	%rax, 16(%rsp)
+	movq	304(%rsp), %rax
+	movq	%rax, 8(%rsp)
+	movq	320(%rsp), %r15
+	movq	312(%rsp), %rax
+	movq	%rax, 24(%rsp)
+	xorl	%ebx, %ebx
+	leaq	.LJTI{4}(%rip), %r14
+	jmp	.LBB{5}
+.LBB{6}:
+	movl	$64, %ecx
+	rep		bsfq	32(%rsp,%rdi,8), %rcx
+.LBB{7}:
+	movq	%rcx, 32(%rsp,%rax,8)
+	addq	$3, %rbx
+.LBB{5}:
+	movzbl	(%r13,%rbx), %eax
+	movq	$-1, %r12
+	cmpq	$31, %rax
+	ja	.LBB{8}
+	movslq	(%r14,%rax,4), %rcx
+	addq	%r14, %rcx
+	jmpq	*%rcx
+.LBB{9}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movq	32(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{11}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{12}:
+	testq	%rcx, %rcx
+	jne	.LBB{13}
+	jmp	.LBB{14}
+.LBB{15}:
+	movzbl	1(%r13,%rbx), %eax
+	movzbl	2(%r13,%rbx), %ecx
+	movzbl	3(%r13,%rbx), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	32(%rsp,%rsi,8), %rsi
+	imulq	32(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, 32(%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	32(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	32(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, 32(%rsp,%rax,8)
+	addq	$8, %rbx
+	jmp	.LBB{5}
+.LBB{16}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	2(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movq	32(%rsp,%rdi,8), %rdx
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	jb	.LBB{8}
+	cmpq	8(%rsp), %rsi
+	ja	.LBB{8}
+	movq	32(%rsp,%rcx,8), %rcx
+	testq	%r15, %r15
+	je	.LBB{19}
+	cmpq	%rsi, %rdx
+	jae	.LBB{19}
+	movq	%rax, %rsi
+	movq	%rdx, %rdi
+	jmp	.LBB{20}
+.LBB{21}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{19}
+.LBB{20}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r15, %r8
+	jae	.LBB{21}
+	movq	24(%rsp), %r9
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{21}
+	jmp	.LBB{8}
+.LBB{22}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	addq	32(%rsp,%rdi,8), %rax
+	movzbl	1(%r13,%rbx), %edi
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%r15, %rcx
+	jae	.LBB{23}
+	movq	24(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{8}
+.LBB{23}:
+	cmpq	8(%rsp), %rax
+	jae	.LBB{8}
+	cmpb	$15, %dil
+	ja	.LBB{10}
+	addq	$4, %rbx
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	16(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+	jmp	.LBB{5}
+.LBB{24}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	movzbl	1(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	addq	$4, %rbx
+	movq	32(%rsp,%rdi,8), %rdx
+	cmpq	32(%rsp,%rcx,8), %rdx
+	setl	%cl
+	setg	%dl
+	subb	%cl, %dl
+	movsbq	%dl, %rcx
+	movq	%rcx, 32(%rsp,%rax,8)
+	jmp	.LBB{5}
+.LBB{25}:
+	movq	200(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{8}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 200(%rsp)
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{10}
+	movq	192(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 32(%rsp,%rdi,8)
+	addq	$2, %rbx
+	jmp	.LBB{5}
+.LBB{26}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	1(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	addq	$4, %rbx
+	movq	32(%rsp,%rdi,8), %rdx
+	subq	32(%rsp,%rax,8), %rdx
+	movq	%rdx, 32(%rsp,%rcx,8)
+	jmp	.LBB{5}
+.LBB{27}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	1(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movq	32(%rsp,%rdi,8), %rcx
+	jmp	.LBB{7}
+.LBB{28}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	decq	32(%rsp,%rdi,8)
+	je	.LBB{29}
+	movzwl	2(%rbx,%r13), %ebx
+	jmp	.LBB{5}
+.LBB{30}:
+	movq	176(%rsp), %rbp
+	cmpq	$1024, %rbp
+	je	.LBB{8}
+	movzwl	1(%r13,%rbx), %r12d
+	cmpq	160(%rsp), %rbp
+	jne	.LBB{31}
+.Ltmp{32}:
+	leaq	160(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{33}:
+.LBB{31}:
+	addq	$3, %rbx
+	movq	168(%rsp), %rax
+	movq	%rbx, (%rax,%rbp,8)
+	incq	%rbp
+	movq	%rbp, 176(%rsp)
+	movq	%r12, %rbx
+	jmp	.LBB{5}
+.LBB{34}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	1(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	addq	$4, %rbx
+	movq	32(%rsp,%rax,8), %rax
+	addq	32(%rsp,%rdi,8), %rax
+	movq	%rax, 32(%rsp,%rcx,8)
+	jmp	.LBB{5}
+.LBB{35}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	2(%r13,%rbx), %eax
+	movzbl	3(%r13,%rbx), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, 32(%rsp,%rdi,8)
+	addq	$4, %rbx
+	jmp	.LBB{5}
+.LBB{36}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	decq	32(%rsp,%rdi,8)
+	addq	$2, %rbx
+	jmp	.LBB{5}
+.LBB{37}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	je	.LBB{38}
+	movzwl	2(%rbx,%r13), %ebx
+	jmp	.LBB{5}
+.LBB{39}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movq	200(%rsp), %rbp
+	cmpq	$1024, %rbp
+	je	.LBB{8}
+	movq	32(%rsp,%rdi,8), %r12
+	cmpq	184(%rsp), %rbp
+	jne	.LBB{40}
+.Ltmp{41}:
+	leaq	184(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{42}:
+.LBB{40}:
+	movq	192(%rsp), %rax
+	movq	%r12, (%rax,%rbp,8)
+	incq	%rbp
+	movq	%rbp, 200(%rsp)
+	addq	$2, %rbx
+	jmp	.LBB{5}
+.LBB{43}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	1(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	addq	$4, %rbx
+	movq	32(%rsp,%rax,8), %rax
+	imulq	32(%rsp,%rdi,8), %rax
+	movq	%rax, 32(%rsp,%rcx,8)
+	jmp	.LBB{5}
+.LBB{44}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	1(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	%rcx, %rdx
+	shrq	%rdx
+	movabsq	$6148914691236517205, %rsi
+	andq	%rdx, %rsi
+	subq	%rsi, %rcx
+	movabsq	$3689348814741910323, %rdx
+	movq	%rcx, %rsi
+	andq	%rdx, %rsi
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rsi, %rcx
+	movq	%rcx, %rdx
+	shrq	$4, %rdx
+	addq	%rcx, %rdx
+	movabsq	$1085102592571150095, %rcx
+	andq	%rdx, %rcx
+	movabsq	$72340172838076673, %rdx
+	imulq	%rcx, %rdx
+	shrq	$56, %rdx
+	movq	%rdx, 32(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{5}
+.LBB{45}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	1(%r13,%rbx), %ecx
+	movq	32(%rsp,%rax,8), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{46}
+	movq	32(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{47}
+	cmpq	$-1, %rsi
+	je	.LBB{48}
+.LBB{47}:
+	movq	%rax, %rdx
+	orq	%rsi, %rdx
+	shrq	$32, %rdx
+	je	.LBB{49}
+	cqto
+	idivq	%rsi
+	cmpb	$15, %cl
+	jbe	.LBB{50}
+	jmp	.LBB{17}
+.LBB{51}:
+	movq	176(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{8}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 176(%rsp)
+	movq	168(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{5}
+.LBB{52}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	addq	32(%rsp,%rdi,8), %rax
+	cmpq	8(%rsp), %rax
+	jae	.LBB{8}
+	movzbl	1(%r13,%rbx), %edi
+	cmpb	$15, %dil
+	ja	.LBB{10}
+	addq	$4, %rbx
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 32(%rsp,%rdi,8)
+	jmp	.LBB{5}
+.LBB{53}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	3(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	1(%r13,%rbx), %ecx
+	movq	32(%rsp,%rax,8), %rsi
+	leaq	-1(%rsi), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{54}
+	xorl	%edx, %edx
+	cmpb	$16, %cl
+	jb	.LBB{55}
+	jmp	.LBB{17}
+.LBB{56}:
+	movzwl	1(%r13,%rbx), %ebx
+	jmp	.LBB{5}
+.LBB{57}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	2(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movzbl	3(%r13,%rbx), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{17}
+	movq	32(%rsp,%rax,8), %rax
+	movq	32(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{8}
+	movq	8(%rsp), %r8
+	cmpq	%r8, %rcx
+	ja	.LBB{8}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r8, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{8}
+	testq	%r15, %r15
+	je	.LBB{58}
+	cmpq	%rsi, %rcx
+	jae	.LBB{58}
+	movq	%rdx, %rsi
+	movq	%rcx, %rdi
+	jmp	.LBB{59}
+.LBB{60}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{58}
+.LBB{59}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r15, %r8
+	jae	.LBB{60}
+	movq	24(%rsp), %r9
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{60}
+	jmp	.LBB{8}
+.LBB{61}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	incq	32(%rsp,%rdi,8)
+	addq	$2, %rbx
+	jmp	.LBB{5}
+.LBB{62}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	1(%r13,%rbx), %eax
+	cmpb	$15, %al
+	jbe	.LBB{6}
+	jmp	.LBB{18}
+.LBB{63}:
+	movzbl	1(%r13,%rbx), %eax
+	movzbl	2(%r13,%rbx), %ecx
+	movzbl	3(%r13,%rbx), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	32(%rsp,%rsi,8), %rsi
+	addq	32(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, 32(%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	addq	32(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, 32(%rsp,%rax,8)
+	addq	$8, %rbx
+	jmp	.LBB{5}
+.LBB{64}:
+	movzbl	2(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movzbl	1(%r13,%rbx), %eax
+	cmpb	$15, %al
+	ja	.LBB{18}
+	movl	$127, %ecx
+	bsrq	32(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+	jmp	.LBB{7}
+.LBB{65}:
+	testq	%rcx, %rcx
+	js	.LBB{14}
+.LBB{13}:
+	addq	$4, %rbx
+	jmp	.LBB{5}
+.LBB{66}:
+	testq	%rcx, %rcx
+	js	.LBB{13}
+.LBB{14}:
+	movzwl	2(%rbx,%r13), %ebx
+	jmp	.LBB{5}
+.LBB{67}:
+	testq	%rcx, %rcx
+	jne	.LBB{14}
+	jmp	.LBB{13}
+.LBB{19}:
+	addq	$4, %rbx
+	testq	%rax, %rax
+	je	.LBB{5}
+	movq	16(%rsp), %rsi
+	leaq	(%rsi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{68}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{68}
+	jmp	.LBB{5}
+.LBB{54}:
+	movq	32(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rsi, %rdx
+	shrq	$32, %rdx
+	je	.LBB{69}
+	cqto
+	idivq	%rsi
+	cmpb	$16, %cl
+	jb	.LBB{55}
+	jmp	.LBB{17}
+.LBB{58}:
+	movq	8(%rsp), %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{70}
+	addq	$4, %rbx
+	movq	16(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	jmp	.LBB{5}
+.LBB{29}:
+	addq	$8, %rbx
+	jmp	.LBB{5}
+.LBB{38}:
+	addq	$4, %rbx
+	jmp	.LBB{5}
+.LBB{46}:
+	xorl	%eax, %eax
+	cmpb	$15, %cl
+	jbe	.LBB{50}
+	jmp	.LBB{17}
+.LBB{49}:
+	xorl	%edx, %edx
+	divl	%esi
+	cmpb	$15, %cl
+	ja	.LBB{17}
+.LBB{50}:
+	addq	$4, %rbx
+	movq	%rax, 32(%rsp,%rcx,8)
+	jmp	.LBB{5}
+.LBB{69}:
+	xorl	%edx, %edx
+	divl	%esi
+	cmpb	$16, %cl
+	jae	.LBB{17}
+.LBB{55}:
+	addq	$4, %rbx
+	movq	%rdx, 32(%rsp,%rcx,8)
+	jmp	.LBB{5}
+.LBB{48}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %cl
+	jbe	.LBB{50}
+.LBB{17}:
+	movq	%rcx, %rdi
+	jmp	.LBB{10}
+.LBB{71}:
+	movzbl	1(%r13,%rbx), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{10}
+	movq	32(%rsp,%rdi,8), %r12
+.LBB{8}:
+	movq	288(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{72}
+	shlq	$3, %rsi
+	movl	$8, %edx
+	movq	16(%rsp), %rdi
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{72}:
+	movq	24(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{73}
+	leaq	312(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{73}:
+	movq	160(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{74}
+	movq	168(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{74}:
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{75}
+	movq	192(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{75}:
+	movq	%r12, %rax
+	addq	$328, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 32(%rsp)
+	leaq	32(%rsp), %rax
+	movq	%rax, 160(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 168(%rsp)
+	leaq	.Lanon{76}(%rip), %rcx
+	movq	%rcx, 176(%rsp)
+	movq	%rax, 184(%rsp)
+	leaq	.Lanon{77}(%rip), %rdi
+	leaq	.Lanon{78}(%rip), %rdx
+	leaq	160(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{70}:
+.Ltmp{79}:
+	leaq	.Lanon{80}(%rip), %rdi
+	leaq	.Lanon{81}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{82}:
+	jmp	.LBB{83}
+.LBB{18}:
+	movq	%rax, %rdi
+.LBB{10}:
+.Ltmp{84}:
+	leaq	.Lanon{85}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{86}:
+.LBB{83}:
+	ud2
+.LBB{87}:
+.Ltmp{88}:
+	jmp	.LBB{89}
+.LBB{90}:
+.Ltmp{91}:
+	movq	%rax, %rbx
+	leaq	160(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{92}:
+.Ltmp{93}:
+.LBB{89}:
+	movq	%rax, %rbx
+	leaq	288(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	160(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,10733 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$312, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	8(%rdi), %rbp
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	shlq	$3, %rdx
+	leaq	176(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	176(%rsp), %xmm0
+	movaps	192(%rsp), %xmm1
+	movaps	208(%rsp), %xmm2
+	movaps	224(%rsp), %xmm3
+	movaps	%xmm0, (%rsp)
+	movaps	%xmm1, 16(%rsp)
+	movaps	%xmm2, 32(%rsp)
+	movaps	%xmm3, 48(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movq	$0, 176(%rsp)
+	movq	$8, 184(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 192(%rsp)
+	movq	$8, 208(%rsp)
+	movq	$0, 216(%rsp)
+	addq	$24, %rbx
+.Ltmp{2}:
+	leaq	136(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%r14d, %r14d
+.LBB{4}:
+	movzbl	(%rbp,%r14), %eax
+	movq	$-1, %r15
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{6}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{7}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{9}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{10}:
+	testq	%rcx, %rcx
+	jne	.LBB{11}
+	jmp	.LBB{12}
+.LBB{13}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	movzbl	8(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{14}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{15}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{17}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{18}:
+	testq	%rcx, %rcx
+	jne	.LBB{19}
+	jmp	.LBB{20}
+.LBB{21}:
+	movzbl	1(%rbp,%r14), %eax
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	2(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{26}
+	cmpq	%r8, %rax
+	jae	.LBB{26}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{27}
+.LBB{28}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{26}
+.LBB{27}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{28}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{28}
+	jmp	.LBB{5}
+.LBB{29}:
+	movzbl	2(%rbp,%r14), %ecx
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{25}
+	movzbl	1(%rbp,%r14), %edi
+	movzbl	3(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{30}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{30}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	144(%rsp), %rcx
+	movq	(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{31}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{32}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{33}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{34}:
+	testq	%rcx, %rcx
+	jne	.LBB{35}
+	jmp	.LBB{36}
+.LBB{37}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movq	(%rsp,%rdi,8), %rdx
+	cmpq	(%rsp,%rcx,8), %rdx
+	setl	%cl
+	setg	%dl
+	subb	%cl, %dl
+	movsbq	%dl, %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{40}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{41}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{43}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{44}:
+	testq	%rcx, %rcx
+	jne	.LBB{45}
+	jmp	.LBB{46}
+.LBB{47}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rdx
+	movq	%rdx, 216(%rsp)
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{48}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rsi
+	movq	%rsi, (%rsp,%rdi,8)
+	movzbl	2(%r14,%rbp), %esi
+	cmpq	$31, %rsi
+	ja	.LBB{5}
+	leaq	.LJTI{49}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %r8
+	addq	%rdi, %r8
+	jmpq	*%r8
+.LBB{50}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rax
+	addl	$-21, %esi
+	leaq	.LJTI{51}(%rip), %rcx
+	movslq	(%rcx,%rsi,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{52}:
+	testq	%rax, %rax
+	jne	.LBB{53}
+	jmp	.LBB{54}
+.LBB{55}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rdi,8), %rdx
+	subq	(%rsp,%rax,8), %rdx
+	movq	%rdx, (%rsp,%rcx,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{58}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{59}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{60}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{61}:
+	testq	%rcx, %rcx
+	jne	.LBB{62}
+	jmp	.LBB{63}
+.LBB{64}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movq	(%rsp,%rdi,8), %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	movzbl	3(%r14,%rbp), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{65}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{66}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{67}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{68}:
+	testq	%rcx, %rcx
+	jne	.LBB{69}
+	jmp	.LBB{70}
+.LBB{71}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{72}
+	movzwl	2(%r14,%rbp), %r14d
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	jbe	.LBB{73}
+	jmp	.LBB{5}
+.LBB{74}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r14), %r12d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{75}
+.Ltmp{76}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{77}:
+.LBB{75}:
+	addq	$3, %r14
+	movq	184(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	leaq	1(%rbx), %rcx
+	movq	%rcx, 192(%rsp)
+	movzbl	(%rbp,%r12), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{78}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rsi
+	addq	%rdx, %rsi
+	jmpq	*%rsi
+.LBB{79}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{80}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{81}:
+	testq	%rcx, %rcx
+	jne	.LBB{82}
+	jmp	.LBB{83}
+.LBB{84}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rdi,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{85}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{86}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{87}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{88}:
+	testq	%rcx, %rcx
+	jne	.LBB{89}
+	jmp	.LBB{90}
+.LBB{91}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	movzbl	2(%rbp,%r14), %eax
+	movzbl	3(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{92}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{93}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{94}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{95}:
+	testq	%rcx, %rcx
+	jne	.LBB{96}
+	jmp	.LBB{97}
+.LBB{98}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	decq	(%rsp,%rdi,8)
+	movzbl	2(%r14,%rbp), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{99}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{100}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{101}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{102}:
+	testq	%rcx, %rcx
+	jne	.LBB{103}
+	jmp	.LBB{104}
+.LBB{105}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{106}
+	movzwl	2(%r14,%rbp), %r14d
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	jbe	.LBB{107}
+	jmp	.LBB{5}
+.LBB{108}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r12
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{109}
+.Ltmp{110}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{111}:
+.LBB{109}:
+	movq	208(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	leaq	1(%rbx), %rax
+	movq	%rax, 216(%rsp)
+	movzbl	2(%r14,%rbp), %ecx
+	cmpq	$31, %rcx
+	ja	.LBB{5}
+	leaq	.LJTI{112}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rsi
+	addq	%rdx, %rsi
+	jmpq	*%rsi
+.LBB{113}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rax
+	addl	$-21, %ecx
+	leaq	.LJTI{114}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{115}:
+	testq	%rax, %rax
+	jne	.LBB{116}
+	jmp	.LBB{117}
+.LBB{118}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rdi,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{121}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{122}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{123}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{124}:
+	testq	%rcx, %rcx
+	jne	.LBB{125}
+	jmp	.LBB{126}
+.LBB{127}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	1(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rdx
+	movq	%rdx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rcx
+	andq	%rcx, %rax
+	subq	%rax, %rdx
+	movabsq	$3689348814741910323, %rax
+	movq	%rdx, %rsi
+	andq	%rax, %rsi
+	shrq	$2, %rdx
+	andq	%rax, %rdx
+	addq	%rsi, %rdx
+	movq	%rdx, %r8
+	shrq	$4, %r8
+	addq	%rdx, %r8
+	movabsq	$1085102592571150095, %rdx
+	andq	%rdx, %r8
+	movabsq	$72340172838076673, %rsi
+	imulq	%rsi, %r8
+	shrq	$56, %r8
+	movq	%r8, (%rsp,%rdi,8)
+	movzbl	3(%r14,%rbp), %r8d
+	cmpq	$31, %r8
+	ja	.LBB{5}
+	leaq	.LJTI{128}(%rip), %rdi
+	movslq	(%rdi,%r8,4), %r9
+	addq	%rdi, %r9
+	jmpq	*%r9
+.LBB{129}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rax
+	addl	$-21, %r8d
+	leaq	.LJTI{130}(%rip), %rcx
+	movslq	(%rcx,%r8,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{131}:
+	testq	%rax, %rax
+	jne	.LBB{132}
+	jmp	.LBB{133}
+.LBB{134}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{135}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{136}
+	cmpq	$-1, %rcx
+	je	.LBB{137}
+.LBB{136}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{138}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{139}
+	jmp	.LBB{24}
+.LBB{140}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rsi
+	movq	%rsi, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movzbl	(%rbp,%r14), %edx
+	cmpq	$31, %rdx
+	ja	.LBB{5}
+	leaq	.LJTI{141}(%rip), %rdi
+	movslq	(%rdi,%rdx,4), %r8
+	addq	%rdi, %r8
+	jmpq	*%r8
+.LBB{142}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rax
+	addl	$-21, %edx
+	leaq	.LJTI{143}(%rip), %rcx
+	movslq	(%rcx,%rdx,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{144}:
+	testq	%rax, %rax
+	jne	.LBB{145}
+	jmp	.LBB{146}
+.LBB{147}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	144(%rsp), %rdi
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{148}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{149}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{150}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{151}:
+	testq	%rcx, %rcx
+	jne	.LBB{152}
+	jmp	.LBB{153}
+.LBB{154}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{155}
+	xorl	%edx, %edx
+	cmpb	$16, %dil
+	jb	.LBB{156}
+	jmp	.LBB{24}
+.LBB{157}:
+	movzwl	1(%rbp,%r14), %r14d
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{158}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{159}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{160}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{161}:
+	testq	%rcx, %rcx
+	jne	.LBB{162}
+	jmp	.LBB{163}
+.LBB{164}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %r13
+	cmpq	%r13, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{5}
+	movq	168(%rsp), %rbx
+	testq	%rbx, %rbx
+	je	.LBB{165}
+	cmpq	%rsi, %rcx
+	jae	.LBB{165}
+	movq	160(%rsp), %rsi
+	movq	%rdx, %rdi
+	movq	%rcx, %r8
+	jmp	.LBB{166}
+.LBB{167}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{165}
+.LBB{166}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{167}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{167}
+	jmp	.LBB{5}
+.LBB{168}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+	incq	(%rsp,%rdi,8)
+	movzbl	2(%r14,%rbp), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{169}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{170}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{171}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{172}:
+	testq	%rcx, %rcx
+	jne	.LBB{173}
+	jmp	.LBB{174}
+.LBB{175}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	movzbl	3(%r14,%rbp), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{176}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{177}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{178}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{179}:
+	testq	%rcx, %rcx
+	jne	.LBB{180}
+	jmp	.LBB{181}
+.LBB{182}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	movzbl	8(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{183}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{184}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{185}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{186}:
+	testq	%rcx, %rcx
+	jne	.LBB{187}
+	jmp	.LBB{188}
+.LBB{189}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{22}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	movzbl	3(%r14,%rbp), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{190}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{191}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{192}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{193}:
+	testq	%rcx, %rcx
+	jne	.LBB{194}
+	jmp	.LBB{195}
+.LBB{196}:
+	testq	%rcx, %rcx
+	js	.LBB{12}
+.LBB{11}:
+	addq	$4, %r14
+	jmp	.LBB{197}
+.LBB{198}:
+	testq	%rcx, %rcx
+	js	.LBB{11}
+.LBB{12}:
+	movzwl	2(%r14,%rbp), %r14d
+.LBB{197}:
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{199}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{200}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{201}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{202}:
+	testq	%rcx, %rcx
+	jne	.LBB{203}
+	jmp	.LBB{204}
+.LBB{205}:
+	testq	%rcx, %rcx
+	jne	.LBB{12}
+	jmp	.LBB{11}
+.LBB{26}:
+	testq	%rcx, %rcx
+	je	.LBB{206}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{207}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{207}
+.LBB{206}:
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{208}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{209}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{210}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{211}:
+	testq	%rcx, %rcx
+	jne	.LBB{212}
+	jmp	.LBB{213}
+.LBB{155}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{214}
+	cqto
+	idivq	%rcx
+	cmpb	$16, %dil
+	jb	.LBB{156}
+	jmp	.LBB{24}
+.LBB{165}:
+	movq	%r13, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{215}
+	movq	144(%rsp), %r12
+	leaq	(%r12,%rax,8), %rsi
+	leaq	(%r12,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{216}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{217}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{218}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{219}:
+	testq	%rcx, %rcx
+	jne	.LBB{220}
+	jmp	.LBB{221}
+.LBB{72}:
+	addq	$8, %r14
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+.LBB{73}:
+	leaq	.LJTI{222}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{223}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{224}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{225}:
+	testq	%rcx, %rcx
+	jne	.LBB{226}
+	jmp	.LBB{227}
+.LBB{106}:
+	addq	$4, %r14
+	movzbl	(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+.LBB{107}:
+	leaq	.LJTI{228}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{229}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{230}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{231}:
+	testq	%rcx, %rcx
+	jne	.LBB{232}
+	jmp	.LBB{233}
+.LBB{135}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{139}
+	jmp	.LBB{24}
+.LBB{234}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{235}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	1(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$4, %r14
+	jmp	.LBB{237}
+.LBB{138}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{139}:
+	movq	%rax, (%rsp,%rdi,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{238}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{239}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{240}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{241}:
+	testq	%rcx, %rcx
+	jne	.LBB{242}
+	jmp	.LBB{243}
+.LBB{244}:
+	movzbl	2(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{120}
+.LBB{245}:
+	movzbl	1(%rbp,%r14), %edi
+	movzbl	3(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{246}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{246}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$4, %r14
+	jmp	.LBB{247}
+.LBB{248}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{42}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{249}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{250}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	2(%rbp,%r14), %eax
+	movzbl	3(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{251}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{252}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{253}
+	xorl	%edx, %edx
+	jmp	.LBB{254}
+.LBB{255}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{256}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{257}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+.LBB{258}:
+	addq	$4, %r14
+	jmp	.LBB{259}
+.LBB{260}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+.LBB{261}:
+	addq	$4, %r14
+	jmp	.LBB{262}
+.LBB{263}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+.LBB{264}:
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{265}
+.LBB{266}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	1(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$3, %r14
+	jmp	.LBB{4}
+.LBB{267}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+.LBB{268}:
+	addq	$4, %r14
+	jmp	.LBB{269}
+.LBB{270}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{271}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{272}
+	cmpq	$-1, %rcx
+	je	.LBB{273}
+.LBB{272}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{274}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{275}
+	jmp	.LBB{24}
+.LBB{276}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+.LBB{277}:
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{265}
+.LBB{278}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+.LBB{279}:
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{265}:
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$3, %r14
+	jmp	.LBB{4}
+.LBB{214}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$16, %dil
+	jae	.LBB{24}
+.LBB{156}:
+	movq	%rdx, (%rsp,%rdi,8)
+	movzbl	4(%rbp,%r14), %eax
+	cmpq	$31, %rax
+	ja	.LBB{5}
+	leaq	.LJTI{280}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rdx
+	addq	%rcx, %rdx
+	jmpq	*%rdx
+.LBB{281}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	(%rsp,%rdi,8), %rcx
+	addl	$-21, %eax
+	leaq	.LJTI{282}(%rip), %rdx
+	movslq	(%rdx,%rax,4), %rax
+	addq	%rdx, %rax
+	jmpq	*%rax
+.LBB{283}:
+	testq	%rcx, %rcx
+	jne	.LBB{284}
+	jmp	.LBB{285}
+.LBB{286}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{287}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{42}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{288}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{289}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{290}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{291}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{292}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{293}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+	movq	144(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{294}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{295}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rcx
+	movq	%rdx, %r8
+	addq	%rcx, %r8
+	setb	%dil
+	cmpq	%rsi, %r8
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{296}
+	cmpq	%r8, %rcx
+	jae	.LBB{296}
+	movq	160(%rsp), %r8
+	movq	%rdx, %r9
+	movq	%rcx, %r10
+	jmp	.LBB{297}
+.LBB{298}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{296}
+.LBB{297}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{298}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{298}
+	jmp	.LBB{5}
+.LBB{299}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{300}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{301}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{302}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{303}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{304}
+.Ltmp{305}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{306}:
+	jmp	.LBB{304}
+.LBB{307}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{308}
+	jmp	.LBB{119}
+.LBB{309}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{310}
+.Ltmp{311}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{312}:
+	jmp	.LBB{310}
+.LBB{313}:
+	movzbl	1(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	2(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{314}
+	cmpq	%r8, %rax
+	jae	.LBB{314}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{315}
+.LBB{316}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{314}
+.LBB{315}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{316}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{316}
+	jmp	.LBB{5}
+.LBB{317}:
+	movzwl	1(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{318}:
+	testq	%rcx, %rcx
+	js	.LBB{204}
+.LBB{203}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{319}:
+	testq	%rcx, %rcx
+	js	.LBB{203}
+.LBB{204}:
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{320}:
+	testq	%rcx, %rcx
+	jne	.LBB{204}
+	jmp	.LBB{203}
+.LBB{321}:
+	movzbl	5(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{38}
+	movzbl	4(%rbp,%r14), %edi
+	movzbl	6(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{322}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{322}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$7, %r14
+	jmp	.LBB{247}
+.LBB{323}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{42}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{324}:
+	movzbl	4(%rbp,%r14), %eax
+	movzbl	5(%rbp,%r14), %ecx
+	movzbl	6(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{325}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	4(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$7, %r14
+	jmp	.LBB{259}
+.LBB{326}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{327}
+.LBB{328}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	4(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$7, %r14
+	jmp	.LBB{262}
+.LBB{329}:
+	movzbl	4(%rbp,%r14), %eax
+	movzbl	5(%rbp,%r14), %ecx
+	movzbl	6(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{330}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	4(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$7, %r14
+	jmp	.LBB{269}
+.LBB{331}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$7, %r14
+	jmp	.LBB{237}
+.LBB{332}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{327}
+.LBB{333}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{327}:
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{334}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{335}
+	jmp	.LBB{57}
+.LBB{336}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{337}
+	jmp	.LBB{56}
+.LBB{338}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{339}
+	jmp	.LBB{57}
+.LBB{340}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{341}
+	jmp	.LBB{57}
+.LBB{342}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{343}
+	jmp	.LBB{56}
+.LBB{344}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{345}
+	jmp	.LBB{56}
+.LBB{346}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	6(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{347}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	3(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$6, %r14
+	jmp	.LBB{237}
+.LBB{348}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{349}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+	jmp	.LBB{237}
+.LBB{314}:
+	addq	$4, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{350}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{350}
+	jmp	.LBB{4}
+.LBB{351}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{352}
+	jmp	.LBB{23}
+.LBB{353}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{335}
+	jmp	.LBB{25}
+.LBB{354}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{337}
+	jmp	.LBB{23}
+.LBB{355}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{339}
+	jmp	.LBB{25}
+.LBB{356}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{341}
+	jmp	.LBB{25}
+.LBB{357}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{343}
+	jmp	.LBB{23}
+.LBB{358}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{345}
+	jmp	.LBB{23}
+.LBB{359}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	jmp	.LBB{360}
+.LBB{361}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{362}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{363}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{364}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{365}
+	jmp	.LBB{39}
+.LBB{366}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	4(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{367}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{368}
+	cmpq	$-1, %rcx
+	je	.LBB{369}
+.LBB{368}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{370}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{371}
+	jmp	.LBB{24}
+.LBB{372}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{373}
+	jmp	.LBB{57}
+.LBB{374}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{375}
+	jmp	.LBB{56}
+.LBB{376}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jb	.LBB{377}
+	jmp	.LBB{56}
+.LBB{253}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{378}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{254}
+.LBB{379}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{380}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{271}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{275}
+	jmp	.LBB{24}
+.LBB{381}:
+	movzbl	10(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{382}
+	movzbl	9(%rbp,%r14), %edi
+	movzbl	11(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{383}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{383}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$12, %r14
+	jmp	.LBB{247}
+.LBB{384}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{385}:
+	movzbl	9(%rbp,%r14), %eax
+	movzbl	10(%rbp,%r14), %ecx
+	movzbl	11(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$16, %r14
+	jmp	.LBB{4}
+.LBB{386}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	10(%rbp,%r14), %eax
+	movzbl	11(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{387}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{388}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	9(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{390}
+.LBB{391}:
+	movzbl	10(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	9(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{392}
+	xorl	%edx, %edx
+	jmp	.LBB{393}
+.LBB{394}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{395}:
+	movzbl	10(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	9(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{396}:
+	movzbl	9(%rbp,%r14), %eax
+	movzbl	10(%rbp,%r14), %ecx
+	movzbl	11(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$16, %r14
+	jmp	.LBB{4}
+.LBB{397}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	9(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$12, %r14
+.LBB{237}:
+	movq	144(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{398}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	9(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{390}
+.LBB{399}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	9(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{390}:
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{400}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{308}
+	jmp	.LBB{119}
+.LBB{401}:
+	movzbl	4(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{402}
+	jmp	.LBB{120}
+.LBB{403}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{42}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{404}:
+	movzbl	3(%rbp,%r14), %eax
+	movzbl	4(%rbp,%r14), %ecx
+	movzbl	5(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{405}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{406}
+	jmp	.LBB{119}
+.LBB{407}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{408}
+	jmp	.LBB{120}
+.LBB{409}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{410}
+	jmp	.LBB{119}
+.LBB{411}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{412}
+	jmp	.LBB{120}
+.LBB{413}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	3(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{414}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{415}
+	jmp	.LBB{120}
+.LBB{416}:
+	movzbl	3(%rbp,%r14), %eax
+	movzbl	4(%rbp,%r14), %ecx
+	movzbl	5(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{417}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{418}
+	jmp	.LBB{119}
+.LBB{419}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{420}
+	jmp	.LBB{119}
+.LBB{421}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{42}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{422}
+.LBB{423}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	6(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{424}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{425}
+	xorl	%edx, %edx
+	jmp	.LBB{426}
+.LBB{427}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{428}
+	jmp	.LBB{38}
+.LBB{429}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{120}
+.LBB{373}:
+	movzbl	5(%rbp,%r14), %edi
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{430}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{430}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+	movq	144(%rsp), %rcx
+	movq	(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{431}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{432}
+	jmp	.LBB{57}
+.LBB{433}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+.LBB{432}:
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{434}
+	xorl	%edx, %edx
+	jmp	.LBB{435}
+.LBB{436}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+.LBB{375}:
+	addq	$8, %r14
+	movq	(%rsp,%rdi,8), %rdx
+	cmpq	(%rsp,%rcx,8), %rdx
+	setl	%cl
+	setg	%dl
+	subb	%cl, %dl
+	movsbq	%dl, %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	jmp	.LBB{4}
+.LBB{437}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{339}
+	jmp	.LBB{120}
+.LBB{438}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{335}
+	jmp	.LBB{120}
+.LBB{439}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{337}
+	jmp	.LBB{119}
+.LBB{440}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+.LBB{377}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{441}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{341}
+	jmp	.LBB{120}
+.LBB{442}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{443}
+	jmp	.LBB{120}
+.LBB{444}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{343}
+	jmp	.LBB{119}
+.LBB{445}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{345}
+	jmp	.LBB{119}
+.LBB{434}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{446}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{435}
+.LBB{447}:
+	movzbl	4(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{25}
+.LBB{402}:
+	movzbl	3(%rbp,%r14), %edi
+	movzbl	5(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{448}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{448}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$6, %r14
+	jmp	.LBB{247}
+.LBB{449}:
+	movzbl	3(%rbp,%r14), %eax
+	movzbl	4(%rbp,%r14), %ecx
+	movzbl	5(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{450}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+.LBB{406}:
+	addq	$6, %r14
+	jmp	.LBB{451}
+.LBB{452}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	4(%rbp,%r14), %eax
+	movzbl	5(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{453}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{454}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{408}:
+	addq	$6, %r14
+	jmp	.LBB{259}
+.LBB{455}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+.LBB{410}:
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{456}
+.LBB{457}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{412}:
+	addq	$6, %r14
+	jmp	.LBB{262}
+.LBB{458}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{459}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	3(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{460}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{415}:
+	addq	$6, %r14
+	jmp	.LBB{269}
+.LBB{461}:
+	movzbl	3(%rbp,%r14), %eax
+	movzbl	4(%rbp,%r14), %ecx
+	movzbl	5(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{462}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+.LBB{418}:
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{456}
+.LBB{463}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+.LBB{420}:
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{456}:
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{464}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{465}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	6(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{466}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{467}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{468}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{469}
+	xorl	%edx, %edx
+	jmp	.LBB{470}
+.LBB{471}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{472}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{473}
+	jmp	.LBB{25}
+.LBB{296}:
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{215}
+	addq	$4, %r14
+	movq	144(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	jmp	.LBB{4}
+.LBB{300}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{302}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{274}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{275}:
+	addq	$4, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{474}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{475}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	6(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{476}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{477}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{478}
+	xorl	%edx, %edx
+	jmp	.LBB{479}
+.LBB{480}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{481}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{367}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{371}
+	jmp	.LBB{24}
+.LBB{378}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{254}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$4, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{482}:
+	movzbl	9(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	10(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{483}
+	cmpq	%r9, %r8
+	jae	.LBB{483}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{484}
+.LBB{485}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{483}
+.LBB{484}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{485}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{485}
+	jmp	.LBB{5}
+.LBB{486}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{487}
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{488}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{489}
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{490}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	9(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{491}
+.Ltmp{492}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{493}:
+	jmp	.LBB{491}
+.LBB{494}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	9(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{495}
+	jmp	.LBB{389}
+.LBB{496}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{497}
+	jmp	.LBB{382}
+.LBB{498}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{499}
+	jmp	.LBB{382}
+.LBB{500}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{501}
+.Ltmp{502}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{503}:
+	jmp	.LBB{501}
+.LBB{504}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{505}
+	jmp	.LBB{382}
+.LBB{506}:
+	movzbl	9(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	10(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{507}
+	cmpq	%r8, %rax
+	jae	.LBB{507}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{508}
+.LBB{509}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{507}
+.LBB{508}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{509}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{509}
+	jmp	.LBB{5}
+.LBB{510}:
+	movzbl	10(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	9(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{511}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{512}
+	cmpq	$-1, %rcx
+	jne	.LBB{512}
+	jmp	.LBB{513}
+.LBB{514}:
+	movzwl	9(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{515}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{516}
+	cmpq	%r9, %r8
+	jae	.LBB{516}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{517}
+.LBB{518}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{516}
+.LBB{517}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{518}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{518}
+	jmp	.LBB{5}
+.LBB{519}:
+	movzbl	9(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	10(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{520}
+	cmpq	%r8, %rax
+	jae	.LBB{520}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{521}
+.LBB{522}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{520}
+.LBB{521}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{522}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{522}
+	jmp	.LBB{5}
+.LBB{523}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	9(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+.LBB{495}:
+	addq	$12, %r14
+	jmp	.LBB{451}
+.LBB{524}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{525}
+	movzwl	2(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{526}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{527}
+	movzwl	2(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{528}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{304}
+.Ltmp{529}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{530}:
+	jmp	.LBB{304}
+.LBB{531}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{499}:
+	addq	$12, %r14
+	jmp	.LBB{259}
+.LBB{532}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{533}
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{534}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{310}
+.Ltmp{535}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{536}:
+	jmp	.LBB{310}
+.LBB{537}:
+	movzbl	1(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	2(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{538}
+	cmpq	%r8, %rax
+	jae	.LBB{538}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{539}
+.LBB{540}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{538}
+.LBB{539}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{540}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{540}
+	jmp	.LBB{5}
+.LBB{541}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	9(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{491}
+.Ltmp{542}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{543}:
+.LBB{491}:
+	addq	$11, %r14
+	jmp	.LBB{544}
+.LBB{545}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{497}:
+	addq	$12, %r14
+	jmp	.LBB{262}
+.LBB{546}:
+	movzwl	1(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{547}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{548}
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{549}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{501}
+.Ltmp{550}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{551}:
+.LBB{501}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{552}:
+	movzbl	10(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	11(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	9(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{505}:
+	addq	$12, %r14
+	jmp	.LBB{269}
+.LBB{553}:
+	movzbl	10(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	9(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{511}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{512}
+	cmpq	$-1, %rcx
+	je	.LBB{513}
+.LBB{512}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{554}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{555}
+	jmp	.LBB{24}
+.LBB{556}:
+	movzwl	9(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{557}:
+	movzbl	9(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	10(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	11(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{558}
+	cmpq	%r9, %r8
+	jae	.LBB{558}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{559}
+.LBB{560}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{558}
+.LBB{559}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{560}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{560}
+	jmp	.LBB{5}
+.LBB{561}:
+	testq	%rcx, %rcx
+	js	.LBB{20}
+.LBB{19}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{562}:
+	testq	%rcx, %rcx
+	js	.LBB{19}
+.LBB{20}:
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{563}:
+	testq	%rcx, %rcx
+	jne	.LBB{20}
+	jmp	.LBB{19}
+.LBB{564}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{565}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %edx
+	cmpb	$15, %dl
+	ja	.LBB{566}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rdi,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	152(%rsp), %rdi
+	ja	.LBB{5}
+	movq	(%rsp,%rdx,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{567}
+	cmpq	%rdi, %rax
+	jae	.LBB{567}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{568}
+.LBB{569}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{567}
+.LBB{568}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{569}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{569}
+	jmp	.LBB{5}
+.LBB{570}:
+	movzbl	2(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{245}
+	jmp	.LBB{382}
+.LBB{571}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{572}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{308}
+	jmp	.LBB{389}
+.LBB{573}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{574}
+	cmpq	%r8, %rax
+	jae	.LBB{574}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{575}
+.LBB{576}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{574}
+.LBB{575}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{576}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{576}
+	jmp	.LBB{5}
+.LBB{577}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{473}
+	jmp	.LBB{57}
+.LBB{578}:
+	movzbl	1(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	2(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{579}
+	cmpq	%r8, %rax
+	jae	.LBB{579}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{580}
+.LBB{581}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{579}
+.LBB{580}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{581}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{581}
+	jmp	.LBB{5}
+.LBB{582}:
+	movzbl	3(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{583}
+	cmpq	%r9, %r8
+	jae	.LBB{583}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{584}
+.LBB{585}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{583}
+.LBB{584}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{585}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{585}
+	jmp	.LBB{5}
+.LBB{586}:
+	testq	%rcx, %rcx
+	js	.LBB{163}
+.LBB{162}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{587}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{352}
+	jmp	.LBB{56}
+.LBB{588}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{589}:
+	testq	%rcx, %rcx
+	js	.LBB{162}
+.LBB{163}:
+	movzwl	2(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{590}:
+	testq	%rcx, %rcx
+	jne	.LBB{163}
+	jmp	.LBB{162}
+.LBB{591}:
+	movzbl	3(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{592}
+	cmpq	%r8, %rax
+	jae	.LBB{592}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{593}
+.LBB{594}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{592}
+.LBB{593}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{594}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{594}
+	jmp	.LBB{5}
+.LBB{595}:
+	testq	%rcx, %rcx
+	js	.LBB{188}
+.LBB{187}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{596}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{258}
+	jmp	.LBB{382}
+.LBB{597}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{264}
+	jmp	.LBB{389}
+.LBB{598}:
+	testq	%rcx, %rcx
+	js	.LBB{187}
+.LBB{188}:
+	movzwl	10(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{599}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{600}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{601}:
+	testq	%rcx, %rcx
+	jne	.LBB{188}
+	jmp	.LBB{187}
+.LBB{602}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	4(%rbp,%r14), %eax
+	movzbl	5(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{603}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{604}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{304}
+.Ltmp{605}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{606}:
+	jmp	.LBB{304}
+.LBB{607}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{261}
+	jmp	.LBB{382}
+.LBB{608}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{609}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{610}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	2(%rbp,%r14), %eax
+	movzbl	3(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{611}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{612}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{613}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{614}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{615}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{616}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{617}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{618}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{619}
+.Ltmp{620}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{621}:
+.LBB{619}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{622}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{623}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{624}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{626}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{627}:
+	jmp	.LBB{625}
+.LBB{628}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{629}
+	xorl	%edx, %edx
+	jmp	.LBB{630}
+.LBB{631}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{304}
+.Ltmp{632}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{633}:
+.LBB{304}:
+	addq	$3, %r14
+	jmp	.LBB{544}
+.LBB{634}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{635}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{268}
+	jmp	.LBB{382}
+.LBB{636}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	1(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$3, %r14
+	jmp	.LBB{4}
+.LBB{637}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	3(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{638}
+.Ltmp{639}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{640}:
+	jmp	.LBB{638}
+.LBB{641}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{642}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{643}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{644}
+	cmpq	$-1, %rcx
+	je	.LBB{645}
+.LBB{644}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{646}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{647}
+	jmp	.LBB{24}
+.LBB{648}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{649}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{650}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{651}
+.Ltmp{652}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{653}:
+.LBB{651}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{654}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{655}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{656}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{310}
+.Ltmp{657}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{658}:
+.LBB{310}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{659}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{389}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{660}
+	xorl	%edx, %edx
+	jmp	.LBB{661}
+.LBB{662}:
+	movzwl	1(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{663}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{389}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{382}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{664}
+	cmpq	%r9, %r8
+	jae	.LBB{664}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{665}
+.LBB{666}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{664}
+.LBB{665}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{666}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{666}
+	jmp	.LBB{5}
+.LBB{667}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{668}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{669}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{670}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jb	.LBB{671}
+	jmp	.LBB{56}
+.LBB{672}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{673}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{674}
+	cmpq	$-1, %rcx
+	je	.LBB{675}
+.LBB{674}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{676}
+.LBB{677}:
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{678}
+	jmp	.LBB{24}
+.LBB{679}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{680}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{681}
+.Ltmp{682}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{683}:
+	jmp	.LBB{681}
+.LBB{684}:
+	movzbl	3(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{685}
+	cmpq	%r8, %rax
+	jae	.LBB{685}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{686}
+.LBB{687}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{685}
+.LBB{686}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{687}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{687}
+	jmp	.LBB{5}
+.LBB{688}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	3(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{638}
+.Ltmp{689}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{690}:
+	jmp	.LBB{638}
+.LBB{691}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{692}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{693}
+	cmpq	$-1, %rcx
+	je	.LBB{694}
+.LBB{693}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{695}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{696}
+	jmp	.LBB{24}
+.LBB{697}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{698}:
+	movzwl	3(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{699}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{700}
+	cmpq	%r9, %r8
+	jae	.LBB{700}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{701}
+.LBB{702}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{700}
+.LBB{701}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{702}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{702}
+	jmp	.LBB{5}
+.LBB{703}:
+	movzwl	1(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{704}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{705}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	4(%rbp,%r14), %eax
+	movzbl	5(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{706}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{707}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{277}
+	jmp	.LBB{389}
+.LBB{708}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{709}
+	cmpq	%r9, %r8
+	jae	.LBB{709}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{710}
+.LBB{711}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{709}
+.LBB{710}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{711}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{711}
+	jmp	.LBB{5}
+.LBB{712}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{713}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{714}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{681}
+.Ltmp{715}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{716}:
+.LBB{681}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{717}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{718}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{719}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{720}
+	cmpq	$-1, %rcx
+	je	.LBB{721}
+.LBB{720}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{722}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{723}
+	jmp	.LBB{24}
+.LBB{724}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{725}
+	xorl	%edx, %edx
+	jmp	.LBB{726}
+.LBB{727}:
+	movzwl	3(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{728}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{729}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{279}
+	jmp	.LBB{389}
+.LBB{730}:
+	movzbl	3(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{731}
+	cmpq	%r9, %r8
+	jae	.LBB{731}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{732}
+.LBB{733}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{731}
+.LBB{732}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{733}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{733}
+	jmp	.LBB{5}
+.LBB{734}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{735}:
+	testq	%rcx, %rcx
+	js	.LBB{227}
+.LBB{226}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{736}:
+	testq	%rcx, %rcx
+	js	.LBB{226}
+.LBB{227}:
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{737}:
+	testq	%rcx, %rcx
+	jne	.LBB{227}
+	jmp	.LBB{226}
+.LBB{738}:
+	testq	%rcx, %rcx
+	js	.LBB{97}
+.LBB{96}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{739}:
+	testq	%rcx, %rcx
+	js	.LBB{233}
+.LBB{232}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{740}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{741}
+	cmpq	%r8, %rax
+	jae	.LBB{741}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{742}
+.LBB{743}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{741}
+.LBB{742}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{743}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{743}
+	jmp	.LBB{5}
+.LBB{744}:
+	testq	%rcx, %rcx
+	js	.LBB{104}
+.LBB{103}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{745}:
+	testq	%rcx, %rcx
+	js	.LBB{96}
+.LBB{97}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{746}:
+	testq	%rcx, %rcx
+	js	.LBB{232}
+.LBB{233}:
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{747}:
+	testq	%rcx, %rcx
+	jne	.LBB{97}
+	jmp	.LBB{96}
+.LBB{748}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{365}:
+	addq	$7, %r14
+	jmp	.LBB{451}
+.LBB{749}:
+	testq	%rcx, %rcx
+	jne	.LBB{233}
+	jmp	.LBB{232}
+.LBB{750}:
+	testq	%rcx, %rcx
+	js	.LBB{103}
+.LBB{104}:
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{751}:
+	testq	%rcx, %rcx
+	jne	.LBB{104}
+	jmp	.LBB{103}
+.LBB{752}:
+	testq	%rcx, %rcx
+	js	.LBB{174}
+.LBB{173}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{753}:
+	testq	%rcx, %rcx
+	js	.LBB{173}
+.LBB{174}:
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{754}:
+	testq	%rcx, %rcx
+	jne	.LBB{174}
+	jmp	.LBB{173}
+.LBB{755}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{756}
+	cmpq	%r9, %r8
+	jae	.LBB{756}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{757}
+.LBB{758}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{756}
+.LBB{757}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{758}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{758}
+	jmp	.LBB{5}
+.LBB{759}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{760}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{761}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	4(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{762}
+.Ltmp{763}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{764}:
+	jmp	.LBB{762}
+.LBB{765}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{766}
+	cmpq	%r9, %r8
+	jae	.LBB{766}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{767}
+.LBB{768}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{766}
+.LBB{767}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{768}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{768}
+	jmp	.LBB{5}
+.LBB{769}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{770}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{771}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{772}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{773}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{774}
+.Ltmp{775}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{776}:
+	jmp	.LBB{774}
+.LBB{777}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	4(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{778}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{779}
+	cmpq	$-1, %rcx
+	je	.LBB{780}
+.LBB{779}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{781}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{782}
+	jmp	.LBB{24}
+.LBB{783}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{784}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{785}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{786}
+	cmpq	%r9, %r8
+	jae	.LBB{786}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{787}
+.LBB{788}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{786}
+.LBB{787}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{788}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{788}
+	jmp	.LBB{5}
+.LBB{789}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{790}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{791}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	4(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{792}
+	xorl	%edx, %edx
+	jmp	.LBB{793}
+.LBB{794}:
+	movzwl	4(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{795}:
+	movzbl	4(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{796}
+	cmpq	%r9, %r8
+	jae	.LBB{796}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{797}
+.LBB{798}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{796}
+.LBB{797}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{798}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{798}
+	jmp	.LBB{5}
+.LBB{799}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{800}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+.LBB{428}:
+	movzbl	4(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{801}
+	xorl	%edx, %edx
+	jmp	.LBB{802}
+.LBB{801}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{803}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{802}
+.LBB{804}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	4(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{762}
+.Ltmp{805}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{806}:
+	jmp	.LBB{762}
+.LBB{807}:
+	movzbl	5(%rbp,%r14), %r9d
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %r9
+	jae	.LBB{808}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%r9,8), %r8
+	movq	%r8, %r9
+	shrq	%r9
+	andq	%rcx, %r9
+	subq	%r9, %r8
+	movq	%r8, %rcx
+	andq	%rax, %rcx
+	shrq	$2, %r8
+	andq	%rax, %r8
+	addq	%rcx, %r8
+	movq	%r8, %rax
+	shrq	$4, %rax
+	addq	%r8, %rax
+	andq	%rdx, %rax
+	imulq	%rsi, %rax
+	shrq	$56, %rax
+.LBB{422}:
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{809}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{810}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{811}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{774}
+.Ltmp{812}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{813}:
+	jmp	.LBB{774}
+.LBB{814}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{815}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{816}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{817}
+	cmpq	%r8, %rax
+	jae	.LBB{817}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{818}
+.LBB{819}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{817}
+.LBB{818}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{819}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{819}
+	jmp	.LBB{5}
+.LBB{820}:
+	movzwl	4(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{821}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	4(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{762}
+.Ltmp{822}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{823}:
+	jmp	.LBB{762}
+.LBB{824}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{825}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{826}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{827}
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{828}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{774}
+.Ltmp{829}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{830}:
+	jmp	.LBB{774}
+.LBB{831}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{832}
+	cmpq	%r8, %rax
+	jae	.LBB{832}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{833}
+.LBB{834}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{832}
+.LBB{833}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{834}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{834}
+	jmp	.LBB{5}
+.LBB{835}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	4(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{762}
+.Ltmp{836}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{837}:
+.LBB{762}:
+	addq	$6, %r14
+	jmp	.LBB{544}
+.LBB{838}:
+	movzwl	4(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{839}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{774}
+.Ltmp{840}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{841}:
+.LBB{774}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$5, %r14
+	jmp	.LBB{4}
+.LBB{842}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{843}
+	cmpq	%r8, %rax
+	jae	.LBB{843}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{844}
+.LBB{845}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{843}
+.LBB{844}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{845}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{845}
+	jmp	.LBB{5}
+.LBB{846}:
+	movzwl	4(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{847}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{848}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{849}
+	cmpq	%r8, %rax
+	jae	.LBB{849}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{850}
+.LBB{851}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{849}
+.LBB{850}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{851}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{851}
+	jmp	.LBB{5}
+.LBB{852}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{38}
+.LBB{473}:
+	movzbl	5(%rbp,%r14), %edi
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{853}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{853}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+.LBB{247}:
+	movq	144(%rsp), %rcx
+	movq	(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{854}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{352}:
+	addq	$8, %r14
+	jmp	.LBB{451}
+.LBB{855}:
+	testq	%rcx, %rcx
+	js	.LBB{70}
+.LBB{69}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{856}:
+	testq	%rcx, %rcx
+	js	.LBB{69}
+.LBB{70}:
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{857}:
+	testq	%rcx, %rcx
+	jne	.LBB{70}
+	jmp	.LBB{69}
+.LBB{858}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{859}
+	cmpq	%r9, %r8
+	jae	.LBB{859}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{860}
+.LBB{861}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{859}
+.LBB{860}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{861}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{861}
+	jmp	.LBB{5}
+.LBB{862}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+.LBB{335}:
+	addq	$8, %r14
+.LBB{259}:
+	movq	(%rsp,%rdi,8), %rdx
+	subq	(%rsp,%rax,8), %rdx
+	movq	%rdx, (%rsp,%rcx,8)
+	jmp	.LBB{4}
+.LBB{863}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{337}:
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{864}
+.LBB{865}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{866}
+	cmpq	%r8, %rax
+	jae	.LBB{866}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{867}
+.LBB{868}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{866}
+.LBB{867}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{868}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{868}
+	jmp	.LBB{5}
+.LBB{869}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{870}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{871}:
+	testq	%rax, %rax
+	js	.LBB{133}
+.LBB{132}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{872}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{873}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{874}:
+	jmp	.LBB{625}
+.LBB{875}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+.LBB{339}:
+	addq	$8, %r14
+.LBB{262}:
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rdi,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	jmp	.LBB{4}
+.LBB{876}:
+	testq	%rax, %rax
+	js	.LBB{132}
+.LBB{133}:
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{877}:
+	testq	%rax, %rax
+	jne	.LBB{133}
+	jmp	.LBB{132}
+.LBB{878}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	6(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{879}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{880}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{881}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{882}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{883}
+.Ltmp{884}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{885}:
+.LBB{883}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{886}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{887}
+	cmpq	%r9, %r8
+	jae	.LBB{887}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{888}
+.LBB{889}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{887}
+.LBB{888}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{889}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{889}
+	jmp	.LBB{5}
+.LBB{890}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{891}
+	cmpq	%r9, %r8
+	jae	.LBB{891}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{892}
+.LBB{893}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{891}
+.LBB{892}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{893}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{893}
+	jmp	.LBB{5}
+.LBB{894}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+.LBB{341}:
+	addq	$8, %r14
+.LBB{269}:
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rdi,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	jmp	.LBB{4}
+.LBB{895}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+.LBB{671}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+.LBB{360}:
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{896}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{897}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{898}
+	cmpq	$-1, %rcx
+	je	.LBB{899}
+.LBB{898}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{900}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{901}
+	jmp	.LBB{24}
+.LBB{902}:
+	testq	%rcx, %rcx
+	js	.LBB{181}
+.LBB{180}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{903}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{119}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{904}
+	cmpq	%r9, %r8
+	jae	.LBB{904}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{905}
+.LBB{906}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{904}
+.LBB{905}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{906}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{906}
+	jmp	.LBB{5}
+.LBB{907}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{908}
+	xorl	%edx, %edx
+	jmp	.LBB{909}
+.LBB{910}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{911}:
+	testq	%rcx, %rcx
+	js	.LBB{180}
+.LBB{181}:
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{912}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{913}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{914}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{915}
+	cmpq	%r9, %r8
+	jae	.LBB{915}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{916}
+.LBB{917}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{915}
+.LBB{916}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{917}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{917}
+	jmp	.LBB{5}
+.LBB{918}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{919}:
+	testq	%rcx, %rcx
+	jne	.LBB{181}
+	jmp	.LBB{180}
+.LBB{920}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{921}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{922}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{923}
+.Ltmp{924}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{925}:
+	jmp	.LBB{923}
+.LBB{926}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{927}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{928}:
+	testq	%rcx, %rcx
+	js	.LBB{195}
+.LBB{194}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{929}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{343}:
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{864}
+.LBB{930}:
+	testq	%rcx, %rcx
+	js	.LBB{194}
+.LBB{195}:
+	movzwl	5(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{931}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{923}
+.Ltmp{932}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{933}:
+	jmp	.LBB{923}
+.LBB{934}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{935}:
+	testq	%rcx, %rcx
+	jne	.LBB{195}
+	jmp	.LBB{194}
+.LBB{936}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{937}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{938}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{939}
+.Ltmp{940}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{941}:
+	jmp	.LBB{939}
+.LBB{942}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{943}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{944}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{939}
+.Ltmp{945}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{946}:
+	jmp	.LBB{939}
+.LBB{947}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{948}
+	cmpq	%r8, %rax
+	jae	.LBB{948}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{949}
+.LBB{950}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{948}
+.LBB{949}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{950}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{950}
+	jmp	.LBB{5}
+.LBB{951}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{952}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{953}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{954}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{955}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{956}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jo	.LBB{957}
+	jmp	.LBB{958}
+.LBB{959}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{960}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{961}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{962}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	5(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{345}:
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{864}:
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{963}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{964}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{965}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{673}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{966}
+	cmpq	$-1, %rcx
+	je	.LBB{675}
+.LBB{966}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	jne	.LBB{677}
+.LBB{676}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	jbe	.LBB{678}
+	jmp	.LBB{24}
+.LBB{967}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{923}
+.Ltmp{968}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{969}:
+	jmp	.LBB{923}
+.LBB{970}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{971}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{972}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{923}
+.Ltmp{973}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{974}:
+	jmp	.LBB{923}
+.LBB{975}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{976}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{977}
+	cmpq	%r9, %r8
+	jae	.LBB{977}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{978}
+.LBB{979}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{977}
+.LBB{978}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{979}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{979}
+	jmp	.LBB{5}
+.LBB{980}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{923}
+.Ltmp{981}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{982}:
+.LBB{923}:
+	addq	$7, %r14
+	movq	184(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 192(%rsp)
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{983}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{939}
+.Ltmp{984}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{985}:
+	jmp	.LBB{939}
+.LBB{986}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{939}
+.Ltmp{987}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{988}:
+	jmp	.LBB{939}
+.LBB{989}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{990}
+	cmpq	%r8, %rax
+	jae	.LBB{990}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{991}
+.LBB{992}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{990}
+.LBB{991}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{992}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{992}
+	jmp	.LBB{5}
+.LBB{993}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{56}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{57}
+.LBB{443}:
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{956}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{958}
+.LBB{957}:
+	cmpq	$-1, %rcx
+	je	.LBB{994}
+.LBB{958}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{995}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{996}
+	jmp	.LBB{24}
+.LBB{956}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{996}
+	jmp	.LBB{24}
+.LBB{997}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{998}
+	cmpq	%r8, %rax
+	jae	.LBB{998}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{999}
+.LBB{1000}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{998}
+.LBB{999}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1000}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1000}
+	jmp	.LBB{5}
+.LBB{1001}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1002}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{939}
+.Ltmp{1003}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1004}:
+.LBB{939}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1005}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1006}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{119}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{120}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1007}
+	cmpq	%r8, %rax
+	jae	.LBB{1007}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1008}
+.LBB{1009}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1007}
+.LBB{1008}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1009}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1009}
+	jmp	.LBB{5}
+.LBB{1010}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1011}:
+	testq	%rcx, %rcx
+	js	.LBB{46}
+.LBB{45}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1012}:
+	testq	%rcx, %rcx
+	js	.LBB{45}
+.LBB{46}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1013}:
+	testq	%rcx, %rcx
+	jne	.LBB{46}
+	jmp	.LBB{45}
+.LBB{1014}:
+	testq	%rcx, %rcx
+	js	.LBB{63}
+.LBB{62}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1015}:
+	testq	%rcx, %rcx
+	js	.LBB{90}
+.LBB{89}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1016}:
+	testq	%rcx, %rcx
+	js	.LBB{62}
+.LBB{63}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1017}:
+	testq	%rcx, %rcx
+	jne	.LBB{63}
+	jmp	.LBB{62}
+.LBB{1018}:
+	testq	%rcx, %rcx
+	js	.LBB{89}
+.LBB{90}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1019}:
+	testq	%rcx, %rcx
+	jne	.LBB{90}
+	jmp	.LBB{89}
+.LBB{1020}:
+	testq	%rcx, %rcx
+	js	.LBB{126}
+.LBB{125}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1021}:
+	testq	%rcx, %rcx
+	js	.LBB{243}
+.LBB{242}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1022}:
+	testq	%rcx, %rcx
+	js	.LBB{125}
+.LBB{126}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1023}:
+	testq	%rcx, %rcx
+	js	.LBB{285}
+.LBB{284}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1024}:
+	testq	%rcx, %rcx
+	js	.LBB{242}
+.LBB{243}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1025}:
+	testq	%rcx, %rcx
+	jne	.LBB{126}
+	jmp	.LBB{125}
+.LBB{1026}:
+	testq	%rcx, %rcx
+	jne	.LBB{243}
+	jmp	.LBB{242}
+.LBB{1027}:
+	testq	%rcx, %rcx
+	js	.LBB{284}
+.LBB{285}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1028}:
+	testq	%rcx, %rcx
+	jne	.LBB{285}
+	jmp	.LBB{284}
+.LBB{392}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1029}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{393}
+.LBB{425}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1030}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{426}
+.LBB{1031}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1032}:
+	movzbl	1(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	2(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1033}
+	cmpq	%r8, %rax
+	jae	.LBB{1033}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1034}
+.LBB{1035}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1033}
+.LBB{1034}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1035}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1035}
+	jmp	.LBB{5}
+.LBB{1036}:
+	movzbl	2(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jb	.LBB{245}
+	jmp	.LBB{25}
+.LBB{1037}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+.LBB{308}:
+	addq	$4, %r14
+.LBB{451}:
+	movq	(%rsp,%rdi,8), %rdx
+	cmpq	(%rsp,%rcx,8), %rdx
+	setl	%cl
+	setg	%dl
+	subb	%cl, %dl
+	movsbq	%dl, %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	jmp	.LBB{4}
+.LBB{1038}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{1039}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{258}
+	jmp	.LBB{38}
+.LBB{1040}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{264}
+	jmp	.LBB{39}
+.LBB{1041}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1042}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1043}:
+	cmpq	$1024, %rsi
+	je	.LBB{5}
+	movzbl	1(%rbp,%r14), %edx
+	movzbl	2(%rbp,%r14), %esi
+	shll	$8, %esi
+	orq	%rdx, %rsi
+	addq	$3, %r14
+	movq	%r14, -8(%rcx,%rax,8)
+	movq	%rax, 192(%rsp)
+	movq	%rsi, %r14
+	jmp	.LBB{4}
+.LBB{1044}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{261}
+	jmp	.LBB{38}
+.LBB{1045}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movzbl	2(%rbp,%r14), %eax
+	movzbl	3(%rbp,%r14), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1046}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{1047}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1048}
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1049}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1050}
+.Ltmp{1051}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1052}:
+.LBB{1050}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{1053}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{268}
+	jmp	.LBB{38}
+.LBB{1054}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	1(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	addq	$3, %r14
+	jmp	.LBB{4}
+.LBB{1055}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1056}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1057}
+	cmpq	$-1, %rcx
+	je	.LBB{1058}
+.LBB{1057}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1059}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{1060}
+	jmp	.LBB{24}
+.LBB{1061}:
+	testq	%rsi, %rsi
+	je	.LBB{5}
+	leaq	-2(%rax), %rdx
+	movq	%rdx, 192(%rsp)
+	movq	-16(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{1062}:
+	movzbl	2(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{1063}
+	xorl	%edx, %edx
+	jmp	.LBB{1064}
+.LBB{1065}:
+	movzwl	1(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1066}:
+	movzbl	1(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	3(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1067}
+	cmpq	%r9, %r8
+	jae	.LBB{1067}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1068}
+.LBB{1069}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1067}
+.LBB{1068}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1069}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1069}
+	jmp	.LBB{5}
+.LBB{1070}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	incq	(%rsp,%rdi,8)
+	addq	$2, %r14
+	jmp	.LBB{4}
+.LBB{1071}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{277}
+	jmp	.LBB{39}
+.LBB{1072}:
+	movzbl	1(%rbp,%r14), %eax
+	movzbl	2(%rbp,%r14), %ecx
+	movzbl	3(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1073}:
+	movzbl	2(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r14), %eax
+	cmpb	$15, %al
+	jbe	.LBB{279}
+	jmp	.LBB{39}
+.LBB{1074}:
+	movzbl	1(%rbp,%r12), %eax
+	movzbl	2(%rbp,%r12), %ecx
+	movzbl	3(%rbp,%r12), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	jmp	.LBB{1075}
+.LBB{1076}:
+	movzbl	1(%rbp,%r12), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	2(%rbp,%r12), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	3(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1077}
+	cmpq	%r8, %rax
+	jae	.LBB{1077}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1078}
+.LBB{1079}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1077}
+.LBB{1078}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1079}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1079}
+	jmp	.LBB{5}
+.LBB{1080}:
+	movzbl	2(%rbp,%r12), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{38}
+	movzbl	1(%rbp,%r12), %edi
+	movzbl	3(%rbp,%r12), %eax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{1081}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1081}:
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$4, %r12
+	movq	144(%rsp), %rcx
+	movq	(%rsp,%rdi,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1082}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	addq	$4, %r12
+	movq	(%rsp,%rdi,8), %rdx
+	cmpq	(%rsp,%rcx,8), %rdx
+	setl	%cl
+	setg	%dl
+	subb	%cl, %dl
+	movsbq	%dl, %rcx
+	movq	%rcx, (%rsp,%rax,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1083}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{1084}
+.LBB{1085}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$4, %r12
+	movq	(%rsp,%rdi,8), %rdx
+	subq	(%rsp,%rax,8), %rdx
+	movq	%rdx, (%rsp,%rcx,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1086}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{1087}
+.LBB{1088}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1075}
+	movzwl	2(%rbp,%r12), %r14d
+	jmp	.LBB{4}
+.LBB{1089}:
+	cmpq	$1024, %rcx
+	je	.LBB{5}
+	movzwl	1(%rbp,%r12), %r14d
+	cmpq	176(%rsp), %rcx
+	jne	.LBB{1090}
+.Ltmp{1091}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1092}:
+.LBB{1090}:
+	addq	$3, %r12
+	movq	184(%rsp), %rax
+	movq	%r12, 8(%rax,%rbx,8)
+	addq	$2, %rbx
+	movq	%rbx, 192(%rsp)
+	jmp	.LBB{4}
+.LBB{1093}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$4, %r12
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rdi,8), %rax
+	jmp	.LBB{1094}
+.LBB{1095}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	movzbl	2(%rbp,%r12), %eax
+	movzbl	3(%rbp,%r12), %ecx
+	shll	$8, %ecx
+	orq	%rax, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{82}
+.LBB{1096}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{1084}
+.LBB{1097}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{82}
+	movzwl	2(%rbp,%r12), %r14d
+	jmp	.LBB{4}
+.LBB{1098}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r14
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1099}
+.Ltmp{1100}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1101}:
+.LBB{1099}:
+	movq	208(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	jmp	.LBB{1084}
+.LBB{1102}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	1(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	addq	$4, %r12
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rdi,8), %rax
+.LBB{1094}:
+	movq	%rax, (%rsp,%rcx,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1103}:
+	movzbl	2(%rbp,%r12), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	1(%rbp,%r12), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{1104}
+.LBB{1105}:
+	movzbl	2(%rbp,%r12), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	3(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r12), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1106}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1107}
+	cmpq	$-1, %rcx
+	je	.LBB{1108}
+.LBB{1107}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1109}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{1110}
+	jmp	.LBB{24}
+.LBB{1111}:
+	movq	%rbx, 192(%rsp)
+	movq	184(%rsp), %rax
+	movq	(%rax,%rbx,8), %r14
+	jmp	.LBB{4}
+.LBB{1112}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	3(%rbp,%r12), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	152(%rsp), %rax
+	jae	.LBB{5}
+	movzbl	1(%rbp,%r12), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$4, %r12
+	movq	144(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1113}:
+	movzbl	2(%rbp,%r12), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{39}
+	movzbl	3(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movzbl	1(%rbp,%r12), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{1114}
+	xorl	%edx, %edx
+	jmp	.LBB{1115}
+.LBB{1116}:
+	movzwl	1(%rbp,%r12), %r14d
+	jmp	.LBB{4}
+.LBB{1117}:
+	movzbl	1(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	2(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movzbl	3(%rbp,%r12), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1118}
+	cmpq	%r9, %r8
+	jae	.LBB{1118}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1119}
+.LBB{1120}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1118}
+.LBB{1119}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1120}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1120}
+	jmp	.LBB{5}
+.LBB{1121}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{42}
+	incq	(%rsp,%rdi,8)
+.LBB{1084}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1122}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$64, %ecx
+	rep		bsfq	(%rsp,%rdi,8), %rcx
+	jmp	.LBB{1087}
+.LBB{1123}:
+	movzbl	1(%rbp,%r12), %eax
+	movzbl	2(%rbp,%r12), %ecx
+	movzbl	3(%rbp,%r12), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+.LBB{1075}:
+	addq	$8, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1124}:
+	movzbl	2(%rbp,%r12), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	1(%rbp,%r12), %eax
+	cmpb	$15, %al
+	ja	.LBB{39}
+	movl	$127, %ecx
+	bsrq	(%rsp,%rdi,8), %rcx
+	xorq	$63, %rcx
+.LBB{1087}:
+	movq	%rcx, (%rsp,%rax,8)
+.LBB{1104}:
+	addq	$3, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1125}:
+	testq	%rdx, %rdx
+	je	.LBB{5}
+	leaq	-2(%rax), %rdx
+	movq	%rdx, 216(%rsp)
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	-16(%rcx,%rax,8), %rax
+	jmp	.LBB{1126}
+.LBB{1127}:
+	movzbl	3(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1128}
+	cmpq	%r9, %r8
+	jae	.LBB{1128}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1129}
+.LBB{1130}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1128}
+.LBB{1129}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1130}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1130}
+	jmp	.LBB{5}
+.LBB{1131}:
+	testq	%rax, %rax
+	js	.LBB{146}
+.LBB{145}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1132}:
+	testq	%rax, %rax
+	js	.LBB{145}
+.LBB{146}:
+	movzwl	2(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1133}:
+	testq	%rax, %rax
+	jne	.LBB{146}
+	jmp	.LBB{145}
+.LBB{1134}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1135}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1136}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1137}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1138}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	jbe	.LBB{1139}
+	jmp	.LBB{25}
+.LBB{1140}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	3(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{638}
+.Ltmp{1141}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1142}:
+	jmp	.LBB{638}
+.LBB{1143}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	%rdx, -8(%rcx,%rax,8)
+	movq	%rax, 216(%rsp)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1144}:
+	movzbl	3(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1145}
+	cmpq	%r8, %rax
+	jae	.LBB{1145}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1146}
+.LBB{1147}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1145}
+.LBB{1146}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1147}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1147}
+	jmp	.LBB{5}
+.LBB{1148}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1149}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1150}
+	cmpq	$-1, %rcx
+	jne	.LBB{1150}
+	jmp	.LBB{1151}
+.LBB{1152}:
+	movzwl	3(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1153}:
+	testq	%rcx, %rcx
+	js	.LBB{83}
+.LBB{82}:
+	addq	$4, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1154}:
+	testq	%rcx, %rcx
+	js	.LBB{82}
+.LBB{83}:
+	movzwl	2(%rbp,%r12), %r14d
+	jmp	.LBB{4}
+.LBB{1155}:
+	testq	%rcx, %rcx
+	jne	.LBB{83}
+	jmp	.LBB{82}
+.LBB{1156}:
+	movzbl	3(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	4(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1157}
+	cmpq	%r8, %rax
+	jae	.LBB{1157}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1158}
+.LBB{1159}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1157}
+.LBB{1158}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1159}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1159}
+	jmp	.LBB{5}
+.LBB{1160}:
+	movq	%rbx, 216(%rsp)
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$15, %rdi
+	ja	.LBB{16}
+	movq	208(%rsp), %rax
+	movq	(%rax,%rbx,8), %rax
+.LBB{1126}:
+	movq	%rax, (%rsp,%rdi,8)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1161}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1162}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1163}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	3(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{638}
+.Ltmp{1164}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1165}:
+.LBB{638}:
+	addq	$5, %r14
+	jmp	.LBB{544}
+.LBB{1166}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1167}
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1168}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$1024, %rax
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rax
+	jne	.LBB{1169}
+.Ltmp{1170}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1171}:
+.LBB{1169}:
+	movq	208(%rsp), %rax
+	movq	%r15, 8(%rax,%rbx,8)
+	addq	$2, %rbx
+	movq	%rbx, 216(%rsp)
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1172}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1149}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1150}
+	cmpq	$-1, %rcx
+	je	.LBB{1151}
+.LBB{1150}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1173}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{1174}
+	jmp	.LBB{24}
+.LBB{1175}:
+	movzbl	4(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+.LBB{1139}:
+	movzbl	3(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{1176}
+	xorl	%edx, %edx
+	jmp	.LBB{1177}
+.LBB{1178}:
+	movzwl	3(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1179}:
+	movzbl	3(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	4(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1180}
+	cmpq	%r9, %r8
+	jae	.LBB{1180}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1181}
+.LBB{1182}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1180}
+.LBB{1181}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1182}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1182}
+	jmp	.LBB{5}
+.LBB{1183}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1184}
+	cmpq	%r8, %rax
+	jae	.LBB{1184}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1185}
+.LBB{1186}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1184}
+.LBB{1185}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1186}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1186}
+	jmp	.LBB{5}
+.LBB{1187}:
+	testq	%rax, %rax
+	js	.LBB{54}
+.LBB{53}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1188}:
+	testq	%rax, %rax
+	js	.LBB{53}
+.LBB{54}:
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1189}:
+	testq	%rax, %rax
+	jne	.LBB{54}
+	jmp	.LBB{53}
+.LBB{1190}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1191}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1192}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{1193}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1194}:
+	jmp	.LBB{625}
+.LBB{1195}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1196}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1197}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1198}
+.Ltmp{1199}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1200}:
+	jmp	.LBB{1198}
+.LBB{1201}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	imulq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	movq	(%rsp,%rcx,8), %rcx
+	shrl	$4, %edx
+	subq	(%rsp,%rdx,8), %rcx
+	shrl	$4, %eax
+	movq	%rcx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1202}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1203}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1204}
+	cmpq	$-1, %rcx
+	jne	.LBB{1204}
+	jmp	.LBB{1205}
+.LBB{1206}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	6(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	7(%rbp,%r14), %r9d
+	cmpb	$15, %r9b
+	ja	.LBB{1207}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%r9,8), %rax
+	movq	%rax, %r8
+	addq	%rdx, %r8
+	jb	.LBB{5}
+	cmpq	%rsi, %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rcx,8), %rcx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1208}
+	cmpq	%r8, %rdx
+	jae	.LBB{1208}
+	movq	160(%rsp), %r8
+	movq	%rax, %r9
+	movq	%rdx, %r10
+	jmp	.LBB{1209}
+.LBB{1210}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{1208}
+.LBB{1209}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rsi, %r11
+	jae	.LBB{1210}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1210}
+	jmp	.LBB{5}
+.LBB{1211}:
+	movzbl	6(%rbp,%r14), %edx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdx
+	jae	.LBB{1212}
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	7(%rbp,%r14), %ecx
+	addq	(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	168(%rsp), %rdx
+	jae	.LBB{1213}
+	movl	$1, %r9d
+	shlq	%cl, %r9
+	cmpq	%rsi, %rcx
+	jae	.LBB{5}
+	movq	160(%rsp), %rsi
+	andq	16(%rsi,%rdx,8), %r9
+	je	.LBB{1214}
+	jmp	.LBB{5}
+.LBB{1215}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1216}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1217}
+	cmpq	%r9, %r8
+	jae	.LBB{1217}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1218}
+.LBB{1219}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1217}
+.LBB{1218}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1219}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1219}
+	jmp	.LBB{5}
+.LBB{1220}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1221}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1222}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{1223}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1224}:
+	jmp	.LBB{625}
+.LBB{1225}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1226}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1227}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1198}
+.Ltmp{1228}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1229}:
+.LBB{1198}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1230}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1203}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1204}
+	cmpq	$-1, %rcx
+	je	.LBB{1205}
+.LBB{1204}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1231}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{1232}
+	jmp	.LBB{24}
+.LBB{1233}:
+	movzbl	6(%rbp,%r14), %ecx
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rcx
+	jae	.LBB{25}
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rcx,8), %rax
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	movzbl	5(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	addq	$8, %r14
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, (%rsp,%rcx,8)
+	jmp	.LBB{4}
+.LBB{1234}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1235}:
+	movzbl	5(%rbp,%r14), %r9d
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %r9
+	jae	.LBB{1207}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%r9,8), %r8
+	movq	%rax, %r10
+	addq	%r8, %r10
+	setb	%r9b
+	cmpq	%rsi, %r10
+	seta	%r11b
+	orb	%r9b, %r11b
+	jne	.LBB{5}
+	movq	168(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{1236}
+	cmpq	%r10, %r8
+	jae	.LBB{1236}
+	movq	160(%rsp), %r10
+	movq	%r8, %r11
+	jmp	.LBB{1237}
+.LBB{1238}:
+	incq	%r11
+	decq	%rax
+	je	.LBB{1236}
+.LBB{1237}:
+	movq	%r11, %rbx
+	shrq	$6, %rbx
+	cmpq	%r9, %rbx
+	jae	.LBB{1238}
+	movq	16(%r10,%rbx,8), %rbx
+	btq	%r11, %rbx
+	jae	.LBB{1238}
+	jmp	.LBB{5}
+.LBB{1239}:
+	movzbl	5(%rbp,%r14), %eax
+	movzbl	6(%rbp,%r14), %ecx
+	movzbl	7(%rbp,%r14), %edx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movl	%ecx, %edi
+	andl	$15, %edi
+	movq	(%rsp,%rsi,8), %rsi
+	addq	(%rsp,%rdi,8), %rsi
+	movl	%eax, %edi
+	andl	$15, %edi
+	movq	%rsi, (%rsp,%rdi,8)
+	shrl	$4, %ecx
+	shrl	$4, %edx
+	movq	(%rsp,%rdx,8), %rdx
+	addq	(%rsp,%rcx,8), %rdx
+	shrl	$4, %eax
+	movq	%rdx, (%rsp,%rax,8)
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1240}:
+	testq	%rax, %rax
+	js	.LBB{117}
+.LBB{116}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1241}:
+	testq	%rax, %rax
+	js	.LBB{116}
+.LBB{117}:
+	movzwl	4(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1242}:
+	testq	%rax, %rax
+	jne	.LBB{117}
+	jmp	.LBB{116}
+.LBB{1243}:
+	testq	%rcx, %rcx
+	js	.LBB{36}
+.LBB{35}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1244}:
+	testq	%rcx, %rcx
+	js	.LBB{35}
+.LBB{36}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1245}:
+	testq	%rcx, %rcx
+	jne	.LBB{36}
+	jmp	.LBB{35}
+.LBB{1246}:
+	testq	%rcx, %rcx
+	js	.LBB{153}
+.LBB{152}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1247}:
+	testq	%rcx, %rcx
+	js	.LBB{152}
+.LBB{153}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1248}:
+	testq	%rcx, %rcx
+	jne	.LBB{153}
+	jmp	.LBB{152}
+.LBB{1176}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1249}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1177}
+.LBB{469}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1250}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{470}
+.LBB{995}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{996}:
+	addq	$8, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1251}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	152(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	168(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1252}
+	cmpq	%r9, %r8
+	jae	.LBB{1252}
+	movq	160(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1253}
+.LBB{1254}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1252}
+.LBB{1253}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1254}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1254}
+	jmp	.LBB{5}
+.LBB{1255}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1256}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1257}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1258}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1259}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{1260}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1261}:
+	jmp	.LBB{625}
+.LBB{507}:
+	addq	$12, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1262}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1262}
+	jmp	.LBB{4}
+.LBB{1263}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1264}
+.Ltmp{1265}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1266}:
+	jmp	.LBB{1264}
+.LBB{1267}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rax
+	movq	(%rsp,%rcx,8), %rcx
+	movq	%rcx, %r8
+	addq	%rax, %r8
+	jb	.LBB{5}
+	cmpq	152(%rsp), %r8
+	ja	.LBB{5}
+	movq	(%rsp,%rdi,8), %rdx
+	movq	168(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1268}
+	cmpq	%r8, %rax
+	jae	.LBB{1268}
+	movq	160(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1269}
+.LBB{1270}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1268}
+.LBB{1269}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1270}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1270}
+	jmp	.LBB{5}
+.LBB{1271}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1272}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1273}
+	cmpq	$-1, %rcx
+	jne	.LBB{1273}
+	jmp	.LBB{1274}
+.LBB{1275}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{520}:
+	addq	$12, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1276}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1276}
+	jmp	.LBB{4}
+.LBB{538}:
+	addq	$4, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1277}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1277}
+	jmp	.LBB{4}
+.LBB{1278}:
+	testq	%rcx, %rcx
+	js	.LBB{213}
+.LBB{212}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1279}:
+	testq	%rcx, %rcx
+	js	.LBB{212}
+.LBB{213}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1280}:
+	testq	%rcx, %rcx
+	jne	.LBB{213}
+	jmp	.LBB{212}
+.LBB{567}:
+	addq	$4, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1281}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1281}
+	jmp	.LBB{4}
+.LBB{574}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1282}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1282}
+	jmp	.LBB{4}
+.LBB{579}:
+	addq	$4, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1283}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1283}
+	jmp	.LBB{4}
+.LBB{592}:
+	addq	$6, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1284}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1284}
+	jmp	.LBB{4}
+.LBB{685}:
+	addq	$6, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1285}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1285}
+	jmp	.LBB{4}
+.LBB{741}:
+	addq	$7, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1286}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1286}
+	jmp	.LBB{4}
+.LBB{817}:
+	addq	$7, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1287}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1287}
+	jmp	.LBB{4}
+.LBB{832}:
+	addq	$7, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1288}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1288}
+	jmp	.LBB{4}
+.LBB{843}:
+	addq	$7, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1289}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1289}
+	jmp	.LBB{4}
+.LBB{849}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1290}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1290}
+	jmp	.LBB{4}
+.LBB{866}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1291}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1291}
+	jmp	.LBB{4}
+.LBB{948}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1292}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1292}
+	jmp	.LBB{4}
+.LBB{990}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1293}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1293}
+	jmp	.LBB{4}
+.LBB{998}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1294}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1294}
+	jmp	.LBB{4}
+.LBB{1007}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1295}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1295}
+	jmp	.LBB{4}
+.LBB{1033}:
+	addq	$4, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1296}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1296}
+	jmp	.LBB{4}
+.LBB{1077}:
+	addq	$4, %r12
+	movq	%r12, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1297}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1297}
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1145}:
+	addq	$6, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1298}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1298}
+	jmp	.LBB{4}
+.LBB{1157}:
+	addq	$6, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1299}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1299}
+	jmp	.LBB{4}
+.LBB{1184}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1300}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1300}
+	jmp	.LBB{4}
+.LBB{1208}:
+	addq	$8, %r14
+	testq	%rax, %rax
+	je	.LBB{4}
+	leaq	(%rdi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1301}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1301}
+	jmp	.LBB{4}
+.LBB{1268}:
+	addq	$8, %r14
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	144(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1302}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1302}
+	jmp	.LBB{4}
+.LBB{511}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{555}
+	jmp	.LBB{24}
+.LBB{673}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{678}:
+	addq	$8, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{446}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{435}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$8, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1149}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{1174}
+	jmp	.LBB{24}
+.LBB{1203}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{1232}
+	jmp	.LBB{24}
+.LBB{370}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{371}:
+	addq	$7, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{478}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1303}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{479}
+.LBB{803}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{802}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$7, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{629}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1304}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{630}
+.LBB{660}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1305}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{661}
+.LBB{725}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1306}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{726}
+.LBB{792}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1307}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{793}
+.LBB{908}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1308}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{909}
+.LBB{1213}:
+	cmpq	%rsi, %rcx
+	jae	.LBB{5}
+.LBB{1214}:
+	cmpb	$15, %al
+	ja	.LBB{23}
+	addq	$8, %r14
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, (%rdi,%rcx,8)
+	jmp	.LBB{4}
+.LBB{1063}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1309}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1064}
+.LBB{1114}:
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1310}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1115}
+.LBB{554}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{555}:
+	addq	$12, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1173}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{1174}:
+	addq	$6, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1231}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{1232}:
+	addq	$8, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{483}:
+	addq	$12, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1311}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1312}:
+	jmp	.LBB{4}
+.LBB{516}:
+	addq	$4, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1313}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1314}:
+	jmp	.LBB{4}
+.LBB{558}:
+	addq	$12, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1315}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1316}:
+	jmp	.LBB{4}
+.LBB{583}:
+	addq	$6, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1317}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1318}:
+	jmp	.LBB{4}
+.LBB{664}:
+	addq	$4, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1319}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1320}:
+	jmp	.LBB{4}
+.LBB{700}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1321}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1322}:
+	jmp	.LBB{4}
+.LBB{709}:
+	addq	$4, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1323}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1324}:
+	jmp	.LBB{4}
+.LBB{731}:
+	addq	$6, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1325}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1326}:
+	jmp	.LBB{4}
+.LBB{756}:
+	addq	$7, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1327}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1328}:
+	jmp	.LBB{4}
+.LBB{766}:
+	addq	$7, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1329}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1330}:
+	jmp	.LBB{4}
+.LBB{786}:
+	addq	$7, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1331}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1332}:
+	jmp	.LBB{4}
+.LBB{796}:
+	addq	$7, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1333}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1334}:
+	jmp	.LBB{4}
+.LBB{859}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1335}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1336}:
+	jmp	.LBB{4}
+.LBB{887}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1337}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1338}:
+	jmp	.LBB{4}
+.LBB{891}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1339}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1340}:
+	jmp	.LBB{4}
+.LBB{904}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1341}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1342}:
+	jmp	.LBB{4}
+.LBB{915}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1343}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1344}:
+	jmp	.LBB{4}
+.LBB{977}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1345}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1346}:
+	jmp	.LBB{4}
+.LBB{1347}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	movzbl	5(%rbp,%r14), %edi
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%rbx, %rcx
+	jae	.LBB{1348}
+	movq	160(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1348}:
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+	movq	(%rsp,%rdi,8), %rcx
+	movq	%rcx, (%r12,%rax,8)
+	jmp	.LBB{4}
+.LBB{1349}:
+	movzbl	5(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	6(%rbp,%r14), %eax
+	cmpb	$15, %al
+	ja	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	setb	%sil
+	cmpq	%r13, %rcx
+	seta	%r8b
+	orb	%sil, %r8b
+	jne	.LBB{5}
+	movq	(%rsp,%rdi,8), %r8
+	movq	%rax, %rsi
+	addq	%r8, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{5}
+	testq	%rbx, %rbx
+	je	.LBB{1350}
+	cmpq	%rsi, %r8
+	jae	.LBB{1350}
+	movq	160(%rsp), %rsi
+	movq	%r8, %rdi
+	jmp	.LBB{1351}
+.LBB{1352}:
+	incq	%rdi
+	decq	%rax
+	je	.LBB{1350}
+.LBB{1351}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{1352}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1352}
+	jmp	.LBB{5}
+.LBB{1353}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	decq	(%rsp,%rdi,8)
+	je	.LBB{1354}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1355}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{1356}
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1357}:
+	movq	192(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movzwl	5(%rbp,%r14), %r15d
+	cmpq	176(%rsp), %rbx
+	jne	.LBB{625}
+.Ltmp{1358}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1359}:
+.LBB{625}:
+	addq	$7, %r14
+.LBB{544}:
+	movq	184(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 192(%rsp)
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{1360}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{16}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{1264}
+.Ltmp{1361}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1362}:
+.LBB{1264}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1363}:
+	movzbl	5(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	6(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movq	(%rsp,%rax,8), %rdx
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	setb	%cl
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%cl, %r8b
+	jne	.LBB{5}
+	movq	(%rsp,%rdi,8), %rcx
+	testq	%rbx, %rbx
+	je	.LBB{1364}
+	cmpq	%rsi, %rdx
+	jae	.LBB{1364}
+	movq	160(%rsp), %rsi
+	movq	%rax, %rdi
+	movq	%rdx, %r8
+	jmp	.LBB{1365}
+.LBB{1366}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{1364}
+.LBB{1365}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{1366}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{1366}
+	jmp	.LBB{5}
+.LBB{1367}:
+	movzbl	6(%rbp,%r14), %edi
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rdi
+	jae	.LBB{24}
+	movzbl	7(%rbp,%r14), %eax
+	addq	(%rsp,%rdi,8), %rax
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	movzbl	5(%rbp,%r14), %edi
+	cmpb	$15, %dil
+	ja	.LBB{24}
+	addq	$8, %r14
+	movq	(%r12,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1368}:
+	movzbl	6(%rbp,%r14), %eax
+	leaq	.Lanon{236}(%rip), %r8
+	cmpq	$16, %rax
+	jae	.LBB{23}
+	movzbl	7(%rbp,%r14), %ecx
+	cmpb	$15, %cl
+	ja	.LBB{25}
+	movzbl	5(%rbp,%r14), %edi
+	movq	(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1272}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1273}
+	cmpq	$-1, %rcx
+	je	.LBB{1274}
+.LBB{1273}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1369}
+	cqto
+	idivq	%rcx
+	cmpb	$15, %dil
+	jbe	.LBB{1370}
+	jmp	.LBB{24}
+.LBB{1272}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{1370}
+	jmp	.LBB{24}
+.LBB{1371}:
+	movzwl	5(%rbp,%r14), %r14d
+	jmp	.LBB{4}
+.LBB{1372}:
+	testq	%rcx, %rcx
+	js	.LBB{221}
+.LBB{220}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1373}:
+	testq	%rcx, %rcx
+	js	.LBB{220}
+.LBB{221}:
+	movzwl	6(%r14,%rbp), %r14d
+	jmp	.LBB{4}
+.LBB{1374}:
+	testq	%rcx, %rcx
+	jne	.LBB{221}
+	jmp	.LBB{220}
+.LBB{1067}:
+	addq	$4, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1375}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1376}:
+	jmp	.LBB{4}
+.LBB{1118}:
+	addq	$4, %r12
+	movq	144(%rsp), %rdi
+.Ltmp{1377}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1378}:
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1128}:
+	addq	$6, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1379}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1380}:
+	jmp	.LBB{4}
+.LBB{1180}:
+	addq	$6, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1381}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1382}:
+	jmp	.LBB{4}
+.LBB{1217}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1383}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1384}:
+	jmp	.LBB{4}
+.LBB{1236}:
+.Ltmp{1385}:
+	addq	$8, %r14
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1386}:
+	jmp	.LBB{4}
+.LBB{1252}:
+	addq	$8, %r14
+	movq	144(%rsp), %rdi
+.Ltmp{1387}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1388}:
+	jmp	.LBB{4}
+.LBB{487}:
+	addq	$16, %r14
+	jmp	.LBB{4}
+.LBB{489}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{525}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{527}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{533}:
+	addq	$16, %r14
+	jmp	.LBB{4}
+.LBB{548}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{600}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{609}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{612}:
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{615}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{617}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{623}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{649}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{655}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{668}:
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{713}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{760}:
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{772}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{784}:
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{643}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{647}
+	jmp	.LBB{24}
+.LBB{790}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{692}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{696}
+	jmp	.LBB{24}
+.LBB{810}:
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{815}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{719}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{723}
+	jmp	.LBB{24}
+.LBB{825}:
+	addq	$11, %r14
+	jmp	.LBB{4}
+.LBB{827}:
+	addq	$7, %r14
+	jmp	.LBB{4}
+.LBB{870}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{881}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{913}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{921}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{927}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{778}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{782}
+	jmp	.LBB{24}
+.LBB{937}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{943}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{952}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{954}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{960}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{964}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{971}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{897}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{901}
+	jmp	.LBB{24}
+.LBB{1029}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{393}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$12, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1030}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{426}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$8, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1042}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1048}:
+	addq	$4, %r14
+	jmp	.LBB{4}
+.LBB{1135}:
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{1137}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1056}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{1060}
+	jmp	.LBB{24}
+.LBB{1162}:
+	addq	$10, %r14
+	jmp	.LBB{4}
+.LBB{1167}:
+	addq	$6, %r14
+	jmp	.LBB{4}
+.LBB{1106}:
+	xorl	%eax, %eax
+	cmpb	$15, %dil
+	jbe	.LBB{1110}
+	jmp	.LBB{24}
+.LBB{1191}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1196}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1221}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1226}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1249}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1177}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$6, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1250}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{470}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$8, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1256}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1258}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1369}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{1370}:
+	addq	$8, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1303}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{479}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$8, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{646}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{647}:
+	addq	$4, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{695}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{696}:
+	addq	$6, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{722}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{723}:
+	addq	$6, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{781}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{782}:
+	addq	$7, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{900}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{901}:
+	addq	$8, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1059}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{1060}:
+	addq	$4, %r14
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1109}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$15, %dil
+	ja	.LBB{24}
+.LBB{1110}:
+	addq	$4, %r12
+	movq	%rax, (%rsp,%rdi,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1364}:
+	addq	$8, %r14
+	testq	%rax, %rax
+	je	.LBB{4}
+	leaq	(%r12,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1389}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1389}
+	jmp	.LBB{4}
+.LBB{1304}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{630}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$6, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1305}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{661}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$4, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1306}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{726}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$6, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1307}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{793}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$7, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1308}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{909}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$8, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1309}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1064}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$4, %r14
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1310}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1115}:
+	cmpb	$16, %dil
+	jae	.LBB{24}
+	addq	$4, %r12
+	movq	%rdx, (%rsp,%rdi,8)
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{1354}:
+	addq	$12, %r14
+	jmp	.LBB{4}
+.LBB{1356}:
+	addq	$8, %r14
+	jmp	.LBB{4}
+.LBB{1350}:
+.Ltmp{1390}:
+	addq	$8, %r14
+	movq	%r12, %rdi
+	movq	%r13, %rsi
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1391}:
+	jmp	.LBB{4}
+.LBB{137}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{139}
+	jmp	.LBB{24}
+.LBB{273}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{275}
+	jmp	.LBB{24}
+.LBB{994}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{996}
+	jmp	.LBB{24}
+.LBB{369}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{371}
+	jmp	.LBB{24}
+.LBB{513}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{555}
+	jmp	.LBB{24}
+.LBB{675}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{678}
+	jmp	.LBB{24}
+.LBB{1151}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{1174}
+	jmp	.LBB{24}
+.LBB{1205}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{1232}
+	jmp	.LBB{24}
+.LBB{1274}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{1370}
+	jmp	.LBB{24}
+.LBB{645}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{647}
+	jmp	.LBB{24}
+.LBB{694}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{696}
+	jmp	.LBB{24}
+.LBB{721}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{723}
+	jmp	.LBB{24}
+.LBB{780}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{782}
+	jmp	.LBB{24}
+.LBB{899}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{901}
+	jmp	.LBB{24}
+.LBB{1058}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{1060}
+	jmp	.LBB{24}
+.LBB{1108}:
+	movabsq	$-9223372036854775808, %rax
+	cmpb	$15, %dil
+	jbe	.LBB{1110}
+	jmp	.LBB{24}
+.LBB{1392}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jae	.LBB{8}
+.LBB{1393}:
+	movq	(%rsp,%rdi,8), %r15
+.LBB{5}:
+	movq	136(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1394}
+	movq	144(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1394}:
+	movq	160(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{1395}
+	leaq	160(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{1395}:
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1396}
+	movq	184(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1396}:
+	movq	200(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1397}
+	movq	208(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1397}:
+	movq	%r15, %rax
+	addq	$312, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1398}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{42}
+.LBB{1399}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{42}
+.LBB{1400}:
+	movzbl	4(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{42}
+.LBB{1401}:
+	movzbl	5(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{16}
+.LBB{1402}:
+	movzbl	9(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{16}
+.LBB{1403}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{42}
+.LBB{1404}:
+	movzbl	1(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+	jmp	.LBB{16}
+.LBB{1405}:
+	movzbl	3(%rbp,%r14), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+.LBB{16}:
+	leaq	.Lanon{236}(%rip), %r8
+	jmp	.LBB{24}
+.LBB{1406}:
+	movzbl	1(%rbp,%r12), %edi
+	cmpq	$16, %rdi
+	jb	.LBB{1393}
+.LBB{42}:
+	leaq	.Lanon{236}(%rip), %r8
+	jmp	.LBB{24}
+.LBB{1}:
+	movq	%rdx, (%rsp)
+	movq	%rsp, %rax
+	movq	%rax, 176(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 184(%rsp)
+	leaq	.Lanon{1407}(%rip), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{1408}(%rip), %rdi
+	leaq	.Lanon{1409}(%rip), %rdx
+	leaq	176(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{215}:
+.Ltmp{1410}:
+	leaq	.Lanon{1411}(%rip), %rdi
+	leaq	.Lanon{1412}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{1413}:
+	jmp	.LBB{1414}
+.LBB{8}:
+	leaq	.Lanon{22}(%rip), %r8
+	jmp	.LBB{24}
+.LBB{39}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{119}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{120}:
+	movq	%rcx, %rdi
+	jmp	.LBB{24}
+.LBB{25}:
+	movq	%rcx, %rdi
+	jmp	.LBB{24}
+.LBB{23}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{56}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{57}:
+	movq	%rcx, %rdi
+	jmp	.LBB{24}
+.LBB{38}:
+	movq	%rcx, %rdi
+	jmp	.LBB{24}
+.LBB{389}:
+	movq	%rax, %rdi
+	jmp	.LBB{24}
+.LBB{48}:
+	leaq	.Lanon{22}(%rip), %r8
+	jmp	.LBB{24}
+.LBB{382}:
+	movq	%rcx, %rdi
+.LBB{24}:
+.Ltmp{1415}:
+	movq	%r8, %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1416}:
+.LBB{1414}:
+	ud2
+.LBB{1207}:
+	movq	%r9, %rdi
+	jmp	.LBB{24}
+.LBB{1212}:
+	movq	%rdx, %rdi
+	jmp	.LBB{24}
+.LBB{566}:
+	movq	%rdx, %rdi
+	jmp	.LBB{24}
+.LBB{808}:
+	movq	%r9, %rdi
+	jmp	.LBB{24}
+.LBB{1417}:
+.Ltmp{1418}:
+	movq	%rax, %rbx
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{1419}:
+.Ltmp{1420}:
+	jmp	.LBB{1421}
+.LBB{1422}:
+.Ltmp{1423}:
+.LBB{1421}:
+	movq	%rax, %rbx
+	leaq	136(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,728 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %r13
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	shlq	$3, %rdx
+	leaq	176(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	176(%rsp), %xmm0
+	movaps	192(%rsp), %xmm1
+	movaps	208(%rsp), %xmm2
+	movaps	224(%rsp), %xmm3
+	movaps	%xmm0, 32(%rsp)
+	movaps	%xmm1, 48(%rsp)
+	movaps	%xmm2, 64(%rsp)
+	movaps	%xmm3, 80(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 144(%rsp)
+	movq	$0, 176(%rsp)
+	movq	$8, 184(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 192(%rsp)
+	movq	$8, 208(%rsp)
+	movq	$0, 216(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	304(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	312(%rsp), %rax
+	movq	%rax, 8(%rsp)
+	movq	320(%rsp), %rax
+	movq	%rax, 16(%rsp)
+	movq	336(%rsp), %rax
+	movq	%rax, 168(%rsp)
+	movq	328(%rsp), %rax
+	movq	%rax, 24(%rsp)
+	xorl	%eax, %eax
+	leaq	.LJTI{4}(%rip), %r14
+	movq	%r13, (%rsp)
+	jmp	.LBB{5}
+.LBB{6}:
+	movl	$64, %ecx
+	rep		bsfq	32(%rsp,%rax,8), %rcx
+.LBB{7}:
+	movq	%rcx, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+.LBB{5}:
+	movl	(%r13,%rax,4), %ecx
+	movq	$-1, %r12
+	cmpb	$31, %cl
+	ja	.LBB{8}
+	movl	%ecx, %edx
+	shrl	$8, %edx
+	movzbl	%dl, %edi
+	movl	%ecx, %r15d
+	shrl	$16, %r15d
+	movl	%ecx, %ebp
+	shrl	$24, %ebp
+	leaq	1(%rax), %rbx
+	movzbl	%cl, %esi
+	movslq	(%r14,%rsi,4), %rsi
+	addq	%r14, %rsi
+	jmpq	*%rsi
+.LBB{9}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jne	.LBB{11}
+	jmp	.LBB{5}
+.LBB{12}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jns	.LBB{5}
+	jmp	.LBB{11}
+.LBB{13}:
+	leaq	.Lanon{14}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{15}
+	movzbl	%r15b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{16}
+	movl	%ebp, %esi
+	cmpl	$268435455, %ecx
+	ja	.LBB{17}
+	movq	32(%rsp,%rax,8), %rax
+	movq	32(%rsp,%rsi,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	16(%rsp), %r10
+	movq	168(%rsp), %r11
+	jb	.LBB{8}
+	cmpq	%r10, %rcx
+	ja	.LBB{8}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r10, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{8}
+	testq	%r11, %r11
+	movq	24(%rsp), %r9
+	je	.LBB{18}
+	cmpq	%rsi, %rcx
+	jae	.LBB{18}
+	movq	%rdx, %rsi
+	movq	%rcx, %rdi
+	jmp	.LBB{19}
+.LBB{20}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{18}
+.LBB{19}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r11, %r8
+	jae	.LBB{20}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{20}
+	jmp	.LBB{8}
+.LBB{21}:
+	movzbl	%r15b, %ecx
+	leaq	.Lanon{14}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{22}
+	movl	%ebp, %eax
+	addq	32(%rsp,%rcx,8), %rax
+	cmpq	16(%rsp), %rax
+	jae	.LBB{8}
+	cmpl	$15, %edi
+	ja	.LBB{15}
+	movq	8(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{23}
+.LBB{24}:
+	movl	%ebp, %ecx
+	andb	$15, %cl
+	movl	%r15d, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%cl, %ecx
+	movq	32(%rsp,%rcx,8), %rcx
+	imulq	32(%rsp,%rsi,8), %rcx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movq	%rcx, 32(%rsp,%rsi,8)
+	shrb	$4, %bpl
+	shrb	$4, %r15b
+	movzbl	%r15b, %ecx
+	movq	32(%rsp,%rcx,8), %rcx
+	movzbl	%bpl, %esi
+	subq	32(%rsp,%rsi,8), %rcx
+	shrb	$4, %dl
+	movzbl	%dl, %edx
+	movq	%rcx, 32(%rsp,%rdx,8)
+	addq	$2, %rax
+	jmp	.LBB{5}
+.LBB{25}:
+	leaq	.Lanon{14}(%rip), %rdx
+	cmpl	$16, %edi
+	jae	.LBB{15}
+	movzbl	%r15b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{16}
+	movl	%ebp, %r8d
+	cmpl	$268435455, %ecx
+	ja	.LBB{26}
+	movq	32(%rsp,%rdi,8), %rsi
+	movq	32(%rsp,%r8,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rsi, %rdi
+	movq	16(%rsp), %rdx
+	movq	168(%rsp), %r10
+	jb	.LBB{8}
+	cmpq	%rdx, %rdi
+	ja	.LBB{8}
+	movq	32(%rsp,%rax,8), %rdx
+	testq	%r10, %r10
+	movq	24(%rsp), %r9
+	je	.LBB{27}
+	cmpq	%rdi, %rsi
+	jae	.LBB{27}
+	movq	%rcx, %rax
+	movq	%rsi, %rdi
+	jmp	.LBB{28}
+.LBB{29}:
+	incq	%rdi
+	decq	%rax
+	je	.LBB{27}
+.LBB{28}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r10, %r8
+	jae	.LBB{29}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{29}
+	jmp	.LBB{8}
+.LBB{30}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	movq	216(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{8}
+	movq	32(%rsp,%rdi,8), %r12
+	cmpq	200(%rsp), %r15
+	jne	.LBB{31}
+.Ltmp{32}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{33}:
+.LBB{31}:
+	movq	208(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 216(%rsp)
+	jmp	.LBB{34}
+.LBB{35}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	movl	%ebp, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{37}
+	cmpl	$15, %edi
+	ja	.LBB{38}
+	movq	32(%rsp,%rax,8), %rax
+	subq	32(%rsp,%rdx,8), %rax
+	jmp	.LBB{39}
+.LBB{40}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{41}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{16}
+	cmpl	$15, %edi
+	ja	.LBB{15}
+	movq	32(%rsp,%rax,8), %rax
+	jmp	.LBB{39}
+.LBB{42}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	cmpl	$268435456, %ecx
+	jae	.LBB{43}
+	cmpl	$15, %edi
+	ja	.LBB{38}
+	movq	32(%rsp,%rbp,8), %rcx
+	addq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{7}
+.LBB{44}:
+	cmpl	$16, %edi
+	jae	.LBB{45}
+	decq	32(%rsp,%rdi,8)
+	jne	.LBB{11}
+	addq	$2, %rax
+	jmp	.LBB{5}
+.LBB{46}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	shll	$8, %ebp
+	movzbl	%r15b, %eax
+	orl	%ebp, %eax
+	jmp	.LBB{39}
+.LBB{47}:
+	cmpl	$16, %edi
+	jae	.LBB{45}
+	decq	32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{48}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{8}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	(%rsp), %r13
+	jmp	.LBB{5}
+.LBB{49}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jne	.LBB{5}
+	jmp	.LBB{11}
+.LBB{50}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	movl	%ebp, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{37}
+	cmpl	$15, %edi
+	ja	.LBB{38}
+	movq	32(%rsp,%rdx,8), %rcx
+	imulq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{7}
+.LBB{51}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	movl	%ebp, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{37}
+	movq	32(%rsp,%rdx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{52}
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{53}
+	cmpq	$-1, %rcx
+	je	.LBB{54}
+.LBB{53}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{55}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{39}
+	jmp	.LBB{38}
+.LBB{56}:
+	movq	192(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{8}
+	cmpq	176(%rsp), %r13
+	jne	.LBB{57}
+.Ltmp{58}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{59}:
+.LBB{57}:
+	movq	184(%rsp), %rax
+	movq	%rbx, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 192(%rsp)
+	movzbl	%r15b, %eax
+	shll	$8, %ebp
+	orl	%eax, %ebp
+	movq	%rbp, %rax
+	movq	(%rsp), %r13
+	jmp	.LBB{5}
+.LBB{60}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{8}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{10}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{23}:
+	movq	%rax, 32(%rsp,%rdi,8)
+	jmp	.LBB{34}
+.LBB{61}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	movl	%ebp, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{37}
+	movq	32(%rsp,%rdx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{62}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{63}
+	jmp	.LBB{38}
+.LBB{64}:
+	movzbl	%r15b, %eax
+	cmpb	$15, %r15b
+	ja	.LBB{36}
+	movl	%ebp, %edx
+	cmpl	$268435455, %ecx
+	ja	.LBB{37}
+	cmpl	$15, %edi
+	ja	.LBB{38}
+	movq	32(%rsp,%rax,8), %rax
+	cmpq	32(%rsp,%rdx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{39}
+.LBB{65}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{41}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{16}
+	cmpl	$15, %edi
+	ja	.LBB{15}
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{7}
+.LBB{66}:
+	movzbl	%r15b, %ecx
+	leaq	.Lanon{14}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{22}
+	movl	%ebp, %eax
+	addq	32(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{67}
+	movq	24(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	movq	16(%rsp), %rcx
+	jb	.LBB{8}
+	cmpq	%rcx, %rax
+	jb	.LBB{68}
+	jmp	.LBB{8}
+.LBB{69}:
+	cmpl	$16, %edi
+	jae	.LBB{45}
+	incq	32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{70}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	js	.LBB{5}
+.LBB{11}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebp
+	orl	%eax, %ebp
+	movq	%rbp, %rax
+	jmp	.LBB{5}
+.LBB{71}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{41}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{16}
+	cmpl	$15, %edi
+	jbe	.LBB{6}
+	jmp	.LBB{15}
+.LBB{72}:
+	movl	%ebp, %ecx
+	andb	$15, %cl
+	movl	%r15d, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%cl, %ecx
+	movq	32(%rsp,%rcx,8), %rcx
+	addq	32(%rsp,%rsi,8), %rcx
+	movl	%edx, %esi
+	andl	$15, %esi
+	movq	%rcx, 32(%rsp,%rsi,8)
+	shrb	$4, %bpl
+	shrb	$4, %r15b
+	movzbl	%r15b, %ecx
+	movzbl	%bpl, %esi
+	movq	32(%rsp,%rsi,8), %rsi
+	addq	32(%rsp,%rcx,8), %rsi
+	shrb	$4, %dl
+	movzbl	%dl, %ecx
+	movq	%rsi, 32(%rsp,%rcx,8)
+	addq	$2, %rax
+	jmp	.LBB{5}
+.LBB{73}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{41}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{16}
+	cmpl	$15, %edi
+	ja	.LBB{15}
+	movl	$127, %ecx
+	bsrq	32(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	jmp	.LBB{7}
+.LBB{27}:
+	movq	%rbx, %rax
+	testq	%rcx, %rcx
+	je	.LBB{5}
+	movq	8(%rsp), %rax
+	leaq	(%rax,%rsi,8), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{74}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{74}
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{67}:
+	cmpq	16(%rsp), %rax
+	jae	.LBB{8}
+.LBB{68}:
+	cmpl	$15, %edi
+	ja	.LBB{15}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	8(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+.LBB{34}:
+	movq	%rbx, %rax
+	movq	(%rsp), %r13
+	jmp	.LBB{5}
+.LBB{62}:
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{75}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{63}
+	jmp	.LBB{38}
+.LBB{18}:
+	movq	%r10, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{76}
+	movq	8(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{52}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{39}
+	jmp	.LBB{38}
+.LBB{55}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{38}
+.LBB{39}:
+	movq	%rax, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{75}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{38}
+.LBB{63}:
+	movq	%rdx, 32(%rsp,%rdi,8)
+	movq	%rbx, %rax
+	jmp	.LBB{5}
+.LBB{54}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{39}
+.LBB{38}:
+	movq	%rdi, %rax
+	jmp	.LBB{36}
+.LBB{77}:
+	cmpl	$16, %edi
+	jae	.LBB{10}
+	movq	32(%rsp,%rdi,8), %r12
+.LBB{8}:
+	movq	304(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{78}
+	shlq	$3, %rsi
+	movl	$8, %edx
+	movq	8(%rsp), %rdi
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{78}:
+	movq	24(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{79}
+	leaq	328(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{79}:
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{80}
+	movq	184(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{80}:
+	movq	200(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{81}
+	movq	208(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{81}:
+	movq	%r12, %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 32(%rsp)
+	leaq	32(%rsp), %rax
+	movq	%rax, 176(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 184(%rsp)
+	leaq	.Lanon{82}(%rip), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{83}(%rip), %rdi
+	leaq	.Lanon{84}(%rip), %rdx
+	leaq	176(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{76}:
+.Ltmp{85}:
+	leaq	.Lanon{86}(%rip), %rdi
+	leaq	.Lanon{87}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{88}:
+	jmp	.LBB{89}
+.LBB{10}:
+	leaq	.Lanon{14}(%rip), %rdx
+	jmp	.LBB{15}
+.LBB{16}:
+	movq	%rax, %rdi
+	jmp	.LBB{15}
+.LBB{37}:
+	movq	%rdx, %rax
+.LBB{36}:
+.Ltmp{90}:
+	leaq	.Lanon{41}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rax, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{91}:
+	jmp	.LBB{89}
+.LBB{45}:
+	leaq	.Lanon{41}(%rip), %rdx
+	jmp	.LBB{15}
+.LBB{22}:
+	movq	%rcx, %rdi
+	jmp	.LBB{15}
+.LBB{26}:
+	movq	%r8, %rdi
+	jmp	.LBB{15}
+.LBB{17}:
+	movq	%rsi, %rdi
+.LBB{15}:
+.Ltmp{92}:
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{93}:
+.LBB{89}:
+	ud2
+.LBB{43}:
+	movq	%rbp, %rax
+	jmp	.LBB{36}
+.LBB{94}:
+.Ltmp{95}:
+	jmp	.LBB{96}
+.LBB{97}:
+.Ltmp{98}:
+	movq	%rax, %rbx
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{99}:
+.Ltmp{100}:
+.LBB{96}:
+	movq	%rax, %rbx
+	leaq	304(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,739 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$360, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %rax
+	movq	%rax, 312(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	shlq	$3, %rdx
+	leaq	176(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	176(%rsp), %xmm0
+	movaps	192(%rsp), %xmm1
+	movaps	208(%rsp), %xmm2
+	movaps	224(%rsp), %xmm3
+	movaps	%xmm0, 32(%rsp)
+	movaps	%xmm1, 48(%rsp)
+	movaps	%xmm2, 64(%rsp)
+	movaps	%xmm3, 80(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 144(%rsp)
+	movq	$0, 176(%rsp)
+	movq	$8, 184(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 192(%rsp)
+	movq	$8, 208(%rsp)
+	movq	$0, 216(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	320(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	328(%rsp), %rax
+	movq	%rax, 8(%rsp)
+	movq	336(%rsp), %rax
+	movq	%rax, 16(%rsp)
+	movq	352(%rsp), %rax
+	movq	%rax, 168(%rsp)
+	movq	344(%rsp), %rax
+	movq	%rax, 24(%rsp)
+	xorl	%eax, %eax
+	movq	$-1, %r13
+	jmp	.LBB{4}
+.LBB{5}:
+	xorl	%eax, %eax
+.LBB{6}:
+	cmpl	$15, %r9d
+	ja	.LBB{7}
+.LBB{8}:
+	movq	%rax, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+.LBB{4}:
+	movq	312(%rsp), %rcx
+	movl	(%rcx,%rax,4), %ebp
+	cmpb	$31, %bpl
+	ja	.LBB{9}
+	movl	%ebp, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r9d
+	movl	%ebp, %r15d
+	shrl	$16, %r15d
+	movl	%ebp, %ebx
+	shrl	$24, %ebx
+	leaq	1(%rax), %r12
+	movzbl	%bpl, %r14d
+	leaq	.LJTI{10}(%rip), %rsi
+	movslq	(%rsi,%r14,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{11}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jne	.LBB{13}
+	jmp	.LBB{4}
+.LBB{14}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jns	.LBB{4}
+	jmp	.LBB{13}
+.LBB{15}:
+	leaq	.Lanon{16}(%rip), %rdx
+	cmpl	$16, %r9d
+	jae	.LBB{17}
+	movzbl	%r15b, %eax
+	cmpl	$15, %eax
+	ja	.LBB{18}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %ebp
+	ja	.LBB{19}
+	movq	32(%rsp,%rax,8), %rax
+	movq	32(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	16(%rsp), %r10
+	movq	168(%rsp), %r11
+	jb	.LBB{9}
+	cmpq	%r10, %rcx
+	ja	.LBB{9}
+	movq	32(%rsp,%r9,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r10, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{9}
+	movq	%r9, %rbx
+	testq	%r11, %r11
+	movq	24(%rsp), %r9
+	je	.LBB{20}
+	cmpq	%rsi, %rcx
+	jae	.LBB{20}
+	movq	%rdx, %rsi
+	movq	%rcx, %rdi
+	jmp	.LBB{21}
+.LBB{22}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{20}
+.LBB{21}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r11, %r8
+	jae	.LBB{22}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{22}
+	jmp	.LBB{9}
+.LBB{23}:
+	movzbl	%r15b, %ecx
+	leaq	.Lanon{16}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{19}
+	movl	%ebx, %eax
+	addq	32(%rsp,%rcx,8), %rax
+	cmpq	16(%rsp), %rax
+	jae	.LBB{9}
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movq	8(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{8}
+.LBB{24}:
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movl	%r15d, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	imulq	32(%rsp,%rsi,8), %rdx
+	movl	%ecx, %esi
+	andl	$15, %esi
+	movq	%rdx, 32(%rsp,%rsi,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	movzbl	%bl, %esi
+	subq	32(%rsp,%rsi,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rdx, 32(%rsp,%rcx,8)
+	addq	$2, %rax
+	jmp	.LBB{4}
+.LBB{25}:
+	leaq	.Lanon{16}(%rip), %rdx
+	cmpl	$16, %r9d
+	jae	.LBB{17}
+	movzbl	%r15b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{19}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{18}
+	movq	32(%rsp,%r9,8), %rdx
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	movq	16(%rsp), %rdi
+	movq	168(%rsp), %r10
+	jb	.LBB{9}
+	cmpq	%rdi, %rsi
+	ja	.LBB{9}
+	movq	%r9, %r11
+	movq	32(%rsp,%rcx,8), %rcx
+	testq	%r10, %r10
+	movq	24(%rsp), %r9
+	je	.LBB{26}
+	cmpq	%rsi, %rdx
+	jae	.LBB{26}
+	movq	%rax, %rsi
+	movq	%rdx, %rdi
+	jmp	.LBB{27}
+.LBB{28}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{26}
+.LBB{27}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r10, %r8
+	jae	.LBB{28}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{28}
+	jmp	.LBB{9}
+.LBB{29}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{9}
+	movq	32(%rsp,%r9,8), %r14
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{30}
+.Ltmp{31}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{32}:
+.LBB{30}:
+	movq	208(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{33}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{35}
+	cmpl	$15, %r9d
+	ja	.LBB{7}
+	movq	32(%rsp,%rdi,8), %rcx
+	subq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{36}
+.LBB{37}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	cmpl	$268435456, %ebp
+	jae	.LBB{38}
+	cmpl	$15, %r9d
+	ja	.LBB{7}
+	movq	32(%rsp,%rbx,8), %rax
+	addq	32(%rsp,%rdi,8), %rax
+	jmp	.LBB{8}
+.LBB{39}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{8}
+.LBB{40}:
+	cmpl	$16, %r9d
+	jae	.LBB{41}
+	decq	32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{42}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{43}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{18}
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movq	32(%rsp,%rax,8), %rax
+	jmp	.LBB{8}
+.LBB{44}:
+	cmpl	$16, %r9d
+	jae	.LBB{41}
+	decq	32(%rsp,%r9,8)
+	jne	.LBB{13}
+	addq	$2, %rax
+	jmp	.LBB{4}
+.LBB{45}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{9}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{4}
+.LBB{46}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{35}
+	cmpl	$15, %r9d
+	ja	.LBB{7}
+	movq	32(%rsp,%rax,8), %rax
+	imulq	32(%rsp,%rdi,8), %rax
+	jmp	.LBB{8}
+.LBB{47}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{35}
+	movq	32(%rsp,%rax,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{5}
+	movq	32(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{48}
+	cmpq	$-1, %rcx
+	je	.LBB{49}
+.LBB{48}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{50}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{6}
+.LBB{51}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{52}
+.LBB{53}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{35}
+	movq	32(%rsp,%rax,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{54}
+	xorl	%edx, %edx
+	cmpl	$16, %r9d
+	jb	.LBB{55}
+	jmp	.LBB{7}
+.LBB{56}:
+	movq	192(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{9}
+	cmpq	176(%rsp), %r14
+	jne	.LBB{57}
+.Ltmp{58}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{59}:
+.LBB{57}:
+	movq	184(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 192(%rsp)
+	jmp	.LBB{13}
+.LBB{60}:
+	cmpl	$16, %r9d
+	jae	.LBB{41}
+	incq	32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{61}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{9}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	cmpl	$15, %r9d
+	ja	.LBB{12}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{8}
+.LBB{62}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{34}
+	movl	%ebx, %eax
+	cmpl	$268435455, %ebp
+	ja	.LBB{35}
+	cmpl	$15, %r9d
+	ja	.LBB{7}
+	movq	32(%rsp,%rdi,8), %rcx
+	cmpq	32(%rsp,%rax,8), %rcx
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{8}
+.LBB{63}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{43}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{18}
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{36}
+.LBB{64}:
+	movzbl	%r15b, %ecx
+	leaq	.Lanon{16}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{19}
+	movl	%ebx, %eax
+	addq	32(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{65}
+	movq	24(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	movq	16(%rsp), %rcx
+	jb	.LBB{9}
+	cmpq	%rcx, %rax
+	jb	.LBB{66}
+	jmp	.LBB{9}
+.LBB{67}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jns	.LBB{13}
+	jmp	.LBB{4}
+.LBB{68}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jne	.LBB{4}
+.LBB{13}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rax
+	jmp	.LBB{4}
+.LBB{69}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{43}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{18}
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movl	$64, %ecx
+	rep		bsfq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{36}
+.LBB{70}:
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movl	%r15d, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	addq	32(%rsp,%rsi,8), %rdx
+	movl	%ecx, %esi
+	andl	$15, %esi
+	movq	%rdx, 32(%rsp,%rsi,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %edx
+	movzbl	%bl, %esi
+	movq	32(%rsp,%rsi,8), %rsi
+	addq	32(%rsp,%rdx,8), %rsi
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rsi, 32(%rsp,%rcx,8)
+	addq	$2, %rax
+	jmp	.LBB{4}
+.LBB{71}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{43}(%rip), %rdx
+	cmpl	$16, %eax
+	jae	.LBB{18}
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movl	$127, %ecx
+	bsrq	32(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{36}:
+	movq	%rcx, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{26}:
+	testq	%rax, %rax
+	movq	%r11, %r9
+	je	.LBB{72}
+	movq	8(%rsp), %rsi
+	leaq	(%rsi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{73}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{73}
+.LBB{72}:
+	movq	%r12, %rax
+	cmpb	$28, %bpl
+	jbe	.LBB{74}
+	jmp	.LBB{4}
+.LBB{65}:
+	cmpq	16(%rsp), %rax
+	jae	.LBB{9}
+.LBB{66}:
+	cmpl	$15, %r9d
+	ja	.LBB{17}
+	movq	32(%rsp,%r9,8), %rcx
+	movq	8(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{54}:
+	movq	32(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{75}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %r9d
+	jb	.LBB{55}
+	jmp	.LBB{7}
+.LBB{20}:
+	movq	%r10, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{76}
+	movq	8(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	%rbx, %r9
+	movq	%r12, %rax
+	cmpb	$28, %bpl
+	ja	.LBB{4}
+.LBB{74}:
+	movl	$300941824, %eax
+	btl	%r14d, %eax
+	movq	%r12, %rax
+	jae	.LBB{4}
+.LBB{52}:
+	movq	%r12, %rax
+	cmpl	$16, %r9d
+	jb	.LBB{4}
+	jmp	.LBB{77}
+.LBB{50}:
+	xorl	%edx, %edx
+	divl	%ecx
+	jmp	.LBB{6}
+.LBB{75}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %r9d
+	jae	.LBB{7}
+.LBB{55}:
+	movq	%rdx, 32(%rsp,%r9,8)
+	movq	%r12, %rax
+	jmp	.LBB{4}
+.LBB{49}:
+	movabsq	$-9223372036854775808, %rax
+	jmp	.LBB{6}
+.LBB{78}:
+	cmpl	$16, %r9d
+	jae	.LBB{12}
+	movq	32(%rsp,%r9,8), %r13
+.LBB{9}:
+	movq	320(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{79}
+	shlq	$3, %rsi
+	movl	$8, %edx
+	movq	8(%rsp), %rdi
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{79}:
+	movq	24(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{80}
+	leaq	344(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{80}:
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{81}
+	movq	184(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{81}:
+	movq	200(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{82}
+	movq	208(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{82}:
+	movq	%r13, %rax
+	addq	$360, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 32(%rsp)
+	leaq	32(%rsp), %rax
+	movq	%rax, 176(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 184(%rsp)
+	leaq	.Lanon{83}(%rip), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{84}(%rip), %rdi
+	leaq	.Lanon{85}(%rip), %rdx
+	leaq	176(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{76}:
+.Ltmp{86}:
+	leaq	.Lanon{87}(%rip), %rdi
+	leaq	.Lanon{88}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{89}:
+	jmp	.LBB{90}
+.LBB{12}:
+	movq	%r9, %rdi
+	leaq	.Lanon{16}(%rip), %rdx
+	jmp	.LBB{91}
+.LBB{17}:
+	movq	%r9, %rdi
+	jmp	.LBB{91}
+.LBB{18}:
+	movq	%rax, %rdi
+	jmp	.LBB{91}
+.LBB{7}:
+	movq	%r9, %rdi
+	jmp	.LBB{34}
+.LBB{35}:
+	movq	%rax, %rdi
+.LBB{34}:
+.Ltmp{92}:
+	leaq	.Lanon{43}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{93}:
+	jmp	.LBB{90}
+.LBB{19}:
+	movq	%rcx, %rdi
+	jmp	.LBB{91}
+.LBB{41}:
+	movq	%r9, %rdi
+	leaq	.Lanon{43}(%rip), %rdx
+	jmp	.LBB{91}
+.LBB{77}:
+	movq	%r9, %rdi
+	leaq	.Lanon{94}(%rip), %rdx
+.LBB{91}:
+.Ltmp{95}:
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{96}:
+.LBB{90}:
+	ud2
+.LBB{38}:
+	movq	%rbx, %rdi
+	jmp	.LBB{34}
+.LBB{97}:
+.Ltmp{98}:
+	jmp	.LBB{99}
+.LBB{100}:
+.Ltmp{101}:
+	movq	%rax, %rbx
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{102}:
+.Ltmp{103}:
+.LBB{99}:
+	movq	%rax, %rbx
+	leaq	320(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
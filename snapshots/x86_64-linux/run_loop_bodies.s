@@ -0,0 +1,10166 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$392, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %r14
+	movq	8(%rdi), %rax
+	movq	%rax, 328(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 16(%rsp)
+	movaps	%xmm1, 32(%rsp)
+	movaps	%xmm2, 48(%rsp)
+	movaps	%xmm3, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	leaq	48(%r14), %rsi
+.Ltmp{2}:
+	leaq	336(%rsp), %rdi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	344(%rsp), %rax
+	movq	%rax, 160(%rsp)
+	movq	352(%rsp), %rax
+	movq	%rax, 168(%rsp)
+	movq	368(%rsp), %rax
+	movq	%rax, 184(%rsp)
+	movq	360(%rsp), %rax
+	movq	%rax, 176(%rsp)
+	movq	32(%r14), %rax
+	movq	40(%r14), %r13
+	movq	%rax, 384(%rsp)
+	addq	$2, %rax
+	movq	%rax, 376(%rsp)
+	xorl	%ebx, %ebx
+	leaq	.Lanon{4}(%rip), %rax
+	movq	%rax, 152(%rsp)
+	leaq	.Lanon{5}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	leaq	.LJTI{6}(%rip), %r15
+	leaq	.LJTI{7}(%rip), %r12
+	movq	%r13, 144(%rsp)
+	jmp	.LBB{8}
+.LBB{9}:
+	movq	72(%rsi), %rbx
+.LBB{8}:
+	movq	%rbx, %rcx
+	movq	328(%rsp), %rax
+	movzbl	(%rax,%rbx,4), %edx
+	movzwl	2(%rax,%rbx,4), %ebx
+	cmpl	$254, %edx
+	jne	.LBB{10}
+	cmpq	%rbx, %r13
+	jbe	.LBB{11}
+	imulq	$88, %rbx, %rdi
+	movq	384(%rsp), %rcx
+	movq	64(%rcx,%rdi), %rax
+	decq	%rax
+	cmpq	$15, %rax
+	movabsq	$-9223372036854775808, %r14
+	ja	.LBB{12}
+	leaq	(%rcx,%rdi), %rsi
+	leaq	.LJTI{13}(%rip), %rcx
+	movslq	(%rcx,%rax,4), %rax
+	addq	%rcx, %rax
+	jmpq	*%rax
+.LBB{14}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	movzbl	1(%rsi), %r8d
+	movzbl	(%rsi), %r9d
+	addl	$-2, %r9d
+	jmp	.LBB{15}
+.LBB{10}:
+	movq	$-1, %rbp
+	cmpl	$31, %edx
+	ja	.LBB{16}
+	movl	%ebx, %eax
+	shrl	$8, %eax
+	movq	328(%rsp), %rsi
+	movzbl	1(%rsi,%rcx,4), %edi
+	leaq	1(%rcx), %r14
+	movl	%edx, %edx
+	leaq	.LJTI{17}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{18}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	cmoveq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{20}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{15}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{21}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{22}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{23}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{24}
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{28}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{25}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{29}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{24}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{30}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{26}
+.LBB{32}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{33}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{34}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{24}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{35}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{26}
+.LBB{36}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{37}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{38}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{39}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{40}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{21}
+.LBB{40}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{41}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{42}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{24}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{43}
+.LBB{39}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{44}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{25}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{45}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{24}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{43}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{46}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{41}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{21}:
+	cmpb	$15, %r8b
+	ja	.LBB{27}
+.LBB{26}:
+	movq	%rax, 16(%rsp,%r8,8)
+	cmpl	$15, %edi
+	ja	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{48}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{49}:
+	testq	%rax, %rax
+	jne	.LBB{15}
+	jmp	.LBB{9}
+.LBB{50}:
+	testq	%rax, %rax
+	je	.LBB{15}
+	jmp	.LBB{9}
+.LBB{51}:
+	testq	%rax, %rax
+	jns	.LBB{15}
+	jmp	.LBB{9}
+.LBB{46}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{26}
+	jmp	.LBB{27}
+.LBB{52}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{53}
+.LBB{54}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{53}:
+	movl	$2, %r9d
+	jmp	.LBB{55}
+.LBB{56}:
+	xorl	%eax, %eax
+.LBB{57}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$62, %r9
+	je	.LBB{59}
+.LBB{55}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{57}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%r15,%r10,4), %r10
+	addq	%r15, %r10
+	jmpq	*%r10
+.LBB{60}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{57}
+.LBB{63}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{57}
+.LBB{64}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{57}
+.LBB{65}:
+	movl	$1, %eax
+	jmp	.LBB{66}
+.LBB{67}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{57}
+.LBB{69}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{57}
+.LBB{70}:
+	movq	$-1, %rax
+.LBB{66}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$62, %r9
+	jne	.LBB{55}
+	jmp	.LBB{59}
+.LBB{71}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{57}
+.LBB{72}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{56}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{74}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{57}
+.LBB{74}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{75}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{57}
+.LBB{76}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{56}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{77}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{57}
+.LBB{78}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{57}
+.LBB{79}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{57}
+.LBB{75}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{57}
+.LBB{77}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{57}
+.LBB{59}:
+	cmpl	$16, %edi
+	jae	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{80}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{81}:
+	testq	%rax, %rax
+	jne	.LBB{53}
+	jmp	.LBB{9}
+.LBB{82}:
+	testq	%rax, %rax
+	je	.LBB{53}
+	jmp	.LBB{9}
+.LBB{83}:
+	testq	%rax, %rax
+	jns	.LBB{53}
+	jmp	.LBB{9}
+.LBB{84}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{85}
+.LBB{86}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{85}:
+	movl	$2, %r9d
+	leaq	.LJTI{87}(%rip), %rbp
+	jmp	.LBB{88}
+.LBB{89}:
+	xorl	%eax, %eax
+.LBB{90}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$50, %r9
+	je	.LBB{91}
+.LBB{88}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{90}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{92}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{90}
+.LBB{93}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{90}
+.LBB{94}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{90}
+.LBB{95}:
+	movl	$1, %eax
+	jmp	.LBB{96}
+.LBB{97}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{90}
+.LBB{98}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{90}
+.LBB{99}:
+	movq	$-1, %rax
+.LBB{96}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$50, %r9
+	jne	.LBB{88}
+	jmp	.LBB{91}
+.LBB{100}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{90}
+.LBB{101}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{89}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{102}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{90}
+.LBB{102}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{103}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{90}
+.LBB{104}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{89}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{105}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{90}
+.LBB{106}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{90}
+.LBB{107}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{90}
+.LBB{103}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{90}
+.LBB{105}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{90}
+.LBB{91}:
+	cmpl	$16, %edi
+	jae	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{108}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{109}:
+	testq	%rax, %rax
+	jne	.LBB{85}
+	jmp	.LBB{9}
+.LBB{110}:
+	testq	%rax, %rax
+	je	.LBB{85}
+	jmp	.LBB{9}
+.LBB{111}:
+	testq	%rax, %rax
+	jns	.LBB{85}
+	jmp	.LBB{9}
+.LBB{112}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	1(%rsi), %r8d
+	movzbl	(%rsi), %r9d
+	addl	$-2, %r9d
+	jmp	.LBB{113}
+.LBB{114}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{113}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{115}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{116}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{117}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{118}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{122}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{123}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{118}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{124}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{120}
+.LBB{125}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{127}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{118}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{128}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{120}
+.LBB{129}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{130}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{132}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{133}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{115}
+.LBB{133}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{134}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{135}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{118}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{136}
+.LBB{132}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{137}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{138}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{118}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{136}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{139}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{134}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{115}:
+	cmpb	$15, %r8b
+	ja	.LBB{121}
+.LBB{120}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{140}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{141}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{142}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{145}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{146}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{147}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{148}
+	jmp	.LBB{118}
+.LBB{149}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{150}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{151}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{148}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{143}
+.LBB{152}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{153}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{155}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{156}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{140}
+.LBB{156}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{157}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{158}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{159}
+.LBB{155}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{160}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{161}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{159}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{162}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{157}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{140}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{143}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{163}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{164}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{165}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{167}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{168}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{169}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{170}
+	jmp	.LBB{118}
+.LBB{171}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{172}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{173}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{170}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{166}
+.LBB{174}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{175}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{176}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{177}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{163}
+.LBB{177}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{178}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{179}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{180}
+.LBB{176}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{181}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{182}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{180}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{183}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{178}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{163}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{166}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	12(%rsi), %r11d
+	movzbl	13(%rsi), %r10d
+	movzwl	14(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{184}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{185}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{186}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{188}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{189}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{190}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{191}
+	jmp	.LBB{118}
+.LBB{192}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{193}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{194}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{191}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{187}
+.LBB{195}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{196}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{197}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{198}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{184}
+.LBB{198}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{199}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{200}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{201}
+.LBB{197}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{202}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{203}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{201}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{204}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{199}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{184}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{187}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	16(%rsi), %r11d
+	movzbl	17(%rsi), %r10d
+	movzwl	18(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{205}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{206}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{207}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{211}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{212}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{213}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{214}
+	jmp	.LBB{118}
+.LBB{215}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{216}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{217}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{214}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{210}
+.LBB{218}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{219}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{220}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{221}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{205}
+.LBB{221}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{222}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{223}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{224}
+.LBB{220}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{225}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{226}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{224}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{227}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{222}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{205}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{210}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{228}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{229}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{230}:
+	testq	%rax, %rax
+	jne	.LBB{113}
+	jmp	.LBB{9}
+.LBB{231}:
+	testq	%rax, %rax
+	je	.LBB{113}
+	jmp	.LBB{9}
+.LBB{232}:
+	testq	%rax, %rax
+	jns	.LBB{113}
+	jmp	.LBB{9}
+.LBB{139}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{120}
+	jmp	.LBB{121}
+.LBB{162}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{143}
+	jmp	.LBB{144}
+.LBB{183}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{166}
+	jmp	.LBB{144}
+.LBB{204}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{187}
+	jmp	.LBB{144}
+.LBB{227}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{210}
+	jmp	.LBB{144}
+.LBB{233}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{234}
+.LBB{235}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{234}:
+	movl	$2, %r9d
+	leaq	.LJTI{236}(%rip), %rbp
+	jmp	.LBB{237}
+.LBB{238}:
+	xorl	%eax, %eax
+.LBB{239}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$54, %r9
+	je	.LBB{240}
+.LBB{237}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{239}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{241}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{239}
+.LBB{242}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{239}
+.LBB{243}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{239}
+.LBB{244}:
+	movl	$1, %eax
+	jmp	.LBB{245}
+.LBB{246}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{239}
+.LBB{247}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{239}
+.LBB{248}:
+	movq	$-1, %rax
+.LBB{245}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$54, %r9
+	jne	.LBB{237}
+	jmp	.LBB{240}
+.LBB{249}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{239}
+.LBB{250}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{238}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{251}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{239}
+.LBB{251}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{252}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{239}
+.LBB{253}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{238}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{254}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{239}
+.LBB{255}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{239}
+.LBB{256}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{239}
+.LBB{252}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{239}
+.LBB{254}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{239}
+.LBB{240}:
+	cmpl	$16, %edi
+	jae	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{257}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{258}:
+	testq	%rax, %rax
+	jne	.LBB{234}
+	jmp	.LBB{9}
+.LBB{259}:
+	testq	%rax, %rax
+	je	.LBB{234}
+	jmp	.LBB{9}
+.LBB{260}:
+	testq	%rax, %rax
+	jns	.LBB{234}
+	jmp	.LBB{9}
+.LBB{261}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{262}
+.LBB{263}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{262}:
+	movl	$2, %r9d
+	leaq	.LJTI{264}(%rip), %rbp
+	jmp	.LBB{265}
+.LBB{266}:
+	xorl	%eax, %eax
+.LBB{267}:
+	cmpb	$16, %r8b
+	jae	.LBB{268}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$42, %r9
+	je	.LBB{269}
+.LBB{265}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{267}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{270}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{267}
+.LBB{271}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{267}
+.LBB{272}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{267}
+.LBB{273}:
+	movl	$1, %eax
+	jmp	.LBB{274}
+.LBB{275}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{267}
+.LBB{276}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{267}
+.LBB{277}:
+	movq	$-1, %rax
+.LBB{274}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$42, %r9
+	jne	.LBB{265}
+	jmp	.LBB{269}
+.LBB{278}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{267}
+.LBB{279}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{266}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{280}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{267}
+.LBB{280}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{281}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{267}
+.LBB{282}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{266}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{283}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{267}
+.LBB{284}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{267}
+.LBB{285}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{267}
+.LBB{281}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{267}
+.LBB{283}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{267}
+.LBB{269}:
+	cmpl	$16, %edi
+	jae	.LBB{286}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{287}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{288}:
+	testq	%rax, %rax
+	jne	.LBB{262}
+	jmp	.LBB{9}
+.LBB{289}:
+	testq	%rax, %rax
+	je	.LBB{262}
+	jmp	.LBB{9}
+.LBB{290}:
+	testq	%rax, %rax
+	jns	.LBB{262}
+	jmp	.LBB{9}
+.LBB{291}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	1(%rsi), %r8d
+	movzbl	(%rsi), %r9d
+	addl	$-2, %r9d
+	jmp	.LBB{292}
+.LBB{293}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{292}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{294}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{295}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{296}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{299}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{300}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{301}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{298}
+.LBB{302}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{304}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{305}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{298}
+.LBB{306}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{307}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{308}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{309}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{294}
+.LBB{309}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{310}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{311}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{312}
+.LBB{308}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{313}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{314}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{312}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{315}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{310}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{294}:
+	cmpb	$15, %r8b
+	ja	.LBB{27}
+.LBB{298}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{316}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{317}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{318}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{321}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{322}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{323}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{324}
+	jmp	.LBB{297}
+.LBB{325}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{326}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{327}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{324}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{319}
+.LBB{328}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{329}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{330}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{331}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{332}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{316}
+.LBB{332}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{333}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{334}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{335}
+.LBB{331}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{336}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{337}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{335}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{338}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{333}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{316}:
+	cmpb	$16, %r10b
+	jae	.LBB{320}
+.LBB{319}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{339}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{340}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{341}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{343}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{344}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{345}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{346}
+	jmp	.LBB{297}
+.LBB{347}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{348}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{349}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{346}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{342}
+.LBB{350}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{351}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{330}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{352}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{353}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{339}
+.LBB{353}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{354}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{355}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{356}
+.LBB{352}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{357}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{358}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{356}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{359}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{354}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{339}:
+	cmpb	$16, %r10b
+	jae	.LBB{320}
+.LBB{342}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{47}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{360}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{361}:
+	testq	%rax, %rax
+	jne	.LBB{292}
+	jmp	.LBB{9}
+.LBB{362}:
+	testq	%rax, %rax
+	je	.LBB{292}
+	jmp	.LBB{9}
+.LBB{363}:
+	testq	%rax, %rax
+	jns	.LBB{292}
+	jmp	.LBB{9}
+.LBB{315}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{298}
+	jmp	.LBB{27}
+.LBB{338}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{319}
+	jmp	.LBB{320}
+.LBB{359}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{342}
+	jmp	.LBB{320}
+.LBB{364}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %ebp
+	addq	376(%rsp), %rdi
+	jmp	.LBB{365}
+.LBB{366}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{365}:
+	xorl	%r9d, %r9d
+	jmp	.LBB{367}
+.LBB{368}:
+	xorl	%eax, %eax
+.LBB{369}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	addq	$4, %r9
+	movq	%rax, 16(%rsp,%r8,8)
+	cmpq	$64, %r9
+	je	.LBB{370}
+.LBB{367}:
+	movzbl	-2(%rdi,%r9), %r10d
+	movzbl	-1(%rdi,%r9), %r8d
+	movzwl	(%rdi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{369}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%r12,%r10,4), %r10
+	addq	%r12, %r10
+	jmpq	*%r10
+.LBB{371}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{369}
+.LBB{372}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{369}
+.LBB{373}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{369}
+.LBB{374}:
+	movl	$1, %eax
+	jmp	.LBB{375}
+.LBB{376}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{369}
+.LBB{377}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{369}
+.LBB{378}:
+	movq	$-1, %rax
+.LBB{375}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	addq	$4, %r9
+	movq	%rax, 16(%rsp,%r8,8)
+	cmpq	$64, %r9
+	jne	.LBB{367}
+	jmp	.LBB{370}
+.LBB{379}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{369}
+.LBB{380}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{368}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{381}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{369}
+.LBB{381}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{382}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{369}
+.LBB{383}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{368}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{384}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{369}
+.LBB{385}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{369}
+.LBB{386}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{369}
+.LBB{382}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{369}
+.LBB{384}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{369}
+.LBB{370}:
+	cmpl	$16, %ebp
+	jae	.LBB{387}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rbp,8), %rax
+	leaq	.LJTI{388}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{389}:
+	testq	%rax, %rax
+	jne	.LBB{365}
+	jmp	.LBB{9}
+.LBB{390}:
+	testq	%rax, %rax
+	je	.LBB{365}
+	jmp	.LBB{9}
+.LBB{391}:
+	testq	%rax, %rax
+	jns	.LBB{365}
+	jmp	.LBB{9}
+.LBB{392}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	1(%rsi), %r8d
+	movzbl	(%rsi), %r9d
+	addl	$-2, %r9d
+	jmp	.LBB{393}
+.LBB{394}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{393}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{395}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{396}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{397}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{400}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{401}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{402}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{398}
+.LBB{403}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{404}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{405}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{398}
+.LBB{406}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{407}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{408}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{409}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{395}
+.LBB{409}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{410}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{411}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{412}
+.LBB{408}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{413}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{414}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{412}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{415}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{410}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{395}:
+	cmpb	$15, %r8b
+	ja	.LBB{399}
+.LBB{398}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{416}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{417}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{418}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{420}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{421}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{422}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{423}
+	jmp	.LBB{297}
+.LBB{424}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{425}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{426}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{423}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{419}
+.LBB{427}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{428}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{330}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{429}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{430}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{416}
+.LBB{430}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{431}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{432}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{433}
+.LBB{429}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{434}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{435}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{433}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{436}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{431}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{416}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{419}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{437}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{438}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{439}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{441}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{442}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{443}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{444}
+	jmp	.LBB{297}
+.LBB{445}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{446}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{447}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{444}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{440}
+.LBB{448}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{449}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{330}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{450}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{451}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{437}
+.LBB{451}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{452}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{453}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{454}
+.LBB{450}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{455}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{456}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{454}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{457}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{452}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{437}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{440}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	12(%rsi), %r11d
+	movzbl	13(%rsi), %r10d
+	movzwl	14(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{458}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{459}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{460}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{462}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{463}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{464}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{465}
+	jmp	.LBB{297}
+.LBB{466}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{467}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{468}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{465}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{461}
+.LBB{469}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{470}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{330}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{471}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{472}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{458}
+.LBB{472}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{473}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{474}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{475}
+.LBB{471}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{476}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{477}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{208}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{475}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{478}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{473}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{458}:
+	cmpb	$16, %r10b
+	jae	.LBB{144}
+.LBB{461}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{228}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{479}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{480}:
+	testq	%rax, %rax
+	jne	.LBB{393}
+	jmp	.LBB{9}
+.LBB{481}:
+	testq	%rax, %rax
+	je	.LBB{393}
+	jmp	.LBB{9}
+.LBB{482}:
+	testq	%rax, %rax
+	jns	.LBB{393}
+	jmp	.LBB{9}
+.LBB{415}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{398}
+	jmp	.LBB{399}
+.LBB{436}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{419}
+	jmp	.LBB{144}
+.LBB{457}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{440}
+	jmp	.LBB{144}
+.LBB{478}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{461}
+	jmp	.LBB{144}
+.LBB{483}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	(%rsi), %r9d
+	movzbl	1(%rsi), %r8d
+	addl	$-2, %r9d
+	jmp	.LBB{484}
+.LBB{485}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{484}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{486}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{487}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{488}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{492}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{493}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{494}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{491}
+.LBB{495}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{496}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{497}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{491}
+.LBB{498}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{499}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{501}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{502}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{486}
+.LBB{502}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{503}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{504}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{505}
+.LBB{501}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{506}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{507}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{505}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{508}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{503}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{486}:
+	cmpb	$15, %r8b
+	ja	.LBB{268}
+.LBB{491}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{509}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{510}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{511}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{514}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{515}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{516}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{517}
+	jmp	.LBB{489}
+.LBB{518}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{520}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{521}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{517}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{512}
+.LBB{522}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{523}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{525}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{526}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{509}
+.LBB{526}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{527}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{528}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{529}
+.LBB{525}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{530}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{531}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{529}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{532}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{527}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{509}:
+	cmpb	$16, %r10b
+	jae	.LBB{513}
+.LBB{512}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{533}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{534}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{535}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{537}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{538}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{539}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{540}
+	jmp	.LBB{489}
+.LBB{541}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{542}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{543}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{540}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{536}
+.LBB{544}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{545}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{546}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{547}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{533}
+.LBB{547}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{548}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{549}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{550}
+.LBB{546}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{551}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{552}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{550}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{553}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{548}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{533}:
+	cmpb	$16, %r10b
+	jae	.LBB{513}
+.LBB{536}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	12(%rsi), %r11d
+	movzbl	13(%rsi), %r10d
+	movzwl	14(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{554}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{555}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{556}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{558}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{559}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{560}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{561}
+	jmp	.LBB{489}
+.LBB{562}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{563}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{564}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{561}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{557}
+.LBB{565}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{566}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{567}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{568}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{554}
+.LBB{568}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{569}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{570}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{571}
+.LBB{567}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{572}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{573}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{571}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{574}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{569}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{554}:
+	cmpb	$16, %r10b
+	jae	.LBB{513}
+.LBB{557}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	16(%rsi), %r11d
+	movzbl	17(%rsi), %r10d
+	movzwl	18(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{575}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{576}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{577}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{579}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{580}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{581}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{582}
+	jmp	.LBB{489}
+.LBB{583}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{584}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{585}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{582}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{578}
+.LBB{586}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{587}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{588}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{589}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{575}
+.LBB{589}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{590}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{591}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{592}
+.LBB{588}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{593}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{594}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{592}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{595}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{590}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{575}:
+	cmpb	$16, %r10b
+	jae	.LBB{513}
+.LBB{578}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	20(%rsi), %r11d
+	movzbl	21(%rsi), %r10d
+	movzwl	22(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{596}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{597}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{598}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{600}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{601}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{602}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{603}
+	jmp	.LBB{489}
+.LBB{604}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{605}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{606}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{603}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{599}
+.LBB{607}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{608}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{609}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{610}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{596}
+.LBB{610}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{611}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{612}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{613}
+.LBB{609}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{614}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{615}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{613}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{616}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{611}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{596}:
+	cmpb	$16, %r10b
+	jae	.LBB{513}
+.LBB{599}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	24(%rsi), %r11d
+	movzbl	25(%rsi), %r10d
+	movzwl	26(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{617}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{618}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{619}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{622}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{623}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{624}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{625}
+	jmp	.LBB{489}
+.LBB{626}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{627}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{628}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{625}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{620}
+.LBB{629}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{630}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{631}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{632}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{617}
+.LBB{632}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{633}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{634}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{635}
+.LBB{631}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{636}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{637}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{635}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{638}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{633}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{617}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{620}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	28(%rsi), %r11d
+	movzbl	29(%rsi), %r10d
+	movzwl	30(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{639}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{640}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{641}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{643}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{644}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{645}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{646}
+	jmp	.LBB{489}
+.LBB{647}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{648}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{649}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{489}
+.LBB{646}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{642}
+.LBB{650}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{651}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{524}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{652}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{653}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{639}
+.LBB{653}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{654}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{655}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{656}
+.LBB{652}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{657}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{658}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{500}
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{656}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{659}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{654}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{639}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{642}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{660}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{661}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{662}:
+	testq	%rax, %rax
+	jne	.LBB{484}
+	jmp	.LBB{9}
+.LBB{663}:
+	testq	%rax, %rax
+	je	.LBB{484}
+	jmp	.LBB{9}
+.LBB{664}:
+	testq	%rax, %rax
+	jns	.LBB{484}
+	jmp	.LBB{9}
+.LBB{508}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{491}
+	jmp	.LBB{268}
+.LBB{532}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{512}
+	jmp	.LBB{513}
+.LBB{553}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{536}
+	jmp	.LBB{513}
+.LBB{574}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{557}
+	jmp	.LBB{513}
+.LBB{595}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{578}
+	jmp	.LBB{513}
+.LBB{616}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{599}
+	jmp	.LBB{513}
+.LBB{638}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{620}
+	jmp	.LBB{621}
+.LBB{659}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{642}
+	jmp	.LBB{621}
+.LBB{665}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	movzbl	1(%rsi), %r8d
+	movzbl	(%rsi), %r9d
+	addl	$-2, %r9d
+	jmp	.LBB{666}
+.LBB{667}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{666}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{668}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{669}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{670}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{672}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{673}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{674}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{671}
+.LBB{675}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{676}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{677}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{671}
+.LBB{678}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{303}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{679}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{208}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{680}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{681}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{668}
+.LBB{681}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{682}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{683}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{684}
+.LBB{680}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{685}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{209}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{686}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{297}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{209}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{684}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{687}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{682}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{668}:
+	cmpb	$15, %r8b
+	ja	.LBB{27}
+.LBB{671}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{688}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{689}(%rip), %rbp
+	movslq	(%rbp,%r11,4), %r11
+	addq	%rbp, %r11
+	jmpq	*%r11
+.LBB{690}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{38}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{692}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{25}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{693}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{38}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{694}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{695}
+	jmp	.LBB{297}
+.LBB{696}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{303}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{697}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{38}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{698}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{297}
+.LBB{695}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{691}
+.LBB{699}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{700}:
+	movzbl	%al, %r14d
+	cmpb	$15, %dl
+	ja	.LBB{701}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{702}
+	movq	16(%rsp,%r14,8), %rcx
+	movabsq	$-9223372036854775808, %r14
+	cmpq	%r14, %rcx
+	jne	.LBB{703}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{688}
+.LBB{703}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{704}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{705}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{38}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{706}
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{707}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{25}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{708}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{38}
+	cmpl	$4095, %eax
+	ja	.LBB{25}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{706}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{709}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{702}:
+	xorl	%eax, %eax
+	movabsq	$-9223372036854775808, %r14
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{704}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{688}:
+	cmpb	$16, %r10b
+	jae	.LBB{320}
+.LBB{691}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{710}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{711}:
+	testq	%rax, %rax
+	jne	.LBB{666}
+	jmp	.LBB{9}
+.LBB{712}:
+	testq	%rax, %rax
+	je	.LBB{666}
+	jmp	.LBB{9}
+.LBB{713}:
+	testq	%rax, %rax
+	jns	.LBB{666}
+	jmp	.LBB{9}
+.LBB{687}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{671}
+	jmp	.LBB{27}
+.LBB{709}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{691}
+	jmp	.LBB{320}
+.LBB{714}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	(%rsi), %r9d
+	movzbl	1(%rsi), %r8d
+	addl	$-2, %r9d
+	jmp	.LBB{715}
+.LBB{716}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{715}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{717}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{718}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{719}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{723}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{724}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{725}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{722}
+.LBB{726}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{728}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{729}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{722}
+.LBB{730}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{731}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{733}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{734}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{717}
+.LBB{734}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{735}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{736}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{737}
+.LBB{733}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{738}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{739}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{737}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{740}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{735}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{717}:
+	cmpb	$15, %r8b
+	ja	.LBB{121}
+.LBB{722}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{741}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{742}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{743}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{746}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{747}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{748}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{749}
+	jmp	.LBB{720}
+.LBB{750}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{751}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{752}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{749}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{744}
+.LBB{753}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{754}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{756}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{757}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{741}
+.LBB{757}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{758}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{759}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{760}
+.LBB{756}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{761}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{762}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{760}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{763}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{758}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{741}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{744}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{764}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{765}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{766}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{768}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{769}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{770}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{771}
+	jmp	.LBB{720}
+.LBB{772}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{773}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{774}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{771}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{767}
+.LBB{775}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{776}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{777}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{778}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{764}
+.LBB{778}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{779}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{780}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{781}
+.LBB{777}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{782}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{783}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{781}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{784}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{779}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{764}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{767}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	12(%rsi), %r11d
+	movzbl	13(%rsi), %r10d
+	movzwl	14(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{785}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{786}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{787}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{789}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{790}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{791}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{792}
+	jmp	.LBB{118}
+.LBB{793}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{794}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{795}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{792}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{788}
+.LBB{796}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{797}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{798}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{799}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{785}
+.LBB{799}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{800}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{801}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{802}
+.LBB{798}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{803}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{804}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{802}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{805}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{800}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{785}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{788}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	16(%rsi), %r11d
+	movzbl	17(%rsi), %r10d
+	movzwl	18(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{806}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{807}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{808}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{810}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{811}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{812}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{813}
+	jmp	.LBB{118}
+.LBB{814}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{815}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{816}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{813}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{809}
+.LBB{817}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{818}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{819}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{820}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{806}
+.LBB{820}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{821}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{822}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{823}
+.LBB{819}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{824}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{825}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{823}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{826}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{821}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{806}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{809}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	20(%rsi), %r11d
+	movzbl	21(%rsi), %r10d
+	movzwl	22(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{827}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{828}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{829}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{831}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{832}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{833}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{834}
+	jmp	.LBB{118}
+.LBB{835}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{836}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{837}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{118}
+.LBB{834}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{830}
+.LBB{838}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{126}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{839}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{154}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{840}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{841}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{827}
+.LBB{841}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{842}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{843}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{844}
+.LBB{840}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{845}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{119}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{846}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{131}
+	cmpl	$4095, %eax
+	ja	.LBB{119}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{844}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{847}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{842}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{827}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{830}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{848}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{849}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{850}:
+	testq	%rax, %rax
+	jne	.LBB{715}
+	jmp	.LBB{9}
+.LBB{851}:
+	testq	%rax, %rax
+	je	.LBB{715}
+	jmp	.LBB{9}
+.LBB{852}:
+	testq	%rax, %rax
+	jns	.LBB{715}
+	jmp	.LBB{9}
+.LBB{740}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{722}
+	jmp	.LBB{121}
+.LBB{763}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{744}
+	jmp	.LBB{745}
+.LBB{784}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{767}
+	jmp	.LBB{745}
+.LBB{805}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{788}
+	jmp	.LBB{745}
+.LBB{826}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{809}
+	jmp	.LBB{745}
+.LBB{847}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{830}
+	jmp	.LBB{745}
+.LBB{853}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{854}
+.LBB{855}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{854}:
+	movl	$2, %r9d
+	leaq	.LJTI{856}(%rip), %rbp
+	jmp	.LBB{857}
+.LBB{858}:
+	xorl	%eax, %eax
+.LBB{859}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$58, %r9
+	je	.LBB{860}
+.LBB{857}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{859}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{861}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{859}
+.LBB{862}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{859}
+.LBB{863}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{859}
+.LBB{864}:
+	movl	$1, %eax
+	jmp	.LBB{865}
+.LBB{866}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{859}
+.LBB{867}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{859}
+.LBB{868}:
+	movq	$-1, %rax
+.LBB{865}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$58, %r9
+	jne	.LBB{857}
+	jmp	.LBB{860}
+.LBB{869}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{859}
+.LBB{870}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{858}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{871}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{859}
+.LBB{871}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{872}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{859}
+.LBB{873}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{858}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{874}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{859}
+.LBB{875}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{859}
+.LBB{876}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{859}
+.LBB{872}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{859}
+.LBB{874}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{859}
+.LBB{860}:
+	cmpl	$16, %edi
+	jae	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{877}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{878}:
+	testq	%rax, %rax
+	jne	.LBB{854}
+	jmp	.LBB{9}
+.LBB{879}:
+	testq	%rax, %rax
+	je	.LBB{854}
+	jmp	.LBB{9}
+.LBB{880}:
+	testq	%rax, %rax
+	jns	.LBB{854}
+	jmp	.LBB{9}
+.LBB{881}:
+	movl	80(%rsi), %eax
+	movl	%eax, %ebp
+	movzbl	%ah, %edi
+	movzbl	(%rsi), %r9d
+	movzbl	1(%rsi), %r8d
+	addl	$-2, %r9d
+	jmp	.LBB{882}
+.LBB{883}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{882}:
+	movzwl	2(%rsi), %ecx
+	movl	%ecx, %eax
+	cmpl	$29, %r9d
+	ja	.LBB{884}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{885}(%rip), %r11
+	movslq	(%r11,%r9,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{886}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{720}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{889}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{490}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{890}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{891}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	incq	%rax
+	jmp	.LBB{887}
+.LBB{892}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{519}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{893}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{894}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	movq	16(%rsp,%r8,8), %rax
+	decq	%rax
+	jmp	.LBB{887}
+.LBB{895}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{519}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{896}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{500}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{897}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{898}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{884}
+.LBB{898}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{899}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{900}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{901}
+.LBB{897}:
+	xorl	%eax, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{902}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{490}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{903}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{489}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{901}:
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{904}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{899}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+.LBB{884}:
+	cmpb	$15, %r8b
+	ja	.LBB{888}
+.LBB{887}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movzbl	4(%rsi), %r11d
+	movzbl	5(%rsi), %r10d
+	movzwl	6(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{905}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{906}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{907}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{909}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{910}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{911}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{912}
+	jmp	.LBB{720}
+.LBB{913}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{914}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{915}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{912}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{908}
+.LBB{916}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{917}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{918}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{919}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{905}
+.LBB{919}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{920}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{921}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{922}
+.LBB{918}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{923}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{924}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{922}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{925}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{920}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{905}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{908}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	8(%rsi), %r11d
+	movzbl	9(%rsi), %r10d
+	movzwl	10(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{926}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{927}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{928}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{930}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{931}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{932}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{933}
+	jmp	.LBB{720}
+.LBB{934}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{935}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{936}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{933}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{929}
+.LBB{937}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{938}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{939}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{940}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{926}
+.LBB{940}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{941}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{942}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{943}
+.LBB{939}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{944}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{945}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{943}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{946}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{941}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{926}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{929}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	12(%rsi), %r11d
+	movzbl	13(%rsi), %r10d
+	movzwl	14(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{947}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{948}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{949}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{951}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{952}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{953}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{954}
+	jmp	.LBB{720}
+.LBB{955}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{956}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{957}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{954}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{950}
+.LBB{958}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{959}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{960}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{961}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{947}
+.LBB{961}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{962}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{963}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{964}
+.LBB{960}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{965}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{966}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{964}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{967}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{962}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{947}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{950}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	16(%rsi), %r11d
+	movzbl	17(%rsi), %r10d
+	movzwl	18(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{968}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{969}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{970}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{972}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{973}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{974}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{975}
+	jmp	.LBB{720}
+.LBB{976}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{977}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{978}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{975}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{971}
+.LBB{979}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{980}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{981}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{982}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{968}
+.LBB{982}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{983}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{984}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{985}
+.LBB{981}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{986}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{987}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{985}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{988}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{983}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{968}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{971}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	20(%rsi), %r11d
+	movzbl	21(%rsi), %r10d
+	movzwl	22(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{989}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{990}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{991}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{993}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{994}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{995}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{996}
+	jmp	.LBB{720}
+.LBB{997}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{998}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{999}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{996}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{992}
+.LBB{1000}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1001}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{1002}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{1003}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{989}
+.LBB{1003}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{1004}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1005}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{1006}
+.LBB{1002}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1007}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1008}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1006}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1009}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1004}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{989}:
+	cmpb	$16, %r10b
+	jae	.LBB{621}
+.LBB{992}:
+	movq	%rax, 16(%rsp,%r10,8)
+	movzbl	24(%rsi), %r11d
+	movzbl	25(%rsi), %r10d
+	movzwl	26(%rsi), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r11d
+	cmpl	$29, %r11d
+	ja	.LBB{1010}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	leaq	.LJTI{1011}(%rip), %rbx
+	movslq	(%rbx,%r11,4), %r11
+	addq	%rbx, %r11
+	jmpq	*%r11
+.LBB{1012}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1014}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1015}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r11,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1016}:
+	movl	$1, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{1017}
+	jmp	.LBB{720}
+.LBB{1018}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1019}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1020}:
+	movq	$-1, %rax
+	cmpb	$16, %r10b
+	jae	.LBB{720}
+.LBB{1017}:
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{1013}
+.LBB{1021}:
+	movzbl	%al, %eax
+	cmpb	$15, %dl
+	ja	.LBB{727}
+	movq	16(%rsp,%rax,8), %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1022}:
+	movzbl	%al, %ebx
+	cmpb	$15, %dl
+	ja	.LBB{755}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %r11
+	testq	%r11, %r11
+	je	.LBB{1023}
+	movq	16(%rsp,%rbx,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{1024}
+	movq	%r14, %rax
+	cmpq	$-1, %r11
+	je	.LBB{1010}
+.LBB{1024}:
+	movq	%rcx, %rax
+	orq	%r11, %rax
+	shrq	$32, %rax
+	je	.LBB{1025}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r11
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1026}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	jbe	.LBB{1027}
+.LBB{1023}:
+	xorl	%eax, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1028}:
+	movzbl	%al, %ecx
+	cmpb	$15, %dl
+	ja	.LBB{721}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1029}:
+	movzbl	%al, %r11d
+	cmpb	$15, %dl
+	ja	.LBB{732}
+	cmpl	$4095, %eax
+	ja	.LBB{721}
+	movq	16(%rsp,%r11,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1027}:
+	movq	16(%rsp,%r11,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1030}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1025}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r11d
+.LBB{1010}:
+	cmpb	$16, %r10b
+	jae	.LBB{745}
+.LBB{1013}:
+	movq	%rax, 16(%rsp,%r10,8)
+	cmpl	$15, %edi
+	ja	.LBB{848}
+	movl	%ebp, %eax
+	movzbl	%al, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{1031}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{1032}:
+	testq	%rax, %rax
+	jne	.LBB{882}
+	jmp	.LBB{9}
+.LBB{1033}:
+	testq	%rax, %rax
+	je	.LBB{882}
+	jmp	.LBB{9}
+.LBB{1034}:
+	testq	%rax, %rax
+	jns	.LBB{882}
+	jmp	.LBB{9}
+.LBB{904}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$15, %r8b
+	jbe	.LBB{887}
+	jmp	.LBB{888}
+.LBB{925}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{908}
+	jmp	.LBB{621}
+.LBB{946}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{929}
+	jmp	.LBB{621}
+.LBB{967}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{950}
+	jmp	.LBB{621}
+.LBB{988}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{971}
+	jmp	.LBB{621}
+.LBB{1009}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{992}
+	jmp	.LBB{621}
+.LBB{1030}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	cmpb	$16, %r10b
+	jb	.LBB{1013}
+	jmp	.LBB{745}
+.LBB{1035}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{1036}
+.LBB{1037}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{1036}:
+	movl	$2, %r9d
+	leaq	.LJTI{1038}(%rip), %rbp
+	jmp	.LBB{1039}
+.LBB{1040}:
+	xorl	%eax, %eax
+.LBB{1041}:
+	cmpb	$16, %r8b
+	jae	.LBB{58}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$46, %r9
+	je	.LBB{1042}
+.LBB{1039}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{1041}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{1043}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{1041}
+.LBB{1044}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1041}
+.LBB{1045}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{1041}
+.LBB{1046}:
+	movl	$1, %eax
+	jmp	.LBB{1047}
+.LBB{1048}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{1041}
+.LBB{1049}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1041}
+.LBB{1050}:
+	movq	$-1, %rax
+.LBB{1047}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$46, %r9
+	jne	.LBB{1039}
+	jmp	.LBB{1042}
+.LBB{1051}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1041}
+.LBB{1052}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{1040}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{1053}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{1041}
+.LBB{1053}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{1054}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{1041}
+.LBB{1055}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{1040}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1056}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{1041}
+.LBB{1057}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{1041}
+.LBB{1058}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1041}
+.LBB{1054}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{1041}
+.LBB{1056}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{1041}
+.LBB{1042}:
+	cmpl	$16, %edi
+	jae	.LBB{47}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{1059}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{1060}:
+	testq	%rax, %rax
+	jne	.LBB{1036}
+	jmp	.LBB{9}
+.LBB{1061}:
+	testq	%rax, %rax
+	je	.LBB{1036}
+	jmp	.LBB{9}
+.LBB{1062}:
+	testq	%rax, %rax
+	jns	.LBB{1036}
+	jmp	.LBB{9}
+.LBB{1063}:
+	movl	80(%rsi), %ebx
+	movzbl	%bh, %edi
+	jmp	.LBB{1064}
+.LBB{1065}:
+	testq	%rax, %rax
+	jns	.LBB{9}
+.LBB{1064}:
+	movl	$2, %r9d
+	leaq	.LJTI{1066}(%rip), %rbp
+	jmp	.LBB{1067}
+.LBB{1068}:
+	xorl	%eax, %eax
+.LBB{1069}:
+	cmpb	$16, %r8b
+	jae	.LBB{268}
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$38, %r9
+	je	.LBB{1070}
+.LBB{1067}:
+	movzbl	-2(%rsi,%r9), %r10d
+	movzbl	-1(%rsi,%r9), %r8d
+	movzwl	(%rsi,%r9), %ecx
+	movl	%ecx, %eax
+	addl	$-2, %r10d
+	cmpl	$29, %r10d
+	ja	.LBB{1069}
+	movl	%ecx, %edx
+	shrl	$8, %ecx
+	movslq	(%rbp,%r10,4), %r10
+	addq	%rbp, %r10
+	jmpq	*%r10
+.LBB{1071}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	addq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{1069}
+.LBB{1072}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$64, %eax
+	rep		bsfq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1069}
+.LBB{1073}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%r10,8), %rax
+	jmp	.LBB{1069}
+.LBB{1074}:
+	movl	$1, %eax
+	jmp	.LBB{1075}
+.LBB{1076}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	%rax
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rax
+	subq	%rax, %rcx
+	movq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rax
+	shrq	$2, %rcx
+	andq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	%rcx, %rax
+	shrq	$4, %rax
+	addq	%rcx, %rax
+	movabsq	$1085102592571150095, %rcx
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rcx, %rax
+	shrq	$56, %rax
+	jmp	.LBB{1069}
+.LBB{1077}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1069}
+.LBB{1078}:
+	movq	$-1, %rax
+.LBB{1075}:
+	cmpb	$16, %r8b
+	jae	.LBB{31}
+	addq	16(%rsp,%r8,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	addq	$4, %r9
+	cmpq	$38, %r9
+	jne	.LBB{1067}
+	jmp	.LBB{1070}
+.LBB{1079}:
+	movzbl	%al, %eax
+	cmpb	$16, %dl
+	jae	.LBB{68}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1069}
+.LBB{1080}:
+	movzbl	%al, %r11d
+	cmpb	$16, %dl
+	jae	.LBB{73}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %r10
+	testq	%r10, %r10
+	je	.LBB{1068}
+	movq	16(%rsp,%r11,8), %rcx
+	cmpq	%r14, %rcx
+	jne	.LBB{1081}
+	movq	%r14, %rax
+	cmpq	$-1, %r10
+	je	.LBB{1069}
+.LBB{1081}:
+	movq	%rcx, %rax
+	orq	%r10, %rax
+	shrq	$32, %rax
+	je	.LBB{1082}
+	movq	%rcx, %rax
+	cqto
+	idivq	%r10
+	jmp	.LBB{1069}
+.LBB{1083}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-3, %rax
+	ja	.LBB{1068}
+	movq	16(%rsp,%r10,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1084}
+	cqto
+	idivq	%rcx
+	movq	%rdx, %rax
+	jmp	.LBB{1069}
+.LBB{1085}:
+	movzbl	%al, %ecx
+	cmpb	$16, %dl
+	jae	.LBB{62}
+	movl	$127, %eax
+	bsrq	16(%rsp,%rcx,8), %rax
+	xorq	$63, %rax
+	jmp	.LBB{1069}
+.LBB{1086}:
+	movzbl	%al, %r10d
+	cmpb	$16, %dl
+	jae	.LBB{61}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %eax
+	ja	.LBB{62}
+	movq	16(%rsp,%r10,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1069}
+.LBB{1082}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%r10d
+	jmp	.LBB{1069}
+.LBB{1084}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movl	%edx, %eax
+	jmp	.LBB{1069}
+.LBB{1070}:
+	cmpl	$16, %edi
+	jae	.LBB{286}
+	movzbl	%bl, %ecx
+	addl	$-9, %ecx
+	cmpl	$19, %ecx
+	ja	.LBB{9}
+	movq	16(%rsp,%rdi,8), %rax
+	leaq	.LJTI{1087}(%rip), %rdx
+	movslq	(%rdx,%rcx,4), %rcx
+	addq	%rdx, %rcx
+	jmpq	*%rcx
+.LBB{1088}:
+	testq	%rax, %rax
+	jne	.LBB{1064}
+	jmp	.LBB{9}
+.LBB{1089}:
+	testq	%rax, %rax
+	je	.LBB{1064}
+	jmp	.LBB{9}
+.LBB{1090}:
+	testq	%rax, %rax
+	jns	.LBB{1064}
+	jmp	.LBB{9}
+.LBB{1091}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	cmovsq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1092}:
+	leaq	.Lanon{1093}(%rip), %rcx
+	cmpb	$16, %dil
+	jae	.LBB{1094}
+	movzbl	%bl, %edx
+	cmpb	$15, %bl
+	ja	.LBB{1095}
+	movzwl	%ax, %esi
+	cmpl	$4095, %ebx
+	ja	.LBB{1096}
+	movq	16(%rsp,%rdx,8), %rax
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	168(%rsp), %r9
+	movq	184(%rsp), %r10
+	movq	176(%rsp), %r11
+	jb	.LBB{16}
+	cmpq	%r9, %rcx
+	ja	.LBB{16}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r9, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{16}
+	testq	%r10, %r10
+	je	.LBB{1097}
+	cmpq	%rsi, %rcx
+	jae	.LBB{1097}
+	movq	%rdx, %rsi
+	movq	%rcx, %rdi
+	jmp	.LBB{1098}
+.LBB{1099}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1097}
+.LBB{1098}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r10, %r8
+	jae	.LBB{1099}
+	movq	16(%r11,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{1099}
+	jmp	.LBB{16}
+.LBB{1100}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{1093}(%rip), %rcx
+	cmpb	$16, %bl
+	jae	.LBB{1095}
+	movzwl	%ax, %eax
+	addq	16(%rsp,%rdx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{16}
+	cmpb	$15, %dil
+	ja	.LBB{1094}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1101}
+.LBB{1102}:
+	cmpb	$16, %dil
+	jae	.LBB{1103}
+	addq	$2, %rcx
+	decq	16(%rsp,%rdi,8)
+	cmoveq	%rcx, %rbx
+	jmp	.LBB{8}
+.LBB{1104}:
+	leaq	.Lanon{1093}(%rip), %rcx
+	cmpb	$16, %dil
+	jae	.LBB{1094}
+	movzbl	%bl, %esi
+	cmpb	$15, %bl
+	ja	.LBB{1096}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{1105}
+	movq	16(%rsp,%rdi,8), %rdx
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rdi
+	addq	%rdx, %rdi
+	movq	168(%rsp), %rcx
+	movq	184(%rsp), %r9
+	movq	176(%rsp), %r10
+	jb	.LBB{16}
+	cmpq	%rcx, %rdi
+	ja	.LBB{16}
+	movq	16(%rsp,%rsi,8), %rcx
+	testq	%r9, %r9
+	je	.LBB{1106}
+	cmpq	%rdi, %rdx
+	jae	.LBB{1106}
+	movq	%rax, %rsi
+	movq	%rdx, %rdi
+	jmp	.LBB{1107}
+.LBB{1108}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1106}
+.LBB{1107}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r9, %r8
+	jae	.LBB{1108}
+	movq	16(%r10,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{1108}
+	jmp	.LBB{16}
+.LBB{1109}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{16}
+	movq	16(%rsp,%rdi,8), %r13
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1110}
+.Ltmp{1111}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1112}:
+.LBB{1110}:
+	movq	224(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{1113}
+.LBB{1114}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	cmpb	$15, %dil
+	ja	.LBB{1115}
+	movq	16(%rsp,%r8,8), %rcx
+	subq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{1116}
+.LBB{1117}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	cmpb	$15, %dil
+	ja	.LBB{1115}
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%r8,8), %rax
+	jmp	.LBB{1118}
+.LBB{1119}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	movq	%rbx, 16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1120}:
+	cmpb	$16, %dil
+	jae	.LBB{1103}
+	decq	16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1121}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{5}(%rip), %rax
+	cmpb	$16, %bl
+	jae	.LBB{1122}
+	cmpb	$15, %dil
+	ja	.LBB{1123}
+	movq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1118}
+.LBB{1124}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{5}(%rip), %rcx
+	cmpb	$16, %bl
+	jae	.LBB{1105}
+	cmpb	$15, %dil
+	ja	.LBB{1094}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	jmp	.LBB{1116}
+.LBB{1125}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{16}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	movq	144(%rsp), %r13
+	jmp	.LBB{8}
+.LBB{1126}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	cmpb	$15, %dil
+	ja	.LBB{1115}
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%r8,8), %rax
+	jmp	.LBB{1118}
+.LBB{1127}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	movq	16(%rsp,%rax,8), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1128}
+	movq	16(%rsp,%r8,8), %rcx
+	movabsq	$-9223372036854775808, %rax
+	cmpq	%rax, %rcx
+	jne	.LBB{1129}
+	cmpq	$-1, %rsi
+	je	.LBB{1130}
+.LBB{1129}:
+	movq	%rcx, %rax
+	orq	%rsi, %rax
+	shrq	$32, %rax
+	je	.LBB{1131}
+	movq	%rcx, %rax
+	cqto
+	idivq	%rsi
+	cmpb	$15, %dil
+	jbe	.LBB{1118}
+	jmp	.LBB{1115}
+.LBB{1132}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	cmovneq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1133}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	movq	16(%rsp,%rax,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1134}
+	xorl	%edx, %edx
+	cmpb	$16, %dil
+	jb	.LBB{1135}
+	jmp	.LBB{1115}
+.LBB{1136}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{16}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{1137}
+.Ltmp{1138}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1139}:
+.LBB{1137}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+	movq	144(%rsp), %r13
+	jmp	.LBB{8}
+.LBB{1140}:
+	cmpb	$16, %dil
+	jae	.LBB{1103}
+	incq	16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1141}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{16}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpb	$15, %dil
+	ja	.LBB{19}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{1101}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	jmp	.LBB{1113}
+.LBB{1142}:
+	movzbl	%bl, %r8d
+	cmpb	$15, %bl
+	ja	.LBB{31}
+	movzwl	%ax, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{68}
+	cmpb	$15, %dil
+	ja	.LBB{1115}
+	movq	16(%rsp,%r8,8), %rcx
+	cmpq	16(%rsp,%rax,8), %rcx
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1118}
+.LBB{1143}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{1093}(%rip), %rcx
+	cmpb	$16, %bl
+	jae	.LBB{1095}
+	movzwl	%ax, %eax
+	addq	16(%rsp,%rdx,8), %rax
+	movq	%rax, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	movq	168(%rsp), %rsi
+	jae	.LBB{1144}
+	movq	176(%rsp), %r8
+	movq	16(%r8,%rdx,8), %rdx
+	btq	%rax, %rdx
+	jb	.LBB{16}
+.LBB{1144}:
+	cmpq	%rsi, %rax
+	jae	.LBB{16}
+	cmpb	$15, %dil
+	ja	.LBB{1094}
+	movq	16(%rsp,%rdi,8), %rcx
+	movq	160(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+.LBB{1113}:
+	movq	%r14, %rbx
+	movq	144(%rsp), %r13
+	jmp	.LBB{8}
+.LBB{1145}:
+	movl	%eax, %edx
+	andb	$15, %dl
+	movl	%ebx, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rsi,8), %rdx
+	movl	%edi, %esi
+	andl	$15, %esi
+	movq	%rdx, 16(%rsp,%rsi,8)
+	shrb	$4, %al
+	shrb	$4, %bl
+	movzbl	%bl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %dil
+	movzbl	%dil, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	jmp	.LBB{1146}
+.LBB{1147}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	cmpq	$0, 16(%rsp,%rdi,8)
+	cmovnsq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1148}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{5}(%rip), %rax
+	cmpb	$16, %bl
+	jae	.LBB{1122}
+	cmpb	$15, %dil
+	ja	.LBB{1123}
+	movq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	movabsq	$72340172838076673, %rax
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1116}
+.LBB{1149}:
+	movl	%eax, %edx
+	andb	$15, %dl
+	movl	%ebx, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	imulq	16(%rsp,%rsi,8), %rdx
+	movl	%edi, %esi
+	andl	$15, %esi
+	movq	%rdx, 16(%rsp,%rsi,8)
+	shrb	$4, %al
+	shrb	$4, %bl
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	movzbl	%al, %eax
+	subq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %dil
+	movzbl	%dil, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1146}:
+	addq	$2, %rcx
+	movq	%rcx, %rbx
+	jmp	.LBB{8}
+.LBB{1150}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{5}(%rip), %rcx
+	cmpb	$16, %bl
+	jae	.LBB{1105}
+	cmpb	$15, %dil
+	ja	.LBB{1094}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+.LBB{1116}:
+	movq	%rcx, 16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1106}:
+	movq	%r14, %rbx
+	testq	%rax, %rax
+	je	.LBB{8}
+	movq	160(%rsp), %rsi
+	leaq	(%rsi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1151}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1151}
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1134}:
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1152}
+	cqto
+	idivq	%rcx
+	cmpb	$16, %dil
+	jb	.LBB{1135}
+	jmp	.LBB{1115}
+.LBB{1097}:
+	movq	%r9, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{1153}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1128}:
+	xorl	%eax, %eax
+.LBB{1130}:
+	cmpb	$15, %dil
+	ja	.LBB{1115}
+.LBB{1118}:
+	movq	%rax, 16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1131}:
+	movl	%ecx, %eax
+	xorl	%edx, %edx
+	divl	%esi
+	cmpb	$15, %dil
+	jbe	.LBB{1118}
+	jmp	.LBB{1115}
+.LBB{1152}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$16, %dil
+	jae	.LBB{1115}
+.LBB{1135}:
+	movq	%rdx, 16(%rsp,%rdi,8)
+	movq	%r14, %rbx
+	jmp	.LBB{8}
+.LBB{1154}:
+	cmpb	$16, %dil
+	jae	.LBB{19}
+	movq	16(%rsp,%rdi,8), %rbp
+.LBB{16}:
+	movq	336(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1155}
+	movq	344(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1155}:
+	movq	360(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{1156}
+	leaq	360(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{1156}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1157}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1157}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1158}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1158}:
+	movq	%rbp, %rax
+	addq	$392, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{58}:
+	leaq	.Lanon{1159}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{62}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{61}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{68}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{73}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{12}:
+.Ltmp{1160}:
+	leaq	.Lanon{1161}(%rip), %rdi
+	leaq	.Lanon{1162}(%rip), %rdx
+	movl	$199, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{1163}:
+	jmp	.LBB{1164}
+.LBB{1}:
+	movq	%rdx, 16(%rsp)
+	leaq	16(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{1165}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{1166}(%rip), %rdi
+	leaq	.Lanon{1167}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{47}:
+	movq	%rdi, %r8
+.LBB{1168}:
+	leaq	.Lanon{1169}(%rip), %rax
+	jmp	.LBB{1170}
+.LBB{144}:
+	movq	%r10, %r8
+.LBB{399}:
+	leaq	.Lanon{1159}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{621}:
+	movq	%r10, %r8
+.LBB{888}:
+	leaq	.Lanon{1159}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{209}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{721}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{490}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{119}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{745}:
+	movq	%r10, %r8
+.LBB{121}:
+	leaq	.Lanon{1159}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{513}:
+	movq	%r10, %r8
+.LBB{268}:
+	leaq	.Lanon{1159}(%rip), %rax
+.LBB{1170}:
+	movq	%rax, 8(%rsp)
+.LBB{31}:
+.Ltmp{1171}:
+	movq	%r8, %rdi
+	movl	$16, %esi
+	movq	8(%rsp), %rdx
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1172}:
+	jmp	.LBB{1164}
+.LBB{1153}:
+.Ltmp{1173}:
+	leaq	.Lanon{1174}(%rip), %rdi
+	leaq	.Lanon{1175}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{1176}:
+	jmp	.LBB{1164}
+.LBB{131}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{500}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{732}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{320}:
+	movq	%r10, %r8
+.LBB{27}:
+	leaq	.Lanon{1159}(%rip), %rax
+	jmp	.LBB{1170}
+.LBB{208}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{297}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{228}:
+	movq	%rdi, %r8
+	leaq	.Lanon{1169}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{848}:
+	movq	%rdi, %r8
+	leaq	.Lanon{1169}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{286}:
+	movq	%rdi, %r8
+	leaq	.Lanon{1169}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{31}
+.LBB{720}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{489}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{118}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{303}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{126}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{727}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{25}:
+	movq	%rcx, %r8
+	jmp	.LBB{31}
+.LBB{519}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{660}:
+	movq	%rdi, %r8
+	leaq	.Lanon{1169}(%rip), %rax
+	jmp	.LBB{1170}
+.LBB{387}:
+	movq	%rbp, %r8
+	jmp	.LBB{1168}
+.LBB{154}:
+	movq	%rbx, %r8
+	jmp	.LBB{31}
+.LBB{755}:
+	movq	%rbx, %r8
+	jmp	.LBB{31}
+.LBB{524}:
+	movq	%rbx, %r8
+	jmp	.LBB{31}
+.LBB{11}:
+	movq	%rbx, %rdi
+	jmp	.LBB{1177}
+.LBB{38}:
+	movq	%r11, %r8
+	jmp	.LBB{31}
+.LBB{24}:
+	movq	%r10, %r8
+	jmp	.LBB{31}
+.LBB{330}:
+	movq	%rbx, %r8
+	jmp	.LBB{31}
+.LBB{33}:
+	movq	%rax, %r8
+	jmp	.LBB{31}
+.LBB{19}:
+	leaq	.Lanon{1093}(%rip), %rax
+	jmp	.LBB{1178}
+.LBB{1115}:
+	movq	%rdi, %r8
+	jmp	.LBB{31}
+.LBB{701}:
+	movq	%r14, %r8
+	jmp	.LBB{31}
+.LBB{1105}:
+	movq	%rax, %rdi
+	jmp	.LBB{1094}
+.LBB{1103}:
+	leaq	.Lanon{5}(%rip), %rax
+.LBB{1178}:
+	movq	%rax, 152(%rsp)
+	movl	$16, %eax
+	movq	%rax, 144(%rsp)
+	jmp	.LBB{1177}
+.LBB{1095}:
+	movq	%rdx, %rdi
+	jmp	.LBB{1094}
+.LBB{1122}:
+	movq	%rcx, %rdi
+.LBB{1123}:
+	movl	$16, %ecx
+	movq	%rcx, 144(%rsp)
+	movq	%rax, 152(%rsp)
+	jmp	.LBB{1177}
+.LBB{1096}:
+	movq	%rsi, %rdi
+.LBB{1094}:
+	movl	$16, %eax
+	movq	%rax, 144(%rsp)
+	movq	%rcx, 152(%rsp)
+.LBB{1177}:
+.Ltmp{1179}:
+	movq	144(%rsp), %rsi
+	movq	152(%rsp), %rdx
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1180}:
+.LBB{1164}:
+	ud2
+.LBB{1181}:
+.Ltmp{1182}:
+	jmp	.LBB{1183}
+.LBB{1184}:
+.Ltmp{1185}:
+	movq	%rax, %r14
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1186}
+	jmp	.LBB{1187}
+.LBB{1188}:
+.Ltmp{1189}:
+.LBB{1183}:
+	movq	%rax, %r14
+	movq	336(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{1190}
+	movq	360(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{1191}
+.LBB{1192}:
+	leaq	360(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1186}
+.LBB{1187}:
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1193}
+.LBB{1194}:
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	%r14, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{1190}:
+	movq	344(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	360(%rsp), %rax
+	lock		decq	(%rax)
+	je	.LBB{1192}
+.LBB{1191}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{1187}
+.LBB{1186}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{1194}
+.LBB{1193}:
+	movq	%r14, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,690 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	8(%rdi), %rbp
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	shlq	$3, %rdx
+	leaq	176(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	176(%rsp), %xmm0
+	movaps	192(%rsp), %xmm1
+	movaps	208(%rsp), %xmm2
+	movaps	224(%rsp), %xmm3
+	movaps	%xmm0, 32(%rsp)
+	movaps	%xmm1, 48(%rsp)
+	movaps	%xmm2, 64(%rsp)
+	movaps	%xmm3, 80(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 144(%rsp)
+	movq	$0, 176(%rsp)
+	movq	$8, 184(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 192(%rsp)
+	movq	$8, 208(%rsp)
+	movq	$0, 216(%rsp)
+	addq	$24, %rbx
+.Ltmp{2}:
+	leaq	304(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	312(%rsp), %rax
+	movq	%rax, 8(%rsp)
+	movq	320(%rsp), %rax
+	movq	%rax, 24(%rsp)
+	movq	336(%rsp), %rax
+	movq	%rax, 168(%rsp)
+	movq	328(%rsp), %rax
+	movq	%rax, 16(%rsp)
+	xorl	%ebx, %ebx
+	leaq	.LJTI{4}(%rip), %r14
+	movq	$-1, %r12
+	jmp	.LBB{5}
+.LBB{6}:
+	xorl	%eax, %eax
+.LBB{7}:
+	cmpb	$15, %dil
+	ja	.LBB{8}
+.LBB{9}:
+	movq	%rax, 32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+.LBB{5}:
+	movzbl	(%rbp,%rbx,4), %edx
+	cmpq	$31, %rdx
+	ja	.LBB{10}
+	movq	%rbx, %rax
+	movzwl	2(%rbp,%rbx,4), %ebx
+	movl	%ebx, %ecx
+	shrl	$8, %ecx
+	leaq	1(%rax), %r13
+	movzbl	1(%rbp,%rax,4), %edi
+	movslq	(%r14,%rdx,4), %rdx
+	addq	%r14, %rdx
+	jmpq	*%rdx
+.LBB{11}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	cmoveq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{13}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	cmovsq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{14}:
+	leaq	.Lanon{15}(%rip), %rdx
+	cmpb	$16, %dil
+	jae	.LBB{16}
+	movzbl	%bl, %esi
+	cmpb	$15, %bl
+	ja	.LBB{17}
+	movzwl	%cx, %eax
+	cmpl	$4095, %ebx
+	ja	.LBB{18}
+	movq	32(%rsp,%rdi,8), %rdx
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rdi
+	addq	%rdx, %rdi
+	movq	24(%rsp), %rcx
+	movq	168(%rsp), %r10
+	jb	.LBB{10}
+	cmpq	%rcx, %rdi
+	ja	.LBB{10}
+	movq	32(%rsp,%rsi,8), %rcx
+	testq	%r10, %r10
+	movq	16(%rsp), %r9
+	je	.LBB{19}
+	cmpq	%rdi, %rdx
+	jae	.LBB{19}
+	movq	%rax, %rsi
+	movq	%rdx, %rdi
+	jmp	.LBB{20}
+.LBB{21}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{19}
+.LBB{20}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r10, %r8
+	jae	.LBB{21}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{21}
+	jmp	.LBB{10}
+.LBB{22}:
+	movzbl	%bl, %esi
+	leaq	.Lanon{15}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{17}
+	movzwl	%cx, %eax
+	addq	32(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	168(%rsp), %rcx
+	movq	24(%rsp), %r8
+	jae	.LBB{23}
+	movq	16(%rsp), %rsi
+	movq	16(%rsi,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{10}
+.LBB{23}:
+	cmpq	%r8, %rax
+	jae	.LBB{10}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	8(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{24}:
+	cmpb	$16, %dil
+	jae	.LBB{25}
+	addq	$2, %rax
+	decq	32(%rsp,%rdi,8)
+	cmoveq	%rax, %rbx
+	jmp	.LBB{5}
+.LBB{26}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{28}
+	cmpb	$15, %dil
+	ja	.LBB{8}
+	movq	32(%rsp,%rax,8), %rax
+	cmpq	32(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{9}
+.LBB{29}:
+	movq	216(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{10}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 216(%rsp)
+	cmpb	$15, %dil
+	ja	.LBB{12}
+	movq	208(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{9}
+.LBB{30}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{28}
+	cmpb	$15, %dil
+	ja	.LBB{8}
+	movq	32(%rsp,%rax,8), %rax
+	subq	32(%rsp,%rcx,8), %rax
+	jmp	.LBB{9}
+.LBB{31}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{32}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{18}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movq	32(%rsp,%rax,8), %rax
+	jmp	.LBB{9}
+.LBB{33}:
+	movq	192(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{10}
+	cmpq	176(%rsp), %r15
+	jne	.LBB{34}
+.Ltmp{35}:
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{36}:
+.LBB{34}:
+	movq	184(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 192(%rsp)
+	jmp	.LBB{5}
+.LBB{37}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	cmpl	$4096, %ebx
+	jae	.LBB{28}
+	cmpb	$15, %dil
+	ja	.LBB{8}
+	movq	32(%rsp,%rcx,8), %rcx
+	addq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{38}
+.LBB{39}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{32}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{18}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movl	$127, %ecx
+	bsrq	32(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	jmp	.LBB{38}
+.LBB{40}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	movq	%rbx, 32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{41}:
+	cmpb	$16, %dil
+	jae	.LBB{25}
+	decq	32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{42}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	movq	216(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{10}
+	movq	32(%rsp,%rdi,8), %r15
+	cmpq	200(%rsp), %rbx
+	jne	.LBB{43}
+.Ltmp{44}:
+	leaq	200(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{45}:
+.LBB{43}:
+	movq	208(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 216(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{46}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{28}
+	cmpb	$15, %dil
+	ja	.LBB{8}
+	movq	32(%rsp,%rcx,8), %rcx
+	imulq	32(%rsp,%rax,8), %rcx
+	jmp	.LBB{38}
+.LBB{47}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{28}
+	movq	32(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{6}
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{48}
+	cmpq	$-1, %rcx
+	je	.LBB{49}
+.LBB{48}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{50}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{7}
+.LBB{51}:
+	movq	192(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{10}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	184(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{5}
+.LBB{52}:
+	movzbl	%bl, %esi
+	leaq	.Lanon{15}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{17}
+	movzwl	%cx, %eax
+	addq	32(%rsp,%rsi,8), %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{10}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movq	8(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{9}
+.LBB{53}:
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{27}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{28}
+	movq	32(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{54}
+	xorl	%edx, %edx
+	cmpb	$16, %dil
+	jb	.LBB{55}
+	jmp	.LBB{8}
+.LBB{56}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	cmovneq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{57}:
+	leaq	.Lanon{15}(%rip), %rdx
+	cmpb	$16, %dil
+	jae	.LBB{16}
+	movzbl	%bl, %eax
+	cmpb	$15, %bl
+	ja	.LBB{18}
+	movzwl	%cx, %ecx
+	cmpl	$4095, %ebx
+	ja	.LBB{58}
+	movq	32(%rsp,%rax,8), %rax
+	movq	32(%rsp,%rcx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	movq	24(%rsp), %r10
+	movq	168(%rsp), %r11
+	jb	.LBB{10}
+	cmpq	%r10, %rcx
+	ja	.LBB{10}
+	movq	32(%rsp,%rdi,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r10, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{10}
+	testq	%r11, %r11
+	movq	16(%rsp), %r9
+	je	.LBB{59}
+	cmpq	%rsi, %rcx
+	jae	.LBB{59}
+	movq	%rdx, %rsi
+	movq	%rcx, %rdi
+	jmp	.LBB{60}
+.LBB{61}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{59}
+.LBB{60}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r11, %r8
+	jae	.LBB{61}
+	movq	16(%r9,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{61}
+	jmp	.LBB{10}
+.LBB{62}:
+	cmpb	$16, %dil
+	jae	.LBB{25}
+	incq	32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{63}:
+	movl	%ecx, %edx
+	andb	$15, %dl
+	movl	%ebx, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	addq	32(%rsp,%rsi,8), %rdx
+	movl	%edi, %esi
+	andl	$15, %esi
+	movq	%rdx, 32(%rsp,%rsi,8)
+	shrb	$4, %cl
+	shrb	$4, %bl
+	movzbl	%bl, %edx
+	movzbl	%cl, %ecx
+	movq	32(%rsp,%rcx,8), %rcx
+	addq	32(%rsp,%rdx,8), %rcx
+	shrb	$4, %dil
+	movzbl	%dil, %edx
+	movq	%rcx, 32(%rsp,%rdx,8)
+	jmp	.LBB{64}
+.LBB{65}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	cmpq	$0, 32(%rsp,%rdi,8)
+	cmovnsq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{66}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{32}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{18}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{38}
+.LBB{67}:
+	movl	%ecx, %edx
+	andb	$15, %dl
+	movl	%ebx, %esi
+	andb	$15, %sil
+	movzbl	%sil, %esi
+	movzbl	%dl, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	imulq	32(%rsp,%rsi,8), %rdx
+	movl	%edi, %esi
+	andl	$15, %esi
+	movq	%rdx, 32(%rsp,%rsi,8)
+	shrb	$4, %cl
+	shrb	$4, %bl
+	movzbl	%bl, %edx
+	movq	32(%rsp,%rdx,8), %rdx
+	movzbl	%cl, %ecx
+	subq	32(%rsp,%rcx,8), %rdx
+	shrb	$4, %dil
+	movzbl	%dil, %ecx
+	movq	%rdx, 32(%rsp,%rcx,8)
+.LBB{64}:
+	addq	$2, %rax
+	movq	%rax, %rbx
+	jmp	.LBB{5}
+.LBB{68}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{32}(%rip), %rdx
+	cmpb	$16, %bl
+	jae	.LBB{18}
+	cmpb	$15, %dil
+	ja	.LBB{16}
+	movl	$64, %ecx
+	rep		bsfq	32(%rsp,%rax,8), %rcx
+.LBB{38}:
+	movq	%rcx, 32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{19}:
+	movq	%r13, %rbx
+	testq	%rax, %rax
+	je	.LBB{5}
+	movq	8(%rsp), %rsi
+	leaq	(%rsi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{69}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{69}
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{54}:
+	movq	32(%rsp,%rax,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{70}
+	cqto
+	idivq	%rcx
+	cmpb	$16, %dil
+	jb	.LBB{55}
+	jmp	.LBB{8}
+.LBB{59}:
+	movq	%r10, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{71}
+	movq	8(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{50}:
+	xorl	%edx, %edx
+	divl	%ecx
+	jmp	.LBB{7}
+.LBB{70}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpb	$16, %dil
+	jae	.LBB{8}
+.LBB{55}:
+	movq	%rdx, 32(%rsp,%rdi,8)
+	movq	%r13, %rbx
+	jmp	.LBB{5}
+.LBB{49}:
+	movabsq	$-9223372036854775808, %rax
+	jmp	.LBB{7}
+.LBB{72}:
+	cmpb	$16, %dil
+	jae	.LBB{12}
+	movq	32(%rsp,%rdi,8), %r12
+.LBB{10}:
+	movq	304(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{73}
+	shlq	$3, %rsi
+	movl	$8, %edx
+	movq	8(%rsp), %rdi
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{73}:
+	movq	16(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{74}
+	leaq	328(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{74}:
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{75}
+	movq	184(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{75}:
+	movq	200(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{76}
+	movq	208(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{76}:
+	movq	%r12, %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 32(%rsp)
+	leaq	32(%rsp), %rax
+	movq	%rax, 176(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 184(%rsp)
+	leaq	.Lanon{77}(%rip), %rcx
+	movq	%rcx, 192(%rsp)
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{78}(%rip), %rdi
+	leaq	.Lanon{79}(%rip), %rdx
+	leaq	176(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{71}:
+.Ltmp{80}:
+	leaq	.Lanon{81}(%rip), %rdi
+	leaq	.Lanon{82}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{83}:
+	jmp	.LBB{84}
+.LBB{12}:
+	leaq	.Lanon{15}(%rip), %rdx
+	jmp	.LBB{16}
+.LBB{18}:
+	movq	%rax, %rdi
+	jmp	.LBB{16}
+.LBB{8}:
+	movq	%rdi, %rax
+	jmp	.LBB{27}
+.LBB{28}:
+	movq	%rcx, %rax
+.LBB{27}:
+.Ltmp{85}:
+	leaq	.Lanon{32}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rax, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{86}:
+	jmp	.LBB{84}
+.LBB{17}:
+	movq	%rsi, %rdi
+	jmp	.LBB{16}
+.LBB{25}:
+	leaq	.Lanon{32}(%rip), %rdx
+	jmp	.LBB{16}
+.LBB{58}:
+	movq	%rcx, %rdi
+.LBB{16}:
+.Ltmp{87}:
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{88}:
+.LBB{84}:
+	ud2
+.LBB{89}:
+.Ltmp{90}:
+	jmp	.LBB{91}
+.LBB{92}:
+.Ltmp{93}:
+	movq	%rax, %rbx
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{94}:
+.Ltmp{95}:
+.LBB{91}:
+	movq	%rax, %rbx
+	leaq	304(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	176(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,249 @@
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	subq	$256, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{0}
+	movq	8(%rdi), %rbx
+	movq	32(%rdi), %r14
+	movabsq	$-9223372036854775808, %r15
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	movaps	%xmm0, 160(%rsp)
+	movaps	%xmm0, 144(%rsp)
+	movaps	%xmm0, 128(%rsp)
+	shlq	$3, %rdx
+	leaq	128(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	128(%rsp), %xmm0
+	movaps	144(%rsp), %xmm1
+	movaps	160(%rsp), %xmm2
+	movaps	176(%rsp), %xmm3
+	movaps	%xmm0, (%rsp)
+	movaps	%xmm1, 16(%rsp)
+	movaps	%xmm2, 32(%rsp)
+	movaps	%xmm3, 48(%rsp)
+	movaps	192(%rsp), %xmm0
+	movaps	%xmm0, 64(%rsp)
+	movaps	208(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	224(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	xorl	%r8d, %r8d
+	leaq	.LJTI{1}(%rip), %rsi
+	jmp	.LBB{2}
+.LBB{3}:
+	movl	(%r14,%rcx,4), %r8d
+.LBB{2}:
+	movl	(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{4}
+	movq	%r8, %rcx
+	incq	%r8
+	movzbl	%dh, %edi
+	movl	%edx, %r9d
+	shrl	$16, %r9d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %r10d
+	movslq	(%rsi,%r10,4), %r10
+	addq	%rsi, %r10
+	jmpq	*%r10
+.LBB{5}:
+	cmpl	$16, %edi
+	jae	.LBB{6}
+	shll	$8, %eax
+	movzbl	%r9b, %ecx
+	orl	%eax, %ecx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{7}:
+	cmpl	$16, %edi
+	jae	.LBB{6}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{2}
+	movl	(%r14,%rcx,4), %r8d
+	jmp	.LBB{2}
+.LBB{8}:
+	movzbl	%r9b, %ecx
+	cmpb	$15, %r9b
+	ja	.LBB{9}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{10}
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rcx,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{12}:
+	movzbl	%r9b, %ecx
+	cmpb	$15, %r9b
+	ja	.LBB{9}
+	cmpl	$268435456, %edx
+	jae	.LBB{10}
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{13}:
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{14}:
+	movzbl	%r9b, %ecx
+	cmpb	$15, %r9b
+	ja	.LBB{9}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{10}
+	movq	(%rsp,%rax,8), %r9
+	leaq	-1(%r9), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{15}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{16}
+	jmp	.LBB{11}
+.LBB{17}:
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{18}:
+	movzbl	%r9b, %ecx
+	cmpb	$15, %r9b
+	ja	.LBB{9}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{10}
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	movq	(%rsp,%rcx,8), %rcx
+	subq	(%rsp,%rax,8), %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{19}:
+	movzbl	%r9b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{10}
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{20}:
+	movzbl	%r9b, %ecx
+	cmpb	$15, %r9b
+	ja	.LBB{9}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{10}
+	movq	(%rsp,%rax,8), %r9
+	testq	%r9, %r9
+	je	.LBB{21}
+	movq	(%rsp,%rcx,8), %rax
+	cmpq	%r15, %rax
+	jne	.LBB{22}
+	movq	%r15, %rcx
+	cmpq	$-1, %r9
+	je	.LBB{23}
+.LBB{22}:
+	movq	%rax, %rcx
+	orq	%r9, %rcx
+	shrq	$32, %rcx
+	je	.LBB{24}
+	cqto
+	idivq	%r9
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{25}
+	jmp	.LBB{11}
+.LBB{15}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r9, %rcx
+	shrq	$32, %rcx
+	je	.LBB{26}
+	cqto
+	idivq	%r9
+	cmpl	$16, %edi
+	jb	.LBB{16}
+	jmp	.LBB{11}
+.LBB{21}:
+	xorl	%ecx, %ecx
+.LBB{23}:
+	cmpl	$16, %edi
+	jae	.LBB{11}
+.LBB{25}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{24}:
+	xorl	%edx, %edx
+	divl	%r9d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{25}
+	jmp	.LBB{11}
+.LBB{26}:
+	xorl	%edx, %edx
+	divl	%r9d
+	cmpl	$16, %edi
+	jae	.LBB{11}
+.LBB{16}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{2}
+.LBB{27}:
+	cmpl	$16, %edi
+	jae	.LBB{6}
+	movq	(%rsp,%rdi,8), %rax
+	jmp	.LBB{28}
+.LBB{4}:
+	movq	$-1, %rax
+.LBB{28}:
+	addq	$256, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	retq
+.LBB{0}:
+	movq	%rdx, (%rsp)
+	movq	%rsp, %rax
+	movq	%rax, 128(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 136(%rsp)
+	leaq	.Lanon{29}(%rip), %rcx
+	movq	%rcx, 144(%rsp)
+	movq	%rax, 152(%rsp)
+	leaq	.Lanon{30}(%rip), %rdi
+	leaq	.Lanon{31}(%rip), %rdx
+	leaq	128(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{11}:
+	leaq	.Lanon{32}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{10}:
+	leaq	.Lanon{32}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rax, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{9}:
+	leaq	.Lanon{32}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rcx, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{6}:
+	leaq	.Lanon{33}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
@@ -0,0 +1,1668 @@
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$264, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{0}
+	movq	8(%rdi), %rbx
+	movq	32(%rdi), %r14
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	movaps	%xmm0, 176(%rsp)
+	movaps	%xmm0, 160(%rsp)
+	movaps	%xmm0, 144(%rsp)
+	movaps	%xmm0, 128(%rsp)
+	shlq	$3, %rdx
+	leaq	128(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	128(%rsp), %xmm0
+	movaps	144(%rsp), %xmm1
+	movaps	160(%rsp), %xmm2
+	movaps	176(%rsp), %xmm3
+	movaps	%xmm0, (%rsp)
+	movaps	%xmm1, 16(%rsp)
+	movaps	%xmm2, 32(%rsp)
+	movaps	%xmm3, 48(%rsp)
+	movaps	192(%rsp), %xmm0
+	movaps	%xmm0, 64(%rsp)
+	movaps	208(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	224(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	240(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	xorl	%ebp, %ebp
+	leaq	.LJTI{1}(%rip), %rsi
+	leaq	.LJTI{2}(%rip), %r10
+	leaq	.LJTI{3}(%rip), %r11
+	leaq	.LJTI{4}(%rip), %r12
+	leaq	.LJTI{5}(%rip), %r13
+	jmp	.LBB{6}
+.LBB{7}:
+	movl	4(%r14,%r8,4), %ebp
+.LBB{6}:
+	movl	(%rbx,%rbp,4), %edx
+	movq	$-1, %rcx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	movq	%rbp, %r8
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ebp
+	movslq	(%rsi,%rbp,4), %rbp
+	addq	%rsi, %rbp
+	jmpq	*%rbp
+.LBB{9}:
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	movslq	(%r11,%rcx,4), %rcx
+	addq	%r11, %rcx
+	jmpq	*%rcx
+.LBB{10}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{13}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{14}
+	cmpq	$-1, %r8
+	je	.LBB{15}
+.LBB{14}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{16}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{17}
+	jmp	.LBB{18}
+.LBB{19}:
+	cmpl	$16, %edi
+	jae	.LBB{20}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{21}
+	movl	(%r14,%r8,4), %r8d
+	movl	(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	jbe	.LBB{22}
+	jmp	.LBB{8}
+.LBB{23}:
+	movzbl	%r15b, %ebp
+	cmpb	$15, %r15b
+	ja	.LBB{24}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rbp,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{25}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{26}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{27}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{28}
+	cmpq	$-1, %r8
+	je	.LBB{29}
+.LBB{28}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{30}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{31}
+	jmp	.LBB{18}
+.LBB{32}:
+	movzbl	%r15b, %ebp
+	cmpb	$15, %r15b
+	ja	.LBB{24}
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rbp,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{33}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{34}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{35}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{36}
+	cmpq	$-1, %r8
+	je	.LBB{37}
+.LBB{36}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{38}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{39}
+	jmp	.LBB{18}
+.LBB{40}:
+	cmpl	$16, %edi
+	jae	.LBB{20}
+	shll	$8, %eax
+	movzbl	%r15b, %edx
+	orl	%eax, %edx
+	movq	%rdx, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{41}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{42}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{43}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{44}
+	cmpq	$-1, %r8
+	je	.LBB{45}
+.LBB{44}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{46}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{47}
+	jmp	.LBB{18}
+.LBB{48}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{49}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{50}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{51}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{52}
+	cmpq	$-1, %r8
+	je	.LBB{53}
+.LBB{52}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{54}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{55}
+	jmp	.LBB{18}
+.LBB{56}:
+	movzbl	%r15b, %ebp
+	cmpb	$15, %r15b
+	ja	.LBB{24}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r15
+	leaq	-1(%r15), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{57}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{58}
+	jmp	.LBB{18}
+.LBB{59}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{60}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{61}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{62}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{63}
+	cmpq	$-1, %r8
+	je	.LBB{64}
+.LBB{63}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{65}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{66}
+	jmp	.LBB{18}
+.LBB{67}:
+	movzbl	%r15b, %ebp
+	cmpb	$15, %r15b
+	ja	.LBB{24}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rbp,8), %rdx
+	subq	(%rsp,%rax,8), %rdx
+	movq	%rdx, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{68}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{69}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{70}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{71}
+	cmpq	$-1, %r8
+	je	.LBB{72}
+.LBB{71}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{73}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{74}
+	jmp	.LBB{18}
+.LBB{75}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	movslq	(%r10,%rcx,4), %rcx
+	addq	%r10, %rcx
+	jmpq	*%rcx
+.LBB{76}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{77}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{78}
+	cmpq	$-1, %r8
+	je	.LBB{79}
+.LBB{78}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{80}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{81}
+	jmp	.LBB{18}
+.LBB{82}:
+	movzbl	%r15b, %ebp
+	cmpb	$15, %r15b
+	ja	.LBB{24}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r15
+	testq	%r15, %r15
+	je	.LBB{83}
+	movq	(%rsp,%rbp,8), %rax
+	movabsq	$-9223372036854775808, %rdx
+	cmpq	%rdx, %rax
+	jne	.LBB{84}
+	cmpq	$-1, %r15
+	je	.LBB{85}
+.LBB{84}:
+	movq	%rax, %rdx
+	orq	%r15, %rdx
+	shrq	$32, %rdx
+	je	.LBB{86}
+	cqto
+	idivq	%r15
+	movq	%rax, %rdx
+	cmpl	$16, %edi
+	jb	.LBB{87}
+	jmp	.LBB{18}
+.LBB{88}:
+	movl	(%r14,%r8,4), %r15d
+	movl	(%rbx,%r15,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	1(%r15), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r8d
+	shrl	$16, %r8d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	movslq	(%r12,%rcx,4), %rcx
+	addq	%r12, %rcx
+	jmpq	*%rcx
+.LBB{89}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	shll	$8, %eax
+	movzbl	%r8b, %ecx
+	jmp	.LBB{91}
+.LBB{57}:
+	movq	(%rsp,%rbp,8), %rax
+	movq	%rax, %rdx
+	orq	%r15, %rdx
+	shrq	$32, %rdx
+	je	.LBB{92}
+	cqto
+	idivq	%r15
+	cmpl	$16, %edi
+	jb	.LBB{58}
+	jmp	.LBB{18}
+.LBB{21}:
+	incq	%r8
+	movl	(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+.LBB{22}:
+	leaq	1(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	movslq	(%r13,%rcx,4), %rcx
+	addq	%r13, %rcx
+	jmpq	*%rcx
+.LBB{93}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{94}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{95}
+	cmpq	$-1, %r8
+	je	.LBB{96}
+.LBB{95}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{97}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{98}
+	jmp	.LBB{18}
+.LBB{83}:
+	xorl	%edx, %edx
+.LBB{85}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{87}:
+	movq	%rdx, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{99}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{100}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{101}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{102}
+	cmpq	$-1, %r8
+	je	.LBB{103}
+.LBB{102}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{104}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{105}
+	jmp	.LBB{18}
+.LBB{86}:
+	xorl	%edx, %edx
+	divl	%r15d
+	movl	%eax, %edx
+	cmpl	$16, %edi
+	jb	.LBB{87}
+	jmp	.LBB{18}
+.LBB{106}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{107}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{108}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{109}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{110}
+	jmp	.LBB{18}
+.LBB{111}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{92}:
+	xorl	%edx, %edx
+	divl	%r15d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{58}:
+	movq	%rdx, (%rsp,%rdi,8)
+	movl	4(%rbx,%r8,4), %edx
+	cmpb	$14, %dl
+	ja	.LBB{8}
+	leaq	2(%r8), %rbp
+	movzbl	%dh, %edi
+	movl	%edx, %r15d
+	shrl	$16, %r15d
+	movl	%edx, %eax
+	shrl	$24, %eax
+	movzbl	%dl, %ecx
+	leaq	.LJTI{112}(%rip), %r9
+	movslq	(%r9,%rcx,4), %rcx
+	addq	%r9, %rcx
+	jmpq	*%rcx
+.LBB{113}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	shll	$8, %eax
+	movzbl	%r15b, %ecx
+.LBB{91}:
+	orl	%eax, %ecx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{114}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+.LBB{115}:
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	imulq	(%rsp,%rcx,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{116}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+.LBB{117}:
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	addq	(%rsp,%rcx,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{118}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+.LBB{119}:
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rcx,8), %rcx
+	subq	(%rsp,%rax,8), %rcx
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{120}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{12}
+.LBB{121}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	movq	(%rsp,%rax,8), %rax
+	movq	%rax, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{109}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{122}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{110}
+	jmp	.LBB{18}
+.LBB{13}:
+	xorl	%ecx, %ecx
+.LBB{15}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{17}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{123}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	(%r14,%r15,4), %ebp
+	jmp	.LBB{6}
+.LBB{124}:
+	movl	(%r14,%r15,4), %ebp
+	jmp	.LBB{6}
+.LBB{125}:
+	movzbl	%r8b, %ecx
+	cmpb	$15, %r8b
+	jbe	.LBB{115}
+	jmp	.LBB{11}
+.LBB{126}:
+	movzbl	%r8b, %ecx
+	cmpb	$15, %r8b
+	jbe	.LBB{117}
+	jmp	.LBB{11}
+.LBB{127}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{128}:
+	movzbl	%r8b, %ecx
+	cmpb	$15, %r8b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{129}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{130}
+	jmp	.LBB{18}
+.LBB{131}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{132}:
+	movzbl	%r8b, %ecx
+	cmpb	$15, %r8b
+	jbe	.LBB{119}
+	jmp	.LBB{11}
+.LBB{133}:
+	movzbl	%r8b, %eax
+	cmpl	$16, %eax
+	jb	.LBB{121}
+	jmp	.LBB{12}
+.LBB{134}:
+	movzbl	%r8b, %ecx
+	cmpb	$15, %r8b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{135}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{136}
+	cmpq	$-1, %r8
+	je	.LBB{137}
+.LBB{136}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{138}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{139}
+	jmp	.LBB{18}
+.LBB{140}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{141}:
+	movl	(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{142}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{143}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{144}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{145}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{146}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{147}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{148}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{149}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{150}
+	jmp	.LBB{18}
+.LBB{151}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{152}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{153}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{154}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{155}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{156}
+	jmp	.LBB{18}
+.LBB{157}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{158}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{159}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{160}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{161}
+	jmp	.LBB{18}
+.LBB{162}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{163}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{164}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{165}
+	jmp	.LBB{18}
+.LBB{166}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{167}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{168}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{169}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{170}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{171}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{172}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{173}
+	jmp	.LBB{18}
+.LBB{174}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{175}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{176}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{177}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{178}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{179}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{180}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{181}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{182}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+	cmpq	$0, (%rsp,%rdi,8)
+	je	.LBB{6}
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{183}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{184}:
+	movl	4(%r14,%r8,4), %ebp
+	jmp	.LBB{6}
+.LBB{185}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{186}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{187}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{188}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{189}
+	jmp	.LBB{18}
+.LBB{190}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{191}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{192}
+	jmp	.LBB{18}
+.LBB{193}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{194}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{195}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	testq	%r8, %r8
+	je	.LBB{196}
+	movq	(%rsp,%rcx,8), %rax
+	movabsq	$-9223372036854775808, %rcx
+	cmpq	%rcx, %rax
+	jne	.LBB{197}
+	cmpq	$-1, %r8
+	je	.LBB{198}
+.LBB{197}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{199}
+	cqto
+	idivq	%r8
+	movq	%rax, %rcx
+	cmpl	$16, %edi
+	jb	.LBB{200}
+	jmp	.LBB{18}
+.LBB{201}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{202}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{203}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{204}
+	jmp	.LBB{18}
+.LBB{205}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{206}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{207}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{208}
+	jmp	.LBB{18}
+.LBB{209}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	incq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{210}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{211}:
+	movzbl	%r15b, %ecx
+	cmpb	$15, %r15b
+	ja	.LBB{11}
+	movl	%eax, %eax
+	cmpl	$268435456, %edx
+	jae	.LBB{12}
+	movq	(%rsp,%rax,8), %r8
+	leaq	-1(%r8), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{212}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{213}
+	jmp	.LBB{18}
+.LBB{214}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{215}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+	decq	(%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{16}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{17}
+	jmp	.LBB{18}
+.LBB{122}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{110}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{129}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{216}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{130}
+	jmp	.LBB{18}
+.LBB{149}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{217}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{150}
+	jmp	.LBB{18}
+.LBB{155}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{218}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{156}
+	jmp	.LBB{18}
+.LBB{160}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{219}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{161}
+	jmp	.LBB{18}
+.LBB{164}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{220}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{165}
+	jmp	.LBB{18}
+.LBB{172}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{221}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{173}
+	jmp	.LBB{18}
+.LBB{188}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{222}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{189}
+	jmp	.LBB{18}
+.LBB{191}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{223}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{192}
+	jmp	.LBB{18}
+.LBB{203}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{224}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{204}
+	jmp	.LBB{18}
+.LBB{207}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{225}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{208}
+	jmp	.LBB{18}
+.LBB{212}:
+	movq	(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{226}
+	cqto
+	idivq	%r8
+	cmpl	$16, %edi
+	jb	.LBB{213}
+	jmp	.LBB{18}
+.LBB{135}:
+	xorl	%ecx, %ecx
+.LBB{137}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{139}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{94}:
+	xorl	%ecx, %ecx
+.LBB{96}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{98}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{51}:
+	xorl	%ecx, %ecx
+.LBB{53}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{55}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{43}:
+	xorl	%ecx, %ecx
+.LBB{45}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{47}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{62}:
+	xorl	%ecx, %ecx
+.LBB{64}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{66}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{77}:
+	xorl	%ecx, %ecx
+.LBB{79}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{81}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{27}:
+	xorl	%ecx, %ecx
+.LBB{29}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{31}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{35}:
+	xorl	%ecx, %ecx
+.LBB{37}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{39}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{196}:
+	xorl	%ecx, %ecx
+.LBB{198}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{200}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{70}:
+	xorl	%ecx, %ecx
+.LBB{72}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{74}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{101}:
+	xorl	%ecx, %ecx
+.LBB{103}:
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{105}:
+	movq	%rcx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{138}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{139}
+	jmp	.LBB{18}
+.LBB{97}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{98}
+	jmp	.LBB{18}
+.LBB{54}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{55}
+	jmp	.LBB{18}
+.LBB{46}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{47}
+	jmp	.LBB{18}
+.LBB{65}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{66}
+	jmp	.LBB{18}
+.LBB{80}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{81}
+	jmp	.LBB{18}
+.LBB{30}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{31}
+	jmp	.LBB{18}
+.LBB{38}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{39}
+	jmp	.LBB{18}
+.LBB{199}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{200}
+	jmp	.LBB{18}
+.LBB{73}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{74}
+	jmp	.LBB{18}
+.LBB{104}:
+	xorl	%edx, %edx
+	divl	%r8d
+	movl	%eax, %ecx
+	cmpl	$16, %edi
+	jb	.LBB{105}
+	jmp	.LBB{18}
+.LBB{216}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{130}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{217}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{150}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{218}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{156}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{219}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{161}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{220}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{165}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{221}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{173}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{222}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{189}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{223}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{192}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{224}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{204}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{225}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{208}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{226}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$16, %edi
+	jae	.LBB{18}
+.LBB{213}:
+	movq	%rdx, (%rsp,%rdi,8)
+	jmp	.LBB{6}
+.LBB{227}:
+	cmpl	$16, %edi
+	jb	.LBB{228}
+.LBB{20}:
+	leaq	.Lanon{229}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{230}:
+	cmpl	$16, %edi
+	jae	.LBB{90}
+.LBB{228}:
+	movq	(%rsp,%rdi,8), %rcx
+.LBB{8}:
+	movq	%rcx, %rax
+	addq	$264, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{0}:
+	movq	%rdx, (%rsp)
+	movq	%rsp, %rax
+	movq	%rax, 128(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 136(%rsp)
+	leaq	.Lanon{231}(%rip), %rcx
+	movq	%rcx, 144(%rsp)
+	movq	%rax, 152(%rsp)
+	leaq	.Lanon{232}(%rip), %rdi
+	leaq	.Lanon{233}(%rip), %rdx
+	leaq	128(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{18}:
+	leaq	.Lanon{234}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{12}:
+	leaq	.Lanon{234}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rax, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{24}:
+	leaq	.Lanon{234}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rbp, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{11}:
+	leaq	.Lanon{234}(%rip), %rdx
+	movl	$16, %esi
+	movq	%rcx, %rdi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.LBB{90}:
+	leaq	.Lanon{235}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
@@ -0,0 +1,148 @@
+.Lfunc_begin{0}:
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	subq	$464, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %r14
+	movq	168(%rdi), %r15
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 400(%rsp)
+	movaps	%xmm0, 384(%rsp)
+	movaps	%xmm0, 368(%rsp)
+	movaps	%xmm0, 352(%rsp)
+	movaps	%xmm0, 336(%rsp)
+	movaps	%xmm0, 320(%rsp)
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	shlq	$3, %rdx
+	leaq	288(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movq	$0, (%rsp)
+	movq	$8, 8(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 16(%rsp)
+	movq	$8, 32(%rsp)
+	movq	$0, 40(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	424(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	movq	%r14, 136(%rsp)
+	movq	%r15, 144(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	304(%rsp), %xmm1
+	movaps	320(%rsp), %xmm2
+	movaps	336(%rsp), %xmm3
+	movups	%xmm0, 152(%rsp)
+	movups	%xmm1, 168(%rsp)
+	movups	%xmm2, 184(%rsp)
+	movups	%xmm3, 200(%rsp)
+	movaps	352(%rsp), %xmm0
+	movups	%xmm0, 216(%rsp)
+	movaps	368(%rsp), %xmm0
+	movups	%xmm0, 232(%rsp)
+	movaps	384(%rsp), %xmm0
+	movups	%xmm0, 248(%rsp)
+	movaps	400(%rsp), %xmm0
+	movups	%xmm0, 264(%rsp)
+	movq	$0, 280(%rsp)
+	movups	(%rsp), %xmm0
+	movups	16(%rsp), %xmm1
+	movups	32(%rsp), %xmm2
+	movaps	%xmm2, 80(%rsp)
+	movaps	%xmm1, 64(%rsp)
+	movaps	%xmm0, 48(%rsp)
+	movq	456(%rsp), %rax
+	movq	%rax, 128(%rsp)
+	movups	424(%rsp), %xmm0
+	movups	440(%rsp), %xmm1
+	movaps	%xmm1, 112(%rsp)
+	movaps	%xmm0, 96(%rsp)
+	leaq	_ZN9rust_goto5TABLE17h{hash}E(%rip), %r14
+	leaq	48(%rsp), %rbx
+.LBB{4}:
+	movq	136(%rsp), %rax
+	movq	280(%rsp), %rcx
+	movl	(%rax,%rcx,4), %esi
+	incq	%rcx
+	movq	%rcx, 280(%rsp)
+	movzbl	%sil, %eax
+.Ltmp{5}:
+	movq	%rbx, %rdi
+	callq	*(%r14,%rax,8)
+.Ltmp{6}:
+	cmpq	$1, %rax
+	jne	.LBB{4}
+	movq	48(%rsp), %rsi
+	testq	%rsi, %rsi
+	movq	%rdx, %rbx
+	je	.LBB{7}
+	movq	56(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	%rbx, %rdx
+.LBB{7}:
+	movq	72(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{8}
+	movq	80(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	%rbx, %rdx
+.LBB{8}:
+	movq	96(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{9}
+	movq	104(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+	movq	%rbx, %rdx
+.LBB{9}:
+	movq	120(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{10}
+	leaq	120(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+	movq	%rbx, %rdx
+.LBB{10}:
+	movq	%rdx, %rax
+	addq	$464, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	retq
+.LBB{1}:
+	movq	%rdx, (%rsp)
+	movq	%rsp, %rax
+	movq	%rax, 48(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 56(%rsp)
+	leaq	.Lanon{11}(%rip), %rcx
+	movq	%rcx, 64(%rsp)
+	movq	%rax, 72(%rsp)
+	leaq	.Lanon{12}(%rip), %rdi
+	leaq	.Lanon{13}(%rip), %rdx
+	leaq	48(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{14}:
+.Ltmp{15}:
+	movq	%rax, %rbx
+	movq	%rsp, %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{16}:
+.Ltmp{17}:
+	movq	%rax, %rbx
+	leaq	48(%rsp), %rdi
+	callq	_ZN4core3ptr42drop_in_place$LT$rust_goto..TableState$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,11398 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %rax
+	movq	%rax, 328(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 16(%rsp)
+	movaps	%xmm1, 32(%rsp)
+	movaps	%xmm2, 48(%rsp)
+	movaps	%xmm3, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	152(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%ebx, %ebx
+.LBB{4}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%rbx), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{6}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{7}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r13d
+	movzbl	%r12b, %eax
+	orl	%r13d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{10}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{11}:
+	cmpl	$16, %r8d
+	jb	.LBB{12}
+	jmp	.LBB{13}
+.LBB{14}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r13, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{16}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{17}:
+	cmpl	$16, %r8d
+	jb	.LBB{18}
+	jmp	.LBB{15}
+.LBB{19}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{24}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{25}:
+	cmpl	$16, %r8d
+	jb	.LBB{26}
+	jmp	.LBB{27}
+.LBB{28}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	3(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{29}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{30}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{31}
+	jmp	.LBB{21}
+.LBB{32}:
+	movq	232(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{5}
+	leaq	-1(%rdx), %rdi
+	movq	%rdi, 232(%rsp)
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movq	224(%rsp), %rsi
+	movq	-8(%rsi,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	2(%rbx), %r14
+	movzbl	%al, %r9d
+	leaq	.LJTI{34}(%rip), %r10
+	movslq	(%r10,%r9,4), %r9
+	addq	%r10, %r9
+	jmpq	*%r9
+.LBB{35}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jne	.LBB{36}
+	jmp	.LBB{4}
+.LBB{37}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{38}
+.Ltmp{39}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{40}:
+.LBB{38}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 208(%rsp)
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %esi
+	leaq	.LJTI{41}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{42}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{43}
+	jmp	.LBB{4}
+.LBB{44}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{47}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{48}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{49}
+.Ltmp{50}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{51}:
+	jmp	.LBB{49}
+.LBB{52}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r13, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{53}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{54}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{18}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{55}
+	jmp	.LBB{4}
+.LBB{56}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{57}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{58}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{59}
+	jmp	.LBB{4}
+.LBB{60}:
+	leaq	.Lanon{8}(%rip), %r11
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{62}
+	movq	$-1, 8(%rsp)
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{63}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{64}
+	cmpq	%rdi, %rax
+	jae	.LBB{64}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{65}
+.LBB{66}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{64}
+.LBB{65}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{66}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{66}
+	jmp	.LBB{23}
+.LBB{67}:
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{68}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{69}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{70}
+.Ltmp{71}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{72}:
+	jmp	.LBB{70}
+.LBB{73}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$16, %r8d
+	jae	.LBB{75}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{76}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{77}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	jbe	.LBB{78}
+	jmp	.LBB{79}
+.LBB{80}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{81}
+	cmpl	$16, %r8d
+	jae	.LBB{75}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r13,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{82}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{83}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{84}
+.Ltmp{85}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{86}:
+	jmp	.LBB{84}
+.LBB{87}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{89}
+	xorl	%edx, %edx
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jb	.LBB{90}
+	jmp	.LBB{91}
+.LBB{92}:
+	leaq	.Lanon{45}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{93}
+	addq	$2, %rbx
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	decq	16(%rsp,%r8,8)
+	cmoveq	%rbx, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{94}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{95}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{96}
+	jmp	.LBB{4}
+.LBB{97}:
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{98}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{99}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	jbe	.LBB{100}
+	jmp	.LBB{101}
+.LBB{102}:
+	movq	$-1, 8(%rsp)
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %r15
+	jne	.LBB{103}
+.Ltmp{104}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{105}:
+.LBB{103}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	2(%rbx), %r14
+	movzbl	%al, %esi
+	leaq	.LJTI{106}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{107}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jne	.LBB{108}
+	jmp	.LBB{4}
+.LBB{109}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{110}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{111}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{112}
+	jmp	.LBB{4}
+.LBB{113}:
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{114}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{115}:
+	cmpl	$16, %r8d
+	jb	.LBB{116}
+	jmp	.LBB{117}
+.LBB{118}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{120}
+	cmpq	$-1, %rcx
+	je	.LBB{121}
+.LBB{120}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{122}
+	cqto
+	idivq	%rcx
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jb	.LBB{123}
+	jmp	.LBB{91}
+.LBB{124}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$16, %r8d
+	jae	.LBB{75}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{125}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{126}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+.LBB{127}:
+	cmpl	$15, %r8d
+	ja	.LBB{75}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{128}
+.LBB{129}:
+	movq	208(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{5}
+	movq	$-1, 8(%rsp)
+	leaq	-1(%r9), %r11
+	movq	%r11, 208(%rsp)
+	movq	200(%rsp), %r10
+	movq	-8(%r10,%r9,8), %rdx
+	movq	328(%rsp), %rax
+	movl	(%rax,%rdx,4), %esi
+	cmpb	$31, %sil
+	ja	.LBB{23}
+	movl	%esi, %edi
+	shrl	$8, %edi
+	movzbl	%dil, %r8d
+	movl	%esi, %ecx
+	shrl	$16, %ecx
+	movl	%esi, %eax
+	shrl	$24, %eax
+	leaq	1(%rdx), %rbx
+	movzbl	%sil, %r14d
+	leaq	.LJTI{130}(%rip), %r15
+	movslq	(%r15,%r14,4), %r14
+	addq	%r15, %r14
+	jmpq	*%r14
+.LBB{131}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{132}
+	jmp	.LBB{4}
+.LBB{133}:
+	movzbl	%r12b, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{135}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{135}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	movq	$-1, 8(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{136}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{137}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	jbe	.LBB{138}
+	jmp	.LBB{139}
+.LBB{140}:
+	movzbl	%r12b, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movq	160(%rsp), %rdi
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{141}(%rip), %r9
+	movslq	(%r9,%rdx,4), %rdx
+	addq	%r9, %rdx
+	jmpq	*%rdx
+.LBB{142}:
+	cmpl	$16, %r8d
+	jb	.LBB{143}
+	jmp	.LBB{33}
+.LBB{144}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rsi
+	andq	%rsi, %rcx
+	subq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	movq	%rax, %rcx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rdi
+	andq	%rdi, %rcx
+	movabsq	$72340172838076673, %r9
+	imulq	%r9, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %r10d
+	leaq	.LJTI{145}(%rip), %r11
+	movslq	(%r11,%r10,4), %r10
+	addq	%r11, %r10
+	jmpq	*%r10
+.LBB{146}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+.LBB{26}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{43}
+	jmp	.LBB{4}
+.LBB{147}:
+	leaq	.Lanon{8}(%rip), %r15
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	movzbl	%r12b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{149}
+	movq	$-1, 8(%rsp)
+	movl	%r13d, %edx
+	cmpl	$268435455, %eax
+	ja	.LBB{150}
+	movq	16(%rsp,%rcx,8), %rax
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rbp
+	cmpq	%rbp, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%rbp, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{23}
+	movq	184(%rsp), %r13
+	testq	%r13, %r13
+	je	.LBB{151}
+	cmpq	%rsi, %rcx
+	jae	.LBB{151}
+	movq	176(%rsp), %rsi
+	movq	%rdx, %rdi
+	movq	%rcx, %r8
+	jmp	.LBB{152}
+.LBB{153}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{151}
+.LBB{152}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%r13, %r9
+	jae	.LBB{153}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{153}
+	jmp	.LBB{23}
+.LBB{154}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r12b, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{155}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{156}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{116}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{157}
+	jmp	.LBB{4}
+.LBB{158}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{159}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{160}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{49}
+.Ltmp{161}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{162}:
+	jmp	.LBB{49}
+.LBB{163}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r13b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	3(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{164}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{165}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+.LBB{143}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{96}
+	jmp	.LBB{4}
+.LBB{166}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	$-1, 8(%rsp)
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{167}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{168}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{59}
+	jmp	.LBB{4}
+.LBB{64}:
+	testq	%rcx, %rcx
+	je	.LBB{169}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{170}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{170}
+.LBB{169}:
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{171}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{172}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{173}
+	jmp	.LBB{4}
+.LBB{89}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{174}
+	cqto
+	idivq	%rcx
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jb	.LBB{90}
+	jmp	.LBB{91}
+.LBB{151}:
+	movq	%rbp, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{175}
+	movq	160(%rsp), %rdi
+	leaq	(%rdi,%rax,8), %rsi
+	movq	%rdi, 336(%rsp)
+	leaq	(%rdi,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r10
+	movzbl	%al, %edx
+	leaq	.LJTI{176}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{177}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jne	.LBB{173}
+	jmp	.LBB{4}
+.LBB{119}:
+	xorl	%eax, %eax
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jb	.LBB{123}
+	jmp	.LBB{91}
+.LBB{122}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+.LBB{123}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{178}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{179}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{128}
+.LBB{180}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+.LBB{12}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{181}
+	jmp	.LBB{4}
+.LBB{174}:
+	xorl	%edx, %edx
+	divl	%ecx
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+.LBB{90}:
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{23}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	2(%rbx), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{182}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{183}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{112}
+	jmp	.LBB{4}
+.LBB{184}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{185}
+	jmp	.LBB{186}
+.LBB{187}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{188}
+	jmp	.LBB{186}
+.LBB{189}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{190}
+	jmp	.LBB{186}
+.LBB{191}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{192}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{194}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{195}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{196}
+	xorl	%edx, %edx
+	jmp	.LBB{197}
+.LBB{198}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{199}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{200}
+	cmpq	$-1, %rcx
+	je	.LBB{201}
+.LBB{200}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{202}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{128}
+	jmp	.LBB{75}
+.LBB{203}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{204}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+.LBB{205}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{206}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+.LBB{207}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{208}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{112}
+.LBB{209}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{112}
+.LBB{210}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{211}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{112}
+	jmp	.LBB{4}
+.LBB{212}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{213}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{214}
+.LBB{215}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{101}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{214}
+.LBB{216}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{188}
+	jmp	.LBB{46}
+.LBB{217}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{190}
+	jmp	.LBB{46}
+.LBB{218}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{219}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{128}
+.LBB{220}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{79}
+.LBB{78}:
+	cmpl	$15, %r8d
+	ja	.LBB{75}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{128}
+.LBB{221}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{222}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$15, %r8d
+	ja	.LBB{75}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{128}
+.LBB{223}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$15, %r8d
+	jbe	.LBB{224}
+	jmp	.LBB{75}
+.LBB{225}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	jne	.LBB{226}
+	jmp	.LBB{23}
+.LBB{227}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{228}
+	jmp	.LBB{186}
+.LBB{229}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{230}
+.LBB{231}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{157}
+	jmp	.LBB{230}
+.LBB{232}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{230}:
+	addq	$2, %r13
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{233}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{234}
+	jmp	.LBB{88}
+.LBB{235}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{236}
+	jmp	.LBB{186}
+.LBB{237}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{238}
+	jmp	.LBB{9}
+.LBB{239}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{240}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{241}
+	cmpq	$-1, %rcx
+	je	.LBB{242}
+.LBB{241}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{243}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{244}
+	jmp	.LBB{91}
+.LBB{245}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{244}
+.LBB{246}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{247}
+	xorl	%edx, %edx
+	jmp	.LBB{248}
+.LBB{249}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{244}
+.LBB{250}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{244}
+.LBB{251}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	jbe	.LBB{252}
+	jmp	.LBB{91}
+.LBB{253}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{214}
+.LBB{254}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{59}
+.LBB{255}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{59}
+	jmp	.LBB{4}
+.LBB{256}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{257}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{257}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{258}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{259}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{260}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{261}
+	cmpq	$-1, %rcx
+	je	.LBB{262}
+.LBB{261}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{263}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{91}
+.LBB{264}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	jbe	.LBB{265}
+	jmp	.LBB{91}
+.LBB{266}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{267}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{214}
+.LBB{268}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{269}
+	xorl	%edx, %edx
+	jmp	.LBB{270}
+.LBB{271}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	jbe	.LBB{272}
+	jmp	.LBB{91}
+.LBB{273}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{274}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{275}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{59}
+.LBB{276}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	jne	.LBB{277}
+	jmp	.LBB{23}
+.LBB{278}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{117}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{214}
+.LBB{279}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{185}
+	jmp	.LBB{46}
+.LBB{280}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{214}
+.LBB{281}:
+	cmpl	$16, %r8d
+	jb	.LBB{282}
+	jmp	.LBB{117}
+.LBB{283}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{285}
+	jmp	.LBB{33}
+.LBB{286}:
+	cmpl	$16, %r8d
+	jb	.LBB{287}
+	jmp	.LBB{117}
+.LBB{288}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{289}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{289}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{290}
+	jmp	.LBB{33}
+.LBB{291}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	jne	.LBB{292}
+	jmp	.LBB{23}
+.LBB{293}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{294}
+	jmp	.LBB{117}
+.LBB{295}:
+	cmpl	$16, %r8d
+	jb	.LBB{296}
+	jmp	.LBB{117}
+.LBB{297}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{185}
+	jmp	.LBB{46}
+.LBB{298}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{188}
+	jmp	.LBB{46}
+.LBB{299}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{190}
+	jmp	.LBB{46}
+.LBB{300}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{302}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{196}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{303}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{197}
+.LBB{304}:
+	cmpl	$16, %r8d
+	jb	.LBB{305}
+	jmp	.LBB{27}
+.LBB{306}:
+	cmpl	$16, %r8d
+	jb	.LBB{307}
+	jmp	.LBB{27}
+.LBB{308}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$4, %rbx
+	jmp	.LBB{4}
+.LBB{309}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{310}
+	jmp	.LBB{311}
+.LBB{312}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{313}
+	movq	$-1, 8(%rsp)
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{314}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{314}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{315}
+	jmp	.LBB{33}
+.LBB{316}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{317}
+	jmp	.LBB{20}
+.LBB{318}:
+	cmpl	$16, %r8d
+	jb	.LBB{319}
+	jmp	.LBB{27}
+.LBB{320}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{321}
+	jmp	.LBB{20}
+.LBB{322}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{323}
+	jmp	.LBB{20}
+.LBB{324}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{325}
+	jmp	.LBB{20}
+.LBB{326}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{313}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{327}
+	jmp	.LBB{33}
+.LBB{328}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{329}
+.LBB{330}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{331}
+	jmp	.LBB{20}
+.LBB{332}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{185}
+	jmp	.LBB{311}
+.LBB{333}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{334}
+	jmp	.LBB{27}
+.LBB{335}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$4, %rbx
+	jmp	.LBB{4}
+.LBB{336}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{188}
+	jmp	.LBB{311}
+.LBB{337}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{190}
+	jmp	.LBB{311}
+.LBB{338}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{339}
+	jmp	.LBB{88}
+.LBB{340}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{341}
+	jmp	.LBB{88}
+.LBB{342}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{101}
+	cmpl	$15, %r8d
+	jbe	.LBB{343}
+	jmp	.LBB{91}
+.LBB{344}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jb	.LBB{345}
+	jmp	.LBB{284}
+.LBB{346}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r13d
+	movzbl	%bpl, %eax
+	orl	%r13d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{347}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+.LBB{339}:
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{348}:
+	cmpl	$16, %r8d
+	jb	.LBB{349}
+	jmp	.LBB{33}
+.LBB{350}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+.LBB{234}:
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{351}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+.LBB{341}:
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{352}:
+	cmpl	$16, %r8d
+	jb	.LBB{353}
+	jmp	.LBB{33}
+.LBB{354}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jb	.LBB{355}
+	jmp	.LBB{284}
+.LBB{356}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	jmp	.LBB{358}
+.LBB{359}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{360}
+	jmp	.LBB{186}
+.LBB{361}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{185}
+	jmp	.LBB{186}
+.LBB{362}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	jne	.LBB{363}
+	jmp	.LBB{23}
+.LBB{364}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	jne	.LBB{365}
+	jmp	.LBB{23}
+.LBB{366}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jb	.LBB{367}
+	jmp	.LBB{23}
+.LBB{368}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{188}
+	jmp	.LBB{186}
+.LBB{369}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{190}
+	jmp	.LBB{186}
+.LBB{370}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{101}
+.LBB{100}:
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{244}
+.LBB{371}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{372}
+.LBB{373}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{374}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{375}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{375}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{376}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{377}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{372}
+.LBB{378}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{379}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+.LBB{355}:
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{244}
+.LBB{380}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+.LBB{345}:
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{381}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{381}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{382}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+.LBB{360}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{244}
+.LBB{383}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+.LBB{363}:
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{384}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+.LBB{365}:
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{117}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{244}
+.LBB{385}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{27}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{386}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{387}
+.LBB{388}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	jmp	.LBB{389}
+.LBB{390}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jns	.LBB{36}
+	jmp	.LBB{4}
+.LBB{391}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{392}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{392}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{393}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{387}
+.LBB{394}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{395}
+.LBB{396}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{387}
+.LBB{397}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r13b, %edx
+	jmp	.LBB{398}
+.LBB{399}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{395}
+.LBB{400}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{395}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{401}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+.LBB{307}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{96}
+.LBB{402}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+.LBB{389}:
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{403}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+.LBB{305}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{96}
+.LBB{404}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+.LBB{319}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{96}
+	jmp	.LBB{4}
+.LBB{405}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{406}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{407}
+.LBB{408}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{407}
+.LBB{409}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{407}
+.LBB{410}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{411}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{407}
+.LBB{412}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+.LBB{398}:
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{413}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{407}
+.LBB{414}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{407}
+.LBB{199}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{128}
+	jmp	.LBB{75}
+.LBB{247}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{415}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{248}
+.LBB{269}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{416}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{270}
+.LBB{417}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{418}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{202}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{128}
+	jmp	.LBB{75}
+.LBB{240}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{244}
+	jmp	.LBB{91}
+.LBB{260}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{91}
+.LBB{419}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{420}
+.Ltmp{421}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{422}:
+	jmp	.LBB{420}
+.LBB{423}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{424}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{313}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{425}
+	cmpq	%rdi, %rax
+	jae	.LBB{425}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{426}
+.LBB{427}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{425}
+.LBB{426}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{427}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{427}
+	jmp	.LBB{23}
+.LBB{428}:
+	cmpl	$16, %r8d
+	jb	.LBB{429}
+	jmp	.LBB{117}
+.LBB{430}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	addq	$4, %rbx
+	jmp	.LBB{4}
+.LBB{431}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+	movq	$-1, 8(%rsp)
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{432}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{432}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{433}
+	jmp	.LBB{33}
+.LBB{434}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{313}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{435}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{436}
+	cmpq	%r9, %r8
+	jae	.LBB{436}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{437}
+.LBB{438}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{436}
+.LBB{437}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{438}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{438}
+	jmp	.LBB{23}
+.LBB{439}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{440}
+.Ltmp{441}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{442}:
+	jmp	.LBB{440}
+.LBB{443}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{444}
+.Ltmp{445}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{446}:
+	jmp	.LBB{444}
+.LBB{447}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{448}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{449}:
+	cmpl	$16, %r8d
+	jb	.LBB{450}
+	jmp	.LBB{117}
+.LBB{451}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{453}
+	cmpq	%rdi, %rax
+	jae	.LBB{453}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{454}
+.LBB{455}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{453}
+.LBB{454}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{455}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{455}
+	jmp	.LBB{23}
+.LBB{456}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{424}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{313}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{457}
+	cmpq	%rdi, %rax
+	jae	.LBB{457}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{458}
+.LBB{459}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{457}
+.LBB{458}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{459}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{459}
+	jmp	.LBB{23}
+.LBB{460}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{444}
+.Ltmp{461}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{462}:
+	jmp	.LBB{444}
+.LBB{463}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{284}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{464}
+	jmp	.LBB{33}
+.LBB{465}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{466}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{467}
+	cmpq	$-1, %rcx
+	je	.LBB{468}
+.LBB{467}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{469}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{470}
+	jmp	.LBB{91}
+.LBB{471}:
+	cmpl	$16, %r8d
+	jb	.LBB{472}
+	jmp	.LBB{117}
+.LBB{473}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{474}
+	xorl	%edx, %edx
+	jmp	.LBB{475}
+.LBB{476}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{478}
+	cmpq	%r9, %r8
+	jae	.LBB{478}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{479}
+.LBB{480}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{478}
+.LBB{479}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{480}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{480}
+	jmp	.LBB{23}
+.LBB{481}:
+	cmpl	$16, %r8d
+	jb	.LBB{482}
+	jmp	.LBB{117}
+.LBB{483}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{484}
+.Ltmp{485}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{486}:
+	jmp	.LBB{484}
+.LBB{487}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{238}
+	jmp	.LBB{117}
+.LBB{488}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{313}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{435}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{489}
+	cmpq	%r9, %r8
+	jae	.LBB{489}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{490}
+.LBB{491}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{489}
+.LBB{490}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{491}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{491}
+	jmp	.LBB{23}
+.LBB{492}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	addq	$4, %rbx
+	jmp	.LBB{4}
+.LBB{493}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	jbe	.LBB{494}
+	jmp	.LBB{20}
+.LBB{495}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{420}
+.Ltmp{496}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{497}:
+.LBB{420}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	movzbl	%bpl, %eax
+	jmp	.LBB{498}
+.LBB{499}:
+	cmpl	$16, %r8d
+	jb	.LBB{500}
+	jmp	.LBB{15}
+.LBB{501}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{503}
+	cmpq	%rdi, %rax
+	jae	.LBB{503}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{504}
+.LBB{505}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{503}
+.LBB{504}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{505}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{505}
+	jmp	.LBB{23}
+.LBB{506}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{507}
+.LBB{508}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{484}
+.Ltmp{509}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{510}:
+	jmp	.LBB{484}
+.LBB{511}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{228}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{512}:
+	cmpl	$16, %r8d
+	jb	.LBB{513}
+	jmp	.LBB{15}
+.LBB{514}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{500}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{55}
+.LBB{515}:
+	cmpl	$16, %r8d
+	jb	.LBB{516}
+	jmp	.LBB{15}
+.LBB{517}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{59}
+.LBB{518}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{519}
+	cmpq	%rdi, %rax
+	jae	.LBB{519}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{520}
+.LBB{521}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{519}
+.LBB{520}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{521}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{521}
+	jmp	.LBB{23}
+.LBB{522}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{524}
+	cmpq	%r9, %r8
+	jae	.LBB{524}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{525}
+.LBB{526}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{524}
+.LBB{525}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{526}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{526}
+	jmp	.LBB{23}
+.LBB{527}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{528}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	jbe	.LBB{529}
+	jmp	.LBB{530}
+.LBB{531}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{532}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{533}
+	cmpq	$-1, %rcx
+	je	.LBB{534}
+.LBB{533}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{535}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{536}
+	jmp	.LBB{91}
+.LBB{537}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	je	.LBB{507}
+	movzbl	%bpl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{538}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{96}
+.LBB{539}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{540}
+	xorl	%edx, %edx
+	jmp	.LBB{541}
+.LBB{542}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{543}
+.LBB{544}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{484}
+.Ltmp{545}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{546}:
+	jmp	.LBB{484}
+.LBB{547}:
+	cmpl	$16, %r8d
+	jae	.LBB{13}
+.LBB{296}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{181}
+.LBB{548}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{549}
+	cmpq	%rdi, %rax
+	jae	.LBB{549}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{550}
+.LBB{551}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{549}
+.LBB{550}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{551}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{551}
+	jmp	.LBB{23}
+.LBB{552}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{553}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{112}
+.LBB{554}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{555}
+.Ltmp{556}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{557}:
+	jmp	.LBB{555}
+.LBB{558}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{559}
+.LBB{560}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{561}
+.Ltmp{562}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{563}:
+.LBB{561}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{564}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{464}
+	jmp	.LBB{15}
+.LBB{565}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{424}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{313}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{566}
+	cmpq	%rdi, %rax
+	jae	.LBB{566}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{567}
+.LBB{568}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{566}
+.LBB{567}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{568}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{568}
+	jmp	.LBB{23}
+.LBB{569}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+.LBB{238}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{570}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{571}
+	cmpq	%rdi, %rax
+	jae	.LBB{571}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{572}
+.LBB{573}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{571}
+.LBB{572}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{573}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{573}
+	jmp	.LBB{23}
+.LBB{574}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{205}
+	jmp	.LBB{46}
+.LBB{575}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{576}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{577}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{577}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	jbe	.LBB{433}
+	jmp	.LBB{15}
+.LBB{578}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{579}
+	cmpq	%rdi, %rax
+	jae	.LBB{579}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{580}
+.LBB{581}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{579}
+.LBB{580}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{581}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{581}
+	jmp	.LBB{23}
+.LBB{582}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{583}
+.LBB{584}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{585}
+.Ltmp{586}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{587}:
+.LBB{585}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{588}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{513}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{55}
+.LBB{589}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{70}
+.Ltmp{590}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{591}:
+	jmp	.LBB{70}
+.LBB{592}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{594}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{595}:
+	jmp	.LBB{593}
+.LBB{596}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{429}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{157}
+.LBB{597}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{93}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{599}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{484}
+.Ltmp{600}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{601}:
+	jmp	.LBB{484}
+.LBB{602}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{516}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{55}
+	jmp	.LBB{4}
+.LBB{603}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{59}
+.LBB{604}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{605}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{207}
+	jmp	.LBB{46}
+.LBB{606}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{607}
+	cmpq	%r9, %r8
+	jae	.LBB{607}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{608}
+.LBB{609}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{607}
+.LBB{608}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{609}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{609}
+	jmp	.LBB{23}
+.LBB{610}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{611}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{530}
+.LBB{529}:
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%r13,8), %rax
+	jmp	.LBB{612}
+.LBB{613}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{614}
+	cmpq	%rdi, %rax
+	jae	.LBB{614}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{615}
+.LBB{616}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{614}
+.LBB{615}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{616}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{616}
+	jmp	.LBB{23}
+.LBB{617}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{618}
+	xorl	%edx, %edx
+	jmp	.LBB{619}
+.LBB{620}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{55}
+	jmp	.LBB{543}
+.LBB{621}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{96}
+.LBB{622}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r13b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{507}:
+	addq	$2, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{623}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{624}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{59}
+	jmp	.LBB{4}
+.LBB{625}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{626}
+	cmpq	%rdi, %rax
+	jae	.LBB{626}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{627}
+.LBB{628}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{626}
+.LBB{627}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{628}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{628}
+	jmp	.LBB{23}
+.LBB{629}:
+	cmpl	$16, %r8d
+	jae	.LBB{13}
+.LBB{282}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{181}
+.LBB{630}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{631}
+	cmpq	%r9, %r8
+	jae	.LBB{631}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{632}
+.LBB{633}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{631}
+.LBB{632}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{633}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{633}
+	jmp	.LBB{23}
+.LBB{634}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{440}
+.Ltmp{635}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{636}:
+	jmp	.LBB{440}
+.LBB{637}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{638}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{101}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{639}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{112}
+.LBB{640}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{641}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{642}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{643}
+	cmpq	$-1, %rcx
+	je	.LBB{644}
+.LBB{643}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{645}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{646}
+	jmp	.LBB{91}
+.LBB{647}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{648}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{649}
+	cmpq	$-1, %rcx
+	je	.LBB{650}
+.LBB{649}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{651}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{652}
+	jmp	.LBB{91}
+.LBB{653}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{236}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{654}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{59}
+	jmp	.LBB{559}
+.LBB{655}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{96}
+	jmp	.LBB{4}
+.LBB{656}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{657}
+	xorl	%edx, %edx
+	jmp	.LBB{658}
+.LBB{659}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{660}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{555}
+.Ltmp{661}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{662}:
+.LBB{555}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+.LBB{55}:
+	movzbl	%bpl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{663}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{287}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{181}
+	jmp	.LBB{4}
+.LBB{664}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+.LBB{277}:
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{665}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+.LBB{367}:
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{464}:
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{666}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{313}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{435}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{667}
+	cmpq	%r9, %r8
+	jae	.LBB{667}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{668}
+.LBB{669}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{667}
+.LBB{668}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{669}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{669}
+	jmp	.LBB{23}
+.LBB{670}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{112}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{671}:
+	cmpl	$16, %r8d
+	jae	.LBB{93}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{672}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435456, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{673}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{674}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{675}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{112}
+	jmp	.LBB{4}
+.LBB{676}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{677}
+	cmpq	%r9, %r8
+	jae	.LBB{677}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{678}
+.LBB{679}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{677}
+.LBB{678}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{679}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{679}
+	jmp	.LBB{23}
+.LBB{680}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{681}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{682}
+	cmpq	$-1, %rcx
+	je	.LBB{683}
+.LBB{682}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{684}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{685}
+	jmp	.LBB{22}
+.LBB{686}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$15, %r8d
+	ja	.LBB{75}
+.LBB{224}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{128}
+.LBB{687}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{688}:
+	cmpl	$16, %r8d
+	jae	.LBB{93}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	jmp	.LBB{583}
+.LBB{689}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{690}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{691}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r13d, %eax
+.LBB{358}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{692}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{692}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{433}:
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{693}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{694}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{695}
+	xorl	%edx, %edx
+	jmp	.LBB{696}
+.LBB{697}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{112}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{698}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{699}
+	cmpq	%r9, %r8
+	jae	.LBB{699}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{700}
+.LBB{701}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{699}
+.LBB{700}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{701}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{701}
+	jmp	.LBB{23}
+.LBB{702}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{703}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{101}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{704}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{181}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{705}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{706}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{707}
+.Ltmp{708}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{709}:
+.LBB{707}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{59}
+.LBB{710}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{450}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{157}
+.LBB{711}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{712}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{713}
+	cmpq	$-1, %rcx
+	je	.LBB{714}
+.LBB{713}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{715}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{716}
+	jmp	.LBB{91}
+.LBB{717}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{718}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{719}
+	cmpq	%r9, %r8
+	jae	.LBB{719}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{720}
+.LBB{721}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{719}
+.LBB{720}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{721}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{721}
+	jmp	.LBB{23}
+.LBB{722}:
+	cmpl	$16, %r8d
+	jae	.LBB{13}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{723}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+.LBB{292}:
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{724}:
+	cmpl	$16, %r8d
+	jae	.LBB{93}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{725}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{502}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{357}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{726}
+	cmpq	%rdi, %rax
+	jae	.LBB{726}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{727}
+.LBB{728}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{726}
+.LBB{727}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{728}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{728}
+	jmp	.LBB{23}
+.LBB{729}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{730}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{731}
+	xorl	%edx, %edx
+	jmp	.LBB{732}
+.LBB{733}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{734}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{735}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{736}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{737}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{738}
+	cmpq	%r9, %r8
+	jae	.LBB{738}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{739}
+.LBB{740}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{738}
+.LBB{739}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{740}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{740}
+	jmp	.LBB{23}
+.LBB{741}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{742}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{742}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{743}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{744}
+.Ltmp{745}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{746}:
+	jmp	.LBB{744}
+.LBB{747}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{748}
+.Ltmp{749}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{750}:
+.LBB{748}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{96}
+.LBB{751}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{752}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{313}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{753}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{84}
+.Ltmp{754}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{755}:
+	jmp	.LBB{84}
+.LBB{756}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{757}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{27}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{758}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{759}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{744}
+.Ltmp{760}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{761}:
+	jmp	.LBB{744}
+.LBB{762}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{285}:
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{128}
+.LBB{763}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{93}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{764}:
+	movq	$-1, 8(%rsp)
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{23}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{440}
+.Ltmp{765}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{766}:
+.LBB{440}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{157}
+.LBB{767}:
+	movq	$-1, 8(%rsp)
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{294}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{128}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{768}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{313}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{769}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{769}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{770}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{771}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{772}
+	cmpq	$-1, %rcx
+	je	.LBB{773}
+.LBB{772}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{774}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{775}
+	jmp	.LBB{91}
+.LBB{776}:
+	movq	$-1, 8(%rsp)
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{357}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{777}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{777}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{290}:
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{778}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r13b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{543}:
+	addq	$2, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{779}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{780}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{472}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{157}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{781}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{88}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{782}
+	xorl	%edx, %edx
+	jmp	.LBB{783}
+.LBB{784}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{559}:
+	addq	$2, %r13
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{785}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{357}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{523}
+	movq	$-1, 8(%rsp)
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{786}
+	cmpq	%r9, %r8
+	jae	.LBB{786}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{787}
+.LBB{788}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{786}
+.LBB{787}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{788}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{788}
+	jmp	.LBB{23}
+.LBB{789}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{482}:
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{790}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{93}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{791}:
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{484}
+.Ltmp{792}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{793}:
+.LBB{484}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{794}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{583}:
+	addq	$2, %r13
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{795}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{796}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{93}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{797}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{186}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{798}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{800}
+	cmpq	%r9, %r8
+	jae	.LBB{800}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{801}
+.LBB{802}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{800}
+.LBB{801}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{802}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{802}
+	jmp	.LBB{23}
+.LBB{803}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{805}
+	cmpq	%rdi, %rax
+	jae	.LBB{805}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{806}
+.LBB{807}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{805}
+.LBB{806}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{807}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{807}
+	jmp	.LBB{23}
+.LBB{808}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{809}
+.Ltmp{810}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{811}:
+	jmp	.LBB{809}
+.LBB{812}:
+	cmpl	$16, %r8d
+	jb	.LBB{813}
+	jmp	.LBB{117}
+.LBB{814}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{59}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{815}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{816}
+.Ltmp{817}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{818}:
+	jmp	.LBB{816}
+.LBB{819}:
+	cmpl	$16, %r8d
+	jb	.LBB{820}
+	jmp	.LBB{117}
+.LBB{821}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{822}
+.Ltmp{823}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{824}:
+	jmp	.LBB{822}
+.LBB{825}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{809}
+.Ltmp{826}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{827}:
+	jmp	.LBB{809}
+.LBB{828}:
+	cmpl	$16, %r8d
+	jb	.LBB{829}
+	jmp	.LBB{33}
+.LBB{830}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{809}
+.Ltmp{831}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{832}:
+.LBB{809}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{833}:
+	cmpl	$16, %r8d
+	jb	.LBB{834}
+	jmp	.LBB{33}
+.LBB{835}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{43}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{836}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{837}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{838}
+	cmpq	$-1, %rcx
+	je	.LBB{839}
+.LBB{838}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{840}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{22}
+.LBB{841}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{842}
+	xorl	%edx, %edx
+	jmp	.LBB{843}
+.LBB{844}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{845}
+	jmp	.LBB{46}
+.LBB{846}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{847}
+	cmpq	%rdi, %rax
+	jae	.LBB{847}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{848}
+.LBB{849}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{847}
+.LBB{848}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{849}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{849}
+	jmp	.LBB{23}
+.LBB{850}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{49}
+.Ltmp{851}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{852}:
+.LBB{49}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{59}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{853}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{265}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{214}
+.LBB{854}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{855}
+	cmpq	%r9, %r8
+	jae	.LBB{855}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{856}
+.LBB{857}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{855}
+.LBB{856}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{857}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{857}
+	jmp	.LBB{23}
+.LBB{858}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	andq	%rsi, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	andq	%rdi, %rcx
+	imulq	%r9, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{859}
+.LBB{860}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{861}
+	jmp	.LBB{117}
+.LBB{862}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{272}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+.LBB{214}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{863}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{59}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{864}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{865}
+	cmpq	%rdi, %rax
+	jae	.LBB{865}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{866}
+.LBB{867}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{865}
+.LBB{866}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{867}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{867}
+	jmp	.LBB{23}
+.LBB{868}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{59}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{869}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{870}
+	cmpq	%r9, %r8
+	jae	.LBB{870}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{871}
+.LBB{872}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{870}
+.LBB{871}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{872}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{872}
+	jmp	.LBB{23}
+.LBB{873}:
+	cmpl	$16, %r8d
+	jb	.LBB{874}
+	jmp	.LBB{117}
+.LBB{875}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{876}
+	cmpq	%rdi, %rax
+	jae	.LBB{876}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{877}
+.LBB{878}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{876}
+.LBB{877}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{878}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{878}
+	jmp	.LBB{23}
+.LBB{879}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{880}
+	cmpq	%r9, %r8
+	jae	.LBB{880}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{881}
+.LBB{882}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{880}
+.LBB{881}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{882}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{882}
+	jmp	.LBB{23}
+.LBB{883}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+.LBB{874}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{43}
+.LBB{884}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{885}
+	cmpq	%r9, %r8
+	jae	.LBB{885}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{886}
+.LBB{887}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{885}
+.LBB{886}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{887}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{887}
+	jmp	.LBB{23}
+.LBB{888}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{889}
+	cmpq	%rdi, %rax
+	jae	.LBB{889}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{890}
+.LBB{891}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{889}
+.LBB{890}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{891}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{891}
+	jmp	.LBB{23}
+.LBB{892}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{816}
+.Ltmp{893}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{894}:
+.LBB{816}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{895}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{372}
+.LBB{896}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{845}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{372}
+.LBB{897}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{372}
+.LBB{898}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{43}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{899}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{900}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{901}:
+	jmp	.LBB{593}
+.LBB{902}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+.LBB{829}:
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{903}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{904}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{905}:
+	jmp	.LBB{593}
+.LBB{906}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+.LBB{834}:
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{907}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{744}
+.Ltmp{908}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{909}:
+	jmp	.LBB{744}
+.LBB{910}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+.LBB{813}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{43}
+.LBB{911}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{372}
+.LBB{912}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{372}
+.LBB{913}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{822}
+.Ltmp{914}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{915}:
+.LBB{822}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{43}
+.LBB{916}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{917}
+	cmpq	%r9, %r8
+	jae	.LBB{917}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{918}
+.LBB{919}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{917}
+.LBB{918}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{919}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{919}
+	jmp	.LBB{23}
+.LBB{920}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{921}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{922}:
+.LBB{593}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{923}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{27}
+.LBB{861}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{372}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{924}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{925}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{926}
+	cmpq	$-1, %rcx
+	je	.LBB{927}
+.LBB{926}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{928}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{372}
+	jmp	.LBB{22}
+.LBB{929}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{930}
+	xorl	%edx, %edx
+	jmp	.LBB{931}
+.LBB{932}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{933}
+	cmpq	%rdi, %rax
+	jae	.LBB{933}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{934}
+.LBB{935}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{933}
+.LBB{934}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{935}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{935}
+	jmp	.LBB{23}
+.LBB{936}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{84}
+.Ltmp{937}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{938}:
+	jmp	.LBB{84}
+.LBB{939}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+.LBB{820}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{43}
+	jmp	.LBB{4}
+.LBB{940}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{70}
+.Ltmp{941}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{942}:
+	jmp	.LBB{70}
+.LBB{943}:
+	cmpl	$16, %r8d
+	jae	.LBB{117}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{744}
+.Ltmp{944}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{945}:
+.LBB{744}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{946}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{84}
+.Ltmp{947}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{948}:
+.LBB{84}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{181}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{949}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+.LBB{353}:
+	incq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{950}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+.LBB{349}:
+	decq	16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{951}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{181}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{952}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{181}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{953}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{112}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{954}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{955}
+	cmpq	%rdi, %rax
+	jae	.LBB{955}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{956}
+.LBB{957}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{955}
+.LBB{956}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{957}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{957}
+	jmp	.LBB{23}
+.LBB{958}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{959}
+	cmpq	%rdi, %rax
+	jae	.LBB{959}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{960}
+.LBB{961}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{959}
+.LBB{960}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{961}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{961}
+	jmp	.LBB{23}
+.LBB{962}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{963}
+	cmpq	%rdi, %rax
+	jae	.LBB{963}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{964}
+.LBB{965}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{963}
+.LBB{964}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{965}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{965}
+	jmp	.LBB{23}
+.LBB{966}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{112}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{967}:
+	cmpl	$16, %r8d
+	jae	.LBB{193}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{181}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{968}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{969}
+	cmpq	%r9, %r8
+	jae	.LBB{969}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{970}
+.LBB{971}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{969}
+.LBB{970}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{971}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{971}
+	jmp	.LBB{23}
+.LBB{972}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{973}
+	cmpq	%r9, %r8
+	jae	.LBB{973}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{974}
+.LBB{975}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{973}
+.LBB{974}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{975}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{975}
+	jmp	.LBB{23}
+.LBB{976}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{977}
+	cmpq	%r9, %r8
+	jae	.LBB{977}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{978}
+.LBB{979}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{977}
+.LBB{978}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{979}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{979}
+	jmp	.LBB{23}
+.LBB{980}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{452}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{284}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{981}
+	cmpq	%rdi, %rax
+	jae	.LBB{981}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{982}
+.LBB{983}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{981}
+.LBB{982}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{983}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{983}
+	jmp	.LBB{23}
+.LBB{984}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{127}
+	jmp	.LBB{74}
+.LBB{985}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{70}
+.Ltmp{986}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{987}:
+.LBB{70}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{112}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{988}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{74}
+	cmpl	$15, %r8d
+	ja	.LBB{75}
+.LBB{252}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{244}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{989}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{284}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{477}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{990}
+	cmpq	%r9, %r8
+	jae	.LBB{990}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{991}
+.LBB{992}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{990}
+.LBB{991}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{992}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{992}
+	jmp	.LBB{23}
+.LBB{993}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{132}
+.LBB{994}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%cl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{46}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{995}
+	cmpq	%r9, %r8
+	jae	.LBB{995}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{996}
+.LBB{997}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{995}
+.LBB{996}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{997}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{997}
+	jmp	.LBB{23}
+.LBB{998}:
+	movzbl	%cl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%eax, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jb	.LBB{999}
+	jmp	.LBB{23}
+.LBB{1000}:
+	movl	%eax, %esi
+	andb	$15, %sil
+	movl	%ecx, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%sil, %esi
+	movq	16(%rsp,%rsi,8), %rsi
+	imulq	16(%rsp,%r8,8), %rsi
+	movl	%edi, %r8d
+	andl	$15, %r8d
+	movq	%rsi, 16(%rsp,%r8,8)
+	shrb	$4, %al
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%al, %eax
+	subq	16(%rsp,%rax,8), %rcx
+	shrb	$4, %dil
+	movzbl	%dil, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{1001}
+.LBB{1002}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%cl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%eax, %ecx
+	cmpl	$268435455, %esi
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1003}
+	cmpq	%rdi, %rax
+	jae	.LBB{1003}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1004}
+.LBB{1005}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1003}
+.LBB{1004}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1005}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1005}
+	jmp	.LBB{23}
+.LBB{1006}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1007}
+.Ltmp{1008}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1009}:
+	jmp	.LBB{1007}
+.LBB{1010}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rcx
+	subq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1012}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{1013}
+	jmp	.LBB{46}
+.LBB{1014}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{612}
+.LBB{1015}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{132}
+	jmp	.LBB{1001}
+.LBB{1016}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	shll	$8, %eax
+	movzbl	%cl, %ecx
+	orl	%eax, %ecx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1017}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1018}:
+	testq	%r11, %r11
+	je	.LBB{23}
+	leaq	-2(%r9), %rax
+	movq	%rax, 208(%rsp)
+	movq	-16(%r10,%r9,8), %rbx
+	jmp	.LBB{4}
+.LBB{1019}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{132}
+.LBB{1020}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1021}
+.LBB{1022}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	movq	16(%rsp,%rax,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1023}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1024}
+	cmpq	$-1, %rcx
+	je	.LBB{1025}
+.LBB{1024}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1026}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1027}
+	jmp	.LBB{22}
+.LBB{1028}:
+	cmpq	$1024, %r11
+	je	.LBB{23}
+	movq	%rbx, -8(%r10,%r9,8)
+	movq	%r9, 208(%rsp)
+	jmp	.LBB{132}
+.LBB{1029}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	movq	16(%rsp,%rax,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1030}
+	xorl	%edx, %edx
+	jmp	.LBB{1031}
+.LBB{1032}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{20}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1011}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rcx
+	cmpq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{1033}
+.LBB{1034}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{1035}
+	jmp	.LBB{46}
+.LBB{1036}:
+	movzbl	%cl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%eax, %eax
+	jmp	.LBB{1037}
+.LBB{1038}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1039}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{132}:
+	movzbl	%cl, %ecx
+	shll	$8, %eax
+	orl	%ecx, %eax
+	movq	%rax, %rbx
+	jmp	.LBB{4}
+.LBB{1040}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{1041}
+	jmp	.LBB{46}
+.LBB{1042}:
+	movl	%eax, %esi
+	andb	$15, %sil
+	movl	%ecx, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%sil, %esi
+	movq	16(%rsp,%rsi,8), %rsi
+	addq	16(%rsp,%r8,8), %rsi
+	movl	%edi, %r8d
+	andl	$15, %r8d
+	movq	%rsi, 16(%rsp,%r8,8)
+	shrb	$4, %al
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rcx,8), %rax
+	shrb	$4, %dil
+	movzbl	%dil, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+.LBB{1001}:
+	addq	$2, %rdx
+	movq	%rdx, %rbx
+	jmp	.LBB{4}
+.LBB{1043}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jb	.LBB{1044}
+	jmp	.LBB{46}
+.LBB{1045}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{43}
+.LBB{1046}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1047}
+	cmpq	%r9, %r8
+	jae	.LBB{1047}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1048}
+.LBB{1049}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1047}
+.LBB{1048}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1049}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1049}
+	jmp	.LBB{23}
+.LBB{1050}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+.LBB{999}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1051}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1052}
+.LBB{1053}:
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1054}
+	cmpq	%rdi, %rax
+	jae	.LBB{1054}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1055}
+.LBB{1056}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1054}
+.LBB{1055}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1056}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1056}
+	jmp	.LBB{23}
+.LBB{1057}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1007}
+.Ltmp{1058}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1059}:
+.LBB{1007}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{1060}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1061}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{1013}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1062}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{343}:
+	movq	16(%rsp,%r14,8), %rax
+.LBB{612}:
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1063}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{43}
+	jmp	.LBB{1052}
+.LBB{1064}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1065}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1066}:
+	movq	%r15, 208(%rsp)
+	movq	200(%rsp), %rax
+	movq	(%rax,%r15,8), %rbx
+	jmp	.LBB{4}
+.LBB{1067}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{43}
+.LBB{1068}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+.LBB{1021}:
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1069}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1070}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1071}
+	cmpq	$-1, %rcx
+	je	.LBB{1072}
+.LBB{1071}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1073}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1074}
+	jmp	.LBB{22}
+.LBB{1075}:
+	cmpq	$1024, %rdx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rdx
+	jne	.LBB{1076}
+.Ltmp{1077}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1078}:
+.LBB{1076}:
+	movq	200(%rsp), %rax
+	movq	%rbx, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{43}
+.LBB{1079}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1080}
+	xorl	%edx, %edx
+	jmp	.LBB{1081}
+.LBB{1082}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+.LBB{1033}:
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1083}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{1035}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1084}:
+	movzbl	%bpl, %ecx
+	leaq	.Lanon{8}(%rip), %r12
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+.LBB{1037}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1085}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{1085}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{1086}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1087}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{43}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1088}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{1041}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1089}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1052}:
+	addq	$2, %r13
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1090}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{46}
+.LBB{1044}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1091}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{36}
+.LBB{1092}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$1024, %rdi
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, -8(%rsi,%rdx,8)
+	movq	%rdx, 232(%rsp)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1093}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1094}
+.Ltmp{1095}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1096}:
+	jmp	.LBB{1094}
+.LBB{1097}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1098}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1099}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{36}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1100}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1101}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1102}
+	cmpq	$-1, %rcx
+	je	.LBB{1103}
+.LBB{1102}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1104}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{387}
+	jmp	.LBB{22}
+.LBB{1105}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1106}
+	xorl	%edx, %edx
+	jmp	.LBB{1107}
+.LBB{1108}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1109}
+	cmpq	%rdi, %rax
+	jae	.LBB{1109}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1110}
+.LBB{1111}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1109}
+.LBB{1110}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1111}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1111}
+	jmp	.LBB{23}
+.LBB{1112}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	shll	$8, %r13d
+	movzbl	%bpl, %eax
+	orl	%r13d, %eax
+	jmp	.LBB{387}
+.LBB{1113}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{1114}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r13,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{387}
+.LBB{1115}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{387}
+.LBB{1116}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1117}
+	cmpq	%r9, %r8
+	jae	.LBB{1117}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1118}
+.LBB{1119}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1117}
+.LBB{1118}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1119}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1119}
+	jmp	.LBB{23}
+.LBB{1120}:
+	testq	%rdi, %rdi
+	je	.LBB{23}
+	leaq	-2(%rdx), %rax
+	movq	%rax, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	-16(%rsi,%rdx,8), %rax
+	jmp	.LBB{387}
+.LBB{1121}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{387}
+.LBB{1122}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{36}
+.LBB{1123}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{108}
+.LBB{1124}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1125}
+	cmpq	%r9, %r8
+	jae	.LBB{1125}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1126}
+.LBB{1127}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1125}
+.LBB{1126}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1127}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1127}
+	jmp	.LBB{23}
+.LBB{1128}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1129}
+	cmpq	%rdi, %rax
+	jae	.LBB{1129}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1130}
+.LBB{1131}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1129}
+.LBB{1130}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1131}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1131}
+	jmp	.LBB{23}
+.LBB{1132}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$1024, %rdx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %rbx
+	cmpq	216(%rsp), %rdx
+	jne	.LBB{1133}
+.Ltmp{1134}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1135}:
+.LBB{1133}:
+	movq	224(%rsp), %rax
+	movq	%rbx, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 232(%rsp)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1136}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1137}
+.LBB{1138}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{1114}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r13,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1137}
+.LBB{1139}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{36}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1140}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	shll	$8, %r13d
+	movzbl	%bpl, %eax
+	orl	%r13d, %eax
+	jmp	.LBB{1137}
+.LBB{1141}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1142}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jne	.LBB{4}
+.LBB{108}:
+	movzbl	%bpl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1143}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1137}
+.LBB{1144}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1145}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1146}
+	cmpq	$-1, %rcx
+	je	.LBB{1147}
+.LBB{1146}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1148}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1137}
+	jmp	.LBB{22}
+.LBB{1149}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1094}
+.Ltmp{1150}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1151}:
+.LBB{1094}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{36}:
+	movzbl	%bpl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1152}:
+	movq	%r15, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	224(%rsp), %rax
+	movq	(%rax,%r15,8), %rax
+	jmp	.LBB{387}
+.LBB{1153}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1154}
+	xorl	%edx, %edx
+	jmp	.LBB{1155}
+.LBB{1156}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1157}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{407}
+.LBB{1158}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1159}
+.Ltmp{1160}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1161}:
+	jmp	.LBB{1159}
+.LBB{1162}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1163}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r10
+	addq	%r8, %r10
+	setb	%r9b
+	cmpq	%rsi, %r10
+	seta	%r11b
+	orb	%r9b, %r11b
+	jne	.LBB{23}
+	movq	184(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{1164}
+	cmpq	%r10, %r8
+	jae	.LBB{1164}
+	movq	176(%rsp), %r10
+	movq	%r8, %r11
+	jmp	.LBB{1165}
+.LBB{1166}:
+	incq	%r11
+	decq	%rax
+	je	.LBB{1164}
+.LBB{1165}:
+	movq	%r11, %rbx
+	shrq	$6, %rbx
+	cmpq	%r9, %rbx
+	jae	.LBB{1166}
+	movq	16(%r10,%rbx,8), %rbx
+	btq	%r11, %rbx
+	jae	.LBB{1166}
+	jmp	.LBB{23}
+.LBB{1167}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%rsi, %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	(%rdi,%rax,8), %rax
+	jmp	.LBB{407}
+.LBB{1168}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{134}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1169}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{1169}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1170}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1163}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %r8
+	addq	%rdx, %r8
+	jb	.LBB{23}
+	cmpq	%rsi, %r8
+	ja	.LBB{23}
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1171}
+	cmpq	%r8, %rdx
+	jae	.LBB{1171}
+	movq	176(%rsp), %r8
+	movq	%rax, %r9
+	movq	%rdx, %r10
+	jmp	.LBB{1172}
+.LBB{1173}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{1171}
+.LBB{1172}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rsi, %r11
+	jae	.LBB{1173}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1173}
+	jmp	.LBB{23}
+.LBB{1174}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1175}
+.Ltmp{1176}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1177}:
+	jmp	.LBB{1175}
+.LBB{1178}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1159}
+.Ltmp{1179}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1180}:
+.LBB{1159}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1181}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1182}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{139}
+.LBB{138}:
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{407}
+.LBB{1183}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1184}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1185}
+	cmpq	$-1, %rcx
+	je	.LBB{1186}
+.LBB{1185}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1187}
+.LBB{1188}:
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{407}
+	jmp	.LBB{22}
+.LBB{1189}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1190}
+.LBB{1191}:
+	xorl	%edx, %edx
+	jmp	.LBB{1192}
+.LBB{1193}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1194}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{804}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1195}
+	cmpq	%rdi, %rax
+	jae	.LBB{1195}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1196}
+.LBB{1197}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1195}
+.LBB{1196}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1197}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1197}
+	jmp	.LBB{23}
+.LBB{1198}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1184}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1199}
+	cmpq	$-1, %rcx
+	je	.LBB{1186}
+.LBB{1199}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	jne	.LBB{1188}
+.LBB{1187}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{407}
+	jmp	.LBB{22}
+.LBB{1200}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1175}
+.Ltmp{1201}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1202}:
+.LBB{1175}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{96}:
+	movzbl	%bpl, %eax
+	jmp	.LBB{498}
+.LBB{1203}:
+	cmpl	$16, %r8d
+	jae	.LBB{33}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{134}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{799}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1204}
+	cmpq	%r9, %r8
+	jae	.LBB{1204}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1205}
+.LBB{1206}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1204}
+.LBB{1205}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1206}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1206}
+	jmp	.LBB{23}
+.LBB{1207}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jae	.LBB{1191}
+.LBB{1190}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1208}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1192}
+.LBB{1209}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{46}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, %rax
+	shrq	$6, %rax
+	cmpq	184(%rsp), %rax
+	jae	.LBB{1210}
+	movl	$1, %edx
+	shlq	%cl, %rdx
+	cmpq	%rsi, %rcx
+	jae	.LBB{23}
+	movq	176(%rsp), %rsi
+	andq	16(%rsi,%rax,8), %rdx
+	je	.LBB{1211}
+	jmp	.LBB{23}
+.LBB{303}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{197}:
+	cmpl	$16, %r8d
+	jae	.LBB{75}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1212}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{173}
+.LBB{425}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1213}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1213}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1214}:
+	movzbl	%r12b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{63}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{61}
+.LBB{327}:
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{329}
+.LBB{1215}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{444}
+.Ltmp{1216}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1217}:
+.LBB{444}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1218}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{173}
+	jmp	.LBB{4}
+.LBB{1219}:
+	movzbl	%r12b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{63}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1220}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{1220}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{61}
+.LBB{315}:
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1221}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1222}
+.Ltmp{1223}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1224}:
+.LBB{1222}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{173}
+.LBB{453}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1225}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1225}
+	jmp	.LBB{4}
+.LBB{457}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1226}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1226}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1227}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{173}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1228}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{321}:
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1229}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1230}
+	cmpq	$-1, %rcx
+	je	.LBB{1231}
+.LBB{1230}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1232}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{329}
+	jmp	.LBB{22}
+.LBB{1229}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{329}
+	jmp	.LBB{22}
+.LBB{1233}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{325}:
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1234}
+	xorl	%edx, %edx
+	jmp	.LBB{1235}
+.LBB{1234}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1236}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1235}
+.LBB{1237}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+.LBB{310}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{329}
+.LBB{1238}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{62}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{63}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{23}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{23}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1239}
+	cmpq	%rdi, %rax
+	jae	.LBB{1239}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1240}
+.LBB{1241}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1239}
+.LBB{1240}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1241}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1241}
+	jmp	.LBB{23}
+.LBB{1242}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	shll	$8, %r14d
+	movzbl	%r12b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{329}
+.LBB{1243}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{323}:
+	cmpl	$268435455, %eax
+	ja	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{329}
+.LBB{1244}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+.LBB{226}:
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{4}
+.LBB{1245}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{317}:
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{329}
+.LBB{1246}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	movzbl	%r12b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{63}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1247}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{23}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{23}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1248}
+	cmpq	%r9, %r8
+	jae	.LBB{1248}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1249}
+.LBB{1250}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1248}
+.LBB{1249}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1250}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1250}
+	jmp	.LBB{23}
+.LBB{1251}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+.LBB{185}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{859}
+.LBB{1252}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{61}
+.LBB{334}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{329}
+.LBB{1253}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{494}:
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+.LBB{31}:
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{329}
+.LBB{1254}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+.LBB{331}:
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{329}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1255}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+.LBB{188}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{859}
+.LBB{1256}:
+	cmpl	$16, %r8d
+	jae	.LBB{61}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{173}
+.LBB{1257}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+.LBB{190}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{859}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{503}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1258}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1258}
+	jmp	.LBB{4}
+.LBB{519}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1259}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1259}
+	jmp	.LBB{4}
+.LBB{549}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1260}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1260}
+	jmp	.LBB{4}
+.LBB{566}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1261}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1261}
+	jmp	.LBB{4}
+.LBB{571}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1262}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1262}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{579}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1263}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1263}
+	jmp	.LBB{4}
+.LBB{614}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1264}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1264}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{626}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1265}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1265}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{726}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1266}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1266}
+	jmp	.LBB{4}
+.LBB{805}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1267}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1267}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{847}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1268}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1268}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{865}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1269}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1269}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{876}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1270}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1270}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{889}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1271}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1271}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{933}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1272}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1272}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{955}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1273}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1273}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{959}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1274}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1274}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{963}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1275}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1275}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{981}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1276}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1276}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1003}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1277}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1277}
+	jmp	.LBB{4}
+.LBB{1054}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1278}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1278}
+	jmp	.LBB{4}
+.LBB{1109}:
+	movq	%r14, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1279}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1279}
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1129}:
+	movq	%r14, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1280}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1280}
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1171}:
+	movq	%r13, %rbx
+	testq	%rax, %rax
+	je	.LBB{4}
+	leaq	(%rdi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1281}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1281}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1195}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1282}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1282}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1239}:
+	movq	%r13, %rbx
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1283}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1283}
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1184}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{407}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{243}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{244}
+	jmp	.LBB{91}
+.LBB{263}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{91}
+.LBB{1232}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{329}
+	jmp	.LBB{22}
+.LBB{415}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{248}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{416}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{270}:
+	cmpl	$16, %r8d
+	jb	.LBB{1284}
+	jmp	.LBB{91}
+.LBB{1236}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1235}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{474}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1285}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{475}
+.LBB{540}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1286}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{541}
+.LBB{618}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1287}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{619}
+.LBB{657}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1288}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{658}
+.LBB{695}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1289}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{696}
+.LBB{731}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1290}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{732}
+.LBB{782}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1291}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{783}
+.LBB{842}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1292}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{843}
+.LBB{930}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1293}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{931}
+.LBB{1210}:
+	cmpq	%rsi, %rcx
+	jae	.LBB{23}
+.LBB{1211}:
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, (%rdi,%rcx,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1030}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1294}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1031}
+.LBB{1080}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1295}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1081}
+.LBB{1106}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1296}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1107}
+.LBB{1154}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1297}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1155}
+.LBB{436}:
+	movq	160(%rsp), %rdi
+.Ltmp{1298}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1299}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{478}:
+	movq	160(%rsp), %rdi
+.Ltmp{1300}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1301}:
+	jmp	.LBB{4}
+.LBB{489}:
+	movq	160(%rsp), %rdi
+.Ltmp{1302}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1303}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{524}:
+	movq	160(%rsp), %rdi
+.Ltmp{1304}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1305}:
+	jmp	.LBB{4}
+.LBB{607}:
+	movq	160(%rsp), %rdi
+.Ltmp{1306}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1307}:
+	jmp	.LBB{4}
+.LBB{631}:
+	movq	160(%rsp), %rdi
+.Ltmp{1308}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1309}:
+	jmp	.LBB{4}
+.LBB{667}:
+	movq	160(%rsp), %rdi
+.Ltmp{1310}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1311}:
+	jmp	.LBB{4}
+.LBB{677}:
+	movq	160(%rsp), %rdi
+.Ltmp{1312}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1313}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{699}:
+	movq	160(%rsp), %rdi
+.Ltmp{1314}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1315}:
+	jmp	.LBB{4}
+.LBB{719}:
+	movq	160(%rsp), %rdi
+.Ltmp{1316}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1317}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{738}:
+	movq	160(%rsp), %rdi
+.Ltmp{1318}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1319}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{786}:
+	movq	160(%rsp), %rdi
+.Ltmp{1320}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1321}:
+	jmp	.LBB{4}
+.LBB{800}:
+	movq	160(%rsp), %rdi
+.Ltmp{1322}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1323}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{855}:
+	movq	160(%rsp), %rdi
+.Ltmp{1324}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1325}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{870}:
+	movq	160(%rsp), %rdi
+.Ltmp{1326}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1327}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{880}:
+	movq	160(%rsp), %rdi
+.Ltmp{1328}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1329}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{885}:
+	movq	160(%rsp), %rdi
+.Ltmp{1330}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1331}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{917}:
+	movq	160(%rsp), %rdi
+.Ltmp{1332}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1333}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{969}:
+	movq	160(%rsp), %rdi
+.Ltmp{1334}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1335}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{973}:
+	movq	160(%rsp), %rdi
+.Ltmp{1336}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1337}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{977}:
+	movq	160(%rsp), %rdi
+.Ltmp{1338}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1339}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{990}:
+	movq	160(%rsp), %rdi
+.Ltmp{1340}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1341}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1342}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jns	.LBB{4}
+	jmp	.LBB{173}
+.LBB{1343}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	movzbl	%r12b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{149}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1344}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	setb	%sil
+	cmpq	%rbp, %rcx
+	seta	%dil
+	orb	%sil, %dil
+	jne	.LBB{23}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %rsi
+	addq	%r8, %rsi
+	setb	%dil
+	cmpq	%rbp, %rsi
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{23}
+	testq	%r13, %r13
+	je	.LBB{1345}
+	cmpq	%rsi, %r8
+	jae	.LBB{1345}
+	movq	176(%rsp), %rsi
+	movq	%r8, %rdi
+	jmp	.LBB{1346}
+.LBB{1347}:
+	incq	%rdi
+	decq	%rax
+	je	.LBB{1345}
+.LBB{1346}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%r13, %r9
+	jae	.LBB{1347}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1347}
+	jmp	.LBB{23}
+.LBB{1348}:
+	movzbl	%r12b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{149}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%rbp, %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{148}
+	movq	336(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1349}
+.LBB{1350}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	movzbl	%r12b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{149}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1344}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	setb	%dil
+	cmpq	%rbp, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{23}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%r13, %r13
+	je	.LBB{1351}
+	cmpq	%rsi, %rdx
+	jae	.LBB{1351}
+	movq	176(%rsp), %rsi
+	movq	%rax, %rdi
+	movq	%rdx, %r8
+	jmp	.LBB{1352}
+.LBB{1353}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{1351}
+.LBB{1352}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%r13, %r9
+	jae	.LBB{1353}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{1353}
+	jmp	.LBB{23}
+.LBB{1354}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	%r10, %r12
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1355}
+.Ltmp{1356}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1357}:
+.LBB{1355}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r12, %rbx
+	jmp	.LBB{4}
+.LBB{1358}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1359}
+.LBB{1360}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1359}
+.LBB{1361}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	cmpl	$268435455, %eax
+	ja	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1359}
+.LBB{1362}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{173}
+	addq	$3, %rbx
+	jmp	.LBB{4}
+.LBB{1363}:
+	cmpl	$16, %r8d
+	jae	.LBB{1364}
+	shll	$8, %r14d
+	movzbl	%r12b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{1359}
+.LBB{1365}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	decq	16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{1366}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jne	.LBB{4}
+	jmp	.LBB{173}
+.LBB{1367}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1359}
+.LBB{1368}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1369}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1370}
+	cmpq	$-1, %rcx
+	je	.LBB{1371}
+.LBB{1370}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1372}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1359}
+	jmp	.LBB{22}
+.LBB{1373}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{23}
+	movq	%r10, %r15
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1374}
+.Ltmp{1375}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1376}:
+.LBB{1374}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{173}
+.LBB{1377}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{23}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{148}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{1349}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{1378}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1379}
+	xorl	%edx, %edx
+	jmp	.LBB{1380}
+.LBB{1381}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{20}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1359}
+.LBB{1382}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1383}
+.LBB{1384}:
+	movzbl	%r12b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{149}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%r13, %rcx
+	jae	.LBB{1385}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{23}
+.LBB{1385}:
+	cmpq	%rbp, %rax
+	jae	.LBB{23}
+	cmpl	$15, %r8d
+	ja	.LBB{148}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	336(%rsp), %rdx
+	movq	%rcx, (%rdx,%rax,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{1386}:
+	cmpl	$16, %r8d
+	jae	.LBB{301}
+	incq	16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{1387}:
+	cmpl	$16, %r8d
+	jae	.LBB{148}
+	cmpq	$0, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	js	.LBB{4}
+.LBB{173}:
+	movzbl	%r12b, %eax
+.LBB{498}:
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1388}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{1383}
+.LBB{1389}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{45}(%rip), %r12
+	cmpl	$16, %eax
+	jae	.LBB{311}
+	cmpl	$15, %r8d
+	ja	.LBB{33}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{1383}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{995}:
+	movq	160(%rsp), %rdi
+.Ltmp{1390}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1391}:
+	jmp	.LBB{4}
+.LBB{1047}:
+	movq	160(%rsp), %rdi
+.Ltmp{1392}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1393}:
+	jmp	.LBB{4}
+.LBB{1117}:
+	movq	160(%rsp), %rdi
+.Ltmp{1394}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1395}:
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1125}:
+	movq	160(%rsp), %rdi
+.Ltmp{1396}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1397}:
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1164}:
+.Ltmp{1398}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1399}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1204}:
+	movq	160(%rsp), %rdi
+.Ltmp{1400}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1401}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1248}:
+	movq	160(%rsp), %rdi
+.Ltmp{1402}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1403}:
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{466}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{470}
+	jmp	.LBB{91}
+.LBB{532}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{536}
+	jmp	.LBB{91}
+.LBB{642}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{646}
+	jmp	.LBB{91}
+.LBB{648}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{652}
+	jmp	.LBB{91}
+.LBB{681}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{685}
+	jmp	.LBB{22}
+.LBB{712}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{716}
+	jmp	.LBB{91}
+.LBB{771}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{775}
+	jmp	.LBB{91}
+.LBB{837}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{22}
+.LBB{925}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{372}
+	jmp	.LBB{22}
+.LBB{1023}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1027}
+	jmp	.LBB{22}
+.LBB{1070}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1074}
+	jmp	.LBB{22}
+.LBB{1101}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{387}
+	jmp	.LBB{22}
+.LBB{1145}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1137}
+	jmp	.LBB{22}
+.LBB{1208}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1192}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{469}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{470}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{535}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{536}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{645}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{646}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{651}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{652}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{684}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{685}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{715}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{716}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{774}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{91}
+.LBB{775}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{840}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{22}
+.LBB{928}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	jbe	.LBB{372}
+	jmp	.LBB{22}
+.LBB{1026}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{1027}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1073}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{1074}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1104}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{387}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1148}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{1137}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1379}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1404}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1380}
+.LBB{1351}:
+	movq	%r10, %rbx
+	testq	%rax, %rax
+	je	.LBB{4}
+	movq	%r10, %rdi
+	movq	336(%rsp), %rsi
+	leaq	(%rsi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1405}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1405}
+	movq	%rdi, %rbx
+	jmp	.LBB{4}
+.LBB{1285}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{475}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1286}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{541}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1287}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{619}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1288}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{658}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1289}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{696}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1290}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{732}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1291}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{783}:
+	cmpl	$16, %r8d
+	jae	.LBB{91}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1292}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{843}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+.LBB{1284}:
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1293}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{931}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r13, %rbx
+	jmp	.LBB{4}
+.LBB{1294}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1031}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1295}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1081}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1296}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1107}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1297}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1155}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r14, %rbx
+	jmp	.LBB{4}
+.LBB{1369}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1359}
+	jmp	.LBB{22}
+.LBB{1372}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{22}
+.LBB{1359}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{1345}:
+.Ltmp{1406}:
+	movq	336(%rsp), %rdi
+	movq	%rbp, %rsi
+	movq	%r10, %rbx
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1407}:
+	jmp	.LBB{4}
+.LBB{1404}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1380}:
+	cmpl	$16, %r8d
+	jae	.LBB{22}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	%r10, %rbx
+	jmp	.LBB{4}
+.LBB{121}:
+	movabsq	$-9223372036854775808, %rax
+	movq	$-1, 8(%rsp)
+	cmpl	$16, %r8d
+	jb	.LBB{123}
+	jmp	.LBB{91}
+.LBB{201}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{128}
+	jmp	.LBB{75}
+.LBB{242}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{244}
+	jmp	.LBB{91}
+.LBB{262}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{91}
+.LBB{1231}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{329}
+	jmp	.LBB{22}
+.LBB{1186}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{407}
+	jmp	.LBB{22}
+.LBB{468}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{470}
+	jmp	.LBB{91}
+.LBB{534}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{536}
+	jmp	.LBB{91}
+.LBB{644}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{646}
+	jmp	.LBB{91}
+.LBB{650}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{652}
+	jmp	.LBB{91}
+.LBB{683}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{685}
+	jmp	.LBB{22}
+.LBB{714}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{716}
+	jmp	.LBB{91}
+.LBB{773}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{775}
+	jmp	.LBB{91}
+.LBB{839}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{214}
+	jmp	.LBB{22}
+.LBB{927}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{372}
+	jmp	.LBB{22}
+.LBB{1025}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1027}
+	jmp	.LBB{22}
+.LBB{1072}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1074}
+	jmp	.LBB{22}
+.LBB{1103}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{387}
+	jmp	.LBB{22}
+.LBB{1147}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1137}
+	jmp	.LBB{22}
+.LBB{1371}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1359}
+.LBB{22}:
+	movq	%r8, %rdi
+	jmp	.LBB{20}
+.LBB{5}:
+	movq	$-1, 8(%rsp)
+.LBB{23}:
+	movq	152(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1408}
+.LBB{1409}:
+	movq	160(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1408}:
+	movq	176(%rsp), %rax
+	lock		decq	(%rax)
+	movq	8(%rsp), %rbx
+	jne	.LBB{1410}
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{1410}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1411}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1411}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1412}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1412}:
+	movq	%rbx, %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1413}:
+	cmpl	$16, %r8d
+	jb	.LBB{1414}
+.LBB{117}:
+	leaq	.Lanon{8}(%rip), %r12
+	jmp	.LBB{33}
+.LBB{1415}:
+	movq	%rdi, %r12
+.LBB{1416}:
+	cmpl	$16, %r8d
+	jb	.LBB{1414}
+	jmp	.LBB{33}
+.LBB{1417}:
+	cmpl	$16, %r8d
+	jae	.LBB{27}
+.LBB{1414}:
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, 8(%rsp)
+	movq	152(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{1409}
+	jmp	.LBB{1408}
+.LBB{1}:
+	movq	%rdx, 16(%rsp)
+	leaq	16(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{1418}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{1419}(%rip), %rdi
+	leaq	.Lanon{1420}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{1421}:
+	movq	%r11, %r12
+	jmp	.LBB{1416}
+.LBB{1422}:
+	movq	%r15, %r12
+	jmp	.LBB{1416}
+.LBB{175}:
+.Ltmp{1423}:
+	leaq	.Lanon{1424}(%rip), %rdi
+	leaq	.Lanon{1425}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{1426}:
+	jmp	.LBB{1427}
+.LBB{46}:
+	movq	%rax, %r8
+	jmp	.LBB{33}
+.LBB{9}:
+	movq	%rdi, %r12
+	jmp	.LBB{33}
+.LBB{91}:
+	movq	%r8, %rdi
+	jmp	.LBB{20}
+.LBB{75}:
+	movq	%r8, %rdi
+	jmp	.LBB{20}
+.LBB{88}:
+	movq	%rcx, %rdi
+	jmp	.LBB{20}
+.LBB{134}:
+	movq	%rcx, %r8
+	jmp	.LBB{33}
+.LBB{21}:
+	movq	%rcx, %rdi
+	jmp	.LBB{20}
+.LBB{74}:
+	movq	%rcx, %rdi
+	jmp	.LBB{20}
+.LBB{15}:
+	movq	%rdi, %r12
+	jmp	.LBB{33}
+.LBB{186}:
+	movq	%rax, %r8
+	jmp	.LBB{33}
+.LBB{27}:
+	leaq	.Lanon{8}(%rip), %r12
+	jmp	.LBB{33}
+.LBB{61}:
+	movq	%r11, %r12
+	jmp	.LBB{33}
+.LBB{193}:
+	leaq	.Lanon{45}(%rip), %r12
+	jmp	.LBB{33}
+.LBB{93}:
+	movq	%rdi, %r12
+	jmp	.LBB{33}
+.LBB{148}:
+	movq	%r15, %r12
+	jmp	.LBB{33}
+.LBB{63}:
+	movq	%r11, %r12
+	movq	%rcx, %r8
+	jmp	.LBB{33}
+.LBB{149}:
+	movq	%r15, %r12
+	movq	%rcx, %r8
+	jmp	.LBB{33}
+.LBB{62}:
+	movq	%r11, %r12
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{150}:
+	movq	%r15, %r12
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{81}:
+	movq	%r13, %rdi
+	jmp	.LBB{20}
+.LBB{301}:
+	leaq	.Lanon{45}(%rip), %r12
+	jmp	.LBB{33}
+.LBB{357}:
+	movq	%rdi, %r12
+.LBB{284}:
+	movq	%rcx, %r8
+.LBB{33}:
+.Ltmp{1428}:
+	movq	%r8, %rdi
+	movl	$16, %esi
+	movq	%r12, %rdx
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1429}:
+	jmp	.LBB{1427}
+.LBB{311}:
+	movq	%rax, %r8
+	jmp	.LBB{33}
+.LBB{313}:
+	movq	%rcx, %r8
+	jmp	.LBB{33}
+.LBB{101}:
+	movq	%r14, %rdi
+	jmp	.LBB{20}
+.LBB{804}:
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{799}:
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{139}:
+	movq	%r14, %rdi
+	jmp	.LBB{20}
+.LBB{452}:
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{477}:
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{502}:
+	movq	%rdi, %r12
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{523}:
+	movq	%rdi, %r12
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{1011}:
+	movq	%rax, %rdi
+	jmp	.LBB{20}
+.LBB{13}:
+	movq	%rdi, %r12
+	jmp	.LBB{33}
+.LBB{79}:
+	movq	%r14, %rdi
+	jmp	.LBB{20}
+.LBB{598}:
+	movq	%rdi, %r12
+	movq	%rax, %r8
+	jmp	.LBB{33}
+.LBB{424}:
+	movq	%rdx, %r8
+	jmp	.LBB{33}
+.LBB{435}:
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{530}:
+	movq	%r13, %rdi
+	jmp	.LBB{20}
+.LBB{1114}:
+	movq	%r13, %rdi
+.LBB{20}:
+.Ltmp{1430}:
+	leaq	.Lanon{45}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1431}:
+.LBB{1427}:
+	ud2
+.LBB{1163}:
+	movq	%r9, %r8
+	jmp	.LBB{33}
+.LBB{1344}:
+	movq	%r15, %r12
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{1247}:
+	movq	%r11, %r12
+	movq	%rsi, %r8
+	jmp	.LBB{33}
+.LBB{1364}:
+	movq	%r15, %r12
+	jmp	.LBB{33}
+.LBB{1432}:
+.Ltmp{1433}:
+	movq	%rax, %rbx
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{1434}:
+.Ltmp{1435}:
+	jmp	.LBB{1436}
+.LBB{1437}:
+.Ltmp{1438}:
+.LBB{1436}:
+	movq	%rax, %rbx
+	leaq	152(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
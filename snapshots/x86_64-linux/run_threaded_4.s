@@ -0,0 +1,42798 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %rax
+	movq	%rax, 328(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 16(%rsp)
+	movaps	%xmm1, 32(%rsp)
+	movaps	%xmm2, 48(%rsp)
+	movaps	%xmm3, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	144(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%r14d, %r14d
+.LBB{4}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	movq	$-1, 184(%rsp)
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{6}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{7}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	2(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{10}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{11}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{12}
+	jmp	.LBB{13}
+.LBB{14}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{16}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{17}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{18}
+	jmp	.LBB{19}
+.LBB{20}:
+	leaq	.Lanon{8}(%rip), %r11
+	cmpl	$16, %r8d
+	jae	.LBB{21}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{22}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{23}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{24}
+	cmpq	%rdi, %rax
+	jae	.LBB{24}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{25}
+.LBB{26}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{24}
+.LBB{25}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{26}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{26}
+	jmp	.LBB{5}
+.LBB{27}:
+	movzbl	%r13b, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	movq	%rsi, 8(%rsp)
+	jae	.LBB{28}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{29}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{29}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{31}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{32}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{33}
+	jmp	.LBB{34}
+.LBB{35}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	3(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{36}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{37}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{39}
+	jmp	.LBB{40}
+.LBB{41}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{45}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{46}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{47}
+	jmp	.LBB{48}
+.LBB{49}:
+	movq	232(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{5}
+	leaq	-1(%rdx), %rdi
+	movq	%rdi, 232(%rsp)
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$15, %r8d
+	movq	%rcx, 8(%rsp)
+	ja	.LBB{30}
+	movq	224(%rsp), %rsi
+	movq	-8(%rsi,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %r9d
+	leaq	.LJTI{50}(%rip), %r10
+	movslq	(%r10,%r9,4), %r9
+	addq	%r10, %r9
+	jmpq	*%r9
+.LBB{51}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{52}
+	jmp	.LBB{53}
+.LBB{54}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{57}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{58}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{59}
+	jmp	.LBB{60}
+.LBB{61}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rbx
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{64}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{65}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{66}
+	jmp	.LBB{67}
+.LBB{68}:
+	movq	208(%rsp), %rbp
+	cmpq	$1024, %rbp
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbp
+	jne	.LBB{69}
+.Ltmp{70}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{71}:
+.LBB{69}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%rbp,8)
+	leaq	1(%rbp), %rdx
+	movq	%rdx, 208(%rsp)
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %esi
+	leaq	.LJTI{72}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{73}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{74}
+	jmp	.LBB{75}
+.LBB{76}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{77}
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{78}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{79}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{80}
+	jmp	.LBB{81}
+.LBB{82}:
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %r8d
+	movq	%rcx, 8(%rsp)
+	jae	.LBB{30}
+	addq	$2, %r14
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	decq	16(%rsp,%r8,8)
+	cmoveq	%r14, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{83}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{84}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{85}
+	jmp	.LBB{86}
+.LBB{87}:
+	leaq	.Lanon{62}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	2(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{89}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{90}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{91}
+	jmp	.LBB{92}
+.LBB{93}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r15, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{94}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{95}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{96}
+	jmp	.LBB{97}
+.LBB{98}:
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %r8d
+	movq	%rax, 8(%rsp)
+	jae	.LBB{30}
+	movq	232(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rbx
+	cmpq	216(%rsp), %r13
+	jne	.LBB{99}
+.Ltmp{100}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{101}:
+.LBB{99}:
+	movq	224(%rsp), %rax
+	movq	%rbx, (%rax,%r13,8)
+	leaq	1(%r13), %rdx
+	movq	%rdx, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	2(%r14), %r15
+	movzbl	%al, %esi
+	leaq	.LJTI{102}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{103}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{104}
+	jmp	.LBB{105}
+.LBB{106}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{108}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{109}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{110}
+	jmp	.LBB{111}
+.LBB{112}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{113}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{114}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{115}
+	jmp	.LBB{116}
+.LBB{117}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{118}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{119}
+	cmpq	$-1, %rcx
+	je	.LBB{120}
+.LBB{119}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{121}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %r8d
+	jb	.LBB{122}
+	jmp	.LBB{56}
+.LBB{123}:
+	movq	208(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{5}
+	leaq	-1(%r9), %r11
+	movq	%r11, 208(%rsp)
+	movq	200(%rsp), %r10
+	movq	-8(%r10,%r9,8), %rcx
+	movq	328(%rsp), %rax
+	movl	(%rax,%rcx,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %esi
+	shrl	$8, %esi
+	movzbl	%sil, %r8d
+	movl	%edx, %eax
+	shrl	$16, %eax
+	movl	%edx, %edi
+	shrl	$24, %edi
+	leaq	1(%rcx), %r14
+	movzbl	%dl, %ebx
+	leaq	.LJTI{124}(%rip), %r15
+	movslq	(%r15,%rbx,4), %rbx
+	addq	%r15, %rbx
+	jmpq	*%rbx
+.LBB{125}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{126}
+	jmp	.LBB{127}
+.LBB{128}:
+	movzbl	%r13b, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{28}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rdi
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{129}(%rip), %r9
+	movslq	(%r9,%rdx,4), %rdx
+	addq	%r9, %rdx
+	jmpq	*%rdx
+.LBB{130}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{131}
+	jmp	.LBB{132}
+.LBB{133}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{135}
+	xorl	%edx, %edx
+	cmpl	$16, %r8d
+	jb	.LBB{136}
+	jmp	.LBB{137}
+.LBB{138}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{139}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{140}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{141}
+	jmp	.LBB{142}
+.LBB{143}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rcx
+	subq	%rcx, %rax
+	movabsq	$3689348814741910323, %r12
+	movq	%rax, %rcx
+	andq	%r12, %rcx
+	shrq	$2, %rax
+	andq	%r12, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	movabsq	$72340172838076673, %rbx
+	imulq	%rbx, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{146}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{147}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{148}
+	jmp	.LBB{149}
+.LBB{150}:
+	leaq	.Lanon{8}(%rip), %r9
+	cmpl	$16, %r8d
+	jae	.LBB{151}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{152}
+	movl	%r12d, %edx
+	cmpl	$268435455, %eax
+	ja	.LBB{153}
+	movq	16(%rsp,%rcx,8), %rax
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %r13
+	cmpq	%r13, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{5}
+	movq	%r9, 8(%rsp)
+	movq	176(%rsp), %rbx
+	testq	%rbx, %rbx
+	je	.LBB{154}
+	cmpq	%rsi, %rcx
+	jae	.LBB{154}
+	movq	168(%rsp), %rsi
+	movq	%rdx, %rdi
+	movq	%rcx, %r8
+	jmp	.LBB{155}
+.LBB{156}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{154}
+.LBB{155}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{156}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{156}
+	jmp	.LBB{5}
+.LBB{157}:
+	leaq	.Lanon{62}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	2(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{158}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{159}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{160}
+	jmp	.LBB{161}
+.LBB{162}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r15, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{163}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{164}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{165}
+	jmp	.LBB{166}
+.LBB{167}:
+	leaq	.Lanon{8}(%rip), %rbx
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r15, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{168}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{169}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{170}
+	jmp	.LBB{171}
+.LBB{172}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rbx
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{175}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{176}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{177}
+	jmp	.LBB{178}
+.LBB{179}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	3(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{180}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{181}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{182}
+	jmp	.LBB{183}
+.LBB{184}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rbx
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{185}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{186}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{187}
+	jmp	.LBB{188}
+.LBB{24}:
+	testq	%rcx, %rcx
+	je	.LBB{189}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{190}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{190}
+.LBB{189}:
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movq	%r11, 8(%rsp)
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{191}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{192}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{193}
+	jmp	.LBB{194}
+.LBB{135}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{195}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %r8d
+	jb	.LBB{136}
+	jmp	.LBB{137}
+.LBB{154}:
+	movq	%r13, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{196}
+	movq	152(%rsp), %r12
+	leaq	(%r12,%rax,8), %rsi
+	leaq	(%r12,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rdx
+	movq	%rdx, 336(%rsp)
+	movzbl	%al, %edx
+	leaq	.LJTI{197}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{198}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{199}
+	jmp	.LBB{200}
+.LBB{118}:
+	xorl	%eax, %eax
+	cmpl	$16, %r8d
+	jb	.LBB{122}
+	jmp	.LBB{56}
+.LBB{121}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+.LBB{122}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{201}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{202}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{203}
+	jmp	.LBB{204}
+.LBB{195}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+.LBB{136}:
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r12d
+	shrl	$16, %r12d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	2(%r14), %rbx
+	movzbl	%al, %edx
+	leaq	.LJTI{205}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{206}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{207}
+	jmp	.LBB{208}
+.LBB{209}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{40}
+	jmp	.LBB{39}
+.LBB{210}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{211}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{212}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{212}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{213}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{40}
+.LBB{214}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{215}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{216}
+.LBB{217}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{220}
+	cmpq	%r9, %r8
+	jae	.LBB{220}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{221}
+.LBB{222}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{220}
+.LBB{221}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{222}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{222}
+	jmp	.LBB{5}
+.LBB{223}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{40}
+.LBB{224}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{227}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{142}
+	jmp	.LBB{141}
+.LBB{228}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{229}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{230}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{231}
+.LBB{232}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{39}
+	jmp	.LBB{216}
+.LBB{234}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{235}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{236}
+.Ltmp{237}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{238}:
+.LBB{236}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{39}
+.LBB{239}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{240}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{241}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{242}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{243}
+	cmpq	$-1, %rcx
+	je	.LBB{244}
+.LBB{243}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{245}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{231}
+	jmp	.LBB{226}
+.LBB{246}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{249}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{251}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{251}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{252}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{142}
+.LBB{253}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{254}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{255}
+.Ltmp{256}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{257}:
+.LBB{255}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{40}
+.LBB{258}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{259}
+	xorl	%edx, %edx
+	jmp	.LBB{260}
+.LBB{261}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{263}
+	cmpq	%rdi, %rax
+	jae	.LBB{263}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{264}
+.LBB{265}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{263}
+.LBB{264}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{265}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{265}
+	jmp	.LBB{5}
+.LBB{266}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{267}
+.LBB{268}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{269}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{270}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{183}
+	jmp	.LBB{182}
+.LBB{271}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{272}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{273}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{274}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{275}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{278}
+	cmpq	%r9, %r8
+	jae	.LBB{278}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{279}
+.LBB{280}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{278}
+.LBB{279}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{280}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{280}
+	jmp	.LBB{5}
+.LBB{281}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{39}
+	jmp	.LBB{40}
+.LBB{282}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{40}
+.LBB{39}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{40}
+.LBB{283}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{142}
+.LBB{284}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{285}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{286}
+	cmpq	%r9, %r8
+	jae	.LBB{286}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{287}
+.LBB{288}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{286}
+.LBB{287}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{288}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{288}
+	jmp	.LBB{5}
+.LBB{289}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{272}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{273}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{290}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{216}:
+	movq	%r14, %r12
+	addq	$4, %r12
+	jmp	.LBB{40}
+.LBB{291}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{292}
+.LBB{293}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{296}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{297}
+	cmpq	%rdi, %rax
+	jae	.LBB{297}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{298}
+.LBB{299}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{297}
+.LBB{298}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{299}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{299}
+	jmp	.LBB{5}
+.LBB{300}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{301}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{302}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{303}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{304}
+.LBB{305}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{141}
+	jmp	.LBB{267}
+.LBB{306}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{307}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{308}
+.Ltmp{309}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{310}:
+.LBB{308}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{183}
+.LBB{311}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{312}
+.Ltmp{313}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{314}:
+.LBB{312}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{141}
+.LBB{315}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{316}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{317}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{318}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{319}
+	cmpq	$-1, %rcx
+	je	.LBB{320}
+.LBB{319}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{321}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{304}
+	jmp	.LBB{295}
+.LBB{322}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{324}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{325}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{326}
+.Ltmp{327}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{328}:
+.LBB{326}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{142}
+.LBB{329}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{330}
+	xorl	%edx, %edx
+	jmp	.LBB{331}
+.LBB{332}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{275}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{333}
+	cmpq	%rdi, %rax
+	jae	.LBB{333}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{334}
+.LBB{335}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{333}
+.LBB{334}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{335}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{335}
+	jmp	.LBB{5}
+.LBB{336}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{337}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{338}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{252}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{339}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{141}
+	jmp	.LBB{142}
+.LBB{340}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{142}
+.LBB{141}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %rbx
+	jmp	.LBB{142}
+.LBB{341}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{342}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{229}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{343}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{182}
+	jmp	.LBB{292}
+.LBB{344}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{345}
+.LBB{346}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{347}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{183}
+.LBB{348}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{183}
+	jmp	.LBB{182}
+.LBB{349}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{350}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{351}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{352}
+	cmpq	$-1, %rcx
+	je	.LBB{353}
+.LBB{352}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{354}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{345}
+	jmp	.LBB{226}
+.LBB{355}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{356}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{357}
+.Ltmp{358}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{359}:
+.LBB{357}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{182}
+.LBB{360}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{361}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{362}
+	xorl	%edx, %edx
+	jmp	.LBB{363}
+.LBB{364}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{267}:
+	addq	$2, %r12
+	movq	%r12, %rbx
+	jmp	.LBB{142}
+.LBB{365}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{366}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{367}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{211}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{368}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{368}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{213}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{183}
+.LBB{369}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{370}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{183}
+.LBB{182}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{183}
+.LBB{371}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{372}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{373}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{292}:
+	movq	%r14, %r12
+	addq	$4, %r12
+	jmp	.LBB{183}
+.LBB{374}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{375}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{19}
+	jmp	.LBB{18}
+.LBB{376}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{379}
+	cmpq	%r9, %r8
+	jae	.LBB{379}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{380}
+.LBB{381}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{379}
+.LBB{380}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{381}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{381}
+	jmp	.LBB{5}
+.LBB{382}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{383}
+.LBB{384}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{385}
+.LBB{386}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{388}
+	cmpq	%rdi, %rax
+	jae	.LBB{388}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{389}
+.LBB{390}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{388}
+.LBB{389}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{390}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{390}
+	jmp	.LBB{5}
+.LBB{391}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r15
+	jne	.LBB{392}
+.Ltmp{393}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{394}:
+.LBB{392}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{396}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{383}
+.LBB{397}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{383}
+.LBB{400}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{383}
+.LBB{402}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{18}
+	jmp	.LBB{385}
+.LBB{403}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{86}
+	jmp	.LBB{85}
+.LBB{404}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{13}
+	jmp	.LBB{12}
+.LBB{405}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{383}
+.LBB{406}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{407}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{92}
+	jmp	.LBB{91}
+.LBB{408}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{97}
+	jmp	.LBB{96}
+.LBB{409}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{410}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{19}
+	jmp	.LBB{18}
+.LBB{411}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{111}
+	jmp	.LBB{110}
+.LBB{412}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{383}
+.LBB{413}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{414}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{415}
+	cmpq	$-1, %rcx
+	je	.LBB{416}
+.LBB{415}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{417}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{383}
+	jmp	.LBB{295}
+.LBB{418}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{419}
+.Ltmp{420}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{421}:
+.LBB{419}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{18}
+.LBB{422}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{383}
+.LBB{423}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{424}
+	xorl	%edx, %edx
+	jmp	.LBB{425}
+.LBB{426}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{383}
+.LBB{427}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{428}
+.LBB{429}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{430}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{430}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{431}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{432}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{161}
+	jmp	.LBB{160}
+.LBB{433}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{19}
+.LBB{18}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{434}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{166}
+	jmp	.LBB{165}
+.LBB{435}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{171}
+	jmp	.LBB{170}
+.LBB{436}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{437}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{438}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{438}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{439}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{86}
+.LBB{440}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{441}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{442}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{443}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{444}
+	cmpq	%r9, %r8
+	jae	.LBB{444}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{445}
+.LBB{446}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{444}
+.LBB{445}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{446}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{446}
+	jmp	.LBB{5}
+.LBB{447}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{449}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{449}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{92}
+.LBB{451}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{442}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{452}
+.LBB{453}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{454}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{455}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{456}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{457}
+	cmpq	%r9, %r8
+	jae	.LBB{457}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{458}
+.LBB{459}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{457}
+.LBB{458}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{459}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{459}
+	jmp	.LBB{5}
+.LBB{460}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{461}
+.LBB{462}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{463}
+.LBB{464}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{465}
+.LBB{466}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{467}
+.LBB{468}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{469}
+.LBB{470}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{248}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{471}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{472}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{473}
+	cmpq	%r9, %r8
+	jae	.LBB{473}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{474}
+.LBB{475}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{473}
+.LBB{474}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{475}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{475}
+	jmp	.LBB{5}
+.LBB{476}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{477}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{442}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{478}
+	cmpq	%rdi, %rax
+	jae	.LBB{478}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{479}
+.LBB{480}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{478}
+.LBB{479}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{480}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{480}
+	jmp	.LBB{5}
+.LBB{481}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{485}
+	cmpq	%r9, %r8
+	jae	.LBB{485}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{486}
+.LBB{487}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{485}
+.LBB{486}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{487}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{487}
+	jmp	.LBB{5}
+.LBB{488}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{491}
+	cmpq	%r9, %r8
+	jae	.LBB{491}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{492}
+.LBB{493}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{491}
+.LBB{492}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{493}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{493}
+	jmp	.LBB{5}
+.LBB{494}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{495}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{496}
+	cmpq	%rdi, %rax
+	jae	.LBB{496}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{497}
+.LBB{498}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{496}
+.LBB{497}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{498}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{498}
+	jmp	.LBB{5}
+.LBB{499}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{500}
+.LBB{501}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{502}
+.LBB{503}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{505}
+	cmpq	%rdi, %rax
+	jae	.LBB{505}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{506}
+.LBB{507}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{505}
+.LBB{506}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{507}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{507}
+	jmp	.LBB{5}
+.LBB{508}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{428}
+.LBB{509}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{510}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{510}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{161}
+.LBB{511}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{512}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{86}
+.LBB{513}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{514}
+	cmpq	%r9, %r8
+	jae	.LBB{514}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{515}
+.LBB{516}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{514}
+.LBB{515}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{516}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{516}
+	jmp	.LBB{5}
+.LBB{517}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{518}
+	cmpq	%r9, %r8
+	jae	.LBB{518}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{519}
+.LBB{520}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{518}
+.LBB{519}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{520}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{520}
+	jmp	.LBB{5}
+.LBB{521}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{522}
+.Ltmp{523}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{524}:
+.LBB{522}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{526}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{92}
+.LBB{527}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{528}
+.LBB{529}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{385}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{530}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{531}
+.LBB{532}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{533}
+.LBB{534}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r15
+	jne	.LBB{535}
+.Ltmp{536}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{537}:
+.LBB{535}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{539}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{540}
+.LBB{541}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{542}
+.LBB{543}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{544}
+	cmpq	%r9, %r8
+	jae	.LBB{544}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{545}
+.LBB{546}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{544}
+.LBB{545}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{546}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{546}
+	jmp	.LBB{5}
+.LBB{547}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{548}
+	cmpq	%rdi, %rax
+	jae	.LBB{548}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{549}
+.LBB{550}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{548}
+.LBB{549}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{550}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{550}
+	jmp	.LBB{5}
+.LBB{551}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{552}
+	cmpq	%rdi, %rax
+	jae	.LBB{552}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{553}
+.LBB{554}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{552}
+.LBB{553}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{554}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{554}
+	jmp	.LBB{5}
+.LBB{555}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r15
+	jne	.LBB{556}
+.Ltmp{557}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{558}:
+.LBB{556}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{560}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{428}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{561}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{562}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{563}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{452}
+.LBB{566}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{461}
+.LBB{567}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{161}
+.LBB{568}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r15
+	jne	.LBB{569}
+.Ltmp{570}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{571}:
+.LBB{569}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{573}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r15
+	jne	.LBB{574}
+.Ltmp{575}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{576}:
+.LBB{574}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{578}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{229}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{579}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{500}
+.LBB{580}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{581}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{582}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{452}
+.LBB{585}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{452}
+.LBB{587}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{461}
+.LBB{588}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{589}
+.LBB{590}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{85}
+	jmp	.LBB{463}
+.LBB{591}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{592}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{461}
+.LBB{593}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{594}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{12}
+	jmp	.LBB{465}
+.LBB{595}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{596}
+.LBB{597}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{91}
+	jmp	.LBB{467}
+.LBB{598}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{599}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{452}
+.LBB{600}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{601}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{96}
+	jmp	.LBB{469}
+.LBB{602}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{461}
+.LBB{603}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{604}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{605}
+.Ltmp{606}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{607}:
+.LBB{605}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{85}
+.LBB{608}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{609}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{610}
+.Ltmp{611}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{612}:
+.LBB{610}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{91}
+.LBB{613}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{614}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{615}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{500}
+.LBB{617}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{500}
+.LBB{618}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{619}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{528}
+.LBB{622}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{623}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{110}
+	jmp	.LBB{502}
+.LBB{624}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{531}
+.LBB{625}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{626}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{13}
+	jmp	.LBB{12}
+.LBB{627}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{500}
+.LBB{628}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{629}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{630}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{631}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{632}
+	cmpq	$-1, %rcx
+	je	.LBB{633}
+.LBB{632}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{634}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{589}
+	jmp	.LBB{226}
+.LBB{635}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{452}
+.LBB{636}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{97}
+	jmp	.LBB{96}
+.LBB{637}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{638}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{639}
+	cmpq	$-1, %rcx
+	je	.LBB{640}
+.LBB{639}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{641}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{596}
+	jmp	.LBB{137}
+.LBB{642}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{644}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{461}
+.LBB{645}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{646}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{647}
+	cmpq	$-1, %rcx
+	je	.LBB{648}
+.LBB{647}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{649}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{452}
+	jmp	.LBB{565}
+.LBB{650}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{651}
+.Ltmp{652}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{653}:
+.LBB{651}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{12}
+.LBB{654}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{655}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{657}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{658}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{659}
+	cmpq	$-1, %rcx
+	je	.LBB{660}
+.LBB{659}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{661}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{461}
+	jmp	.LBB{137}
+.LBB{662}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{663}
+.Ltmp{664}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{665}:
+.LBB{663}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{96}
+.LBB{666}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{667}
+.Ltmp{668}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{669}:
+.LBB{667}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{86}
+.LBB{670}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{671}
+	xorl	%edx, %edx
+	jmp	.LBB{672}
+.LBB{673}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{581}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{674}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{452}
+.LBB{675}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{676}
+.Ltmp{677}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{678}:
+.LBB{676}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{92}
+.LBB{679}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{680}
+	xorl	%edx, %edx
+	jmp	.LBB{681}
+.LBB{682}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{248}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{471}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{472}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{683}
+	cmpq	%rdi, %rax
+	jae	.LBB{683}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{684}
+.LBB{685}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{683}
+.LBB{684}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{685}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{685}
+	jmp	.LBB{5}
+.LBB{686}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{687}
+	xorl	%edx, %edx
+	jmp	.LBB{688}
+.LBB{689}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{461}
+.LBB{690}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{691}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{111}
+	jmp	.LBB{110}
+.LBB{692}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{528}
+.LBB{693}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{694}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{531}
+.LBB{695}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{452}
+.LBB{696}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{500}
+.LBB{697}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{698}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{528}
+.LBB{699}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{700}
+	cmpq	%rdi, %rax
+	jae	.LBB{700}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{701}
+.LBB{702}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{700}
+.LBB{701}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{702}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{702}
+	jmp	.LBB{5}
+.LBB{703}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{704}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{531}
+.LBB{705}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{437}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{439}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{706}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{707}
+.LBB{708}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{160}
+	jmp	.LBB{533}
+.LBB{709}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{710}
+	xorl	%edx, %edx
+	jmp	.LBB{711}
+.LBB{712}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{713}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{714}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{715}
+.LBB{716}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{717}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{442}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{718}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{718}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{719}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{461}
+.LBB{720}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{721}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{722}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{723}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{724}
+	cmpq	$-1, %rcx
+	je	.LBB{725}
+.LBB{724}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{726}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{500}
+	jmp	.LBB{295}
+.LBB{727}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{728}
+.Ltmp{729}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{730}:
+.LBB{728}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{110}
+.LBB{731}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{732}
+.LBB{733}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{85}
+	jmp	.LBB{86}
+.LBB{734}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{165}
+	jmp	.LBB{540}
+.LBB{735}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{86}
+.LBB{85}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{86}
+.LBB{736}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{170}
+	jmp	.LBB{542}
+.LBB{737}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{738}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{738}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{739}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{740}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{13}
+.LBB{12}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{741}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{528}
+.LBB{742}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{531}
+.LBB{743}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{91}
+	jmp	.LBB{92}
+.LBB{744}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{745}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{92}
+.LBB{91}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	jmp	.LBB{92}
+.LBB{746}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{747}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{748}
+.Ltmp{749}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{750}:
+.LBB{748}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{160}
+.LBB{751}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{97}
+.LBB{96}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{752}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{500}
+.LBB{753}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{754}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{755}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{756}
+	xorl	%edx, %edx
+	jmp	.LBB{757}
+.LBB{758}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{500}
+.LBB{759}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{760}
+.LBB{761}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{762}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{763}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{763}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{764}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{765}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{766}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{767}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{768}
+	cmpq	$-1, %rcx
+	je	.LBB{769}
+.LBB{768}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{770}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{707}
+	jmp	.LBB{137}
+.LBB{771}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{111}
+.LBB{110}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{772}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{166}
+	jmp	.LBB{165}
+.LBB{773}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{171}
+	jmp	.LBB{170}
+.LBB{774}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{528}
+.LBB{775}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{776}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{531}
+.LBB{777}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{778}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{779}
+	cmpq	$-1, %rcx
+	je	.LBB{780}
+.LBB{779}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{781}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{528}
+	jmp	.LBB{621}
+.LBB{782}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{783}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{784}
+	cmpq	$-1, %rcx
+	je	.LBB{785}
+.LBB{784}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{786}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{531}
+	jmp	.LBB{295}
+.LBB{787}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{788}
+.Ltmp{789}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{790}:
+.LBB{788}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{165}
+.LBB{791}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{792}
+.Ltmp{793}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{794}:
+.LBB{792}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{170}
+.LBB{795}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{796}
+.Ltmp{797}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{798}:
+.LBB{796}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{161}
+.LBB{799}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{800}
+	xorl	%edx, %edx
+	jmp	.LBB{801}
+.LBB{802}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{528}
+.LBB{803}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{531}
+.LBB{804}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{805}
+	cmpq	%rdi, %rax
+	jae	.LBB{805}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{806}
+.LBB{807}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{805}
+.LBB{806}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{807}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{807}
+	jmp	.LBB{5}
+.LBB{808}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{809}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{810}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{811}
+	xorl	%edx, %edx
+	jmp	.LBB{812}
+.LBB{813}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{814}
+	xorl	%edx, %edx
+	jmp	.LBB{815}
+.LBB{816}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{817}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{818}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{528}
+.LBB{819}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{531}
+.LBB{820}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{821}
+.LBB{822}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{715}
+.LBB{823}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{824}
+.LBB{825}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{826}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{827}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{827}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{828}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{160}
+	jmp	.LBB{161}
+.LBB{829}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{830}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{831}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{831}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{832}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{161}
+.LBB{160}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	jmp	.LBB{161}
+.LBB{833}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{834}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{732}
+.LBB{835}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{166}
+.LBB{165}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{836}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{171}
+.LBB{170}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{837}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{463}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{86}
+.LBB{838}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{465}:
+	addq	$3, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{839}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{467}:
+	addq	$3, %r14
+	movq	%r14, %r15
+	jmp	.LBB{92}
+.LBB{840}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{469}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{841}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{760}
+.LBB{842}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{502}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{843}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{844}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{715}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{845}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{846}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{732}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{847}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{848}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{821}
+.LBB{849}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{824}
+.LBB{850}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{760}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{851}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{533}:
+	addq	$3, %r14
+	movq	%r14, %r15
+	jmp	.LBB{161}
+.LBB{852}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{540}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{853}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{542}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{854}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{855}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{821}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{856}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{824}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{857}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{67}
+	jmp	.LBB{66}
+.LBB{858}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{860}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{860}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{67}
+.LBB{862}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{863}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{864}
+.LBB{865}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{868}
+	cmpq	%r9, %r8
+	jae	.LBB{868}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{869}
+.LBB{870}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{868}
+.LBB{869}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{870}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{870}
+	jmp	.LBB{5}
+.LBB{871}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{149}
+	jmp	.LBB{148}
+.LBB{872}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{67}
+.LBB{873}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{178}
+	jmp	.LBB{177}
+.LBB{874}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{876}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{876}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{149}
+.LBB{878}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{879}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{880}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{881}
+.LBB{882}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{885}
+	cmpq	%r9, %r8
+	jae	.LBB{885}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{886}
+.LBB{887}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{885}
+.LBB{886}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{887}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{887}
+	jmp	.LBB{5}
+.LBB{888}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{698}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{889}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{188}
+	jmp	.LBB{187}
+.LBB{890}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{891}
+.LBB{892}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{66}
+	jmp	.LBB{864}
+.LBB{893}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{894}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{895}
+.Ltmp{896}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{897}:
+.LBB{895}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{66}
+.LBB{898}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{899}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{899}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{178}
+.LBB{900}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{149}
+.LBB{901}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{902}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{903}
+.LBB{904}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{905}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{906}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{907}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{908}
+	cmpq	$-1, %rcx
+	je	.LBB{909}
+.LBB{908}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{910}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{891}
+	jmp	.LBB{621}
+.LBB{911}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{912}
+	cmpq	%r9, %r8
+	jae	.LBB{912}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{913}
+.LBB{914}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{912}
+.LBB{913}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{914}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{914}
+	jmp	.LBB{5}
+.LBB{915}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{916}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{917}
+.Ltmp{918}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{919}:
+.LBB{917}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{67}
+.LBB{920}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{921}
+	xorl	%edx, %edx
+	jmp	.LBB{922}
+.LBB{923}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{924}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{925}
+	cmpq	%rdi, %rax
+	jae	.LBB{925}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{926}
+.LBB{927}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{925}
+.LBB{926}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{927}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{927}
+	jmp	.LBB{5}
+.LBB{928}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{929}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{930}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{931}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{932}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{932}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{188}
+.LBB{933}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{66}
+	jmp	.LBB{67}
+.LBB{934}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{935}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{67}
+.LBB{66}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{67}
+.LBB{936}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{937}
+.LBB{938}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{178}
+.LBB{939}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{941}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{942}
+	cmpq	%r9, %r8
+	jae	.LBB{942}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{943}
+.LBB{944}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{942}
+.LBB{943}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{944}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{944}
+	jmp	.LBB{5}
+.LBB{945}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bpl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{946}
+.LBB{947}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{148}
+	jmp	.LBB{881}
+.LBB{949}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{950}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{951}
+.Ltmp{952}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{953}:
+.LBB{951}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{148}
+.LBB{954}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{955}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{956}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{957}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{958}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{959}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{960}
+	cmpq	$-1, %rcx
+	je	.LBB{961}
+.LBB{960}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{962}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{946}
+	jmp	.LBB{44}
+.LBB{963}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{188}
+.LBB{964}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{966}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{864}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{67}
+.LBB{967}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{968}
+.Ltmp{969}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{970}:
+.LBB{968}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{149}
+.LBB{971}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{972}
+	xorl	%edx, %edx
+	jmp	.LBB{973}
+.LBB{974}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{698}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{975}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{976}
+	cmpq	%rdi, %rax
+	jae	.LBB{976}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{977}
+.LBB{978}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{976}
+.LBB{977}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{978}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{978}
+	jmp	.LBB{5}
+.LBB{979}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{980}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	andq	%r12, %rcx
+	shrq	$2, %rax
+	andq	%r12, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	imulq	%rbx, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{981}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{982}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{983}
+.LBB{984}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{177}
+	jmp	.LBB{903}
+.LBB{985}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{986}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{148}
+	jmp	.LBB{149}
+.LBB{987}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{149}
+.LBB{148}:
+	movzbl	%bpl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r13
+	jmp	.LBB{149}
+.LBB{988}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{989}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{990}
+.Ltmp{991}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{992}:
+.LBB{990}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{177}
+.LBB{993}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{994}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{995}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{996}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{997}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{998}
+	cmpq	$-1, %rcx
+	je	.LBB{999}
+.LBB{998}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1000}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{983}
+	jmp	.LBB{621}
+.LBB{1001}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1002}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{698}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1003}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1004}
+.Ltmp{1005}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1006}:
+.LBB{1004}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{178}
+.LBB{1007}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1008}
+.LBB{1009}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{187}
+	jmp	.LBB{937}
+.LBB{1010}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1011}
+	xorl	%edx, %edx
+	jmp	.LBB{1012}
+.LBB{1013}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1014}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1015}
+	cmpq	%rdi, %rax
+	jae	.LBB{1015}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1016}
+.LBB{1017}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1015}
+.LBB{1016}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1017}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1017}
+	jmp	.LBB{5}
+.LBB{1018}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1019}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{1020}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1021}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1022}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1023}
+.Ltmp{1024}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1025}:
+.LBB{1023}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{187}
+.LBB{1026}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{177}
+	jmp	.LBB{178}
+.LBB{1027}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{178}
+.LBB{177}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{178}
+.LBB{1028}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1029}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{881}:
+	movq	%r14, %r13
+	addq	$3, %r13
+	jmp	.LBB{149}
+.LBB{1030}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1031}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1032}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1033}
+	cmpq	$-1, %rcx
+	je	.LBB{1034}
+.LBB{1033}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1035}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1008}
+	jmp	.LBB{621}
+.LBB{1036}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1037}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1038}
+.Ltmp{1039}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1040}:
+.LBB{1038}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{188}
+.LBB{1041}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1042}
+	xorl	%edx, %edx
+	jmp	.LBB{1043}
+.LBB{1044}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1045}
+	cmpq	%rdi, %rax
+	jae	.LBB{1045}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1046}
+.LBB{1047}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1045}
+.LBB{1046}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1047}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1047}
+	jmp	.LBB{5}
+.LBB{1048}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1049}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{1050}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1051}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1052}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{187}
+	jmp	.LBB{188}
+.LBB{1053}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{188}
+.LBB{187}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{188}
+.LBB{1054}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1055}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{903}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{178}
+.LBB{1056}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1057}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1058}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{937}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{188}
+.LBB{1059}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1060}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{48}
+	jmp	.LBB{47}
+.LBB{1061}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1062}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1063}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1063}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1064}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{48}
+.LBB{1065}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1066}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1067}
+.LBB{1068}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{60}
+	jmp	.LBB{59}
+.LBB{1069}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1072}
+	cmpq	%r9, %r8
+	jae	.LBB{1072}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1073}
+.LBB{1074}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1072}
+.LBB{1073}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1074}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1074}
+	jmp	.LBB{5}
+.LBB{1075}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{81}
+	jmp	.LBB{80}
+.LBB{1076}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{48}
+.LBB{1077}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{116}
+	jmp	.LBB{115}
+.LBB{1078}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{1080}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1080}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{60}
+.LBB{1082}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1083}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{204}
+	jmp	.LBB{203}
+.LBB{1084}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1085}
+.LBB{1086}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1087}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{208}
+	jmp	.LBB{207}
+.LBB{1088}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1091}
+	cmpq	%r9, %r8
+	jae	.LBB{1091}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1092}
+.LBB{1093}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1091}
+.LBB{1092}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1093}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1093}
+	jmp	.LBB{5}
+.LBB{1094}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{1095}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1095}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{81}
+.LBB{1096}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1097}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1098}
+.LBB{1099}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1100}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1101}
+	cmpq	%r9, %r8
+	jae	.LBB{1101}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1102}
+.LBB{1103}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1101}
+.LBB{1102}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1103}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1103}
+	jmp	.LBB{5}
+.LBB{1104}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1105}
+.LBB{1106}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{47}
+	jmp	.LBB{1067}
+.LBB{1107}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1108}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{60}
+.LBB{1109}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{1110}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1110}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{116}
+.LBB{1111}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1112}
+.Ltmp{1113}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1114}:
+.LBB{1112}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{47}
+.LBB{1115}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1116}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{1117}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1117}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{204}
+.LBB{1118}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1119}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1120}
+.LBB{1121}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1122}
+.LBB{1123}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1124}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1125}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1126}
+	cmpq	%r9, %r8
+	jae	.LBB{1126}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1127}
+.LBB{1128}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1126}
+.LBB{1127}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1128}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1128}
+	jmp	.LBB{5}
+.LBB{1129}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{1130}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1130}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{208}
+.LBB{1131}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1132}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1133}
+	cmpq	$-1, %rcx
+	je	.LBB{1134}
+.LBB{1133}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1135}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1105}
+	jmp	.LBB{44}
+.LBB{1136}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1137}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1138}
+	cmpq	%r9, %r8
+	jae	.LBB{1138}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1139}
+.LBB{1140}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1138}
+.LBB{1139}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1140}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1140}
+	jmp	.LBB{5}
+.LBB{1141}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{81}
+.LBB{1142}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1144}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1145}
+.LBB{1146}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1147}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1148}
+.Ltmp{1149}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1150}:
+.LBB{1148}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{48}
+.LBB{1151}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1152}
+	xorl	%edx, %edx
+	jmp	.LBB{1153}
+.LBB{1154}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1155}
+	cmpq	%r9, %r8
+	jae	.LBB{1155}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1156}
+.LBB{1157}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1155}
+.LBB{1156}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1157}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1157}
+	jmp	.LBB{5}
+.LBB{1158}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1160}
+	cmpq	%rdi, %rax
+	jae	.LBB{1160}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1161}
+.LBB{1162}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1160}
+.LBB{1161}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1162}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1162}
+	jmp	.LBB{5}
+.LBB{1163}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1164}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1165}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1166}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1167}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1168}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{47}
+	jmp	.LBB{48}
+.LBB{1169}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{48}
+.LBB{47}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{48}
+.LBB{1170}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{116}
+.LBB{1171}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1172}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1173}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1174}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{204}
+.LBB{1175}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r12b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1176}
+.LBB{1177}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{59}
+	jmp	.LBB{1085}
+.LBB{1178}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1179}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbx
+	jmp	.LBB{208}
+.LBB{1180}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1181}
+.Ltmp{1182}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1183}:
+.LBB{1181}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{59}
+.LBB{1184}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1172}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1185}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1186}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1187}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1188}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r12b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1189}
+.LBB{1190}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{80}
+	jmp	.LBB{1098}
+.LBB{1191}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1192}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1193}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1194}
+	cmpq	$-1, %rcx
+	je	.LBB{1195}
+.LBB{1194}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1196}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{56}
+.LBB{1197}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1198}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1199}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1200}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1201}
+.Ltmp{1202}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1203}:
+.LBB{1201}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{80}
+.LBB{1204}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1205}
+.Ltmp{1206}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1207}:
+.LBB{1205}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{60}
+.LBB{1208}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1209}
+	xorl	%edx, %edx
+	jmp	.LBB{1210}
+.LBB{1211}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1212}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1213}
+	cmpq	%rdi, %rax
+	jae	.LBB{1213}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1214}
+.LBB{1215}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1213}
+.LBB{1214}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1215}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1215}
+	jmp	.LBB{5}
+.LBB{1216}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1067}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{48}
+.LBB{1217}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1218}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1219}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1220}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1172}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1221}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1222}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1223}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1224}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1225}
+	cmpq	$-1, %rcx
+	je	.LBB{1226}
+.LBB{1225}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1227}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1189}
+	jmp	.LBB{56}
+.LBB{1228}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1172}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1229}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{59}
+	jmp	.LBB{60}
+.LBB{1230}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{60}
+.LBB{59}:
+	movzbl	%r12b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %rbx
+	jmp	.LBB{60}
+.LBB{1231}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1232}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r12b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1233}
+.LBB{1234}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{115}
+	jmp	.LBB{1120}
+.LBB{1235}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1236}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r12b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1237}
+.LBB{1238}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{203}
+	jmp	.LBB{1122}
+.LBB{1239}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1240}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1241}
+.Ltmp{1242}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1243}:
+.LBB{1241}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{81}
+.LBB{1244}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1245}
+.Ltmp{1246}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1247}:
+.LBB{1245}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{115}
+.LBB{1248}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1249}
+	xorl	%edx, %edx
+	jmp	.LBB{1250}
+.LBB{1251}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{592}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1252}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1253}
+.Ltmp{1254}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1255}:
+.LBB{1253}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{203}
+.LBB{1256}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1257}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1258}
+	cmpq	%rdi, %rax
+	jae	.LBB{1258}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1259}
+.LBB{1260}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1258}
+.LBB{1259}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1260}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1260}
+	jmp	.LBB{5}
+.LBB{1261}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1262}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1263}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r12b, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1264}
+.LBB{1265}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1266}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{207}
+	jmp	.LBB{1145}
+.LBB{1267}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1268}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1269}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1270}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{80}
+	jmp	.LBB{81}
+.LBB{1271}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{81}
+.LBB{80}:
+	movzbl	%r12b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %rbx
+	jmp	.LBB{81}
+.LBB{1272}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1273}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1274}
+	cmpq	$-1, %rcx
+	je	.LBB{1275}
+.LBB{1274}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1276}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1233}
+	jmp	.LBB{56}
+.LBB{1277}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1278}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1279}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1280}
+.Ltmp{1281}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1282}:
+.LBB{1280}:
+	movq	200(%rsp), %rax
+	movq	%rbx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{207}
+.LBB{1283}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1284}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1285}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1286}
+	cmpq	$-1, %rcx
+	je	.LBB{1287}
+.LBB{1286}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1288}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1237}
+	jmp	.LBB{56}
+.LBB{1289}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1290}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1291}
+.Ltmp{1292}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1293}:
+.LBB{1291}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{116}
+.LBB{1294}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1295}
+	xorl	%edx, %edx
+	jmp	.LBB{1296}
+.LBB{1297}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1298}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1299}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1300}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1301}
+.Ltmp{1302}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1303}:
+.LBB{1301}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{204}
+.LBB{1304}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1305}
+	xorl	%edx, %edx
+	jmp	.LBB{1306}
+.LBB{1307}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1308}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1309}
+	cmpq	$-1, %rcx
+	je	.LBB{1310}
+.LBB{1309}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1311}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1264}
+	jmp	.LBB{137}
+.LBB{1312}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1313}
+	cmpq	%rdi, %rax
+	jae	.LBB{1313}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1314}
+.LBB{1315}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1313}
+.LBB{1314}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1315}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1315}
+	jmp	.LBB{5}
+.LBB{1316}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1317}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1318}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1319}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1320}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1321}
+	cmpq	%rdi, %rax
+	jae	.LBB{1321}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1322}
+.LBB{1323}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1321}
+.LBB{1322}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1323}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1323}
+	jmp	.LBB{5}
+.LBB{1324}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1325}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1326}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1327}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{115}
+	jmp	.LBB{116}
+.LBB{1328}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{116}
+.LBB{115}:
+	movzbl	%r12b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %rbx
+	jmp	.LBB{116}
+.LBB{1329}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1330}
+.Ltmp{1331}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1332}:
+.LBB{1330}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{208}
+.LBB{1333}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{203}
+	jmp	.LBB{204}
+.LBB{1334}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1085}:
+	addq	$3, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{60}
+.LBB{1335}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{204}
+.LBB{203}:
+	movzbl	%r12b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %rbx
+	jmp	.LBB{204}
+.LBB{1336}:
+	movzbl	%r12b, %edi
+	cmpb	$15, %r12b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1337}
+	xorl	%edx, %edx
+	jmp	.LBB{1338}
+.LBB{1339}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r12b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1340}
+	cmpq	%rdi, %rax
+	jae	.LBB{1340}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1341}
+.LBB{1342}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1340}
+.LBB{1341}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1342}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1342}
+	jmp	.LBB{5}
+.LBB{1343}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1344}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1345}:
+	movzbl	%r12b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1346}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1347}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{207}
+	jmp	.LBB{208}
+.LBB{1348}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{208}
+.LBB{207}:
+	movzbl	%r12b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %rbx
+	jmp	.LBB{208}
+.LBB{1349}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1350}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1098}:
+	addq	$3, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{81}
+.LBB{1351}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1352}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1353}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1354}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1120}:
+	addq	$3, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{116}
+.LBB{1355}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1356}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1122}:
+	addq	$3, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{204}
+.LBB{1357}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1145}:
+	addq	$3, %r14
+	movq	%r14, %rbx
+	jmp	.LBB{208}
+.LBB{1358}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1359}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1360}:
+	movzbl	%r12b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1361}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{127}
+	jmp	.LBB{126}
+.LBB{1362}:
+	movzbl	%al, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1062}
+	movl	%edi, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1363}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1363}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1064}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{127}
+.LBB{1364}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1365}:
+	movl	%edi, %edx
+	andb	$15, %dl
+	movl	%eax, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%dl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	imulq	16(%rsp,%r8,8), %rdx
+	movl	%esi, %r8d
+	andl	$15, %r8d
+	movq	%rdx, 16(%rsp,%r8,8)
+	shrb	$4, %dil
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%dil, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %sil
+	movzbl	%sil, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	jmp	.LBB{1366}
+.LBB{1367}:
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %r8d
+	jae	.LBB{1064}
+	movzbl	%al, %eax
+	cmpl	$15, %eax
+	ja	.LBB{1368}
+	movl	%edi, %ecx
+	cmpl	$268435455, %edx
+	ja	.LBB{1062}
+	movq	16(%rsp,%rax,8), %rdx
+	movq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1369}
+	cmpq	%r9, %r8
+	jae	.LBB{1369}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1370}
+.LBB{1371}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1369}
+.LBB{1370}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1371}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1371}
+	jmp	.LBB{5}
+.LBB{1372}:
+	testq	%r11, %r11
+	je	.LBB{5}
+	leaq	-2(%r9), %rax
+	movq	%rax, 208(%rsp)
+	movq	-16(%r10,%r9,8), %r14
+	jmp	.LBB{127}
+.LBB{1373}:
+	movzbl	%al, %ecx
+	cmpb	$15, %al
+	ja	.LBB{43}
+	movl	%edi, %edi
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	subq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1374}:
+	movzbl	%al, %ecx
+	cmpb	$15, %al
+	ja	.LBB{43}
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1375}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %edi
+	movzbl	%al, %eax
+	orl	%edi, %eax
+	jmp	.LBB{1376}
+.LBB{1377}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{126}
+	jmp	.LBB{1366}
+.LBB{1378}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1379}:
+	cmpq	$1024, %r11
+	je	.LBB{5}
+	movq	%r14, -8(%r10,%r9,8)
+	movq	%r9, 208(%rsp)
+	jmp	.LBB{126}
+.LBB{1380}:
+	movzbl	%al, %ecx
+	cmpb	$15, %al
+	ja	.LBB{43}
+	movl	%edi, %edi
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	imulq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1381}:
+	movzbl	%al, %ecx
+	cmpb	$15, %al
+	ja	.LBB{43}
+	movl	%edi, %edi
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	cmpq	16(%rsp,%rdi,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1382}:
+	movzbl	%al, %esi
+	cmpb	$15, %al
+	ja	.LBB{1383}
+	movl	%edi, %edi
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	movq	16(%rsp,%rdi,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1384}
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1385}
+	cmpq	$-1, %rcx
+	je	.LBB{1386}
+.LBB{1385}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1387}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1376}
+	jmp	.LBB{44}
+.LBB{1388}:
+	movzbl	%al, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1389}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1390}
+.Ltmp{1391}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1392}:
+.LBB{1390}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{127}
+.LBB{1393}:
+	movzbl	%al, %esi
+	cmpb	$15, %al
+	ja	.LBB{1383}
+	movl	%edi, %edi
+	cmpl	$268435455, %edx
+	ja	.LBB{42}
+	movq	16(%rsp,%rdi,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1394}
+	xorl	%edx, %edx
+	jmp	.LBB{1395}
+.LBB{1396}:
+	leaq	.Lanon{8}(%rip), %r9
+	cmpl	$16, %r8d
+	jae	.LBB{1397}
+	movzbl	%al, %esi
+	cmpl	$15, %esi
+	ja	.LBB{1398}
+	movl	%edi, %ecx
+	cmpl	$268435455, %edx
+	ja	.LBB{1399}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rsi,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1400}
+	cmpq	%rdi, %rax
+	jae	.LBB{1400}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1401}
+.LBB{1402}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1400}
+.LBB{1401}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1402}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1402}
+	jmp	.LBB{5}
+.LBB{1403}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1404}:
+	movzbl	%al, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1405}:
+	movzbl	%al, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1166}
+	movl	%edi, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1167}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1406}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{126}
+	jmp	.LBB{127}
+.LBB{1407}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{127}
+.LBB{126}:
+	movzbl	%al, %eax
+	shll	$8, %edi
+	orl	%eax, %edi
+	movq	%rdi, %r14
+	jmp	.LBB{127}
+.LBB{1408}:
+	movzbl	%al, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1409}:
+	movl	%edi, %edx
+	andb	$15, %dl
+	movl	%eax, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%dl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%r8,8), %rdx
+	movl	%esi, %r8d
+	andl	$15, %r8d
+	movq	%rdx, 16(%rsp,%r8,8)
+	shrb	$4, %dil
+	shrb	$4, %al
+	movzbl	%al, %eax
+	movzbl	%dil, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %sil
+	movzbl	%sil, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1366}:
+	addq	$2, %rcx
+	movq	%rcx, %r14
+	jmp	.LBB{127}
+.LBB{1410}:
+	movzbl	%al, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1411}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{75}
+	jmp	.LBB{74}
+.LBB{1412}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1062}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1413}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1413}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1064}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{75}
+.LBB{1414}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1415}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1416}
+.LBB{1417}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1418}
+	cmpq	%r9, %r8
+	jae	.LBB{1418}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1419}
+.LBB{1420}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1418}
+.LBB{1419}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1420}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1420}
+	jmp	.LBB{5}
+.LBB{1421}:
+	movq	%rbp, 208(%rsp)
+	movq	200(%rsp), %rax
+	movq	(%rax,%rbp,8), %r14
+	jmp	.LBB{75}
+.LBB{1422}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1423}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1424}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1425}
+.LBB{1426}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{74}
+	jmp	.LBB{1416}
+.LBB{1427}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1428}:
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rdx
+	jne	.LBB{1429}
+.Ltmp{1430}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1431}:
+.LBB{1429}:
+	movq	200(%rsp), %rax
+	movq	%r14, 8(%rax,%rbp,8)
+	addq	$2, %rbp
+	movq	%rbp, 208(%rsp)
+	jmp	.LBB{74}
+.LBB{1432}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1433}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1434}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1435}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1436}
+	cmpq	$-1, %rcx
+	je	.LBB{1437}
+.LBB{1436}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1438}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1425}
+	jmp	.LBB{44}
+.LBB{1439}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1440}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1441}
+.Ltmp{1442}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1443}:
+.LBB{1441}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{75}
+.LBB{1444}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1445}
+	xorl	%edx, %edx
+	jmp	.LBB{1446}
+.LBB{1447}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1448}
+	cmpq	%rdi, %rax
+	jae	.LBB{1448}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1449}
+.LBB{1450}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1448}
+.LBB{1449}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1450}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1450}
+	jmp	.LBB{5}
+.LBB{1451}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1452}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1453}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1166}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1167}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1454}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{74}
+	jmp	.LBB{75}
+.LBB{1455}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{75}
+.LBB{74}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{75}
+.LBB{1456}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1457}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1416}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{75}
+.LBB{1458}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1459}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{53}
+	jmp	.LBB{52}
+.LBB{1460}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1461}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1461}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{53}
+.LBB{1462}:
+	testq	%rdi, %rdi
+	je	.LBB{5}
+	leaq	-2(%rdx), %rax
+	movq	%rax, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	-16(%rsi,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1463}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1464}
+.LBB{1465}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1467}
+	cmpq	%r9, %r8
+	jae	.LBB{1467}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1468}
+.LBB{1469}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1467}
+.LBB{1468}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1469}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1469}
+	jmp	.LBB{5}
+.LBB{1470}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{53}
+.LBB{1471}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1474}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1475}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1476}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1477}
+.LBB{1478}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{52}
+	jmp	.LBB{1464}
+.LBB{1479}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1480}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1481}
+.Ltmp{1482}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1483}:
+.LBB{1481}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{52}
+.LBB{1484}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1485}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1486}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1487}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1488}
+	cmpq	$-1, %rcx
+	je	.LBB{1489}
+.LBB{1488}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1490}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1477}
+	jmp	.LBB{1473}
+.LBB{1491}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1494}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$1024, %rdi
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, -8(%rsi,%rdx,8)
+	movq	%rdx, 232(%rsp)
+	jmp	.LBB{53}
+.LBB{1495}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1496}
+	xorl	%edx, %edx
+	jmp	.LBB{1497}
+.LBB{1498}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1500}
+	cmpq	%rdi, %rax
+	jae	.LBB{1500}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1501}
+.LBB{1502}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1500}
+.LBB{1501}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1502}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1502}
+	jmp	.LBB{5}
+.LBB{1503}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1504}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1505}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1506}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{52}
+	jmp	.LBB{53}
+.LBB{1507}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{53}
+.LBB{52}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{53}
+.LBB{1508}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1509}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1464}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{53}
+.LBB{1510}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1511}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{105}
+	jmp	.LBB{104}
+.LBB{1512}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1513}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1513}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{105}
+.LBB{1514}:
+	movq	%r13, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rax
+	movq	(%rax,%r13,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1515}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1516}
+.LBB{1517}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1518}
+	cmpq	%r9, %r8
+	jae	.LBB{1518}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1519}
+.LBB{1520}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1518}
+.LBB{1519}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1520}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1520}
+	jmp	.LBB{5}
+.LBB{1521}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{105}
+.LBB{1522}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1523}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1524}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1525}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{1526}
+.LBB{1527}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{104}
+	jmp	.LBB{1516}
+.LBB{1528}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1529}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1530}
+.Ltmp{1531}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1532}:
+.LBB{1530}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{104}
+.LBB{1533}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1534}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1535}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1536}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1537}
+	cmpq	$-1, %rcx
+	je	.LBB{1538}
+.LBB{1537}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1539}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1526}
+	jmp	.LBB{1473}
+.LBB{1540}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1541}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rbx
+	cmpq	216(%rsp), %rdx
+	jne	.LBB{1542}
+.Ltmp{1543}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1544}:
+.LBB{1542}:
+	movq	224(%rsp), %rax
+	movq	%rbx, 8(%rax,%r13,8)
+	addq	$2, %r13
+	movq	%r13, 232(%rsp)
+	jmp	.LBB{105}
+.LBB{1545}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1546}
+	xorl	%edx, %edx
+	jmp	.LBB{1547}
+.LBB{1548}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1549}
+	cmpq	%rdi, %rax
+	jae	.LBB{1549}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1550}
+.LBB{1551}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1549}
+.LBB{1550}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1551}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1551}
+	jmp	.LBB{5}
+.LBB{1552}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1553}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1554}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1555}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{104}
+	jmp	.LBB{105}
+.LBB{1556}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{105}
+.LBB{104}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	jmp	.LBB{105}
+.LBB{1557}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1558}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1516}:
+	movq	%r14, %r15
+	addq	$3, %r15
+	jmp	.LBB{105}
+.LBB{1559}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1560}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{34}
+	jmp	.LBB{33}
+.LBB{1561}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1562}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1562}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{34}
+.LBB{1563}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1564}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1565}
+.LBB{1566}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1567}
+	cmpq	%r9, %r8
+	jae	.LBB{1567}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1568}
+.LBB{1569}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1567}
+.LBB{1568}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1569}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1569}
+	jmp	.LBB{5}
+.LBB{1570}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{34}
+.LBB{1571}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1572}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{132}
+	jmp	.LBB{131}
+.LBB{1573}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1475}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1574}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1575}
+.LBB{1576}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{33}
+	jmp	.LBB{1565}
+.LBB{1577}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1578}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1579}
+.Ltmp{1580}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1581}:
+.LBB{1579}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{33}
+.LBB{1582}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1583}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1584}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1585}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1586}
+	cmpq	$-1, %rcx
+	je	.LBB{1587}
+.LBB{1586}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1588}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1575}
+	jmp	.LBB{1473}
+.LBB{1589}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1590}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1591}
+.Ltmp{1592}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1593}:
+.LBB{1591}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{34}
+.LBB{1594}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1595}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1595}:
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rcx, (%rdi,%rax,8)
+	jmp	.LBB{132}
+.LBB{1596}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1597}
+	xorl	%edx, %edx
+	jmp	.LBB{1598}
+.LBB{1599}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1600}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1601}
+	cmpq	%rdi, %rax
+	jae	.LBB{1601}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1602}
+.LBB{1603}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1601}
+.LBB{1602}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1603}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1603}
+	jmp	.LBB{5}
+.LBB{1604}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1605}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1606}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1607}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1608}
+.LBB{1609}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{33}
+	jmp	.LBB{34}
+.LBB{1610}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{34}
+.LBB{33}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{34}
+.LBB{1611}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1612}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r10
+	addq	%r8, %r10
+	setb	%r9b
+	cmpq	%rsi, %r10
+	seta	%r11b
+	orb	%r9b, %r11b
+	jne	.LBB{5}
+	movq	176(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{1613}
+	cmpq	%r10, %r8
+	jae	.LBB{1613}
+	movq	168(%rsp), %r10
+	movq	%r8, %r11
+	jmp	.LBB{1614}
+.LBB{1615}:
+	incq	%r11
+	decq	%rax
+	je	.LBB{1613}
+.LBB{1614}:
+	movq	%r11, %rbx
+	shrq	$6, %rbx
+	cmpq	%r9, %rbx
+	jae	.LBB{1615}
+	movq	16(%r10,%rbx,8), %rbx
+	btq	%r11, %rbx
+	jae	.LBB{1615}
+	jmp	.LBB{5}
+.LBB{1616}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{132}
+.LBB{1617}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1618}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1565}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{34}
+.LBB{1619}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1620}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1621}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1475}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1622}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1623}
+.LBB{1624}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{131}
+	jmp	.LBB{1608}
+.LBB{1625}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1626}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1627}
+.Ltmp{1628}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1629}:
+.LBB{1627}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{131}
+.LBB{1630}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1631}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1632}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1633}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1634}
+	cmpq	$-1, %rcx
+	je	.LBB{1635}
+.LBB{1634}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1636}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1623}
+	jmp	.LBB{1473}
+.LBB{1637}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1638}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1639}
+.Ltmp{1640}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1641}:
+.LBB{1639}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{132}
+.LBB{1642}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1643}
+	xorl	%edx, %edx
+	jmp	.LBB{1644}
+.LBB{1645}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1612}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %r8
+	addq	%rdx, %r8
+	jb	.LBB{5}
+	cmpq	%rsi, %r8
+	ja	.LBB{5}
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1646}
+	cmpq	%r8, %rdx
+	jae	.LBB{1646}
+	movq	168(%rsp), %r8
+	movq	%rax, %r9
+	movq	%rdx, %r10
+	jmp	.LBB{1647}
+.LBB{1648}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{1646}
+.LBB{1647}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rsi, %r11
+	jae	.LBB{1648}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1648}
+	jmp	.LBB{5}
+.LBB{1649}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1650}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1651}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1652}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{131}
+	jmp	.LBB{132}
+.LBB{1653}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{132}
+.LBB{131}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{132}
+.LBB{1654}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1655}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1608}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{132}
+.LBB{1656}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1657}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{194}
+	jmp	.LBB{193}
+.LBB{1658}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1659}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1659}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{194}
+.LBB{1660}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1661}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1662}
+.LBB{1663}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1664}
+	cmpq	%r9, %r8
+	jae	.LBB{1664}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1665}
+.LBB{1666}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1664}
+.LBB{1665}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1666}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1666}
+	jmp	.LBB{5}
+.LBB{1667}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{194}
+.LBB{1668}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1669}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{229}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1670}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1671}
+.LBB{1672}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{193}
+	jmp	.LBB{1662}
+.LBB{1673}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1674}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1675}
+.Ltmp{1676}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1677}:
+.LBB{1675}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{193}
+.LBB{1678}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1679}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1680}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1681}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1682}
+	cmpq	$-1, %rcx
+	je	.LBB{1683}
+.LBB{1682}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1684}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1671}
+	jmp	.LBB{226}
+.LBB{1685}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1686}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1687}
+.Ltmp{1688}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1689}:
+.LBB{1687}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{194}
+.LBB{1690}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1691}
+	xorl	%edx, %edx
+	jmp	.LBB{1692}
+.LBB{1693}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1694}
+	cmpq	%rdi, %rax
+	jae	.LBB{1694}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1695}
+.LBB{1696}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1694}
+.LBB{1695}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1696}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1696}
+	jmp	.LBB{5}
+.LBB{1697}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1698}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1699}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1700}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{193}
+	jmp	.LBB{194}
+.LBB{263}:
+	testq	%rcx, %rcx
+	je	.LBB{40}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1701}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1701}
+	jmp	.LBB{40}
+.LBB{1702}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{194}
+.LBB{193}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{194}
+.LBB{1703}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1704}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1662}:
+	movq	%r14, %r12
+	addq	$3, %r12
+	jmp	.LBB{194}
+.LBB{297}:
+	testq	%rcx, %rcx
+	je	.LBB{183}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1705}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1705}
+	jmp	.LBB{183}
+.LBB{1706}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{333}:
+	testq	%rcx, %rcx
+	je	.LBB{142}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1707}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1707}
+	jmp	.LBB{142}
+.LBB{388}:
+	testq	%rcx, %rcx
+	je	.LBB{19}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1708}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1708}
+	jmp	.LBB{19}
+.LBB{478}:
+	testq	%rcx, %rcx
+	je	.LBB{13}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1709}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1709}
+	jmp	.LBB{13}
+.LBB{496}:
+	testq	%rcx, %rcx
+	je	.LBB{97}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1710}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1710}
+	jmp	.LBB{97}
+.LBB{505}:
+	testq	%rcx, %rcx
+	je	.LBB{111}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1711}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1711}
+	jmp	.LBB{111}
+.LBB{548}:
+	testq	%rcx, %rcx
+	je	.LBB{166}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1712}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1712}
+	jmp	.LBB{166}
+.LBB{552}:
+	testq	%rcx, %rcx
+	je	.LBB{171}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1713}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1713}
+	jmp	.LBB{171}
+.LBB{683}:
+	testq	%rcx, %rcx
+	je	.LBB{86}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1714}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1714}
+	jmp	.LBB{86}
+.LBB{700}:
+	testq	%rcx, %rcx
+	je	.LBB{92}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1715}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1715}
+	jmp	.LBB{92}
+.LBB{805}:
+	testq	%rcx, %rcx
+	je	.LBB{161}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1716}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1716}
+	jmp	.LBB{161}
+.LBB{925}:
+	testq	%rcx, %rcx
+	je	.LBB{67}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1717}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1717}
+	jmp	.LBB{67}
+.LBB{976}:
+	testq	%rcx, %rcx
+	je	.LBB{149}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1718}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1718}
+	jmp	.LBB{149}
+.LBB{1015}:
+	testq	%rcx, %rcx
+	je	.LBB{178}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1719}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1719}
+	jmp	.LBB{178}
+.LBB{1045}:
+	testq	%rcx, %rcx
+	je	.LBB{188}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1720}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1720}
+	jmp	.LBB{188}
+.LBB{1160}:
+	testq	%rcx, %rcx
+	je	.LBB{48}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1721}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1721}
+	jmp	.LBB{48}
+.LBB{1213}:
+	testq	%rcx, %rcx
+	je	.LBB{60}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1722}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1722}
+	jmp	.LBB{60}
+.LBB{1258}:
+	testq	%rcx, %rcx
+	je	.LBB{81}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1723}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1723}
+	jmp	.LBB{81}
+.LBB{1313}:
+	testq	%rcx, %rcx
+	je	.LBB{116}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1724}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1724}
+	jmp	.LBB{116}
+.LBB{1321}:
+	testq	%rcx, %rcx
+	je	.LBB{204}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1725}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1725}
+	jmp	.LBB{204}
+.LBB{1340}:
+	testq	%rcx, %rcx
+	je	.LBB{208}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1726}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1726}
+	jmp	.LBB{208}
+.LBB{1400}:
+	testq	%rcx, %rcx
+	je	.LBB{127}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1727}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1727}
+	jmp	.LBB{127}
+.LBB{1448}:
+	testq	%rcx, %rcx
+	je	.LBB{75}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1728}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1728}
+	jmp	.LBB{75}
+.LBB{1500}:
+	testq	%rcx, %rcx
+	je	.LBB{53}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1729}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1729}
+	jmp	.LBB{53}
+.LBB{1549}:
+	testq	%rcx, %rcx
+	je	.LBB{105}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1730}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1730}
+	jmp	.LBB{105}
+.LBB{1601}:
+	testq	%rcx, %rcx
+	je	.LBB{34}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1731}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1731}
+	jmp	.LBB{34}
+.LBB{1646}:
+	testq	%rax, %rax
+	je	.LBB{132}
+	leaq	(%rdi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1732}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1732}
+	jmp	.LBB{132}
+.LBB{1694}:
+	testq	%rcx, %rcx
+	je	.LBB{194}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1733}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1733}
+	jmp	.LBB{194}
+.LBB{259}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1734}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{260}
+.LBB{330}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1735}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{331}
+.LBB{362}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1736}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{363}
+.LBB{424}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1737}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{425}
+.LBB{671}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1738}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{672}
+.LBB{680}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1739}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{681}
+.LBB{687}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1740}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{688}
+.LBB{710}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1741}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{711}
+.LBB{756}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1742}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{757}
+.LBB{800}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1743}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{801}
+.LBB{811}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1744}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{812}
+.LBB{814}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1745}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{815}
+.LBB{921}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1746}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{922}
+.LBB{972}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1747}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{973}
+.LBB{1011}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1748}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1012}
+.LBB{1042}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1749}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1043}
+.LBB{1152}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1750}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1153}
+.LBB{1209}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1751}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1210}
+.LBB{1249}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1752}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1250}
+.LBB{1295}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1753}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1296}
+.LBB{1305}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1754}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1306}
+.LBB{1337}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1755}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1338}
+.LBB{1394}:
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1756}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1395}
+.LBB{1445}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1757}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1446}
+.LBB{1496}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1758}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1497}
+.LBB{1546}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1759}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1547}
+.LBB{1597}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1760}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1598}
+.LBB{1643}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1761}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1644}
+.LBB{220}:
+	movq	152(%rsp), %rdi
+.Ltmp{1762}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1763}:
+	jmp	.LBB{40}
+.LBB{278}:
+	movq	152(%rsp), %rdi
+.Ltmp{1764}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1765}:
+	jmp	.LBB{142}
+.LBB{286}:
+	movq	152(%rsp), %rdi
+.Ltmp{1766}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1767}:
+	jmp	.LBB{183}
+.LBB{379}:
+	movq	152(%rsp), %rdi
+.Ltmp{1768}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1769}:
+	jmp	.LBB{19}
+.LBB{444}:
+	movq	152(%rsp), %rdi
+.Ltmp{1770}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1771}:
+	jmp	.LBB{13}
+.LBB{457}:
+	movq	152(%rsp), %rdi
+.Ltmp{1772}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1773}:
+	jmp	.LBB{97}
+.LBB{473}:
+	movq	152(%rsp), %rdi
+.Ltmp{1774}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1775}:
+	jmp	.LBB{86}
+.LBB{485}:
+	movq	152(%rsp), %rdi
+.Ltmp{1776}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1777}:
+	jmp	.LBB{92}
+.LBB{491}:
+	movq	152(%rsp), %rdi
+.Ltmp{1778}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1779}:
+	jmp	.LBB{111}
+.LBB{514}:
+	movq	152(%rsp), %rdi
+.Ltmp{1780}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1781}:
+	jmp	.LBB{166}
+.LBB{518}:
+	movq	152(%rsp), %rdi
+.Ltmp{1782}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1783}:
+	jmp	.LBB{171}
+.LBB{544}:
+	movq	152(%rsp), %rdi
+.Ltmp{1784}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1785}:
+	jmp	.LBB{161}
+.LBB{868}:
+	movq	152(%rsp), %rdi
+.Ltmp{1786}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1787}:
+	jmp	.LBB{67}
+.LBB{885}:
+	movq	152(%rsp), %rdi
+.Ltmp{1788}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1789}:
+	jmp	.LBB{149}
+.LBB{912}:
+	movq	152(%rsp), %rdi
+.Ltmp{1790}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1791}:
+	jmp	.LBB{178}
+.LBB{942}:
+	movq	152(%rsp), %rdi
+.Ltmp{1792}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1793}:
+	jmp	.LBB{188}
+.LBB{1072}:
+	movq	152(%rsp), %rdi
+.Ltmp{1794}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1795}:
+	jmp	.LBB{48}
+.LBB{1091}:
+	movq	152(%rsp), %rdi
+.Ltmp{1796}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1797}:
+	jmp	.LBB{60}
+.LBB{1101}:
+	movq	152(%rsp), %rdi
+.Ltmp{1798}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1799}:
+	jmp	.LBB{81}
+.LBB{1126}:
+	movq	152(%rsp), %rdi
+.Ltmp{1800}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1801}:
+	jmp	.LBB{116}
+.LBB{1138}:
+	movq	152(%rsp), %rdi
+.Ltmp{1802}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1803}:
+	jmp	.LBB{204}
+.LBB{1155}:
+	movq	152(%rsp), %rdi
+.Ltmp{1804}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1805}:
+	jmp	.LBB{208}
+.LBB{1806}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{200}
+	jmp	.LBB{199}
+.LBB{1807}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%rbx, %rcx
+	jae	.LBB{1808}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1808}:
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rcx, (%r12,%rax,8)
+	jmp	.LBB{200}
+.LBB{1809}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1810}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1811}
+.LBB{1812}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	setb	%sil
+	cmpq	%r13, %rcx
+	seta	%dil
+	orb	%sil, %dil
+	jne	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %rsi
+	addq	%r8, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{5}
+	testq	%rbx, %rbx
+	je	.LBB{1813}
+	cmpq	%rsi, %r8
+	jae	.LBB{1813}
+	movq	168(%rsp), %rsi
+	movq	%r8, %rdi
+	jmp	.LBB{1814}
+.LBB{1815}:
+	incq	%rdi
+	decq	%rax
+	je	.LBB{1813}
+.LBB{1814}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{1815}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1815}
+	jmp	.LBB{5}
+.LBB{1816}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 336(%rsp)
+	jmp	.LBB{200}
+.LBB{1817}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1818}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1475}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1819}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bpl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{1820}
+.LBB{1821}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{199}
+	jmp	.LBB{1811}
+.LBB{1822}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1823}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{1824}
+.Ltmp{1825}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1826}:
+.LBB{1824}:
+	movq	200(%rsp), %rax
+	movq	336(%rsp), %rcx
+	movq	%rcx, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{199}
+.LBB{1827}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1828}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1829}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1830}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1831}
+	cmpq	$-1, %rcx
+	je	.LBB{1832}
+.LBB{1831}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1833}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1820}
+	jmp	.LBB{226}
+.LBB{1834}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1835}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1836}
+.Ltmp{1837}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1838}:
+.LBB{1836}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{200}
+.LBB{1839}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1840}
+	xorl	%edx, %edx
+	jmp	.LBB{1841}
+.LBB{1842}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bpl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{5}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rbx, %rbx
+	je	.LBB{1843}
+	cmpq	%rsi, %rdx
+	jae	.LBB{1843}
+	movq	168(%rsp), %rsi
+	movq	%rax, %rdi
+	movq	%rdx, %r8
+	jmp	.LBB{1844}
+.LBB{1845}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{1843}
+.LBB{1844}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%rbx, %r9
+	jae	.LBB{1845}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{1845}
+	jmp	.LBB{5}
+.LBB{1846}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1847}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1848}:
+	movzbl	%bpl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	(%r12,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1849}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{199}
+	jmp	.LBB{200}
+.LBB{1850}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{200}
+.LBB{199}:
+	movzbl	%bpl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, 336(%rsp)
+	jmp	.LBB{200}
+.LBB{1851}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1852}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1811}:
+	addq	$3, %r14
+	movq	%r14, 336(%rsp)
+	jmp	.LBB{200}
+.LBB{1853}:
+	movzbl	%bpl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1691}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1854}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1692}
+.LBB{1369}:
+	movq	152(%rsp), %rdi
+.Ltmp{1855}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1856}:
+	jmp	.LBB{127}
+.LBB{1418}:
+	movq	152(%rsp), %rdi
+.Ltmp{1857}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1858}:
+	jmp	.LBB{75}
+.LBB{1467}:
+	movq	152(%rsp), %rdi
+.Ltmp{1859}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1860}:
+	jmp	.LBB{53}
+.LBB{1518}:
+	movq	152(%rsp), %rdi
+.Ltmp{1861}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1862}:
+	jmp	.LBB{105}
+.LBB{1567}:
+	movq	152(%rsp), %rdi
+.Ltmp{1863}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1864}:
+	jmp	.LBB{34}
+.LBB{1613}:
+.Ltmp{1865}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1866}:
+	jmp	.LBB{132}
+.LBB{1664}:
+	movq	152(%rsp), %rdi
+.Ltmp{1867}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1868}:
+	jmp	.LBB{194}
+.LBB{242}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{231}
+	jmp	.LBB{226}
+.LBB{318}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{304}
+	jmp	.LBB{295}
+.LBB{351}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{345}
+	jmp	.LBB{226}
+.LBB{414}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{383}
+	jmp	.LBB{295}
+.LBB{631}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{589}
+	jmp	.LBB{226}
+.LBB{638}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{596}
+	jmp	.LBB{137}
+.LBB{646}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{452}
+	jmp	.LBB{565}
+.LBB{658}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{461}
+	jmp	.LBB{137}
+.LBB{723}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{500}
+	jmp	.LBB{295}
+.LBB{767}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{707}
+	jmp	.LBB{137}
+.LBB{778}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{528}
+	jmp	.LBB{621}
+.LBB{783}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{531}
+	jmp	.LBB{295}
+.LBB{907}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{891}
+	jmp	.LBB{621}
+.LBB{959}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{946}
+	jmp	.LBB{44}
+.LBB{997}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{983}
+	jmp	.LBB{621}
+.LBB{1032}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1008}
+	jmp	.LBB{621}
+.LBB{1132}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1105}
+	jmp	.LBB{44}
+.LBB{1193}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{56}
+.LBB{1224}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1189}
+	jmp	.LBB{56}
+.LBB{1273}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1233}
+	jmp	.LBB{56}
+.LBB{1285}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1237}
+	jmp	.LBB{56}
+.LBB{1308}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1264}
+	jmp	.LBB{137}
+.LBB{1384}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1376}
+	jmp	.LBB{44}
+.LBB{1435}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1425}
+	jmp	.LBB{44}
+.LBB{1487}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1477}
+	jmp	.LBB{1473}
+.LBB{1536}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1526}
+	jmp	.LBB{1473}
+.LBB{1585}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1575}
+	jmp	.LBB{1473}
+.LBB{1633}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1623}
+	jmp	.LBB{1473}
+.LBB{1681}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1671}
+	jmp	.LBB{226}
+.LBB{245}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{231}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{40}
+.LBB{321}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{304}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{142}
+.LBB{354}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{345}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{417}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{383}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{19}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{395}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{1869}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1870}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1871}
+	jmp	.LBB{1872}
+.LBB{1873}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{1872}
+	jmp	.LBB{1871}
+.LBB{1874}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1875}
+	cmpq	%r9, %r8
+	jae	.LBB{1875}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1876}
+.LBB{1877}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1875}
+.LBB{1876}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1877}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1877}
+	jmp	.LBB{5}
+.LBB{1878}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1879}
+.LBB{1880}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1881}
+.LBB{1882}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1883}
+	cmpq	%rdi, %rax
+	jae	.LBB{1883}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1884}
+.LBB{1885}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1883}
+.LBB{1884}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1885}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1885}
+	jmp	.LBB{5}
+.LBB{1886}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1887}
+.Ltmp{1888}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1889}:
+.LBB{1887}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1891}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1879}
+.LBB{1892}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1879}
+.LBB{1893}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1879}
+.LBB{1894}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1871}
+	jmp	.LBB{1881}
+.LBB{1895}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{1879}
+.LBB{1896}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1897}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1898}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1872}
+	jmp	.LBB{1871}
+.LBB{1899}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1879}
+.LBB{1900}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1901}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1902}
+	cmpq	$-1, %rcx
+	je	.LBB{1903}
+.LBB{1902}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1904}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1879}
+	jmp	.LBB{295}
+.LBB{1905}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1906}
+.Ltmp{1907}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1908}:
+.LBB{1906}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{1871}
+.LBB{1909}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{1879}
+.LBB{1910}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1911}
+	xorl	%edx, %edx
+	jmp	.LBB{1912}
+.LBB{1913}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1879}
+.LBB{1914}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1915}
+.LBB{1916}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1917}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1917}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1918}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1919}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{1872}
+.LBB{1871}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1920}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{1915}
+.LBB{1921}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1881}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1922}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{1915}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{1883}:
+	testq	%rcx, %rcx
+	je	.LBB{1872}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1923}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1923}
+	jmp	.LBB{1872}
+.LBB{1911}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1924}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1912}
+.LBB{1875}:
+	movq	152(%rsp), %rdi
+.Ltmp{1925}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1926}:
+	jmp	.LBB{1872}
+.LBB{1901}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1879}
+	jmp	.LBB{295}
+.LBB{634}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{589}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{86}
+.LBB{641}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{596}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{92}
+.LBB{649}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+.LBB{452}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{13}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{525}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r15), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{1927}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1928}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1930}
+	jmp	.LBB{1931}
+.LBB{1932}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{1931}
+	jmp	.LBB{1930}
+.LBB{1933}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{442}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{443}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1934}
+	cmpq	%r9, %r8
+	jae	.LBB{1934}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1935}
+.LBB{1936}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1934}
+.LBB{1935}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1936}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1936}
+	jmp	.LBB{5}
+.LBB{1937}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{442}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1938}
+.LBB{1939}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1940}
+.LBB{1941}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{477}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{442}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1942}
+	cmpq	%rdi, %rax
+	jae	.LBB{1942}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1943}
+.LBB{1944}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1942}
+.LBB{1943}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1944}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1944}
+	jmp	.LBB{5}
+.LBB{1945}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{1946}
+.Ltmp{1947}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1948}:
+.LBB{1946}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1950}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1938}
+.LBB{1951}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1938}
+.LBB{1952}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1953}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1938}
+.LBB{1954}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1930}
+	jmp	.LBB{1940}
+.LBB{1955}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{1938}
+.LBB{1956}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1957}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1958}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1931}
+	jmp	.LBB{1930}
+.LBB{1959}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1938}
+.LBB{1960}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1961}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1962}
+	cmpq	$-1, %rcx
+	je	.LBB{1963}
+.LBB{1962}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1964}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1938}
+	jmp	.LBB{565}
+.LBB{1965}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1966}
+.Ltmp{1967}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1968}:
+.LBB{1966}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{1930}
+.LBB{1969}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{1938}
+.LBB{1970}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1971}
+	xorl	%edx, %edx
+	jmp	.LBB{1972}
+.LBB{1973}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1938}
+.LBB{1974}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1975}
+.LBB{1976}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{442}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{1977}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1977}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1978}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1979}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{1931}
+.LBB{1930}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1980}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{1975}
+.LBB{1981}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1940}:
+	addq	$2, %r15
+	movq	%r15, %r12
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1982}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{1975}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{1942}:
+	testq	%rcx, %rcx
+	je	.LBB{1931}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1983}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1983}
+	jmp	.LBB{1931}
+.LBB{1971}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1984}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1972}
+.LBB{1934}:
+	movq	152(%rsp), %rdi
+.Ltmp{1985}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1986}:
+	jmp	.LBB{1931}
+.LBB{1961}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1938}
+	jmp	.LBB{565}
+.LBB{661}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{461}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{97}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{538}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{1987}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1988}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1989}
+	jmp	.LBB{1990}
+.LBB{1991}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{1990}
+	jmp	.LBB{1989}
+.LBB{1992}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{455}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{456}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1993}
+	cmpq	%r9, %r8
+	jae	.LBB{1993}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1994}
+.LBB{1995}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1993}
+.LBB{1994}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1995}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1995}
+	jmp	.LBB{5}
+.LBB{1996}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1997}
+.LBB{1998}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1999}
+.LBB{2000}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{495}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2001}
+	cmpq	%rdi, %rax
+	jae	.LBB{2001}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2002}
+.LBB{2003}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2001}
+.LBB{2002}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2003}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2003}
+	jmp	.LBB{5}
+.LBB{2004}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2005}
+.Ltmp{2006}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2007}:
+.LBB{2005}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2009}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{1997}
+.LBB{2010}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{1997}
+.LBB{2011}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{581}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1997}
+.LBB{2012}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1989}
+	jmp	.LBB{1999}
+.LBB{2013}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{1997}
+.LBB{2014}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2015}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2016}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1990}
+	jmp	.LBB{1989}
+.LBB{2017}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{1997}
+.LBB{2018}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2019}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2020}
+	cmpq	$-1, %rcx
+	je	.LBB{2021}
+.LBB{2020}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2022}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1997}
+	jmp	.LBB{137}
+.LBB{2023}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2024}
+.Ltmp{2025}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2026}:
+.LBB{2024}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{1989}
+.LBB{2027}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{1997}
+.LBB{2028}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2029}
+	xorl	%edx, %edx
+	jmp	.LBB{2030}
+.LBB{2031}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1997}
+.LBB{2032}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{2033}
+.LBB{2034}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2035}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2035}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2036}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2037}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{1990}
+.LBB{1989}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2038}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{2033}
+.LBB{2039}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1999}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2040}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{2033}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2001}:
+	testq	%rcx, %rcx
+	je	.LBB{1990}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2041}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2041}
+	jmp	.LBB{1990}
+.LBB{2029}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2042}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2030}
+.LBB{1993}:
+	movq	152(%rsp), %rdi
+.Ltmp{2043}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2044}:
+	jmp	.LBB{1990}
+.LBB{2019}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1997}
+	jmp	.LBB{137}
+.LBB{726}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{500}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{111}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{559}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2045}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2046}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2047}
+	jmp	.LBB{2048}
+.LBB{2049}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2048}
+	jmp	.LBB{2047}
+.LBB{2050}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2051}
+	cmpq	%r9, %r8
+	jae	.LBB{2051}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2052}
+.LBB{2053}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2051}
+.LBB{2052}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2053}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2053}
+	jmp	.LBB{5}
+.LBB{2054}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{2055}
+.LBB{2056}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2057}
+.LBB{2058}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2059}
+	cmpq	%rdi, %rax
+	jae	.LBB{2059}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2060}
+.LBB{2061}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2059}
+.LBB{2060}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2061}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2061}
+	jmp	.LBB{5}
+.LBB{2062}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2063}
+.Ltmp{2064}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2065}:
+.LBB{2063}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2067}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{2055}
+.LBB{2068}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{2055}
+.LBB{2069}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2055}
+.LBB{2070}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2047}
+	jmp	.LBB{2057}
+.LBB{2071}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{2055}
+.LBB{2072}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2073}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2074}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2048}
+	jmp	.LBB{2047}
+.LBB{2075}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2055}
+.LBB{2076}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2077}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2078}
+	cmpq	$-1, %rcx
+	je	.LBB{2079}
+.LBB{2078}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2080}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2055}
+	jmp	.LBB{295}
+.LBB{2081}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2082}
+.Ltmp{2083}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2084}:
+.LBB{2082}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{2047}
+.LBB{2085}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{2055}
+.LBB{2086}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2087}
+	xorl	%edx, %edx
+	jmp	.LBB{2088}
+.LBB{2089}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{2055}
+.LBB{2090}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{2091}
+.LBB{2092}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2093}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2093}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2094}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2095}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{2048}
+.LBB{2047}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2096}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{2091}
+.LBB{2097}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2057}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2098}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{2091}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2059}:
+	testq	%rcx, %rcx
+	je	.LBB{2048}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2099}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2099}
+	jmp	.LBB{2048}
+.LBB{2087}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2100}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2088}
+.LBB{2051}:
+	movq	152(%rsp), %rdi
+.Ltmp{2101}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2102}:
+	jmp	.LBB{2048}
+.LBB{2077}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2055}
+	jmp	.LBB{295}
+.LBB{770}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{707}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{161}
+.LBB{781}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{528}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{166}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{572}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2103}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2104}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2105}
+	jmp	.LBB{2106}
+.LBB{2107}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2106}
+	jmp	.LBB{2105}
+.LBB{2108}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2109}
+	cmpq	%r9, %r8
+	jae	.LBB{2109}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2110}
+.LBB{2111}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2109}
+.LBB{2110}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2111}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2111}
+	jmp	.LBB{5}
+.LBB{2112}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{2113}
+.LBB{2114}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2115}
+.LBB{2116}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2117}
+	cmpq	%rdi, %rax
+	jae	.LBB{2117}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2118}
+.LBB{2119}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2117}
+.LBB{2118}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2119}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2119}
+	jmp	.LBB{5}
+.LBB{2120}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2121}
+.Ltmp{2122}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2123}:
+.LBB{2121}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2125}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{2113}
+.LBB{2126}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{2113}
+.LBB{2127}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2128}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2113}
+.LBB{2129}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2105}
+	jmp	.LBB{2115}
+.LBB{2130}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{2113}
+.LBB{2131}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2132}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2133}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2106}
+	jmp	.LBB{2105}
+.LBB{2134}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2113}
+.LBB{2135}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2136}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2137}
+	cmpq	$-1, %rcx
+	je	.LBB{2138}
+.LBB{2137}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2139}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2113}
+	jmp	.LBB{621}
+.LBB{2140}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2141}
+.Ltmp{2142}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2143}:
+.LBB{2141}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{2105}
+.LBB{2144}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{2113}
+.LBB{2145}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2146}
+	xorl	%edx, %edx
+	jmp	.LBB{2147}
+.LBB{2148}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{2113}
+.LBB{2149}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{2150}
+.LBB{2151}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2152}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2152}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2153}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2154}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{2106}
+.LBB{2105}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2155}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{2150}
+.LBB{2156}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2115}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2157}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	ja	.LBB{399}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{2150}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2117}:
+	testq	%rcx, %rcx
+	je	.LBB{2106}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2158}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2158}
+	jmp	.LBB{2106}
+.LBB{2146}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2159}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2147}
+.LBB{2109}:
+	movq	152(%rsp), %rdi
+.Ltmp{2160}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2161}:
+	jmp	.LBB{2106}
+.LBB{2136}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2113}
+	jmp	.LBB{621}
+.LBB{786}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{531}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{171}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{577}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2162}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2163}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2164}
+	jmp	.LBB{2165}
+.LBB{2166}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2165}
+	jmp	.LBB{2164}
+.LBB{2167}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2168}
+	cmpq	%r9, %r8
+	jae	.LBB{2168}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2169}
+.LBB{2170}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2168}
+.LBB{2169}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2170}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2170}
+	jmp	.LBB{5}
+.LBB{2171}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{2172}
+.LBB{2173}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2174}
+.LBB{2175}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2176}
+	cmpq	%rdi, %rax
+	jae	.LBB{2176}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2177}
+.LBB{2178}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2176}
+.LBB{2177}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2178}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2178}
+	jmp	.LBB{5}
+.LBB{2179}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2180}
+.Ltmp{2181}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2182}:
+.LBB{2180}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2184}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	jmp	.LBB{2172}
+.LBB{2185}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	jmp	.LBB{2172}
+.LBB{2186}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2172}
+.LBB{2187}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2164}
+	jmp	.LBB{2174}
+.LBB{2188}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{2172}
+.LBB{2189}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2190}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2191}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2165}
+	jmp	.LBB{2164}
+.LBB{2192}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	jmp	.LBB{2172}
+.LBB{2193}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2194}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2195}
+	cmpq	$-1, %rcx
+	je	.LBB{2196}
+.LBB{2195}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2197}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2172}
+	jmp	.LBB{295}
+.LBB{2198}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2199}
+.Ltmp{2200}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2201}:
+.LBB{2199}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{2164}
+.LBB{2202}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{2172}
+.LBB{2203}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2204}
+	xorl	%edx, %edx
+	jmp	.LBB{2205}
+.LBB{2206}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{2172}
+.LBB{2207}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{2208}
+.LBB{2209}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2210}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2210}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2211}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2212}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{2165}
+.LBB{2164}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2213}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	jmp	.LBB{2208}
+.LBB{2214}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2174}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2215}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+	cmpl	$15, %r8d
+	ja	.LBB{275}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+.LBB{2208}:
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2176}:
+	testq	%rcx, %rcx
+	je	.LBB{2165}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2216}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2216}
+	jmp	.LBB{2165}
+.LBB{2204}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2217}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2205}
+.LBB{2168}:
+	movq	152(%rsp), %rdi
+.Ltmp{2218}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2219}:
+	jmp	.LBB{2165}
+.LBB{2194}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2172}
+	jmp	.LBB{295}
+.LBB{910}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{891}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{67}
+.LBB{962}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{946}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{149}
+.LBB{1000}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{983}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{178}
+.LBB{1035}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{1008}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{188}
+.LBB{1135}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{1105}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{48}
+.LBB{1196}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{1176}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{60}
+.LBB{1227}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{1189}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{81}
+.LBB{1276}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{1233}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{116}
+.LBB{1288}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{1237}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{204}
+.LBB{1311}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{1264}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{208}
+.LBB{1387}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{1376}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{127}
+.LBB{1438}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{1425}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{75}
+.LBB{1490}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{1477}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{53}
+.LBB{1539}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{1526}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{105}
+.LBB{1588}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{1575}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{34}
+.LBB{1636}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{1623}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{132}
+.LBB{1904}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{1879}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{1872}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{1890}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2220}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2221}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2222}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2223}
+	cmpq	$-1, %rcx
+	je	.LBB{2224}
+.LBB{2223}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2225}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2226}
+	jmp	.LBB{295}
+.LBB{2227}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{2228}
+	jmp	.LBB{377}
+.LBB{2229}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2230}
+.Ltmp{2231}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2232}:
+	jmp	.LBB{2230}
+.LBB{2233}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{2234}
+	jmp	.LBB{377}
+.LBB{2235}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2236}
+.Ltmp{2237}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2238}:
+	jmp	.LBB{2236}
+.LBB{2239}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2240}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2241}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2242}
+	xorl	%edx, %edx
+	jmp	.LBB{2243}
+.LBB{2244}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2245}
+	jmp	.LBB{398}
+.LBB{2246}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2247}
+	cmpq	%rdi, %rax
+	jae	.LBB{2247}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2248}
+.LBB{2249}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2247}
+.LBB{2248}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2249}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2249}
+	jmp	.LBB{5}
+.LBB{2250}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2251}
+	cmpq	%r9, %r8
+	jae	.LBB{2251}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2252}
+.LBB{2253}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2251}
+.LBB{2252}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2253}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2253}
+	jmp	.LBB{5}
+.LBB{2254}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2255}
+	jmp	.LBB{398}
+.LBB{2256}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2257}
+	jmp	.LBB{398}
+.LBB{2258}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2259}
+	jmp	.LBB{398}
+.LBB{2247}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2260}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2260}
+	jmp	.LBB{4}
+.LBB{2242}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2261}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2243}
+.LBB{2251}:
+	movq	152(%rsp), %rdi
+.Ltmp{2262}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2263}:
+	jmp	.LBB{4}
+.LBB{2222}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2226}
+	jmp	.LBB{295}
+.LBB{1964}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+.LBB{1938}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{1931}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{1949}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2264}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2265}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2266}
+	jmp	.LBB{4}
+.LBB{2267}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2266}
+.LBB{2268}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{2269}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{2270}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2271}
+	cmpq	%r9, %r8
+	jae	.LBB{2271}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2272}
+.LBB{2273}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2271}
+.LBB{2272}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2273}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2273}
+	jmp	.LBB{5}
+.LBB{2274}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{2269}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1929}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2275}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	jmp	.LBB{2276}
+.LBB{2277}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{2278}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{2269}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2279}
+	cmpq	%rdi, %rax
+	jae	.LBB{2279}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2280}
+.LBB{2281}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2279}
+.LBB{2280}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2281}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2281}
+	jmp	.LBB{5}
+.LBB{2282}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2283}
+.Ltmp{2284}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2285}:
+	jmp	.LBB{2283}
+.LBB{2286}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2287}
+	jmp	.LBB{564}
+.LBB{2288}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2289}
+	cmpl	$15, %r8d
+	jbe	.LBB{2290}
+	jmp	.LBB{2291}
+.LBB{2292}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2293}
+	cmpl	$15, %r8d
+	jbe	.LBB{2294}
+	jmp	.LBB{565}
+.LBB{2295}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2266}
+	jmp	.LBB{2296}
+.LBB{2297}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2298}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2299}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2266}
+.LBB{2300}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	jbe	.LBB{2301}
+	jmp	.LBB{565}
+.LBB{2302}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2303}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2304}
+	cmpq	$-1, %rcx
+	je	.LBB{2305}
+.LBB{2304}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2306}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2307}
+	jmp	.LBB{565}
+.LBB{2308}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2309}
+.Ltmp{2310}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2311}:
+.LBB{2309}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{2266}
+.LBB{2312}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2313}
+	jmp	.LBB{1929}
+.LBB{2314}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2315}
+	xorl	%edx, %edx
+	jmp	.LBB{2316}
+.LBB{2317}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+	cmpl	$15, %r8d
+	jbe	.LBB{2318}
+	jmp	.LBB{565}
+.LBB{2319}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2289}
+	cmpl	$15, %r8d
+	jbe	.LBB{2320}
+	jmp	.LBB{2291}
+.LBB{2321}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{2269}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2322}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2322}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1929}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2323}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2324}:
+	cmpl	$16, %r8d
+	jae	.LBB{1929}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{2266}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{2325}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2289}
+	cmpl	$15, %r8d
+	jbe	.LBB{2326}
+	jmp	.LBB{2291}
+.LBB{2327}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	jmp	.LBB{2328}
+.LBB{2329}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2289}
+	cmpl	$15, %r8d
+	jbe	.LBB{2330}
+	jmp	.LBB{2291}
+.LBB{2279}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2331}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2331}
+	jmp	.LBB{4}
+.LBB{2315}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2332}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2316}
+.LBB{2271}:
+	movq	152(%rsp), %rdi
+.Ltmp{2333}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2334}:
+	jmp	.LBB{4}
+.LBB{2303}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2307}
+	jmp	.LBB{565}
+.LBB{2022}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{1997}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{1990}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{2008}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2335}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2336}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2337}
+	jmp	.LBB{4}
+.LBB{2338}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2337}
+.LBB{2339}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{455}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{456}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2340}
+	cmpq	%r9, %r8
+	jae	.LBB{2340}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2341}
+.LBB{2342}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2340}
+.LBB{2341}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2342}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2342}
+	jmp	.LBB{5}
+.LBB{2343}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{2344}
+	jmp	.LBB{88}
+.LBB{2345}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{495}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2346}
+	cmpq	%rdi, %rax
+	jae	.LBB{2346}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2347}
+.LBB{2348}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2346}
+.LBB{2347}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2348}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2348}
+	jmp	.LBB{5}
+.LBB{2349}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2230}
+.Ltmp{2350}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2351}:
+	jmp	.LBB{2230}
+.LBB{2352}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2353}
+	jmp	.LBB{134}
+.LBB{2354}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	jbe	.LBB{2355}
+	jmp	.LBB{482}
+.LBB{2356}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{581}
+	cmpl	$15, %r8d
+	jbe	.LBB{2357}
+	jmp	.LBB{137}
+.LBB{2358}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2359}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2360}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2337}
+.LBB{2361}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2362}
+	jmp	.LBB{134}
+.LBB{2363}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2364}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2365}
+	cmpq	$-1, %rcx
+	je	.LBB{2366}
+.LBB{2365}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2367}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2368}
+	jmp	.LBB{137}
+.LBB{2369}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2236}
+.Ltmp{2370}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2371}:
+	jmp	.LBB{2236}
+.LBB{2372}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2373}
+	jmp	.LBB{88}
+.LBB{2374}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2375}
+	xorl	%edx, %edx
+	jmp	.LBB{2376}
+.LBB{2377}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	jbe	.LBB{2378}
+	jmp	.LBB{137}
+.LBB{2379}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2380}
+	jmp	.LBB{323}
+.LBB{2381}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{455}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2382}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2382}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{2383}
+	jmp	.LBB{88}
+.LBB{2384}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2385}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{2337}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{2386}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2387}
+	jmp	.LBB{323}
+.LBB{2388}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	jbe	.LBB{2389}
+	jmp	.LBB{482}
+.LBB{2346}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2390}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2390}
+	jmp	.LBB{4}
+.LBB{2375}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2391}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2376}
+.LBB{2340}:
+	movq	152(%rsp), %rdi
+.Ltmp{2392}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2393}:
+	jmp	.LBB{4}
+.LBB{2364}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2368}
+	jmp	.LBB{137}
+.LBB{2080}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2055}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{2048}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{2066}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2394}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2395}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2396}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2397}
+	cmpq	$-1, %rcx
+	je	.LBB{2398}
+.LBB{2397}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2399}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2400}
+	jmp	.LBB{295}
+.LBB{2401}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2245}
+	jmp	.LBB{323}
+.LBB{2402}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2403}
+	xorl	%edx, %edx
+	jmp	.LBB{2404}
+.LBB{2405}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2406}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2407}
+	cmpq	%r9, %r8
+	jae	.LBB{2407}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2408}
+.LBB{2409}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2407}
+.LBB{2408}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2409}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2409}
+	jmp	.LBB{5}
+.LBB{2410}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2411}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2236}
+.Ltmp{2412}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2413}:
+	jmp	.LBB{2236}
+.LBB{2414}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2255}
+	jmp	.LBB{323}
+.LBB{2415}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2230}
+.Ltmp{2416}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2417}:
+	jmp	.LBB{2230}
+.LBB{2418}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2419}
+	cmpq	%rdi, %rax
+	jae	.LBB{2419}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2420}
+.LBB{2421}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2419}
+.LBB{2420}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2421}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2421}
+	jmp	.LBB{5}
+.LBB{2422}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2257}
+	jmp	.LBB{323}
+.LBB{2423}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2259}
+	jmp	.LBB{323}
+.LBB{2419}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2424}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2424}
+	jmp	.LBB{4}
+.LBB{2403}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2425}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2404}
+.LBB{2407}:
+	movq	152(%rsp), %rdi
+.Ltmp{2426}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2427}:
+	jmp	.LBB{4}
+.LBB{2396}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2400}
+	jmp	.LBB{295}
+.LBB{2139}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{2113}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{2106}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{2124}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2428}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2429}:
+	cmpl	$16, %r8d
+	jb	.LBB{2430}
+	jmp	.LBB{15}
+.LBB{2431}:
+	cmpl	$16, %r8d
+	jb	.LBB{2432}
+	jmp	.LBB{233}
+.LBB{2433}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	jbe	.LBB{2434}
+	jmp	.LBB{295}
+.LBB{2435}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2436}
+	jmp	.LBB{15}
+.LBB{2437}:
+	cmpl	$16, %r8d
+	jb	.LBB{2438}
+	jmp	.LBB{15}
+.LBB{2439}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2440}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	jbe	.LBB{2441}
+	jmp	.LBB{399}
+.LBB{2442}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2443}
+	xorl	%edx, %edx
+	jmp	.LBB{2444}
+.LBB{2445}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2446}:
+	cmpl	$16, %r8d
+	jb	.LBB{2447}
+	jmp	.LBB{15}
+.LBB{2448}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	jbe	.LBB{2449}
+	jmp	.LBB{295}
+.LBB{2450}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2451}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2452}
+	cmpq	$-1, %rcx
+	je	.LBB{2453}
+.LBB{2452}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2454}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2455}
+	jmp	.LBB{295}
+.LBB{2456}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{377}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{378}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2457}
+	cmpq	%r9, %r8
+	jae	.LBB{2457}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2458}
+.LBB{2459}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2457}
+.LBB{2458}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2459}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2459}
+	jmp	.LBB{5}
+.LBB{2460}:
+	cmpl	$16, %r8d
+	jb	.LBB{2461}
+	jmp	.LBB{15}
+.LBB{2462}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2463}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2464}
+.Ltmp{2465}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2466}:
+	jmp	.LBB{2464}
+.LBB{2467}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{377}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2468}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2468}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2469}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	jbe	.LBB{2470}
+	jmp	.LBB{399}
+.LBB{2471}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2472}
+.Ltmp{2473}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2474}:
+	jmp	.LBB{2472}
+.LBB{2475}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	jbe	.LBB{2476}
+	jmp	.LBB{295}
+.LBB{2477}:
+	cmpl	$16, %r8d
+	jb	.LBB{2478}
+	jmp	.LBB{15}
+.LBB{2479}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{387}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{377}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2480}
+	cmpq	%rdi, %rax
+	jae	.LBB{2480}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2481}
+.LBB{2482}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2480}
+.LBB{2481}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2482}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2482}
+	jmp	.LBB{5}
+.LBB{2483}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	jbe	.LBB{2484}
+	jmp	.LBB{399}
+.LBB{2485}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{398}
+	cmpl	$15, %r8d
+	jbe	.LBB{2486}
+	jmp	.LBB{399}
+.LBB{2480}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2487}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2487}
+	jmp	.LBB{4}
+.LBB{2443}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2488}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2444}
+.LBB{2457}:
+	movq	152(%rsp), %rdi
+.Ltmp{2489}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2490}:
+	jmp	.LBB{4}
+.LBB{2451}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2455}
+	jmp	.LBB{295}
+.LBB{2197}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2172}:
+	movq	%rax, 16(%rsp,%r8,8)
+.LBB{2165}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+.LBB{2183}:
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{2491}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2492}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2493}
+	jmp	.LBB{4}
+.LBB{2494}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{401}
+	cmpl	$15, %r8d
+	jbe	.LBB{2357}
+	jmp	.LBB{295}
+.LBB{2495}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2493}
+.LBB{2496}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2493}
+	jmp	.LBB{4}
+.LBB{2497}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2498}
+	jmp	.LBB{294}
+.LBB{2499}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2373}
+	jmp	.LBB{107}
+.LBB{2500}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2501}
+.LBB{2502}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2503}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	jbe	.LBB{2504}
+	jmp	.LBB{295}
+.LBB{2505}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2493}
+.LBB{2506}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	jbe	.LBB{2507}
+	jmp	.LBB{295}
+.LBB{2508}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2493}
+	jmp	.LBB{2501}
+.LBB{2509}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2501}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{2510}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+.LBB{2228}:
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+.LBB{2344}:
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2511}:
+	movzbl	%r13b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{489}
+.LBB{2234}:
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2512}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2512}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{107}
+.LBB{2383}:
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2513}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2230}
+.Ltmp{2514}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2515}:
+	jmp	.LBB{2230}
+.LBB{2516}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2236}
+.Ltmp{2517}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2518}:
+.LBB{2236}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{2493}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{2519}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2520}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2521}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2522}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2523}
+	cmpq	$-1, %rcx
+	je	.LBB{2524}
+.LBB{2523}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2525}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2526}
+	jmp	.LBB{295}
+.LBB{2527}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2528}
+	xorl	%edx, %edx
+	jmp	.LBB{2529}
+.LBB{2530}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+.LBB{2245}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2531}
+	jmp	.LBB{275}
+.LBB{2532}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{504}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{489}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2533}
+	cmpq	%rdi, %rax
+	jae	.LBB{2533}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2534}
+.LBB{2535}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2533}
+.LBB{2534}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2535}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2535}
+	jmp	.LBB{5}
+.LBB{2536}:
+	cmpl	$16, %r8d
+	jae	.LBB{107}
+	movzbl	%r13b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{489}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{490}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2537}
+	cmpq	%r9, %r8
+	jae	.LBB{2537}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2538}
+.LBB{2539}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2537}
+.LBB{2538}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2539}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2539}
+	jmp	.LBB{5}
+.LBB{2540}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+.LBB{2255}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2541}
+	jmp	.LBB{275}
+.LBB{2542}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+.LBB{2257}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2543}
+	jmp	.LBB{275}
+.LBB{2544}:
+	movzbl	%r13b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{616}
+.LBB{2259}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2545}
+	jmp	.LBB{275}
+.LBB{2533}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2546}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2546}
+	jmp	.LBB{4}
+.LBB{2528}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2547}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2529}
+.LBB{2537}:
+	movq	152(%rsp), %rdi
+.Ltmp{2548}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2549}:
+	jmp	.LBB{4}
+.LBB{2522}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2526}
+	jmp	.LBB{295}
+.LBB{2225}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2226}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2399}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2400}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2306}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{565}
+.LBB{2307}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2367}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{2368}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2525}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2526}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2454}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2455}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1840}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2550}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1841}
+.LBB{1684}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{1671}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{194}
+.LBB{1843}:
+	testq	%rax, %rax
+	je	.LBB{200}
+	leaq	(%r12,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{2551}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{2551}
+	jmp	.LBB{200}
+.LBB{1734}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{260}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{40}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{2552}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2553}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2554}
+	jmp	.LBB{2555}
+.LBB{2556}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2555}
+	jmp	.LBB{2554}
+.LBB{2557}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{211}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2558}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2558}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{213}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{2555}
+.LBB{2559}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2560}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2561}
+.LBB{2562}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2563}
+	cmpq	%r9, %r8
+	jae	.LBB{2563}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2564}
+.LBB{2565}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2563}
+.LBB{2564}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2565}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2565}
+	jmp	.LBB{5}
+.LBB{2566}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{2555}
+.LBB{2567}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2568}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2569}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2570}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2571}
+.LBB{2572}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2554}
+	jmp	.LBB{2561}
+.LBB{2573}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2574}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2575}
+.Ltmp{2576}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2577}:
+.LBB{2575}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2554}
+.LBB{2578}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2579}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2580}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2581}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2582}
+	cmpq	$-1, %rcx
+	je	.LBB{2583}
+.LBB{2582}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2584}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2571}
+	jmp	.LBB{226}
+.LBB{2585}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2586}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2587}
+.Ltmp{2588}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2589}:
+.LBB{2587}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{2555}
+.LBB{2590}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2591}
+	xorl	%edx, %edx
+	jmp	.LBB{2592}
+.LBB{2593}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2594}
+	cmpq	%rdi, %rax
+	jae	.LBB{2594}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2595}
+.LBB{2596}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2594}
+.LBB{2595}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2596}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2596}
+	jmp	.LBB{5}
+.LBB{2597}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2598}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2599}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{272}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{273}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2600}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2554}
+	jmp	.LBB{2555}
+.LBB{2601}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2555}
+.LBB{2554}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r13
+	jmp	.LBB{2555}
+.LBB{2602}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2603}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2561}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{2555}
+.LBB{2604}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{2594}:
+	testq	%rcx, %rcx
+	je	.LBB{2555}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2605}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2605}
+	jmp	.LBB{2555}
+.LBB{2591}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2606}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2592}
+.LBB{2563}:
+	movq	152(%rsp), %rdi
+.Ltmp{2607}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2608}:
+	jmp	.LBB{2555}
+.LBB{2581}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2571}
+	jmp	.LBB{226}
+.LBB{2584}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{2571}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2555}
+.LBB{1735}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{331}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{142}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{2609}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2610}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2611}
+	jmp	.LBB{2612}
+.LBB{2613}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2612}
+	jmp	.LBB{2611}
+.LBB{2614}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2615}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2615}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{252}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2612}
+.LBB{2616}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2617}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2618}
+.LBB{2619}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{275}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2620}
+	cmpq	%r9, %r8
+	jae	.LBB{2620}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2621}
+.LBB{2622}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2620}
+.LBB{2621}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2622}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2622}
+	jmp	.LBB{5}
+.LBB{2623}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{2612}
+.LBB{2624}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2625}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{302}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2626}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2627}
+.LBB{2628}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2611}
+	jmp	.LBB{2618}
+.LBB{2629}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2630}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2631}
+.Ltmp{2632}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2633}:
+.LBB{2631}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{2611}
+.LBB{2634}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2635}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2636}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2637}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2638}
+	cmpq	$-1, %rcx
+	je	.LBB{2639}
+.LBB{2638}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2640}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2627}
+	jmp	.LBB{295}
+.LBB{2641}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2642}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2643}
+.Ltmp{2644}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2645}:
+.LBB{2643}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{2612}
+.LBB{2646}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2647}
+	xorl	%edx, %edx
+	jmp	.LBB{2648}
+.LBB{2649}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{275}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2650}
+	cmpq	%rdi, %rax
+	jae	.LBB{2650}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2651}
+.LBB{2652}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2650}
+.LBB{2651}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2652}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2652}
+	jmp	.LBB{5}
+.LBB{2653}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2654}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2655}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{252}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2656}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2611}
+	jmp	.LBB{2612}
+.LBB{2657}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2612}
+.LBB{2611}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{2612}
+.LBB{2658}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2659}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2618}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{2612}
+.LBB{2660}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{2650}:
+	testq	%rcx, %rcx
+	je	.LBB{2612}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2661}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2661}
+	jmp	.LBB{2612}
+.LBB{2647}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2662}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2648}
+.LBB{2620}:
+	movq	152(%rsp), %rdi
+.Ltmp{2663}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2664}:
+	jmp	.LBB{2612}
+.LBB{2637}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2627}
+	jmp	.LBB{295}
+.LBB{2640}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2627}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2612}
+.LBB{1736}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{363}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{183}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{2665}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2666}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2667}
+	jmp	.LBB{2668}
+.LBB{2669}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2668}
+	jmp	.LBB{2667}
+.LBB{2670}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{211}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{2671}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2671}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{213}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{2668}
+.LBB{2672}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2673}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2674}
+.LBB{2675}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2676}
+	cmpq	%r9, %r8
+	jae	.LBB{2676}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2677}
+.LBB{2678}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2676}
+.LBB{2677}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2678}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2678}
+	jmp	.LBB{5}
+.LBB{2679}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{2668}
+.LBB{2680}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2681}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2569}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2682}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2683}
+.LBB{2684}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2667}
+	jmp	.LBB{2674}
+.LBB{2685}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2686}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2687}
+.Ltmp{2688}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2689}:
+.LBB{2687}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2667}
+.LBB{2690}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2691}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2692}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2693}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2694}
+	cmpq	$-1, %rcx
+	je	.LBB{2695}
+.LBB{2694}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2696}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2683}
+	jmp	.LBB{226}
+.LBB{2697}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2698}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2699}
+.Ltmp{2700}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2701}:
+.LBB{2699}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{2668}
+.LBB{2702}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2703}
+	xorl	%edx, %edx
+	jmp	.LBB{2704}
+.LBB{2705}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2706}
+	cmpq	%rdi, %rax
+	jae	.LBB{2706}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2707}
+.LBB{2708}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2706}
+.LBB{2707}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2708}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2708}
+	jmp	.LBB{5}
+.LBB{2709}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2710}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2711}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{272}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{273}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2712}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2667}
+	jmp	.LBB{2668}
+.LBB{2713}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2668}
+.LBB{2667}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r13
+	jmp	.LBB{2668}
+.LBB{2714}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2715}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2674}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{2668}
+.LBB{2716}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{2706}:
+	testq	%rcx, %rcx
+	je	.LBB{2668}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2717}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2717}
+	jmp	.LBB{2668}
+.LBB{2703}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2718}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2704}
+.LBB{2676}:
+	movq	152(%rsp), %rdi
+.Ltmp{2719}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2720}:
+	jmp	.LBB{2668}
+.LBB{2693}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2683}
+	jmp	.LBB{226}
+.LBB{2696}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{2683}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2668}
+.LBB{1737}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{425}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{395}
+	jmp	.LBB{5}
+.LBB{1738}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{672}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{86}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2721}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2722}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2723}
+	jmp	.LBB{2724}
+.LBB{2725}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2724}
+	jmp	.LBB{2723}
+.LBB{2726}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{437}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2727}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2727}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{439}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2724}
+.LBB{2728}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2729}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2730}
+.LBB{2731}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{248}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{471}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{472}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2732}
+	cmpq	%r9, %r8
+	jae	.LBB{2732}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2733}
+.LBB{2734}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2732}
+.LBB{2733}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2734}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2734}
+	jmp	.LBB{5}
+.LBB{2735}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{2724}
+.LBB{2736}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2737}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2738}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2739}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{2740}
+.LBB{2741}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2723}
+	jmp	.LBB{2730}
+.LBB{2742}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2743}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2744}
+.Ltmp{2745}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2746}:
+.LBB{2744}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{2723}
+.LBB{2747}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2748}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2749}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2750}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2751}
+	cmpq	$-1, %rcx
+	je	.LBB{2752}
+.LBB{2751}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2753}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2740}
+	jmp	.LBB{226}
+.LBB{2754}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2755}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2756}
+.Ltmp{2757}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2758}:
+.LBB{2756}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{2724}
+.LBB{2759}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2760}
+	xorl	%edx, %edx
+	jmp	.LBB{2761}
+.LBB{2762}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{248}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{471}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{472}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2763}
+	cmpq	%rdi, %rax
+	jae	.LBB{2763}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2764}
+.LBB{2765}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2763}
+.LBB{2764}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2765}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2765}
+	jmp	.LBB{5}
+.LBB{2766}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2767}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2768}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{437}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{439}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2769}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2723}
+	jmp	.LBB{2724}
+.LBB{2770}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2724}
+.LBB{2723}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	jmp	.LBB{2724}
+.LBB{2771}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2772}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2730}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	jmp	.LBB{2724}
+.LBB{2773}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{2763}:
+	testq	%rcx, %rcx
+	je	.LBB{2724}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2774}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2774}
+	jmp	.LBB{2724}
+.LBB{2760}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2775}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2761}
+.LBB{2732}:
+	movq	152(%rsp), %rdi
+.Ltmp{2776}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2777}:
+	jmp	.LBB{2724}
+.LBB{2750}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2740}
+	jmp	.LBB{226}
+.LBB{2753}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{2740}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2724}
+.LBB{1739}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{681}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{92}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r15), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{2778}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2779}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2780}
+	jmp	.LBB{2781}
+.LBB{2782}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2781}
+	jmp	.LBB{2780}
+.LBB{2783}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2784}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2784}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2781}
+.LBB{2785}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2786}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2787}
+.LBB{2788}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2789}
+	cmpq	%r9, %r8
+	jae	.LBB{2789}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2790}
+.LBB{2791}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2789}
+.LBB{2790}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2791}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2791}
+	jmp	.LBB{5}
+.LBB{2792}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{2781}
+.LBB{2793}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2794}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2795}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2796}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2797}
+.LBB{2798}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2780}
+	jmp	.LBB{2787}
+.LBB{2799}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2800}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2801}
+.Ltmp{2802}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2803}:
+.LBB{2801}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2780}
+.LBB{2804}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2805}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2806}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2807}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2808}
+	cmpq	$-1, %rcx
+	je	.LBB{2809}
+.LBB{2808}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2810}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2797}
+	jmp	.LBB{137}
+.LBB{2811}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2812}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2813}
+.Ltmp{2814}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2815}:
+.LBB{2813}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{2781}
+.LBB{2816}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2817}
+	xorl	%edx, %edx
+	jmp	.LBB{2818}
+.LBB{2819}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2820}
+	cmpq	%rdi, %rax
+	jae	.LBB{2820}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2821}
+.LBB{2822}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2820}
+.LBB{2821}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2822}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2822}
+	jmp	.LBB{5}
+.LBB{2823}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2824}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2825}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2826}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2780}
+	jmp	.LBB{2781}
+.LBB{2827}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2781}
+.LBB{2780}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{2781}
+.LBB{2828}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2829}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2787}:
+	addq	$2, %r15
+	movq	%r15, %r12
+	jmp	.LBB{2781}
+.LBB{2830}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{2820}:
+	testq	%rcx, %rcx
+	je	.LBB{2781}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2831}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2831}
+	jmp	.LBB{2781}
+.LBB{2817}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2832}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2818}
+.LBB{2789}:
+	movq	152(%rsp), %rdi
+.Ltmp{2833}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2834}:
+	jmp	.LBB{2781}
+.LBB{2807}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2797}
+	jmp	.LBB{137}
+.LBB{2810}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{2797}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2781}
+.LBB{1740}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{688}:
+	cmpl	$16, %r8d
+	jae	.LBB{565}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{525}
+	jmp	.LBB{5}
+.LBB{1741}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{711}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{538}
+	jmp	.LBB{5}
+.LBB{1742}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{757}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{559}
+	jmp	.LBB{5}
+.LBB{1743}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{801}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{161}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r15), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{2835}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2836}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2837}
+	jmp	.LBB{2838}
+.LBB{2839}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2838}
+	jmp	.LBB{2837}
+.LBB{2840}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2841}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2841}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2838}
+.LBB{2842}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2843}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2844}
+.LBB{2845}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2846}
+	cmpq	%r9, %r8
+	jae	.LBB{2846}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2847}
+.LBB{2848}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2846}
+.LBB{2847}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2848}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2848}
+	jmp	.LBB{5}
+.LBB{2849}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{2838}
+.LBB{2850}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2851}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2795}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2852}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2853}
+.LBB{2854}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2837}
+	jmp	.LBB{2844}
+.LBB{2855}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2856}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2857}
+.Ltmp{2858}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2859}:
+.LBB{2857}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2837}
+.LBB{2860}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2861}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2862}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2863}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2864}
+	cmpq	$-1, %rcx
+	je	.LBB{2865}
+.LBB{2864}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2866}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2853}
+	jmp	.LBB{137}
+.LBB{2867}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2868}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2869}
+.Ltmp{2870}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2871}:
+.LBB{2869}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{2838}
+.LBB{2872}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2873}
+	xorl	%edx, %edx
+	jmp	.LBB{2874}
+.LBB{2875}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2876}
+	cmpq	%rdi, %rax
+	jae	.LBB{2876}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2877}
+.LBB{2878}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2876}
+.LBB{2877}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2878}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2878}
+	jmp	.LBB{5}
+.LBB{2879}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2880}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2881}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2882}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2837}
+	jmp	.LBB{2838}
+.LBB{2883}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2838}
+.LBB{2837}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{2838}
+.LBB{2884}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2885}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2844}:
+	addq	$2, %r15
+	movq	%r15, %r12
+	jmp	.LBB{2838}
+.LBB{2886}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	ja	.LBB{88}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{2876}:
+	testq	%rcx, %rcx
+	je	.LBB{2838}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2887}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2887}
+	jmp	.LBB{2838}
+.LBB{2873}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2888}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2874}
+.LBB{2846}:
+	movq	152(%rsp), %rdi
+.Ltmp{2889}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2890}:
+	jmp	.LBB{2838}
+.LBB{2863}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2853}
+	jmp	.LBB{137}
+.LBB{2866}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{2853}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2838}
+.LBB{1744}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{812}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{572}
+	jmp	.LBB{5}
+.LBB{1745}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{815}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{577}
+	jmp	.LBB{5}
+.LBB{1746}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{922}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{67}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2891}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2892}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2893}
+	jmp	.LBB{2894}
+.LBB{2895}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2894}
+	jmp	.LBB{2893}
+.LBB{2896}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2897}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2897}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2894}
+.LBB{2898}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2899}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2900}
+.LBB{2901}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2902}
+	cmpq	%r9, %r8
+	jae	.LBB{2902}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2903}
+.LBB{2904}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2902}
+.LBB{2903}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2904}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2904}
+	jmp	.LBB{5}
+.LBB{2905}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{2894}
+.LBB{2906}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2907}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2908}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2909}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2910}
+.LBB{2911}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2893}
+	jmp	.LBB{2900}
+.LBB{2912}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2913}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{2914}
+.Ltmp{2915}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2916}:
+.LBB{2914}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{2893}
+.LBB{2917}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2918}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2919}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2920}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2921}
+	cmpq	$-1, %rcx
+	je	.LBB{2922}
+.LBB{2921}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2923}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2910}
+	jmp	.LBB{621}
+.LBB{2924}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2925}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2926}
+.Ltmp{2927}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2928}:
+.LBB{2926}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{2894}
+.LBB{2929}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2930}
+	xorl	%edx, %edx
+	jmp	.LBB{2931}
+.LBB{2932}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2933}
+	cmpq	%rdi, %rax
+	jae	.LBB{2933}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2934}
+.LBB{2935}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2933}
+.LBB{2934}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2935}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2935}
+	jmp	.LBB{5}
+.LBB{2936}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2937}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2938}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2939}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2893}
+	jmp	.LBB{2894}
+.LBB{2940}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2894}
+.LBB{2893}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{2894}
+.LBB{2941}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2942}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2900}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{2894}
+.LBB{2943}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{2933}:
+	testq	%rcx, %rcx
+	je	.LBB{2894}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2944}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2944}
+	jmp	.LBB{2894}
+.LBB{2930}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2945}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2931}
+.LBB{2902}:
+	movq	152(%rsp), %rdi
+.Ltmp{2946}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2947}:
+	jmp	.LBB{2894}
+.LBB{2920}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2910}
+	jmp	.LBB{621}
+.LBB{2923}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{2910}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2894}
+.LBB{1747}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{973}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{149}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r13), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{2948}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2949}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2950}
+	jmp	.LBB{2951}
+.LBB{2952}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2951}
+	jmp	.LBB{2950}
+.LBB{2953}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{2954}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2954}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{2951}
+.LBB{2955}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2956}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2957}
+.LBB{2958}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2959}
+	cmpq	%r9, %r8
+	jae	.LBB{2959}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2960}
+.LBB{2961}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2959}
+.LBB{2960}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2961}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2961}
+	jmp	.LBB{5}
+.LBB{2962}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{2951}
+.LBB{2963}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2964}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2908}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2965}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%bpl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{2966}
+.LBB{2967}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2950}
+	jmp	.LBB{2957}
+.LBB{2968}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2969}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{5}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{2970}
+.Ltmp{2971}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2972}:
+.LBB{2970}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+	jmp	.LBB{2950}
+.LBB{2973}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2974}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2975}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2976}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2977}
+	cmpq	$-1, %rcx
+	je	.LBB{2978}
+.LBB{2977}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2979}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2966}
+	jmp	.LBB{44}
+.LBB{2980}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2981}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r13
+	cmpq	216(%rsp), %r14
+	jne	.LBB{2982}
+.Ltmp{2983}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2984}:
+.LBB{2982}:
+	movq	224(%rsp), %rax
+	movq	%r13, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{2951}
+.LBB{2985}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2986}
+	xorl	%edx, %edx
+	jmp	.LBB{2987}
+.LBB{2988}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2989}
+	cmpq	%rdi, %rax
+	jae	.LBB{2989}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2990}
+.LBB{2991}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2989}
+.LBB{2990}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2991}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2991}
+	jmp	.LBB{5}
+.LBB{2992}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2993}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	andq	%r12, %rcx
+	shrq	$2, %rax
+	andq	%r12, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	imulq	%rbx, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2994}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2995}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2950}
+	jmp	.LBB{2951}
+.LBB{2996}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2951}
+.LBB{2950}:
+	movzbl	%bpl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{2951}
+.LBB{2997}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2998}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2957}:
+	addq	$2, %r13
+	movq	%r13, %r15
+	jmp	.LBB{2951}
+.LBB{2999}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{2989}:
+	testq	%rcx, %rcx
+	je	.LBB{2951}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3000}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3000}
+	jmp	.LBB{2951}
+.LBB{2986}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3001}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2987}
+.LBB{2959}:
+	movq	152(%rsp), %rdi
+.Ltmp{3002}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3003}:
+	jmp	.LBB{2951}
+.LBB{2976}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2966}
+	jmp	.LBB{44}
+.LBB{2979}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{2966}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{2951}
+.LBB{1748}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1012}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{178}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{3004}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3005}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3006}
+	jmp	.LBB{3007}
+.LBB{3008}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3007}
+	jmp	.LBB{3006}
+.LBB{3009}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3010}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3010}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3007}
+.LBB{3011}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3012}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3013}
+.LBB{3014}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3015}
+	cmpq	%r9, %r8
+	jae	.LBB{3015}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3016}
+.LBB{3017}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3015}
+.LBB{3016}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3017}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3017}
+	jmp	.LBB{5}
+.LBB{3018}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{3007}
+.LBB{3019}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3020}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2908}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3021}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3022}
+.LBB{3023}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3006}
+	jmp	.LBB{3013}
+.LBB{3024}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3025}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3026}
+.Ltmp{3027}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3028}:
+.LBB{3026}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3006}
+.LBB{3029}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3030}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3031}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3032}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3033}
+	cmpq	$-1, %rcx
+	je	.LBB{3034}
+.LBB{3033}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3035}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3022}
+	jmp	.LBB{621}
+.LBB{3036}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3037}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{3038}
+.Ltmp{3039}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3040}:
+.LBB{3038}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{3007}
+.LBB{3041}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3042}
+	xorl	%edx, %edx
+	jmp	.LBB{3043}
+.LBB{3044}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3045}
+	cmpq	%rdi, %rax
+	jae	.LBB{3045}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3046}
+.LBB{3047}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3045}
+.LBB{3046}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3047}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3047}
+	jmp	.LBB{5}
+.LBB{3048}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3049}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3050}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3051}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3006}
+	jmp	.LBB{3007}
+.LBB{3052}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3007}
+.LBB{3006}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{3007}
+.LBB{3053}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3054}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3013}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{3007}
+.LBB{3055}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	ja	.LBB{174}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{3045}:
+	testq	%rcx, %rcx
+	je	.LBB{3007}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3056}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3056}
+	jmp	.LBB{3007}
+.LBB{3042}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3057}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3043}
+.LBB{3015}:
+	movq	152(%rsp), %rdi
+.Ltmp{3058}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3059}:
+	jmp	.LBB{3007}
+.LBB{3032}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3022}
+	jmp	.LBB{621}
+.LBB{3035}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{3022}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3007}
+.LBB{1749}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1043}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{188}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{3060}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3061}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3062}
+	jmp	.LBB{3063}
+.LBB{3064}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3063}
+	jmp	.LBB{3062}
+.LBB{3065}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3066}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3066}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3063}
+.LBB{3067}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3068}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3069}
+.LBB{3070}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3071}
+	cmpq	%r9, %r8
+	jae	.LBB{3071}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3072}
+.LBB{3073}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3071}
+.LBB{3072}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3073}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3073}
+	jmp	.LBB{5}
+.LBB{3074}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{3063}
+.LBB{3075}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3076}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2908}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3077}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r13b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3078}
+.LBB{3079}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3062}
+	jmp	.LBB{3069}
+.LBB{3080}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3081}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3082}
+.Ltmp{3083}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3084}:
+.LBB{3082}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3062}
+.LBB{3085}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3086}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3087}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3088}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3089}
+	cmpq	$-1, %rcx
+	je	.LBB{3090}
+.LBB{3089}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3091}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3078}
+	jmp	.LBB{621}
+.LBB{3092}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3093}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r12
+	cmpq	216(%rsp), %r14
+	jne	.LBB{3094}
+.Ltmp{3095}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3096}:
+.LBB{3094}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 232(%rsp)
+	jmp	.LBB{3063}
+.LBB{3097}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3098}
+	xorl	%edx, %edx
+	jmp	.LBB{3099}
+.LBB{3100}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3101}
+	cmpq	%rdi, %rax
+	jae	.LBB{3101}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3102}
+.LBB{3103}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3101}
+.LBB{3102}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3103}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3103}
+	jmp	.LBB{5}
+.LBB{3104}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3105}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3106}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3107}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3062}
+	jmp	.LBB{3063}
+.LBB{3108}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3063}
+.LBB{3062}:
+	movzbl	%r13b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{3063}
+.LBB{3109}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3110}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3069}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{3063}
+.LBB{3111}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{3101}:
+	testq	%rcx, %rcx
+	je	.LBB{3063}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3112}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3112}
+	jmp	.LBB{3063}
+.LBB{3098}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3113}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3099}
+.LBB{3071}:
+	movq	152(%rsp), %rdi
+.Ltmp{3114}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3115}:
+	jmp	.LBB{3063}
+.LBB{3088}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3078}
+	jmp	.LBB{621}
+.LBB{3091}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{3078}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3063}
+.LBB{1750}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1153}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{48}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{3116}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3117}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3118}
+	jmp	.LBB{3119}
+.LBB{3120}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3119}
+	jmp	.LBB{3118}
+.LBB{3121}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3122}
+	cmpq	%r9, %r8
+	jae	.LBB{3122}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3123}
+.LBB{3124}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3122}
+.LBB{3123}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3124}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3124}
+	jmp	.LBB{5}
+.LBB{3125}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3126}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3127}
+.LBB{3128}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3129}
+	cmpq	%rdi, %rax
+	jae	.LBB{3129}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3130}
+.LBB{3131}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3129}
+.LBB{3130}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3131}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3131}
+	jmp	.LBB{5}
+.LBB{3132}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3133}
+.Ltmp{3134}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3135}:
+.LBB{3133}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3119}
+.LBB{3136}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3137}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3138}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3139}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3140}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3118}
+	jmp	.LBB{3127}
+.LBB{3141}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3142}
+.LBB{3143}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3144}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{3119}
+.LBB{3145}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3119}
+	jmp	.LBB{3118}
+.LBB{3146}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3147}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3148}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3149}
+	cmpq	$-1, %rcx
+	je	.LBB{3150}
+.LBB{3149}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3151}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3142}
+	jmp	.LBB{44}
+.LBB{3152}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{3153}
+.Ltmp{3154}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3155}:
+.LBB{3153}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{3118}
+.LBB{3156}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3157}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3158}
+	xorl	%edx, %edx
+	jmp	.LBB{3159}
+.LBB{3160}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3161}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3162}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3163}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3163}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3119}
+.LBB{3164}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3165}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{3119}
+.LBB{3118}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r13
+	jmp	.LBB{3119}
+.LBB{3166}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3167}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3127}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{3119}
+.LBB{3168}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{3129}:
+	testq	%rcx, %rcx
+	je	.LBB{3119}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3169}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3169}
+	jmp	.LBB{3119}
+.LBB{3158}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3170}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3159}
+.LBB{3122}:
+	movq	152(%rsp), %rdi
+.Ltmp{3171}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3172}:
+	jmp	.LBB{3119}
+.LBB{3148}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3142}
+	jmp	.LBB{44}
+.LBB{3151}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{3142}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3119}
+.LBB{1751}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1210}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{60}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3173}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3174}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3175}
+	jmp	.LBB{3176}
+.LBB{3177}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3176}
+	jmp	.LBB{3175}
+.LBB{3178}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3179}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3179}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3176}
+.LBB{3180}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3181}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3182}
+.LBB{3183}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3184}
+	cmpq	%r9, %r8
+	jae	.LBB{3184}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3185}
+.LBB{3186}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3184}
+.LBB{3185}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3186}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3186}
+	jmp	.LBB{5}
+.LBB{3187}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3176}
+.LBB{3188}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3189}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3190}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3191}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3192}
+.LBB{3193}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3175}
+	jmp	.LBB{3182}
+.LBB{3194}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3195}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{3196}
+.Ltmp{3197}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3198}:
+.LBB{3196}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{3175}
+.LBB{3199}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3200}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3201}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3202}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3203}
+	cmpq	$-1, %rcx
+	je	.LBB{3204}
+.LBB{3203}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3205}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3192}
+	jmp	.LBB{56}
+.LBB{3206}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3207}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3208}
+.Ltmp{3209}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3210}:
+.LBB{3208}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3176}
+.LBB{3211}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3212}
+	xorl	%edx, %edx
+	jmp	.LBB{3213}
+.LBB{3214}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3215}
+	cmpq	%rdi, %rax
+	jae	.LBB{3215}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3216}
+.LBB{3217}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3215}
+.LBB{3216}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3217}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3217}
+	jmp	.LBB{5}
+.LBB{3218}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3219}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3220}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3221}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3175}
+	jmp	.LBB{3176}
+.LBB{3222}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3176}
+.LBB{3175}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3176}
+.LBB{3223}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3224}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3182}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{3176}
+.LBB{3225}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{3215}:
+	testq	%rcx, %rcx
+	je	.LBB{3176}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3226}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3226}
+	jmp	.LBB{3176}
+.LBB{3212}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3227}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3213}
+.LBB{3184}:
+	movq	152(%rsp), %rdi
+.Ltmp{3228}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3229}:
+	jmp	.LBB{3176}
+.LBB{3202}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3192}
+	jmp	.LBB{56}
+.LBB{3205}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{3192}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3176}
+.LBB{1752}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1250}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{81}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3230}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3231}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3232}
+	jmp	.LBB{3233}
+.LBB{3234}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3233}
+	jmp	.LBB{3232}
+.LBB{3235}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3236}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3236}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3233}
+.LBB{3237}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3238}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3239}
+.LBB{3240}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3241}
+	cmpq	%r9, %r8
+	jae	.LBB{3241}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3242}
+.LBB{3243}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3241}
+.LBB{3242}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3243}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3243}
+	jmp	.LBB{5}
+.LBB{3244}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3233}
+.LBB{3245}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3246}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3190}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3247}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3248}
+.LBB{3249}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3232}
+	jmp	.LBB{3239}
+.LBB{3250}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3251}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{3252}
+.Ltmp{3253}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3254}:
+.LBB{3252}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{3232}
+.LBB{3255}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3256}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3257}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3258}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3259}
+	cmpq	$-1, %rcx
+	je	.LBB{3260}
+.LBB{3259}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3261}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3248}
+	jmp	.LBB{56}
+.LBB{3262}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3263}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3264}
+.Ltmp{3265}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3266}:
+.LBB{3264}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3233}
+.LBB{3267}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3268}
+	xorl	%edx, %edx
+	jmp	.LBB{3269}
+.LBB{3270}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3271}
+	cmpq	%rdi, %rax
+	jae	.LBB{3271}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3272}
+.LBB{3273}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3271}
+.LBB{3272}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3273}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3273}
+	jmp	.LBB{5}
+.LBB{3274}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3275}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3276}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3277}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3232}
+	jmp	.LBB{3233}
+.LBB{3278}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3233}
+.LBB{3232}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3233}
+.LBB{3279}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3280}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3239}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{3233}
+.LBB{3281}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{3271}:
+	testq	%rcx, %rcx
+	je	.LBB{3233}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3282}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3282}
+	jmp	.LBB{3233}
+.LBB{3268}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3283}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3269}
+.LBB{3241}:
+	movq	152(%rsp), %rdi
+.Ltmp{3284}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3285}:
+	jmp	.LBB{3233}
+.LBB{3258}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3248}
+	jmp	.LBB{56}
+.LBB{3261}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{3248}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3233}
+.LBB{1753}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1296}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{116}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3286}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3287}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3288}
+	jmp	.LBB{3289}
+.LBB{3290}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3289}
+	jmp	.LBB{3288}
+.LBB{3291}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3292}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3292}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3289}
+.LBB{3293}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3294}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3295}
+.LBB{3296}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3297}
+	cmpq	%r9, %r8
+	jae	.LBB{3297}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3298}
+.LBB{3299}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3297}
+.LBB{3298}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3299}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3299}
+	jmp	.LBB{5}
+.LBB{3300}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3289}
+.LBB{3301}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3302}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3190}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3303}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3304}
+.LBB{3305}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3288}
+	jmp	.LBB{3295}
+.LBB{3306}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3307}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{3308}
+.Ltmp{3309}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3310}:
+.LBB{3308}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{3288}
+.LBB{3311}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3312}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3313}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3314}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3315}
+	cmpq	$-1, %rcx
+	je	.LBB{3316}
+.LBB{3315}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3317}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3304}
+	jmp	.LBB{56}
+.LBB{3318}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3319}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3320}
+.Ltmp{3321}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3322}:
+.LBB{3320}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3289}
+.LBB{3323}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3324}
+	xorl	%edx, %edx
+	jmp	.LBB{3325}
+.LBB{3326}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3327}
+	cmpq	%rdi, %rax
+	jae	.LBB{3327}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3328}
+.LBB{3329}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3327}
+.LBB{3328}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3329}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3329}
+	jmp	.LBB{5}
+.LBB{3330}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3331}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3332}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3333}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3288}
+	jmp	.LBB{3289}
+.LBB{3334}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3289}
+.LBB{3288}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3289}
+.LBB{3335}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3336}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3295}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{3289}
+.LBB{3337}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{3327}:
+	testq	%rcx, %rcx
+	je	.LBB{3289}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3338}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3338}
+	jmp	.LBB{3289}
+.LBB{3324}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3339}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3325}
+.LBB{3297}:
+	movq	152(%rsp), %rdi
+.Ltmp{3340}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3341}:
+	jmp	.LBB{3289}
+.LBB{3314}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3304}
+	jmp	.LBB{56}
+.LBB{3317}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{3304}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3289}
+.LBB{1754}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1306}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{204}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3342}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3343}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3344}
+	jmp	.LBB{3345}
+.LBB{3346}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3345}
+	jmp	.LBB{3344}
+.LBB{3347}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3348}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3348}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3345}
+.LBB{3349}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3350}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3351}
+.LBB{3352}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3353}
+	cmpq	%r9, %r8
+	jae	.LBB{3353}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3354}
+.LBB{3355}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3353}
+.LBB{3354}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3355}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3355}
+	jmp	.LBB{5}
+.LBB{3356}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3345}
+.LBB{3357}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3358}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3190}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3359}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3360}
+.LBB{3361}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3344}
+	jmp	.LBB{3351}
+.LBB{3362}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3363}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{3364}
+.Ltmp{3365}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3366}:
+.LBB{3364}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{3344}
+.LBB{3367}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3368}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3369}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3370}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3371}
+	cmpq	$-1, %rcx
+	je	.LBB{3372}
+.LBB{3371}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3373}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3360}
+	jmp	.LBB{56}
+.LBB{3374}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3375}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3376}
+.Ltmp{3377}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3378}:
+.LBB{3376}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3345}
+.LBB{3379}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3380}
+	xorl	%edx, %edx
+	jmp	.LBB{3381}
+.LBB{3382}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3383}
+	cmpq	%rdi, %rax
+	jae	.LBB{3383}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3384}
+.LBB{3385}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3383}
+.LBB{3384}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3385}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3385}
+	jmp	.LBB{5}
+.LBB{3386}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3387}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3388}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3389}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3344}
+	jmp	.LBB{3345}
+.LBB{3390}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3345}
+.LBB{3344}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3345}
+.LBB{3391}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3392}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3351}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{3345}
+.LBB{3393}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{3383}:
+	testq	%rcx, %rcx
+	je	.LBB{3345}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3394}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3394}
+	jmp	.LBB{3345}
+.LBB{3380}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3395}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3381}
+.LBB{3353}:
+	movq	152(%rsp), %rdi
+.Ltmp{3396}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3397}:
+	jmp	.LBB{3345}
+.LBB{3370}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3360}
+	jmp	.LBB{56}
+.LBB{3373}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{3360}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3345}
+.LBB{1755}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1338}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{208}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%rbx,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rbx), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3398}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3399}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3400}
+	jmp	.LBB{3401}
+.LBB{3402}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3401}
+	jmp	.LBB{3400}
+.LBB{3403}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3404}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3404}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{3401}
+.LBB{3405}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3406}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3407}
+.LBB{3408}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3409}
+	cmpq	%r9, %r8
+	jae	.LBB{3409}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3410}
+.LBB{3411}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3409}
+.LBB{3410}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3411}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3411}
+	jmp	.LBB{5}
+.LBB{3412}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3401}
+.LBB{3413}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3414}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2795}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3415}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r14d
+	movzbl	%r15b, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3416}
+.LBB{3417}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3400}
+	jmp	.LBB{3407}
+.LBB{3418}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3419}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{3420}
+.Ltmp{3421}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3422}:
+.LBB{3420}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{3400}
+.LBB{3423}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3424}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3425}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3426}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3427}
+	cmpq	$-1, %rcx
+	je	.LBB{3428}
+.LBB{3427}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3429}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3416}
+	jmp	.LBB{137}
+.LBB{3430}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3431}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3432}
+.Ltmp{3433}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3434}:
+.LBB{3432}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3401}
+.LBB{3435}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3436}
+	xorl	%edx, %edx
+	jmp	.LBB{3437}
+.LBB{3438}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3439}
+	cmpq	%rdi, %rax
+	jae	.LBB{3439}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3440}
+.LBB{3441}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3439}
+.LBB{3440}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3441}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3441}
+	jmp	.LBB{5}
+.LBB{3442}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3443}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3444}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%r14d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3445}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3400}
+	jmp	.LBB{3401}
+.LBB{3446}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3401}
+.LBB{3400}:
+	movzbl	%r15b, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3401}
+.LBB{3447}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3448}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3407}:
+	addq	$2, %rbx
+	movq	%rbx, %r12
+	jmp	.LBB{3401}
+.LBB{3449}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{3439}:
+	testq	%rcx, %rcx
+	je	.LBB{3401}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3450}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3450}
+	jmp	.LBB{3401}
+.LBB{3436}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3451}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3437}
+.LBB{3409}:
+	movq	152(%rsp), %rdi
+.Ltmp{3452}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3453}:
+	jmp	.LBB{3401}
+.LBB{3426}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3416}
+	jmp	.LBB{137}
+.LBB{3429}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{3416}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3401}
+.LBB{1756}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1395}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{127}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3454}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3455}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3456}
+	jmp	.LBB{3457}
+.LBB{3458}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3457}
+	jmp	.LBB{3456}
+.LBB{3459}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1062}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3460}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3460}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1064}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3457}
+.LBB{3461}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3462}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3463}
+.LBB{3464}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3465}
+	cmpq	%r9, %r8
+	jae	.LBB{3465}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3466}
+.LBB{3467}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3465}
+.LBB{3466}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3467}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3467}
+	jmp	.LBB{5}
+.LBB{3468}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3457}
+.LBB{3469}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3470}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3471}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{3472}
+.LBB{3473}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3456}
+	jmp	.LBB{3463}
+.LBB{3474}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3475}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{3476}
+.Ltmp{3477}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3478}:
+.LBB{3476}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{3456}
+.LBB{3479}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3480}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3481}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3482}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3483}
+	cmpq	$-1, %rcx
+	je	.LBB{3484}
+.LBB{3483}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3485}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3472}
+	jmp	.LBB{44}
+.LBB{3486}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3487}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3488}
+.Ltmp{3489}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3490}:
+.LBB{3488}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3457}
+.LBB{3491}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3492}
+	xorl	%edx, %edx
+	jmp	.LBB{3493}
+.LBB{3494}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3495}
+	cmpq	%rdi, %rax
+	jae	.LBB{3495}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3496}
+.LBB{3497}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3495}
+.LBB{3496}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3497}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3497}
+	jmp	.LBB{5}
+.LBB{3498}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3499}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3500}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1166}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1167}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3501}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3456}
+	jmp	.LBB{3457}
+.LBB{3502}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3457}
+.LBB{3456}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{3457}
+.LBB{3503}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3504}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3463}:
+	addq	$2, %r14
+	movq	%r14, %r12
+	jmp	.LBB{3457}
+.LBB{3505}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{3495}:
+	testq	%rcx, %rcx
+	je	.LBB{3457}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3506}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3506}
+	jmp	.LBB{3457}
+.LBB{3492}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3507}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3493}
+.LBB{3465}:
+	movq	152(%rsp), %rdi
+.Ltmp{3508}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3509}:
+	jmp	.LBB{3457}
+.LBB{3482}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3472}
+	jmp	.LBB{44}
+.LBB{3485}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{3472}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3457}
+.LBB{1757}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1446}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{75}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r14), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3510}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3511}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3512}
+	jmp	.LBB{3513}
+.LBB{3514}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3513}
+	jmp	.LBB{3512}
+.LBB{3515}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1062}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3516}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3516}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1064}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3513}
+.LBB{3517}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3518}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3519}
+.LBB{3520}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3521}
+	cmpq	%r9, %r8
+	jae	.LBB{3521}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3522}
+.LBB{3523}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3521}
+.LBB{3522}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3523}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3523}
+	jmp	.LBB{5}
+.LBB{3524}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3513}
+.LBB{3525}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3526}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r15,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3527}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	jmp	.LBB{3528}
+.LBB{3529}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3512}
+	jmp	.LBB{3519}
+.LBB{3530}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3531}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{3532}
+.Ltmp{3533}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3534}:
+.LBB{3532}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{3512}
+.LBB{3535}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3536}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3537}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3538}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3539}
+	cmpq	$-1, %rcx
+	je	.LBB{3540}
+.LBB{3539}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3541}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3528}
+	jmp	.LBB{44}
+.LBB{3542}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3543}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3544}
+.Ltmp{3545}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3546}:
+.LBB{3544}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3513}
+.LBB{3547}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3548}
+	xorl	%edx, %edx
+	jmp	.LBB{3549}
+.LBB{3550}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3551}
+	cmpq	%rdi, %rax
+	jae	.LBB{3551}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3552}
+.LBB{3553}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3551}
+.LBB{3552}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3553}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3553}
+	jmp	.LBB{5}
+.LBB{3554}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3555}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3556}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1166}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1167}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3557}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3512}
+	jmp	.LBB{3513}
+.LBB{3558}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3513}
+.LBB{3512}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r12
+	jmp	.LBB{3513}
+.LBB{3559}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3560}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3519}:
+	addq	$2, %r14
+	movq	%r14, %r12
+	jmp	.LBB{3513}
+.LBB{3561}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{3551}:
+	testq	%rcx, %rcx
+	je	.LBB{3513}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3562}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3562}
+	jmp	.LBB{3513}
+.LBB{3548}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3563}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3549}
+.LBB{3521}:
+	movq	152(%rsp), %rdi
+.Ltmp{3564}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3565}:
+	jmp	.LBB{3513}
+.LBB{3538}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3528}
+	jmp	.LBB{44}
+.LBB{3541}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{3528}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3513}
+.LBB{1758}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1497}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{53}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{3566}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3567}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3568}
+	jmp	.LBB{3569}
+.LBB{3570}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3569}
+	jmp	.LBB{3568}
+.LBB{3571}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3572}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3572}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3569}
+.LBB{3573}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3574}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3575}
+.LBB{3576}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3577}
+	cmpq	%r9, %r8
+	jae	.LBB{3577}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3578}
+.LBB{3579}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3577}
+.LBB{3578}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3579}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3579}
+	jmp	.LBB{5}
+.LBB{3580}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{3569}
+.LBB{3581}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3582}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3583}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3584}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3585}
+.LBB{3586}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3568}
+	jmp	.LBB{3575}
+.LBB{3587}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3588}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3589}
+.Ltmp{3590}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3591}:
+.LBB{3589}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3568}
+.LBB{3592}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3593}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3594}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3595}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3596}
+	cmpq	$-1, %rcx
+	je	.LBB{3597}
+.LBB{3596}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3598}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3585}
+	jmp	.LBB{1473}
+.LBB{3599}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3600}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3601}
+.Ltmp{3602}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3603}:
+.LBB{3601}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3569}
+.LBB{3604}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3605}
+	xorl	%edx, %edx
+	jmp	.LBB{3606}
+.LBB{3607}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3608}
+	cmpq	%rdi, %rax
+	jae	.LBB{3608}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3609}
+.LBB{3610}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3608}
+.LBB{3609}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3610}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3610}
+	jmp	.LBB{5}
+.LBB{3611}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3612}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3613}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3614}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3568}
+	jmp	.LBB{3569}
+.LBB{3615}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3569}
+.LBB{3568}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{3569}
+.LBB{3616}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3617}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3575}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{3569}
+.LBB{3618}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{3608}:
+	testq	%rcx, %rcx
+	je	.LBB{3569}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3619}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3619}
+	jmp	.LBB{3569}
+.LBB{3605}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3620}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3606}
+.LBB{3577}:
+	movq	152(%rsp), %rdi
+.Ltmp{3621}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3622}:
+	jmp	.LBB{3569}
+.LBB{3595}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3585}
+	jmp	.LBB{1473}
+.LBB{3598}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{3585}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3569}
+.LBB{1759}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1547}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{105}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r15), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{3623}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3624}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3625}
+	jmp	.LBB{3626}
+.LBB{3627}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3626}
+	jmp	.LBB{3625}
+.LBB{3628}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3629}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3629}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3626}
+.LBB{3630}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3631}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3632}
+.LBB{3633}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3634}
+	cmpq	%r9, %r8
+	jae	.LBB{3634}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3635}
+.LBB{3636}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3634}
+.LBB{3635}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3636}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3636}
+	jmp	.LBB{5}
+.LBB{3637}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{3626}
+.LBB{3638}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3639}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3139}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3640}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3641}
+.LBB{3642}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3625}
+	jmp	.LBB{3632}
+.LBB{3643}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3644}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{3645}
+.Ltmp{3646}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3647}:
+.LBB{3645}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{3625}
+.LBB{3648}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3649}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3650}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3651}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3652}
+	cmpq	$-1, %rcx
+	je	.LBB{3653}
+.LBB{3652}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3654}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3641}
+	jmp	.LBB{44}
+.LBB{3655}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3656}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3657}
+.Ltmp{3658}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3659}:
+.LBB{3657}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3626}
+.LBB{3660}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3661}
+	xorl	%edx, %edx
+	jmp	.LBB{3662}
+.LBB{3663}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3664}
+	cmpq	%rdi, %rax
+	jae	.LBB{3664}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3665}
+.LBB{3666}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3664}
+.LBB{3665}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3666}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3666}
+	jmp	.LBB{5}
+.LBB{3667}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3668}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3669}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3670}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3625}
+	jmp	.LBB{3626}
+.LBB{3671}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3626}
+.LBB{3625}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{3626}
+.LBB{3672}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3673}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3632}:
+	addq	$2, %r15
+	movq	%r15, %r12
+	jmp	.LBB{3626}
+.LBB{3674}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{3664}:
+	testq	%rcx, %rcx
+	je	.LBB{3626}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3675}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3675}
+	jmp	.LBB{3626}
+.LBB{3661}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3676}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3662}
+.LBB{3634}:
+	movq	152(%rsp), %rdi
+.Ltmp{3677}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3678}:
+	jmp	.LBB{3626}
+.LBB{3651}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3641}
+	jmp	.LBB{44}
+.LBB{3654}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{3641}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3626}
+.LBB{1760}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1598}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{34}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{3679}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3680}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3681}
+	jmp	.LBB{3682}
+.LBB{3683}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3682}
+	jmp	.LBB{3681}
+.LBB{3684}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3685}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3685}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3682}
+.LBB{3686}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3687}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3688}
+.LBB{3689}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3690}
+	cmpq	%r9, %r8
+	jae	.LBB{3690}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3691}
+.LBB{3692}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3690}
+.LBB{3691}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3692}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3692}
+	jmp	.LBB{5}
+.LBB{3693}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{3682}
+.LBB{3694}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3695}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3583}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3696}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3697}
+.LBB{3698}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3681}
+	jmp	.LBB{3688}
+.LBB{3699}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3700}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3701}
+.Ltmp{3702}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3703}:
+.LBB{3701}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3681}
+.LBB{3704}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3705}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3706}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3707}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3708}
+	cmpq	$-1, %rcx
+	je	.LBB{3709}
+.LBB{3708}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3710}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3697}
+	jmp	.LBB{1473}
+.LBB{3711}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3712}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3713}
+.Ltmp{3714}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3715}:
+.LBB{3713}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3682}
+.LBB{3716}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3717}
+	xorl	%edx, %edx
+	jmp	.LBB{3718}
+.LBB{3719}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3720}
+	cmpq	%rdi, %rax
+	jae	.LBB{3720}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3721}
+.LBB{3722}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3720}
+.LBB{3721}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3722}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3722}
+	jmp	.LBB{5}
+.LBB{3723}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3724}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3725}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3726}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3681}
+	jmp	.LBB{3682}
+.LBB{3727}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3682}
+.LBB{3681}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{3682}
+.LBB{3728}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3729}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3688}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{3682}
+.LBB{3730}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{3720}:
+	testq	%rcx, %rcx
+	je	.LBB{3682}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3731}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3731}
+	jmp	.LBB{3682}
+.LBB{3717}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3732}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3718}
+.LBB{3690}:
+	movq	152(%rsp), %rdi
+.Ltmp{3733}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3734}:
+	jmp	.LBB{3682}
+.LBB{3707}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3697}
+	jmp	.LBB{1473}
+.LBB{3710}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{3697}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3682}
+.LBB{1761}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1644}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{132}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{3735}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3736}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3737}
+	jmp	.LBB{3738}
+.LBB{3739}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3738}
+	jmp	.LBB{3737}
+.LBB{3740}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{3741}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3741}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{3738}
+.LBB{3742}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3743}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3744}
+.LBB{3745}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3746}
+	cmpq	%r9, %r8
+	jae	.LBB{3746}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3747}
+.LBB{3748}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3746}
+.LBB{3747}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3748}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3748}
+	jmp	.LBB{5}
+.LBB{3749}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{3738}
+.LBB{3750}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3751}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3583}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3752}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{3753}
+.LBB{3754}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3737}
+	jmp	.LBB{3744}
+.LBB{3755}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3756}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3757}
+.Ltmp{3758}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3759}:
+.LBB{3757}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3737}
+.LBB{3760}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3761}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3762}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3763}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3764}
+	cmpq	$-1, %rcx
+	je	.LBB{3765}
+.LBB{3764}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3766}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3753}
+	jmp	.LBB{1473}
+.LBB{3767}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3768}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3769}
+.Ltmp{3770}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3771}:
+.LBB{3769}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{3738}
+.LBB{3772}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3773}
+	xorl	%edx, %edx
+	jmp	.LBB{3774}
+.LBB{3775}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3776}
+	cmpq	%rdi, %rax
+	jae	.LBB{3776}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3777}
+.LBB{3778}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3776}
+.LBB{3777}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3778}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3778}
+	jmp	.LBB{5}
+.LBB{3779}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3780}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3781}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3782}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3737}
+	jmp	.LBB{3738}
+.LBB{3783}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3738}
+.LBB{3737}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{3738}
+.LBB{3784}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3785}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3744}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{3738}
+.LBB{3786}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{3776}:
+	testq	%rcx, %rcx
+	je	.LBB{3738}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3787}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3787}
+	jmp	.LBB{3738}
+.LBB{3773}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3788}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3774}
+.LBB{3746}:
+	movq	152(%rsp), %rdi
+.Ltmp{3789}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3790}:
+	jmp	.LBB{3738}
+.LBB{3763}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3753}
+	jmp	.LBB{1473}
+.LBB{3766}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{3753}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{3738}
+.LBB{2606}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2592}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2555}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r13), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{3791}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3792}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	jbe	.LBB{3793}
+	jmp	.LBB{229}
+.LBB{3794}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3795}
+	xorl	%edx, %edx
+	jmp	.LBB{3796}
+.LBB{3797}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3798}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3799}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3800}
+	cmpq	$-1, %rcx
+	je	.LBB{3801}
+.LBB{3800}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3802}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3803}
+	jmp	.LBB{226}
+.LBB{3804}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3805}
+	cmpq	%r9, %r8
+	jae	.LBB{3805}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3806}
+.LBB{3807}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3805}
+.LBB{3806}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3807}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3807}
+	jmp	.LBB{5}
+.LBB{3808}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3809}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3810}
+.Ltmp{3811}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3812}:
+	jmp	.LBB{3810}
+.LBB{3813}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	jbe	.LBB{3814}
+	jmp	.LBB{248}
+.LBB{3815}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3816}
+.Ltmp{3817}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3818}:
+	jmp	.LBB{3816}
+.LBB{3819}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3820}
+	cmpq	%rdi, %rax
+	jae	.LBB{3820}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3821}
+.LBB{3822}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3820}
+.LBB{3821}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3822}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3822}
+	jmp	.LBB{5}
+.LBB{3820}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3823}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3823}
+	jmp	.LBB{4}
+.LBB{3795}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3824}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3796}
+.LBB{3805}:
+	movq	152(%rsp), %rdi
+.Ltmp{3825}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3826}:
+	jmp	.LBB{4}
+.LBB{3799}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3803}
+	jmp	.LBB{226}
+.LBB{3802}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{3803}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2662}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2648}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2612}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{3827}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3828}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3829}
+	jmp	.LBB{4}
+.LBB{3830}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{3831}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{3829}
+.LBB{3832}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3833}
+	cmpq	%r9, %r8
+	jae	.LBB{3833}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3834}
+.LBB{3835}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3833}
+.LBB{3834}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3835}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3835}
+	jmp	.LBB{5}
+.LBB{3836}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3837}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3838}
+.LBB{3839}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{276}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{277}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3840}
+	cmpq	%rdi, %rax
+	jae	.LBB{3840}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3841}
+.LBB{3842}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3840}
+.LBB{3841}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3842}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3842}
+	jmp	.LBB{5}
+.LBB{3843}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2230}
+.Ltmp{3844}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3845}:
+.LBB{2230}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{3846}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{2507}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3847}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+.LBB{2531}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3848}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3849}
+	cmpl	$15, %r8d
+	jbe	.LBB{3850}
+	jmp	.LBB{137}
+.LBB{3851}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3829}
+	jmp	.LBB{3838}
+.LBB{3852}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3853}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3854}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{3829}
+.LBB{3855}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{2504}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3856}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3857}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3858}
+	cmpq	$-1, %rcx
+	je	.LBB{3859}
+.LBB{3858}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3860}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3861}
+	jmp	.LBB{295}
+.LBB{3862}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3863}
+.Ltmp{3864}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3865}:
+.LBB{3863}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{3829}
+.LBB{3866}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+.LBB{2373}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3867}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3868}
+	xorl	%edx, %edx
+	jmp	.LBB{3869}
+.LBB{3870}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{294}
+.LBB{2498}:
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{2378}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3871}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+.LBB{2380}:
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+.LBB{2541}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3872}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{250}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{3873}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{3873}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{450}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{3874}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3875}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{3829}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{4}
+.LBB{3876}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+.LBB{2387}:
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+.LBB{2543}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3877}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3838}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{3878}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{323}
+	cmpl	$15, %r8d
+	ja	.LBB{482}
+.LBB{2545}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3840}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3879}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3879}
+	jmp	.LBB{4}
+.LBB{3868}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3880}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3869}
+.LBB{3833}:
+	movq	152(%rsp), %rdi
+.Ltmp{3881}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3882}:
+	jmp	.LBB{4}
+.LBB{3857}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3861}
+	jmp	.LBB{295}
+.LBB{3860}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{295}
+.LBB{3861}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2718}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2704}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2668}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r13), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{3883}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3884}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3885}
+	jmp	.LBB{4}
+.LBB{3886}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{3885}
+.LBB{3887}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+.LBB{3888}:
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jb	.LBB{3889}
+	jmp	.LBB{5}
+.LBB{3890}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{3891}
+	jmp	.LBB{225}
+.LBB{3892}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3893}
+.LBB{3894}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3895}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3885}
+	jmp	.LBB{3893}
+.LBB{3896}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{3897}
+	jmp	.LBB{225}
+.LBB{3898}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3885}
+	jmp	.LBB{4}
+.LBB{3899}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{3900}
+	jmp	.LBB{225}
+.LBB{3901}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{3885}
+.LBB{3902}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3893}:
+	addq	$2, %r13
+	movq	%r13, %r14
+	jmp	.LBB{4}
+.LBB{3903}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{218}
+.LBB{3904}:
+	movl	%r15d, %eax
+	jmp	.LBB{3905}
+.LBB{3906}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3907}
+	cmpq	%r9, %r8
+	jae	.LBB{3907}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3908}
+.LBB{3909}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3907}
+.LBB{3908}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3909}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3909}
+	jmp	.LBB{5}
+.LBB{3910}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3911}
+	cmpq	%rdi, %rax
+	jae	.LBB{3911}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3912}
+.LBB{3913}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3911}
+.LBB{3912}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3913}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3913}
+	jmp	.LBB{5}
+.LBB{3914}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3816}
+.Ltmp{3915}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3916}:
+	jmp	.LBB{3816}
+.LBB{3917}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{229}
+.LBB{3793}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2294}
+	jmp	.LBB{226}
+.LBB{3918}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3919}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3920}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{3810}
+.Ltmp{3921}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3922}:
+.LBB{3810}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+.LBB{3885}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{3923}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3924}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3925}
+	cmpq	$-1, %rcx
+	je	.LBB{3926}
+.LBB{3925}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3927}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3928}
+	jmp	.LBB{226}
+.LBB{3929}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3930}
+	xorl	%edx, %edx
+	jmp	.LBB{3931}
+.LBB{3911}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3932}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3932}
+	jmp	.LBB{4}
+.LBB{3930}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3933}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3931}
+.LBB{3907}:
+	movq	152(%rsp), %rdi
+.Ltmp{3934}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3935}:
+	jmp	.LBB{4}
+.LBB{3924}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3928}
+	jmp	.LBB{226}
+.LBB{3927}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{3928}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1924}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1912}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1890}
+	jmp	.LBB{5}
+.LBB{2775}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2761}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2724}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{3936}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3937}:
+	cmpl	$16, %r8d
+	jb	.LBB{3938}
+	jmp	.LBB{38}
+.LBB{3939}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{3940}
+	jmp	.LBB{38}
+.LBB{3941}:
+	cmpl	$16, %r8d
+	jb	.LBB{3942}
+	jmp	.LBB{38}
+.LBB{3943}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jb	.LBB{3944}
+	jmp	.LBB{218}
+.LBB{3945}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3816}
+.Ltmp{3946}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3947}:
+	jmp	.LBB{3816}
+.LBB{3948}:
+	cmpl	$16, %r8d
+	jb	.LBB{3949}
+	jmp	.LBB{38}
+.LBB{3950}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jb	.LBB{3951}
+	jmp	.LBB{218}
+.LBB{3952}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{3953}
+.Ltmp{3954}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3955}:
+	jmp	.LBB{3953}
+.LBB{3956}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3957}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3958}:
+	cmpl	$16, %r8d
+	jb	.LBB{3959}
+	jmp	.LBB{30}
+.LBB{3960}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3961}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3962}
+	cmpq	$-1, %rcx
+	je	.LBB{3963}
+.LBB{3962}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3964}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3965}
+	jmp	.LBB{226}
+.LBB{3966}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3967}
+	xorl	%edx, %edx
+	jmp	.LBB{3968}
+.LBB{3969}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	jbe	.LBB{3970}
+	jmp	.LBB{30}
+.LBB{3971}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3972}
+	cmpq	%rdi, %rax
+	jae	.LBB{3972}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3973}
+.LBB{3974}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3972}
+.LBB{3973}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3974}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3974}
+	jmp	.LBB{5}
+.LBB{3975}:
+	cmpl	$16, %r8d
+	jb	.LBB{3976}
+	jmp	.LBB{38}
+.LBB{3977}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435456, %eax
+	jb	.LBB{3978}
+	jmp	.LBB{2738}
+.LBB{3979}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3980}
+	cmpq	%r9, %r8
+	jae	.LBB{3980}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3981}
+.LBB{3982}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3980}
+.LBB{3981}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3982}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3982}
+	jmp	.LBB{5}
+.LBB{3983}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	jbe	.LBB{3814}
+	jmp	.LBB{30}
+.LBB{3984}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	jbe	.LBB{3985}
+	jmp	.LBB{30}
+.LBB{3986}:
+	cmpl	$16, %r8d
+	jb	.LBB{3987}
+	jmp	.LBB{38}
+.LBB{3988}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{643}
+	cmpl	$15, %r8d
+	jbe	.LBB{3989}
+	jmp	.LBB{30}
+.LBB{3972}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3990}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3990}
+	jmp	.LBB{4}
+.LBB{3967}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3991}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3968}
+.LBB{3980}:
+	movq	152(%rsp), %rdi
+.Ltmp{3992}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3993}:
+	jmp	.LBB{4}
+.LBB{3961}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3965}
+	jmp	.LBB{226}
+.LBB{3964}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{3965}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2832}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2818}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2781}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{3994}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3995}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	jbe	.LBB{3996}
+	jmp	.LBB{592}
+.LBB{3997}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3998}
+.Ltmp{3999}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4000}:
+	jmp	.LBB{3998}
+.LBB{4001}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{4002}
+.Ltmp{4003}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4004}:
+	jmp	.LBB{4002}
+.LBB{4005}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4006}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4007}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4008}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4009}
+	cmpq	$-1, %rcx
+	je	.LBB{4010}
+.LBB{4009}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4011}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4012}
+	jmp	.LBB{137}
+.LBB{4013}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4014}
+	xorl	%edx, %edx
+	jmp	.LBB{4015}
+.LBB{4016}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4017}
+	cmpq	%rdi, %rax
+	jae	.LBB{4017}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4018}
+.LBB{4019}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4017}
+.LBB{4018}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4019}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4019}
+	jmp	.LBB{5}
+.LBB{4020}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4021}
+	cmpq	%r9, %r8
+	jae	.LBB{4021}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4022}
+.LBB{4023}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4021}
+.LBB{4022}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4023}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4023}
+	jmp	.LBB{5}
+.LBB{4017}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4024}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4024}
+	jmp	.LBB{4}
+.LBB{4014}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4025}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4015}
+.LBB{4021}:
+	movq	152(%rsp), %rdi
+.Ltmp{4026}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4027}:
+	jmp	.LBB{4}
+.LBB{4008}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4012}
+	jmp	.LBB{137}
+.LBB{4011}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{4012}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1984}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1972}:
+	cmpl	$16, %r8d
+	jae	.LBB{565}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{1949}
+	jmp	.LBB{5}
+.LBB{2042}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2030}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2008}
+	jmp	.LBB{5}
+.LBB{2100}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2088}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2066}
+	jmp	.LBB{5}
+.LBB{2888}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2874}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2838}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4028}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4029}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4030}
+	jmp	.LBB{4}
+.LBB{4031}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4032}
+	jmp	.LBB{450}
+.LBB{4033}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4030}
+.LBB{4034}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	jmp	.LBB{4035}
+.LBB{4036}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4030}
+.LBB{4037}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4030}
+	jmp	.LBB{4}
+.LBB{4038}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{448}
+	movl	%r15d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4039}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4039}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4040}
+	jmp	.LBB{450}
+.LBB{4041}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4030}
+	jmp	.LBB{4042}
+.LBB{4043}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	jbe	.LBB{2355}
+	jmp	.LBB{88}
+.LBB{4044}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r15d
+	movzbl	%r13b, %eax
+	orl	%r15d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4045}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2353}
+	jmp	.LBB{134}
+.LBB{4046}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2362}
+	jmp	.LBB{134}
+.LBB{4047}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r15b, %edx
+	jmp	.LBB{4048}
+.LBB{4049}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	jbe	.LBB{4050}
+	jmp	.LBB{88}
+.LBB{4051}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4052}
+	jmp	.LBB{134}
+.LBB{4053}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	jbe	.LBB{4054}
+	jmp	.LBB{88}
+.LBB{4055}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{656}
+	cmpl	$15, %r8d
+	jbe	.LBB{2389}
+	jmp	.LBB{88}
+.LBB{4056}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4057}
+	cmpq	%r9, %r8
+	jae	.LBB{4057}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4058}
+.LBB{4059}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4057}
+.LBB{4058}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4059}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4059}
+	jmp	.LBB{5}
+.LBB{4060}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{482}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{483}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{484}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4061}
+	cmpq	%rdi, %rax
+	jae	.LBB{4061}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4062}
+.LBB{4063}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4061}
+.LBB{4062}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4063}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4063}
+	jmp	.LBB{5}
+.LBB{4064}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3998}
+.Ltmp{4065}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4066}:
+	jmp	.LBB{3998}
+.LBB{4067}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{592}
+.LBB{3996}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2294}
+	jmp	.LBB{137}
+.LBB{4068}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4069}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4070}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4071}
+	cmpq	$-1, %rcx
+	je	.LBB{4072}
+.LBB{4071}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4073}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4074}
+	jmp	.LBB{137}
+.LBB{4075}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{4002}
+.Ltmp{4076}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4077}:
+.LBB{4002}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{4030}:
+	movzbl	%r13b, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4078}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4079}
+	xorl	%edx, %edx
+	jmp	.LBB{4080}
+.LBB{4081}:
+	cmpl	$16, %r8d
+	jae	.LBB{88}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4061}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4082}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4082}
+	jmp	.LBB{4}
+.LBB{4079}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4083}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4080}
+.LBB{4057}:
+	movq	152(%rsp), %rdi
+.Ltmp{4084}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4085}:
+	jmp	.LBB{4}
+.LBB{4070}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4074}
+	jmp	.LBB{137}
+.LBB{4073}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{4074}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2159}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2147}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2124}
+	jmp	.LBB{5}
+.LBB{2217}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2205}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	jbe	.LBB{2183}
+	jmp	.LBB{5}
+.LBB{2945}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2931}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2894}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4086}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4087}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4088}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4089}
+	cmpq	$-1, %rcx
+	je	.LBB{4090}
+.LBB{4089}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4091}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4092}
+	jmp	.LBB{621}
+.LBB{4093}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4094}
+	cmpq	%r9, %r8
+	jae	.LBB{4094}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4095}
+.LBB{4096}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4094}
+.LBB{4095}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4096}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4096}
+	jmp	.LBB{5}
+.LBB{4097}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4098}
+	jmp	.LBB{861}
+.LBB{4099}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4100}
+	cmpq	%rdi, %rax
+	jae	.LBB{4100}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4101}
+.LBB{4102}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4100}
+.LBB{4101}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4102}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4102}
+	jmp	.LBB{5}
+.LBB{4103}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2472}
+.Ltmp{4104}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4105}:
+	jmp	.LBB{2472}
+.LBB{4106}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4107}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2464}
+.Ltmp{4108}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4109}:
+	jmp	.LBB{2464}
+.LBB{4110}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4111}
+	xorl	%edx, %edx
+	jmp	.LBB{4112}
+.LBB{4113}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4114}
+	jmp	.LBB{620}
+.LBB{4115}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4100}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4116}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4116}
+	jmp	.LBB{4}
+.LBB{4111}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4117}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4112}
+.LBB{4094}:
+	movq	152(%rsp), %rdi
+.Ltmp{4118}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4119}:
+	jmp	.LBB{4}
+.LBB{4088}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4092}
+	jmp	.LBB{621}
+.LBB{4091}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{4092}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3001}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2987}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{2951}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebp
+	shrl	$16, %ebp
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4120}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4121}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4122}
+	jmp	.LBB{4}
+.LBB{4123}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4122}
+.LBB{4124}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4125}
+	cmpq	%r9, %r8
+	jae	.LBB{4125}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4126}
+.LBB{4127}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4125}
+.LBB{4126}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4127}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4127}
+	jmp	.LBB{5}
+.LBB{4128}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r13d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4129}
+	jmp	.LBB{877}
+.LBB{4130}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4131}
+.LBB{4132}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%bpl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4133}
+	cmpq	%rdi, %rax
+	jae	.LBB{4133}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4134}
+.LBB{4135}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4133}
+.LBB{4134}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4135}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4135}
+	jmp	.LBB{5}
+.LBB{4136}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4137}
+.Ltmp{4138}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4139}:
+	jmp	.LBB{4137}
+.LBB{4140}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4141}
+	jmp	.LBB{620}
+.LBB{4142}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	jbe	.LBB{4143}
+	jmp	.LBB{948}
+.LBB{4144}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{4145}
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+	movq	16(%rsp,%r13,8), %rax
+	jmp	.LBB{4146}
+.LBB{4147}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4122}
+	jmp	.LBB{4131}
+.LBB{4148}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %r13d
+	movzbl	%bpl, %eax
+	orl	%r13d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4149}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4150}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4122}
+.LBB{4151}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4152}
+	jmp	.LBB{620}
+.LBB{4153}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4154}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4155}
+	cmpq	$-1, %rcx
+	je	.LBB{4156}
+.LBB{4155}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4157}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4158}
+	jmp	.LBB{621}
+.LBB{4159}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{4160}
+.Ltmp{4161}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4162}:
+.LBB{4160}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+	jmp	.LBB{4122}
+.LBB{4163}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4164}
+	xorl	%edx, %edx
+	jmp	.LBB{4165}
+.LBB{4166}:
+	movzbl	%bpl, %edi
+	cmpb	$15, %bpl
+	ja	.LBB{42}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	cmpl	$15, %r8d
+	jbe	.LBB{4167}
+	jmp	.LBB{621}
+.LBB{4168}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	ja	.LBB{948}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rdx, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	andq	%r12, %rcx
+	shrq	$2, %rax
+	andq	%r12, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	imulq	%rbx, %rcx
+	jmp	.LBB{4169}
+.LBB{4170}:
+	movzbl	%bpl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r13d, %ecx
+	jmp	.LBB{4171}
+.LBB{4172}:
+	cmpl	$16, %r8d
+	jae	.LBB{948}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4173}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{4122}:
+	movzbl	%bpl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %r14
+	jmp	.LBB{4}
+.LBB{4174}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	jbe	.LBB{4175}
+	jmp	.LBB{948}
+.LBB{4176}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%r13b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4131}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4177}:
+	movzbl	%bpl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{965}
+	cmpl	$15, %r8d
+	jbe	.LBB{4178}
+	jmp	.LBB{948}
+.LBB{4133}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4179}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4179}
+	jmp	.LBB{4}
+.LBB{4164}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4180}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4165}
+.LBB{4125}:
+	movq	152(%rsp), %rdi
+.Ltmp{4181}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4182}:
+	jmp	.LBB{4}
+.LBB{4154}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4158}
+	jmp	.LBB{621}
+.LBB{4157}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{4158}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3057}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3043}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3007}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4183}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4184}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4185}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4186}
+	cmpq	$-1, %rcx
+	je	.LBB{4187}
+.LBB{4186}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4188}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4189}
+	jmp	.LBB{621}
+.LBB{4190}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4098}
+	jmp	.LBB{877}
+.LBB{4191}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2472}
+.Ltmp{4192}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4193}:
+	jmp	.LBB{2472}
+.LBB{4194}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4195}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4195}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4196}
+	jmp	.LBB{877}
+.LBB{4197}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2464}
+.Ltmp{4198}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4199}:
+	jmp	.LBB{2464}
+.LBB{4200}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4201}:
+	cmpl	$16, %r8d
+	jae	.LBB{174}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4202}:
+	cmpl	$16, %r8d
+	jb	.LBB{2432}
+	jmp	.LBB{174}
+.LBB{4203}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4204}
+	xorl	%edx, %edx
+	jmp	.LBB{4205}
+.LBB{4206}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	jbe	.LBB{2441}
+	jmp	.LBB{174}
+.LBB{4207}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4208}
+	cmpq	%rdi, %rax
+	jae	.LBB{4208}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4209}
+.LBB{4210}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4208}
+.LBB{4209}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4210}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4210}
+	jmp	.LBB{5}
+.LBB{4211}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4212}
+	cmpq	%r9, %r8
+	jae	.LBB{4212}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4213}
+.LBB{4214}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4212}
+.LBB{4213}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4214}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4214}
+	jmp	.LBB{5}
+.LBB{4215}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{173}
+	cmpl	$15, %r8d
+	jbe	.LBB{2470}
+	jmp	.LBB{174}
+.LBB{4216}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	jbe	.LBB{2484}
+	jmp	.LBB{174}
+.LBB{4217}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	jbe	.LBB{2486}
+	jmp	.LBB{174}
+.LBB{4208}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4218}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4218}
+	jmp	.LBB{4}
+.LBB{4204}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4219}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4205}
+.LBB{4212}:
+	movq	152(%rsp), %rdi
+.Ltmp{4220}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4221}:
+	jmp	.LBB{4}
+.LBB{4185}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4189}
+	jmp	.LBB{621}
+.LBB{4188}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{4189}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3113}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3099}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3063}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r13d
+	shrl	$16, %r13d
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4222}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4223}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{2430}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4224}
+	jmp	.LBB{4}
+.LBB{4225}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4226}
+.LBB{4227}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%r13d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %r13b
+	movzbl	%r13b, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{4226}
+.LBB{4228}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4229}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{2478}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4224}
+.LBB{4230}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{2447}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4224}
+	jmp	.LBB{4}
+.LBB{4231}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{2461}:
+	shll	$8, %r12d
+	movzbl	%r13b, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4232}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2128}
+	cmpl	$15, %r8d
+	jbe	.LBB{2357}
+	jmp	.LBB{621}
+.LBB{4233}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{2438}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4224}
+.LBB{4234}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+.LBB{4141}:
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{2476}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4235}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+.LBB{4152}:
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{2449}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4236}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+.LBB{2436}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4237}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4238}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4238}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+.LBB{4196}:
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{4239}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+.LBB{2432}:
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4224}
+.LBB{4226}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4240}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+.LBB{2441}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4241}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+.LBB{2470}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4242}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+.LBB{2484}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4243}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+.LBB{4114}:
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{2434}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4244}:
+	movzbl	%r13b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{63}
+	cmpl	$15, %r8d
+	ja	.LBB{15}
+.LBB{2486}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4245}:
+	movzbl	%r13b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{859}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{861}
+.LBB{4098}:
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4246}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2472}
+.Ltmp{4247}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4248}:
+.LBB{2472}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4249}:
+	movq	208(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rbx
+	jne	.LBB{2464}
+.Ltmp{4250}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4251}:
+.LBB{2464}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 208(%rsp)
+.LBB{4224}:
+	movzbl	%r13b, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4252}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4253}:
+	cmpl	$16, %r8d
+	jae	.LBB{15}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4254}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4255}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4256}
+	cmpq	$-1, %rcx
+	je	.LBB{4257}
+.LBB{4256}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4258}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4259}
+	jmp	.LBB{621}
+.LBB{4260}:
+	movzbl	%r13b, %edi
+	cmpb	$15, %r13b
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{620}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4261}
+	xorl	%edx, %edx
+	jmp	.LBB{4262}
+.LBB{4263}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4264}
+	cmpq	%rdi, %rax
+	jae	.LBB{4264}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4265}
+.LBB{4266}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4264}
+.LBB{4265}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4266}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4266}
+	jmp	.LBB{5}
+.LBB{4267}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{399}
+	movzbl	%r13b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{866}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{867}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4268}
+	cmpq	%r9, %r8
+	jae	.LBB{4268}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4269}
+.LBB{4270}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4268}
+.LBB{4269}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4270}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4270}
+	jmp	.LBB{5}
+.LBB{4264}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4271}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4271}
+	jmp	.LBB{4}
+.LBB{4261}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4272}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4262}
+.LBB{4268}:
+	movq	152(%rsp), %rdi
+.Ltmp{4273}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4274}:
+	jmp	.LBB{4}
+.LBB{4255}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4259}
+	jmp	.LBB{621}
+.LBB{4258}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{621}
+.LBB{4259}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3170}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3159}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3119}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4275}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4276}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4277}
+	jmp	.LBB{4}
+.LBB{4278}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4277}
+.LBB{4279}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4280}
+	cmpq	%r9, %r8
+	jae	.LBB{4280}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4281}
+.LBB{4282}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4280}
+.LBB{4281}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4282}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4282}
+	jmp	.LBB{5}
+.LBB{4283}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+.LBB{4129}:
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4284}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4285}
+.LBB{4286}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{145}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{883}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{884}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4287}
+	cmpq	%rdi, %rax
+	jae	.LBB{4287}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4288}
+.LBB{4289}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4287}
+.LBB{4288}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4289}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4289}
+	jmp	.LBB{5}
+.LBB{4290}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4137}
+.Ltmp{4291}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4292}:
+	jmp	.LBB{4137}
+.LBB{4293}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	jbe	.LBB{4294}
+	jmp	.LBB{44}
+.LBB{4295}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{144}
+	cmpl	$15, %r8d
+	jbe	.LBB{4143}
+	jmp	.LBB{145}
+.LBB{4296}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{4297}
+	cmpl	$15, %r8d
+	jbe	.LBB{3850}
+	jmp	.LBB{44}
+.LBB{4298}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4277}
+	jmp	.LBB{4285}
+.LBB{4299}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4300}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4301}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4277}
+.LBB{4302}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4303}
+	jmp	.LBB{43}
+.LBB{4304}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4305}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4306}
+	cmpq	$-1, %rcx
+	je	.LBB{4307}
+.LBB{4306}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4308}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4309}
+	jmp	.LBB{44}
+.LBB{4310}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4311}
+.Ltmp{4312}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4313}:
+.LBB{4311}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{4277}
+.LBB{4314}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4315}
+	xorl	%edx, %edx
+	jmp	.LBB{4316}
+.LBB{4317}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4318}
+	jmp	.LBB{43}
+.LBB{4319}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4320}
+	jmp	.LBB{144}
+.LBB{4321}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{875}
+	movl	%ebx, %ecx
+.LBB{4171}:
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4322}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4322}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{877}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{4323}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4324}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{4277}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{4}
+.LBB{4325}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4326}
+	jmp	.LBB{144}
+.LBB{4327}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4285}:
+	addq	$2, %r13
+	movq	%r13, %r14
+	jmp	.LBB{4}
+.LBB{4328}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4329}
+	jmp	.LBB{144}
+.LBB{4287}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4330}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4330}
+	jmp	.LBB{4}
+.LBB{4315}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4331}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4316}
+.LBB{4280}:
+	movq	152(%rsp), %rdi
+.Ltmp{4332}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4333}:
+	jmp	.LBB{4}
+.LBB{4305}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4309}
+	jmp	.LBB{44}
+.LBB{4308}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4309}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3227}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3213}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3176}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4334}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4335}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4336}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4337}
+	cmpq	$-1, %rcx
+	je	.LBB{4338}
+.LBB{4337}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4339}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4340}
+	jmp	.LBB{56}
+.LBB{4341}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{4342}
+	jmp	.LBB{1081}
+.LBB{4343}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	jbe	.LBB{2290}
+	jmp	.LBB{584}
+.LBB{4344}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4345}
+	xorl	%edx, %edx
+	jmp	.LBB{4346}
+.LBB{4347}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4348}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4349}
+	cmpq	%r9, %r8
+	jae	.LBB{4349}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4350}
+.LBB{4351}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4349}
+.LBB{4350}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4351}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4351}
+	jmp	.LBB{5}
+.LBB{4352}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4353}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4354}
+.Ltmp{4355}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4356}:
+	jmp	.LBB{4354}
+.LBB{4357}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2283}
+.Ltmp{4358}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4359}:
+	jmp	.LBB{2283}
+.LBB{4360}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4361}
+	cmpq	%rdi, %rax
+	jae	.LBB{4361}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4362}
+.LBB{4363}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4361}
+.LBB{4362}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4363}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4363}
+	jmp	.LBB{5}
+.LBB{4361}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4364}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4364}
+	jmp	.LBB{4}
+.LBB{4345}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4365}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4346}
+.LBB{4349}:
+	movq	152(%rsp), %rdi
+.Ltmp{4366}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4367}:
+	jmp	.LBB{4}
+.LBB{4336}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4340}
+	jmp	.LBB{56}
+.LBB{4339}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{4340}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3283}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3269}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3233}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4368}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4369}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{4370}
+	cmpl	$15, %r8d
+	jbe	.LBB{3850}
+	jmp	.LBB{565}
+.LBB{4371}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4372}
+	cmpq	%r9, %r8
+	jae	.LBB{4372}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4373}
+.LBB{4374}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4372}
+.LBB{4373}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4374}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4374}
+	jmp	.LBB{5}
+.LBB{4375}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4376}
+	cmpq	%rdi, %rax
+	jae	.LBB{4376}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4377}
+.LBB{4378}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4376}
+.LBB{4377}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4378}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4378}
+	jmp	.LBB{5}
+.LBB{4379}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2283}
+.Ltmp{4380}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4381}:
+	jmp	.LBB{2283}
+.LBB{4382}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{564}
+.LBB{2287}:
+	cmpl	$15, %r8d
+	jbe	.LBB{4383}
+	jmp	.LBB{565}
+.LBB{4384}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4385}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4386}
+	jmp	.LBB{55}
+.LBB{4387}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4388}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4389}
+	cmpq	$-1, %rcx
+	je	.LBB{4390}
+.LBB{4389}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4391}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4392}
+	jmp	.LBB{56}
+.LBB{4393}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4354}
+.Ltmp{4394}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4395}:
+	jmp	.LBB{4354}
+.LBB{4396}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4397}
+	xorl	%edx, %edx
+	jmp	.LBB{4398}
+.LBB{4399}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4376}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4400}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4400}
+	jmp	.LBB{4}
+.LBB{4397}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4401}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4398}
+.LBB{4372}:
+	movq	152(%rsp), %rdi
+.Ltmp{4402}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4403}:
+	jmp	.LBB{4}
+.LBB{4388}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4392}
+	jmp	.LBB{56}
+.LBB{4391}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{4392}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3339}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3325}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3289}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4404}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4405}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4406}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4407}
+	cmpq	$-1, %rcx
+	je	.LBB{4408}
+.LBB{4407}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4409}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4410}
+	jmp	.LBB{56}
+.LBB{4411}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4412}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4413}
+.LBB{4414}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{2318}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4415}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+.LBB{2313}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4416}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+.LBB{2276}:
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2296}
+.LBB{4417}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+.LBB{2328}:
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{2296}
+.LBB{4418}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4413}
+	jmp	.LBB{4}
+.LBB{4419}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4413}
+.LBB{2296}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4420}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4413}
+.LBB{4421}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4422}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4422}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{4423}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2320}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4424}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2326}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4425}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2330}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4426}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+.LBB{4342}:
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4427}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2290}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4428}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4429}
+	xorl	%edx, %edx
+	jmp	.LBB{4430}
+.LBB{4431}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4432}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4433}
+	cmpq	%r9, %r8
+	jae	.LBB{4433}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4434}
+.LBB{4435}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4433}
+.LBB{4434}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4435}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4435}
+	jmp	.LBB{5}
+.LBB{4436}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4437}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4354}
+.Ltmp{4438}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4439}:
+.LBB{4354}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{4413}
+.LBB{4440}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2283}
+.Ltmp{4441}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4442}:
+.LBB{2283}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4443}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4444}
+	cmpq	%rdi, %rax
+	jae	.LBB{4444}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4445}
+.LBB{4446}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4444}
+.LBB{4445}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4446}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4446}
+	jmp	.LBB{5}
+.LBB{4444}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4447}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4447}
+	jmp	.LBB{4}
+.LBB{4429}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4448}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4430}
+.LBB{4433}:
+	movq	152(%rsp), %rdi
+.Ltmp{4449}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4450}:
+	jmp	.LBB{4}
+.LBB{4406}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4410}
+	jmp	.LBB{56}
+.LBB{4409}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{4410}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3395}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3381}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3345}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4451}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4452}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4453}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	je	.LBB{4}
+.LBB{4413}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{4}
+.LBB{4454}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+.LBB{4386}:
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{2301}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4455}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{4383}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4456}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4457}
+	cmpq	%r9, %r8
+	jae	.LBB{4457}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4458}
+.LBB{4459}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4457}
+.LBB{4458}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4459}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4459}
+	jmp	.LBB{5}
+.LBB{4460}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4461}
+	cmpq	%rdi, %rax
+	jae	.LBB{4461}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4462}
+.LBB{4463}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4461}
+.LBB{4462}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4463}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4463}
+	jmp	.LBB{5}
+.LBB{4464}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3998}
+.Ltmp{4465}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4466}:
+	jmp	.LBB{3998}
+.LBB{4467}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4468}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4469}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4470}
+.Ltmp{4471}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4472}:
+	jmp	.LBB{4470}
+.LBB{4473}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4474}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4475}
+	cmpq	$-1, %rcx
+	je	.LBB{4476}
+.LBB{4475}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4477}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4478}
+	jmp	.LBB{56}
+.LBB{4479}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	cmpl	$15, %r8d
+	jbe	.LBB{4480}
+	jmp	.LBB{56}
+.LBB{4481}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{55}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4482}
+	xorl	%edx, %edx
+	jmp	.LBB{4483}
+.LBB{4461}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4484}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4484}
+	jmp	.LBB{4}
+.LBB{4482}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4485}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4483}
+.LBB{4457}:
+	movq	152(%rsp), %rdi
+.Ltmp{4486}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4487}:
+	jmp	.LBB{4}
+.LBB{4474}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4478}
+	jmp	.LBB{56}
+.LBB{4477}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{4478}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3451}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3437}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3401}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4488}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4489}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4490}
+	jmp	.LBB{4}
+.LBB{4491}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4492}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4493}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{4494}
+	cmpl	$15, %r8d
+	ja	.LBB{56}
+.LBB{3850}:
+	movq	16(%rsp,%rbx,8), %rax
+	jmp	.LBB{4146}
+.LBB{4495}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4490}
+.LBB{4496}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+.LBB{4032}:
+	movq	152(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4497}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+.LBB{4035}:
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4042}
+.LBB{4498}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2355}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4499}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4490}
+	jmp	.LBB{4042}
+.LBB{4500}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4490}
+	jmp	.LBB{4}
+.LBB{4501}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4490}
+.LBB{4502}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{1079}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	176(%rsp), %rdx
+	jae	.LBB{4503}
+	movq	168(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{4503}:
+	cmpq	160(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1081}
+.LBB{4040}:
+	movq	152(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{4504}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{4050}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4505}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+.LBB{4048}:
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4042}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4506}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{4054}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4507}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{583}
+	cmpl	$15, %r8d
+	ja	.LBB{584}
+.LBB{2389}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4508}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+.LBB{4052}:
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{4480}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4509}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4510}
+	xorl	%edx, %edx
+	jmp	.LBB{4511}
+.LBB{4512}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4513}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+.LBB{2362}:
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4514}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4515}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4516}
+	cmpq	$-1, %rcx
+	je	.LBB{4517}
+.LBB{4516}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4518}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4519}
+	jmp	.LBB{137}
+.LBB{4520}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4521}
+	cmpq	%r9, %r8
+	jae	.LBB{4521}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4522}
+.LBB{4523}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4521}
+.LBB{4522}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4523}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4523}
+	jmp	.LBB{5}
+.LBB{4524}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4525}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4526}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4470}
+.Ltmp{4527}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4528}:
+.LBB{4470}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+.LBB{4490}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{4}
+.LBB{4529}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3998}
+.Ltmp{4530}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4531}:
+.LBB{3998}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4532}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{42}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{134}
+.LBB{2353}:
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4533}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{584}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1089}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1090}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4534}
+	cmpq	%rdi, %rax
+	jae	.LBB{4534}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4535}
+.LBB{4536}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4534}
+.LBB{4535}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4536}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4536}
+	jmp	.LBB{5}
+.LBB{4534}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4537}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4537}
+	jmp	.LBB{4}
+.LBB{4510}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4538}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4511}
+.LBB{4521}:
+	movq	152(%rsp), %rdi
+.Ltmp{4539}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4540}:
+	jmp	.LBB{4}
+.LBB{4515}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4519}
+	jmp	.LBB{137}
+.LBB{4518}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{137}
+.LBB{4519}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3563}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3549}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3513}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4541}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4542}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4543}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4544}
+	cmpq	$-1, %rcx
+	je	.LBB{4545}
+.LBB{4544}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4546}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4547}
+	jmp	.LBB{44}
+.LBB{4548}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4549}
+	cmpq	%r9, %r8
+	jae	.LBB{4549}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4550}
+.LBB{4551}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4549}
+.LBB{4550}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4551}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4551}
+	jmp	.LBB{5}
+.LBB{4552}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4553}
+	cmpq	%rdi, %rax
+	jae	.LBB{4553}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4554}
+.LBB{4555}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4553}
+.LBB{4554}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4555}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4555}
+	jmp	.LBB{5}
+.LBB{4556}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4137}
+.Ltmp{4557}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4558}:
+	jmp	.LBB{4137}
+.LBB{4559}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4560}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4561}
+.Ltmp{4562}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4563}:
+	jmp	.LBB{4561}
+.LBB{4564}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4565}
+	xorl	%edx, %edx
+	jmp	.LBB{4566}
+.LBB{4567}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4553}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4568}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4568}
+	jmp	.LBB{4}
+.LBB{4565}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4569}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4566}
+.LBB{4549}:
+	movq	152(%rsp), %rdi
+.Ltmp{4570}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4571}:
+	jmp	.LBB{4}
+.LBB{4543}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4547}
+	jmp	.LBB{44}
+.LBB{4546}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4547}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3676}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3662}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3626}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4572}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4573}:
+	cmpl	$16, %r8d
+	jb	.LBB{4574}
+	jmp	.LBB{30}
+.LBB{4575}:
+	cmpl	$16, %r8d
+	jb	.LBB{4576}
+	jmp	.LBB{30}
+.LBB{4577}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4578}
+	cmpq	%r9, %r8
+	jae	.LBB{4578}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4579}
+.LBB{4580}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4578}
+.LBB{4579}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4580}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4580}
+	jmp	.LBB{5}
+.LBB{4581}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jb	.LBB{4582}
+	jmp	.LBB{5}
+.LBB{4583}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4584}
+.LBB{4585}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4586}
+	cmpq	%rdi, %rax
+	jae	.LBB{4586}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4587}
+.LBB{4588}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4586}
+.LBB{4587}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4588}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4588}
+	jmp	.LBB{5}
+.LBB{4589}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4590}
+.Ltmp{4591}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4592}:
+	jmp	.LBB{4590}
+.LBB{4593}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	jbe	.LBB{4594}
+	jmp	.LBB{1493}
+.LBB{4595}:
+	cmpl	$16, %r8d
+	jb	.LBB{4596}
+	jmp	.LBB{30}
+.LBB{4597}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4598}
+	jmp	.LBB{4584}
+.LBB{4599}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4600}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4601}:
+	cmpl	$16, %r8d
+	jb	.LBB{4602}
+	jmp	.LBB{30}
+.LBB{4603}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4604}
+.Ltmp{4605}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4606}:
+	jmp	.LBB{4604}
+.LBB{4607}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4608}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4609}
+	cmpq	$-1, %rcx
+	je	.LBB{4610}
+.LBB{4609}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4611}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4612}
+	jmp	.LBB{44}
+.LBB{4613}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	jbe	.LBB{4614}
+	jmp	.LBB{44}
+.LBB{4615}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4616}
+	jmp	.LBB{1143}
+.LBB{4617}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r15d, %eax
+	jmp	.LBB{4618}
+.LBB{4619}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4620}
+	xorl	%edx, %edx
+	jmp	.LBB{4621}
+.LBB{4622}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4623}
+	jmp	.LBB{1143}
+.LBB{4624}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4584}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4625}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{4626}
+	jmp	.LBB{1143}
+.LBB{4586}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4627}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4627}
+	jmp	.LBB{4}
+.LBB{4620}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4628}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4621}
+.LBB{4578}:
+	movq	152(%rsp), %rdi
+.Ltmp{4629}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4630}:
+	jmp	.LBB{4}
+.LBB{4608}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4612}
+	jmp	.LBB{44}
+.LBB{4611}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4612}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3507}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3493}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3457}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4631}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4632}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{4574}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4633}
+	jmp	.LBB{4}
+.LBB{4634}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4635}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{38}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4636}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{940}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{2294}:
+	movq	16(%rsp,%r15,8), %rax
+	jmp	.LBB{4146}
+.LBB{4637}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4294}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4638}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+.LBB{4303}:
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4639}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{4576}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4633}
+.LBB{4640}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1070}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4641}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+.LBB{4318}:
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4167}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4642}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4643}
+.LBB{4644}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+.LBB{4143}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4645}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{4596}:
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4646}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4633}
+	jmp	.LBB{4643}
+.LBB{4647}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4633}
+	jmp	.LBB{4}
+.LBB{4648}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{4602}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4633}
+.LBB{4649}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4643}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4650}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{1070}
+	movl	%r15d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{4651}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{4651}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{4652}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+.LBB{4320}:
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+.LBB{4169}:
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4653}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+.LBB{4326}:
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+.LBB{4175}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4654}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1143}
+.LBB{4329}:
+	cmpl	$15, %r8d
+	ja	.LBB{145}
+.LBB{4178}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4655}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4656}
+	xorl	%edx, %edx
+	jmp	.LBB{4657}
+.LBB{4658}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4659}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{43}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4660}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4661}
+	cmpq	$-1, %rcx
+	je	.LBB{4662}
+.LBB{4661}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4663}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4664}
+	jmp	.LBB{44}
+.LBB{4665}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1070}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1071}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4666}
+	cmpq	%r9, %r8
+	jae	.LBB{4666}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4667}
+.LBB{4668}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4666}
+.LBB{4667}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4668}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4668}
+	jmp	.LBB{5}
+.LBB{4669}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4670}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4561}
+.Ltmp{4671}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4672}:
+.LBB{4561}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+.LBB{4633}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4673}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4137}
+.Ltmp{4674}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4675}:
+.LBB{4137}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4676}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1159}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1070}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4677}
+	cmpq	%rdi, %rax
+	jae	.LBB{4677}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4678}
+.LBB{4679}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4677}
+.LBB{4678}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4679}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4679}
+	jmp	.LBB{5}
+.LBB{4677}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4680}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4680}
+	jmp	.LBB{4}
+.LBB{4656}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4681}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4657}
+.LBB{4666}:
+	movq	152(%rsp), %rdi
+.Ltmp{4682}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4683}:
+	jmp	.LBB{4}
+.LBB{4660}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4664}
+	jmp	.LBB{44}
+.LBB{4663}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{44}
+.LBB{4664}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3620}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3606}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3569}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4684}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4685}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	jbe	.LBB{4686}
+	jmp	.LBB{1524}
+.LBB{4687}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4688}
+	cmpq	%r9, %r8
+	jae	.LBB{4688}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4689}
+.LBB{4690}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4688}
+.LBB{4689}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4690}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4690}
+	jmp	.LBB{5}
+.LBB{4691}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4692}
+	cmpq	%rdi, %rax
+	jae	.LBB{4692}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4693}
+.LBB{4694}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4692}
+.LBB{4693}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4694}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4694}
+	jmp	.LBB{5}
+.LBB{4695}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4590}
+.Ltmp{4696}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4697}:
+	jmp	.LBB{4590}
+.LBB{4698}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4699}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4700}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4701}
+	cmpq	$-1, %rcx
+	je	.LBB{4702}
+.LBB{4701}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4703}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4704}
+	jmp	.LBB{1473}
+.LBB{4705}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{4706}
+.Ltmp{4707}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4708}:
+	jmp	.LBB{4706}
+.LBB{4709}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4710}
+	xorl	%edx, %edx
+	jmp	.LBB{4711}
+.LBB{4712}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4692}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4713}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4713}
+	jmp	.LBB{4}
+.LBB{4710}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4714}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4711}
+.LBB{4688}:
+	movq	152(%rsp), %rdi
+.Ltmp{4715}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4716}:
+	jmp	.LBB{4}
+.LBB{4700}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4704}
+	jmp	.LBB{1473}
+.LBB{4703}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{4704}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3732}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3718}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3682}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4717}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4718}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	jbe	.LBB{4686}
+	jmp	.LBB{2738}
+.LBB{4719}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4720}
+	xorl	%edx, %edx
+	jmp	.LBB{4721}
+.LBB{4722}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4723}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4724}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4725}
+	cmpq	$-1, %rcx
+	je	.LBB{4726}
+.LBB{4725}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4727}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4728}
+	jmp	.LBB{1473}
+.LBB{4729}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4730}
+	cmpq	%r9, %r8
+	jae	.LBB{4730}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4731}
+.LBB{4732}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4730}
+.LBB{4731}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4732}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4732}
+	jmp	.LBB{5}
+.LBB{4733}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4734}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{4706}
+.Ltmp{4735}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4736}:
+	jmp	.LBB{4706}
+.LBB{4737}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4590}
+.Ltmp{4738}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4739}:
+	jmp	.LBB{4590}
+.LBB{4740}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4741}
+	cmpq	%rdi, %rax
+	jae	.LBB{4741}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4742}
+.LBB{4743}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4741}
+.LBB{4742}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4743}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4743}
+	jmp	.LBB{5}
+.LBB{4741}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4744}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4744}
+	jmp	.LBB{4}
+.LBB{4720}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4745}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4721}
+.LBB{4730}:
+	movq	152(%rsp), %rdi
+.Ltmp{4746}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4747}:
+	jmp	.LBB{4}
+.LBB{4724}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4728}
+	jmp	.LBB{1473}
+.LBB{4727}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{4728}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3788}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3774}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{3738}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4748}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4749}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4750}
+	jmp	.LBB{4}
+.LBB{4751}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4752}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4750}
+.LBB{4753}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+.LBB{4582}:
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4754}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+.LBB{4755}:
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{4614}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4756}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4757}
+.LBB{4758}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4759}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+.LBB{4760}:
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4761}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{4757}
+.LBB{4762}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4750}
+	jmp	.LBB{4}
+.LBB{4763}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4750}
+.LBB{4757}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4764}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+.LBB{4765}:
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4766}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4750}
+.LBB{4767}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r12d, %eax
+.LBB{4618}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{4768}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{4768}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{4769}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4770}
+	xorl	%edx, %edx
+	jmp	.LBB{4771}
+.LBB{4772}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4773}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4774}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4775}
+	cmpq	$-1, %rcx
+	je	.LBB{4776}
+.LBB{4775}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4777}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4778}
+	jmp	.LBB{1473}
+.LBB{4779}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4780}
+	cmpq	%r9, %r8
+	jae	.LBB{4780}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4781}
+.LBB{4782}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4780}
+.LBB{4781}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4782}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4782}
+	jmp	.LBB{5}
+.LBB{4783}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4784}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{4706}
+.Ltmp{4785}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4786}:
+.LBB{4706}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{4750}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4787}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1524}
+.LBB{4686}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2357}
+	jmp	.LBB{1473}
+.LBB{4788}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4590}
+.Ltmp{4789}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4790}:
+.LBB{4590}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4791}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4792}
+	cmpq	%rdi, %rax
+	jae	.LBB{4792}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4793}
+.LBB{4794}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4792}
+.LBB{4793}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4794}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4794}
+	jmp	.LBB{5}
+.LBB{4792}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4795}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4795}
+	jmp	.LBB{4}
+.LBB{4770}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4796}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4771}
+.LBB{4780}:
+	movq	152(%rsp), %rdi
+.Ltmp{4797}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4798}:
+	jmp	.LBB{4}
+.LBB{4774}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4778}
+	jmp	.LBB{1473}
+.LBB{4777}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{4778}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3824}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3796}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3880}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3869}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3933}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3931}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2261}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2243}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2425}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2404}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3991}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3968}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4025}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4015}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2488}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2444}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2332}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2316}:
+	cmpl	$16, %r8d
+	jae	.LBB{565}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2391}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2376}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2547}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2529}:
+	cmpl	$16, %r8d
+	jae	.LBB{295}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4083}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4080}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4117}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4112}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4180}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4165}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4219}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4205}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4272}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4262}:
+	cmpl	$16, %r8d
+	jae	.LBB{621}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4365}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4346}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4331}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4316}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4448}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4430}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4538}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4511}:
+	cmpl	$16, %r8d
+	jae	.LBB{137}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4401}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4398}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4485}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4483}:
+	cmpl	$16, %r8d
+	jae	.LBB{56}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4569}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4566}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4628}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4621}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4681}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4657}:
+	cmpl	$16, %r8d
+	jae	.LBB{44}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4714}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4711}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4745}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4721}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4796}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4771}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1854}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1692}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{194}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%r12), %r15
+	movzbl	%al, %edx
+	leaq	.LJTI{4799}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4800}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4801}
+	jmp	.LBB{4802}
+.LBB{4803}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4802}
+	jmp	.LBB{4801}
+.LBB{4804}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{4805}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{4805}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4802}
+.LBB{4806}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4807}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4808}
+.LBB{4809}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4810}
+	cmpq	%r9, %r8
+	jae	.LBB{4810}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4811}
+.LBB{4812}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4810}
+.LBB{4811}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4812}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4812}
+	jmp	.LBB{5}
+.LBB{4813}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4802}
+.LBB{4814}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4815}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2569}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4816}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{4817}
+.LBB{4818}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4801}
+	jmp	.LBB{4808}
+.LBB{4819}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4820}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4821}
+.Ltmp{4822}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4823}:
+.LBB{4821}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{4801}
+.LBB{4824}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4825}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4826}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4827}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4828}
+	cmpq	$-1, %rcx
+	je	.LBB{4829}
+.LBB{4828}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4830}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4817}
+	jmp	.LBB{226}
+.LBB{4831}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4832}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4833}
+.Ltmp{4834}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4835}:
+.LBB{4833}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4802}
+.LBB{4836}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4837}
+	xorl	%edx, %edx
+	jmp	.LBB{4838}
+.LBB{4839}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4840}
+	cmpq	%rdi, %rax
+	jae	.LBB{4840}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4841}
+.LBB{4842}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4840}
+.LBB{4841}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4842}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4842}
+	jmp	.LBB{5}
+.LBB{4843}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4844}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4845}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4846}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4801}
+	jmp	.LBB{4802}
+.LBB{4847}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4802}
+.LBB{4801}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4802}
+.LBB{4848}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4849}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4808}:
+	addq	$2, %r12
+	movq	%r12, %r15
+	jmp	.LBB{4802}
+.LBB{4850}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{4840}:
+	testq	%rcx, %rcx
+	je	.LBB{4802}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4851}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4851}
+	jmp	.LBB{4802}
+.LBB{4837}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4852}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4838}
+.LBB{4810}:
+	movq	152(%rsp), %rdi
+.Ltmp{4853}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4854}:
+	jmp	.LBB{4802}
+.LBB{4827}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4817}
+	jmp	.LBB{226}
+.LBB{4830}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{4817}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4802}
+.LBB{1830}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1820}
+	jmp	.LBB{226}
+.LBB{4852}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4838}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{4802}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r15), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4855}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4856}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{3938}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4857}
+	jmp	.LBB{4}
+.LBB{4858}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4859}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+.LBB{3970}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4860}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+.LBB{3985}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4861}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+.LBB{3989}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4862}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{247}
+	cmpl	$15, %r8d
+	ja	.LBB{248}
+.LBB{3814}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4863}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4864}
+.LBB{4865}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+.LBB{3900}:
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4866}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+.LBB{3897}:
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4867}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{4864}
+.LBB{4868}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+.LBB{3891}:
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4869}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{3987}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4857}
+.LBB{4870}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{218}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{219}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4871}
+	cmpq	%r9, %r8
+	jae	.LBB{4871}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4872}
+.LBB{4873}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4871}
+.LBB{4872}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4873}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4873}
+	jmp	.LBB{5}
+.LBB{4874}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+.LBB{3944}:
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+.LBB{3889}:
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4875}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{262}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{218}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4876}
+	cmpq	%rdi, %rax
+	jae	.LBB{4876}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4877}
+.LBB{4878}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4876}
+.LBB{4877}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4878}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4878}
+	jmp	.LBB{5}
+.LBB{4879}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3816}
+.Ltmp{4880}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4881}:
+	jmp	.LBB{3816}
+.LBB{4882}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{2738}
+.LBB{3978}:
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{2357}:
+	movq	16(%rsp,%r12,8), %rax
+.LBB{4146}:
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4883}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+.LBB{3959}:
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4857}
+.LBB{4864}:
+	addq	$2, %r15
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{4884}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{3976}:
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4885}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4886}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{3942}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4857}
+.LBB{4887}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4888}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4889}
+	cmpq	$-1, %rcx
+	je	.LBB{4890}
+.LBB{4889}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4891}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4892}
+	jmp	.LBB{226}
+.LBB{4893}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{3953}
+.Ltmp{4894}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4895}:
+.LBB{3953}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{4857}
+.LBB{4896}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{225}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4897}
+	xorl	%edx, %edx
+	jmp	.LBB{4898}
+.LBB{4899}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{218}
+.LBB{3951}:
+	movl	%r12d, %eax
+.LBB{3905}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{4900}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{4900}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{4901}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4902}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{3949}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{4857}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4876}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4903}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4903}
+	jmp	.LBB{4}
+.LBB{4897}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4904}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4898}
+.LBB{4871}:
+	movq	152(%rsp), %rdi
+.Ltmp{4905}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4906}:
+	jmp	.LBB{4}
+.LBB{4888}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4892}
+	jmp	.LBB{226}
+.LBB{4891}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{4892}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4904}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4898}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1833}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{226}
+.LBB{1820}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{200}
+.LBB{1813}:
+.Ltmp{4907}:
+	movq	%r12, %rdi
+	movq	%r13, %rsi
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4908}:
+	jmp	.LBB{200}
+.LBB{2550}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1841}:
+	cmpl	$16, %r8d
+	jae	.LBB{226}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{200}:
+	movq	328(%rsp), %rax
+	movq	336(%rsp), %rdi
+	movl	(%rax,%rdi,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r14d
+	shrl	$24, %r14d
+	leaq	1(%rdi), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{4909}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4910}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4911}
+	jmp	.LBB{4912}
+.LBB{4913}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4912}
+	jmp	.LBB{4911}
+.LBB{4914}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	176(%rsp), %rcx
+	jae	.LBB{4915}
+	movq	168(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{4915}:
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4912}
+.LBB{4916}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4917}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4918}
+.LBB{4919}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r14d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4920}
+	cmpq	%r9, %r8
+	jae	.LBB{4920}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4921}
+.LBB{4922}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4920}
+.LBB{4921}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4922}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4922}
+	jmp	.LBB{5}
+.LBB{4923}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{4912}
+.LBB{4924}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4925}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{3583}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%r14,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4926}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{4927}
+.LBB{4928}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{4911}
+	jmp	.LBB{4918}
+.LBB{4929}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4930}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{4931}
+.Ltmp{4932}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4933}:
+.LBB{4931}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{4911}
+.LBB{4934}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4935}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4936}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{4937}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{4938}
+	cmpq	$-1, %rcx
+	je	.LBB{4939}
+.LBB{4938}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4940}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{4927}
+	jmp	.LBB{1473}
+.LBB{4941}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4942}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{4943}
+.Ltmp{4944}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4945}:
+.LBB{4943}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4912}
+.LBB{4946}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{4947}
+	xorl	%edx, %edx
+	jmp	.LBB{4948}
+.LBB{4949}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r14d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4950}
+	cmpq	%rdi, %rax
+	jae	.LBB{4950}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4951}
+.LBB{4952}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4950}
+.LBB{4951}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4952}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4952}
+	jmp	.LBB{5}
+.LBB{4953}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4954}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4955}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{28}
+	movl	%r14d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	160(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+	movq	152(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4956}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4911}
+	jmp	.LBB{4912}
+.LBB{4957}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4912}
+.LBB{4911}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r12
+	jmp	.LBB{4912}
+.LBB{4958}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4959}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4918}:
+	addq	$2, %rdi
+	movq	%rdi, %r12
+	jmp	.LBB{4912}
+.LBB{4960}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1492}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4950}:
+	testq	%rcx, %rcx
+	je	.LBB{4912}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{4961}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{4961}
+	jmp	.LBB{4912}
+.LBB{4947}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{4962}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{4948}
+.LBB{4920}:
+	movq	152(%rsp), %rdi
+.Ltmp{4963}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{4964}:
+	jmp	.LBB{4912}
+.LBB{4937}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{4927}
+	jmp	.LBB{1473}
+.LBB{4940}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{4927}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4912}
+.LBB{4962}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{4948}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{4912}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r15d
+	shrl	$24, %r15d
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{4965}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{4966}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4598}
+	jmp	.LBB{4}
+.LBB{4967}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{4}
+.LBB{4968}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{4969}
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+.LBB{4594}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4970}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{4969}
+.LBB{4616}:
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4971}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{4969}
+.LBB{4623}:
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4972}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{62}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{4969}
+.LBB{4626}:
+	cmpl	$15, %r8d
+	ja	.LBB{1493}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4973}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4598}
+	jmp	.LBB{4}
+.LBB{4974}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{30}
+.LBB{3940}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4975}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{4598}
+.LBB{4976}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{28}
+	movl	%r15d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1466}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	160(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	176(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{4977}
+	cmpq	%r9, %r8
+	jae	.LBB{4977}
+	movq	168(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{4978}
+.LBB{4979}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{4977}
+.LBB{4978}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{4979}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{4979}
+	jmp	.LBB{5}
+.LBB{4980}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{3888}
+	jmp	.LBB{28}
+.LBB{4981}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r15b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{4982}
+.LBB{4983}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1499}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{28}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	160(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	176(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{4984}
+	cmpq	%rdi, %rax
+	jae	.LBB{4984}
+	movq	168(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{4985}
+.LBB{4986}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{4984}
+.LBB{4985}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{4986}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{4986}
+	jmp	.LBB{5}
+.LBB{4987}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{3816}
+.Ltmp{4988}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{4989}:
+.LBB{3816}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{4990}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	cmpl	$268435455, %eax
+	ja	.LBB{1475}
+	cmpl	$15, %r8d
+	jbe	.LBB{2294}
+	jmp	.LBB{1473}
+.LBB{4991}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	shll	$8, %r15d
+	movzbl	%bl, %eax
+	orl	%r15d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4992}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3885}
+	jmp	.LBB{4982}
+.LBB{4993}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4994}:
+	cmpl	$16, %r8d
+	jae	.LBB{233}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{4995}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{4598}
+.LBB{4996}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4765}
+	jmp	.LBB{1472}
+.LBB{4997}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4760}
+	jmp	.LBB{1472}
+.LBB{4998}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{4604}
+.Ltmp{4999}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{5000}:
+.LBB{4604}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+.LBB{4598}:
+	movzbl	%bl, %eax
+	shll	$8, %r15d
+	orl	%eax, %r15d
+	movq	%r15, %r14
+	jmp	.LBB{4}
+.LBB{5001}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{5002}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{5003}
+	cmpq	$-1, %rcx
+	je	.LBB{5004}
+.LBB{5003}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{5005}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{5006}
+	jmp	.LBB{1473}
+.LBB{5007}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{4755}
+	jmp	.LBB{1472}
+.LBB{5008}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{3904}
+	jmp	.LBB{28}
+.LBB{5009}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{42}
+	movl	%r15d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1472}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{5010}
+	xorl	%edx, %edx
+	jmp	.LBB{5011}
+.LBB{5012}:
+	movl	%r15d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r15b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r15b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{4982}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{4}
+.LBB{4984}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	152(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{5013}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{5013}
+	jmp	.LBB{4}
+.LBB{5010}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{5014}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{5011}
+.LBB{4977}:
+	movq	152(%rsp), %rdi
+.Ltmp{5015}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{5016}:
+	jmp	.LBB{4}
+.LBB{5002}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{5006}
+	jmp	.LBB{1473}
+.LBB{5005}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{1473}
+.LBB{5006}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{5014}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{5011}:
+	cmpl	$16, %r8d
+	jae	.LBB{1473}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{120}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$16, %r8d
+	jb	.LBB{122}
+	jmp	.LBB{56}
+.LBB{244}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{231}
+	jmp	.LBB{226}
+.LBB{320}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{304}
+	jmp	.LBB{295}
+.LBB{353}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{345}
+	jmp	.LBB{226}
+.LBB{416}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{383}
+	jmp	.LBB{295}
+.LBB{633}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{589}
+	jmp	.LBB{226}
+.LBB{640}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{596}
+	jmp	.LBB{137}
+.LBB{648}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{452}
+	jmp	.LBB{565}
+.LBB{660}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{461}
+	jmp	.LBB{137}
+.LBB{725}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{500}
+	jmp	.LBB{295}
+.LBB{769}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{707}
+	jmp	.LBB{137}
+.LBB{780}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{528}
+	jmp	.LBB{621}
+.LBB{785}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{531}
+	jmp	.LBB{295}
+.LBB{909}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{891}
+	jmp	.LBB{621}
+.LBB{961}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{946}
+	jmp	.LBB{44}
+.LBB{999}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{983}
+	jmp	.LBB{621}
+.LBB{1034}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1008}
+	jmp	.LBB{621}
+.LBB{1134}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1105}
+	jmp	.LBB{44}
+.LBB{1195}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{56}
+.LBB{1226}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1189}
+	jmp	.LBB{56}
+.LBB{1275}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1233}
+	jmp	.LBB{56}
+.LBB{1287}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1237}
+	jmp	.LBB{56}
+.LBB{1310}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1264}
+	jmp	.LBB{137}
+.LBB{1386}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1376}
+	jmp	.LBB{44}
+.LBB{1437}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1425}
+	jmp	.LBB{44}
+.LBB{1489}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1477}
+	jmp	.LBB{1473}
+.LBB{1538}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1526}
+	jmp	.LBB{1473}
+.LBB{1587}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1575}
+	jmp	.LBB{1473}
+.LBB{1635}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1623}
+	jmp	.LBB{1473}
+.LBB{2583}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2571}
+	jmp	.LBB{226}
+.LBB{2639}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2627}
+	jmp	.LBB{295}
+.LBB{2695}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2683}
+	jmp	.LBB{226}
+.LBB{1903}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1879}
+	jmp	.LBB{295}
+.LBB{2752}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2740}
+	jmp	.LBB{226}
+.LBB{2809}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2797}
+	jmp	.LBB{137}
+.LBB{1963}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1938}
+	jmp	.LBB{565}
+.LBB{2021}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1997}
+	jmp	.LBB{137}
+.LBB{2079}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2055}
+	jmp	.LBB{295}
+.LBB{2865}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2853}
+	jmp	.LBB{137}
+.LBB{2138}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2113}
+	jmp	.LBB{621}
+.LBB{2196}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2172}
+	jmp	.LBB{295}
+.LBB{2922}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2910}
+	jmp	.LBB{621}
+.LBB{2978}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2966}
+	jmp	.LBB{44}
+.LBB{3034}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3022}
+	jmp	.LBB{621}
+.LBB{3090}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3078}
+	jmp	.LBB{621}
+.LBB{3150}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3142}
+	jmp	.LBB{44}
+.LBB{3204}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3192}
+	jmp	.LBB{56}
+.LBB{3260}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3248}
+	jmp	.LBB{56}
+.LBB{3316}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3304}
+	jmp	.LBB{56}
+.LBB{3372}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3360}
+	jmp	.LBB{56}
+.LBB{3428}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3416}
+	jmp	.LBB{137}
+.LBB{3540}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3528}
+	jmp	.LBB{44}
+.LBB{3653}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3641}
+	jmp	.LBB{44}
+.LBB{3484}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3472}
+	jmp	.LBB{44}
+.LBB{3597}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3585}
+	jmp	.LBB{1473}
+.LBB{3709}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3697}
+	jmp	.LBB{1473}
+.LBB{3765}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3753}
+	jmp	.LBB{1473}
+.LBB{3801}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3803}
+	jmp	.LBB{226}
+.LBB{3859}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3861}
+	jmp	.LBB{295}
+.LBB{3926}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3928}
+	jmp	.LBB{226}
+.LBB{2224}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2226}
+	jmp	.LBB{295}
+.LBB{3963}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3965}
+	jmp	.LBB{226}
+.LBB{4010}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4012}
+	jmp	.LBB{137}
+.LBB{2398}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2400}
+	jmp	.LBB{295}
+.LBB{2305}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2307}
+	jmp	.LBB{565}
+.LBB{2366}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2368}
+	jmp	.LBB{137}
+.LBB{2453}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2455}
+	jmp	.LBB{295}
+.LBB{2524}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2526}
+	jmp	.LBB{295}
+.LBB{4072}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4074}
+	jmp	.LBB{137}
+.LBB{4090}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4092}
+	jmp	.LBB{621}
+.LBB{4156}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4158}
+	jmp	.LBB{621}
+.LBB{4187}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4189}
+	jmp	.LBB{621}
+.LBB{4257}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4259}
+	jmp	.LBB{621}
+.LBB{4307}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4309}
+	jmp	.LBB{44}
+.LBB{4338}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4340}
+	jmp	.LBB{56}
+.LBB{4390}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4392}
+	jmp	.LBB{56}
+.LBB{4408}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4410}
+	jmp	.LBB{56}
+.LBB{4517}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4519}
+	jmp	.LBB{137}
+.LBB{4476}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4478}
+	jmp	.LBB{56}
+.LBB{4545}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4547}
+	jmp	.LBB{44}
+.LBB{4610}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4612}
+	jmp	.LBB{44}
+.LBB{4662}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4664}
+	jmp	.LBB{44}
+.LBB{4702}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4704}
+	jmp	.LBB{1473}
+.LBB{4726}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4728}
+	jmp	.LBB{1473}
+.LBB{4776}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4778}
+	jmp	.LBB{1473}
+.LBB{1683}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1671}
+	jmp	.LBB{226}
+.LBB{4829}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4817}
+	jmp	.LBB{226}
+.LBB{4890}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4892}
+	jmp	.LBB{226}
+.LBB{1832}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1820}
+	jmp	.LBB{226}
+.LBB{4939}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{4927}
+	jmp	.LBB{1473}
+.LBB{5004}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{5006}
+.LBB{1473}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{5017}:
+	cmpl	$16, %r8d
+	jae	.LBB{38}
+.LBB{5018}:
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, 184(%rsp)
+.LBB{5}:
+	movq	144(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{5019}
+.LBB{5020}:
+	movq	152(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{5019}:
+	movq	168(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{5021}
+	leaq	168(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{5021}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{5022}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{5022}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{5023}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{5023}:
+	movq	184(%rsp), %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{5024}:
+	cmpl	$16, %r8d
+	jb	.LBB{5025}
+.LBB{38}:
+	leaq	.Lanon{8}(%rip), %rax
+	jmp	.LBB{439}
+.LBB{5026}:
+	cmpl	$16, %r8d
+	jae	.LBB{30}
+.LBB{5025}:
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, 184(%rsp)
+	movq	144(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{5020}
+	jmp	.LBB{5019}
+.LBB{5027}:
+	cmpl	$16, %r8d
+	jb	.LBB{5018}
+.LBB{107}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{5028}:
+	cmpl	$16, %r8d
+	jb	.LBB{5018}
+.LBB{1929}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{5029}:
+	cmpl	$16, %r8d
+	jb	.LBB{5018}
+.LBB{15}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{5030}:
+	cmpl	$16, %r8d
+	jb	.LBB{5018}
+.LBB{88}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1}:
+	movq	%rdx, 16(%rsp)
+	leaq	16(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{5031}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{5032}(%rip), %rdi
+	leaq	.Lanon{5033}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{196}:
+.Ltmp{5034}:
+	leaq	.Lanon{5035}(%rip), %rdi
+	leaq	.Lanon{5036}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{5037}:
+	jmp	.LBB{5038}
+.LBB{233}:
+	leaq	.Lanon{62}(%rip), %rax
+.LBB{439}:
+	movq	%rax, 8(%rsp)
+.LBB{30}:
+.Ltmp{5039}:
+	movq	8(%rsp), %rdx
+	movq	%r8, %rdi
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{5040}:
+	jmp	.LBB{5038}
+.LBB{56}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{55}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{28}:
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{137}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{44}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{134}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{584}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{43}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{63}:
+	movq	%rax, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{174}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{145}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{621}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{295}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{226}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{583}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{294}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{620}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{225}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{1472}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{9}:
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1493}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1492}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{482}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{248}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{173}:
+	movq	%rax, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{144}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{275}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{218}:
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{323}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1143}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{30}
+.LBB{23}:
+	movq	%r11, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{21}:
+	movq	%r11, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{22}:
+	movq	%r11, 8(%rsp)
+	movq	%rdx, %r8
+	jmp	.LBB{30}
+.LBB{77}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{151}:
+	movq	%r9, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{152}:
+	movq	%r9, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{153}:
+	movq	%r9, 8(%rsp)
+	movq	%rdx, %r8
+	jmp	.LBB{30}
+.LBB{399}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1079}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1089}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1090}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1081}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{247}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{30}
+.LBB{377}:
+	movq	%rcx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{489}:
+	movq	%rcx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{656}:
+	movq	%rax, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{398}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{565}:
+	movq	%r8, %rdi
+	jmp	.LBB{42}
+.LBB{616}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{877}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1070}:
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{564}:
+	movq	%rcx, %rdi
+	jmp	.LBB{42}
+.LBB{875}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{450}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{883}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{884}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{483}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{448}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{484}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{4969}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{30}
+.LBB{643}:
+	movq	%rax, %r8
+	jmp	.LBB{30}
+.LBB{866}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{867}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{859}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{455}:
+	movq	%rcx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{965}:
+	movq	%rax, %r8
+.LBB{948}:
+	leaq	.Lanon{62}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1499}:
+	movq	%rdx, %r8
+	jmp	.LBB{30}
+.LBB{1466}:
+	movq	%rsi, %r8
+	jmp	.LBB{30}
+.LBB{219}:
+	movq	%rsi, %r8
+	jmp	.LBB{30}
+.LBB{861}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{262}:
+	movq	%rdx, %r8
+	jmp	.LBB{30}
+.LBB{401}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{442}:
+	movq	%rcx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{940}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{1159}:
+	movq	%rdx, %r8
+	jmp	.LBB{30}
+.LBB{229}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{276}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{277}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{250}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{504}:
+	movq	%rdx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{378}:
+	movq	%rsi, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{490}:
+	movq	%rsi, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{387}:
+	movq	%rdx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1062}:
+	movq	%rsi, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{1071}:
+	movq	%rsi, %r8
+	jmp	.LBB{30}
+.LBB{1064}:
+	movq	%rsi, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1166}:
+	movq	%rdx, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{1167}:
+	movq	%rdx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1172}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{2908}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{3190}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{211}:
+	movq	%rsi, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{698}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{272}:
+	movq	%rdx, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{581}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{471}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{472}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{437}:
+	movq	%rdx, %r8
+	jmp	.LBB{439}
+.LBB{252}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{213}:
+	movq	%rsi, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{273}:
+	movq	%rdx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{592}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{2128}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{2291}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{4494}:
+	movq	%rbx, %rdi
+	jmp	.LBB{42}
+.LBB{1475}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{2269}:
+	movq	%rcx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{2289}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{2738}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{3583}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{2795}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{456}:
+	movq	%rsi, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{495}:
+	movq	%rdx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{2569}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{1524}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{1612}:
+	movq	%r9, %r8
+	jmp	.LBB{30}
+.LBB{302}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{1383}:
+	movq	%rsi, %rdi
+	jmp	.LBB{42}
+.LBB{477}:
+	movq	%rdx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{443}:
+	movq	%rsi, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{3139}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{586}:
+	movq	%r12, %rdi
+	jmp	.LBB{42}
+.LBB{1953}:
+	movq	%r14, %rdi
+	jmp	.LBB{42}
+.LBB{704}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{1397}:
+	movq	%r9, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{1398}:
+	movq	%r9, 8(%rsp)
+	movq	%rsi, %r8
+	jmp	.LBB{30}
+.LBB{1399}:
+	movq	%r9, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{30}
+.LBB{1368}:
+	movq	%rsi, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{30}
+.LBB{4370}:
+	movq	%rbx, %rdi
+	jmp	.LBB{42}
+.LBB{3849}:
+	movq	%rbx, %rdi
+	jmp	.LBB{42}
+.LBB{2278}:
+	movq	%rdx, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{2270}:
+	movq	%rsi, %r8
+	movq	%rbx, 8(%rsp)
+	jmp	.LBB{30}
+.LBB{4145}:
+	movq	%r13, %rdi
+	jmp	.LBB{42}
+.LBB{2293}:
+	movq	%r15, %rdi
+	jmp	.LBB{42}
+.LBB{4297}:
+	movq	%rbx, %rdi
+.LBB{42}:
+.Ltmp{5041}:
+	leaq	.Lanon{62}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{5042}:
+.LBB{5038}:
+	ud2
+.LBB{5043}:
+.Ltmp{5044}:
+	movq	%rax, %rbx
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{5045}:
+.Ltmp{5046}:
+	jmp	.LBB{5047}
+.LBB{5048}:
+.Ltmp{5049}:
+.LBB{5047}:
+	movq	%rax, %rbx
+	leaq	144(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
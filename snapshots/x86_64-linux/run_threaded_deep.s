@@ -0,0 +1,26763 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %rax
+	movq	%rax, 320(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 16(%rsp)
+	movaps	%xmm1, 32(%rsp)
+	movaps	%xmm2, 48(%rsp)
+	movaps	%xmm3, 64(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 80(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 96(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	152(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%ebp, %ebp
+.LBB{4}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%rbp,4), %eax
+	movq	$-1, 336(%rsp)
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%rbp), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{6}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{7}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{10}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{11}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{12}
+	jmp	.LBB{13}
+.LBB{14}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r12, %r13
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{15}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{16}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{17}
+	jmp	.LBB{18}
+.LBB{19}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{22}
+	cmpq	%rdi, %rax
+	jae	.LBB{22}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{23}
+.LBB{24}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{22}
+.LBB{23}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{24}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{24}
+	jmp	.LBB{5}
+.LBB{25}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	movq	%rsi, 8(%rsp)
+	jae	.LBB{21}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{26}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{26}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{27}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{28}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{29}
+	jmp	.LBB{30}
+.LBB{31}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	movq	320(%rsp), %rax
+	movl	8(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	3(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{32}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{33}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{35}
+	jmp	.LBB{36}
+.LBB{37}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{41}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{42}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{43}
+	jmp	.LBB{44}
+.LBB{45}:
+	movq	232(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{5}
+	leaq	-1(%rdx), %rdi
+	movq	%rdi, 232(%rsp)
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$15, %r8d
+	movq	%rcx, 8(%rsp)
+	ja	.LBB{9}
+	movq	224(%rsp), %rsi
+	movq	-8(%rsi,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %r9d
+	leaq	.LJTI{46}(%rip), %r10
+	movslq	(%r10,%r9,4), %r9
+	addq	%r10, %r9
+	jmpq	*%r9
+.LBB{47}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{48}
+	jmp	.LBB{49}
+.LBB{50}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{53}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{54}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{55}
+	jmp	.LBB{56}
+.LBB{57}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	movq	%rcx, 8(%rsp)
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{60}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{61}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{62}
+	jmp	.LBB{63}
+.LBB{64}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{65}
+.Ltmp{66}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{67}:
+.LBB{65}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 208(%rsp)
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r13
+	movzbl	%al, %esi
+	leaq	.LJTI{68}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{69}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{70}
+	jmp	.LBB{71}
+.LBB{72}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{73}
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	16(%rsp,%r12,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{74}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{75}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{76}
+	jmp	.LBB{77}
+.LBB{78}:
+	leaq	.Lanon{58}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	addq	$2, %rbp
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	decq	16(%rsp,%r8,8)
+	cmoveq	%rbp, %r12
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{79}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{80}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{81}
+	jmp	.LBB{82}
+.LBB{83}:
+	leaq	.Lanon{58}(%rip), %r14
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{85}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{86}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{87}
+	jmp	.LBB{88}
+.LBB{89}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r13, %r12
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{90}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{91}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{92}
+	jmp	.LBB{93}
+.LBB{94}:
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %r8d
+	movq	%rax, 8(%rsp)
+	jae	.LBB{9}
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rbx
+	cmpq	216(%rsp), %r15
+	jne	.LBB{95}
+.Ltmp{96}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{97}:
+.LBB{95}:
+	movq	224(%rsp), %rax
+	movq	%rbx, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 232(%rsp)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %esi
+	leaq	.LJTI{98}(%rip), %rdi
+	movslq	(%rdi,%rsi,4), %rsi
+	addq	%rdi, %rsi
+	jmpq	*%rsi
+.LBB{99}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{100}
+	jmp	.LBB{101}
+.LBB{102}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r12, %r13
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{103}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{104}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{105}
+	jmp	.LBB{106}
+.LBB{107}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{108}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{109}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{110}
+	jmp	.LBB{111}
+.LBB{112}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{114}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{115}
+	cmpq	$-1, %rcx
+	je	.LBB{116}
+.LBB{115}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{117}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %r8d
+	jb	.LBB{118}
+	jmp	.LBB{119}
+.LBB{120}:
+	movq	208(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{5}
+	leaq	-1(%r9), %r11
+	movq	%r11, 208(%rsp)
+	movq	200(%rsp), %r10
+	movq	-8(%r10,%r9,8), %rdx
+	movq	320(%rsp), %rax
+	movl	(%rax,%rdx,4), %esi
+	cmpb	$31, %sil
+	ja	.LBB{5}
+	movl	%esi, %edi
+	shrl	$8, %edi
+	movzbl	%dil, %r8d
+	movl	%esi, %ecx
+	shrl	$16, %ecx
+	movl	%esi, %eax
+	shrl	$24, %eax
+	leaq	1(%rdx), %r12
+	movzbl	%sil, %ebx
+	leaq	.LJTI{121}(%rip), %r14
+	movslq	(%r14,%rbx,4), %rbx
+	addq	%r14, %rbx
+	jmpq	*%rbx
+.LBB{122}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{123}
+	jmp	.LBB{124}
+.LBB{125}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{21}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rdi
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{126}(%rip), %r9
+	movslq	(%r9,%rdx,4), %rdx
+	addq	%r9, %rdx
+	jmpq	*%rdx
+.LBB{127}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{128}
+	jmp	.LBB{129}
+.LBB{130}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{131}
+	xorl	%edx, %edx
+	cmpl	$16, %r8d
+	jb	.LBB{132}
+	jmp	.LBB{119}
+.LBB{133}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{134}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{135}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{136}
+	jmp	.LBB{137}
+.LBB{138}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rdx
+	cmpl	$16, %eax
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %r15
+	andq	%r15, %rcx
+	subq	%rcx, %rax
+	movabsq	$3689348814741910323, %rsi
+	movq	%rax, %rcx
+	andq	%rsi, %rcx
+	shrq	$2, %rax
+	andq	%rsi, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	movabsq	$72340172838076673, %rax
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{140}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{141}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	%r15, %r12
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{142}
+	jmp	.LBB{143}
+.LBB{144}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%r12d, %edx
+	cmpl	$268435455, %eax
+	ja	.LBB{20}
+	movq	16(%rsp,%rcx,8), %rax
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	%rdx, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %r13
+	cmpq	%r13, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rdx, %rsi
+	addq	%rcx, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{5}
+	movq	184(%rsp), %r14
+	testq	%r14, %r14
+	je	.LBB{145}
+	cmpq	%rsi, %rcx
+	jae	.LBB{145}
+	movq	176(%rsp), %rsi
+	movq	%rdx, %rdi
+	movq	%rcx, %r8
+	jmp	.LBB{146}
+.LBB{147}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{145}
+.LBB{146}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%r14, %r9
+	jae	.LBB{147}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{147}
+	jmp	.LBB{5}
+.LBB{148}:
+	leaq	.Lanon{58}(%rip), %r14
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	incq	16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{149}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{150}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{151}
+	jmp	.LBB{152}
+.LBB{153}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmovsq	%r13, %r12
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{154}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{155}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{156}
+	jmp	.LBB{157}
+.LBB{158}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	cmpq	$0, 16(%rsp,%r8,8)
+	cmoveq	%r13, %r12
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %r13
+	movzbl	%al, %edx
+	leaq	.LJTI{159}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{160}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{161}
+	jmp	.LBB{162}
+.LBB{163}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rdx
+	cmpl	$16, %eax
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{164}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{165}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{166}
+	jmp	.LBB{167}
+.LBB{168}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	movq	320(%rsp), %rax
+	movl	8(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	3(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{169}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{170}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{171}
+	jmp	.LBB{172}
+.LBB{173}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rdx
+	cmpl	$16, %eax
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{174}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{175}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{176}
+	jmp	.LBB{177}
+.LBB{22}:
+	testq	%rcx, %rcx
+	je	.LBB{178}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{179}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{179}
+.LBB{178}:
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r12
+	movzbl	%al, %edx
+	leaq	.LJTI{180}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{181}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{182}
+	jmp	.LBB{183}
+.LBB{131}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{184}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %r8d
+	jb	.LBB{132}
+	jmp	.LBB{119}
+.LBB{145}:
+	movq	%r13, %rsi
+	subq	%rdx, %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{185}
+	movq	160(%rsp), %r12
+	leaq	(%r12,%rax,8), %rsi
+	leaq	(%r12,%rcx,8), %rdi
+	shlq	$3, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %rdx
+	movq	%rdx, 328(%rsp)
+	movzbl	%al, %edx
+	leaq	.LJTI{186}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{187}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{188}
+	jmp	.LBB{189}
+.LBB{114}:
+	xorl	%eax, %eax
+	cmpl	$16, %r8d
+	jb	.LBB{118}
+	jmp	.LBB{119}
+.LBB{117}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+.LBB{118}:
+	movq	%rax, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{190}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{191}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{192}
+	jmp	.LBB{193}
+.LBB{184}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+.LBB{132}:
+	movq	%rdx, 16(%rsp,%r8,8)
+	movq	320(%rsp), %rax
+	movl	4(%rax,%rbp,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r15d
+	shrl	$16, %r15d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	2(%rbp), %r14
+	movzbl	%al, %edx
+	leaq	.LJTI{194}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{195}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{196}
+	jmp	.LBB{197}
+.LBB{198}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{36}
+	jmp	.LBB{35}
+.LBB{199}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{201}
+	cmpq	%r9, %r8
+	jae	.LBB{201}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{202}
+.LBB{203}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{201}
+.LBB{202}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{203}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{203}
+	jmp	.LBB{5}
+.LBB{204}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{205}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{206}
+.LBB{207}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{208}
+	cmpq	%rdi, %rax
+	jae	.LBB{208}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{209}
+.LBB{210}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{208}
+.LBB{209}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{210}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{210}
+	jmp	.LBB{5}
+.LBB{211}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{212}
+.Ltmp{213}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{214}:
+.LBB{212}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{36}
+.LBB{215}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{218}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{137}
+	jmp	.LBB{136}
+.LBB{219}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{222}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{224}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{35}
+	jmp	.LBB{206}
+.LBB{226}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{227}
+.LBB{228}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{229}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{36}
+.LBB{230}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{36}
+	jmp	.LBB{35}
+.LBB{231}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{232}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{233}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{234}
+	cmpq	$-1, %rcx
+	je	.LBB{235}
+.LBB{234}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{236}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{227}
+	jmp	.LBB{217}
+.LBB{237}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{238}
+.Ltmp{239}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{240}:
+.LBB{238}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{35}
+.LBB{241}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{243}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{243}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{137}
+.LBB{245}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{246}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{247}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{248}
+	xorl	%edx, %edx
+	jmp	.LBB{249}
+.LBB{250}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{251}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{252}
+.LBB{253}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{254}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{172}
+	jmp	.LBB{171}
+.LBB{255}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{256}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{256}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{36}
+.LBB{257}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{258}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{262}
+	cmpq	%r9, %r8
+	jae	.LBB{262}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{263}
+.LBB{264}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{262}
+.LBB{263}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{264}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{264}
+	jmp	.LBB{5}
+.LBB{265}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{36}
+.LBB{35}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{36}
+.LBB{266}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{137}
+.LBB{267}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{268}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{269}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{269}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{172}
+.LBB{270}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{271}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{206}:
+	movq	%rbp, %r12
+	addq	$4, %r12
+	jmp	.LBB{36}
+.LBB{272}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{273}
+.LBB{274}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{275}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{276}
+	cmpq	%r9, %r8
+	jae	.LBB{276}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{277}
+.LBB{278}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{276}
+.LBB{277}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{278}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{278}
+	jmp	.LBB{5}
+.LBB{279}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{280}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{282}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{283}
+.LBB{284}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{136}
+	jmp	.LBB{252}
+.LBB{285}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{286}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{172}
+.LBB{287}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{288}
+.Ltmp{289}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{290}:
+.LBB{288}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{136}
+.LBB{291}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{292}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{293}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{294}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{295}
+	cmpq	$-1, %rcx
+	je	.LBB{296}
+.LBB{295}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{297}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{283}
+	jmp	.LBB{119}
+.LBB{298}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{300}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{301}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{302}
+.Ltmp{303}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{304}:
+.LBB{302}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{137}
+.LBB{305}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{306}
+	xorl	%edx, %edx
+	jmp	.LBB{307}
+.LBB{308}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{309}
+	cmpq	%rdi, %rax
+	jae	.LBB{309}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{310}
+.LBB{311}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{309}
+.LBB{310}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{311}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{311}
+	jmp	.LBB{5}
+.LBB{312}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{313}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{314}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{315}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{136}
+	jmp	.LBB{137}
+.LBB{316}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{137}
+.LBB{136}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{137}
+.LBB{317}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{318}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{319}
+.LBB{320}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{171}
+	jmp	.LBB{273}
+.LBB{321}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{322}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{323}
+.Ltmp{324}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{325}:
+.LBB{323}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{171}
+.LBB{326}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{327}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{328}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{329}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{330}
+	cmpq	$-1, %rcx
+	je	.LBB{331}
+.LBB{330}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{332}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{319}
+	jmp	.LBB{217}
+.LBB{333}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{334}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{335}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{336}
+.Ltmp{337}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{338}:
+.LBB{336}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{172}
+.LBB{339}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{340}
+	xorl	%edx, %edx
+	jmp	.LBB{341}
+.LBB{342}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{252}:
+	addq	$2, %r12
+	movq	%r12, %r14
+	jmp	.LBB{137}
+.LBB{343}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{344}
+	cmpq	%rdi, %rax
+	jae	.LBB{344}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{345}
+.LBB{346}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{344}
+.LBB{345}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{346}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{346}
+	jmp	.LBB{5}
+.LBB{347}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{348}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{349}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{350}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{171}
+	jmp	.LBB{172}
+.LBB{351}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{172}
+.LBB{171}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{172}
+.LBB{352}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{353}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{354}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{273}:
+	movq	%rbp, %r12
+	addq	$4, %r12
+	jmp	.LBB{172}
+.LBB{355}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{356}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{18}
+	jmp	.LBB{17}
+.LBB{357}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{359}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{359}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{18}
+.LBB{360}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{361}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{362}
+.LBB{363}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{365}
+	cmpq	%r9, %r8
+	jae	.LBB{365}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{366}
+.LBB{367}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{365}
+.LBB{366}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{367}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{367}
+	jmp	.LBB{5}
+.LBB{368}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{18}
+.LBB{369}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{372}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{374}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{375}
+.LBB{376}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{17}
+	jmp	.LBB{362}
+.LBB{377}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{82}
+	jmp	.LBB{81}
+.LBB{378}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{379}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{13}
+	jmp	.LBB{12}
+.LBB{380}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{88}
+	jmp	.LBB{87}
+.LBB{381}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{93}
+	jmp	.LBB{92}
+.LBB{382}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{383}
+.Ltmp{384}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{385}:
+.LBB{383}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{17}
+.LBB{386}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{387}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{388}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{106}
+	jmp	.LBB{105}
+.LBB{389}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{390}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{391}
+	cmpq	$-1, %rcx
+	je	.LBB{392}
+.LBB{391}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{393}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{375}
+	jmp	.LBB{371}
+.LBB{394}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{396}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{397}
+.Ltmp{398}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{399}:
+.LBB{397}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{18}
+.LBB{400}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{401}
+	xorl	%edx, %edx
+	jmp	.LBB{402}
+.LBB{403}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{405}
+	cmpq	%rdi, %rax
+	jae	.LBB{405}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{406}
+.LBB{407}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{405}
+.LBB{406}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{407}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{407}
+	jmp	.LBB{5}
+.LBB{408}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{409}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{410}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{411}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{152}
+	jmp	.LBB{151}
+.LBB{412}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{17}
+	jmp	.LBB{18}
+.LBB{413}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{157}
+	jmp	.LBB{156}
+.LBB{414}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{18}
+.LBB{17}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{18}
+.LBB{415}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{162}
+	jmp	.LBB{161}
+.LBB{416}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{417}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{418}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{418}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{419}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{82}
+.LBB{420}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{421}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{422}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{423}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{423}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{13}
+.LBB{424}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{426}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{426}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{88}
+.LBB{428}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{429}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{430}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{431}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{431}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{93}
+.LBB{432}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{433}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{434}
+.LBB{435}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{436}
+.LBB{437}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{438}
+.LBB{439}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{440}
+.LBB{441}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{221}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{442}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{443}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{444}
+	cmpq	%r9, %r8
+	jae	.LBB{444}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{445}
+.LBB{446}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{444}
+.LBB{445}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{446}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{446}
+	jmp	.LBB{5}
+.LBB{447}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{422}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{448}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{449}
+	cmpq	%r9, %r8
+	jae	.LBB{449}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{450}
+.LBB{451}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{449}
+.LBB{450}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{451}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{451}
+	jmp	.LBB{5}
+.LBB{452}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{456}
+	cmpq	%r9, %r8
+	jae	.LBB{456}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{457}
+.LBB{458}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{456}
+.LBB{457}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{458}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{458}
+	jmp	.LBB{5}
+.LBB{459}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{460}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{460}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{106}
+.LBB{461}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{462}
+	cmpq	%r9, %r8
+	jae	.LBB{462}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{463}
+.LBB{464}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{462}
+.LBB{463}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{464}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{464}
+	jmp	.LBB{5}
+.LBB{465}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{466}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{467}
+.LBB{468}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{469}
+	cmpq	%r9, %r8
+	jae	.LBB{469}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{470}
+.LBB{471}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{469}
+.LBB{470}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{471}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{471}
+	jmp	.LBB{5}
+.LBB{472}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{473}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{474}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{474}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{152}
+.LBB{475}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{476}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{82}
+.LBB{477}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{478}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{478}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{157}
+.LBB{479}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{480}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{480}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{162}
+.LBB{481}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{13}
+.LBB{482}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{88}
+.LBB{483}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{484}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{362}:
+	addq	$2, %r13
+	movq	%r13, %r12
+	jmp	.LBB{18}
+.LBB{485}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{486}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{487}
+.LBB{488}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{93}
+.LBB{489}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{490}
+.LBB{491}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{492}
+.LBB{493}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{494}
+	cmpq	%r9, %r8
+	jae	.LBB{494}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{495}
+.LBB{496}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{494}
+.LBB{495}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{496}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{496}
+	jmp	.LBB{5}
+.LBB{497}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{498}
+	cmpq	%r9, %r8
+	jae	.LBB{498}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{499}
+.LBB{500}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{498}
+.LBB{499}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{500}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{500}
+	jmp	.LBB{5}
+.LBB{501}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{502}
+	cmpq	%r9, %r8
+	jae	.LBB{502}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{503}
+.LBB{504}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{502}
+.LBB{503}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{504}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{504}
+	jmp	.LBB{5}
+.LBB{505}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{106}
+.LBB{506}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{507}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{508}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{509}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{510}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{511}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{152}
+.LBB{512}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{157}
+.LBB{513}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{514}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{162}
+.LBB{515}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{516}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{518}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{519}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{520}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{521}
+.LBB{522}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{81}
+	jmp	.LBB{434}
+.LBB{523}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{524}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{525}
+.LBB{526}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{12}
+	jmp	.LBB{436}
+.LBB{527}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{528}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{529}
+.LBB{530}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{87}
+	jmp	.LBB{438}
+.LBB{531}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{532}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{533}
+.LBB{534}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{92}
+	jmp	.LBB{440}
+.LBB{535}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{536}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{537}
+.Ltmp{538}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{539}:
+.LBB{537}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{81}
+.LBB{540}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{541}
+.Ltmp{542}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{543}:
+.LBB{541}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{12}
+.LBB{544}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{5}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{545}
+.Ltmp{546}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{547}:
+.LBB{545}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+	jmp	.LBB{87}
+.LBB{548}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{549}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{550}
+.Ltmp{551}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{552}:
+.LBB{550}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{92}
+.LBB{553}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{554}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{555}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{556}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{557}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{558}
+.LBB{559}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{560}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{105}
+	jmp	.LBB{467}
+.LBB{561}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{562}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{563}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{564}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{565}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{566}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{567}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{568}
+	cmpq	$-1, %rcx
+	je	.LBB{569}
+.LBB{568}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{570}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{521}
+	jmp	.LBB{217}
+.LBB{571}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{572}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{573}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{574}
+	cmpq	$-1, %rcx
+	je	.LBB{575}
+.LBB{574}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{576}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{525}
+	jmp	.LBB{52}
+.LBB{577}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{578}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{579}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{580}
+	cmpq	$-1, %rcx
+	je	.LBB{581}
+.LBB{580}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{582}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{529}
+	jmp	.LBB{119}
+.LBB{583}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{585}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{587}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{588}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{589}
+.Ltmp{590}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{591}:
+.LBB{589}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{105}
+.LBB{592}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{593}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{594}
+	cmpq	$-1, %rcx
+	je	.LBB{595}
+.LBB{594}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{596}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{533}
+	jmp	.LBB{119}
+.LBB{597}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{599}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{600}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{601}
+.Ltmp{602}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{603}:
+.LBB{601}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{82}
+.LBB{604}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{605}
+	xorl	%edx, %edx
+	jmp	.LBB{606}
+.LBB{607}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{608}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{609}
+.Ltmp{610}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{611}:
+.LBB{609}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{13}
+.LBB{612}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{613}
+.Ltmp{614}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{615}:
+.LBB{613}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{88}
+.LBB{616}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{617}
+	xorl	%edx, %edx
+	jmp	.LBB{618}
+.LBB{619}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{620}
+	xorl	%edx, %edx
+	jmp	.LBB{621}
+.LBB{622}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{221}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{442}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{443}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{623}
+	cmpq	%rdi, %rax
+	jae	.LBB{623}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{624}
+.LBB{625}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{623}
+.LBB{624}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{625}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{625}
+	jmp	.LBB{5}
+.LBB{626}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{627}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{628}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{629}
+.Ltmp{630}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{631}:
+.LBB{629}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{93}
+.LBB{632}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{633}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{634}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{635}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{636}
+	xorl	%edx, %edx
+	jmp	.LBB{637}
+.LBB{638}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{639}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{640}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{422}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{641}
+	cmpq	%rdi, %rax
+	jae	.LBB{641}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{642}
+.LBB{643}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{641}
+.LBB{642}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{643}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{643}
+	jmp	.LBB{5}
+.LBB{644}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{645}
+	cmpq	%rdi, %rax
+	jae	.LBB{645}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{646}
+.LBB{647}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{645}
+.LBB{646}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{647}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{647}
+	jmp	.LBB{5}
+.LBB{648}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{417}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{419}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{649}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{650}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{651}
+.LBB{652}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{151}
+	jmp	.LBB{487}
+.LBB{653}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{654}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{655}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{587}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{656}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{657}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{658}
+	cmpq	$-1, %rcx
+	je	.LBB{659}
+.LBB{658}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{660}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{558}
+	jmp	.LBB{119}
+.LBB{661}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{662}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{422}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{663}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{664}
+	cmpq	%rdi, %rax
+	jae	.LBB{664}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{665}
+.LBB{666}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{664}
+.LBB{665}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{666}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{666}
+	jmp	.LBB{5}
+.LBB{667}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{668}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{669}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{670}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{671}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{672}
+.LBB{673}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{81}
+	jmp	.LBB{82}
+.LBB{674}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{156}
+	jmp	.LBB{490}
+.LBB{675}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{676}
+.LBB{677}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{678}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{82}
+.LBB{81}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{82}
+.LBB{679}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{161}
+	jmp	.LBB{492}
+.LBB{680}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{681}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{682}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{12}
+	jmp	.LBB{13}
+.LBB{683}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{13}
+.LBB{12}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{13}
+.LBB{684}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{87}
+	jmp	.LBB{88}
+.LBB{685}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{88}
+.LBB{87}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{88}
+.LBB{686}:
+	movq	208(%rsp), %r13
+	cmpq	$1024, %r13
+	je	.LBB{5}
+	cmpq	192(%rsp), %r13
+	jne	.LBB{687}
+.Ltmp{688}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{689}:
+.LBB{687}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r13,8)
+	incq	%r13
+	movq	%r13, 208(%rsp)
+	jmp	.LBB{151}
+.LBB{690}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{92}
+	jmp	.LBB{93}
+.LBB{691}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{93}
+.LBB{92}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{93}
+.LBB{692}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{693}
+.Ltmp{694}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{695}:
+.LBB{693}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{106}
+.LBB{696}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{697}
+.Ltmp{698}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{699}:
+.LBB{697}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{156}
+.LBB{700}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{701}
+	xorl	%edx, %edx
+	jmp	.LBB{702}
+.LBB{703}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{704}
+.Ltmp{705}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{706}:
+.LBB{704}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{161}
+.LBB{707}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{708}
+	cmpq	%rdi, %rax
+	jae	.LBB{708}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{709}
+.LBB{710}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{708}
+.LBB{709}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{710}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{710}
+	jmp	.LBB{5}
+.LBB{711}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{712}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{713}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{714}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{715}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{716}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{717}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{718}
+	cmpq	$-1, %rcx
+	je	.LBB{719}
+.LBB{718}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{720}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{651}
+	jmp	.LBB{119}
+.LBB{721}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{722}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{723}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{105}
+	jmp	.LBB{106}
+.LBB{724}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{725}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{106}
+.LBB{105}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{106}
+.LBB{726}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{727}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{728}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{729}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{730}
+	cmpq	$-1, %rcx
+	je	.LBB{731}
+.LBB{730}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{732}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{672}
+	jmp	.LBB{371}
+.LBB{733}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{734}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{735}
+	cmpq	$-1, %rcx
+	je	.LBB{736}
+.LBB{735}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{737}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{676}
+	jmp	.LBB{371}
+.LBB{738}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{739}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{740}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{741}
+.Ltmp{742}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{743}:
+.LBB{741}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{152}
+.LBB{744}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{745}
+	xorl	%edx, %edx
+	jmp	.LBB{746}
+.LBB{747}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{748}
+.Ltmp{749}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{750}:
+.LBB{748}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{157}
+.LBB{751}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{752}
+.Ltmp{753}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{754}:
+.LBB{752}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{162}
+.LBB{755}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{756}
+	cmpq	%rdi, %rax
+	jae	.LBB{756}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{757}
+.LBB{758}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{756}
+.LBB{757}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{758}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{758}
+	jmp	.LBB{5}
+.LBB{759}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{760}
+	xorl	%edx, %edx
+	jmp	.LBB{761}
+.LBB{762}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{763}
+	xorl	%edx, %edx
+	jmp	.LBB{764}
+.LBB{765}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{766}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{767}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{768}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{769}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{770}
+	cmpq	%rdi, %rax
+	jae	.LBB{770}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{771}
+.LBB{772}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{770}
+.LBB{771}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{772}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{772}
+	jmp	.LBB{5}
+.LBB{773}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{774}
+	cmpq	%rdi, %rax
+	jae	.LBB{774}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{775}
+.LBB{776}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{774}
+.LBB{775}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{776}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{776}
+	jmp	.LBB{5}
+.LBB{777}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{778}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{779}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{780}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{587}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{781}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{782}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{783}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{784}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{151}
+	jmp	.LBB{152}
+.LBB{785}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{786}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{152}
+.LBB{151}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{152}
+.LBB{787}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{788}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{156}
+	jmp	.LBB{157}
+.LBB{789}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{157}
+.LBB{156}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{157}
+.LBB{790}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{161}
+	jmp	.LBB{162}
+.LBB{791}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{162}
+.LBB{161}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{162}
+.LBB{792}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{434}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{82}
+.LBB{793}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{436}:
+	addq	$3, %rbp
+	movq	%rbp, %r12
+	jmp	.LBB{13}
+.LBB{794}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{438}:
+	addq	$3, %rbp
+	movq	%rbp, %r12
+	jmp	.LBB{88}
+.LBB{795}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{440}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{93}
+.LBB{796}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{797}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{467}:
+	addq	$2, %r13
+	movq	%r13, %r12
+	jmp	.LBB{106}
+.LBB{798}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{799}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{587}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{800}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{801}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{802}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{803}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{804}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{805}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{806}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{487}:
+	addq	$3, %rbp
+	movq	%rbp, %r12
+	jmp	.LBB{152}
+.LBB{807}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{490}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{157}
+.LBB{808}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{492}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{162}
+.LBB{809}:
+	movzbl	%r15b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	ja	.LBB{84}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{810}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{811}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{812}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{63}
+	jmp	.LBB{62}
+.LBB{813}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{814}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{814}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{63}
+.LBB{815}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{816}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{817}
+.LBB{818}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{819}
+	cmpq	%r9, %r8
+	jae	.LBB{819}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{820}
+.LBB{821}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{819}
+.LBB{820}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{821}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{821}
+	jmp	.LBB{5}
+.LBB{822}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	%r15, %r12
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{143}
+	jmp	.LBB{142}
+.LBB{823}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{63}
+.LBB{824}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{167}
+	jmp	.LBB{166}
+.LBB{825}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{826}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{827}
+	cmpq	%r9, %r8
+	jae	.LBB{827}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{828}
+.LBB{829}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{827}
+.LBB{828}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{829}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{829}
+	jmp	.LBB{5}
+.LBB{830}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{831}
+	movq	%r15, %r12
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{832}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{833}:
+	movq	%r15, %r12
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{834}
+.LBB{835}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{826}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{836}
+	cmpq	%rdi, %rax
+	jae	.LBB{836}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{837}
+.LBB{838}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{836}
+.LBB{837}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{838}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{838}
+	jmp	.LBB{5}
+.LBB{839}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{840}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{177}
+	jmp	.LBB{176}
+.LBB{841}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{842}
+.LBB{843}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{62}
+	jmp	.LBB{817}
+.LBB{844}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{845}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{846}
+.Ltmp{847}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{848}:
+.LBB{846}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{62}
+.LBB{849}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{850}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{850}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{831}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{167}
+.LBB{851}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	%r15, %r12
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{852}
+.Ltmp{853}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{854}:
+.LBB{852}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{143}
+.LBB{855}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{856}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{857}
+.LBB{858}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{859}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{860}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{861}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{862}
+	cmpq	$-1, %rcx
+	je	.LBB{863}
+.LBB{862}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{864}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{842}
+	jmp	.LBB{371}
+.LBB{865}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{826}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{866}
+	cmpq	%r9, %r8
+	jae	.LBB{866}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{867}
+.LBB{868}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{866}
+.LBB{867}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{868}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{868}
+	jmp	.LBB{5}
+.LBB{869}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{870}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	%r15, %r12
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{871}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{872}
+.Ltmp{873}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{874}:
+.LBB{872}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{63}
+.LBB{875}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{876}
+	xorl	%edx, %edx
+	jmp	.LBB{877}
+.LBB{878}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{879}
+	cmpq	%rdi, %rax
+	jae	.LBB{879}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{880}
+.LBB{881}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{879}
+.LBB{880}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{881}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{881}
+	jmp	.LBB{5}
+.LBB{882}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{883}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{884}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{885}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{886}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{886}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{177}
+.LBB{887}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{62}
+	jmp	.LBB{63}
+.LBB{888}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{889}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{63}
+.LBB{62}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{63}
+.LBB{890}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	%r15, %r12
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{892}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{893}
+.LBB{894}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{167}
+.LBB{895}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	%r15, %r12
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{896}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	%r15, %r12
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{897}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{898}
+	cmpq	%r9, %r8
+	jae	.LBB{898}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{899}
+.LBB{900}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{898}
+.LBB{899}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{900}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{900}
+	jmp	.LBB{5}
+.LBB{901}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	%r15, %r12
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{902}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	%r15, %r12
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{142}
+	jmp	.LBB{834}
+.LBB{903}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	movq	%r15, %r12
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r13
+	jmp	.LBB{143}
+.LBB{904}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	%r15, %r12
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{905}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{906}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{907}
+	cmpq	$-1, %rcx
+	je	.LBB{908}
+.LBB{907}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{909}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{910}
+	jmp	.LBB{40}
+.LBB{911}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{912}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{913}:
+	movq	%r15, %r12
+	jmp	.LBB{142}
+.LBB{914}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{177}
+.LBB{915}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{916}
+	xorl	%edx, %edx
+	jmp	.LBB{917}
+.LBB{918}:
+	movq	%r15, %r12
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{919}
+.Ltmp{920}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{921}:
+.LBB{919}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{142}
+.LBB{922}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	%r15, %r12
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{923}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{817}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{63}
+.LBB{924}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	%r15, %r12
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{925}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{926}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	%r15, %r12
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{927}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movq	%r15, %r12
+	andq	%r15, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	movabsq	$72340172838076673, %rax
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{928}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{929}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{929}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{831}
+	movq	%r15, %r12
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{143}
+.LBB{930}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{931}
+.LBB{932}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{166}
+	jmp	.LBB{857}
+.LBB{933}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{934}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	%r15, %r12
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{142}
+	jmp	.LBB{143}
+.LBB{935}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	%r15, %r12
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{143}
+.LBB{142}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{143}
+.LBB{936}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{937}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{938}
+.Ltmp{939}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{940}:
+.LBB{938}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{166}
+.LBB{941}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{942}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{943}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{944}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{945}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{946}
+	cmpq	$-1, %rcx
+	je	.LBB{947}
+.LBB{946}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{948}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{931}
+	jmp	.LBB{371}
+.LBB{949}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{950}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{951}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{952}
+.Ltmp{953}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{954}:
+.LBB{952}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{167}
+.LBB{955}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{956}
+.LBB{957}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{176}
+	jmp	.LBB{893}
+.LBB{958}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{959}
+	xorl	%edx, %edx
+	jmp	.LBB{960}
+.LBB{961}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{962}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{826}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{963}
+	cmpq	%rdi, %rax
+	jae	.LBB{963}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{964}
+.LBB{965}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{963}
+.LBB{964}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{965}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{965}
+	jmp	.LBB{5}
+.LBB{966}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{967}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	%r15, %r12
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{968}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{969}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{831}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{970}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{971}
+.Ltmp{972}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{973}:
+.LBB{971}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{176}
+.LBB{974}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{166}
+	jmp	.LBB{167}
+.LBB{975}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{167}
+.LBB{166}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{167}
+.LBB{976}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{977}:
+	movq	%r15, %r12
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{834}:
+	movq	%rbp, %r13
+	addq	$3, %r13
+	jmp	.LBB{143}
+.LBB{978}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{979}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{980}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{981}
+	cmpq	$-1, %rcx
+	je	.LBB{982}
+.LBB{981}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{983}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{956}
+	jmp	.LBB{371}
+.LBB{984}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{985}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{986}
+.Ltmp{987}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{988}:
+.LBB{986}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{177}
+.LBB{989}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{990}
+	xorl	%edx, %edx
+	jmp	.LBB{991}
+.LBB{992}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{260}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{261}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{993}
+	cmpq	%rdi, %rax
+	jae	.LBB{993}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{994}
+.LBB{995}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{993}
+.LBB{994}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{995}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{995}
+	jmp	.LBB{5}
+.LBB{996}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{997}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	%r15, %r12
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{998}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{999}:
+	movzbl	%r14b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{242}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{1000}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{176}
+	jmp	.LBB{177}
+.LBB{1001}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{177}
+.LBB{176}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{177}
+.LBB{1002}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{1003}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{857}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{167}
+.LBB{1004}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{1005}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{1006}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{893}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{177}
+.LBB{1007}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{1008}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{44}
+	jmp	.LBB{43}
+.LBB{1009}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1011}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1011}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{44}
+.LBB{1012}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1013}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1014}
+.LBB{1015}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{56}
+	jmp	.LBB{55}
+.LBB{1016}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1018}
+	cmpq	%r9, %r8
+	jae	.LBB{1018}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1019}
+.LBB{1020}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1018}
+.LBB{1019}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1020}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1020}
+	jmp	.LBB{5}
+.LBB{1021}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{77}
+	jmp	.LBB{76}
+.LBB{1022}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{44}
+.LBB{1023}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{111}
+	jmp	.LBB{110}
+.LBB{1024}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1025}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1025}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{56}
+.LBB{1026}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1027}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{193}
+	jmp	.LBB{192}
+.LBB{1028}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1029}
+.LBB{1030}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1031}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{197}
+	jmp	.LBB{196}
+.LBB{1032}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1033}
+	cmpq	%r9, %r8
+	jae	.LBB{1033}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1034}
+.LBB{1035}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1033}
+.LBB{1034}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1035}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1035}
+	jmp	.LBB{5}
+.LBB{1036}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1037}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1037}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{77}
+.LBB{1038}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1039}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1040}
+.LBB{1041}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1042}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1043}
+	cmpq	%r9, %r8
+	jae	.LBB{1043}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1044}
+.LBB{1045}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1043}
+.LBB{1044}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1045}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1045}
+	jmp	.LBB{5}
+.LBB{1046}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1047}
+.LBB{1048}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{43}
+	jmp	.LBB{1014}
+.LBB{1049}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1050}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{56}
+.LBB{1051}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1052}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1052}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{111}
+.LBB{1053}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1054}
+.Ltmp{1055}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1056}:
+.LBB{1054}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{43}
+.LBB{1057}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1058}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1059}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1059}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{193}
+.LBB{1060}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1061}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1062}
+.LBB{1063}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1064}
+.LBB{1065}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1066}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1067}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1068}
+	cmpq	%r9, %r8
+	jae	.LBB{1068}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1069}
+.LBB{1070}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1068}
+.LBB{1069}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1070}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1070}
+	jmp	.LBB{5}
+.LBB{1071}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1072}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1072}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{197}
+.LBB{1073}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1074}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1075}
+	cmpq	$-1, %rcx
+	je	.LBB{1076}
+.LBB{1075}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1077}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1047}
+	jmp	.LBB{40}
+.LBB{1078}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1079}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1080}
+	cmpq	%r9, %r8
+	jae	.LBB{1080}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1081}
+.LBB{1082}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1080}
+.LBB{1081}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1082}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1082}
+	jmp	.LBB{5}
+.LBB{1083}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{77}
+.LBB{1084}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1086}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1087}
+.LBB{1088}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1089}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1090}
+.Ltmp{1091}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1092}:
+.LBB{1090}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{44}
+.LBB{1093}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1094}
+	xorl	%edx, %edx
+	jmp	.LBB{1095}
+.LBB{1096}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1097}
+	cmpq	%r9, %r8
+	jae	.LBB{1097}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1098}
+.LBB{1099}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1097}
+.LBB{1098}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1099}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1099}
+	jmp	.LBB{5}
+.LBB{1100}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1102}
+	cmpq	%rdi, %rax
+	jae	.LBB{1102}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1103}
+.LBB{1104}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1102}
+.LBB{1103}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1104}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1104}
+	jmp	.LBB{5}
+.LBB{1105}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1106}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1107}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1108}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{43}
+	jmp	.LBB{44}
+.LBB{1109}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{44}
+.LBB{43}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{44}
+.LBB{1110}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{111}
+.LBB{1111}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1112}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1113}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{193}
+.LBB{1114}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1115}
+.LBB{1116}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{55}
+	jmp	.LBB{1029}
+.LBB{1117}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1118}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r14
+	jmp	.LBB{197}
+.LBB{1119}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{1120}
+.Ltmp{1121}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1122}:
+.LBB{1120}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{55}
+.LBB{1123}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1124}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1125}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1126}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1127}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1128}
+.LBB{1129}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{76}
+	jmp	.LBB{1040}
+.LBB{1130}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1131}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1132}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1133}
+	cmpq	$-1, %rcx
+	je	.LBB{1134}
+.LBB{1133}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1135}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1115}
+	jmp	.LBB{52}
+.LBB{1136}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1137}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1138}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1139}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{1140}
+.Ltmp{1141}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1142}:
+.LBB{1140}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{76}
+.LBB{1143}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1144}
+.Ltmp{1145}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1146}:
+.LBB{1144}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{56}
+.LBB{1147}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1148}
+	xorl	%edx, %edx
+	jmp	.LBB{1149}
+.LBB{1150}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1151}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1152}
+	cmpq	%rdi, %rax
+	jae	.LBB{1152}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1153}
+.LBB{1154}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1152}
+.LBB{1153}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1154}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1154}
+	jmp	.LBB{5}
+.LBB{1155}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1014}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{44}
+.LBB{1156}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1157}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1158}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1159}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1160}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1161}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1162}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1163}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1164}
+	cmpq	$-1, %rcx
+	je	.LBB{1165}
+.LBB{1164}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1166}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1128}
+	jmp	.LBB{52}
+.LBB{1167}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1168}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{55}
+	jmp	.LBB{56}
+.LBB{1169}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{56}
+.LBB{55}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{56}
+.LBB{1170}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1171}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1172}
+.LBB{1173}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{110}
+	jmp	.LBB{1062}
+.LBB{1174}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1175}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1176}
+.LBB{1177}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{192}
+	jmp	.LBB{1064}
+.LBB{1178}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1179}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1180}
+.Ltmp{1181}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1182}:
+.LBB{1180}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{77}
+.LBB{1183}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{1184}
+.Ltmp{1185}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1186}:
+.LBB{1184}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{110}
+.LBB{1187}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1188}
+	xorl	%edx, %edx
+	jmp	.LBB{1189}
+.LBB{1190}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{281}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1191}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{1192}
+.Ltmp{1193}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1194}:
+.LBB{1192}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{192}
+.LBB{1195}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1196}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1197}
+	cmpq	%rdi, %rax
+	jae	.LBB{1197}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1198}
+.LBB{1199}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1197}
+.LBB{1198}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1199}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1199}
+	jmp	.LBB{5}
+.LBB{1200}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1201}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1202}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1203}
+.LBB{1204}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1205}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{196}
+	jmp	.LBB{1087}
+.LBB{1206}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1207}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1208}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1209}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{76}
+	jmp	.LBB{77}
+.LBB{1210}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{77}
+.LBB{76}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{77}
+.LBB{1211}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1212}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1213}
+	cmpq	$-1, %rcx
+	je	.LBB{1214}
+.LBB{1213}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1215}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1172}
+	jmp	.LBB{52}
+.LBB{1216}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1217}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1218}:
+	movq	208(%rsp), %r12
+	cmpq	$1024, %r12
+	je	.LBB{5}
+	cmpq	192(%rsp), %r12
+	jne	.LBB{1219}
+.Ltmp{1220}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1221}:
+.LBB{1219}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r12,8)
+	incq	%r12
+	movq	%r12, 208(%rsp)
+	jmp	.LBB{196}
+.LBB{1222}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1223}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1224}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1225}
+	cmpq	$-1, %rcx
+	je	.LBB{1226}
+.LBB{1225}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1227}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{52}
+.LBB{1228}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1229}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1230}
+.Ltmp{1231}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1232}:
+.LBB{1230}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{111}
+.LBB{1233}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1234}
+	xorl	%edx, %edx
+	jmp	.LBB{1235}
+.LBB{1236}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1237}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1238}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1239}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1240}
+.Ltmp{1241}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1242}:
+.LBB{1240}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{193}
+.LBB{1243}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1244}
+	xorl	%edx, %edx
+	jmp	.LBB{1245}
+.LBB{1246}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1247}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1248}
+	cmpq	$-1, %rcx
+	je	.LBB{1249}
+.LBB{1248}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1250}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1203}
+	jmp	.LBB{119}
+.LBB{1251}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1252}
+	cmpq	%rdi, %rax
+	jae	.LBB{1252}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1253}
+.LBB{1254}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1252}
+.LBB{1253}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1254}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1254}
+	jmp	.LBB{5}
+.LBB{1255}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1256}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1257}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1258}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1259}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1260}
+	cmpq	%rdi, %rax
+	jae	.LBB{1260}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1261}
+.LBB{1262}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1260}
+.LBB{1261}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1262}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1262}
+	jmp	.LBB{5}
+.LBB{1263}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1264}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1265}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1266}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{110}
+	jmp	.LBB{111}
+.LBB{1267}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{111}
+.LBB{110}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{111}
+.LBB{1268}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1269}
+.Ltmp{1270}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1271}:
+.LBB{1269}:
+	movq	224(%rsp), %rax
+	movq	%r15, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{197}
+.LBB{1272}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{192}
+	jmp	.LBB{193}
+.LBB{1273}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1029}:
+	addq	$3, %rbp
+	movq	%rbp, %r14
+	jmp	.LBB{56}
+.LBB{1274}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{193}
+.LBB{192}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{193}
+.LBB{1275}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1276}
+	xorl	%edx, %edx
+	jmp	.LBB{1277}
+.LBB{1278}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%r15b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1279}
+	cmpq	%rdi, %rax
+	jae	.LBB{1279}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1280}
+.LBB{1281}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1279}
+.LBB{1280}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1281}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1281}
+	jmp	.LBB{5}
+.LBB{1282}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1283}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1284}:
+	movzbl	%r15b, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%ebx, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1285}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1286}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{196}
+	jmp	.LBB{197}
+.LBB{1287}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{197}
+.LBB{196}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r14
+	jmp	.LBB{197}
+.LBB{1288}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1289}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1040}:
+	addq	$3, %rbp
+	movq	%rbp, %r14
+	jmp	.LBB{77}
+.LBB{1290}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1291}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1292}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1293}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1062}:
+	addq	$3, %rbp
+	movq	%rbp, %r14
+	jmp	.LBB{111}
+.LBB{1294}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1295}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1064}:
+	addq	$3, %rbp
+	movq	%rbp, %r14
+	jmp	.LBB{193}
+.LBB{1296}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1087}:
+	addq	$3, %rbp
+	movq	%rbp, %r14
+	jmp	.LBB{197}
+.LBB{1297}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1298}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1299}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1300}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{124}
+	jmp	.LBB{123}
+.LBB{1301}:
+	movzbl	%cl, %ecx
+	leaq	.Lanon{8}(%rip), %rsi
+	cmpl	$16, %ecx
+	jae	.LBB{1302}
+	movl	%eax, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1303}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1303}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1304}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{124}
+.LBB{1305}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1306}:
+	movl	%eax, %esi
+	andb	$15, %sil
+	movl	%ecx, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%sil, %esi
+	movq	16(%rsp,%rsi,8), %rsi
+	imulq	16(%rsp,%r8,8), %rsi
+	movl	%edi, %r8d
+	andl	$15, %r8d
+	movq	%rsi, 16(%rsp,%r8,8)
+	shrb	$4, %al
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	16(%rsp,%rcx,8), %rcx
+	movzbl	%al, %eax
+	subq	16(%rsp,%rax,8), %rcx
+	shrb	$4, %dil
+	movzbl	%dil, %eax
+	movq	%rcx, 16(%rsp,%rax,8)
+	jmp	.LBB{1307}
+.LBB{1308}:
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %r8d
+	jae	.LBB{1309}
+	movzbl	%cl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1310}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1311}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1312}
+	cmpq	%r9, %r8
+	jae	.LBB{1312}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1313}
+.LBB{1314}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1312}
+.LBB{1313}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1314}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1314}
+	jmp	.LBB{5}
+.LBB{1315}:
+	testq	%r11, %r11
+	je	.LBB{5}
+	leaq	-2(%r9), %rax
+	movq	%rax, 208(%rsp)
+	movq	-16(%r10,%r9,8), %r12
+	jmp	.LBB{124}
+.LBB{1316}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rcx
+	subq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1318}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1319}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %eax
+	movzbl	%cl, %ecx
+	orl	%eax, %ecx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1320}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{123}
+	jmp	.LBB{1307}
+.LBB{1321}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1322}:
+	cmpq	$1024, %r11
+	je	.LBB{5}
+	movq	%r12, -8(%r10,%r9,8)
+	movq	%r9, 208(%rsp)
+	jmp	.LBB{123}
+.LBB{1323}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1324}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rcx
+	cmpq	16(%rsp,%rax,8), %rcx
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1325}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	movq	16(%rsp,%rax,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1326}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1327}
+	cmpq	$-1, %rcx
+	je	.LBB{1328}
+.LBB{1327}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1329}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1330}
+	jmp	.LBB{40}
+.LBB{1331}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1332}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1333}
+.Ltmp{1334}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1335}:
+.LBB{1333}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{124}
+.LBB{1336}:
+	movzbl	%cl, %edi
+	cmpb	$15, %cl
+	ja	.LBB{38}
+	movl	%eax, %eax
+	cmpl	$268435455, %esi
+	ja	.LBB{1317}
+	movq	16(%rsp,%rax,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1337}
+	xorl	%edx, %edx
+	jmp	.LBB{1338}
+.LBB{1339}:
+	leaq	.Lanon{8}(%rip), %rdi
+	cmpl	$16, %r8d
+	jae	.LBB{1340}
+	movzbl	%cl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1341}
+	movl	%eax, %ecx
+	cmpl	$268435455, %esi
+	ja	.LBB{1342}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1343}
+	cmpq	%rdi, %rax
+	jae	.LBB{1343}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1344}
+.LBB{1345}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1343}
+.LBB{1344}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1345}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1345}
+	jmp	.LBB{5}
+.LBB{1346}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1347}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1348}:
+	movzbl	%cl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	jae	.LBB{1310}
+	movl	%eax, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{1309}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1349}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{123}
+	jmp	.LBB{124}
+.LBB{1350}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{124}
+.LBB{123}:
+	movzbl	%cl, %ecx
+	shll	$8, %eax
+	orl	%ecx, %eax
+	movq	%rax, %r12
+	jmp	.LBB{124}
+.LBB{1351}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1352}:
+	movl	%eax, %esi
+	andb	$15, %sil
+	movl	%ecx, %r8d
+	andb	$15, %r8b
+	movzbl	%r8b, %r8d
+	movzbl	%sil, %esi
+	movq	16(%rsp,%rsi,8), %rsi
+	addq	16(%rsp,%r8,8), %rsi
+	movl	%edi, %r8d
+	andl	$15, %r8d
+	movq	%rsi, 16(%rsp,%r8,8)
+	shrb	$4, %al
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rcx,8), %rax
+	shrb	$4, %dil
+	movzbl	%dil, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+.LBB{1307}:
+	addq	$2, %rdx
+	movq	%rdx, %r12
+	jmp	.LBB{124}
+.LBB{1353}:
+	movzbl	%cl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1354}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{71}
+	jmp	.LBB{70}
+.LBB{1355}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1356}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1356}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{71}
+.LBB{1357}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1358}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1359}
+.LBB{1360}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1361}
+	cmpq	%r9, %r8
+	jae	.LBB{1361}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1362}
+.LBB{1363}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1361}
+.LBB{1362}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1363}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1363}
+	jmp	.LBB{5}
+.LBB{1364}:
+	movq	%r15, 208(%rsp)
+	movq	200(%rsp), %rax
+	movq	(%rax,%r15,8), %r13
+	jmp	.LBB{71}
+.LBB{1365}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1366}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1367}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1368}
+.LBB{1369}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{70}
+	jmp	.LBB{1359}
+.LBB{1370}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1371}:
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rdx
+	jne	.LBB{1372}
+.Ltmp{1373}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1374}:
+.LBB{1372}:
+	movq	200(%rsp), %rax
+	movq	%r13, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{70}
+.LBB{1375}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1376}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1377}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1378}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1379}
+	cmpq	$-1, %rcx
+	je	.LBB{1380}
+.LBB{1379}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1381}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1368}
+	jmp	.LBB{40}
+.LBB{1382}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1383}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1384}
+.Ltmp{1385}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1386}:
+.LBB{1384}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{71}
+.LBB{1387}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1388}
+	xorl	%edx, %edx
+	jmp	.LBB{1389}
+.LBB{1390}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1391}
+	cmpq	%rdi, %rax
+	jae	.LBB{1391}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1392}
+.LBB{1393}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1391}
+.LBB{1392}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1393}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1393}
+	jmp	.LBB{5}
+.LBB{1394}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1395}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1396}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1397}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{70}
+	jmp	.LBB{71}
+.LBB{1398}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{71}
+.LBB{70}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r13
+	jmp	.LBB{71}
+.LBB{1399}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1400}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1359}:
+	addq	$2, %r12
+	movq	%r12, %r13
+	jmp	.LBB{71}
+.LBB{1401}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1402}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{49}
+	jmp	.LBB{48}
+.LBB{1403}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1404}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1404}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{49}
+.LBB{1405}:
+	testq	%rdi, %rdi
+	je	.LBB{5}
+	leaq	-2(%rdx), %rax
+	movq	%rax, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	-16(%rsi,%rdx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1406}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1407}
+.LBB{1408}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1409}
+	cmpq	%r9, %r8
+	jae	.LBB{1409}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1410}
+.LBB{1411}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1409}
+.LBB{1410}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1411}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1411}
+	jmp	.LBB{5}
+.LBB{1412}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{49}
+.LBB{1413}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1414}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1415}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1416}
+.LBB{1417}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{48}
+	jmp	.LBB{1407}
+.LBB{1418}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1419}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1420}
+.Ltmp{1421}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1422}:
+.LBB{1420}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{48}
+.LBB{1423}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1424}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1425}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1426}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1427}
+	cmpq	$-1, %rcx
+	je	.LBB{1428}
+.LBB{1427}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1429}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1416}
+	jmp	.LBB{40}
+.LBB{1430}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1432}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$1024, %rdi
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, -8(%rsi,%rdx,8)
+	movq	%rdx, 232(%rsp)
+	jmp	.LBB{49}
+.LBB{1433}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1434}
+	xorl	%edx, %edx
+	jmp	.LBB{1435}
+.LBB{1436}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1437}
+	cmpq	%rdi, %rax
+	jae	.LBB{1437}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1438}
+.LBB{1439}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1437}
+.LBB{1438}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1439}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1439}
+	jmp	.LBB{5}
+.LBB{1440}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1441}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1442}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1443}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{48}
+	jmp	.LBB{49}
+.LBB{1444}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{49}
+.LBB{48}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{49}
+.LBB{1445}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1446}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1407}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{49}
+.LBB{1447}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1448}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{101}
+	jmp	.LBB{100}
+.LBB{1449}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1450}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1450}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{101}
+.LBB{1451}:
+	movq	%r15, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rax
+	movq	(%rax,%r15,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1452}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1453}
+.LBB{1454}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1455}
+	cmpq	%r9, %r8
+	jae	.LBB{1455}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1456}
+.LBB{1457}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1455}
+.LBB{1456}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1457}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1457}
+	jmp	.LBB{5}
+.LBB{1458}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{101}
+.LBB{1459}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1460}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1461}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1462}
+.LBB{1463}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{100}
+	jmp	.LBB{1453}
+.LBB{1464}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1465}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1466}
+.Ltmp{1467}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1468}:
+.LBB{1466}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{100}
+.LBB{1469}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1470}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1471}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1472}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1473}
+	cmpq	$-1, %rcx
+	je	.LBB{1474}
+.LBB{1473}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1475}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1462}
+	jmp	.LBB{40}
+.LBB{1476}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1477}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %rbx
+	cmpq	216(%rsp), %rdx
+	jne	.LBB{1478}
+.Ltmp{1479}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1480}:
+.LBB{1478}:
+	movq	224(%rsp), %rax
+	movq	%rbx, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 232(%rsp)
+	jmp	.LBB{101}
+.LBB{1481}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1482}
+	xorl	%edx, %edx
+	jmp	.LBB{1483}
+.LBB{1484}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1485}
+	cmpq	%rdi, %rax
+	jae	.LBB{1485}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1486}
+.LBB{1487}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1485}
+.LBB{1486}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1487}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1487}
+	jmp	.LBB{5}
+.LBB{1488}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1489}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1490}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1491}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{100}
+	jmp	.LBB{101}
+.LBB{1492}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{101}
+.LBB{100}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{101}
+.LBB{1493}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1494}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1453}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{101}
+.LBB{1495}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1496}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{30}
+	jmp	.LBB{29}
+.LBB{1497}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1498}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1498}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{30}
+.LBB{1499}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1500}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1501}
+.LBB{1502}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1503}
+	cmpq	%r9, %r8
+	jae	.LBB{1503}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1504}
+.LBB{1505}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1503}
+.LBB{1504}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1505}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1505}
+	jmp	.LBB{5}
+.LBB{1506}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{30}
+.LBB{1507}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1508}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{129}
+	jmp	.LBB{128}
+.LBB{1509}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1510}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1511}
+.LBB{1512}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{29}
+	jmp	.LBB{1501}
+.LBB{1513}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1514}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1515}
+.Ltmp{1516}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1517}:
+.LBB{1515}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{29}
+.LBB{1518}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1519}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1520}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1521}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1522}
+	cmpq	$-1, %rcx
+	je	.LBB{1523}
+.LBB{1522}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1524}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1511}
+	jmp	.LBB{217}
+.LBB{1525}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1526}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1527}
+.Ltmp{1528}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1529}:
+.LBB{1527}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{30}
+.LBB{1530}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1531}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1531}:
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rcx, (%rdi,%rax,8)
+	jmp	.LBB{129}
+.LBB{1532}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1533}
+	xorl	%edx, %edx
+	jmp	.LBB{1534}
+.LBB{1535}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1536}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1537}
+	cmpq	%rdi, %rax
+	jae	.LBB{1537}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1538}
+.LBB{1539}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1537}
+.LBB{1538}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1539}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1539}
+	jmp	.LBB{5}
+.LBB{1540}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1541}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1542}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1543}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1544}
+.LBB{1545}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{29}
+	jmp	.LBB{30}
+.LBB{1546}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{30}
+.LBB{29}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{30}
+.LBB{1547}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1548}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r10
+	addq	%r8, %r10
+	setb	%r9b
+	cmpq	%rsi, %r10
+	seta	%r11b
+	orb	%r9b, %r11b
+	jne	.LBB{5}
+	movq	184(%rsp), %r9
+	testq	%r9, %r9
+	je	.LBB{1549}
+	cmpq	%r10, %r8
+	jae	.LBB{1549}
+	movq	176(%rsp), %r10
+	movq	%r8, %r11
+	jmp	.LBB{1550}
+.LBB{1551}:
+	incq	%r11
+	decq	%rax
+	je	.LBB{1549}
+.LBB{1550}:
+	movq	%r11, %rbx
+	shrq	$6, %rbx
+	cmpq	%r9, %rbx
+	jae	.LBB{1551}
+	movq	16(%r10,%rbx,8), %rbx
+	btq	%r11, %rbx
+	jae	.LBB{1551}
+	jmp	.LBB{5}
+.LBB{1552}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{129}
+.LBB{1553}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1554}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1501}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{30}
+.LBB{1555}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1556}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1557}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1558}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1559}
+.LBB{1560}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{128}
+	jmp	.LBB{1544}
+.LBB{1561}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1562}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1563}
+.Ltmp{1564}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1565}:
+.LBB{1563}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{128}
+.LBB{1566}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1567}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1568}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1569}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1570}
+	cmpq	$-1, %rcx
+	je	.LBB{1571}
+.LBB{1570}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1572}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1559}
+	jmp	.LBB{217}
+.LBB{1573}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1574}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1575}
+.Ltmp{1576}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1577}:
+.LBB{1575}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{129}
+.LBB{1578}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1579}
+	xorl	%edx, %edx
+	jmp	.LBB{1580}
+.LBB{1581}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %r9d
+	cmpl	$268435455, %eax
+	ja	.LBB{1548}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%r9,8), %rax
+	movq	%rax, %r8
+	addq	%rdx, %r8
+	jb	.LBB{5}
+	cmpq	%rsi, %r8
+	ja	.LBB{5}
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1582}
+	cmpq	%r8, %rdx
+	jae	.LBB{1582}
+	movq	176(%rsp), %r8
+	movq	%rax, %r9
+	movq	%rdx, %r10
+	jmp	.LBB{1583}
+.LBB{1584}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{1582}
+.LBB{1583}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rsi, %r11
+	jae	.LBB{1584}
+	movq	16(%r8,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1584}
+	jmp	.LBB{5}
+.LBB{1585}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1586}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1587}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	(%rdi,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1588}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{128}
+	jmp	.LBB{129}
+.LBB{1589}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{129}
+.LBB{128}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{129}
+.LBB{1590}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1591}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1544}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{129}
+.LBB{1592}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1593}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{183}
+	jmp	.LBB{182}
+.LBB{1594}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{1595}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1595}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{183}
+.LBB{1596}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1597}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1598}
+.LBB{1599}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1600}
+	cmpq	%r9, %r8
+	jae	.LBB{1600}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1601}
+.LBB{1602}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1600}
+.LBB{1601}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1602}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1602}
+	jmp	.LBB{5}
+.LBB{208}:
+	testq	%rcx, %rcx
+	je	.LBB{36}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1603}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1603}
+	jmp	.LBB{36}
+.LBB{1604}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r12
+	jmp	.LBB{183}
+.LBB{1605}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1606}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1607}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1608}
+.LBB{1609}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{182}
+	jmp	.LBB{1598}
+.LBB{1610}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1611}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1612}
+.Ltmp{1613}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1614}:
+.LBB{1612}:
+	movq	200(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{182}
+.LBB{1615}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1616}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1617}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1618}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1619}
+	cmpq	$-1, %rcx
+	je	.LBB{1620}
+.LBB{1619}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1621}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1608}
+	jmp	.LBB{217}
+.LBB{1622}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1623}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1624}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1625}
+.Ltmp{1626}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1627}:
+.LBB{1625}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{183}
+.LBB{1628}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1629}
+	xorl	%edx, %edx
+	jmp	.LBB{1630}
+.LBB{1631}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1632}
+	cmpq	%rdi, %rax
+	jae	.LBB{1632}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1633}
+.LBB{1634}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1632}
+.LBB{1633}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1634}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1634}
+	jmp	.LBB{5}
+.LBB{1635}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1636}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1623}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1637}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1638}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{182}
+	jmp	.LBB{183}
+.LBB{1639}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{183}
+.LBB{182}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r12
+	jmp	.LBB{183}
+.LBB{1640}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1623}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1641}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1598}:
+	movq	%rbp, %r12
+	addq	$3, %r12
+	jmp	.LBB{183}
+.LBB{1642}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1623}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{309}:
+	testq	%rcx, %rcx
+	je	.LBB{137}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1643}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1643}
+	jmp	.LBB{137}
+.LBB{344}:
+	testq	%rcx, %rcx
+	je	.LBB{172}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1644}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1644}
+	jmp	.LBB{172}
+.LBB{405}:
+	testq	%rcx, %rcx
+	je	.LBB{18}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1645}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1645}
+	jmp	.LBB{18}
+.LBB{623}:
+	testq	%rcx, %rcx
+	je	.LBB{82}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1646}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1646}
+	jmp	.LBB{82}
+.LBB{641}:
+	testq	%rcx, %rcx
+	je	.LBB{13}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1647}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1647}
+	jmp	.LBB{13}
+.LBB{645}:
+	testq	%rcx, %rcx
+	je	.LBB{88}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1648}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1648}
+	jmp	.LBB{88}
+.LBB{664}:
+	testq	%rcx, %rcx
+	je	.LBB{93}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1649}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1649}
+	jmp	.LBB{93}
+.LBB{708}:
+	testq	%rcx, %rcx
+	je	.LBB{106}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1650}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1650}
+	jmp	.LBB{106}
+.LBB{756}:
+	testq	%rcx, %rcx
+	je	.LBB{152}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1651}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1651}
+	jmp	.LBB{152}
+.LBB{770}:
+	testq	%rcx, %rcx
+	je	.LBB{157}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1652}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1652}
+	jmp	.LBB{157}
+.LBB{774}:
+	testq	%rcx, %rcx
+	je	.LBB{162}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1653}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1653}
+	jmp	.LBB{162}
+.LBB{836}:
+	movq	%r15, %r12
+	testq	%rcx, %rcx
+	je	.LBB{143}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1654}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1654}
+	jmp	.LBB{143}
+.LBB{879}:
+	testq	%rcx, %rcx
+	je	.LBB{63}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1655}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1655}
+	jmp	.LBB{63}
+.LBB{963}:
+	testq	%rcx, %rcx
+	je	.LBB{167}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1656}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1656}
+	jmp	.LBB{167}
+.LBB{993}:
+	testq	%rcx, %rcx
+	je	.LBB{177}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1657}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1657}
+	jmp	.LBB{177}
+.LBB{1102}:
+	testq	%rcx, %rcx
+	je	.LBB{44}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1658}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1658}
+	jmp	.LBB{44}
+.LBB{1152}:
+	testq	%rcx, %rcx
+	je	.LBB{56}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1659}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1659}
+	jmp	.LBB{56}
+.LBB{1197}:
+	testq	%rcx, %rcx
+	je	.LBB{77}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1660}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1660}
+	jmp	.LBB{77}
+.LBB{1252}:
+	testq	%rcx, %rcx
+	je	.LBB{111}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1661}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1661}
+	jmp	.LBB{111}
+.LBB{1260}:
+	testq	%rcx, %rcx
+	je	.LBB{193}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1662}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1662}
+	jmp	.LBB{193}
+.LBB{1279}:
+	testq	%rcx, %rcx
+	je	.LBB{197}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1663}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1663}
+	jmp	.LBB{197}
+.LBB{1343}:
+	testq	%rcx, %rcx
+	je	.LBB{124}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1664}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1664}
+	jmp	.LBB{124}
+.LBB{1391}:
+	testq	%rcx, %rcx
+	je	.LBB{71}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1665}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1665}
+	jmp	.LBB{71}
+.LBB{1437}:
+	testq	%rcx, %rcx
+	je	.LBB{49}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1666}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1666}
+	jmp	.LBB{49}
+.LBB{1485}:
+	testq	%rcx, %rcx
+	je	.LBB{101}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1667}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1667}
+	jmp	.LBB{101}
+.LBB{1537}:
+	testq	%rcx, %rcx
+	je	.LBB{30}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1668}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1668}
+	jmp	.LBB{30}
+.LBB{1582}:
+	testq	%rax, %rax
+	je	.LBB{129}
+	leaq	(%rdi,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1669}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1669}
+	jmp	.LBB{129}
+.LBB{1632}:
+	testq	%rcx, %rcx
+	je	.LBB{183}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1670}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1670}
+	jmp	.LBB{183}
+.LBB{248}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1671}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{249}
+.LBB{306}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1672}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{307}
+.LBB{340}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1673}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{341}
+.LBB{401}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1674}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{402}
+.LBB{605}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1675}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{606}
+.LBB{617}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1676}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{618}
+.LBB{620}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1677}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{621}
+.LBB{636}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1678}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{637}
+.LBB{701}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1679}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{702}
+.LBB{745}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1680}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{746}
+.LBB{760}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1681}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{761}
+.LBB{763}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1682}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{764}
+.LBB{876}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1683}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{877}
+.LBB{916}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1684}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{917}
+.LBB{959}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1685}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{960}
+.LBB{990}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1686}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{991}
+.LBB{1094}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1687}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1095}
+.LBB{1148}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1688}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1149}
+.LBB{1188}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1689}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1189}
+.LBB{1234}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1690}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1235}
+.LBB{1244}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1691}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1245}
+.LBB{1276}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1692}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1277}
+.LBB{1337}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1693}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1338}
+.LBB{1388}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1694}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1389}
+.LBB{1434}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1695}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1435}
+.LBB{1482}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1696}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1483}
+.LBB{1533}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1697}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1534}
+.LBB{1579}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1698}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1580}
+.LBB{201}:
+	movq	160(%rsp), %rdi
+.Ltmp{1699}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1700}:
+	jmp	.LBB{36}
+.LBB{262}:
+	movq	160(%rsp), %rdi
+.Ltmp{1701}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1702}:
+	jmp	.LBB{137}
+.LBB{276}:
+	movq	160(%rsp), %rdi
+.Ltmp{1703}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1704}:
+	jmp	.LBB{172}
+.LBB{365}:
+	movq	160(%rsp), %rdi
+.Ltmp{1705}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1706}:
+	jmp	.LBB{18}
+.LBB{444}:
+	movq	160(%rsp), %rdi
+.Ltmp{1707}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1708}:
+	jmp	.LBB{82}
+.LBB{449}:
+	movq	160(%rsp), %rdi
+.Ltmp{1709}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1710}:
+	jmp	.LBB{13}
+.LBB{456}:
+	movq	160(%rsp), %rdi
+.Ltmp{1711}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1712}:
+	jmp	.LBB{88}
+.LBB{462}:
+	movq	160(%rsp), %rdi
+.Ltmp{1713}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1714}:
+	jmp	.LBB{93}
+.LBB{469}:
+	movq	160(%rsp), %rdi
+.Ltmp{1715}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1716}:
+	jmp	.LBB{106}
+.LBB{494}:
+	movq	160(%rsp), %rdi
+.Ltmp{1717}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1718}:
+	jmp	.LBB{152}
+.LBB{498}:
+	movq	160(%rsp), %rdi
+.Ltmp{1719}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1720}:
+	jmp	.LBB{157}
+.LBB{502}:
+	movq	160(%rsp), %rdi
+.Ltmp{1721}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1722}:
+	jmp	.LBB{162}
+.LBB{819}:
+	movq	160(%rsp), %rdi
+.Ltmp{1723}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1724}:
+	jmp	.LBB{63}
+.LBB{827}:
+	movq	%r15, %r12
+	movq	160(%rsp), %rdi
+.Ltmp{1725}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1726}:
+	jmp	.LBB{143}
+.LBB{866}:
+	movq	160(%rsp), %rdi
+.Ltmp{1727}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1728}:
+	jmp	.LBB{167}
+.LBB{898}:
+	movq	160(%rsp), %rdi
+.Ltmp{1729}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1730}:
+	jmp	.LBB{177}
+.LBB{1018}:
+	movq	160(%rsp), %rdi
+.Ltmp{1731}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1732}:
+	jmp	.LBB{44}
+.LBB{1033}:
+	movq	160(%rsp), %rdi
+.Ltmp{1733}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1734}:
+	jmp	.LBB{56}
+.LBB{1043}:
+	movq	160(%rsp), %rdi
+.Ltmp{1735}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1736}:
+	jmp	.LBB{77}
+.LBB{1068}:
+	movq	160(%rsp), %rdi
+.Ltmp{1737}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1738}:
+	jmp	.LBB{111}
+.LBB{1080}:
+	movq	160(%rsp), %rdi
+.Ltmp{1739}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1740}:
+	jmp	.LBB{193}
+.LBB{1097}:
+	movq	160(%rsp), %rdi
+.Ltmp{1741}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1742}:
+	jmp	.LBB{197}
+.LBB{1743}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{189}
+	jmp	.LBB{188}
+.LBB{1744}:
+	movzbl	%r15b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%r14, %rcx
+	jae	.LBB{1745}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1745}:
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%r8,8), %rcx
+	movq	%rcx, (%r12,%rax,8)
+	jmp	.LBB{189}
+.LBB{1746}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1747}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1748}
+.LBB{1749}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r15b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	setb	%sil
+	cmpq	%r13, %rcx
+	seta	%dil
+	orb	%sil, %dil
+	jne	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %rsi
+	addq	%r8, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r9b
+	orb	%dil, %r9b
+	jne	.LBB{5}
+	testq	%r14, %r14
+	je	.LBB{1750}
+	cmpq	%rsi, %r8
+	jae	.LBB{1750}
+	movq	176(%rsp), %rsi
+	movq	%r8, %rdi
+	jmp	.LBB{1751}
+.LBB{1752}:
+	incq	%rdi
+	decq	%rax
+	je	.LBB{1750}
+.LBB{1751}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%r14, %r9
+	jae	.LBB{1752}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1752}
+	jmp	.LBB{5}
+.LBB{1753}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 328(%rsp)
+	jmp	.LBB{189}
+.LBB{1754}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1755}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1756}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r15b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1757}
+.LBB{1758}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{188}
+	jmp	.LBB{1748}
+.LBB{1759}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1760}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1761}
+.Ltmp{1762}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1763}:
+.LBB{1761}:
+	movq	200(%rsp), %rax
+	movq	328(%rsp), %rcx
+	movq	%rcx, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{188}
+.LBB{1764}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1765}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1766}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1767}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1768}
+	cmpq	$-1, %rcx
+	je	.LBB{1769}
+.LBB{1768}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1770}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1757}
+	jmp	.LBB{217}
+.LBB{1771}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1772}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1773}
+.Ltmp{1774}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1775}:
+.LBB{1773}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{189}
+.LBB{1776}:
+	movzbl	%r15b, %edi
+	cmpb	$15, %r15b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1777}
+	xorl	%edx, %edx
+	jmp	.LBB{1778}
+.LBB{1779}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r15b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%r8,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rsi
+	addq	%rdx, %rsi
+	setb	%dil
+	cmpq	%r13, %rsi
+	seta	%r8b
+	orb	%dil, %r8b
+	jne	.LBB{5}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%r14, %r14
+	je	.LBB{1780}
+	cmpq	%rsi, %rdx
+	jae	.LBB{1780}
+	movq	176(%rsp), %rsi
+	movq	%rax, %rdi
+	movq	%rdx, %r8
+	jmp	.LBB{1781}
+.LBB{1782}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{1780}
+.LBB{1781}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%r14, %r9
+	jae	.LBB{1782}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{1782}
+	jmp	.LBB{5}
+.LBB{1783}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1784}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1785}:
+	movzbl	%r15b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	%r13, %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	(%r12,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1786}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{188}
+	jmp	.LBB{189}
+.LBB{1787}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{189}
+.LBB{188}:
+	movzbl	%r15b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, 328(%rsp)
+	jmp	.LBB{189}
+.LBB{1788}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1789}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r15d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r15b
+	movzbl	%r15b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1748}:
+	addq	$3, %rbp
+	movq	%rbp, 328(%rsp)
+	jmp	.LBB{189}
+.LBB{1790}:
+	movzbl	%r15b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1431}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1629}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1791}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1630}
+.LBB{1312}:
+	movq	160(%rsp), %rdi
+.Ltmp{1792}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1793}:
+	jmp	.LBB{124}
+.LBB{1361}:
+	movq	160(%rsp), %rdi
+.Ltmp{1794}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1795}:
+	jmp	.LBB{71}
+.LBB{1409}:
+	movq	160(%rsp), %rdi
+.Ltmp{1796}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1797}:
+	jmp	.LBB{49}
+.LBB{1455}:
+	movq	160(%rsp), %rdi
+.Ltmp{1798}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1799}:
+	jmp	.LBB{101}
+.LBB{1503}:
+	movq	160(%rsp), %rdi
+.Ltmp{1800}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1801}:
+	jmp	.LBB{30}
+.LBB{1549}:
+.Ltmp{1802}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1803}:
+	jmp	.LBB{129}
+.LBB{1600}:
+	movq	160(%rsp), %rdi
+.Ltmp{1804}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1805}:
+	jmp	.LBB{183}
+.LBB{233}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{227}
+	jmp	.LBB{217}
+.LBB{294}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{283}
+	jmp	.LBB{119}
+.LBB{329}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{319}
+	jmp	.LBB{217}
+.LBB{390}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{375}
+	jmp	.LBB{371}
+.LBB{567}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{521}
+	jmp	.LBB{217}
+.LBB{573}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{525}
+	jmp	.LBB{52}
+.LBB{579}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{529}
+	jmp	.LBB{119}
+.LBB{593}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{533}
+	jmp	.LBB{119}
+.LBB{657}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{558}
+	jmp	.LBB{119}
+.LBB{717}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{651}
+	jmp	.LBB{119}
+.LBB{729}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{672}
+	jmp	.LBB{371}
+.LBB{734}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{676}
+	jmp	.LBB{371}
+.LBB{861}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{842}
+	jmp	.LBB{371}
+.LBB{906}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{910}
+	jmp	.LBB{40}
+.LBB{945}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{931}
+	jmp	.LBB{371}
+.LBB{980}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{956}
+	jmp	.LBB{371}
+.LBB{1074}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1047}
+	jmp	.LBB{40}
+.LBB{1132}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1115}
+	jmp	.LBB{52}
+.LBB{1163}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1128}
+	jmp	.LBB{52}
+.LBB{1212}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1172}
+	jmp	.LBB{52}
+.LBB{1224}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{52}
+.LBB{1247}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1203}
+	jmp	.LBB{119}
+.LBB{1326}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1330}
+	jmp	.LBB{40}
+.LBB{1378}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1368}
+	jmp	.LBB{40}
+.LBB{1426}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1416}
+	jmp	.LBB{40}
+.LBB{1472}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1462}
+	jmp	.LBB{40}
+.LBB{1521}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1511}
+	jmp	.LBB{217}
+.LBB{1569}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1559}
+	jmp	.LBB{217}
+.LBB{1618}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1608}
+	jmp	.LBB{217}
+.LBB{236}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{227}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{36}
+.LBB{297}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{283}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{137}
+.LBB{332}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{319}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{172}
+.LBB{393}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{375}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{18}
+.LBB{570}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{521}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{82}
+.LBB{576}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{525}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{13}
+.LBB{582}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{529}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{88}
+.LBB{596}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{533}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{93}
+.LBB{660}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{558}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{106}
+.LBB{720}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{651}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{152}
+.LBB{732}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{672}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{157}
+.LBB{737}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{676}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{162}
+.LBB{864}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{842}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{63}
+.LBB{909}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{910}:
+	movq	%r15, %r12
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{143}
+.LBB{948}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{931}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{167}
+.LBB{983}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{956}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{177}
+.LBB{1077}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{1047}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{44}
+.LBB{1135}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{1115}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{56}
+.LBB{1166}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{1128}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{77}
+.LBB{1215}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{1172}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{111}
+.LBB{1227}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{1176}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{193}
+.LBB{1250}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{1203}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{197}
+.LBB{1329}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{1330}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{124}
+.LBB{1381}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{1368}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{71}
+.LBB{1429}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{1416}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{49}
+.LBB{1475}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{1462}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{101}
+.LBB{1524}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1511}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{30}
+.LBB{1572}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1559}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{129}
+.LBB{1777}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1806}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1778}
+.LBB{1621}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1608}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{183}
+.LBB{1780}:
+	testq	%rax, %rax
+	je	.LBB{189}
+	leaq	(%r12,%rdx,8), %rdx
+	shlq	$3, %rax
+	xorl	%esi, %esi
+.LBB{1807}:
+	movq	%rcx, (%rdx,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rax
+	jne	.LBB{1807}
+	jmp	.LBB{189}
+.LBB{1671}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{249}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{36}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{1808}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1809}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1810}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1811}
+	cmpq	$-1, %rcx
+	je	.LBB{1812}
+.LBB{1811}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1813}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1814}
+	jmp	.LBB{217}
+.LBB{1815}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1816}
+.Ltmp{1817}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1818}:
+	jmp	.LBB{1816}
+.LBB{1819}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1820}
+.Ltmp{1821}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1822}:
+	jmp	.LBB{1820}
+.LBB{1823}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1824}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1825}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1826}
+	xorl	%edx, %edx
+	jmp	.LBB{1827}
+.LBB{1828}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1829}
+	cmpq	%rdi, %rax
+	jae	.LBB{1829}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1830}
+.LBB{1831}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1829}
+.LBB{1830}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1831}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1831}
+	jmp	.LBB{5}
+.LBB{1832}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1833}
+	cmpq	%r9, %r8
+	jae	.LBB{1833}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1834}
+.LBB{1835}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1833}
+.LBB{1834}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1835}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1835}
+	jmp	.LBB{5}
+.LBB{1829}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1836}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1836}
+	jmp	.LBB{4}
+.LBB{1826}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1837}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1827}
+.LBB{1833}:
+	movq	160(%rsp), %rdi
+.Ltmp{1838}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1839}:
+	jmp	.LBB{4}
+.LBB{1810}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1814}
+	jmp	.LBB{217}
+.LBB{1813}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1814}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1672}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{307}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{137}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{1840}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1841}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1842}
+	jmp	.LBB{4}
+.LBB{1843}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{1842}
+.LBB{1844}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1845}
+	cmpq	%r9, %r8
+	jae	.LBB{1845}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1846}
+.LBB{1847}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1845}
+.LBB{1846}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1847}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1847}
+	jmp	.LBB{5}
+.LBB{1848}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1849}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1850}
+.LBB{1851}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{259}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1852}
+	cmpq	%rdi, %rax
+	jae	.LBB{1852}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1853}
+.LBB{1854}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1852}
+.LBB{1853}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1854}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1854}
+	jmp	.LBB{5}
+.LBB{1855}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1856}
+.Ltmp{1857}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1858}:
+	jmp	.LBB{1856}
+.LBB{1859}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{1860}
+	jmp	.LBB{113}
+.LBB{1861}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	jbe	.LBB{1862}
+	jmp	.LBB{259}
+.LBB{1863}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{1864}
+	jmp	.LBB{1865}
+.LBB{1866}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1842}
+	jmp	.LBB{1850}
+.LBB{1867}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1868}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1869}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{1842}
+.LBB{1870}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{1871}
+	jmp	.LBB{113}
+.LBB{1872}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1873}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1874}
+	cmpq	$-1, %rcx
+	je	.LBB{1875}
+.LBB{1874}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1876}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1877}
+	jmp	.LBB{119}
+.LBB{1878}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1879}
+.Ltmp{1880}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1881}:
+.LBB{1879}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{1842}
+.LBB{1882}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{1883}
+	jmp	.LBB{34}
+.LBB{1884}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1885}
+	xorl	%edx, %edx
+	jmp	.LBB{1886}
+.LBB{1887}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{1888}
+	jmp	.LBB{113}
+.LBB{1889}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{1890}
+	jmp	.LBB{299}
+.LBB{1891}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{1892}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{1892}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{244}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{1893}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1894}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{1842}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{1895}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{1896}
+	jmp	.LBB{299}
+.LBB{1897}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1850}:
+	addq	$2, %r14
+	movq	%r14, %rbp
+	jmp	.LBB{4}
+.LBB{1898}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{1899}
+	jmp	.LBB{299}
+.LBB{1852}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1900}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1900}
+	jmp	.LBB{4}
+.LBB{1885}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1901}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1886}
+.LBB{1845}:
+	movq	160(%rsp), %rdi
+.Ltmp{1902}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1903}:
+	jmp	.LBB{4}
+.LBB{1873}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1877}
+	jmp	.LBB{119}
+.LBB{1876}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{1877}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1673}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{341}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{172}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{1904}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1905}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1906}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1907}
+	cmpq	$-1, %rcx
+	je	.LBB{1908}
+.LBB{1907}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1909}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1910}
+	jmp	.LBB{217}
+.LBB{1911}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1912}
+	xorl	%edx, %edx
+	jmp	.LBB{1913}
+.LBB{1914}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1915}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1916}
+	cmpq	%r9, %r8
+	jae	.LBB{1916}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1917}
+.LBB{1918}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1916}
+.LBB{1917}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1918}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1918}
+	jmp	.LBB{5}
+.LBB{1919}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1920}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1820}
+.Ltmp{1921}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1922}:
+	jmp	.LBB{1820}
+.LBB{1923}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1816}
+.Ltmp{1924}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1925}:
+	jmp	.LBB{1816}
+.LBB{1926}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1927}
+	cmpq	%rdi, %rax
+	jae	.LBB{1927}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1928}
+.LBB{1929}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1927}
+.LBB{1928}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1929}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1929}
+	jmp	.LBB{5}
+.LBB{1927}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1930}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1930}
+	jmp	.LBB{4}
+.LBB{1912}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1931}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1913}
+.LBB{1916}:
+	movq	160(%rsp), %rdi
+.Ltmp{1932}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1933}:
+	jmp	.LBB{4}
+.LBB{1906}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1910}
+	jmp	.LBB{217}
+.LBB{1909}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1910}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1674}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{402}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{18}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{1934}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1935}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1936}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{1937}
+	cmpq	$-1, %rcx
+	je	.LBB{1938}
+.LBB{1937}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1939}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{1940}
+	jmp	.LBB{371}
+.LBB{1941}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1942}
+	cmpq	%r9, %r8
+	jae	.LBB{1942}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1943}
+.LBB{1944}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1942}
+.LBB{1943}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1944}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1944}
+	jmp	.LBB{5}
+.LBB{1945}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	jmp	.LBB{1946}
+.LBB{1947}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1948}
+	cmpq	%rdi, %rax
+	jae	.LBB{1948}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1949}
+.LBB{1950}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1948}
+.LBB{1949}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1950}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1950}
+	jmp	.LBB{5}
+.LBB{1951}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1856}
+.Ltmp{1952}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1953}:
+	jmp	.LBB{1856}
+.LBB{1954}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1955}
+	jmp	.LBB{1956}
+.LBB{1957}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1958}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1959}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1960}
+.Ltmp{1961}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1962}:
+	jmp	.LBB{1960}
+.LBB{1963}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{1964}
+	xorl	%edx, %edx
+	jmp	.LBB{1965}
+.LBB{1966}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	jmp	.LBB{1967}
+.LBB{1948}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{1968}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{1968}
+	jmp	.LBB{4}
+.LBB{1964}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{1969}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{1965}
+.LBB{1942}:
+	movq	160(%rsp), %rdi
+.Ltmp{1970}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1971}:
+	jmp	.LBB{4}
+.LBB{1936}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1940}
+	jmp	.LBB{371}
+.LBB{1939}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{1940}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1675}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{606}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{82}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{1972}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{1973}:
+	cmpl	$16, %r8d
+	jb	.LBB{1974}
+	jmp	.LBB{34}
+.LBB{1975}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jb	.LBB{1976}
+	jmp	.LBB{21}
+.LBB{1977}:
+	cmpl	$16, %r8d
+	jb	.LBB{1978}
+	jmp	.LBB{34}
+.LBB{1979}:
+	cmpl	$16, %r8d
+	jb	.LBB{1980}
+	jmp	.LBB{34}
+.LBB{1981}:
+	cmpl	$16, %r8d
+	jb	.LBB{1982}
+	jmp	.LBB{34}
+.LBB{1983}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{1984}
+	jmp	.LBB{34}
+.LBB{1985}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jb	.LBB{1986}
+	jmp	.LBB{21}
+.LBB{1987}:
+	cmpl	$16, %r8d
+	jb	.LBB{1988}
+	jmp	.LBB{34}
+.LBB{1989}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1990}
+	cmpq	%r9, %r8
+	jae	.LBB{1990}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{1991}
+.LBB{1992}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{1990}
+.LBB{1991}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1992}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1992}
+	jmp	.LBB{5}
+.LBB{1993}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1994}
+.LBB{1995}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1996}
+	cmpq	%rdi, %rax
+	jae	.LBB{1996}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{1997}
+.LBB{1998}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1996}
+.LBB{1997}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{1998}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1998}
+	jmp	.LBB{5}
+.LBB{1999}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1816}
+.Ltmp{2000}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2001}:
+	jmp	.LBB{1816}
+.LBB{2002}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435456, %eax
+	jb	.LBB{2003}
+	jmp	.LBB{223}
+.LBB{2004}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	jbe	.LBB{2005}
+	jmp	.LBB{9}
+.LBB{2006}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2007}
+	jmp	.LBB{1994}
+.LBB{2008}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2009}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2010}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1820}
+.Ltmp{2011}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2012}:
+	jmp	.LBB{1820}
+.LBB{2013}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2014}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2015}
+	cmpq	$-1, %rcx
+	je	.LBB{2016}
+.LBB{2015}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2017}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2018}
+	jmp	.LBB{217}
+.LBB{2019}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2020}
+	jmp	.LBB{216}
+.LBB{2021}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	jbe	.LBB{2022}
+	jmp	.LBB{9}
+.LBB{2023}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2024}
+	xorl	%edx, %edx
+	jmp	.LBB{2025}
+.LBB{2026}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	jbe	.LBB{2027}
+	jmp	.LBB{9}
+.LBB{2028}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{1994}:
+	addq	$2, %r13
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2029}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{584}
+	cmpl	$15, %r8d
+	jbe	.LBB{2030}
+	jmp	.LBB{9}
+.LBB{1996}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2031}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2031}
+	jmp	.LBB{4}
+.LBB{2024}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2032}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2025}
+.LBB{1990}:
+	movq	160(%rsp), %rdi
+.Ltmp{2033}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2034}:
+	jmp	.LBB{4}
+.LBB{2014}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2018}
+	jmp	.LBB{217}
+.LBB{2017}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{2018}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1676}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{618}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{13}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2035}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2036}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2037}
+	jmp	.LBB{4}
+.LBB{2038}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2037}
+.LBB{2039}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{422}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{448}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2040}
+	cmpq	%r9, %r8
+	jae	.LBB{2040}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2041}
+.LBB{2042}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2040}
+.LBB{2041}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2042}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2042}
+	jmp	.LBB{5}
+.LBB{2043}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{422}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2044}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2045}
+.LBB{2046}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{640}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{422}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2047}
+	cmpq	%rdi, %rax
+	jae	.LBB{2047}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2048}
+.LBB{2049}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2047}
+.LBB{2048}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2049}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2049}
+	jmp	.LBB{5}
+.LBB{2050}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2051}
+.Ltmp{2052}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2053}:
+	jmp	.LBB{2051}
+.LBB{2054}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	jbe	.LBB{2055}
+	jmp	.LBB{52}
+.LBB{2056}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2057}
+	jmp	.LBB{586}
+.LBB{2058}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{517}
+	cmpl	$15, %r8d
+	jbe	.LBB{2059}
+	jmp	.LBB{52}
+.LBB{2060}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2037}
+	jmp	.LBB{2045}
+.LBB{2061}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2062}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2063}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2037}
+.LBB{2064}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2065}
+	jmp	.LBB{51}
+.LBB{2066}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2067}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2068}
+	cmpq	$-1, %rcx
+	je	.LBB{2069}
+.LBB{2068}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2070}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2071}
+	jmp	.LBB{52}
+.LBB{2072}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2073}
+.Ltmp{2074}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2075}:
+.LBB{2073}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2037}
+.LBB{2076}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2077}
+	jmp	.LBB{9}
+.LBB{2078}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2079}
+	xorl	%edx, %edx
+	jmp	.LBB{2080}
+.LBB{2081}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2082}
+	jmp	.LBB{51}
+.LBB{2083}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	jbe	.LBB{2084}
+	jmp	.LBB{587}
+.LBB{2085}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{422}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{2086}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2086}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2087}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2088}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{2037}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{2089}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+	cmpl	$15, %r8d
+	jbe	.LBB{2090}
+	jmp	.LBB{587}
+.LBB{2091}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2045}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2092}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2093}
+	jmp	.LBB{586}
+.LBB{2047}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2094}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2094}
+	jmp	.LBB{4}
+.LBB{2079}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2095}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2080}
+.LBB{2040}:
+	movq	160(%rsp), %rdi
+.Ltmp{2096}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2097}:
+	jmp	.LBB{4}
+.LBB{2067}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2071}
+	jmp	.LBB{52}
+.LBB{2070}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2071}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1677}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{621}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{88}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2098}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2099}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{2100}
+	jmp	.LBB{2101}
+.LBB{2102}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r13d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	jbe	.LBB{2103}
+	jmp	.LBB{427}
+.LBB{2104}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2105}
+.Ltmp{2106}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2107}:
+	jmp	.LBB{2105}
+.LBB{2108}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2109}
+.Ltmp{2110}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2111}:
+	jmp	.LBB{2109}
+.LBB{2112}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2113}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2114}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2115}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2116}
+	cmpq	$-1, %rcx
+	je	.LBB{2117}
+.LBB{2116}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2118}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2119}
+	jmp	.LBB{119}
+.LBB{2120}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2121}
+	xorl	%edx, %edx
+	jmp	.LBB{2122}
+.LBB{2123}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2124}
+	cmpq	%rdi, %rax
+	jae	.LBB{2124}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2125}
+.LBB{2126}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2124}
+.LBB{2125}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2126}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2126}
+	jmp	.LBB{5}
+.LBB{2127}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2128}
+	cmpq	%r9, %r8
+	jae	.LBB{2128}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2129}
+.LBB{2130}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2128}
+.LBB{2129}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2130}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2130}
+	jmp	.LBB{5}
+.LBB{2124}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2131}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2131}
+	jmp	.LBB{4}
+.LBB{2121}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2132}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2122}
+.LBB{2128}:
+	movq	160(%rsp), %rdi
+.Ltmp{2133}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2134}:
+	jmp	.LBB{4}
+.LBB{2115}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2119}
+	jmp	.LBB{119}
+.LBB{2118}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2119}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1678}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{637}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{93}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2135}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2136}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2137}
+	jmp	.LBB{4}
+.LBB{2138}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2137}
+.LBB{2139}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{2140}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{2141}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2142}
+	cmpq	%r9, %r8
+	jae	.LBB{2142}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2143}
+.LBB{2144}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2142}
+.LBB{2143}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2144}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2144}
+	jmp	.LBB{5}
+.LBB{2145}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{2146}
+	jmp	.LBB{2140}
+.LBB{2147}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{2148}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{2140}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2149}
+	cmpq	%rdi, %rax
+	jae	.LBB{2149}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2150}
+.LBB{2151}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2149}
+.LBB{2150}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2151}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2151}
+	jmp	.LBB{5}
+.LBB{2152}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1856}
+.Ltmp{2153}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2154}:
+	jmp	.LBB{1856}
+.LBB{2155}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2156}
+	jmp	.LBB{113}
+.LBB{2157}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	jbe	.LBB{2158}
+	jmp	.LBB{453}
+.LBB{2159}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{2160}
+	jmp	.LBB{281}
+.LBB{2161}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2162}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2163}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2137}
+.LBB{2164}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2165}
+	jmp	.LBB{113}
+.LBB{2166}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2167}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2168}
+	cmpq	$-1, %rcx
+	je	.LBB{2169}
+.LBB{2168}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2170}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2171}
+	jmp	.LBB{119}
+.LBB{2172}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1960}
+.Ltmp{2173}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2174}:
+	jmp	.LBB{1960}
+.LBB{2175}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2176}
+	xorl	%edx, %edx
+	jmp	.LBB{2177}
+.LBB{2178}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	jbe	.LBB{2179}
+	jmp	.LBB{453}
+.LBB{2180}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{2181}
+	jmp	.LBB{2140}
+.LBB{2182}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2183}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{2137}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{2184}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	jbe	.LBB{2185}
+	jmp	.LBB{453}
+.LBB{2186}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2187}
+	jmp	.LBB{299}
+.LBB{2149}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2188}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2188}
+	jmp	.LBB{4}
+.LBB{2176}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2189}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2177}
+.LBB{2142}:
+	movq	160(%rsp), %rdi
+.Ltmp{2190}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2191}:
+	jmp	.LBB{4}
+.LBB{2167}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2171}
+	jmp	.LBB{119}
+.LBB{2170}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2171}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1679}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{702}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{106}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2192}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2193}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{2160}
+	jmp	.LBB{281}
+.LBB{2194}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{1888}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2195}
+	jmp	.LBB{119}
+.LBB{2196}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2197}
+	cmpq	%r9, %r8
+	jae	.LBB{2197}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2198}
+.LBB{2199}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2197}
+.LBB{2198}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2199}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2199}
+	jmp	.LBB{5}
+.LBB{2200}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2201}
+.LBB{2202}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2203}
+	cmpq	%rdi, %rax
+	jae	.LBB{2203}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2204}
+.LBB{2205}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2203}
+.LBB{2204}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2205}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2205}
+	jmp	.LBB{5}
+.LBB{2206}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1856}
+.Ltmp{2207}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2208}:
+	jmp	.LBB{1856}
+.LBB{2209}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2210}
+	jmp	.LBB{2201}
+.LBB{2211}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2212}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2213}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{1860}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2214}
+	jmp	.LBB{119}
+.LBB{2215}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{1871}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2216}
+	jmp	.LBB{119}
+.LBB{2217}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1960}
+.Ltmp{2218}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2219}:
+	jmp	.LBB{1960}
+.LBB{2220}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2221}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2222}
+	cmpq	$-1, %rcx
+	je	.LBB{2223}
+.LBB{2222}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2224}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2225}
+	jmp	.LBB{119}
+.LBB{2226}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2227}
+	xorl	%edx, %edx
+	jmp	.LBB{2228}
+.LBB{2229}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2201}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2203}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2230}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2230}
+	jmp	.LBB{4}
+.LBB{2227}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2231}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2228}
+.LBB{2197}:
+	movq	160(%rsp), %rdi
+.Ltmp{2232}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2233}:
+	jmp	.LBB{4}
+.LBB{2221}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2225}
+	jmp	.LBB{119}
+.LBB{2224}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2225}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1680}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{746}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{152}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2234}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2235}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2236}
+	jmp	.LBB{4}
+.LBB{2237}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2236}
+.LBB{2238}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2239}
+.LBB{2240}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2236}
+.LBB{2241}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2236}
+	jmp	.LBB{4}
+.LBB{2242}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r13d, %ecx
+	jmp	.LBB{2243}
+.LBB{2244}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2236}
+	jmp	.LBB{2239}
+.LBB{2245}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	jbe	.LBB{2158}
+	jmp	.LBB{84}
+.LBB{2246}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %r13d
+	movzbl	%bl, %eax
+	orl	%r13d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2247}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2156}
+	jmp	.LBB{113}
+.LBB{2248}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2165}
+	jmp	.LBB{113}
+.LBB{2249}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r13b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2239}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2250}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	jbe	.LBB{2251}
+	jmp	.LBB{84}
+.LBB{2252}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2253}
+	jmp	.LBB{113}
+.LBB{2254}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	jbe	.LBB{2255}
+	jmp	.LBB{84}
+.LBB{2256}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{598}
+	cmpl	$15, %r8d
+	jbe	.LBB{2257}
+	jmp	.LBB{84}
+.LBB{2258}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2259}
+	cmpq	%r9, %r8
+	jae	.LBB{2259}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2260}
+.LBB{2261}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2259}
+.LBB{2260}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2261}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2261}
+	jmp	.LBB{5}
+.LBB{2262}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r13d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jb	.LBB{2263}
+	jmp	.LBB{5}
+.LBB{2264}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2265}
+	cmpq	%rdi, %rax
+	jae	.LBB{2265}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2266}
+.LBB{2267}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2265}
+.LBB{2266}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2267}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2267}
+	jmp	.LBB{5}
+.LBB{2268}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2105}
+.Ltmp{2269}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2270}:
+	jmp	.LBB{2105}
+.LBB{2271}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{2101}
+.LBB{2100}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2272}
+	jmp	.LBB{119}
+.LBB{2273}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2274}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2275}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2276}
+	cmpq	$-1, %rcx
+	je	.LBB{2277}
+.LBB{2276}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2278}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2279}
+	jmp	.LBB{119}
+.LBB{2280}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2109}
+.Ltmp{2281}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2282}:
+.LBB{2109}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+.LBB{2236}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2283}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2284}
+	xorl	%edx, %edx
+	jmp	.LBB{2285}
+.LBB{2286}:
+	cmpl	$16, %r8d
+	jae	.LBB{84}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2265}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2287}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2287}
+	jmp	.LBB{4}
+.LBB{2284}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2288}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2285}
+.LBB{2259}:
+	movq	160(%rsp), %rdi
+.Ltmp{2289}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2290}:
+	jmp	.LBB{4}
+.LBB{2275}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2279}
+	jmp	.LBB{119}
+.LBB{2278}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2279}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1681}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{761}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{157}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2291}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2292}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{1955}
+	jmp	.LBB{4}
+.LBB{2293}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{1955}
+.LBB{2294}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2295}
+	cmpq	%r9, %r8
+	jae	.LBB{2295}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2296}
+.LBB{2297}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2295}
+.LBB{2296}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2297}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2297}
+	jmp	.LBB{5}
+.LBB{2298}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jb	.LBB{2299}
+	jmp	.LBB{5}
+.LBB{2300}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2301}
+.LBB{2302}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2303}
+	cmpq	%rdi, %rax
+	jae	.LBB{2303}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2304}
+.LBB{2305}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2303}
+.LBB{2304}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2305}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2305}
+	jmp	.LBB{5}
+.LBB{2306}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2307}
+.Ltmp{2308}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2309}:
+	jmp	.LBB{2307}
+.LBB{2310}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2311}
+	cmpl	$15, %r8d
+	jbe	.LBB{2312}
+	jmp	.LBB{259}
+.LBB{2313}:
+	cmpl	$16, %r8d
+	jb	.LBB{2314}
+	jmp	.LBB{9}
+.LBB{2315}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{1955}
+	jmp	.LBB{2301}
+.LBB{2316}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2317}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2318}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{1955}
+.LBB{2319}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2320}
+.Ltmp{2321}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2322}:
+.LBB{2320}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{1955}
+.LBB{2323}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	jbe	.LBB{2324}
+	jmp	.LBB{9}
+.LBB{2325}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2326}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2327}
+	cmpq	$-1, %rcx
+	je	.LBB{2328}
+.LBB{2327}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2329}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2330}
+	jmp	.LBB{371}
+.LBB{2331}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2332}
+	jmp	.LBB{370}
+.LBB{2333}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2311}
+	cmpl	$15, %r8d
+	jbe	.LBB{2334}
+	jmp	.LBB{259}
+.LBB{2335}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+	movl	%ebx, %eax
+	jmp	.LBB{2336}
+.LBB{2337}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2338}
+	xorl	%edx, %edx
+	jmp	.LBB{2339}
+.LBB{2340}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{1955}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{2341}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2311}
+	cmpl	$15, %r8d
+	jbe	.LBB{2342}
+	jmp	.LBB{259}
+.LBB{2343}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2301}:
+	addq	$2, %r13
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2344}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{2311}
+	cmpl	$15, %r8d
+	jbe	.LBB{2345}
+	jmp	.LBB{259}
+.LBB{2303}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2346}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2346}
+	jmp	.LBB{4}
+.LBB{2338}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2347}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2339}
+.LBB{2295}:
+	movq	160(%rsp), %rdi
+.Ltmp{2348}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2349}:
+	jmp	.LBB{4}
+.LBB{2326}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2330}
+	jmp	.LBB{371}
+.LBB{2329}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2330}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1682}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{764}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{162}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2350}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2351}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2210}
+	jmp	.LBB{4}
+.LBB{2352}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{2353}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{1883}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2354}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2210}
+.LBB{2355}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+.LBB{2146}:
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2356}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+.LBB{1862}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2357}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2358}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2210}
+.LBB{2359}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2210}
+	jmp	.LBB{4}
+.LBB{2360}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+.LBB{1890}:
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+.LBB{2179}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2361}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+.LBB{2181}:
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{2362}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2362}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2363}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+.LBB{1896}:
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+.LBB{2185}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2364}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{395}
+.LBB{1899}:
+	cmpl	$15, %r8d
+	ja	.LBB{259}
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2365}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2195}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2366}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2216}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2367}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2368}
+.LBB{2369}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2214}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2370}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2210}
+	jmp	.LBB{2368}
+.LBB{2371}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2368}:
+	addq	$2, %r13
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2372}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2373}
+	xorl	%edx, %edx
+	jmp	.LBB{2374}
+.LBB{2375}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2376}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2377}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2378}
+	cmpq	$-1, %rcx
+	je	.LBB{2379}
+.LBB{2378}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2380}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2381}
+	jmp	.LBB{371}
+.LBB{2382}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2383}
+	cmpq	%r9, %r8
+	jae	.LBB{2383}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2384}
+.LBB{2385}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2383}
+.LBB{2384}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2385}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2385}
+	jmp	.LBB{5}
+.LBB{2386}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2387}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1960}
+.Ltmp{2388}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2389}:
+.LBB{1960}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{2210}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{2390}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+.LBB{2160}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2059}
+	jmp	.LBB{119}
+.LBB{2391}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1856}
+.Ltmp{2392}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2393}:
+.LBB{1856}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{2394}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2395}
+	cmpq	%rdi, %rax
+	jae	.LBB{2395}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2396}
+.LBB{2397}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2395}
+.LBB{2396}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2397}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2397}
+	jmp	.LBB{5}
+.LBB{2395}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2398}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2398}
+	jmp	.LBB{4}
+.LBB{2373}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2399}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2374}
+.LBB{2383}:
+	movq	160(%rsp), %rdi
+.Ltmp{2400}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2401}:
+	jmp	.LBB{4}
+.LBB{2377}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2381}
+	jmp	.LBB{371}
+.LBB{2380}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2381}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1683}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{877}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{63}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2402}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2403}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2404}
+	jmp	.LBB{370}
+.LBB{2405}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2406}
+	cmpq	%r9, %r8
+	jae	.LBB{2406}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2407}
+.LBB{2408}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2406}
+.LBB{2407}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2408}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2408}
+	jmp	.LBB{5}
+.LBB{2409}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2410}
+	cmpq	%rdi, %rax
+	jae	.LBB{2410}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2411}
+.LBB{2412}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2410}
+.LBB{2411}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2412}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2412}
+	jmp	.LBB{5}
+.LBB{2413}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2307}
+.Ltmp{2414}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2415}:
+	jmp	.LBB{2307}
+.LBB{2416}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2417}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2418}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2419}
+	cmpq	$-1, %rcx
+	je	.LBB{2420}
+.LBB{2419}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2421}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2422}
+	jmp	.LBB{371}
+.LBB{2423}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2424}
+.Ltmp{2425}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2426}:
+	jmp	.LBB{2424}
+.LBB{2427}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2428}
+	xorl	%edx, %edx
+	jmp	.LBB{2429}
+.LBB{2430}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2410}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2431}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2431}
+	jmp	.LBB{4}
+.LBB{2428}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2432}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2429}
+.LBB{2406}:
+	movq	160(%rsp), %rdi
+.Ltmp{2433}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2434}:
+	jmp	.LBB{4}
+.LBB{2418}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2422}
+	jmp	.LBB{371}
+.LBB{2421}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2422}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1684}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{917}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%r15, %r12
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{143}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2435}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2436}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+.LBB{2314}:
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2437}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{373}
+	cmpl	$15, %r8d
+	jbe	.LBB{2059}
+	jmp	.LBB{371}
+.LBB{2438}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2439}
+	jmp	.LBB{370}
+.LBB{2440}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2404}
+	jmp	.LBB{370}
+.LBB{2441}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2442}
+.Ltmp{2443}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2444}:
+	jmp	.LBB{2442}
+.LBB{2445}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2446}
+.Ltmp{2447}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2448}:
+	jmp	.LBB{2446}
+.LBB{2449}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2450}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2451}:
+	cmpl	$16, %r8d
+	jb	.LBB{2452}
+	jmp	.LBB{9}
+.LBB{2453}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2454}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2455}
+	cmpq	$-1, %rcx
+	je	.LBB{2456}
+.LBB{2455}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2457}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2458}
+	jmp	.LBB{371}
+.LBB{2459}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2460}
+	xorl	%edx, %edx
+	jmp	.LBB{2461}
+.LBB{2462}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	jbe	.LBB{2463}
+	jmp	.LBB{9}
+.LBB{2464}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2465}
+	cmpq	%rdi, %rax
+	jae	.LBB{2465}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2466}
+.LBB{2467}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2465}
+.LBB{2466}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2467}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2467}
+	jmp	.LBB{5}
+.LBB{2468}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2469}
+	cmpq	%r9, %r8
+	jae	.LBB{2469}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2470}
+.LBB{2471}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2469}
+.LBB{2470}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2471}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2471}
+	jmp	.LBB{5}
+.LBB{2472}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	andq	%r12, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	movabsq	$3689348814741910323, %rdx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rax, %rcx
+	movabsq	$72340172838076673, %rax
+	jmp	.LBB{2473}
+.LBB{2474}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	jbe	.LBB{2475}
+	jmp	.LBB{9}
+.LBB{2476}:
+	movzbl	%r14b, %eax
+	cmpl	$16, %eax
+	jae	.LBB{139}
+	cmpl	$15, %r8d
+	jbe	.LBB{2477}
+	jmp	.LBB{9}
+.LBB{2465}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2478}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2478}
+	jmp	.LBB{4}
+.LBB{2460}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2479}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2461}
+.LBB{2469}:
+	movq	160(%rsp), %rdi
+.Ltmp{2480}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2481}:
+	jmp	.LBB{4}
+.LBB{2454}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2458}
+	jmp	.LBB{371}
+.LBB{2457}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2458}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1685}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{960}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{167}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2482}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2483}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2484}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2485}
+	cmpq	$-1, %rcx
+	je	.LBB{2486}
+.LBB{2485}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2487}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2488}
+	jmp	.LBB{371}
+.LBB{2489}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jb	.LBB{2490}
+	jmp	.LBB{1010}
+.LBB{2491}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2492}
+	xorl	%edx, %edx
+	jmp	.LBB{2493}
+.LBB{2494}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2495}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2496}
+	cmpq	%r9, %r8
+	jae	.LBB{2496}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2497}
+.LBB{2498}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2496}
+.LBB{2497}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2498}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2498}
+	jmp	.LBB{5}
+.LBB{2499}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2500}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2424}
+.Ltmp{2501}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2502}:
+	jmp	.LBB{2424}
+.LBB{2503}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jb	.LBB{2504}
+	jmp	.LBB{1010}
+.LBB{2505}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{2506}
+	jmp	.LBB{139}
+.LBB{2507}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2307}
+.Ltmp{2508}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2509}:
+	jmp	.LBB{2307}
+.LBB{2510}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2511}
+	cmpq	%rdi, %rax
+	jae	.LBB{2511}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2512}
+.LBB{2513}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2511}
+.LBB{2512}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2513}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2513}
+	jmp	.LBB{5}
+.LBB{2514}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jb	.LBB{2515}
+	jmp	.LBB{139}
+.LBB{2511}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2516}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2516}
+	jmp	.LBB{4}
+.LBB{2492}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2517}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2493}
+.LBB{2496}:
+	movq	160(%rsp), %rdi
+.Ltmp{2518}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2519}:
+	jmp	.LBB{4}
+.LBB{2484}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2488}
+	jmp	.LBB{371}
+.LBB{2487}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2488}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1686}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{991}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{177}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2520}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2521}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2522}
+	jmp	.LBB{4}
+.LBB{2523}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{2524}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2522}
+.LBB{2525}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+.LBB{2332}:
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2526}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+.LBB{2324}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2527}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{2312}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2528}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+.LBB{1946}:
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{1956}
+.LBB{2529}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %r13d
+	movzbl	%bl, %eax
+	orl	%r13d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2530}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r13b, %edx
+.LBB{1967}:
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{1956}
+.LBB{2531}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2522}
+	jmp	.LBB{4}
+.LBB{2532}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2522}
+.LBB{1956}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2533}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+.LBB{2439}:
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2534}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{2535}
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2272}:
+	movq	16(%rsp,%r13,8), %rax
+	jmp	.LBB{2536}
+.LBB{2537}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2522}
+.LBB{2538}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{2345}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2539}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{358}
+.LBB{2490}:
+	movl	%r13d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+.LBB{2299}:
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2540}:
+	movzbl	%bl, %ecx
+	leaq	.Lanon{8}(%rip), %rdx
+	cmpl	$16, %ecx
+	movq	%rdx, 8(%rsp)
+	jae	.LBB{358}
+.LBB{2504}:
+	movl	%r13d, %eax
+.LBB{2336}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{2541}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2541}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2542}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+.LBB{2506}:
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{2334}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2543}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+.LBB{2404}:
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2544}:
+	movzbl	%bl, %eax
+	cmpl	$16, %eax
+	jae	.LBB{59}
+.LBB{2515}:
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{2342}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2545}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2546}
+	xorl	%edx, %edx
+	jmp	.LBB{2547}
+.LBB{2548}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2549}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{370}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2550}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2551}
+	cmpq	$-1, %rcx
+	je	.LBB{2552}
+.LBB{2551}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2553}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2554}
+	jmp	.LBB{371}
+.LBB{2555}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{358}
+	movl	%r13d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{364}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2556}
+	cmpq	%r9, %r8
+	jae	.LBB{2556}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2557}
+.LBB{2558}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2556}
+.LBB{2557}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2558}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2558}
+	jmp	.LBB{5}
+.LBB{2559}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2560}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2424}
+.Ltmp{2561}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2562}:
+.LBB{2424}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+.LBB{2522}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2563}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2307}
+.Ltmp{2564}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2565}:
+.LBB{2307}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{2566}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{404}
+	movl	%r13d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{358}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2567}
+	cmpq	%rdi, %rax
+	jae	.LBB{2567}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2568}
+.LBB{2569}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2567}
+.LBB{2568}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2569}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2569}
+	jmp	.LBB{5}
+.LBB{2567}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2570}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2570}
+	jmp	.LBB{4}
+.LBB{2546}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2571}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2547}
+.LBB{2556}:
+	movq	160(%rsp), %rdi
+.Ltmp{2572}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2573}:
+	jmp	.LBB{4}
+.LBB{2550}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2554}
+	jmp	.LBB{371}
+.LBB{2553}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{371}
+.LBB{2554}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1687}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1095}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{44}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2574}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2575}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{2576}
+	jmp	.LBB{891}
+.LBB{2577}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2578}
+	jmp	.LBB{39}
+.LBB{2579}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2580}
+	xorl	%edx, %edx
+	jmp	.LBB{2581}
+.LBB{2582}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2583}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2584}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2585}
+	cmpq	$-1, %rcx
+	je	.LBB{2586}
+.LBB{2585}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2587}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2588}
+	jmp	.LBB{40}
+.LBB{2589}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2590}
+	cmpq	%r9, %r8
+	jae	.LBB{2590}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2591}
+.LBB{2592}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2590}
+.LBB{2591}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2592}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2592}
+	jmp	.LBB{5}
+.LBB{2593}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2594}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2446}
+.Ltmp{2595}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2596}:
+	jmp	.LBB{2446}
+.LBB{2597}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	jbe	.LBB{2598}
+	jmp	.LBB{826}
+.LBB{2599}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2442}
+.Ltmp{2600}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2601}:
+	jmp	.LBB{2442}
+.LBB{2602}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2603}
+	cmpq	%rdi, %rax
+	jae	.LBB{2603}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2604}
+.LBB{2605}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2603}
+.LBB{2604}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2605}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2605}
+	jmp	.LBB{5}
+.LBB{2603}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2606}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2606}
+	jmp	.LBB{4}
+.LBB{2580}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2607}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2581}
+.LBB{2590}:
+	movq	160(%rsp), %rdi
+.Ltmp{2608}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2609}:
+	jmp	.LBB{4}
+.LBB{2584}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2588}
+	jmp	.LBB{40}
+.LBB{2587}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2588}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1688}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1149}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{56}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2610}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2611}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2612}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2613}
+	cmpq	$-1, %rcx
+	je	.LBB{2614}
+.LBB{2613}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2615}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2616}
+	jmp	.LBB{52}
+.LBB{2617}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2618}
+	xorl	%edx, %edx
+	jmp	.LBB{2619}
+.LBB{2620}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2621}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2622}
+	cmpq	%r9, %r8
+	jae	.LBB{2622}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2623}
+.LBB{2624}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2622}
+.LBB{2623}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2624}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2624}
+	jmp	.LBB{5}
+.LBB{2625}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2626}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2627}
+.Ltmp{2628}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2629}:
+	jmp	.LBB{2627}
+.LBB{2630}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2051}
+.Ltmp{2631}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2632}:
+	jmp	.LBB{2051}
+.LBB{2633}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2634}
+	cmpq	%rdi, %rax
+	jae	.LBB{2634}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2635}
+.LBB{2636}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2634}
+.LBB{2635}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2636}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2636}
+	jmp	.LBB{5}
+.LBB{2634}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2637}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2637}
+	jmp	.LBB{4}
+.LBB{2618}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2638}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2619}
+.LBB{2622}:
+	movq	160(%rsp), %rdi
+.Ltmp{2639}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2640}:
+	jmp	.LBB{4}
+.LBB{2612}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2616}
+	jmp	.LBB{52}
+.LBB{2615}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2616}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1689}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1189}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{77}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2641}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2642}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	jbe	.LBB{2643}
+	jmp	.LBB{73}
+.LBB{2644}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2645}
+	cmpq	%r9, %r8
+	jae	.LBB{2645}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2646}
+.LBB{2647}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2645}
+.LBB{2646}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2647}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2647}
+	jmp	.LBB{5}
+.LBB{2648}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2649}
+	cmpq	%rdi, %rax
+	jae	.LBB{2649}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2650}
+.LBB{2651}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2649}
+.LBB{2650}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2651}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2651}
+	jmp	.LBB{5}
+.LBB{2652}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2051}
+.Ltmp{2653}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2654}:
+	jmp	.LBB{2051}
+.LBB{2655}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+.LBB{2057}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2656}
+	jmp	.LBB{587}
+.LBB{2657}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2658}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2065}
+	jmp	.LBB{51}
+.LBB{2659}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2660}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2661}
+	cmpq	$-1, %rcx
+	je	.LBB{2662}
+.LBB{2661}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2663}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2664}
+	jmp	.LBB{52}
+.LBB{2665}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2627}
+.Ltmp{2666}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2667}:
+	jmp	.LBB{2627}
+.LBB{2668}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2669}
+	xorl	%edx, %edx
+	jmp	.LBB{2670}
+.LBB{2671}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2672}
+	jmp	.LBB{586}
+.LBB{2673}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2674}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2675}
+	jmp	.LBB{586}
+.LBB{2676}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{586}
+.LBB{2093}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2677}
+	jmp	.LBB{587}
+.LBB{2649}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2678}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2678}
+	jmp	.LBB{4}
+.LBB{2669}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2679}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2670}
+.LBB{2645}:
+	movq	160(%rsp), %rdi
+.Ltmp{2680}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2681}:
+	jmp	.LBB{4}
+.LBB{2660}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2664}
+	jmp	.LBB{52}
+.LBB{2663}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2664}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1690}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1235}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{111}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2682}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2683}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2684}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2685}
+	cmpq	$-1, %rcx
+	je	.LBB{2686}
+.LBB{2685}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2687}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2688}
+	jmp	.LBB{52}
+.LBB{2689}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{2690}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2691}
+.LBB{2692}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2693}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+.LBB{2082}:
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2694}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+.LBB{2077}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2695}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2696}
+.LBB{2697}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{2696}
+.LBB{2698}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2691}
+	jmp	.LBB{4}
+.LBB{2699}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2691}
+.LBB{2696}:
+	addq	$2, %r14
+	movq	%r14, %rbp
+	jmp	.LBB{4}
+.LBB{2700}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2691}
+.LBB{2701}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{2702}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2702}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{2703}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2656}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2704}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+.LBB{2672}:
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2084}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2705}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+.LBB{2675}:
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2090}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2706}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2677}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2707}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2708}
+	xorl	%edx, %edx
+	jmp	.LBB{2709}
+.LBB{2710}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2711}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2712}
+	cmpq	%r9, %r8
+	jae	.LBB{2712}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2713}
+.LBB{2714}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2712}
+.LBB{2713}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2714}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2714}
+	jmp	.LBB{5}
+.LBB{2715}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2716}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2627}
+.Ltmp{2717}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2718}:
+.LBB{2627}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{2691}
+.LBB{2719}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2051}
+.Ltmp{2720}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2721}:
+.LBB{2051}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{2722}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2723}
+	cmpq	%rdi, %rax
+	jae	.LBB{2723}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2724}
+.LBB{2725}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2723}
+.LBB{2724}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2725}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2725}
+	jmp	.LBB{5}
+.LBB{2723}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2726}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2726}
+	jmp	.LBB{4}
+.LBB{2708}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2727}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2709}
+.LBB{2712}:
+	movq	160(%rsp), %rdi
+.Ltmp{2728}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2729}:
+	jmp	.LBB{4}
+.LBB{2684}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2688}
+	jmp	.LBB{52}
+.LBB{2687}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2688}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1691}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1245}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{193}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2730}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2731}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2732}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	je	.LBB{4}
+.LBB{2691}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2733}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2055}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2734}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+.LBB{2065}:
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2735}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{73}
+.LBB{2643}:
+	cmpl	$15, %r8d
+	jbe	.LBB{2736}
+	jmp	.LBB{52}
+.LBB{2737}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2738}
+	cmpq	%r9, %r8
+	jae	.LBB{2738}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2739}
+.LBB{2740}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2738}
+.LBB{2739}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2740}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2740}
+	jmp	.LBB{5}
+.LBB{2741}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2742}
+	cmpq	%rdi, %rax
+	jae	.LBB{2742}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2743}
+.LBB{2744}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2742}
+.LBB{2743}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2744}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2744}
+	jmp	.LBB{5}
+.LBB{2745}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2105}
+.Ltmp{2746}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2747}:
+	jmp	.LBB{2105}
+.LBB{2748}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2749}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2750}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2751}
+.Ltmp{2752}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2753}:
+	jmp	.LBB{2751}
+.LBB{2754}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2755}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2756}
+	cmpq	$-1, %rcx
+	je	.LBB{2757}
+.LBB{2756}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2758}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2759}
+	jmp	.LBB{52}
+.LBB{2760}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	cmpl	$15, %r8d
+	jbe	.LBB{2761}
+	jmp	.LBB{52}
+.LBB{2762}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{51}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2763}
+	xorl	%edx, %edx
+	jmp	.LBB{2764}
+.LBB{2742}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2765}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2765}
+	jmp	.LBB{4}
+.LBB{2763}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2766}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2764}
+.LBB{2738}:
+	movq	160(%rsp), %rdi
+.Ltmp{2767}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2768}:
+	jmp	.LBB{4}
+.LBB{2755}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2759}
+	jmp	.LBB{52}
+.LBB{2758}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{52}
+.LBB{2759}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1692}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1277}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{197}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r14,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%r14), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2769}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2770}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2771}
+	jmp	.LBB{4}
+.LBB{2772}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{2773}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2774}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2771}
+.LBB{2775}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+	addq	16(%rsp,%rdx,8), %rcx
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+.LBB{2263}:
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+.LBB{2103}:
+	movq	160(%rsp), %rax
+	movq	(%rax,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2776}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2777}
+.LBB{2778}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2158}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2779}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2771}
+	jmp	.LBB{2777}
+.LBB{2780}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2771}
+	jmp	.LBB{4}
+.LBB{2781}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2771}
+.LBB{2782}:
+	movzbl	%bl, %edx
+	leaq	.Lanon{8}(%rip), %rax
+	cmpl	$16, %edx
+	jae	.LBB{425}
+	movl	%r12d, %ecx
+.LBB{2243}:
+	addq	16(%rsp,%rdx,8), %rcx
+	movq	%rcx, %rdx
+	shrq	$6, %rdx
+	cmpq	184(%rsp), %rdx
+	jae	.LBB{2783}
+	movq	176(%rsp), %rsi
+	movq	16(%rsi,%rdx,8), %rdx
+	btq	%rcx, %rdx
+	jb	.LBB{5}
+.LBB{2783}:
+	cmpq	168(%rsp), %rcx
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{427}
+	movq	160(%rsp), %rax
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rax,%rcx,8)
+	jmp	.LBB{4}
+.LBB{2784}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2251}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2785}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2777}:
+	addq	$2, %r14
+	movq	%r14, %rbp
+	jmp	.LBB{4}
+.LBB{2786}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2255}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2787}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{299}
+.LBB{2187}:
+	cmpl	$15, %r8d
+	ja	.LBB{453}
+.LBB{2257}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2788}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{2253}:
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2761}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2789}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2790}
+	xorl	%edx, %edx
+	jmp	.LBB{2791}
+.LBB{2792}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2793}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{2165}:
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2794}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2795}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2796}
+	cmpq	$-1, %rcx
+	je	.LBB{2797}
+.LBB{2796}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2798}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2799}
+	jmp	.LBB{119}
+.LBB{2800}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2801}
+	cmpq	%r9, %r8
+	jae	.LBB{2801}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2802}
+.LBB{2803}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2801}
+.LBB{2802}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2803}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2803}
+	jmp	.LBB{5}
+.LBB{2804}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2805}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2806}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{2751}
+.Ltmp{2807}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2808}:
+.LBB{2751}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+.LBB{2771}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2809}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{1865}
+.LBB{1864}:
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2736}:
+	movq	16(%rsp,%r12,8), %rax
+	jmp	.LBB{2536}
+.LBB{2810}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2105}
+.Ltmp{2811}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2812}:
+.LBB{2105}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{2813}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{113}
+.LBB{2156}:
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2814}:
+	leaq	.Lanon{8}(%rip), %rcx
+	cmpl	$16, %r8d
+	jae	.LBB{453}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{454}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{455}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rsi,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2815}
+	cmpq	%rdi, %rax
+	jae	.LBB{2815}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2816}
+.LBB{2817}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2815}
+.LBB{2816}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2817}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2817}
+	jmp	.LBB{5}
+.LBB{2815}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2818}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2818}
+	jmp	.LBB{4}
+.LBB{2790}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2819}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2791}
+.LBB{2801}:
+	movq	160(%rsp), %rdi
+.Ltmp{2820}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2821}:
+	jmp	.LBB{4}
+.LBB{2795}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2799}
+	jmp	.LBB{119}
+.LBB{2798}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{119}
+.LBB{2799}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1693}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1338}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{124}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2822}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2823}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2824}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2825}
+	cmpq	$-1, %rcx
+	je	.LBB{2826}
+.LBB{2825}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2827}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2828}
+	jmp	.LBB{40}
+.LBB{2829}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2830}
+.LBB{2831}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2832}
+	jmp	.LBB{2830}
+.LBB{2833}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{2830}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{2834}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2835}
+	cmpq	%r9, %r8
+	jae	.LBB{2835}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2836}
+.LBB{2837}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2835}
+.LBB{2836}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2837}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2837}
+	jmp	.LBB{5}
+.LBB{2838}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2839}
+	cmpq	%rdi, %rax
+	jae	.LBB{2839}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2840}
+.LBB{2841}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2839}
+.LBB{2840}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2841}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2841}
+	jmp	.LBB{5}
+.LBB{2842}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2442}
+.Ltmp{2843}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2844}:
+	jmp	.LBB{2442}
+.LBB{2845}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2846}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2847}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2446}
+.Ltmp{2848}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2849}:
+	jmp	.LBB{2446}
+.LBB{2850}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2851}
+	xorl	%edx, %edx
+	jmp	.LBB{2852}
+.LBB{2839}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2853}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2853}
+	jmp	.LBB{4}
+.LBB{2851}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2854}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2852}
+.LBB{2835}:
+	movq	160(%rsp), %rdi
+.Ltmp{2855}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2856}:
+	jmp	.LBB{4}
+.LBB{2824}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2828}
+	jmp	.LBB{40}
+.LBB{2827}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2828}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1694}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1389}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{71}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r13,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2857}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2858}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+.LBB{2859}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2832}
+	jmp	.LBB{4}
+.LBB{2860}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2861}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+.LBB{2862}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2832}
+.LBB{2863}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+.LBB{2864}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2832}
+.LBB{2865}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{2866}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{2832}
+	jmp	.LBB{4}
+.LBB{2867}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{34}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2868}:
+	movzbl	%r14b, %ecx
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{2869}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{2869}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{2870}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+.LBB{2578}:
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2871}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+.LBB{2463}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2872}:
+	cmpl	$16, %r8d
+	jb	.LBB{2873}
+	jmp	.LBB{34}
+.LBB{2874}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+.LBB{2475}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2875}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+.LBB{2477}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2876}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{2877}
+.LBB{2878}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+	jmp	.LBB{2877}
+.LBB{2879}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	ja	.LBB{826}
+.LBB{2598}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+.LBB{2473}:
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2880}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2881}
+	cmpq	%r9, %r8
+	jae	.LBB{2881}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2882}
+.LBB{2883}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2881}
+.LBB{2882}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2883}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2883}
+	jmp	.LBB{5}
+.LBB{2884}:
+	leaq	.Lanon{8}(%rip), %rcx
+	movq	%rcx, 8(%rsp)
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2885}
+	cmpq	%rdi, %rax
+	jae	.LBB{2885}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2886}
+.LBB{2887}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2885}
+.LBB{2886}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2887}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2887}
+	jmp	.LBB{5}
+.LBB{2888}:
+	cmpl	$16, %r8d
+	jae	.LBB{34}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2442}
+.Ltmp{2889}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2890}:
+.LBB{2442}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{2891}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+.LBB{2452}:
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2832}
+.LBB{2877}:
+	addq	$2, %r13
+	movq	%r13, %rbp
+	jmp	.LBB{4}
+.LBB{2892}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2893}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2894}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2895}
+	cmpq	$-1, %rcx
+	je	.LBB{2896}
+.LBB{2895}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2897}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2898}
+	jmp	.LBB{40}
+.LBB{2899}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2446}
+.Ltmp{2900}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2901}:
+.LBB{2446}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2832}
+.LBB{2902}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2903}
+	xorl	%edx, %edx
+	jmp	.LBB{2904}
+.LBB{2905}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2885}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2906}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2906}
+	jmp	.LBB{4}
+.LBB{2903}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2907}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2904}
+.LBB{2881}:
+	movq	160(%rsp), %rdi
+.Ltmp{2908}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2909}:
+	jmp	.LBB{4}
+.LBB{2894}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2898}
+	jmp	.LBB{40}
+.LBB{2897}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2898}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1695}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1435}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{49}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2910}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2911}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2912}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2913}
+	cmpq	$-1, %rcx
+	je	.LBB{2914}
+.LBB{2913}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2915}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2916}
+	jmp	.LBB{40}
+.LBB{2917}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2918}
+	xorl	%edx, %edx
+	jmp	.LBB{2919}
+.LBB{2920}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2921}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2922}
+	cmpq	%r9, %r8
+	jae	.LBB{2922}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2923}
+.LBB{2924}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2922}
+.LBB{2923}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2924}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2924}
+	jmp	.LBB{5}
+.LBB{2925}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2926}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2927}
+.Ltmp{2928}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2929}:
+	jmp	.LBB{2927}
+.LBB{2930}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2931}
+.Ltmp{2932}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2933}:
+	jmp	.LBB{2931}
+.LBB{2934}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2935}
+	cmpq	%rdi, %rax
+	jae	.LBB{2935}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2936}
+.LBB{2937}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2935}
+.LBB{2936}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2937}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2937}
+	jmp	.LBB{5}
+.LBB{2935}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2938}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2938}
+	jmp	.LBB{4}
+.LBB{2918}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2939}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2919}
+.LBB{2922}:
+	movq	160(%rsp), %rdi
+.Ltmp{2940}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2941}:
+	jmp	.LBB{4}
+.LBB{2912}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2916}
+	jmp	.LBB{40}
+.LBB{2915}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2916}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1696}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1483}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{101}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2942}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2943}:
+	cmpl	$16, %r8d
+	jb	.LBB{2859}
+	jmp	.LBB{9}
+.LBB{2832}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{2944}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2945}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2946}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+.LBB{2576}:
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2947}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2948}
+	jmp	.LBB{39}
+.LBB{2949}:
+	cmpl	$16, %r8d
+	jb	.LBB{2862}
+	jmp	.LBB{9}
+.LBB{2950}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{2951}
+	cmpq	%r9, %r8
+	jae	.LBB{2951}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{2952}
+.LBB{2953}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{2951}
+.LBB{2952}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{2953}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{2953}
+	jmp	.LBB{5}
+.LBB{2954}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{1101}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{1010}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{2955}
+	cmpq	%rdi, %rax
+	jae	.LBB{2955}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{2956}
+.LBB{2957}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{2955}
+.LBB{2956}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{2957}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{2957}
+	jmp	.LBB{5}
+.LBB{2958}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2931}
+.Ltmp{2959}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2960}:
+	jmp	.LBB{2931}
+.LBB{2961}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{2873}:
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2962}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2963}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2964}:
+	cmpl	$16, %r8d
+	jb	.LBB{2864}
+	jmp	.LBB{9}
+.LBB{2965}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2927}
+.Ltmp{2966}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{2967}:
+	jmp	.LBB{2927}
+.LBB{2968}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2969}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2970}
+	cmpq	$-1, %rcx
+	je	.LBB{2971}
+.LBB{2970}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2972}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{2973}
+	jmp	.LBB{40}
+.LBB{2974}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2975}
+	xorl	%edx, %edx
+	jmp	.LBB{2976}
+.LBB{2955}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{2977}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{2977}
+	jmp	.LBB{4}
+.LBB{2975}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2978}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2976}
+.LBB{2951}:
+	movq	160(%rsp), %rdi
+.Ltmp{2979}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{2980}:
+	jmp	.LBB{4}
+.LBB{2969}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{2973}
+	jmp	.LBB{40}
+.LBB{2972}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2973}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1697}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1534}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{30}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{2981}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{2982}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+	cmpl	$15, %r8d
+	jbe	.LBB{2059}
+	jmp	.LBB{217}
+.LBB{2983}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2984}
+	jmp	.LBB{216}
+.LBB{2985}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{2986}
+	jmp	.LBB{21}
+.LBB{2987}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{2988}
+	jmp	.LBB{1085}
+.LBB{2989}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{2990}
+	xorl	%edx, %edx
+	jmp	.LBB{2991}
+.LBB{2992}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2993}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{2994}
+	jmp	.LBB{216}
+.LBB{2995}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{2996}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{2997}
+	cmpq	$-1, %rcx
+	je	.LBB{2998}
+.LBB{2997}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{2999}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3000}
+	jmp	.LBB{217}
+.LBB{3001}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3002}
+	cmpq	%r9, %r8
+	jae	.LBB{3002}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3003}
+.LBB{3004}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3002}
+.LBB{3003}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3004}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3004}
+	jmp	.LBB{5}
+.LBB{3005}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3006}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2927}
+.Ltmp{3007}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3008}:
+	jmp	.LBB{2927}
+.LBB{3009}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jb	.LBB{3010}
+	jmp	.LBB{21}
+.LBB{3011}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{3012}
+	jmp	.LBB{1085}
+.LBB{3013}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2931}
+.Ltmp{3014}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3015}:
+	jmp	.LBB{2931}
+.LBB{3016}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	jbe	.LBB{3017}
+	jmp	.LBB{216}
+.LBB{3018}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3019}
+	cmpq	%rdi, %rax
+	jae	.LBB{3019}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3020}
+.LBB{3021}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3019}
+.LBB{3020}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3021}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3021}
+	jmp	.LBB{5}
+.LBB{3022}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{3023}
+	jmp	.LBB{1085}
+.LBB{3024}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jb	.LBB{3025}
+	jmp	.LBB{1085}
+.LBB{3019}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3026}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3026}
+	jmp	.LBB{4}
+.LBB{2990}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3027}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{2991}
+.LBB{3002}:
+	movq	160(%rsp), %rdi
+.Ltmp{3028}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3029}:
+	jmp	.LBB{4}
+.LBB{2996}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3000}
+	jmp	.LBB{217}
+.LBB{2999}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3000}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1698}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1580}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{129}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{3030}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3031}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3032}
+	jmp	.LBB{4}
+.LBB{3033}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3034}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3035}
+.LBB{3036}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{3032}
+	jmp	.LBB{4}
+.LBB{3037}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{3032}
+	jmp	.LBB{3035}
+.LBB{3038}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3035}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{3039}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+.LBB{2986}:
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3040}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	jbe	.LBB{3041}
+	jmp	.LBB{826}
+.LBB{3042}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{1010}
+.LBB{3010}:
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{3043}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3043}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{3044}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	jbe	.LBB{3045}
+	jmp	.LBB{826}
+.LBB{3046}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	jbe	.LBB{3047}
+	jmp	.LBB{826}
+.LBB{3048}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+	cmpl	$15, %r8d
+	jbe	.LBB{3049}
+	jmp	.LBB{826}
+.LBB{3050}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{3032}
+.LBB{3051}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3052}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{3032}
+.LBB{3053}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	jbe	.LBB{3054}
+	jmp	.LBB{40}
+.LBB{3055}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{891}
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{2059}:
+	movq	16(%rsp,%rbx,8), %rax
+.LBB{2536}:
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3056}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	cmpl	$15, %r8d
+	jbe	.LBB{3057}
+	jmp	.LBB{40}
+.LBB{3058}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+.LBB{2948}:
+	cmpl	$15, %r8d
+	jbe	.LBB{3059}
+	jmp	.LBB{40}
+.LBB{3060}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3061}
+	xorl	%edx, %edx
+	jmp	.LBB{3062}
+.LBB{3063}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3064}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{39}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3065}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3066}
+	cmpq	$-1, %rcx
+	je	.LBB{3067}
+.LBB{3066}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3068}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3069}
+	jmp	.LBB{40}
+.LBB{3070}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{1010}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{1017}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3071}
+	cmpq	%r9, %r8
+	jae	.LBB{3071}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3072}
+.LBB{3073}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3071}
+.LBB{3072}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3073}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3073}
+	jmp	.LBB{5}
+.LBB{3074}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3075}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{2927}
+.Ltmp{3076}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3077}:
+.LBB{2927}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{3032}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{3078}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{2931}
+.Ltmp{3079}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3080}:
+.LBB{2931}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{3081}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3082}
+	cmpq	%rdi, %rax
+	jae	.LBB{3082}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3083}
+.LBB{3084}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3082}
+.LBB{3083}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3084}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3084}
+	jmp	.LBB{5}
+.LBB{3082}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3085}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3085}
+	jmp	.LBB{4}
+.LBB{3061}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3086}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3062}
+.LBB{3071}:
+	movq	160(%rsp), %rdi
+.Ltmp{3087}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3088}:
+	jmp	.LBB{4}
+.LBB{3065}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3069}
+	jmp	.LBB{40}
+.LBB{3068}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{40}
+.LBB{3069}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1837}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1827}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1931}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1913}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1901}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1886}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1969}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1965}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2132}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2122}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2399}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2374}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2095}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2080}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2032}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2025}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2189}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2177}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2231}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2228}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2288}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2285}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2347}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2339}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2432}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2429}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2479}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2461}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2517}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2493}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2571}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2547}:
+	cmpl	$16, %r8d
+	jae	.LBB{371}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2607}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2581}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2638}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2619}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2727}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2709}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2819}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2791}:
+	cmpl	$16, %r8d
+	jae	.LBB{119}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2679}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2670}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2766}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2764}:
+	cmpl	$16, %r8d
+	jae	.LBB{52}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2907}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2904}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2978}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2976}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2854}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2852}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{2939}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2919}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3027}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{2991}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3086}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3062}:
+	cmpl	$16, %r8d
+	jae	.LBB{40}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1791}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1630}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{183}:
+	movq	320(%rsp), %rax
+	movl	(%rax,%r12,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %r14d
+	shrl	$16, %r14d
+	movl	%eax, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r12), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{3089}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3090}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{1974}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{2007}
+	jmp	.LBB{4}
+.LBB{3091}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{3092}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3093}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3094}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3095}
+.LBB{3096}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+.LBB{2020}:
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3097}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{2005}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3098}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jne	.LBB{2007}
+	jmp	.LBB{3095}
+.LBB{3099}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{223}
+.LBB{2003}:
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+	movq	16(%rsp,%rbx,8), %rax
+	addq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3100}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r14d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r14b
+	movzbl	%r14b, %eax
+	movzbl	%bl, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3095}:
+	addq	$2, %r12
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{3101}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{2022}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3102}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{2027}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3103}:
+	movzbl	%r14b, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{220}
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{2030}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3104}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{1978}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{2007}
+.LBB{3105}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%ebx, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3106}
+	cmpq	%r9, %r8
+	jae	.LBB{3106}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3107}
+.LBB{3108}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3106}
+.LBB{3107}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3108}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3108}
+	jmp	.LBB{5}
+.LBB{3109}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+.LBB{1976}:
+	movl	%ebx, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+.LBB{3110}:
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3111}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%r14b, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3112}
+	cmpq	%rdi, %rax
+	jae	.LBB{3112}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3113}
+.LBB{3114}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3112}
+.LBB{3113}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3114}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3114}
+	jmp	.LBB{5}
+.LBB{3115}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1816}
+.Ltmp{3116}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3117}:
+	jmp	.LBB{1816}
+.LBB{3118}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{1988}:
+	shll	$8, %ebx
+	movzbl	%r14b, %eax
+	orl	%ebx, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3119}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3120}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{1980}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{2007}
+.LBB{3121}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3122}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3123}
+	cmpq	$-1, %rcx
+	je	.LBB{3124}
+.LBB{3123}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3125}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3126}
+	jmp	.LBB{217}
+.LBB{3127}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1820}
+.Ltmp{3128}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3129}:
+.LBB{1820}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{2007}
+.LBB{3130}:
+	movzbl	%r14b, %edi
+	cmpb	$15, %r14b
+	ja	.LBB{38}
+	movl	%ebx, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3131}
+	xorl	%edx, %edx
+	jmp	.LBB{3132}
+.LBB{3133}:
+	movzbl	%r14b, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+.LBB{1986}:
+	movl	%ebx, %eax
+.LBB{3134}:
+	addq	16(%rsp,%rcx,8), %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	184(%rsp), %rcx
+	jae	.LBB{3135}
+	movq	176(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{3135}:
+	cmpq	168(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+	movq	160(%rsp), %rcx
+	movq	16(%rsp,%r8,8), %rdx
+	movq	%rdx, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{3136}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3137}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{1982}:
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{2007}:
+	movzbl	%r14b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %rbp
+	jmp	.LBB{4}
+.LBB{3112}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3138}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3138}
+	jmp	.LBB{4}
+.LBB{3131}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3139}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3132}
+.LBB{3106}:
+	movq	160(%rsp), %rdi
+.Ltmp{3140}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3141}:
+	jmp	.LBB{4}
+.LBB{3122}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3126}
+	jmp	.LBB{217}
+.LBB{3125}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3126}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1767}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{1757}
+	jmp	.LBB{217}
+.LBB{3139}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3132}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{1770}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{1757}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{189}
+.LBB{1750}:
+.Ltmp{3142}:
+	movq	%r12, %rdi
+	movq	%r13, %rsi
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3143}:
+	jmp	.LBB{189}
+.LBB{1806}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{1778}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+.LBB{189}:
+	movq	320(%rsp), %rax
+	movq	328(%rsp), %rdi
+	movl	(%rax,%rdi,4), %eax
+	cmpb	$31, %al
+	ja	.LBB{5}
+	movl	%eax, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %r8d
+	movl	%eax, %ebx
+	shrl	$16, %ebx
+	movl	%eax, %r12d
+	shrl	$24, %r12d
+	leaq	1(%rdi), %rbp
+	movzbl	%al, %edx
+	leaq	.LJTI{3144}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{3145}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{3146}
+	jmp	.LBB{4}
+.LBB{3147}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rbp
+	jmp	.LBB{4}
+.LBB{3148}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %r8d
+	ja	.LBB{9}
+.LBB{1984}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3149}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jns	.LBB{4}
+	jmp	.LBB{3146}
+.LBB{3150}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %ecx
+	cmpl	$15, %ecx
+	ja	.LBB{21}
+	movl	%r12d, %esi
+	cmpl	$268435455, %eax
+	ja	.LBB{200}
+	movq	16(%rsp,%rcx,8), %rdx
+	movq	16(%rsp,%rsi,8), %rax
+	movq	%rax, %rcx
+	addq	%rdx, %rcx
+	jb	.LBB{5}
+	movq	168(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	16(%rsp,%r8,8), %r8
+	movq	%rax, %r9
+	addq	%r8, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	184(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{3151}
+	cmpq	%r9, %r8
+	jae	.LBB{3151}
+	movq	176(%rsp), %r9
+	movq	%r8, %r10
+	jmp	.LBB{3152}
+.LBB{3153}:
+	incq	%r10
+	decq	%rax
+	je	.LBB{3151}
+.LBB{3152}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{3153}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{3153}
+	jmp	.LBB{5}
+.LBB{3154}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%r12d, %eax
+	addq	16(%rsp,%rcx,8), %rax
+	cmpq	168(%rsp), %rax
+	jb	.LBB{3110}
+	jmp	.LBB{5}
+.LBB{3155}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	imulq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	16(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+	subq	16(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 16(%rsp,%rcx,8)
+	jmp	.LBB{3156}
+.LBB{3157}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movzbl	%bl, %edx
+	cmpl	$15, %edx
+	ja	.LBB{20}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{21}
+	movq	16(%rsp,%r8,8), %rax
+	movq	16(%rsp,%rcx,8), %rcx
+	movq	%rcx, %rdi
+	addq	%rax, %rdi
+	jb	.LBB{5}
+	cmpq	168(%rsp), %rdi
+	ja	.LBB{5}
+	movq	16(%rsp,%rdx,8), %rdx
+	movq	184(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3158}
+	cmpq	%rdi, %rax
+	jae	.LBB{3158}
+	movq	176(%rsp), %rdi
+	movq	%rcx, %r8
+	movq	%rax, %r9
+	jmp	.LBB{3159}
+.LBB{3160}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{3158}
+.LBB{3159}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rsi, %r10
+	jae	.LBB{3160}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{3160}
+	jmp	.LBB{5}
+.LBB{3161}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	16(%rsp,%r8,8), %r14
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1816}
+.Ltmp{3162}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3163}:
+.LBB{1816}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{3164}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+.LBB{3017}:
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3057}:
+	movq	16(%rsp,%rdi,8), %rax
+	subq	16(%rsp,%rcx,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3165}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+.LBB{2988}:
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{3041}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3166}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	cmpl	$268435455, %eax
+	ja	.LBB{3167}
+	cmpl	$15, %r8d
+	jbe	.LBB{2736}
+	jmp	.LBB{217}
+.LBB{3168}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	je	.LBB{3156}
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{3169}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3170}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	decq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3171}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	jne	.LBB{4}
+	jmp	.LBB{3146}
+.LBB{3172}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+.LBB{2994}:
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3054}:
+	movq	16(%rsp,%rcx,8), %rax
+	imulq	16(%rsp,%rdi,8), %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3173}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{3174}
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{3175}
+	cmpq	$-1, %rcx
+	je	.LBB{3176}
+.LBB{3175}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3177}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %r8d
+	jbe	.LBB{3178}
+	jmp	.LBB{217}
+.LBB{3179}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{3180}
+.Ltmp{3181}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{3182}:
+.LBB{3180}:
+	movq	200(%rsp), %rax
+	movq	%rbp, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{3146}
+.LBB{3183}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+	movq	16(%rsp,%rcx,8), %rcx
+	leaq	-1(%rcx), %rax
+	cmpq	$-2, %rax
+	jb	.LBB{3184}
+	xorl	%edx, %edx
+	jmp	.LBB{3185}
+.LBB{3186}:
+	movzbl	%bl, %edi
+	cmpb	$15, %bl
+	ja	.LBB{38}
+	movl	%r12d, %ecx
+	cmpl	$268435455, %eax
+	ja	.LBB{216}
+.LBB{2984}:
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3059}:
+	movq	16(%rsp,%rdi,8), %rax
+	cmpq	16(%rsp,%rcx,8), %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3187}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+.LBB{3012}:
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{3045}:
+	movq	16(%rsp,%rax,8), %rax
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3188}:
+	movzbl	%bl, %ecx
+	cmpl	$16, %ecx
+	jae	.LBB{21}
+	movl	%r12d, %eax
+	jmp	.LBB{3134}
+.LBB{3189}:
+	cmpl	$16, %r8d
+	jae	.LBB{225}
+	incq	16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3190}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+	cmpq	$0, 16(%rsp,%r8,8)
+	js	.LBB{4}
+.LBB{3146}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %rbp
+	jmp	.LBB{4}
+.LBB{3191}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+.LBB{3023}:
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{3047}:
+	movl	$64, %ecx
+	rep		bsfq	16(%rsp,%rax,8), %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3192}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	16(%rsp,%rax,8), %rax
+	addq	16(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 16(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+	movq	16(%rsp,%rdx,8), %rdx
+	addq	16(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 16(%rsp,%rax,8)
+.LBB{3156}:
+	addq	$2, %rdi
+	movq	%rdi, %rbp
+	jmp	.LBB{4}
+.LBB{3193}:
+	movzbl	%bl, %eax
+	leaq	.Lanon{58}(%rip), %rcx
+	cmpl	$16, %eax
+	jae	.LBB{1085}
+.LBB{3025}:
+	cmpl	$15, %r8d
+	ja	.LBB{221}
+.LBB{3049}:
+	movl	$127, %ecx
+	bsrq	16(%rsp,%rax,8), %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3158}:
+	testq	%rcx, %rcx
+	je	.LBB{4}
+	shlq	$3, %rax
+	addq	160(%rsp), %rax
+	shlq	$3, %rcx
+	xorl	%esi, %esi
+.LBB{3194}:
+	movq	%rdx, (%rax,%rsi)
+	addq	$8, %rsi
+	cmpq	%rsi, %rcx
+	jne	.LBB{3194}
+	jmp	.LBB{4}
+.LBB{3184}:
+	movq	16(%rsp,%rdi,8), %rax
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{3195}
+	cqto
+	idivq	%rcx
+	jmp	.LBB{3185}
+.LBB{3151}:
+	movq	160(%rsp), %rdi
+.Ltmp{3196}:
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{3197}:
+	jmp	.LBB{4}
+.LBB{3174}:
+	xorl	%eax, %eax
+	cmpl	$15, %r8d
+	jbe	.LBB{3178}
+	jmp	.LBB{217}
+.LBB{3177}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %r8d
+	ja	.LBB{217}
+.LBB{3178}:
+	movq	%rax, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{3195}:
+	xorl	%edx, %edx
+	divl	%ecx
+.LBB{3185}:
+	cmpl	$16, %r8d
+	jae	.LBB{217}
+	movq	%rdx, 16(%rsp,%r8,8)
+	jmp	.LBB{4}
+.LBB{116}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$16, %r8d
+	jb	.LBB{118}
+	jmp	.LBB{119}
+.LBB{235}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{227}
+	jmp	.LBB{217}
+.LBB{296}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{283}
+	jmp	.LBB{119}
+.LBB{331}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{319}
+	jmp	.LBB{217}
+.LBB{392}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{375}
+	jmp	.LBB{371}
+.LBB{569}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{521}
+	jmp	.LBB{217}
+.LBB{575}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{525}
+	jmp	.LBB{52}
+.LBB{581}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{529}
+	jmp	.LBB{119}
+.LBB{595}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{533}
+	jmp	.LBB{119}
+.LBB{659}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{558}
+	jmp	.LBB{119}
+.LBB{719}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{651}
+	jmp	.LBB{119}
+.LBB{731}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{672}
+	jmp	.LBB{371}
+.LBB{736}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{676}
+	jmp	.LBB{371}
+.LBB{863}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{842}
+	jmp	.LBB{371}
+.LBB{908}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{910}
+	jmp	.LBB{40}
+.LBB{947}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{931}
+	jmp	.LBB{371}
+.LBB{982}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{956}
+	jmp	.LBB{371}
+.LBB{1076}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1047}
+	jmp	.LBB{40}
+.LBB{1134}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1115}
+	jmp	.LBB{52}
+.LBB{1165}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1128}
+	jmp	.LBB{52}
+.LBB{1214}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1172}
+	jmp	.LBB{52}
+.LBB{1226}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1176}
+	jmp	.LBB{52}
+.LBB{1249}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1203}
+	jmp	.LBB{119}
+.LBB{1328}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1330}
+	jmp	.LBB{40}
+.LBB{1380}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1368}
+	jmp	.LBB{40}
+.LBB{1428}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1416}
+	jmp	.LBB{40}
+.LBB{1474}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1462}
+	jmp	.LBB{40}
+.LBB{1523}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1511}
+	jmp	.LBB{217}
+.LBB{1571}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1559}
+	jmp	.LBB{217}
+.LBB{1812}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1814}
+	jmp	.LBB{217}
+.LBB{1875}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1877}
+	jmp	.LBB{119}
+.LBB{1908}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1910}
+	jmp	.LBB{217}
+.LBB{1938}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1940}
+	jmp	.LBB{371}
+.LBB{2117}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2119}
+	jmp	.LBB{119}
+.LBB{2069}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2071}
+	jmp	.LBB{52}
+.LBB{2169}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2171}
+	jmp	.LBB{119}
+.LBB{2016}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2018}
+	jmp	.LBB{217}
+.LBB{2379}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2381}
+	jmp	.LBB{371}
+.LBB{2223}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2225}
+	jmp	.LBB{119}
+.LBB{2277}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2279}
+	jmp	.LBB{119}
+.LBB{2328}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2330}
+	jmp	.LBB{371}
+.LBB{2420}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2422}
+	jmp	.LBB{371}
+.LBB{2456}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2458}
+	jmp	.LBB{371}
+.LBB{2486}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2488}
+	jmp	.LBB{371}
+.LBB{2552}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2554}
+	jmp	.LBB{371}
+.LBB{2586}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2588}
+	jmp	.LBB{40}
+.LBB{2614}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2616}
+	jmp	.LBB{52}
+.LBB{2662}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2664}
+	jmp	.LBB{52}
+.LBB{2686}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2688}
+	jmp	.LBB{52}
+.LBB{2797}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2799}
+	jmp	.LBB{119}
+.LBB{2757}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2759}
+	jmp	.LBB{52}
+.LBB{2896}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2898}
+	jmp	.LBB{40}
+.LBB{2971}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2973}
+	jmp	.LBB{40}
+.LBB{2826}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2828}
+	jmp	.LBB{40}
+.LBB{2914}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{2916}
+	jmp	.LBB{40}
+.LBB{2998}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3000}
+	jmp	.LBB{217}
+.LBB{3067}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3069}
+	jmp	.LBB{40}
+.LBB{1620}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1608}
+	jmp	.LBB{217}
+.LBB{3124}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3126}
+	jmp	.LBB{217}
+.LBB{1769}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{1757}
+	jmp	.LBB{217}
+.LBB{3176}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %r8d
+	jbe	.LBB{3178}
+.LBB{217}:
+	movq	%r8, %rdi
+	jmp	.LBB{38}
+.LBB{3198}:
+	cmpl	$16, %r8d
+	jb	.LBB{3199}
+.LBB{34}:
+	leaq	.Lanon{8}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{3200}:
+	cmpl	$16, %r8d
+	jae	.LBB{9}
+.LBB{3199}:
+	movq	16(%rsp,%r8,8), %rax
+	movq	%rax, 336(%rsp)
+.LBB{5}:
+	movq	152(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3201}
+	movq	160(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{3201}:
+	movq	176(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{3202}
+	leaq	176(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{3202}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3203}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{3203}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{3204}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{3204}:
+	movq	336(%rsp), %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1}:
+	movq	%rdx, 16(%rsp)
+	leaq	16(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{3205}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{3206}(%rip), %rdi
+	leaq	.Lanon{3207}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{185}:
+.Ltmp{3208}:
+	leaq	.Lanon{3209}(%rip), %rdi
+	leaq	.Lanon{3210}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{3211}:
+	jmp	.LBB{3212}
+.LBB{21}:
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{52}:
+	movq	%r8, %rdi
+	jmp	.LBB{38}
+.LBB{119}:
+	movq	%r8, %rdi
+	jmp	.LBB{38}
+.LBB{225}:
+	leaq	.Lanon{58}(%rip), %rax
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{113}:
+	movq	%rcx, %rdi
+	jmp	.LBB{38}
+.LBB{51}:
+	movq	%rcx, %rdi
+	jmp	.LBB{38}
+.LBB{40}:
+	movq	%r8, %rdi
+	jmp	.LBB{38}
+.LBB{84}:
+	movq	%r14, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{39}:
+	movq	%rcx, %rdi
+	jmp	.LBB{38}
+.LBB{59}:
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{371}:
+	movq	%r8, %rdi
+	jmp	.LBB{38}
+.LBB{453}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{139}:
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{20}:
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{370}:
+	movq	%rcx, %rdi
+	jmp	.LBB{38}
+.LBB{216}:
+	movq	%rcx, %rdi
+	jmp	.LBB{38}
+.LBB{299}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{259}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{826}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1010}:
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{358}:
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{1085}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{73}:
+	movq	%r12, %rdi
+	jmp	.LBB{38}
+.LBB{425}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{454}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{455}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{395}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{427}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{220}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{1431}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{598}:
+	movq	%rax, %r8
+	movq	%r14, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{586}:
+	movq	%rax, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1017}:
+	movq	%rsi, %r8
+	jmp	.LBB{9}
+.LBB{364}:
+	movq	%rsi, %r8
+	jmp	.LBB{9}
+.LBB{404}:
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{223}:
+	movq	%rbx, %rdi
+	jmp	.LBB{38}
+.LBB{1101}:
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{891}:
+	movq	%rbx, %rdi
+	jmp	.LBB{38}
+.LBB{587}:
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{373}:
+	movq	%rbx, %rdi
+	jmp	.LBB{38}
+.LBB{260}:
+	movq	%rdx, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{261}:
+	movq	%rsi, %r8
+	movq	%rcx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{242}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{200}:
+	movq	%rsi, %r8
+	jmp	.LBB{9}
+.LBB{281}:
+	movq	%rbx, %rdi
+	jmp	.LBB{38}
+.LBB{422}:
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{584}:
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{244}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1317}:
+	movq	%rax, %rdi
+	jmp	.LBB{38}
+.LBB{517}:
+	movq	%rbx, %rdi
+	jmp	.LBB{38}
+.LBB{831}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{2140}:
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{2311}:
+	movq	%rcx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{1623}:
+	movq	%rax, %r8
+	jmp	.LBB{221}
+.LBB{2535}:
+	movq	%r13, %rdi
+	jmp	.LBB{38}
+.LBB{1548}:
+	movq	%r9, %r8
+	jmp	.LBB{9}
+.LBB{640}:
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{448}:
+	movq	%rsi, %r8
+	jmp	.LBB{9}
+.LBB{1865}:
+	movq	%r12, %rdi
+	jmp	.LBB{38}
+.LBB{2101}:
+	movq	%r13, %rdi
+.LBB{38}:
+.Ltmp{3213}:
+	leaq	.Lanon{58}(%rip), %rdx
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{3214}:
+	jmp	.LBB{3212}
+.LBB{442}:
+	movq	%rdx, %r8
+	jmp	.LBB{221}
+.LBB{443}:
+	movq	%rsi, %r8
+.LBB{221}:
+	movq	%rcx, 8(%rsp)
+.LBB{9}:
+.Ltmp{3215}:
+	movq	8(%rsp), %rdx
+	movq	%r8, %rdi
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{3216}:
+.LBB{3212}:
+	ud2
+.LBB{417}:
+	movq	%rdx, %r8
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{419}:
+	movq	%rax, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1309}:
+	movq	%rdx, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1310}:
+	movq	%rdx, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{1340}:
+	movq	%rdi, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{1341}:
+	movq	%rdi, 8(%rsp)
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{1342}:
+	movq	%rdi, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{1311}:
+	movq	%rdx, 8(%rsp)
+	movq	%rax, %r8
+	jmp	.LBB{9}
+.LBB{1302}:
+	movq	%rsi, 8(%rsp)
+	movq	%rcx, %r8
+	jmp	.LBB{9}
+.LBB{2148}:
+	movq	%rdx, %r8
+	jmp	.LBB{9}
+.LBB{2141}:
+	movq	%rsi, %r8
+	jmp	.LBB{9}
+.LBB{1304}:
+	movq	%rsi, 8(%rsp)
+	jmp	.LBB{9}
+.LBB{3167}:
+	movq	%r12, %rdi
+	jmp	.LBB{38}
+.LBB{3217}:
+.Ltmp{3218}:
+	movq	%rax, %rbx
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{3219}:
+.Ltmp{3220}:
+	jmp	.LBB{3221}
+.LBB{3222}:
+.Ltmp{3223}:
+.LBB{3221}:
+	movq	%rax, %rbx
+	leaq	152(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,10690 @@
+.Lfunc_begin{0}:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$344, %rsp
+	cmpq	$17, %rdx
+	jae	.LBB{1}
+	movq	%rdi, %rbx
+	movq	160(%rdi), %rax
+	movq	%rax, 328(%rsp)
+	xorps	%xmm0, %xmm0
+	movaps	%xmm0, 304(%rsp)
+	movaps	%xmm0, 288(%rsp)
+	movaps	%xmm0, 272(%rsp)
+	movaps	%xmm0, 256(%rsp)
+	movaps	%xmm0, 240(%rsp)
+	movaps	%xmm0, 224(%rsp)
+	movaps	%xmm0, 208(%rsp)
+	movaps	%xmm0, 192(%rsp)
+	shlq	$3, %rdx
+	leaq	192(%rsp), %rdi
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	192(%rsp), %xmm0
+	movaps	208(%rsp), %xmm1
+	movaps	224(%rsp), %xmm2
+	movaps	240(%rsp), %xmm3
+	movaps	%xmm0, 48(%rsp)
+	movaps	%xmm1, 64(%rsp)
+	movaps	%xmm2, 80(%rsp)
+	movaps	%xmm3, 96(%rsp)
+	movaps	256(%rsp), %xmm0
+	movaps	%xmm0, 112(%rsp)
+	movaps	272(%rsp), %xmm0
+	movaps	%xmm0, 128(%rsp)
+	movaps	288(%rsp), %xmm0
+	movaps	%xmm0, 144(%rsp)
+	movaps	304(%rsp), %xmm0
+	movaps	%xmm0, 160(%rsp)
+	movq	$0, 192(%rsp)
+	movq	$8, 200(%rsp)
+	xorps	%xmm0, %xmm0
+	movups	%xmm0, 208(%rsp)
+	movq	$8, 224(%rsp)
+	movq	$0, 232(%rsp)
+	addq	$176, %rbx
+.Ltmp{2}:
+	leaq	8(%rsp), %rdi
+	movq	%rbx, %rsi
+	callq	_ZN9rust_goto6memory11MemoryImage5fresh17h{hash}E
+.Ltmp{3}:
+	xorl	%r15d, %r15d
+.LBB{4}:
+	movq	328(%rsp), %rax
+	movl	(%rax,%r15,4), %esi
+	movq	$-1, 184(%rsp)
+	cmpb	$31, %sil
+	ja	.LBB{5}
+	movl	%esi, %edx
+	shrl	$8, %edx
+	movzbl	%dl, %edi
+	movl	%esi, %ebx
+	shrl	$16, %ebx
+	movl	%esi, %r13d
+	shrl	$24, %r13d
+	leaq	1(%r15), %r14
+	movl	%esi, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%esi, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%esi, %ecx
+	shrl	$21, %ecx
+	andl	$120, %ecx
+	movq	48(%rsp,%rcx), %rcx
+	movzbl	%sil, %esi
+	leaq	.LJTI{6}(%rip), %r8
+	movslq	(%r8,%rsi,4), %rsi
+	addq	%r8, %rsi
+	jmpq	*%rsi
+.LBB{7}:
+	cmpl	$16, %edi
+	jae	.LBB{8}
+	shll	$8, %r13d
+	movzbl	%bl, %eax
+	orl	%r13d, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{9}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{10}:
+	cmpl	$16, %edi
+	jae	.LBB{11}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{12}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	testq	%r12, %r12
+	cmovsq	%r13, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r14), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{13}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{14}:
+	cmpl	$16, %edi
+	jb	.LBB{15}
+	jmp	.LBB{16}
+.LBB{17}:
+	movq	%rcx, %rsi
+	addq	%r12, %rsi
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rsi
+	ja	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{18}
+	cmpq	%rsi, %r12
+	jae	.LBB{18}
+	movq	32(%rsp), %rsi
+	movq	%rcx, %rdi
+	movq	%r12, %r8
+	jmp	.LBB{19}
+.LBB{20}:
+	incq	%r8
+	decq	%rdi
+	je	.LBB{18}
+.LBB{19}:
+	movq	%r8, %r9
+	shrq	$6, %r9
+	cmpq	%rdx, %r9
+	jae	.LBB{20}
+	movq	16(%rsi,%r9,8), %r9
+	btq	%r8, %r9
+	jae	.LBB{20}
+	jmp	.LBB{5}
+.LBB{21}:
+	addq	%r13, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{22}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{22}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{23}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{24}:
+	cmpl	$16, %edi
+	jb	.LBB{25}
+	jmp	.LBB{26}
+.LBB{27}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movzbl	%cl, %ecx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rcx,8), %rax
+	movl	%edx, %ecx
+	andl	$15, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r13b, %ecx
+	subq	48(%rsp,%rcx,8), %rax
+	shrb	$4, %dl
+	movzbl	%dl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	3(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{28}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{29}:
+	cmpl	$16, %edi
+	jb	.LBB{30}
+	jmp	.LBB{31}
+.LBB{32}:
+	cmpl	$16, %edi
+	jae	.LBB{33}
+	cmpq	%rcx, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{34}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{35}:
+	cmpl	$16, %edi
+	jae	.LBB{36}
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{37}
+.LBB{38}:
+	movq	232(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{5}
+	leaq	-1(%rdx), %r10
+	movq	%r10, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{39}
+	movq	224(%rsp), %rsi
+	movq	-8(%rsi,%rdx,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %r11d
+	cmpb	$31, %r11b
+	ja	.LBB{5}
+	movl	%r11d, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%r11d, %ebx
+	shrl	$16, %ebx
+	movl	%r11d, %r12d
+	shrl	$24, %r12d
+	leaq	2(%r15), %r14
+	movl	%r11d, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r8
+	movl	%r11d, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%r11d, %r9d
+	shrl	$21, %r9d
+	andl	$120, %r9d
+	movq	48(%rsp,%r9), %r9
+	movzbl	%r11b, %r11d
+	movq	%r15, %r13
+	leaq	.LJTI{40}(%rip), %r15
+	movslq	(%r15,%r11,4), %r11
+	addq	%r15, %r11
+	movq	%r13, %r15
+	jmpq	*%r11
+.LBB{41}:
+	movq	%r14, %r15
+	testq	%r8, %r8
+	jne	.LBB{42}
+	jmp	.LBB{4}
+.LBB{43}:
+	cmpl	$16, %edi
+	jae	.LBB{44}
+	subq	%rcx, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{45}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{46}:
+	cmpl	$16, %edi
+	jb	.LBB{47}
+	jmp	.LBB{48}
+.LBB{49}:
+	cmpl	$16, %edi
+	jae	.LBB{50}
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{51}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{52}:
+	cmpl	$16, %edi
+	jb	.LBB{53}
+	jmp	.LBB{54}
+.LBB{55}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{56}
+.Ltmp{57}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{58}:
+.LBB{56}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 208(%rsp)
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %esi
+	cmpb	$31, %sil
+	ja	.LBB{5}
+	movl	%esi, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%esi, %ebp
+	shrl	$16, %ebp
+	movl	%esi, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r14
+	movl	%esi, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%esi, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%esi, %r8d
+	shrl	$21, %r8d
+	andl	$120, %r8d
+	movq	48(%rsp,%r8), %r8
+	movzbl	%sil, %esi
+	leaq	.LJTI{59}(%rip), %r9
+	movslq	(%r9,%rsi,4), %rsi
+	addq	%r9, %rsi
+	jmpq	*%rsi
+.LBB{60}:
+	testq	%r12, %r12
+	movq	%r14, %r15
+	jne	.LBB{61}
+	jmp	.LBB{4}
+.LBB{62}:
+	cmpl	$16, %edi
+	jae	.LBB{63}
+	addq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{64}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{65}:
+	cmpl	$16, %edi
+	jb	.LBB{47}
+	jmp	.LBB{66}
+.LBB{67}:
+	cmpl	$16, %edi
+	jae	.LBB{68}
+	movq	%r15, %rax
+	addq	$2, %rax
+	movzbl	%bl, %ecx
+	shll	$8, %r13d
+	orl	%ecx, %r13d
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	cmoveq	%rax, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{69}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{70}:
+	testq	%r12, %r12
+	jne	.LBB{71}
+	jmp	.LBB{4}
+.LBB{72}:
+	cmpl	$16, %edi
+	jae	.LBB{73}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{74}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{75}:
+	cmpl	$16, %edi
+	jb	.LBB{76}
+	jmp	.LBB{77}
+.LBB{78}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	testq	%r12, %r12
+	cmoveq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{79}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{80}:
+	testq	%r12, %r12
+	jne	.LBB{81}
+	jmp	.LBB{4}
+.LBB{82}:
+	movq	%r15, %rbp
+	movq	232(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	216(%rsp), %r15
+	movq	%rbp, %r14
+	jne	.LBB{83}
+.Ltmp{84}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{85}:
+.LBB{83}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%r15,8)
+	leaq	1(%r15), %rdx
+	movq	%rdx, 232(%rsp)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r14,4), %esi
+	cmpb	$31, %sil
+	ja	.LBB{5}
+	movl	%esi, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%esi, %ebx
+	shrl	$16, %ebx
+	movl	%esi, %r13d
+	shrl	$24, %r13d
+	leaq	2(%r14), %rax
+	movq	%rax, 336(%rsp)
+	movl	%esi, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%esi, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%esi, %r8d
+	shrl	$21, %r8d
+	andl	$120, %r8d
+	movq	48(%rsp,%r8), %r8
+	movzbl	%sil, %esi
+	leaq	.LJTI{86}(%rip), %r9
+	movslq	(%r9,%rsi,4), %rsi
+	addq	%r9, %rsi
+	jmpq	*%rsi
+.LBB{87}:
+	movq	336(%rsp), %r15
+	testq	%r12, %r12
+	jne	.LBB{88}
+	jmp	.LBB{4}
+.LBB{89}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	testq	%r12, %r12
+	cmoveq	%r13, %r14
+	movq	328(%rsp), %rax
+	movl	(%rax,%r14,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r14), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{90}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{91}:
+	cmpl	$16, %edi
+	jb	.LBB{15}
+	jmp	.LBB{92}
+.LBB{93}:
+	cmpl	$16, %edi
+	jae	.LBB{94}
+	imulq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{95}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{96}:
+	cmpl	$16, %edi
+	jb	.LBB{47}
+	jmp	.LBB{97}
+.LBB{98}:
+	testq	%rcx, %rcx
+	je	.LBB{99}
+	movq	%rax, %rdx
+	negq	%rdx
+	jno	.LBB{100}
+	cmpq	$-1, %rcx
+	je	.LBB{101}
+.LBB{100}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{102}
+	cqto
+	idivq	%rcx
+	cmpl	$15, %edi
+	jbe	.LBB{103}
+	jmp	.LBB{104}
+.LBB{105}:
+	movq	208(%rsp), %r10
+	testq	%r10, %r10
+	je	.LBB{5}
+	leaq	-1(%r10), %rbx
+	movq	%rbx, 208(%rsp)
+	movq	200(%rsp), %r11
+	movq	-8(%r11,%r10,8), %rsi
+	movq	328(%rsp), %rax
+	movl	(%rax,%rsi,4), %ebp
+	cmpb	$31, %bpl
+	ja	.LBB{5}
+	movl	%ebp, %r9d
+	shrl	$8, %r9d
+	movzbl	%r9b, %edi
+	movl	%ebp, %edx
+	shrl	$16, %edx
+	movl	%ebp, %ecx
+	shrl	$24, %ecx
+	leaq	1(%rsi), %r13
+	movl	%ebp, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%ebp, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%ebp, %r8d
+	shrl	$21, %r8d
+	andl	$120, %r8d
+	movq	48(%rsp,%r8), %r8
+	movzbl	%bpl, %r14d
+	leaq	.LJTI{106}(%rip), %r15
+	movslq	(%r15,%r14,4), %r14
+	addq	%r15, %r14
+	jmpq	*%r14
+.LBB{107}:
+	testq	%r12, %r12
+	movq	%r13, %r15
+	jne	.LBB{108}
+	jmp	.LBB{4}
+.LBB{109}:
+	addq	%r13, %rax
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{110}
+	movq	16(%rsp), %rdx
+	movq	(%rdx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %r9d
+	cmpb	$31, %r9b
+	ja	.LBB{5}
+	movl	%r9d, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%r9d, %ebx
+	shrl	$16, %ebx
+	movl	%r9d, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%r9d, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%r9d, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%r9d, %r8d
+	shrl	$21, %r8d
+	andl	$120, %r8d
+	movq	48(%rsp,%r8), %r8
+	movzbl	%r9b, %r9d
+	leaq	.LJTI{111}(%rip), %r10
+	movslq	(%r10,%r9,4), %r9
+	addq	%r10, %r9
+	jmpq	*%r9
+.LBB{112}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{113}
+	jmp	.LBB{4}
+.LBB{114}:
+	leaq	-1(%rcx), %rdx
+	cmpq	$-2, %rdx
+	jb	.LBB{115}
+	xorl	%edx, %edx
+	cmpl	$16, %edi
+	jb	.LBB{116}
+	jmp	.LBB{117}
+.LBB{118}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{119}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{120}:
+	cmpl	$16, %edi
+	jb	.LBB{15}
+	jmp	.LBB{121}
+.LBB{122}:
+	cmpl	$16, %edi
+	jae	.LBB{123}
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rsi
+	andq	%rsi, %rcx
+	subq	%rcx, %rax
+	movabsq	$3689348814741910323, %rdx
+	movq	%rax, %rcx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %r9
+	andq	%r9, %rcx
+	movabsq	$72340172838076673, %r10
+	imulq	%r10, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %r11d
+	cmpb	$31, %r11b
+	ja	.LBB{5}
+	movl	%r11d, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%r11d, %ebx
+	shrl	$16, %ebx
+	movl	%r11d, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%r11d, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%r11d, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%r11d, %r8d
+	shrl	$21, %r8d
+	andl	$120, %r8d
+	movq	48(%rsp,%r8), %r8
+	movzbl	%r11b, %r11d
+	movq	%r15, %rbp
+	leaq	.LJTI{124}(%rip), %r15
+	movslq	(%r15,%r11,4), %r11
+	addq	%r15, %r11
+	movq	%rbp, %r15
+	jmpq	*%r11
+.LBB{125}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{126}
+	jmp	.LBB{4}
+.LBB{127}:
+	movq	%rcx, %rdx
+	addq	%rax, %rdx
+	jb	.LBB{5}
+	movq	24(%rsp), %rbp
+	cmpq	%rbp, %rdx
+	ja	.LBB{5}
+	movq	%rcx, %rdx
+	addq	%r12, %rdx
+	setb	%sil
+	cmpq	%rbp, %rdx
+	seta	%dil
+	orb	%sil, %dil
+	jne	.LBB{5}
+	movq	40(%rsp), %r14
+	testq	%r14, %r14
+	je	.LBB{128}
+	cmpq	%rdx, %r12
+	jae	.LBB{128}
+	movq	32(%rsp), %rdx
+	movq	%rcx, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{129}
+.LBB{130}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{128}
+.LBB{129}:
+	movq	%rdi, %r8
+	shrq	$6, %r8
+	cmpq	%r14, %r8
+	jae	.LBB{130}
+	movq	16(%rdx,%r8,8), %r8
+	btq	%rdi, %r8
+	jae	.LBB{130}
+	jmp	.LBB{5}
+.LBB{131}:
+	cmpl	$16, %edi
+	jae	.LBB{132}
+	incq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{133}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{134}:
+	cmpl	$16, %edi
+	jb	.LBB{76}
+	jmp	.LBB{135}
+.LBB{136}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	testq	%r12, %r12
+	cmovsq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{137}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{138}:
+	testq	%r12, %r12
+	jne	.LBB{139}
+	jmp	.LBB{4}
+.LBB{140}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	testq	%r12, %r12
+	cmoveq	%r14, %r13
+	movq	328(%rsp), %rax
+	movl	(%rax,%r13,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	1(%r13), %r15
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{141}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{142}:
+	testq	%r12, %r12
+	jne	.LBB{143}
+	jmp	.LBB{4}
+.LBB{144}:
+	cmpl	$16, %edi
+	jae	.LBB{145}
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{146}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{147}:
+	cmpl	$16, %edi
+	jb	.LBB{53}
+	jmp	.LBB{148}
+.LBB{149}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %ecx
+	andb	$15, %cl
+	movzbl	%cl, %ecx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rcx,8), %rax
+	movl	%edx, %ecx
+	andl	$15, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r13b, %ecx
+	movq	48(%rsp,%rcx,8), %rcx
+	addq	48(%rsp,%rax,8), %rcx
+	shrb	$4, %dl
+	movzbl	%dl, %eax
+	movq	%rcx, 48(%rsp,%rax,8)
+	movq	328(%rsp), %rax
+	movl	8(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	3(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{150}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{151}:
+	cmpl	$16, %edi
+	jb	.LBB{30}
+	jmp	.LBB{152}
+.LBB{153}:
+	cmpl	$16, %edi
+	jae	.LBB{154}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{155}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{156}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{157}
+	jmp	.LBB{4}
+.LBB{18}:
+	testq	%rcx, %rcx
+	je	.LBB{158}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %rcx
+	xorl	%edx, %edx
+.LBB{159}:
+	movq	%rax, (%r12,%rdx)
+	addq	$8, %rdx
+	cmpq	%rdx, %rcx
+	jne	.LBB{159}
+.LBB{158}:
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebp
+	shrl	$16, %ebp
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{160}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{161}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{71}
+	jmp	.LBB{4}
+.LBB{115}:
+	movq	%rax, %rdx
+	orq	%rcx, %rdx
+	shrq	$32, %rdx
+	je	.LBB{162}
+	cqto
+	idivq	%rcx
+	cmpl	$16, %edi
+	jb	.LBB{116}
+	jmp	.LBB{117}
+.LBB{128}:
+	movq	%rbp, %rdx
+	subq	%rcx, %rdx
+	cmpq	%rdx, %r12
+	ja	.LBB{163}
+	movq	16(%rsp), %r13
+	leaq	(,%rax,8), %rsi
+	addq	%r13, %rsi
+	leaq	(,%r12,8), %rdi
+	addq	%r13, %rdi
+	shlq	$3, %rcx
+	movq	%rcx, %rdx
+	callq	*memmove@GOTPCREL(%rip)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %r12d
+	shrl	$16, %r12d
+	movl	%edx, %ebx
+	shrl	$24, %ebx
+	leaq	2(%r15), %r9
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r8
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %rsi
+	movzbl	%dl, %edx
+	leaq	.LJTI{164}(%rip), %r10
+	movslq	(%r10,%rdx,4), %rdx
+	addq	%r10, %rdx
+	jmpq	*%rdx
+.LBB{165}:
+	movq	%r9, %r15
+	testq	%r8, %r8
+	jne	.LBB{166}
+	jmp	.LBB{4}
+.LBB{99}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{103}
+	jmp	.LBB{104}
+.LBB{102}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$15, %edi
+	ja	.LBB{104}
+.LBB{103}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{167}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{168}:
+	cmpl	$16, %edi
+	jae	.LBB{169}
+.LBB{47}:
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{170}
+.LBB{171}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{172}
+	jmp	.LBB{4}
+.LBB{162}:
+	xorl	%edx, %edx
+	divl	%ecx
+	cmpl	$16, %edi
+	jae	.LBB{117}
+.LBB{116}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	328(%rsp), %rax
+	movl	4(%rax,%r15,4), %edx
+	cmpb	$31, %dl
+	ja	.LBB{5}
+	movl	%edx, %ecx
+	shrl	$8, %ecx
+	movzbl	%cl, %edi
+	movl	%edx, %ebx
+	shrl	$16, %ebx
+	movl	%edx, %r14d
+	shrl	$24, %r14d
+	leaq	2(%r15), %r13
+	movl	%edx, %eax
+	shrl	$5, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %r12
+	movl	%edx, %eax
+	shrl	$13, %eax
+	andl	$120, %eax
+	movq	48(%rsp,%rax), %rax
+	movl	%edx, %esi
+	shrl	$21, %esi
+	andl	$120, %esi
+	movq	48(%rsp,%rsi), %r8
+	movzbl	%dl, %edx
+	leaq	.LJTI{173}(%rip), %rsi
+	movslq	(%rsi,%rdx,4), %rdx
+	addq	%rsi, %rdx
+	jmpq	*%rdx
+.LBB{174}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{175}
+	jmp	.LBB{4}
+.LBB{176}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{177}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{177}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{178}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{179}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{180}:
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{143}
+.LBB{181}:
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{143}
+.LBB{182}:
+	testq	%r12, %r12
+	jns	.LBB{143}
+	jmp	.LBB{4}
+.LBB{183}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{184}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{172}
+.LBB{185}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{175}
+.LBB{186}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{172}
+	jmp	.LBB{4}
+.LBB{187}:
+	addq	%r14, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{188}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{188}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{189}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{190}
+.LBB{191}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{175}
+	jmp	.LBB{4}
+.LBB{192}:
+	addq	%r14, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{193}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{193}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{194}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{195}
+.LBB{196}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{190}
+.LBB{197}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{198}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{172}
+.LBB{199}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{195}
+.LBB{200}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{201}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{175}
+.LBB{202}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{203}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{204}
+.LBB{205}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{204}
+.LBB{206}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{157}
+.LBB{207}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{208}
+.LBB{209}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{157}
+	jmp	.LBB{4}
+.LBB{210}:
+	addq	%r14, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{211}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{211}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{212}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{157}
+.LBB{213}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{208}
+.LBB{214}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{71}
+.LBB{215}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{71}
+.LBB{216}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{217}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{217}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{218}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{71}
+	jmp	.LBB{4}
+.LBB{219}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{220}
+.LBB{221}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{220}
+.LBB{222}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{126}
+.LBB{223}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{224}
+.LBB{225}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{126}
+.LBB{226}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{126}
+	jmp	.LBB{4}
+.LBB{227}:
+	addq	%r14, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jb	.LBB{228}
+	jmp	.LBB{229}
+.LBB{230}:
+	movq	208(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	200(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %r15
+	jmp	.LBB{4}
+.LBB{231}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+	jmp	.LBB{224}
+.LBB{232}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{113}
+.LBB{233}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r14b, %edx
+	jmp	.LBB{234}
+.LBB{235}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{113}
+.LBB{236}:
+	movq	%r13, %r15
+	testq	%r12, %r12
+	jns	.LBB{113}
+	jmp	.LBB{4}
+.LBB{237}:
+	movl	%r14d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r14b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r14b, %edx
+	jmp	.LBB{238}
+.LBB{239}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{240}
+	cmpq	%rdi, %r12
+	jae	.LBB{240}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{241}
+.LBB{242}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{240}
+.LBB{241}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{242}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{242}
+	jmp	.LBB{5}
+.LBB{243}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{244}
+	jmp	.LBB{16}
+.LBB{245}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{246}
+.LBB{247}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{248}
+	cmpq	%rdx, %r12
+	jae	.LBB{248}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{249}
+.LBB{250}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{248}
+.LBB{249}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{250}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{250}
+	jmp	.LBB{5}
+.LBB{251}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{252}
+.Ltmp{253}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{254}:
+	jmp	.LBB{252}
+.LBB{255}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{256}
+	jmp	.LBB{31}
+.LBB{257}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{258}
+.Ltmp{259}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{260}:
+	jmp	.LBB{258}
+.LBB{261}:
+	cmpl	$16, %edi
+	jb	.LBB{262}
+	jmp	.LBB{16}
+.LBB{263}:
+	cmpl	$16, %edi
+	jae	.LBB{16}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{264}:
+	cmpl	$16, %edi
+	jae	.LBB{16}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	je	.LBB{246}
+	jmp	.LBB{139}
+.LBB{265}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{266}
+.Ltmp{267}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{268}:
+	jmp	.LBB{266}
+.LBB{269}:
+	cmpl	$16, %edi
+	jb	.LBB{270}
+	jmp	.LBB{16}
+.LBB{271}:
+	cmpl	$16, %edi
+	jb	.LBB{272}
+	jmp	.LBB{16}
+.LBB{273}:
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{81}
+.LBB{274}:
+	cmpl	$16, %edi
+	jb	.LBB{275}
+	jmp	.LBB{16}
+.LBB{276}:
+	cmpl	$16, %edi
+	jb	.LBB{277}
+	jmp	.LBB{16}
+.LBB{278}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{279}
+.Ltmp{280}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{281}:
+	jmp	.LBB{279}
+.LBB{282}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{31}
+.LBB{284}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{285}
+	jmp	.LBB{16}
+.LBB{286}:
+	testq	%r8, %r8
+	je	.LBB{287}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{288}
+	cmpq	$-1, %r8
+	je	.LBB{289}
+.LBB{288}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{290}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{291}
+	jmp	.LBB{16}
+.LBB{292}:
+	cmpl	$16, %edi
+	jb	.LBB{293}
+	jmp	.LBB{16}
+.LBB{294}:
+	cmpl	$16, %edi
+	jb	.LBB{295}
+	jmp	.LBB{16}
+.LBB{296}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{297}
+	xorl	%edx, %edx
+	jmp	.LBB{298}
+.LBB{299}:
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{139}
+.LBB{300}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{31}
+.LBB{302}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{303}
+	cmpq	%rdi, %r12
+	jae	.LBB{303}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{304}
+.LBB{305}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{303}
+.LBB{304}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{305}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{305}
+	jmp	.LBB{5}
+.LBB{306}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{244}
+	jmp	.LBB{307}
+.LBB{308}:
+	cmpl	$16, %edi
+	jb	.LBB{309}
+	jmp	.LBB{31}
+.LBB{310}:
+	testq	%r8, %r8
+	je	.LBB{311}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{312}
+	cmpq	$-1, %r8
+	je	.LBB{313}
+.LBB{312}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{314}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{31}
+.LBB{316}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{317}
+	xorl	%edx, %edx
+	jmp	.LBB{318}
+.LBB{319}:
+	cmpl	$16, %edi
+	jb	.LBB{315}
+	jmp	.LBB{31}
+.LBB{320}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{321}
+	cmpq	%rdi, %r12
+	jae	.LBB{321}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{322}
+.LBB{323}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{321}
+.LBB{322}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{323}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{323}
+	jmp	.LBB{5}
+.LBB{324}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{325}
+	cmpq	%rdx, %r12
+	jae	.LBB{325}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{326}
+.LBB{327}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{325}
+.LBB{326}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{327}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{327}
+	jmp	.LBB{5}
+.LBB{328}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{244}
+	jmp	.LBB{92}
+.LBB{329}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{330}
+	cmpq	%rdx, %r12
+	jae	.LBB{330}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{331}
+.LBB{332}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{330}
+.LBB{331}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{332}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{332}
+	jmp	.LBB{5}
+.LBB{333}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{334}
+.LBB{335}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{31}
+.LBB{337}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{338}
+	cmpq	%rdx, %r12
+	jae	.LBB{338}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{339}
+.LBB{340}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{338}
+.LBB{339}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{340}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{340}
+	jmp	.LBB{5}
+.LBB{341}:
+	cmpl	$16, %edi
+	jb	.LBB{342}
+	jmp	.LBB{16}
+.LBB{343}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{244}
+	jmp	.LBB{121}
+.LBB{344}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{252}
+.Ltmp{345}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{346}:
+	jmp	.LBB{252}
+.LBB{347}:
+	cmpl	$16, %edi
+	jb	.LBB{348}
+	jmp	.LBB{31}
+.LBB{349}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{350}
+	cmpq	%rdi, %r12
+	jae	.LBB{350}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{351}
+.LBB{352}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{350}
+.LBB{351}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{352}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{352}
+	jmp	.LBB{5}
+.LBB{353}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{354}
+	cmpq	%rdi, %r12
+	jae	.LBB{354}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{355}
+.LBB{356}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{354}
+.LBB{355}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{356}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{356}
+	jmp	.LBB{5}
+.LBB{357}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{358}
+.LBB{244}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{359}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{360}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{361}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{31}
+.LBB{363}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{246}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{364}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{252}
+.Ltmp{365}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{366}:
+	jmp	.LBB{252}
+.LBB{367}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{368}
+	jmp	.LBB{31}
+.LBB{369}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{31}
+.LBB{371}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{252}
+.Ltmp{372}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{373}:
+	jmp	.LBB{252}
+.LBB{374}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{375}
+.LBB{376}:
+	cmpl	$16, %edi
+	jb	.LBB{377}
+	jmp	.LBB{31}
+.LBB{378}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{379}
+	cmpq	%rdx, %r12
+	jae	.LBB{379}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{380}
+.LBB{381}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{379}
+.LBB{380}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{381}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{381}
+	jmp	.LBB{5}
+.LBB{382}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{252}
+.Ltmp{383}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{384}:
+.LBB{252}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{385}:
+	cmpl	$16, %edi
+	jb	.LBB{386}
+	jmp	.LBB{16}
+.LBB{387}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	subq	%r8, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{389}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{279}
+.Ltmp{390}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{391}:
+	jmp	.LBB{279}
+.LBB{392}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{393}
+.Ltmp{394}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{395}:
+.LBB{393}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{396}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{279}
+.Ltmp{397}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{398}:
+	jmp	.LBB{279}
+.LBB{399}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{31}
+.LBB{401}:
+	cmpl	$16, %edi
+	jb	.LBB{262}
+	jmp	.LBB{92}
+.LBB{402}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{403}
+	cmpq	%rdi, %r12
+	jae	.LBB{403}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{404}
+.LBB{405}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{403}
+.LBB{404}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{405}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{405}
+	jmp	.LBB{5}
+.LBB{406}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{407}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{256}
+	jmp	.LBB{408}
+.LBB{409}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	addq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{410}:
+	cmpl	$16, %edi
+	jb	.LBB{411}
+	jmp	.LBB{307}
+.LBB{412}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{413}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{414}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{415}
+	cmpq	%rdx, %r12
+	jae	.LBB{415}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{416}
+.LBB{417}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{415}
+.LBB{416}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{417}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{417}
+	jmp	.LBB{5}
+.LBB{418}:
+	cmpl	$16, %edi
+	jb	.LBB{270}
+	jmp	.LBB{121}
+.LBB{419}:
+	cmpl	$16, %edi
+	jae	.LBB{92}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{420}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	addq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{421}:
+	cmpl	$16, %edi
+	jae	.LBB{92}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	je	.LBB{334}
+	jmp	.LBB{143}
+.LBB{422}:
+	cmpl	$16, %edi
+	jb	.LBB{270}
+	jmp	.LBB{358}
+.LBB{423}:
+	cmpl	$16, %edi
+	jae	.LBB{92}
+.LBB{270}:
+	incq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{424}:
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{81}
+.LBB{425}:
+	cmpl	$16, %edi
+	jb	.LBB{272}
+	jmp	.LBB{92}
+.LBB{426}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	imulq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{427}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{31}
+.LBB{429}:
+	testq	%r8, %r8
+	je	.LBB{430}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{431}
+	cmpq	$-1, %r8
+	je	.LBB{432}
+.LBB{431}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{433}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{434}
+	jmp	.LBB{307}
+.LBB{435}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{279}
+.Ltmp{436}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{437}:
+	jmp	.LBB{279}
+.LBB{438}:
+	cmpl	$16, %edi
+	jb	.LBB{272}
+	jmp	.LBB{121}
+.LBB{439}:
+	cmpl	$16, %edi
+	jb	.LBB{411}
+	jmp	.LBB{121}
+.LBB{440}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{285}
+	jmp	.LBB{307}
+.LBB{441}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{442}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{272}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{443}:
+	cmpl	$16, %edi
+	jb	.LBB{275}
+	jmp	.LBB{92}
+.LBB{444}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{445}:
+	testq	%r8, %r8
+	je	.LBB{446}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{447}
+	cmpq	$-1, %r8
+	je	.LBB{448}
+.LBB{447}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{449}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{450}
+	jmp	.LBB{121}
+.LBB{451}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{452}
+	xorl	%edx, %edx
+	jmp	.LBB{453}
+.LBB{454}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{258}
+.Ltmp{455}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{456}:
+	jmp	.LBB{258}
+.LBB{457}:
+	cmpl	$16, %edi
+	jb	.LBB{293}
+	jmp	.LBB{307}
+.LBB{458}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{459}
+	xorl	%edx, %edx
+	jmp	.LBB{460}
+.LBB{461}:
+	cmpl	$16, %edi
+	jb	.LBB{277}
+	jmp	.LBB{92}
+.LBB{462}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{279}
+.Ltmp{463}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{464}:
+.LBB{279}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{143}
+.LBB{465}:
+	cmpl	$16, %edi
+	jb	.LBB{295}
+	jmp	.LBB{307}
+.LBB{466}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{139}
+	jmp	.LBB{375}
+.LBB{467}:
+	cmpl	$16, %edi
+	jae	.LBB{121}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{468}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{411}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{143}
+.LBB{204}:
+	addq	$2, %r13
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{143}:
+	movzbl	%bpl, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{469}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	incq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{470}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	incq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{471}:
+	testq	%r8, %r8
+	je	.LBB{472}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{473}
+	cmpq	$-1, %r8
+	je	.LBB{474}
+.LBB{473}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{475}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{476}
+	jmp	.LBB{358}
+.LBB{477}:
+	testq	%r12, %r12
+	js	.LBB{4}
+.LBB{81}:
+	movzbl	%bpl, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{478}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{479}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{480}
+	xorl	%edx, %edx
+	jmp	.LBB{481}
+.LBB{482}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{285}
+	jmp	.LBB{92}
+.LBB{483}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{484}:
+	testq	%r8, %r8
+	je	.LBB{485}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{486}
+	cmpq	$-1, %r8
+	je	.LBB{487}
+.LBB{486}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{488}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{489}
+	jmp	.LBB{92}
+.LBB{490}:
+	cmpl	$16, %edi
+	jb	.LBB{293}
+	jmp	.LBB{92}
+.LBB{491}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{492}
+	cmpq	%rdx, %r12
+	jae	.LBB{492}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{493}
+.LBB{494}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{492}
+.LBB{493}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{494}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{494}
+	jmp	.LBB{5}
+.LBB{495}:
+	cmpl	$16, %edi
+	jb	.LBB{295}
+	jmp	.LBB{92}
+.LBB{496}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{497}
+	xorl	%edx, %edx
+	jmp	.LBB{498}
+.LBB{499}:
+	cmpl	$16, %edi
+	jb	.LBB{262}
+	jmp	.LBB{121}
+.LBB{500}:
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{139}
+.LBB{501}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{502}
+	cmpq	%rdx, %r12
+	jae	.LBB{502}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{503}
+.LBB{504}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{502}
+.LBB{503}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{504}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{504}
+	jmp	.LBB{5}
+.LBB{505}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	subq	%r8, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{506}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{15}:
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{507}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	imulq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{508}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{152}
+.LBB{509}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{262}:
+	addq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{510}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{511}
+.Ltmp{512}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{513}:
+.LBB{511}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{139}
+.LBB{514}:
+	cmpl	$16, %edi
+	jb	.LBB{275}
+	jmp	.LBB{121}
+.LBB{515}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{516}
+	cmpq	%rdi, %r12
+	jae	.LBB{516}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{517}
+.LBB{518}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{516}
+.LBB{517}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{518}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{518}
+	jmp	.LBB{5}
+.LBB{519}:
+	cmpl	$16, %edi
+	jb	.LBB{295}
+	jmp	.LBB{121}
+.LBB{520}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{360}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{521}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{285}
+	jmp	.LBB{121}
+.LBB{522}:
+	cmpl	$16, %edi
+	jb	.LBB{277}
+	jmp	.LBB{121}
+.LBB{523}:
+	testq	%r8, %r8
+	je	.LBB{524}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{525}
+	cmpq	$-1, %r8
+	je	.LBB{526}
+.LBB{525}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{527}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{528}
+	jmp	.LBB{360}
+.LBB{529}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{275}:
+	subq	%r8, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{530}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{531}:
+	cmpl	$16, %edi
+	jb	.LBB{293}
+	jmp	.LBB{121}
+.LBB{532}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{533}
+	cmpq	%rdi, %r12
+	jae	.LBB{533}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{534}
+.LBB{535}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{533}
+.LBB{534}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{535}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{535}
+	jmp	.LBB{5}
+.LBB{536}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{537}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{295}:
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{538}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{539}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{539}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{540}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{541}
+	xorl	%edx, %edx
+	jmp	.LBB{542}
+.LBB{543}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{358}
+.LBB{285}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{544}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{277}:
+	imulq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{545}:
+	cmpl	$16, %edi
+	jb	.LBB{342}
+	jmp	.LBB{307}
+.LBB{546}:
+	cmpl	$16, %edi
+	jb	.LBB{315}
+	jmp	.LBB{408}
+.LBB{547}:
+	testq	%r12, %r12
+	js	.LBB{4}
+.LBB{139}:
+	movzbl	%bpl, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{548}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{293}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{549}:
+	cmpl	$16, %edi
+	jae	.LBB{408}
+.LBB{309}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{71}
+.LBB{220}:
+	movq	%r15, %rax
+	addq	$4, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{550}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{408}
+.LBB{551}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{408}
+.LBB{552}:
+	cmpl	$16, %edi
+	jb	.LBB{342}
+	jmp	.LBB{92}
+.LBB{553}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{334}:
+	addq	$2, %r14
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{554}:
+	cmpl	$16, %edi
+	jb	.LBB{348}
+	jmp	.LBB{408}
+.LBB{555}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{408}
+.LBB{556}:
+	cmpl	$16, %edi
+	jb	.LBB{342}
+	jmp	.LBB{121}
+.LBB{557}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{266}
+.Ltmp{558}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{559}:
+	jmp	.LBB{266}
+.LBB{560}:
+	cmpl	$16, %edi
+	jae	.LBB{388}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{561}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{562}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{342}:
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{563}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{368}
+	jmp	.LBB{408}
+.LBB{564}:
+	testq	%r8, %r8
+	je	.LBB{565}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{566}
+	cmpq	$-1, %r8
+	je	.LBB{567}
+.LBB{566}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{568}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{408}
+.LBB{569}:
+	cmpl	$16, %edi
+	jb	.LBB{377}
+	jmp	.LBB{408}
+.LBB{570}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{408}
+.LBB{571}:
+	cmpl	$16, %edi
+	jb	.LBB{386}
+	jmp	.LBB{92}
+.LBB{572}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{573}
+	xorl	%edx, %edx
+	jmp	.LBB{574}
+.LBB{575}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{375}:
+	addq	$2, %r13
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{576}:
+	cmpl	$16, %edi
+	jb	.LBB{386}
+	jmp	.LBB{121}
+.LBB{577}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{578}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+.LBB{386}:
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{579}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{408}
+.LBB{580}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{408}
+.LBB{581}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{582}
+	cmpq	%rdi, %r12
+	jae	.LBB{582}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{583}
+.LBB{584}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{582}
+.LBB{583}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{584}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{584}
+	jmp	.LBB{5}
+.LBB{585}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{586}
+	jmp	.LBB{36}
+.LBB{587}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{588}
+	cmpq	%rdx, %r12
+	jae	.LBB{588}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{589}
+.LBB{590}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{588}
+.LBB{589}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{590}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{590}
+	jmp	.LBB{5}
+.LBB{591}:
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{71}
+.LBB{592}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{594}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{595}:
+	jmp	.LBB{593}
+.LBB{596}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{597}
+	jmp	.LBB{48}
+.LBB{598}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{599}
+	cmpq	%rdi, %r12
+	jae	.LBB{599}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{600}
+.LBB{601}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{599}
+.LBB{600}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{601}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{601}
+	jmp	.LBB{5}
+.LBB{602}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{603}
+.Ltmp{604}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{605}:
+	jmp	.LBB{603}
+.LBB{606}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{607}
+	jmp	.LBB{54}
+.LBB{608}:
+	cmpl	$16, %edi
+	jb	.LBB{609}
+	jmp	.LBB{36}
+.LBB{610}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{611}
+	cmpq	%rdx, %r12
+	jae	.LBB{611}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{612}
+.LBB{613}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{611}
+.LBB{612}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{613}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{613}
+	jmp	.LBB{5}
+.LBB{614}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{597}
+	jmp	.LBB{66}
+.LBB{615}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{603}
+.Ltmp{616}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{617}:
+	jmp	.LBB{603}
+.LBB{618}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{619}
+	cmpq	%rdi, %r12
+	jae	.LBB{619}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{620}
+.LBB{621}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{619}
+.LBB{620}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{621}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{621}
+	jmp	.LBB{5}
+.LBB{622}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{623}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{624}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{625}
+	cmpq	%rdi, %r12
+	jae	.LBB{625}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{626}
+.LBB{627}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{625}
+.LBB{626}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{627}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{627}
+	jmp	.LBB{5}
+.LBB{628}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{629}
+	jmp	.LBB{77}
+.LBB{630}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{597}
+	jmp	.LBB{11}
+.LBB{631}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{632}
+.Ltmp{633}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{634}:
+	jmp	.LBB{632}
+.LBB{635}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{636}
+.LBB{637}:
+	cmpl	$16, %edi
+	jb	.LBB{37}
+	jmp	.LBB{36}
+.LBB{638}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{36}
+.LBB{639}:
+	cmpl	$16, %edi
+	jb	.LBB{640}
+	jmp	.LBB{36}
+.LBB{641}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{642}
+	cmpq	%rdx, %r12
+	jae	.LBB{642}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{643}
+.LBB{644}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{642}
+.LBB{643}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{644}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{644}
+	jmp	.LBB{5}
+.LBB{645}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{646}
+	cmpq	%rdx, %r12
+	jae	.LBB{646}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{647}
+.LBB{648}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{646}
+.LBB{647}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{648}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{648}
+	jmp	.LBB{5}
+.LBB{649}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{36}
+.LBB{650}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{651}
+.Ltmp{652}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{653}:
+	jmp	.LBB{651}
+.LBB{654}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{655}
+.Ltmp{656}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{657}:
+	jmp	.LBB{655}
+.LBB{658}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{97}
+.LBB{597}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{170}
+.LBB{659}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{603}
+.Ltmp{660}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{661}:
+	jmp	.LBB{603}
+.LBB{662}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{663}
+	cmpq	%rdi, %r12
+	jae	.LBB{663}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{664}
+.LBB{665}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{663}
+.LBB{664}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{665}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{665}
+	jmp	.LBB{5}
+.LBB{666}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{629}
+	jmp	.LBB{169}
+.LBB{667}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{36}
+.LBB{668}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{629}
+	jmp	.LBB{669}
+.LBB{670}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{632}
+.Ltmp{671}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{672}:
+	jmp	.LBB{632}
+.LBB{673}:
+	testq	%r8, %r8
+	je	.LBB{674}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{675}
+	cmpq	$-1, %r8
+	je	.LBB{676}
+.LBB{675}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{677}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{36}
+.LBB{678}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{679}
+	cmpq	%rdx, %r12
+	jae	.LBB{679}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{680}
+.LBB{681}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{679}
+.LBB{680}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{681}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{681}
+	jmp	.LBB{5}
+.LBB{682}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{651}
+.Ltmp{683}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{684}:
+	jmp	.LBB{651}
+.LBB{685}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{686}
+.Ltmp{687}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{688}:
+	jmp	.LBB{686}
+.LBB{689}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{586}
+	jmp	.LBB{690}
+.LBB{691}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{135}
+.LBB{629}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{692}
+.LBB{693}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{593}
+.Ltmp{694}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{695}:
+.LBB{593}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{696}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{632}
+.Ltmp{697}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{698}:
+	jmp	.LBB{632}
+.LBB{699}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{700}
+.Ltmp{701}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{702}:
+.LBB{700}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	jmp	.LBB{703}
+.LBB{704}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{603}
+.Ltmp{705}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{706}:
+.LBB{603}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{707}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{708}
+.Ltmp{709}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{710}:
+	jmp	.LBB{708}
+.LBB{711}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{48}
+.LBB{712}:
+	cmpl	$16, %edi
+	jb	.LBB{713}
+	jmp	.LBB{54}
+.LBB{714}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{715}
+	jmp	.LBB{36}
+.LBB{716}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{717}
+	xorl	%edx, %edx
+	jmp	.LBB{718}
+.LBB{719}:
+	cmpl	$16, %edi
+	jb	.LBB{720}
+	jmp	.LBB{36}
+.LBB{721}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{36}
+.LBB{722}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{36}
+.LBB{723}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{651}
+.Ltmp{724}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{725}:
+	jmp	.LBB{651}
+.LBB{726}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{48}
+.LBB{727}:
+	cmpl	$16, %edi
+	jb	.LBB{728}
+	jmp	.LBB{54}
+.LBB{729}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{54}
+.LBB{730}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{66}
+.LBB{731}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{632}
+.Ltmp{732}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{733}:
+.LBB{632}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{734}:
+	cmpl	$16, %edi
+	jb	.LBB{735}
+	jmp	.LBB{48}
+.LBB{736}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	addq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{737}:
+	cmpl	$16, %edi
+	jb	.LBB{738}
+	jmp	.LBB{54}
+.LBB{739}:
+	cmpl	$16, %edi
+	jb	.LBB{740}
+	jmp	.LBB{11}
+.LBB{741}:
+	testq	%r8, %r8
+	je	.LBB{742}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{743}
+	cmpq	$-1, %r8
+	je	.LBB{744}
+.LBB{743}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{745}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{48}
+.LBB{746}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{54}
+.LBB{747}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{77}
+.LBB{748}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{749}
+	xorl	%edx, %edx
+	jmp	.LBB{750}
+.LBB{751}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{708}
+.Ltmp{752}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{753}:
+	jmp	.LBB{708}
+.LBB{754}:
+	cmpl	$16, %edi
+	jb	.LBB{170}
+	jmp	.LBB{48}
+.LBB{755}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	jbe	.LBB{607}
+	jmp	.LBB{148}
+.LBB{756}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{686}
+.Ltmp{757}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{758}:
+.LBB{686}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{126}
+.LBB{759}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{655}
+.Ltmp{760}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{761}:
+	jmp	.LBB{655}
+.LBB{762}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{708}
+.Ltmp{763}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{764}:
+	jmp	.LBB{708}
+.LBB{765}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{66}
+.LBB{766}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{767}
+	cmpq	%rdx, %r12
+	jae	.LBB{767}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{768}
+.LBB{769}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{767}
+.LBB{768}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{769}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{769}
+	jmp	.LBB{5}
+.LBB{770}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{771}:
+	cmpl	$16, %edi
+	jb	.LBB{735}
+	jmp	.LBB{66}
+.LBB{772}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{773}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{54}
+.LBB{774}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{97}
+.LBB{775}:
+	cmpl	$16, %edi
+	jb	.LBB{170}
+	jmp	.LBB{11}
+.LBB{776}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{77}
+.LBB{777}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{11}
+.LBB{778}:
+	testq	%r8, %r8
+	je	.LBB{779}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{780}
+	cmpq	$-1, %r8
+	je	.LBB{781}
+.LBB{780}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{782}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{66}
+.LBB{783}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{48}
+.LBB{784}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{169}
+.LBB{785}:
+	testq	%r8, %r8
+	je	.LBB{786}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{787}
+	cmpq	$-1, %r8
+	je	.LBB{788}
+.LBB{787}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{789}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{54}
+.LBB{790}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{36}
+.LBB{791}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{792}
+.Ltmp{793}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{794}:
+	jmp	.LBB{792}
+.LBB{795}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	je	.LBB{636}
+	jmp	.LBB{71}
+.LBB{796}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{797}
+	xorl	%edx, %edx
+	jmp	.LBB{798}
+.LBB{799}:
+	cmpl	$16, %edi
+	jb	.LBB{735}
+	jmp	.LBB{11}
+.LBB{800}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	incq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{801}:
+	cmpl	$16, %edi
+	jb	.LBB{802}
+	jmp	.LBB{77}
+.LBB{803}:
+	cmpl	$16, %edi
+	jb	.LBB{170}
+	jmp	.LBB{66}
+.LBB{804}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{11}
+.LBB{805}:
+	testq	%r8, %r8
+	je	.LBB{806}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{807}
+	cmpq	$-1, %r8
+	je	.LBB{808}
+.LBB{807}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{809}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{77}
+.LBB{810}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{811}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{812}
+	jmp	.LBB{54}
+.LBB{813}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{814}
+	xorl	%edx, %edx
+	jmp	.LBB{815}
+.LBB{816}:
+	cmpl	$16, %edi
+	jb	.LBB{740}
+	jmp	.LBB{48}
+.LBB{817}:
+	cmpl	$16, %edi
+	jb	.LBB{692}
+	jmp	.LBB{77}
+.LBB{818}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{819}
+	xorl	%edx, %edx
+	jmp	.LBB{820}
+.LBB{821}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{669}
+.LBB{822}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{823}
+	cmpq	%rdi, %r12
+	jae	.LBB{823}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{824}
+.LBB{825}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{823}
+.LBB{824}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{825}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{825}
+	jmp	.LBB{5}
+.LBB{826}:
+	cmpl	$16, %edi
+	jb	.LBB{827}
+	jmp	.LBB{54}
+.LBB{828}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{48}
+.LBB{829}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{54}
+.LBB{830}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{831}
+.LBB{832}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{833}
+	cmpq	%rdx, %r12
+	jae	.LBB{833}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{834}
+.LBB{835}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{833}
+.LBB{834}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{835}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{835}
+	jmp	.LBB{5}
+.LBB{836}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{97}
+.LBB{837}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{838}
+	jmp	.LBB{48}
+.LBB{839}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{48}
+.LBB{840}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{54}
+.LBB{841}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{135}
+.LBB{842}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{843}
+.LBB{607}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{728}
+.LBB{844}:
+	cmpl	$16, %edi
+	jb	.LBB{692}
+	jmp	.LBB{169}
+.LBB{845}:
+	testq	%r12, %r12
+	jne	.LBB{4}
+	jmp	.LBB{71}
+.LBB{846}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{66}
+.LBB{847}:
+	cmpl	$16, %edi
+	jb	.LBB{848}
+	jmp	.LBB{48}
+.LBB{849}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{655}
+.Ltmp{850}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{851}:
+.LBB{655}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{852}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	subq	%r8, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{853}:
+	cmpl	$16, %edi
+	jae	.LBB{97}
+.LBB{735}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{172}
+.LBB{190}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{854}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{855}
+	cmpq	%rdx, %r12
+	jae	.LBB{855}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{856}
+.LBB{857}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{855}
+.LBB{856}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{857}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{857}
+	jmp	.LBB{5}
+.LBB{858}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{11}
+.LBB{859}:
+	testq	%r8, %r8
+	je	.LBB{860}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{861}
+	cmpq	$-1, %r8
+	je	.LBB{862}
+.LBB{861}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{863}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{97}
+.LBB{864}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	imulq	%rax, %r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{865}:
+	cmpl	$16, %edi
+	jb	.LBB{802}
+	jmp	.LBB{169}
+.LBB{866}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{867}
+.Ltmp{868}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{869}:
+.LBB{867}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	jmp	.LBB{870}
+.LBB{871}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{872}
+	xorl	%edx, %edx
+	jmp	.LBB{873}
+.LBB{874}:
+	testq	%r8, %r8
+	je	.LBB{875}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{876}
+	cmpq	$-1, %r8
+	je	.LBB{877}
+.LBB{876}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{878}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{11}
+.LBB{879}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{77}
+.LBB{880}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{169}
+.LBB{881}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{651}
+.Ltmp{882}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{883}:
+.LBB{651}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{172}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{884}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{792}
+.Ltmp{885}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{886}:
+	jmp	.LBB{792}
+.LBB{887}:
+	cmpl	$16, %edi
+	jb	.LBB{170}
+	jmp	.LBB{97}
+.LBB{888}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{169}
+.LBB{889}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{669}
+.LBB{890}:
+	cmpl	$16, %edi
+	jb	.LBB{740}
+	jmp	.LBB{66}
+.LBB{891}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{36}
+.LBB{892}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{893}
+	cmpq	%rdi, %r12
+	jae	.LBB{893}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{894}
+.LBB{895}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{893}
+.LBB{894}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{895}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{895}
+	jmp	.LBB{5}
+.LBB{896}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{623}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{897}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{831}
+.LBB{898}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{66}
+.LBB{899}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{135}
+.LBB{900}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{838}
+	jmp	.LBB{11}
+.LBB{901}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{838}
+	jmp	.LBB{66}
+.LBB{902}:
+	testq	%r8, %r8
+	je	.LBB{903}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{904}
+	cmpq	$-1, %r8
+	je	.LBB{905}
+.LBB{904}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{906}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{907}
+	jmp	.LBB{623}
+.LBB{908}:
+	cmpl	$16, %edi
+	jb	.LBB{802}
+	jmp	.LBB{669}
+.LBB{909}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{66}
+.LBB{910}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{911}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{912}
+	xorl	%edx, %edx
+	jmp	.LBB{913}
+.LBB{914}:
+	cmpl	$16, %edi
+	jb	.LBB{915}
+	jmp	.LBB{77}
+.LBB{916}:
+	testq	%r8, %r8
+	je	.LBB{917}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{918}
+	cmpq	$-1, %r8
+	je	.LBB{919}
+.LBB{918}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{920}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{669}
+.LBB{921}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{922}:
+	cmpl	$16, %edi
+	jae	.LBB{831}
+.LBB{640}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{126}
+.LBB{224}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{126}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{923}:
+	cmpl	$16, %edi
+	jb	.LBB{848}
+	jmp	.LBB{11}
+.LBB{924}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{925}
+	cmpq	%rdx, %r12
+	jae	.LBB{925}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{926}
+.LBB{927}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{925}
+.LBB{926}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{927}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{927}
+	jmp	.LBB{5}
+.LBB{928}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{929}
+	cmpq	%rdi, %r12
+	jae	.LBB{929}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{930}
+.LBB{931}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{929}
+.LBB{930}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{931}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{931}
+	jmp	.LBB{5}
+.LBB{932}:
+	cmpl	$16, %edi
+	jb	.LBB{848}
+	jmp	.LBB{66}
+.LBB{933}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{934}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{934}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	jmp	.LBB{4}
+.LBB{935}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{936}
+	xorl	%edx, %edx
+	jmp	.LBB{937}
+.LBB{938}:
+	cmpl	$16, %edi
+	jae	.LBB{135}
+.LBB{802}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{175}
+.LBB{195}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{939}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{940}
+	xorl	%edx, %edx
+	jmp	.LBB{941}
+.LBB{942}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{11}
+.LBB{943}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{77}
+.LBB{944}:
+	testq	%r8, %r8
+	je	.LBB{945}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{946}
+	cmpq	$-1, %r8
+	je	.LBB{947}
+.LBB{946}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{948}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{831}
+.LBB{949}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{11}
+.LBB{950}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{951}
+	jmp	.LBB{77}
+.LBB{952}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{77}
+.LBB{953}:
+	testq	%r8, %r8
+	je	.LBB{954}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{955}
+	cmpq	$-1, %r8
+	je	.LBB{956}
+.LBB{955}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{957}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{135}
+.LBB{958}:
+	cmpl	$16, %edi
+	jb	.LBB{692}
+	jmp	.LBB{669}
+.LBB{959}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{960}
+	xorl	%edx, %edx
+	jmp	.LBB{961}
+.LBB{962}:
+	testq	%r12, %r12
+	jns	.LBB{71}
+	jmp	.LBB{4}
+.LBB{963}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{97}
+.LBB{964}:
+	cmpl	$16, %edi
+	jb	.LBB{740}
+	jmp	.LBB{169}
+.LBB{965}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{966}
+	xorl	%edx, %edx
+	jmp	.LBB{967}
+.LBB{968}:
+	cmpl	$16, %edi
+	jb	.LBB{37}
+	jmp	.LBB{831}
+.LBB{969}:
+	cmpl	$16, %edi
+	jb	.LBB{970}
+	jmp	.LBB{77}
+.LBB{971}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{169}
+.LBB{972}:
+	cmpl	$16, %edi
+	jb	.LBB{692}
+	jmp	.LBB{135}
+.LBB{973}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{708}
+.Ltmp{974}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{975}:
+.LBB{708}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{175}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{976}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{977}
+	cmpq	%rdx, %r12
+	jae	.LBB{977}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{978}
+.LBB{979}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{977}
+.LBB{978}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{979}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{979}
+	jmp	.LBB{5}
+.LBB{980}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{48}
+.LBB{981}:
+	cmpl	$16, %edi
+	jae	.LBB{97}
+.LBB{740}:
+	subq	%r8, %rax
+	jmp	.LBB{170}
+.LBB{982}:
+	cmpl	$16, %edi
+	jae	.LBB{669}
+.LBB{76}:
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{692}
+.LBB{983}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{54}
+.LBB{984}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{148}
+.LBB{985}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{951}
+	jmp	.LBB{169}
+.LBB{986}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{987}
+	cmpq	%rdi, %r12
+	jae	.LBB{987}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{988}
+.LBB{989}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{987}
+.LBB{988}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{989}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{989}
+	jmp	.LBB{5}
+.LBB{990}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{991}
+	cmpq	%rdx, %r12
+	jae	.LBB{991}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{992}
+.LBB{993}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{991}
+.LBB{992}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{993}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{993}
+	jmp	.LBB{5}
+.LBB{994}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{97}
+.LBB{995}:
+	cmpl	$16, %edi
+	jb	.LBB{53}
+	jmp	.LBB{831}
+.LBB{996}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{997}
+	cmpq	%rdx, %r12
+	jae	.LBB{997}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{998}
+.LBB{999}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{997}
+.LBB{998}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{999}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{999}
+	jmp	.LBB{5}
+.LBB{1000}:
+	testq	%r8, %r8
+	je	.LBB{1001}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1002}
+	cmpq	$-1, %r8
+	je	.LBB{1003}
+.LBB{1002}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1004}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{169}
+.LBB{1005}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{669}
+.LBB{1006}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{97}
+.LBB{838}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{170}
+.LBB{1007}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{97}
+.LBB{1008}:
+	cmpl	$16, %edi
+	jb	.LBB{970}
+	jmp	.LBB{169}
+.LBB{1009}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{792}
+.Ltmp{1010}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1011}:
+.LBB{792}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{157}
+.LBB{1012}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{831}
+.LBB{1013}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{169}
+.LBB{1014}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{135}
+.LBB{1015}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1016}
+	xorl	%edx, %edx
+	jmp	.LBB{1017}
+.LBB{1018}:
+	cmpl	$16, %edi
+	jae	.LBB{97}
+.LBB{848}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{170}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1019}:
+	cmpl	$16, %edi
+	jb	.LBB{915}
+	jmp	.LBB{669}
+.LBB{1020}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1021}
+	cmpq	%rdi, %r12
+	jae	.LBB{1021}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1022}
+.LBB{1023}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1021}
+.LBB{1022}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1023}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1023}
+	jmp	.LBB{5}
+.LBB{1024}:
+	cmpl	$16, %edi
+	jb	.LBB{713}
+	jmp	.LBB{831}
+.LBB{1025}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{669}
+.LBB{1026}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{148}
+.LBB{1027}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{951}
+	jmp	.LBB{669}
+.LBB{1028}:
+	cmpl	$16, %edi
+	jae	.LBB{135}
+.LBB{915}:
+	subq	%r8, %rax
+	jmp	.LBB{692}
+.LBB{1029}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{669}
+.LBB{1030}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1031}
+	cmpq	%rdi, %r12
+	jae	.LBB{1031}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1032}
+.LBB{1033}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1031}
+.LBB{1032}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1033}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1033}
+	jmp	.LBB{5}
+.LBB{1034}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{66}
+.LBB{1035}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1036}
+	cmpq	%rdi, %r12
+	jae	.LBB{1036}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1037}
+.LBB{1038}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1036}
+.LBB{1037}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1038}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1038}
+	jmp	.LBB{5}
+.LBB{1039}:
+	cmpl	$16, %edi
+	jae	.LBB{831}
+	movq	%rax, %rcx
+	shrq	%rcx
+	andq	%rsi, %rcx
+	subq	%rcx, %rax
+	movq	%rax, %rcx
+	andq	%rdx, %rcx
+	shrq	$2, %rax
+	andq	%rdx, %rax
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	andq	%r9, %rcx
+	imulq	%r10, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1040}
+.LBB{1041}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{715}
+	jmp	.LBB{690}
+.LBB{1042}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{831}
+.LBB{1043}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{135}
+.LBB{1044}:
+	cmpl	$16, %edi
+	jb	.LBB{970}
+	jmp	.LBB{669}
+.LBB{1045}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{135}
+.LBB{951}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{692}
+.LBB{1046}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{135}
+.LBB{1047}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1048}:
+	cmpl	$16, %edi
+	jb	.LBB{738}
+	jmp	.LBB{148}
+.LBB{1049}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{11}
+.LBB{1050}:
+	cmpl	$16, %edi
+	jb	.LBB{720}
+	jmp	.LBB{831}
+.LBB{1051}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{77}
+.LBB{1052}:
+	testq	%r8, %r8
+	je	.LBB{1053}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1054}
+	cmpq	$-1, %r8
+	je	.LBB{1055}
+.LBB{1054}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1056}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{148}
+.LBB{1057}:
+	cmpl	$16, %edi
+	jae	.LBB{135}
+.LBB{970}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{692}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1058}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1059}
+	xorl	%edx, %edx
+	jmp	.LBB{1060}
+.LBB{1061}:
+	cmpl	$16, %edi
+	jb	.LBB{728}
+	jmp	.LBB{148}
+.LBB{1062}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{48}
+.LBB{1063}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{54}
+.LBB{1064}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{843}
+.LBB{1065}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{636}:
+	addq	$2, %r13
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1066}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1067}
+	cmpq	%rdx, %r12
+	jae	.LBB{1067}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1068}
+.LBB{1069}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1067}
+.LBB{1068}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1069}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1069}
+	jmp	.LBB{5}
+.LBB{1070}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{97}
+.LBB{1071}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{148}
+.LBB{1072}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{169}
+.LBB{1073}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{843}
+.LBB{1074}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{66}
+.LBB{1075}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+	movq	%rcx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1076}:
+	cmpl	$16, %edi
+	jae	.LBB{843}
+.LBB{738}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{157}
+.LBB{208}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{157}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1077}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{11}
+.LBB{1078}:
+	cmpl	$16, %edi
+	jb	.LBB{713}
+	jmp	.LBB{148}
+.LBB{1079}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{669}
+.LBB{1080}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{77}
+.LBB{1081}:
+	testq	%r8, %r8
+	je	.LBB{1082}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1083}
+	cmpq	$-1, %r8
+	je	.LBB{1084}
+.LBB{1083}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1085}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{843}
+.LBB{1086}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1087}
+	cmpq	%rdi, %r12
+	jae	.LBB{1087}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1088}
+.LBB{1089}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1087}
+.LBB{1088}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1089}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1089}
+	jmp	.LBB{5}
+.LBB{1090}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1091}
+	xorl	%edx, %edx
+	jmp	.LBB{1092}
+.LBB{1093}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{831}
+.LBB{1094}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{148}
+.LBB{1095}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{812}
+	jmp	.LBB{148}
+.LBB{1096}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{135}
+.LBB{1097}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{148}
+.LBB{1098}:
+	cmpl	$16, %edi
+	jb	.LBB{728}
+	jmp	.LBB{843}
+.LBB{1099}:
+	cmpl	$16, %edi
+	jb	.LBB{827}
+	jmp	.LBB{148}
+.LBB{1100}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1101}
+	cmpq	%rdx, %r12
+	jae	.LBB{1101}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1102}
+.LBB{1103}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1101}
+.LBB{1102}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1103}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1103}
+	jmp	.LBB{5}
+.LBB{1104}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{97}
+.LBB{1105}:
+	cmpl	$16, %edi
+	jae	.LBB{843}
+.LBB{53}:
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{728}
+.LBB{1106}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{843}
+.LBB{1107}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{169}
+.LBB{1108}:
+	cmpl	$16, %edi
+	jae	.LBB{843}
+.LBB{713}:
+	subq	%r8, %rax
+	jmp	.LBB{728}
+.LBB{1109}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{669}
+.LBB{1110}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1111}
+	cmpq	%rdi, %r12
+	jae	.LBB{1111}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1112}
+.LBB{1113}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1111}
+.LBB{1112}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1113}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1113}
+	jmp	.LBB{5}
+.LBB{1114}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{831}
+.LBB{1115}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{843}
+.LBB{1116}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{843}
+.LBB{812}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{728}
+.LBB{1117}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{135}
+.LBB{1118}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{843}
+.LBB{1119}:
+	cmpl	$16, %edi
+	jae	.LBB{843}
+.LBB{827}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{728}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1120}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{148}
+.LBB{1121}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{148}
+.LBB{1122}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{843}
+.LBB{1123}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{843}
+.LBB{1124}:
+	addq	%r14, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{26}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1125}
+.LBB{1126}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1127}
+.Ltmp{1128}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1129}:
+	jmp	.LBB{1127}
+.LBB{1130}:
+	addq	%r14, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{1131}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1131}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1132}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1133}
+.Ltmp{1134}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1135}:
+	jmp	.LBB{1133}
+.LBB{1136}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{26}
+.LBB{1137}:
+	testq	%r12, %r12
+	movq	%r13, %r15
+	jns	.LBB{4}
+	jmp	.LBB{108}
+.LBB{1138}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{26}
+.LBB{1139}:
+	cmpl	$16, %edi
+	jb	.LBB{1140}
+	jmp	.LBB{26}
+.LBB{1141}:
+	testq	%r8, %r8
+	je	.LBB{1142}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1143}
+	cmpq	$-1, %r8
+	je	.LBB{1144}
+.LBB{1143}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1145}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{26}
+.LBB{1146}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1147}
+	xorl	%edx, %edx
+	jmp	.LBB{1148}
+.LBB{1149}:
+	cmpl	$16, %edi
+	jb	.LBB{1125}
+	jmp	.LBB{26}
+.LBB{1150}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1151}
+	cmpq	%rdx, %r12
+	jae	.LBB{1151}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1152}
+.LBB{1153}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1151}
+.LBB{1152}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1153}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1153}
+	jmp	.LBB{5}
+.LBB{1154}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{26}
+.LBB{1155}:
+	cmpl	$16, %edi
+	jb	.LBB{1156}
+	jmp	.LBB{26}
+.LBB{1157}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1158}
+	cmpq	%rdi, %r12
+	jae	.LBB{1158}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1159}
+.LBB{1160}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1158}
+.LBB{1159}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1160}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1160}
+	jmp	.LBB{5}
+.LBB{1161}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1162}
+	cmpq	%rdi, %r12
+	jae	.LBB{1162}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1163}
+.LBB{1164}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1162}
+.LBB{1163}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1164}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1164}
+	jmp	.LBB{5}
+.LBB{1165}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{26}
+.LBB{1166}:
+	addq	%rcx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1167}
+.LBB{586}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{37}
+.LBB{1168}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	jbe	.LBB{1169}
+	jmp	.LBB{26}
+.LBB{1170}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{26}
+.LBB{1171}:
+	cmpl	$16, %edi
+	jb	.LBB{1172}
+	jmp	.LBB{26}
+.LBB{1173}:
+	movl	%ecx, %eax
+	andb	$15, %al
+	movl	%edx, %edi
+	andb	$15, %dil
+	movzbl	%dil, %edi
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdi,8), %rax
+	movl	%r9d, %edi
+	andl	$15, %edi
+	movq	%rax, 48(%rsp,%rdi,8)
+	shrb	$4, %cl
+	shrb	$4, %dl
+	movzbl	%dl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%cl, %ecx
+	subq	48(%rsp,%rcx,8), %rax
+	shrb	$4, %r9b
+	movzbl	%r9b, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{1174}
+.LBB{1175}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1176}
+	cmpq	%rdx, %r12
+	jae	.LBB{1176}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1177}
+.LBB{1178}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1176}
+.LBB{1177}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1178}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1178}
+	jmp	.LBB{5}
+.LBB{1179}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	movq	%r13, %r15
+	jne	.LBB{1180}
+.Ltmp{1181}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1182}:
+	jmp	.LBB{1180}
+.LBB{1183}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{26}
+.LBB{1184}:
+	cmpl	$16, %edi
+	jae	.LBB{1167}
+.LBB{609}:
+	subq	%r8, %rax
+	jmp	.LBB{37}
+.LBB{1185}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{26}
+.LBB{1186}:
+	cmpl	$16, %edi
+	jb	.LBB{37}
+	jmp	.LBB{1167}
+.LBB{1187}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{1167}
+.LBB{1188}:
+	cmpl	$16, %edi
+	jae	.LBB{1167}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{108}
+	jmp	.LBB{1174}
+.LBB{1189}:
+	cmpl	$16, %edi
+	jae	.LBB{1167}
+	shll	$8, %ecx
+	movzbl	%dl, %eax
+	orl	%ecx, %eax
+	jmp	.LBB{37}
+.LBB{1190}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{1167}
+.LBB{1191}:
+	testq	%rbx, %rbx
+	je	.LBB{5}
+	leaq	-2(%r10), %rax
+	movq	%rax, 208(%rsp)
+	movq	-16(%r11,%r10,8), %r15
+	jmp	.LBB{4}
+.LBB{1192}:
+	testq	%r12, %r12
+	movq	%r13, %r15
+	jne	.LBB{4}
+	jmp	.LBB{108}
+.LBB{1193}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{1167}
+.LBB{1194}:
+	testq	%r8, %r8
+	je	.LBB{1195}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1196}
+	cmpq	$-1, %r8
+	je	.LBB{1197}
+.LBB{1196}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1198}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{1167}
+.LBB{1199}:
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	%r13, -8(%r11,%r10,8)
+	movq	%r10, 208(%rsp)
+	jmp	.LBB{108}
+.LBB{1200}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1167}
+.LBB{715}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{37}
+.LBB{1201}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1202}
+	xorl	%edx, %edx
+	jmp	.LBB{1203}
+.LBB{1204}:
+	cmpl	$16, %edi
+	jae	.LBB{1167}
+.LBB{720}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{37}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1205}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{1167}
+.LBB{1206}:
+	addq	%rcx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{229}
+.LBB{228}:
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{229}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1207}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{1167}
+.LBB{1208}:
+	testq	%r12, %r12
+	movq	%r13, %r15
+	js	.LBB{4}
+.LBB{108}:
+	movzbl	%dl, %eax
+	shll	$8, %ecx
+	orl	%eax, %ecx
+	movq	%rcx, %r15
+	jmp	.LBB{4}
+.LBB{1209}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{1167}
+.LBB{1210}:
+	movl	%ecx, %eax
+	andb	$15, %al
+	movl	%edx, %edi
+	andb	$15, %dil
+	movzbl	%dil, %edi
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdi,8), %rax
+	movl	%r9d, %edi
+	andl	$15, %edi
+	movq	%rax, 48(%rsp,%rdi,8)
+	shrb	$4, %cl
+	shrb	$4, %dl
+	movzbl	%dl, %eax
+	movzbl	%cl, %ecx
+	movq	48(%rsp,%rcx,8), %rcx
+	addq	48(%rsp,%rax,8), %rcx
+	shrb	$4, %r9b
+	movzbl	%r9b, %eax
+	movq	%rcx, 48(%rsp,%rax,8)
+.LBB{1174}:
+	addq	$2, %rsi
+	movq	%rsi, %r15
+	jmp	.LBB{4}
+.LBB{1211}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{1167}
+.LBB{1212}:
+	testq	%r12, %r12
+	movq	%r14, %r15
+	jns	.LBB{4}
+	jmp	.LBB{61}
+.LBB{1213}:
+	movq	336(%rsp), %r15
+	testq	%r12, %r12
+	jns	.LBB{4}
+	jmp	.LBB{88}
+.LBB{1214}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1215}
+	cmpq	%rdi, %r12
+	jae	.LBB{1215}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1216}
+.LBB{1217}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1215}
+.LBB{1216}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1217}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1217}
+	jmp	.LBB{5}
+.LBB{1218}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1219}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1220}
+.LBB{1221}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{1222}
+.LBB{1223}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1224}
+	cmpq	%rdi, %r12
+	jae	.LBB{1224}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1225}
+.LBB{1226}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1224}
+.LBB{1225}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1226}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1226}
+	jmp	.LBB{5}
+.LBB{1227}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1228}
+	cmpq	%rdx, %r12
+	jae	.LBB{1228}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1229}
+.LBB{1230}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1228}
+.LBB{1229}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1230}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1230}
+	jmp	.LBB{5}
+.LBB{1231}:
+	addq	%r13, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1232}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1233}
+.LBB{1234}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r13b, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{1235}
+.LBB{1236}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1237}
+	cmpq	%rdx, %r12
+	jae	.LBB{1237}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1238}
+.LBB{1239}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1237}
+.LBB{1238}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1239}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1239}
+	jmp	.LBB{5}
+.LBB{1240}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	movq	%r14, %r15
+	jne	.LBB{1180}
+.Ltmp{1241}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1242}:
+.LBB{1180}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{1243}:
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rdx
+	jne	.LBB{1244}
+.Ltmp{1245}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1246}:
+.LBB{1244}:
+	movq	224(%rsp), %rax
+	movq	%r12, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 232(%rsp)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1247}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	subq	%r8, %rax
+	jmp	.LBB{1220}
+.LBB{1248}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	subq	%r8, %rax
+	jmp	.LBB{1249}
+.LBB{1250}:
+	cmpl	$16, %edi
+	jb	.LBB{1220}
+	jmp	.LBB{1219}
+.LBB{1251}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	addq	%rax, %r8
+	jmp	.LBB{1252}
+.LBB{1253}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{61}
+	jmp	.LBB{1222}
+.LBB{1254}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1220}
+.LBB{1255}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	decq	%r12
+	jmp	.LBB{1256}
+.LBB{1257}:
+	movq	%r15, 208(%rsp)
+	movq	200(%rsp), %rax
+	movq	(%rax,%r15,8), %r15
+	jmp	.LBB{4}
+.LBB{1258}:
+	cmpl	$16, %edi
+	jb	.LBB{1233}
+	jmp	.LBB{1232}
+.LBB{1259}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	addq	%rax, %r8
+	jmp	.LBB{1260}
+.LBB{1261}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{1262}
+	addq	$3, %rbp
+	movq	%rbp, %r15
+	jmp	.LBB{4}
+.LBB{1263}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	shll	$8, %r13d
+	movzbl	%bl, %eax
+	orl	%r13d, %eax
+	jmp	.LBB{1249}
+.LBB{1264}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	decq	%r12
+	jmp	.LBB{1265}
+.LBB{1266}:
+	testq	%r12, %r12
+	movq	%r14, %r15
+	jne	.LBB{4}
+	jmp	.LBB{61}
+.LBB{1267}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	imulq	%rax, %r8
+	jmp	.LBB{1252}
+.LBB{1268}:
+	testq	%r8, %r8
+	je	.LBB{1269}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1270}
+	cmpq	$-1, %r8
+	je	.LBB{1271}
+.LBB{1270}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1272}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{1220}
+	jmp	.LBB{1219}
+.LBB{1273}:
+	cmpq	$1024, %rdx
+	je	.LBB{5}
+	cmpq	192(%rsp), %rdx
+	jne	.LBB{1274}
+.Ltmp{1275}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1276}:
+.LBB{1274}:
+	movq	200(%rsp), %rax
+	movq	%r14, 8(%rax,%r15,8)
+	addq	$2, %r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{61}
+.LBB{1277}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1219}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{1220}
+.LBB{1278}:
+	movq	336(%rsp), %r15
+	testq	%r12, %r12
+	jne	.LBB{4}
+.LBB{88}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1279}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	imulq	%rax, %r8
+.LBB{1260}:
+	movq	%r8, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1280}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1281}
+	xorl	%edx, %edx
+	jmp	.LBB{1282}
+.LBB{1283}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1220}
+.LBB{1284}:
+	testq	%r8, %r8
+	je	.LBB{1285}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1286}
+	cmpq	$-1, %r8
+	je	.LBB{1287}
+.LBB{1286}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1288}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{1249}
+	jmp	.LBB{1232}
+.LBB{1289}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1290}
+.Ltmp{1291}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1292}:
+.LBB{1290}:
+	movq	200(%rsp), %rax
+	movq	336(%rsp), %rcx
+	movq	%rcx, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{1262}
+.LBB{1293}:
+	cmpl	$16, %edi
+	jb	.LBB{1294}
+	jmp	.LBB{1219}
+.LBB{1295}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{1296}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1296}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1297}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	incq	%r12
+.LBB{1256}:
+	movq	%r12, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1298}:
+	testq	%r12, %r12
+	movq	%r14, %r15
+	js	.LBB{4}
+.LBB{61}:
+	movzbl	%bpl, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{1299}:
+	movq	%r15, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1232}
+	movq	224(%rsp), %rax
+	movq	(%rax,%r15,8), %rax
+	jmp	.LBB{1233}
+.LBB{1300}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1301}
+	xorl	%edx, %edx
+	jmp	.LBB{1302}
+.LBB{1303}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{1233}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1304}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1305}
+.LBB{1306}:
+	addq	%r13, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{1307}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1307}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r12, (%rcx,%rax,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1308}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	incq	%r12
+.LBB{1265}:
+	movq	%r12, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1309}:
+	movq	336(%rsp), %r15
+	testq	%r12, %r12
+	js	.LBB{4}
+.LBB{1262}:
+	movzbl	%bl, %eax
+	shll	$8, %r13d
+	orl	%eax, %r13d
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1310}:
+	cmpl	$16, %edi
+	jb	.LBB{1311}
+	jmp	.LBB{1219}
+.LBB{1312}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{1222}:
+	addq	$2, %r13
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1313}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	jmp	.LBB{1305}
+.LBB{1314}:
+	movl	%r13d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r13b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r13b, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{1235}:
+	addq	$3, %r14
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1315}:
+	cmpl	$16, %edi
+	jb	.LBB{1316}
+	jmp	.LBB{1219}
+.LBB{1317}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+.LBB{1305}:
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1318}:
+	movq	%r14, %r15
+	testq	%r8, %r8
+	jns	.LBB{4}
+	jmp	.LBB{42}
+.LBB{1319}:
+	movq	%r9, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r9, %rdi
+	addq	%r8, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r10b
+	orb	%dl, %r10b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1320}
+	cmpq	%rdi, %r8
+	jae	.LBB{1320}
+	movq	32(%rsp), %rdi
+	movq	%r8, %r10
+	jmp	.LBB{1321}
+.LBB{1322}:
+	incq	%r10
+	decq	%r9
+	je	.LBB{1320}
+.LBB{1321}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdx, %r11
+	jae	.LBB{1322}
+	movq	16(%rdi,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1322}
+	jmp	.LBB{5}
+.LBB{1323}:
+	addq	%r12, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1324}
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{1325}
+.LBB{1326}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%r12b, %edx
+.LBB{234}:
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{1327}
+.LBB{1328}:
+	movq	%r9, %rdx
+	addq	%r8, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1329}
+	cmpq	%rdx, %r8
+	jae	.LBB{1329}
+	movq	32(%rsp), %rdx
+	movq	%r9, %rsi
+	movq	%r8, %rdi
+	jmp	.LBB{1330}
+.LBB{1331}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1329}
+.LBB{1330}:
+	movq	%rdi, %r10
+	shrq	$6, %r10
+	cmpq	%rcx, %r10
+	jae	.LBB{1331}
+	movq	16(%rdx,%r10,8), %r10
+	btq	%rdi, %r10
+	jae	.LBB{1331}
+	jmp	.LBB{5}
+.LBB{1332}:
+	cmpq	$1024, %r10
+	je	.LBB{5}
+	movq	%r8, -8(%rsi,%rdx,8)
+	movq	%rdx, 232(%rsp)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1333}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	subq	%r9, %rax
+	jmp	.LBB{1325}
+.LBB{1334}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %r9
+	addq	%r12, %r9
+	setb	%dil
+	cmpq	%rsi, %r9
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdi
+	testq	%rdi, %rdi
+	je	.LBB{1335}
+	cmpq	%r9, %r12
+	jae	.LBB{1335}
+	movq	32(%rsp), %r9
+	movq	%r12, %r10
+	jmp	.LBB{1336}
+.LBB{1337}:
+	incq	%r10
+	decq	%r8
+	je	.LBB{1335}
+.LBB{1336}:
+	movq	%r10, %r11
+	shrq	$6, %r11
+	cmpq	%rdi, %r11
+	jae	.LBB{1337}
+	movq	16(%r9,%r11,8), %r11
+	btq	%r10, %r11
+	jae	.LBB{1337}
+	jmp	.LBB{5}
+.LBB{1338}:
+	addq	%r14, %rax
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1339}
+	movq	(%rdx,%rax,8), %rax
+	jmp	.LBB{1125}
+.LBB{1340}:
+	cmpl	$16, %edi
+	jb	.LBB{1325}
+	jmp	.LBB{1324}
+.LBB{1341}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	addq	%rax, %r9
+	jmp	.LBB{1342}
+.LBB{1343}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	decq	%r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	jne	.LBB{42}
+	jmp	.LBB{1327}
+.LBB{1344}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	shll	$8, %r12d
+	movzbl	%bl, %eax
+	orl	%r12d, %eax
+	jmp	.LBB{1325}
+.LBB{1345}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	decq	%r8
+	jmp	.LBB{1252}
+.LBB{1346}:
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	jb	.LBB{5}
+	cmpq	%rsi, %rdi
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1347}
+	cmpq	%rdi, %r12
+	jae	.LBB{1347}
+	movq	32(%rsp), %rsi
+	movq	%r8, %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1348}
+.LBB{1349}:
+	incq	%r9
+	decq	%rdi
+	je	.LBB{1347}
+.LBB{1348}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rcx, %r10
+	jae	.LBB{1349}
+	movq	16(%rsi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1349}
+	jmp	.LBB{5}
+.LBB{1350}:
+	movq	%r14, %r15
+	testq	%r8, %r8
+	jne	.LBB{4}
+	jmp	.LBB{42}
+.LBB{1351}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	imulq	%rax, %r9
+.LBB{1342}:
+	movq	%r9, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1352}:
+	testq	%r9, %r9
+	je	.LBB{1353}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1354}
+	cmpq	$-1, %r9
+	je	.LBB{1355}
+.LBB{1354}:
+	movq	%rax, %rcx
+	orq	%r9, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1356}
+	cqto
+	idivq	%r9
+	cmpl	$15, %edi
+	jbe	.LBB{1325}
+	jmp	.LBB{1324}
+.LBB{1357}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1358}
+.Ltmp{1359}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1360}:
+.LBB{1358}:
+	movq	200(%rsp), %rax
+	movq	%r14, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+	jmp	.LBB{42}
+.LBB{1361}:
+	testq	%r10, %r10
+	je	.LBB{5}
+	leaq	-2(%rdx), %rax
+	movq	%rax, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1324}
+	movq	-16(%rsi,%rdx,8), %rax
+	jmp	.LBB{1325}
+.LBB{1362}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1127}
+.Ltmp{1363}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1364}:
+.LBB{1127}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1365}:
+	leaq	-1(%r9), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1366}
+	xorl	%edx, %edx
+	jmp	.LBB{1367}
+.LBB{1368}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	cmpq	%r9, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1325}
+.LBB{1369}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+.LBB{1294}:
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1370}
+.LBB{1371}:
+	addq	%r12, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	40(%rsp), %rcx
+	jae	.LBB{1372}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1372}:
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	movq	16(%rsp), %rcx
+	movq	%r8, (%rcx,%rax,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1373}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	incq	%r8
+.LBB{1252}:
+	movq	%r8, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1374}:
+	movq	%r14, %r15
+	testq	%r8, %r8
+	js	.LBB{4}
+.LBB{42}:
+	movzbl	%bl, %eax
+	shll	$8, %r12d
+	orl	%eax, %r12d
+	movq	%r12, %r15
+	jmp	.LBB{4}
+.LBB{1375}:
+	cmpl	$16, %edi
+	jae	.LBB{1339}
+.LBB{1156}:
+	subq	%r8, %rax
+	jmp	.LBB{1125}
+.LBB{1376}:
+	cmpl	$16, %edi
+	jb	.LBB{1125}
+	jmp	.LBB{1339}
+.LBB{1377}:
+	cmpl	$16, %edi
+	jb	.LBB{336}
+	jmp	.LBB{1339}
+.LBB{1378}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+.LBB{1311}:
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	jmp	.LBB{1370}
+.LBB{1379}:
+	cmpl	$16, %edi
+	jae	.LBB{1339}
+.LBB{1140}:
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	je	.LBB{1327}
+	jmp	.LBB{113}
+.LBB{1380}:
+	cmpl	$16, %edi
+	jae	.LBB{1339}
+.LBB{25}:
+	shll	$8, %r14d
+	movzbl	%bl, %eax
+	orl	%r14d, %eax
+	jmp	.LBB{1125}
+.LBB{1381}:
+	cmpl	$16, %edi
+	jb	.LBB{301}
+	jmp	.LBB{1339}
+.LBB{1382}:
+	movl	%r12d, %eax
+	andb	$15, %al
+	movl	%ebx, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %r12b
+	shrb	$4, %bl
+	movzbl	%bl, %eax
+	movzbl	%r12b, %edx
+.LBB{238}:
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{1327}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{1383}:
+	cmpl	$16, %edi
+	jb	.LBB{370}
+	jmp	.LBB{1339}
+.LBB{1384}:
+	testq	%r8, %r8
+	je	.LBB{1385}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1386}
+	cmpq	$-1, %r8
+	je	.LBB{1387}
+.LBB{1386}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1388}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{1339}
+.LBB{1389}:
+	movq	208(%rsp), %r15
+	cmpq	$1024, %r15
+	je	.LBB{5}
+	cmpq	192(%rsp), %r15
+	jne	.LBB{1133}
+.Ltmp{1390}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1391}:
+.LBB{1133}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r15,8)
+	incq	%r15
+	movq	%r15, 208(%rsp)
+.LBB{113}:
+	movzbl	%bl, %eax
+	shll	$8, %r14d
+	orl	%eax, %r14d
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1392}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+.LBB{1316}:
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+.LBB{1370}:
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1393}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1339}
+.LBB{1169}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{1125}
+.LBB{1394}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1395}
+	xorl	%edx, %edx
+	jmp	.LBB{1396}
+.LBB{1397}:
+	cmpl	$16, %edi
+	jae	.LBB{1339}
+.LBB{1172}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{1125}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1398}:
+	cmpl	$16, %edi
+	jb	.LBB{362}
+	jmp	.LBB{1339}
+.LBB{1399}:
+	addq	%r14, %rax
+	movq	%rax, %rdi
+	shrq	$6, %rdi
+	cmpq	40(%rsp), %rdi
+	jae	.LBB{1400}
+	movl	$1, %r8d
+	movl	%eax, %ecx
+	shlq	%cl, %r8
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+	movq	32(%rsp), %rcx
+	andq	16(%rcx,%rdi,8), %r8
+	je	.LBB{1401}
+	jmp	.LBB{5}
+.LBB{1402}:
+	cmpl	$16, %edi
+	jb	.LBB{283}
+	jmp	.LBB{1339}
+.LBB{1403}:
+	cmpl	$16, %edi
+	jb	.LBB{400}
+	jmp	.LBB{1339}
+.LBB{1404}:
+	cmpl	$16, %edi
+	jb	.LBB{428}
+	jmp	.LBB{1339}
+.LBB{248}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1405}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1405}
+	jmp	.LBB{4}
+.LBB{1406}:
+	movq	%r8, %rcx
+	addq	%rax, %rcx
+	jb	.LBB{5}
+	movq	24(%rsp), %rsi
+	cmpq	%rsi, %rcx
+	ja	.LBB{5}
+	movq	%r8, %rdi
+	addq	%r12, %rdi
+	setb	%dl
+	cmpq	%rsi, %rdi
+	seta	%r9b
+	orb	%dl, %r9b
+	jne	.LBB{5}
+	movq	40(%rsp), %rdx
+	testq	%rdx, %rdx
+	je	.LBB{1407}
+	cmpq	%rdi, %r12
+	jae	.LBB{1407}
+	movq	32(%rsp), %rdi
+	movq	%r12, %r9
+	jmp	.LBB{1408}
+.LBB{1409}:
+	incq	%r9
+	decq	%r8
+	je	.LBB{1407}
+.LBB{1408}:
+	movq	%r9, %r10
+	shrq	$6, %r10
+	cmpq	%rdx, %r10
+	jae	.LBB{1409}
+	movq	16(%rdi,%r10,8), %r10
+	btq	%r9, %r10
+	jae	.LBB{1409}
+	jmp	.LBB{5}
+.LBB{1410}:
+	addq	%rbx, %rax
+	cmpq	24(%rsp), %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1411}
+.LBB{256}:
+	movq	16(%rsp), %rcx
+	movq	(%rcx,%rax,8), %rax
+	jmp	.LBB{315}
+.LBB{1412}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	jmp	.LBB{1413}
+.LBB{1414}:
+	movq	%r8, %rdx
+	addq	%r12, %rdx
+	jb	.LBB{5}
+	cmpq	24(%rsp), %rdx
+	ja	.LBB{5}
+	movq	40(%rsp), %rcx
+	testq	%rcx, %rcx
+	je	.LBB{1415}
+	cmpq	%rdx, %r12
+	jae	.LBB{1415}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rsi
+	movq	%r12, %rdi
+	jmp	.LBB{1416}
+.LBB{1417}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1415}
+.LBB{1416}:
+	movq	%rdi, %r9
+	shrq	$6, %r9
+	cmpq	%rcx, %r9
+	jae	.LBB{1417}
+	movq	16(%rdx,%r9,8), %r9
+	btq	%rdi, %r9
+	jae	.LBB{1417}
+	jmp	.LBB{5}
+.LBB{1418}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{258}
+.Ltmp{1419}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1420}:
+.LBB{258}:
+	movq	224(%rsp), %rax
+	movq	%r12, (%rax,%rbx,8)
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1421}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{348}:
+	subq	%r8, %rax
+	jmp	.LBB{315}
+.LBB{1422}:
+	cmpl	$16, %edi
+	jb	.LBB{315}
+	jmp	.LBB{1411}
+.LBB{1423}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{336}:
+	addq	%rax, %r8
+	jmp	.LBB{1424}
+.LBB{1425}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+	decq	%r12
+	movq	%r12, 48(%rsp,%rdi,8)
+	jne	.LBB{71}
+	jmp	.LBB{1426}
+.LBB{1427}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{30}:
+	shll	$8, %ebx
+	movzbl	%bpl, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{315}
+.LBB{1428}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{301}:
+	decq	%r12
+	jmp	.LBB{1429}
+.LBB{325}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1430}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1430}
+	jmp	.LBB{4}
+.LBB{1431}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{370}:
+	imulq	%rax, %r8
+.LBB{1424}:
+	movq	%r8, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1432}:
+	testq	%r8, %r8
+	je	.LBB{1433}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1434}
+	cmpq	$-1, %r8
+	je	.LBB{1435}
+.LBB{1434}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1436}
+	cqto
+	idivq	%r8
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{1411}
+.LBB{1437}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	cmpq	192(%rsp), %r14
+	jne	.LBB{266}
+.Ltmp{1438}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1439}:
+.LBB{266}:
+	movq	200(%rsp), %rax
+	movq	%r13, (%rax,%r14,8)
+.LBB{870}:
+	incq	%r14
+	movq	%r14, 208(%rsp)
+.LBB{71}:
+	movzbl	%bpl, %eax
+.LBB{1440}:
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{330}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1441}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1441}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1442}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1411}
+.LBB{368}:
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+	jmp	.LBB{315}
+.LBB{338}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1443}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1443}
+	jmp	.LBB{4}
+.LBB{1444}:
+	leaq	-1(%r8), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1445}
+	xorl	%edx, %edx
+	jmp	.LBB{1446}
+.LBB{1447}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{377}:
+	cmpq	%r8, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+.LBB{315}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1448}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{362}:
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1040}
+.LBB{1449}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{283}:
+	incq	%r12
+.LBB{1429}:
+	movq	%r12, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{379}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1450}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1450}
+	jmp	.LBB{4}
+.LBB{1451}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{400}:
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	jmp	.LBB{1040}
+.LBB{1452}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%ebp, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %bpl
+	movzbl	%bpl, %eax
+	jmp	.LBB{1453}
+.LBB{415}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1454}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1454}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1455}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{428}:
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+.LBB{1040}:
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{492}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1456}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1456}
+	jmp	.LBB{4}
+.LBB{502}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1457}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1457}
+	jmp	.LBB{4}
+.LBB{588}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1458}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1458}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{611}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1459}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1459}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{642}:
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1460}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1460}
+	jmp	.LBB{4}
+.LBB{646}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1461}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1461}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{679}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1462}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1462}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{767}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1463}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1463}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{833}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1464}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1464}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{855}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1465}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1465}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{925}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1466}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1466}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{977}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1467}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1467}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{991}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1468}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1468}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{997}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1469}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1469}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1067}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1470}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1470}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1101}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1471}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1471}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1151}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1472}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1472}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1176}:
+	testq	%r8, %r8
+	movq	%r13, %r15
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1473}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1473}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1228}:
+	testq	%r8, %r8
+	movq	%r14, %r15
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1474}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1474}
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1237}:
+	movq	336(%rsp), %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1475}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1475}
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1329}:
+	movq	%r14, %r15
+	testq	%r9, %r9
+	je	.LBB{4}
+	shlq	$3, %r8
+	addq	16(%rsp), %r8
+	shlq	$3, %r9
+	xorl	%ecx, %ecx
+.LBB{1476}:
+	movq	%rax, (%r8,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r9
+	jne	.LBB{1476}
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1347}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	leaq	(%rdx,%r12,8), %rcx
+	shlq	$3, %r8
+	xorl	%edx, %edx
+.LBB{1477}:
+	movq	%rax, (%rcx,%rdx)
+	addq	$8, %rdx
+	cmpq	%rdx, %r8
+	jne	.LBB{1477}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1415}:
+	movq	%r13, %r15
+	testq	%r8, %r8
+	je	.LBB{4}
+	shlq	$3, %r12
+	addq	16(%rsp), %r12
+	shlq	$3, %r8
+	xorl	%ecx, %ecx
+.LBB{1478}:
+	movq	%rax, (%r12,%rcx)
+	addq	$8, %rcx
+	cmpq	%rcx, %r8
+	jne	.LBB{1478}
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{297}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1479}
+	cqto
+	idivq	%r8
+	jmp	.LBB{298}
+.LBB{317}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1480}
+	cqto
+	idivq	%r8
+	jmp	.LBB{318}
+.LBB{452}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1481}
+	cqto
+	idivq	%r8
+	jmp	.LBB{453}
+.LBB{459}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1482}
+	cqto
+	idivq	%r8
+	jmp	.LBB{460}
+.LBB{480}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1483}
+	cqto
+	idivq	%r8
+	jmp	.LBB{481}
+.LBB{497}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1484}
+	cqto
+	idivq	%r8
+	jmp	.LBB{498}
+.LBB{541}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1485}
+	cqto
+	idivq	%r8
+	jmp	.LBB{542}
+.LBB{573}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1486}
+	cqto
+	idivq	%r8
+	jmp	.LBB{574}
+.LBB{717}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1487}
+	cqto
+	idivq	%r8
+	jmp	.LBB{718}
+.LBB{749}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1488}
+	cqto
+	idivq	%r8
+	jmp	.LBB{750}
+.LBB{797}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1489}
+	cqto
+	idivq	%r8
+	jmp	.LBB{798}
+.LBB{814}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1490}
+	cqto
+	idivq	%r8
+	jmp	.LBB{815}
+.LBB{819}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1491}
+	cqto
+	idivq	%r8
+	jmp	.LBB{820}
+.LBB{872}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1492}
+	cqto
+	idivq	%r8
+	jmp	.LBB{873}
+.LBB{912}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1493}
+	cqto
+	idivq	%r8
+	jmp	.LBB{913}
+.LBB{936}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1494}
+	cqto
+	idivq	%r8
+	jmp	.LBB{937}
+.LBB{940}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1495}
+	cqto
+	idivq	%r8
+	jmp	.LBB{941}
+.LBB{960}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1496}
+	cqto
+	idivq	%r8
+	jmp	.LBB{961}
+.LBB{966}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1497}
+	cqto
+	idivq	%r8
+	jmp	.LBB{967}
+.LBB{1016}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1498}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1017}
+.LBB{1059}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1499}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1060}
+.LBB{1091}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1500}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1092}
+.LBB{1400}:
+	cmpq	%rsi, %rax
+	jae	.LBB{5}
+.LBB{1401}:
+	movq	%r12, (%rdx,%rax,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1147}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1501}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1148}
+.LBB{1202}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1502}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1203}
+.LBB{1281}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1503}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1282}
+.LBB{1301}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1504}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1302}
+.LBB{1366}:
+	movq	%rax, %rcx
+	orq	%r9, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1505}
+	cqto
+	idivq	%r9
+	jmp	.LBB{1367}
+.LBB{1395}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1506}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1396}
+.LBB{240}:
+	movq	16(%rsp), %rdi
+.Ltmp{1507}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1508}:
+	jmp	.LBB{4}
+.LBB{303}:
+	movq	16(%rsp), %rdi
+.Ltmp{1509}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1510}:
+	jmp	.LBB{4}
+.LBB{321}:
+	movq	16(%rsp), %rdi
+.Ltmp{1511}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1512}:
+	jmp	.LBB{4}
+.LBB{350}:
+	movq	16(%rsp), %rdi
+.Ltmp{1513}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1514}:
+	jmp	.LBB{4}
+.LBB{354}:
+	movq	16(%rsp), %rdi
+.Ltmp{1515}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1516}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{403}:
+	movq	16(%rsp), %rdi
+.Ltmp{1517}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1518}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{516}:
+	movq	16(%rsp), %rdi
+.Ltmp{1519}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1520}:
+	jmp	.LBB{4}
+.LBB{533}:
+	movq	16(%rsp), %rdi
+.Ltmp{1521}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1522}:
+	jmp	.LBB{4}
+.LBB{582}:
+	movq	16(%rsp), %rdi
+.Ltmp{1523}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1524}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{599}:
+	movq	16(%rsp), %rdi
+.Ltmp{1525}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1526}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{619}:
+	movq	16(%rsp), %rdi
+.Ltmp{1527}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1528}:
+	jmp	.LBB{4}
+.LBB{625}:
+	movq	16(%rsp), %rdi
+.Ltmp{1529}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1530}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{663}:
+	movq	16(%rsp), %rdi
+.Ltmp{1531}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1532}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{823}:
+	movq	16(%rsp), %rdi
+.Ltmp{1533}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1534}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{893}:
+	movq	16(%rsp), %rdi
+.Ltmp{1535}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1536}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{929}:
+	movq	16(%rsp), %rdi
+.Ltmp{1537}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1538}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{987}:
+	movq	16(%rsp), %rdi
+.Ltmp{1539}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1540}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1021}:
+	movq	16(%rsp), %rdi
+.Ltmp{1541}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1542}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1031}:
+	movq	16(%rsp), %rdi
+.Ltmp{1543}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1544}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1036}:
+	movq	16(%rsp), %rdi
+.Ltmp{1545}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1546}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1087}:
+	movq	16(%rsp), %rdi
+.Ltmp{1547}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1548}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1111}:
+	movq	16(%rsp), %rdi
+.Ltmp{1549}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1550}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1445}:
+	movq	%rax, %rcx
+	orq	%r8, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1551}
+	cqto
+	idivq	%r8
+	jmp	.LBB{1446}
+.LBB{1552}:
+	movq	%r9, %r15
+	testq	%r8, %r8
+	jns	.LBB{4}
+	jmp	.LBB{166}
+.LBB{1553}:
+	movq	%rsi, %rcx
+	addq	%rax, %rcx
+	setb	%dl
+	cmpq	%rbp, %rcx
+	seta	%dil
+	orb	%dl, %dil
+	jne	.LBB{5}
+	movq	%rsi, %rdx
+	addq	%r8, %rdx
+	setb	%dil
+	cmpq	%rbp, %rdx
+	seta	%r10b
+	orb	%dil, %r10b
+	jne	.LBB{5}
+	testq	%r14, %r14
+	je	.LBB{1554}
+	cmpq	%rdx, %r8
+	jae	.LBB{1554}
+	movq	32(%rsp), %rdx
+	movq	%r8, %rdi
+	jmp	.LBB{1555}
+.LBB{1556}:
+	incq	%rdi
+	decq	%rsi
+	je	.LBB{1554}
+.LBB{1555}:
+	movq	%rdi, %r10
+	shrq	$6, %r10
+	cmpq	%r14, %r10
+	jae	.LBB{1556}
+	movq	16(%rdx,%r10,8), %r10
+	btq	%rdi, %r10
+	jae	.LBB{1556}
+	jmp	.LBB{5}
+.LBB{1557}:
+	addq	%rbx, %rax
+	cmpq	%rbp, %rax
+	jae	.LBB{5}
+	cmpl	$15, %edi
+	ja	.LBB{1558}
+	movq	(%r13,%rax,8), %rax
+	jmp	.LBB{1559}
+.LBB{1560}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	imulq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+.LBB{1413}:
+	movq	48(%rsp,%rax,8), %rax
+	movzbl	%bl, %edx
+	subq	48(%rsp,%rdx,8), %rax
+	shrb	$4, %cl
+	movzbl	%cl, %ecx
+	movq	%rax, 48(%rsp,%rcx,8)
+	jmp	.LBB{1426}
+.LBB{1561}:
+	movq	%rsi, %rcx
+	addq	%r8, %rcx
+	setb	%dl
+	cmpq	%rbp, %rcx
+	seta	%dil
+	orb	%dl, %dil
+	jne	.LBB{5}
+	testq	%r14, %r14
+	je	.LBB{1562}
+	cmpq	%rcx, %r8
+	jae	.LBB{1562}
+	movq	32(%rsp), %rcx
+	movq	%rsi, %rdx
+	movq	%r8, %rdi
+	jmp	.LBB{1563}
+.LBB{1564}:
+	incq	%rdi
+	decq	%rdx
+	je	.LBB{1562}
+.LBB{1563}:
+	movq	%rdi, %r10
+	shrq	$6, %r10
+	cmpq	%r14, %r10
+	jae	.LBB{1564}
+	movq	16(%rcx,%r10,8), %r10
+	btq	%rdi, %r10
+	jae	.LBB{1564}
+	jmp	.LBB{5}
+.LBB{1565}:
+	movq	232(%rsp), %rbx
+	cmpq	$1024, %rbx
+	je	.LBB{5}
+	movq	%r8, %r14
+	movq	%r9, %r15
+	cmpq	216(%rsp), %rbx
+	jne	.LBB{1566}
+.Ltmp{1567}:
+	leaq	216(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1568}:
+.LBB{1566}:
+	movq	224(%rsp), %rax
+	movq	%r14, (%rax,%rbx,8)
+.LBB{703}:
+	incq	%rbx
+	movq	%rbx, 232(%rsp)
+	jmp	.LBB{4}
+.LBB{1569}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	subq	%rsi, %rax
+	jmp	.LBB{1570}
+.LBB{1571}:
+	cmpl	$16, %edi
+	jb	.LBB{1570}
+	jmp	.LBB{1558}
+.LBB{1572}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	addq	%rax, %rsi
+	jmp	.LBB{1573}
+.LBB{1574}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	decq	%r8
+	movq	%r8, 48(%rsp,%rdi,8)
+	je	.LBB{1426}
+	movzbl	%r12b, %eax
+	jmp	.LBB{1440}
+.LBB{1575}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	shll	$8, %ebx
+	movzbl	%r12b, %eax
+	orl	%ebx, %eax
+	jmp	.LBB{1570}
+.LBB{1576}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	decq	%r8
+	jmp	.LBB{1577}
+.LBB{1578}:
+	movq	%r9, %r15
+	testq	%r8, %r8
+	jne	.LBB{4}
+	jmp	.LBB{166}
+.LBB{1579}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	imulq	%rax, %rsi
+.LBB{1573}:
+	movq	%rsi, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1580}:
+	testq	%rsi, %rsi
+	je	.LBB{1581}
+	movq	%rax, %rcx
+	negq	%rcx
+	jno	.LBB{1582}
+	cmpq	$-1, %rsi
+	je	.LBB{1583}
+.LBB{1582}:
+	movq	%rax, %rcx
+	orq	%rsi, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1584}
+	cqto
+	idivq	%rsi
+	cmpl	$15, %edi
+	jbe	.LBB{1570}
+	jmp	.LBB{1558}
+.LBB{1585}:
+	movq	208(%rsp), %r14
+	cmpq	$1024, %r14
+	je	.LBB{5}
+	movq	%r9, %r15
+	cmpq	192(%rsp), %r14
+	jne	.LBB{1586}
+.Ltmp{1587}:
+	leaq	192(%rsp), %rdi
+	callq	*_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$8grow_one17h{hash}E@GOTPCREL(%rip)
+.Ltmp{1588}:
+.LBB{1586}:
+	movq	200(%rsp), %rax
+	movq	%r15, (%rax,%r14,8)
+	incq	%r14
+	movq	%r14, 208(%rsp)
+	jmp	.LBB{166}
+.LBB{1589}:
+	movq	232(%rsp), %rax
+	testq	%rax, %rax
+	je	.LBB{5}
+	leaq	-1(%rax), %rcx
+	movq	%rcx, 232(%rsp)
+	cmpl	$15, %edi
+	ja	.LBB{1558}
+	movq	224(%rsp), %rcx
+	movq	-8(%rcx,%rax,8), %rax
+.LBB{1559}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1590}:
+	leaq	-1(%rsi), %rcx
+	cmpq	$-2, %rcx
+	jb	.LBB{1591}
+	xorl	%edx, %edx
+	jmp	.LBB{1592}
+.LBB{1593}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	cmpq	%rsi, %rax
+	setl	%al
+	setg	%cl
+	subb	%al, %cl
+	movsbq	%cl, %rax
+	jmp	.LBB{1570}
+.LBB{1594}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	movq	%rax, %rcx
+	shrq	%rcx
+	movabsq	$6148914691236517205, %rdx
+	andq	%rcx, %rdx
+	subq	%rdx, %rax
+	movabsq	$3689348814741910323, %rcx
+	movq	%rax, %rdx
+	andq	%rcx, %rdx
+	shrq	$2, %rax
+	andq	%rcx, %rax
+	addq	%rdx, %rax
+	movq	%rax, %rcx
+	shrq	$4, %rcx
+	addq	%rax, %rcx
+	movabsq	$1085102592571150095, %rax
+	andq	%rcx, %rax
+	movabsq	$72340172838076673, %rcx
+	imulq	%rax, %rcx
+	shrq	$56, %rcx
+	jmp	.LBB{1595}
+.LBB{1596}:
+	addq	%rbx, %rax
+	movq	%rax, %rcx
+	shrq	$6, %rcx
+	cmpq	%r14, %rcx
+	jae	.LBB{1597}
+	movq	32(%rsp), %rdx
+	movq	16(%rdx,%rcx,8), %rcx
+	btq	%rax, %rcx
+	jb	.LBB{5}
+.LBB{1597}:
+	cmpq	%rbp, %rax
+	jae	.LBB{5}
+	movq	%r8, (%r13,%rax,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1598}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	incq	%r8
+.LBB{1577}:
+	movq	%r8, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1599}:
+	movq	%r9, %r15
+	testq	%r8, %r8
+	js	.LBB{4}
+.LBB{166}:
+	movzbl	%r12b, %eax
+	shll	$8, %ebx
+	orl	%eax, %ebx
+	movq	%rbx, %r15
+	jmp	.LBB{4}
+.LBB{1600}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	movl	$64, %ecx
+	rep		bsfq	%rax, %rcx
+	jmp	.LBB{1595}
+.LBB{1601}:
+	movl	%ebx, %eax
+	andb	$15, %al
+	movl	%r12d, %edx
+	andb	$15, %dl
+	movzbl	%dl, %edx
+	movzbl	%al, %eax
+	movq	48(%rsp,%rax,8), %rax
+	addq	48(%rsp,%rdx,8), %rax
+	movl	%ecx, %edx
+	andl	$15, %edx
+	movq	%rax, 48(%rsp,%rdx,8)
+	shrb	$4, %bl
+	shrb	$4, %r12b
+	movzbl	%r12b, %eax
+.LBB{1453}:
+	movzbl	%bl, %edx
+	movq	48(%rsp,%rdx,8), %rdx
+	addq	48(%rsp,%rax,8), %rdx
+	shrb	$4, %cl
+	movzbl	%cl, %eax
+	movq	%rdx, 48(%rsp,%rax,8)
+.LBB{1426}:
+	movq	%r15, %rax
+	addq	$3, %rax
+	movq	%rax, %r15
+	jmp	.LBB{4}
+.LBB{1602}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	movl	$127, %ecx
+	bsrq	%rax, %rcx
+	xorq	$63, %rcx
+.LBB{1595}:
+	movq	%rcx, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1158}:
+	movq	16(%rsp), %rdi
+.Ltmp{1603}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1604}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1162}:
+	movq	16(%rsp), %rdi
+.Ltmp{1605}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1606}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1215}:
+	movq	16(%rsp), %rdi
+.Ltmp{1607}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1608}:
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1224}:
+	movq	16(%rsp), %rdi
+.Ltmp{1609}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1610}:
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1320}:
+	movq	16(%rsp), %rdi
+.Ltmp{1611}:
+	movq	%rax, %rdx
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1612}:
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1335}:
+.Ltmp{1613}:
+	movq	%rdx, %rdi
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1614}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1407}:
+	movq	16(%rsp), %rdi
+.Ltmp{1615}:
+	movq	%rax, %rdx
+	movq	%r12, %r8
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1616}:
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{287}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{291}
+	jmp	.LBB{16}
+.LBB{311}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{31}
+.LBB{430}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{434}
+	jmp	.LBB{307}
+.LBB{446}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{450}
+	jmp	.LBB{121}
+.LBB{472}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{476}
+	jmp	.LBB{358}
+.LBB{485}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{489}
+	jmp	.LBB{92}
+.LBB{524}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{528}
+	jmp	.LBB{360}
+.LBB{565}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{408}
+.LBB{674}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{36}
+.LBB{742}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{48}
+.LBB{779}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{66}
+.LBB{786}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{54}
+.LBB{806}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{77}
+.LBB{860}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{97}
+.LBB{875}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{11}
+.LBB{903}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{907}
+	jmp	.LBB{623}
+.LBB{917}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{669}
+.LBB{945}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{831}
+.LBB{954}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{135}
+.LBB{1001}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{169}
+.LBB{1053}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{148}
+.LBB{1082}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{843}
+.LBB{1142}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{26}
+.LBB{1195}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{1167}
+.LBB{1269}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1220}
+	jmp	.LBB{1219}
+.LBB{1285}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1249}
+	jmp	.LBB{1232}
+.LBB{1353}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1325}
+	jmp	.LBB{1324}
+.LBB{1385}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{1339}
+.LBB{1433}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{1411}
+.LBB{290}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{16}
+.LBB{291}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{314}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{31}
+.LBB{433}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{307}
+.LBB{434}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{449}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{121}
+.LBB{450}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{475}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{358}
+.LBB{476}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{488}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{92}
+.LBB{489}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{527}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{360}
+.LBB{528}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{568}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{408}
+.LBB{677}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{36}
+.LBB{745}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{48}
+.LBB{782}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{66}
+.LBB{789}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{54}
+.LBB{809}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{77}
+.LBB{863}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{97}
+.LBB{878}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{11}
+.LBB{906}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{623}
+.LBB{907}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{920}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{669}
+.LBB{948}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{831}
+.LBB{957}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{135}
+.LBB{1004}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{169}
+.LBB{1056}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{148}
+.LBB{1085}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{843}
+.LBB{1145}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{26}
+.LBB{1198}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{1167}
+.LBB{1272}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{1219}
+.LBB{1220}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1288}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	ja	.LBB{1232}
+.LBB{1249}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1356}:
+	xorl	%edx, %edx
+	divl	%r9d
+	cmpl	$15, %edi
+	ja	.LBB{1324}
+.LBB{1325}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1388}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{1339}
+.LBB{1591}:
+	movq	%rax, %rcx
+	orq	%rsi, %rcx
+	shrq	$32, %rcx
+	je	.LBB{1617}
+	cqto
+	idivq	%rsi
+	jmp	.LBB{1592}
+.LBB{1436}:
+	xorl	%edx, %edx
+	divl	%r8d
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{1411}
+.LBB{1562}:
+	movq	%r9, %r15
+	testq	%rsi, %rsi
+	je	.LBB{4}
+	movq	%r9, %rdi
+	leaq	(,%r8,8), %rcx
+	addq	%r13, %rcx
+	shlq	$3, %rsi
+	xorl	%edx, %edx
+.LBB{1618}:
+	movq	%rax, (%rcx,%rdx)
+	addq	$8, %rdx
+	cmpq	%rdx, %rsi
+	jne	.LBB{1618}
+	movq	%rdi, %r15
+	jmp	.LBB{4}
+.LBB{1479}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{298}:
+	cmpl	$16, %edi
+	jae	.LBB{16}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1480}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{318}:
+	cmpl	$16, %edi
+	jb	.LBB{1619}
+	jmp	.LBB{31}
+.LBB{1481}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{453}:
+	cmpl	$16, %edi
+	jae	.LBB{307}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1482}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{460}:
+	cmpl	$16, %edi
+	jae	.LBB{121}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1483}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{481}:
+	cmpl	$16, %edi
+	jae	.LBB{358}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1484}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{498}:
+	cmpl	$16, %edi
+	jae	.LBB{92}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1485}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{542}:
+	cmpl	$16, %edi
+	jae	.LBB{360}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1486}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{574}:
+	cmpl	$16, %edi
+	jb	.LBB{1619}
+	jmp	.LBB{408}
+.LBB{1487}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{718}:
+	cmpl	$16, %edi
+	jb	.LBB{1620}
+	jmp	.LBB{36}
+.LBB{1488}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{750}:
+	cmpl	$16, %edi
+	jb	.LBB{1621}
+	jmp	.LBB{48}
+.LBB{1489}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{798}:
+	cmpl	$16, %edi
+	jb	.LBB{1621}
+	jmp	.LBB{66}
+.LBB{1490}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{815}:
+	cmpl	$16, %edi
+	jb	.LBB{1622}
+	jmp	.LBB{77}
+.LBB{1491}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{820}:
+	cmpl	$16, %edi
+	jb	.LBB{1623}
+	jmp	.LBB{54}
+.LBB{1492}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{873}:
+	cmpl	$16, %edi
+	jb	.LBB{1621}
+	jmp	.LBB{97}
+.LBB{1493}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{913}:
+	cmpl	$16, %edi
+	jb	.LBB{1621}
+	jmp	.LBB{11}
+.LBB{1494}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{937}:
+	cmpl	$16, %edi
+	jae	.LBB{623}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	jmp	.LBB{4}
+.LBB{1495}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{941}:
+	cmpl	$16, %edi
+	jb	.LBB{1622}
+	jmp	.LBB{669}
+.LBB{1496}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{961}:
+	cmpl	$16, %edi
+	jb	.LBB{1623}
+	jmp	.LBB{831}
+.LBB{1497}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{967}:
+	cmpl	$16, %edi
+	jae	.LBB{135}
+.LBB{1622}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1498}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1017}:
+	cmpl	$16, %edi
+	jae	.LBB{169}
+.LBB{1621}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1499}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1060}:
+	cmpl	$16, %edi
+	jb	.LBB{1623}
+	jmp	.LBB{148}
+.LBB{1500}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1092}:
+	cmpl	$16, %edi
+	jae	.LBB{843}
+.LBB{1623}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1501}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1148}:
+	cmpl	$16, %edi
+	jb	.LBB{1624}
+	jmp	.LBB{26}
+.LBB{1502}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1203}:
+	cmpl	$16, %edi
+	jae	.LBB{1167}
+.LBB{1620}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1503}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1282}:
+	cmpl	$16, %edi
+	jae	.LBB{1219}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1504}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1302}:
+	cmpl	$16, %edi
+	jae	.LBB{1232}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	336(%rsp), %r15
+	jmp	.LBB{4}
+.LBB{1505}:
+	xorl	%edx, %edx
+	divl	%r9d
+.LBB{1367}:
+	cmpl	$16, %edi
+	jae	.LBB{1324}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r14, %r15
+	jmp	.LBB{4}
+.LBB{1506}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1396}:
+	cmpl	$16, %edi
+	jae	.LBB{1339}
+.LBB{1624}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1551}:
+	xorl	%edx, %edx
+	divl	%r8d
+.LBB{1446}:
+	cmpl	$16, %edi
+	jae	.LBB{1411}
+.LBB{1619}:
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r13, %r15
+	jmp	.LBB{4}
+.LBB{1581}:
+	xorl	%eax, %eax
+	cmpl	$15, %edi
+	jbe	.LBB{1570}
+	jmp	.LBB{1558}
+.LBB{1584}:
+	xorl	%edx, %edx
+	divl	%esi
+	cmpl	$15, %edi
+	ja	.LBB{1558}
+.LBB{1570}:
+	movq	%rax, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{1554}:
+.Ltmp{1625}:
+	movq	%r13, %rdi
+	movq	%rbp, %rsi
+	movq	%rax, %rdx
+	movq	%r9, %r15
+	callq	_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$11copy_within17h{hash}E
+.Ltmp{1626}:
+	jmp	.LBB{4}
+.LBB{1617}:
+	xorl	%edx, %edx
+	divl	%esi
+.LBB{1592}:
+	cmpl	$16, %edi
+	jae	.LBB{1558}
+	movq	%rdx, 48(%rsp,%rdi,8)
+	movq	%r9, %r15
+	jmp	.LBB{4}
+.LBB{101}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{103}
+	jmp	.LBB{104}
+.LBB{289}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{291}
+	jmp	.LBB{16}
+.LBB{313}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{31}
+.LBB{432}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{434}
+	jmp	.LBB{307}
+.LBB{448}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{450}
+	jmp	.LBB{121}
+.LBB{474}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{476}
+	jmp	.LBB{358}
+.LBB{487}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{489}
+	jmp	.LBB{92}
+.LBB{526}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{528}
+	jmp	.LBB{360}
+.LBB{567}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{408}
+.LBB{676}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{36}
+.LBB{744}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{48}
+.LBB{781}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{66}
+.LBB{788}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{54}
+.LBB{808}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{77}
+.LBB{862}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{97}
+.LBB{877}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{11}
+.LBB{905}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{907}
+	jmp	.LBB{623}
+.LBB{919}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{669}
+.LBB{947}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{831}
+.LBB{956}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{692}
+	jmp	.LBB{135}
+.LBB{1003}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{170}
+	jmp	.LBB{169}
+.LBB{1055}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{148}
+.LBB{1084}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{728}
+	jmp	.LBB{843}
+.LBB{1144}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{26}
+.LBB{1197}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{37}
+	jmp	.LBB{1167}
+.LBB{1271}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1220}
+	jmp	.LBB{1219}
+.LBB{1287}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1249}
+	jmp	.LBB{1232}
+.LBB{1355}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1325}
+	jmp	.LBB{1324}
+.LBB{1387}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1125}
+	jmp	.LBB{1339}
+.LBB{1435}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{315}
+	jmp	.LBB{1411}
+.LBB{1583}:
+	movabsq	$-9223372036854775808, %rax
+	cmpl	$15, %edi
+	jbe	.LBB{1570}
+.LBB{1558}:
+	leaq	.Lanon{1627}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1629}:
+	movq	%r12, 184(%rsp)
+.LBB{5}:
+	movq	8(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1630}
+.LBB{1631}:
+	movq	16(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1630}:
+	movq	32(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.LBB{1632}
+	leaq	32(%rsp), %rdi
+	callq	*_ZN5alloc4sync16Arc$LT$T$C$A$GT$9drop_slow17h{hash}E@GOTPCREL(%rip)
+.LBB{1632}:
+	movq	192(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1633}
+	movq	200(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1633}:
+	movq	216(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.LBB{1634}
+	movq	224(%rsp), %rdi
+	shlq	$3, %rsi
+	movl	$8, %edx
+	callq	*_RNvCsfLfy6EI15iL_7___rustc14___rust_dealloc@GOTPCREL(%rip)
+.LBB{1634}:
+	movq	184(%rsp), %rax
+	addq	$344, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.LBB{1635}:
+	movq	%r8, 184(%rsp)
+	movq	8(%rsp), %rsi
+	testq	%rsi, %rsi
+	jne	.LBB{1631}
+	jmp	.LBB{1630}
+.LBB{1}:
+	movq	%rdx, 48(%rsp)
+	leaq	48(%rsp), %rax
+	movq	%rax, 192(%rsp)
+	movq	_RNvXsi_NtNtNtCsgEmfK2I1SDS_4core3fmt3num3impjNtB9_7Display3fmt@GOTPCREL(%rip), %rax
+	movq	%rax, 200(%rsp)
+	leaq	.Lanon{1636}(%rip), %rcx
+	movq	%rcx, 208(%rsp)
+	movq	%rax, 216(%rsp)
+	leaq	.Lanon{1637}(%rip), %rdi
+	leaq	.Lanon{1638}(%rip), %rdx
+	leaq	192(%rsp), %rsi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.LBB{163}:
+.Ltmp{1639}:
+	leaq	.Lanon{1640}(%rip), %rdi
+	leaq	.Lanon{1641}(%rip), %rdx
+	movl	$43, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking9panic_fmt@GOTPCREL(%rip)
+.Ltmp{1642}:
+	jmp	.LBB{1643}
+.LBB{123}:
+	leaq	.Lanon{1644}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{8}:
+	leaq	.Lanon{1645}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{73}:
+	leaq	.Lanon{1646}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{50}:
+	leaq	.Lanon{1647}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{154}:
+	leaq	.Lanon{1648}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{117}:
+	leaq	.Lanon{1649}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{33}:
+	leaq	.Lanon{1650}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{63}:
+	leaq	.Lanon{1651}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{94}:
+	leaq	.Lanon{1652}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{68}:
+	leaq	.Lanon{1653}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{44}:
+	leaq	.Lanon{1654}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{145}:
+	leaq	.Lanon{1655}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{104}:
+	leaq	.Lanon{1656}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{132}:
+	leaq	.Lanon{1657}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{110}:
+	leaq	.Lanon{1658}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{39}:
+	leaq	.Lanon{1659}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{669}:
+	leaq	.Lanon{1660}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{16}:
+	leaq	.Lanon{1661}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{11}:
+	leaq	.Lanon{1662}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{77}:
+	leaq	.Lanon{1663}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{54}:
+	leaq	.Lanon{1664}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{121}:
+	leaq	.Lanon{1665}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{92}:
+	leaq	.Lanon{1666}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{843}:
+	leaq	.Lanon{1667}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{97}:
+	leaq	.Lanon{1668}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{48}:
+	leaq	.Lanon{1669}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{623}:
+	leaq	.Lanon{1670}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{36}:
+	leaq	.Lanon{1671}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{66}:
+	leaq	.Lanon{1672}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{31}:
+	leaq	.Lanon{1673}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{358}:
+	leaq	.Lanon{1674}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{148}:
+	leaq	.Lanon{1675}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{169}:
+	leaq	.Lanon{1676}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{360}:
+	leaq	.Lanon{1677}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{135}:
+	leaq	.Lanon{1678}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1232}:
+	leaq	.Lanon{1679}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1324}:
+	leaq	.Lanon{1680}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1167}:
+	leaq	.Lanon{1681}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{26}:
+	leaq	.Lanon{1682}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1219}:
+	leaq	.Lanon{1683}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1339}:
+	leaq	.Lanon{1684}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{1411}:
+	leaq	.Lanon{1685}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{831}:
+	leaq	.Lanon{1686}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{408}:
+	leaq	.Lanon{1687}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{388}:
+	leaq	.Lanon{1688}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{307}:
+	leaq	.Lanon{1688}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{690}:
+	leaq	.Lanon{1686}(%rip), %rdx
+	jmp	.LBB{1628}
+.LBB{152}:
+	leaq	.Lanon{1687}(%rip), %rdx
+.LBB{1628}:
+.Ltmp{1689}:
+	movl	$16, %esi
+	callq	*_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL(%rip)
+.Ltmp{1690}:
+.LBB{1643}:
+	ud2
+.LBB{1691}:
+.Ltmp{1692}:
+	movq	%rax, %rbx
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
+.LBB{1693}:
+.Ltmp{1694}:
+	jmp	.LBB{1695}
+.LBB{1696}:
+.Ltmp{1697}:
+.LBB{1695}:
+	movq	%rax, %rbx
+	leaq	8(%rsp), %rdi
+	callq	_ZN4core3ptr46drop_in_place$LT$rust_goto..memory..Memory$GT$17h{hash}E
+	leaq	192(%rsp), %rdi
+	callq	_ZN4core3ptr37drop_in_place$LT$rust_goto..Stack$GT$17h{hash}E
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
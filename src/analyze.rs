@@ -0,0 +1,562 @@
+// Did the dispatch get threaded? Answered from the asm instead of by reading it
+//
+// the whole experiment hinges on whether LLVM kept the duplicated dispatch of the
+// threaded variants or tail-merged it back into one central loop, and the only place
+// that shows is the generated code. this finds each run_* function in a `.s` file
+// (the one `cargo rustc --emit=asm` writes, or any other) and counts its indirect
+// jumps and jump tables: a threaded variant with a single indirect jump is a central
+// loop in disguise
+//
+// `rust-goto analyze` prints it next to the benchmark, `cargo xtask verify-threading`
+// fails the build on it. x86-64 (AT&T syntax) and aarch64 are understood
+//
+// the .s file has no addresses, and where a dispatch site lands matters too: the
+// branch target buffer is indexed by a few bits of the branch's address, and two
+// sites of the same loop that agree in those bits share (or fight over) one entry.
+// that's the usual explanation when adding unrelated code makes a variant faster or
+// slower on one machine and not on another, everything after the edit moved.
+// `dispatch_sites` reads them from `objdump -d` of a linked binary instead, and
+// `aliasing` pairs up the ones that collide under a typical BTB
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+// (symbol name, is it supposed to be threaded?)
+pub const DISPATCH_FUNCTIONS: &[(&str, bool)] = &[
+    ("run_central", false),
+    ("run_central_nohook", false),
+    ("run_predecoded", false),
+    ("run_loop_bodies", false),
+    ("run_threaded", true),
+    ("run_threaded_deep", true),
+    ("run_threaded_4", true),
+    ("run_threaded_preload", true),
+    ("run_table", false),
+    ("run_bundled", true),
+    ("run_bytes_central", false),
+    ("run_bytes_threaded", true),
+    ("run_structured_central", false),
+    ("run_structured_threaded", true),
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DispatchStats {
+    pub lines: usize,
+    pub indirect_jumps: usize,
+    pub jump_tables: usize,
+}
+
+impl DispatchStats {
+    // more than one place the next handler gets picked from
+    pub fn is_threaded(&self) -> bool {
+        self.indirect_jumps > 1
+    }
+}
+
+// the crate this was built from, where `cargo rustc` has to run
+pub fn crate_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+// same thing the README tells you to run by hand, `which` is --lib or --bin
+pub fn emit_asm(root: &Path, which: &str, target_dir: &Path, features: &[&str]) -> Result<PathBuf, String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let started = SystemTime::now();
+    let mut cmd = Command::new(cargo);
+    cmd.current_dir(root).args(["rustc", "--release", "--package", "rust-goto", which]);
+    if which == "--bin" {
+        cmd.arg("rust-goto");
+    }
+    let status = cmd
+        .arg("--target-dir")
+        .arg(target_dir)
+        .args(features.iter().flat_map(|f| ["--features", f]))
+        .args(["--", "--emit=asm"])
+        .status()
+        .map_err(|e| format!("failed to run cargo: {e}"))?;
+    if !status.success() {
+        return Err(format!("cargo rustc --emit=asm failed ({status})"));
+    }
+    newest_asm(&target_dir.join("release/deps"), started)
+}
+
+// there can be stale .s files from older builds lying around, take the newest one
+fn newest_asm(deps: &Path, not_before: SystemTime) -> Result<PathBuf, String> {
+    let entries = fs::read_dir(deps).map_err(|e| format!("can't read {}: {e}", deps.display()))?;
+    let mut best: Option<(SystemTime, PathBuf)> = None;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if !(name.starts_with("rust_goto-") && name.ends_with(".s")) {
+            continue;
+        }
+        let Ok(modified) = entry.metadata().and_then(|m| m.modified()) else { continue };
+        if best.as_ref().is_none_or(|(t, _)| modified > *t) {
+            best = Some((modified, path));
+        }
+    }
+    match best {
+        Some((modified, path)) => {
+            if modified < not_before {
+                // cargo didn't rebuild (nothing changed), the newest file is still the right one
+                eprintln!("note: reusing {}, the build was already up to date", path.display());
+            }
+            Ok(path)
+        }
+        None => Err(format!("no rust_goto-*.s found in {}", deps.display())),
+    }
+}
+
+// finds the body of a function from its label down to the .Lfunc_end marker.
+// symbols are mangled, eg. _ZN9rust_goto11run_central17h...E, the length prefix
+// makes sure run_threaded doesn't match run_threaded_deep
+pub fn function_body<'a>(asm: &'a str, name: &str) -> Option<&'a str> {
+    let needle = format!("{}{}", name.len(), name);
+    let mut start = None;
+    let mut offset = 0;
+    for line in asm.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if start.is_none() {
+            if !trimmed.starts_with(['.', '\t', ' ', '#']) && trimmed.ends_with(':') && is_symbol_for(trimmed, &needle)
+            {
+                start = Some(offset + line.len());
+            }
+        } else if trimmed.starts_with(".Lfunc_end") {
+            return start.map(|s| &asm[s..offset]);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+// whether `symbol` is the mangled name of the function `name`
+pub fn is_symbol(symbol: &str, name: &str) -> bool {
+    is_symbol_for(symbol, &format!("{}{}", name.len(), name))
+}
+
+fn is_symbol_for(label: &str, needle: &str) -> bool {
+    label.match_indices(needle).any(|(i, _)| {
+        // the segment has to stand on its own: preceded by a non-digit
+        // (otherwise "111run_central" would match) and followed by the hash/end
+        let before = label[..i].chars().last();
+        let after = label[i + needle.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_digit()) && !after.is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    })
+}
+
+pub fn dispatch_stats(body: &str) -> DispatchStats {
+    let mut stats = DispatchStats::default();
+    let mut tables: Vec<&str> = Vec::new();
+    for line in body.lines() {
+        let code = line.split('#').next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        stats.lines += 1;
+        if is_indirect_jump(code) {
+            stats.indirect_jumps += 1;
+        }
+        for (i, _) in code.match_indices(".LJTI") {
+            let label: &str = code[i..]
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .next()
+                .unwrap_or("");
+            if !tables.contains(&label) {
+                tables.push(label);
+            }
+        }
+    }
+    stats.jump_tables = tables.len();
+    stats
+}
+
+// `callq _ZN9rust_goto11run_central17h...E` and friends, including tail calls
+pub fn direct_calls(asm: &str, name: &str) -> usize {
+    let needle = format!("{}{}", name.len(), name);
+    asm.lines()
+        .filter(|line| {
+            let code = line.split('#').next().unwrap_or("").trim();
+            let mut parts = code.split_whitespace();
+            matches!(parts.next(), Some("call" | "callq" | "jmp" | "jmpq" | "bl" | "b"))
+                && parts.next().is_some_and(|target| is_symbol_for(target, &needle))
+        })
+        .count()
+}
+
+// x86-64 (AT&T): `jmpq *%r9` / `jmp *%rax`, aarch64: `br x8`
+fn is_indirect_jump(code: &str) -> bool {
+    let mut parts = code.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("jmpq" | "jmp"), Some(target)) => target.starts_with('*'),
+        (Some("br"), Some(target)) => target.starts_with('x'),
+        _ => false,
+    }
+}
+
+// the stats of every DISPATCH_FUNCTIONS entry found in `asm`, None for the ones that
+// aren't there (inlined, renamed, or not built with this configuration)
+pub fn analyze(asm: &str) -> Vec<(&'static str, bool, Option<DispatchStats>)> {
+    DISPATCH_FUNCTIONS
+        .iter()
+        .map(|&(name, threaded)| (name, threaded, function_body(asm, name).map(dispatch_stats)))
+        .collect()
+}
+
+// a typical BTB, not any one core's: entries per 32-byte fetch block (2^5), 512 sets
+// picked by the address bits above that (5..14). real ones hash in more bits or
+// differ in the sizes, so a flagged pair is a suspect to time, not a verdict
+pub const BTB_BLOCK_BITS: u32 = 5;
+pub const BTB_INDEX_BITS: u32 = 9;
+
+// an indirect jump in a linked binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Site {
+    pub function: &'static str,
+    pub address: u64,
+}
+
+impl Site {
+    // where it sits in its 64-byte cache line
+    pub fn line_offset(&self) -> u64 {
+        self.address % 64
+    }
+
+    fn block(&self) -> u64 {
+        self.address >> BTB_BLOCK_BITS
+    }
+
+    fn set(&self) -> u64 {
+        self.block() & ((1 << BTB_INDEX_BITS) - 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alias {
+    // in the same fetch block, they compete for the entries of that block
+    SameBlock,
+    // blocks 2^(BTB_BLOCK_BITS + BTB_INDEX_BITS) bytes apart (or a multiple), same set
+    SameSet,
+}
+
+// `objdump -d --no-show-raw-insn` of `binary`, $OBJDUMP or objdump on the path
+pub fn disassemble_binary(binary: &Path) -> Result<String, String> {
+    let objdump = std::env::var("OBJDUMP").unwrap_or_else(|_| "objdump".to_string());
+    let out = Command::new(&objdump)
+        .args(["-d", "--no-show-raw-insn"])
+        .arg(binary)
+        .output()
+        .map_err(|e| format!("failed to run {objdump}: {e}"))?;
+    if !out.status.success() {
+        return Err(format!("{objdump} -d {} failed ({})", binary.display(), out.status));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+// the indirect jumps of every DISPATCH_FUNCTIONS entry in objdump's output, in the
+// order they come in. a function that got inlined or cloned under another name
+// doesn't show up
+pub fn dispatch_sites(objdump: &str) -> Vec<Site> {
+    let mut sites = Vec::new();
+    let mut function = None;
+    for line in objdump.lines() {
+        // `00000000000bce00 <_ZN9rust_goto12run_threaded17h...E>:` starts a function
+        if let Some(symbol) = line.strip_suffix(">:").and_then(|l| l.split_once(" <")).map(|(_, s)| s) {
+            function = DISPATCH_FUNCTIONS.iter().find(|(name, _)| is_symbol(symbol, name)).map(|&(name, _)| name);
+            continue;
+        }
+        let Some(function) = function else { continue };
+        // `   bcee4:\tjmp    *%rdx`, CET builds put a notrack prefix on it
+        let Some((address, code)) = line.split_once(':') else { continue };
+        let Ok(address) = u64::from_str_radix(address.trim(), 16) else { continue };
+        let code = code.trim();
+        if is_indirect_jump(code.strip_prefix("notrack").unwrap_or(code).trim_start()) {
+            sites.push(Site { function, address });
+        }
+    }
+    sites
+}
+
+// pairs of sites (indexes into `sites`) in the same function that collide in the BTB.
+// only one variant runs at a time, so sites of different functions never compete
+pub fn aliasing(sites: &[Site]) -> Vec<(usize, usize, Alias)> {
+    let mut pairs = Vec::new();
+    for (i, a) in sites.iter().enumerate() {
+        for (j, b) in sites.iter().enumerate().skip(i + 1) {
+            if a.function != b.function {
+                continue;
+            }
+            if a.block() == b.block() {
+                pairs.push((i, j, Alias::SameBlock));
+            } else if a.set() == b.set() {
+                pairs.push((i, j, Alias::SameSet));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_functions_and_counts_dispatch_sites() {
+        let asm = "\
+_ZN9rust_goto12run_threaded17h0123456789abcdefE:
+\t.cfi_startproc
+\tleaq\t.LJTI3_0(%rip), %rcx
+\tjmpq\t*%rax
+\tmovslq\t(%rcx,%rax,4), %rax  # jmpq *%rdx in a comment
+\tjmpq\t*%rdx
+.Lfunc_end3:
+_ZN9rust_goto17run_threaded_deep17hfedcba9876543210E:
+\tbr\tx8
+\tjmp\t.LBB4_2
+.Lfunc_end4:
+";
+        let threaded = dispatch_stats(function_body(asm, "run_threaded").unwrap());
+        assert_eq!(threaded, DispatchStats { lines: 5, indirect_jumps: 2, jump_tables: 1 });
+        assert!(threaded.is_threaded());
+        // run_threaded's needle doesn't match run_threaded_deep, and the other way around
+        let deep = dispatch_stats(function_body(asm, "run_threaded_deep").unwrap());
+        assert_eq!(deep, DispatchStats { lines: 2, indirect_jumps: 1, jump_tables: 0 });
+        assert!(!deep.is_threaded());
+        assert!(function_body(asm, "run_central").is_none());
+
+        let report = analyze(asm);
+        assert_eq!(report.len(), DISPATCH_FUNCTIONS.len());
+        assert!(report.iter().any(|&(name, _, stats)| name == "run_threaded" && stats == Some(threaded)));
+        assert_eq!(direct_calls("\tcallq\t_ZN9rust_goto11run_central17h00E\n", "run_central"), 1);
+    }
+
+    #[test]
+    fn dispatch_sites_and_their_aliases() {
+        let objdump = "\
+0000000000001000 <_ZN9rust_goto12run_threaded17h0123456789abcdefE>:
+    1004:\tjmp    *%rax
+    1010:\tjmp    *%rdx
+    1020:\tnotrack jmp *%rcx
+    5020:\tjmp    *%rsi
+    5050:\tjmp    .LBB4_2
+0000000000008000 <_ZN9rust_goto17run_threaded_deep17hfedcba9876543210E>:
+    9004:\tbr\tx8
+000000000000a000 <_ZN4core3fmt5write17h0000000000000000E>:
+    a010:\tjmp    *%rax
+";
+        let sites = dispatch_sites(objdump);
+        let at = |function, address| Site { function, address };
+        assert_eq!(
+            sites,
+            [
+                at("run_threaded", 0x1004),
+                at("run_threaded", 0x1010),
+                at("run_threaded", 0x1020),
+                at("run_threaded", 0x5020),
+                at("run_threaded_deep", 0x9004),
+            ]
+        );
+        assert_eq!(sites[2].line_offset(), 0x20);
+        // 0x1004 and 0x9004 share a set, but only one of the two functions runs at a time
+        assert_eq!(aliasing(&sites), [(0, 1, Alias::SameBlock), (2, 3, Alias::SameSet)]);
+    }
+}
+
+// golden asm, `cargo test --release --features asm-snapshots`: the normalized body of
+// every DISPATCH_FUNCTIONS entry is kept in snapshots/<arch>-<os>/, and a test fails
+// with a diff when the codegen changes, so a handler edit shows what it did to the
+// dispatch. codegen depends on the compiler too, so the snapshots remember which
+// rustc took them and the test refuses to compare against another one.
+// RG_BLESS_SNAPSHOTS=1 writes them instead of comparing
+#[cfg(all(test, feature = "asm-snapshots"))]
+mod snapshots {
+    use super::*;
+
+    const BLESS: &str = "RG_BLESS_SNAPSHOTS";
+
+    fn snapshot_dir() -> PathBuf {
+        crate_root().join("snapshots").join(format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS))
+    }
+
+    fn rustc_version() -> String {
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let out = Command::new(rustc).arg("-V").output().expect("rustc -V");
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    }
+
+    // what's left of a function body once the noise that changes on every unrelated
+    // edit is gone: directives, comments, the hash in mangled names and the numbering
+    // of local labels, which gets redone per function in order of appearance. that
+    // includes the names of anonymous constants, .Lanon.<hash of the crate>.<n>
+    fn normalize(body: &str) -> String {
+        let mut labels: Vec<String> = Vec::new();
+        let mut out = String::new();
+        for line in body.lines() {
+            let code = line.split('#').next().unwrap_or("").trim_end();
+            let trimmed = code.trim_start();
+            let directive = trimmed.starts_with('.') && !trimmed.starts_with(".L");
+            if trimmed.is_empty() || directive {
+                continue;
+            }
+            out.push_str(&rename_labels(&strip_hashes(code), &mut labels));
+            out.push('\n');
+        }
+        out
+    }
+
+    // _ZN9rust_goto11run_central17h0123456789abcdefE -> ..17h{hash}E
+    fn strip_hashes(line: &str) -> String {
+        let mut out = String::new();
+        let mut rest = line;
+        while let Some(i) = rest.find("17h") {
+            let hash = rest.get(i + 3..i + 19);
+            if hash.is_some_and(|h| h.bytes().all(|b| b.is_ascii_hexdigit())) && rest[i + 19..].starts_with('E') {
+                out.push_str(&rest[..i]);
+                out.push_str("17h{hash}");
+                rest = &rest[i + 19..];
+            } else {
+                out.push_str(&rest[..i + 3]);
+                rest = &rest[i + 3..];
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    // .LBB12_7, .LJTI12_0, .Ltmp3, .Lanon.9f3e...c1.12 -> .LBB{n}, .LJTI{n}, .Ltmp{n},
+    // .Lanon{n}, numbered per function
+    fn rename_labels(line: &str, labels: &mut Vec<String>) -> String {
+        let mut out = String::new();
+        let mut rest = line;
+        while let Some(i) = rest.find(".L") {
+            out.push_str(&rest[..i]);
+            let label = &rest[i..];
+            let anon = label.starts_with(".Lanon.");
+            let end = label[2..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || (anon && c == '.')))
+                .map_or(label.len(), |e| e + 2);
+            let (label, after) = label.split_at(end);
+            let kind = if anon { ".Lanon" } else { label.trim_end_matches(|c: char| c.is_ascii_digit() || c == '_') };
+            let n = match labels.iter().position(|l| l == label) {
+                Some(n) => n,
+                None => {
+                    labels.push(label.to_string());
+                    labels.len() - 1
+                }
+            };
+            out.push_str(&format!("{kind}{{{n}}}"));
+            rest = after;
+        }
+        out.push_str(rest);
+        out
+    }
+
+    // the lines that differ, - for the snapshot and + for now, with a little context.
+    // common prefix and suffix go first, what's left in the middle goes through an LCS
+    // when it's small enough, otherwise it's shown whole
+    fn diff(old: &str, new: &str) -> String {
+        const CONTEXT: usize = 3;
+        const MAX_LINES: usize = 200;
+        let (old, new): (Vec<&str>, Vec<&str>) = (old.lines().collect(), new.lines().collect());
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+        let (a, b) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+        let mut ops: Vec<(char, &str)> = Vec::new();
+        if a.len() * b.len() <= 4_000_000 {
+            // lcs[i][j]: longest common subsequence of a[i..] and b[j..]
+            let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+            for i in (0..a.len()).rev() {
+                for j in (0..b.len()).rev() {
+                    lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+                }
+            }
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() || j < b.len() {
+                if i < a.len() && j < b.len() && a[i] == b[j] {
+                    ops.push((' ', a[i]));
+                    (i, j) = (i + 1, j + 1);
+                } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+                    ops.push(('+', b[j]));
+                    j += 1;
+                } else {
+                    ops.push(('-', a[i]));
+                    i += 1;
+                }
+            }
+        } else {
+            ops.extend(a.iter().map(|&l| ('-', l)));
+            ops.extend(b.iter().map(|&l| ('+', l)));
+        }
+
+        let mut out = format!("@@ line {} @@\n", prefix + 1);
+        let before = &old[prefix.saturating_sub(CONTEXT)..prefix];
+        let after = &old[old.len() - suffix..][..suffix.min(CONTEXT)];
+        let lines: Vec<String> = before
+            .iter()
+            .map(|l| format!("  {l}"))
+            .chain(ops.iter().filter(|(op, _)| *op != ' ' || ops.len() < MAX_LINES).map(|(op, l)| format!("{op} {l}")))
+            .chain(after.iter().map(|l| format!("  {l}")))
+            .collect();
+        for line in lines.iter().take(MAX_LINES) {
+            out.push_str(line);
+            out.push('\n');
+        }
+        if lines.len() > MAX_LINES {
+            out.push_str(&format!("... {} more lines\n", lines.len() - MAX_LINES));
+        }
+        out
+    }
+
+    #[test]
+    fn normalizing_keeps_only_what_the_code_does() {
+        let body = "\t.cfi_startproc\n# %bb.0:\n\tleaq\t.LJTI3_0(%rip), %rcx  # comment\n.LBB3_7:\n\tjmp\t.LBB3_7\n\
+                    \tcallq\t_ZN9rust_goto5Stack4call17h0123456789abcdefE\n\
+                    \tleaq\t.Lanon.fbb223e5c2328da770faeb7ff073fe11.250(%rip), %rdi\n\
+                    \tleaq\t.Lanon.fbb223e5c2328da770faeb7ff073fe11.232(%rip), %rsi\n";
+        assert_eq!(
+            normalize(body),
+            "\tleaq\t.LJTI{0}(%rip), %rcx\n.LBB{1}:\n\tjmp\t.LBB{1}\n\tcallq\t_ZN9rust_goto5Stack4call17h{hash}E\n\
+             \tleaq\t.Lanon{2}(%rip), %rdi\n\tleaq\t.Lanon{3}(%rip), %rsi\n"
+        );
+        assert_eq!(diff("a\nb\nc\n", "a\nx\nc\n"), "@@ line 2 @@\n  a\n+ x\n- b\n  c\n");
+    }
+
+    #[test]
+    fn dispatch_matches_the_snapshots() {
+        let root = crate_root();
+        let asm_path = emit_asm(&root, "--lib", &root.join("target/asm-snapshots"), &[]).unwrap();
+        let asm = fs::read_to_string(&asm_path).unwrap();
+        let dir = snapshot_dir();
+        let rustc = rustc_version();
+
+        if std::env::var_os(BLESS).is_some() {
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("rustc-version"), format!("{rustc}\n")).unwrap();
+            for &(name, _) in DISPATCH_FUNCTIONS {
+                let body = function_body(&asm, name).unwrap_or_else(|| panic!("{name} isn't in the asm"));
+                fs::write(dir.join(format!("{name}.s")), normalize(body)).unwrap();
+            }
+            return;
+        }
+
+        let taken_with = fs::read_to_string(dir.join("rustc-version"))
+            .unwrap_or_else(|_| panic!("no snapshots for this target in {}, take them with {BLESS}=1", dir.display()));
+        assert_eq!(
+            taken_with.trim(),
+            rustc,
+            "the snapshots are from another compiler, codegen isn't comparable. {BLESS}=1 retakes them"
+        );
+        let mut changed = Vec::new();
+        for &(name, _) in DISPATCH_FUNCTIONS {
+            let body = normalize(function_body(&asm, name).unwrap_or_else(|| panic!("{name} isn't in the asm")));
+            let snapshot = fs::read_to_string(dir.join(format!("{name}.s"))).unwrap_or_default();
+            if body != snapshot {
+                eprintln!("--- snapshots/{name}.s\n+++ now\n{}", diff(&snapshot, &body));
+                changed.push(name);
+            }
+        }
+        assert!(changed.is_empty(), "codegen changed for {changed:?}, see the diffs above. {BLESS}=1 accepts it");
+    }
+}
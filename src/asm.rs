@@ -0,0 +1,565 @@
+// Text assembler, so benchmark programs can be written as .vmasm files instead of
+// encode() calls
+//
+//   ; sum(i*i - i + 1) for i in 1..=r0
+//           loadi r1, 0
+//           loadi r2, 1
+//   loop:   mov   r3, r0
+//           mul   r4, r3, r3
+//           ...
+//           jmpnz r0, loop
+//           halt  r1
+//
+// one instruction per line, operands separated by commas, `;` or `#` starts a
+// comment. registers are r0..r15, or by their calling-convention aliases arg0..arg7,
+// ret, acc and tmp0..tmp5 (see ARG_REGS in lib.rs). immediates are decimal or 0x hex
+// and have to fit in 16 bits unsigned (that's what LOADI and the jumps encode). a label
+// is `name:`, on its own line or in front of an instruction, and can be used
+// anywhere a jump target goes. `.entry label` (or a pc) sets where execution starts,
+// `.memory 1024` how many words of memory LOAD/STORE get. `load r1, r2, 8` is
+// r1 = mem[r2 + 8], `store r1, r2, 8` the other way around, offsets are 0..255.
+// `memcpy r1, r2, r3` copies r3 words from mem[r2] to mem[r1], `memset r1, r2, r3`
+// sets r3 words from mem[r1] to r2. `.data 16, 1, -2, 0x30` puts those words in
+// memory from address 16 on before the program starts, `.rodata` the same but
+// read-only, writing there stops the program (see memory.rs). `cmp r1, r2, r3` sets
+// r1 to -1, 0 or 1 as r2 is below, equal to or above r3, and `jeq`/`jne`/`jlt`/`jge
+// r1, label` branch on that, `jmp label` always jumps. `waitevent r1` takes the next
+// event the host posts into r1 (Vm::post_event). `clz r1, r2`, `ctz r1, r2` and
+// `popcnt r1, r2` count r2's leading zeros, trailing zeros and set bits (64 for the
+// zeros of 0). `trap 7` calls the host's trap handler with 7 (Vm::set_trap_handler),
+// `resolve 3` asks the Vm's resolver what 3 means first and becomes that TRAP
+// (Vm::set_resolver).
+// `.fall-off-end halt` lets the program end by running past its last instruction, as
+// if a `halt r0` followed, `.fall-off-end trap` makes that stop it with -1 instead
+// (FallOffEnd). without either the last instruction has to be halt, ret or jmp
+//
+// labels end up as Program names and every instruction's line number as its Program
+// line, so whatever looks at the program later can show them.
+// `disassemble` goes the other way, with the names as labels again and what each
+// register op does in a comment
+//
+//           add     r3, r1, r2        ; r3 = r1 + r2
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+use crate::{
+    ACC_REG, ARG_REGS, DEFAULT_MEMORY_WORDS, FallOffEnd, Instruction, NREGS, Opcode, Program, RET_REG, TMP_REGS,
+    semantics,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+// what comes after the mnemonic
+#[derive(Clone, Copy)]
+enum Shape {
+    Nothing,   // ret
+    Imm,       // trap 7
+    Target,    // call label, jmp label
+    Reg,       // halt r1
+    RegImm,    // loadi r1, 42
+    RegReg,    // mov r1, r2
+    RegRegReg, // add r1, r2, r3
+    RegRegOff, // load r1, r2, 8
+    RegTarget, // jmpnz r1, label
+}
+
+const MNEMONICS: &[(&str, Opcode, Shape)] = &[
+    ("halt", Opcode::Halt, Shape::Reg),
+    ("loadi", Opcode::Loadi, Shape::RegImm),
+    ("add", Opcode::Add, Shape::RegRegReg),
+    ("sub", Opcode::Sub, Shape::RegRegReg),
+    ("mul", Opcode::Mul, Shape::RegRegReg),
+    ("div", Opcode::Div, Shape::RegRegReg),
+    ("mod", Opcode::Mod, Shape::RegRegReg),
+    ("inc", Opcode::Inc, Shape::Reg),
+    ("dec", Opcode::Dec, Shape::Reg),
+    ("jmpnz", Opcode::Jmpnz, Shape::RegTarget),
+    ("mov", Opcode::Mov, Shape::RegReg),
+    ("call", Opcode::Call, Shape::Target),
+    ("ret", Opcode::Ret, Shape::Nothing),
+    ("push", Opcode::Push, Shape::Reg),
+    ("pop", Opcode::Pop, Shape::Reg),
+    ("load", Opcode::Load, Shape::RegRegOff),
+    ("store", Opcode::Store, Shape::RegRegOff),
+    ("memcpy", Opcode::Memcpy, Shape::RegRegReg),
+    ("memset", Opcode::Memset, Shape::RegRegReg),
+    ("cmp", Opcode::Cmp, Shape::RegRegReg),
+    ("clz", Opcode::Clz, Shape::RegReg),
+    ("ctz", Opcode::Ctz, Shape::RegReg),
+    ("popcnt", Opcode::Popcnt, Shape::RegReg),
+    ("jmp", Opcode::Jmp, Shape::Target),
+    ("jeq", Opcode::Jeq, Shape::RegTarget),
+    ("jne", Opcode::Jne, Shape::RegTarget),
+    ("jlt", Opcode::Jlt, Shape::RegTarget),
+    ("jge", Opcode::Jge, Shape::RegTarget),
+    ("waitevent", Opcode::Waitevent, Shape::Reg),
+    ("trap", Opcode::Trap, Shape::Imm),
+    ("resolve", Opcode::ResolveCall, Shape::Imm),
+];
+
+// a source line with the comment and the label taken off
+struct Line<'a> {
+    number: usize,
+    label: Option<&'a str>,
+    body: &'a str,
+}
+
+fn split_lines(src: &str) -> Result<Vec<Line<'_>>, AsmError> {
+    let mut out = Vec::new();
+    for (i, raw) in src.lines().enumerate() {
+        let number = i + 1;
+        let text = raw.split([';', '#']).next().unwrap_or("").trim();
+        let (label, body) = match text.split_once(':') {
+            Some((label, body)) => {
+                let label = label.trim();
+                if !is_identifier(label) {
+                    return Err(AsmError { line: number, message: format!("bad label `{label}`") });
+                }
+                (Some(label), body.trim())
+            }
+            None => (None, text),
+        };
+        if label.is_some() || !body.is_empty() {
+            out.push(Line { number, label, body });
+        }
+    }
+    Ok(out)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+// `arg3`, `tmp0`: an alias family and its index
+fn alias_index(s: &str, family: &str, count: usize) -> Option<usize> {
+    s.strip_prefix(family).and_then(|n| n.parse::<usize>().ok()).filter(|&n| n < count)
+}
+
+fn parse_reg(s: &str) -> Result<u8, String> {
+    let alias = match s {
+        "ret" => Some(RET_REG),
+        "acc" => Some(ACC_REG),
+        _ => alias_index(s, "arg", ARG_REGS)
+            .map(|n| n as u8)
+            .or_else(|| alias_index(s, "tmp", TMP_REGS.len()).map(|n| TMP_REGS.start + n as u8)),
+    };
+    if let Some(reg) = alias {
+        return Ok(reg);
+    }
+    let n = s
+        .strip_prefix(['r', 'R'])
+        .and_then(|n| n.parse::<usize>().ok())
+        .ok_or_else(|| {
+            let (last, last_arg, last_tmp) = (NREGS - 1, ARG_REGS - 1, TMP_REGS.len() - 1);
+            format!("expected a register (r0..r{last}, or arg0..arg{last_arg}, ret, acc, tmp0..tmp{last_tmp}), got `{s}`")
+        })?;
+    if n >= NREGS {
+        return Err(format!("there's no register {s}, only r0..r{}", NREGS - 1));
+    }
+    Ok(n as u8)
+}
+
+fn parse_imm(s: &str) -> Result<u16, String> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16),
+        None => s.parse::<i64>(),
+    };
+    let v = parsed.map_err(|_| format!("expected a number, got `{s}`"))?;
+    u16::try_from(v).map_err(|_| format!("{v} doesn't fit in a 16-bit unsigned immediate"))
+}
+
+// a data word, any i64, `-` in front of hex too
+fn parse_word(s: &str) -> Result<i64, String> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let parsed = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).map(|v| v as i64),
+        None => digits.parse::<i64>(),
+    };
+    let v = parsed.map_err(|_| format!("expected a number, got `{s}`"))?;
+    Ok(if negative { v.wrapping_neg() } else { v })
+}
+
+// `.data 16, 1, 2, 3`: the address, then the words
+fn parse_segment(s: &str) -> Result<(usize, Vec<i64>), String> {
+    let mut fields = s.split(',').map(str::trim);
+    let start = fields.next().unwrap_or("");
+    let start = start.parse().map_err(|_| format!("expected a word address, got `{start}`"))?;
+    let words = fields.map(parse_word).collect::<Result<Vec<_>, _>>()?;
+    if words.is_empty() {
+        return Err("expected the words to put there after the address".to_string());
+    }
+    Ok((start, words))
+}
+
+// a label, or a plain pc
+fn parse_target(s: &str, labels: &HashMap<&str, usize>) -> Result<u16, String> {
+    if is_identifier(s) {
+        let pc = *labels.get(s).ok_or_else(|| format!("no label called `{s}`"))?;
+        u16::try_from(pc).map_err(|_| format!("label `{s}` is at pc {pc}, past 16-bit jump targets"))
+    } else {
+        parse_imm(s)
+    }
+}
+
+fn encode_line(body: &str, labels: &HashMap<&str, usize>) -> Result<u32, String> {
+    let (mnemonic, rest) = body.split_once(char::is_whitespace).unwrap_or((body, ""));
+    let lower = mnemonic.to_ascii_lowercase();
+    let &(_, op, shape) = MNEMONICS
+        .iter()
+        .find(|(name, _, _)| *name == lower)
+        .ok_or_else(|| format!("unknown instruction `{mnemonic}`"))?;
+    let operands: Vec<&str> =
+        if rest.trim().is_empty() { Vec::new() } else { rest.split(',').map(str::trim).collect() };
+    let want = match shape {
+        Shape::Nothing => 0,
+        Shape::Imm | Shape::Target | Shape::Reg => 1,
+        Shape::RegImm | Shape::RegReg | Shape::RegTarget => 2,
+        Shape::RegRegReg | Shape::RegRegOff => 3,
+    };
+    if operands.len() != want {
+        return Err(format!("`{lower}` takes {want} operand(s), got {}", operands.len()));
+    }
+    let ins = match shape {
+        Shape::Nothing => Instruction::new(op, 0, 0, 0),
+        Shape::Imm => Instruction::with_imm(op, 0, parse_imm(operands[0])?),
+        Shape::Target => Instruction::with_imm(op, 0, parse_target(operands[0], labels)?),
+        Shape::Reg => Instruction::new(op, parse_reg(operands[0])?, 0, 0),
+        Shape::RegImm => Instruction::with_imm(op, parse_reg(operands[0])?, parse_imm(operands[1])?),
+        Shape::RegReg => Instruction::new(op, parse_reg(operands[0])?, parse_reg(operands[1])?, 0),
+        Shape::RegRegReg => {
+            Instruction::new(op, parse_reg(operands[0])?, parse_reg(operands[1])?, parse_reg(operands[2])?)
+        }
+        Shape::RegRegOff => {
+            let offset = parse_imm(operands[2])?;
+            let offset = u8::try_from(offset).map_err(|_| format!("offset {offset} doesn't fit in 8 bits"))?;
+            Instruction::new(op, parse_reg(operands[0])?, parse_reg(operands[1])?, offset)
+        }
+        Shape::RegTarget => Instruction::with_imm(op, parse_reg(operands[0])?, parse_target(operands[1], labels)?),
+    };
+    Ok(ins.encode())
+}
+
+pub fn assemble(src: &str) -> Result<Program, AsmError> {
+    let lines = split_lines(src)?;
+
+    // pass 1: where every label points
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    let mut pc = 0;
+    for line in &lines {
+        if let Some(label) = line.label
+            && labels.insert(label, pc).is_some()
+        {
+            return Err(AsmError { line: line.number, message: format!("label `{label}` defined twice") });
+        }
+        if !line.body.is_empty() && !line.body.starts_with('.') {
+            pc += 1;
+        }
+    }
+
+    // pass 2: encode, directives on the side
+    let mut code = Vec::with_capacity(pc);
+    let mut numbers = Vec::with_capacity(pc);
+    let mut entry = None;
+    let mut memory = None;
+    let mut fall_off_end = None;
+    let mut segments = Vec::new();
+    for line in &lines {
+        let err = |message: String| AsmError { line: line.number, message };
+        if let Some(directive) = line.body.strip_prefix('.') {
+            match directive.split_once(char::is_whitespace) {
+                Some(("entry", target)) => {
+                    entry = Some((line.number, parse_target(target.trim(), &labels).map_err(err)? as usize))
+                }
+                Some(("memory", words)) => {
+                    let words = words.trim();
+                    let words = words.parse().map_err(|_| err(format!("expected a word count, got `{words}`")))?;
+                    memory = Some((line.number, words));
+                }
+                Some(("fall-off-end", policy)) => {
+                    let policy = policy.trim();
+                    let Some(&found) = FallOffEnd::ALL.iter().find(|p| p.name() == policy) else {
+                        let names: Vec<&str> = FallOffEnd::ALL.iter().map(|p| p.name()).collect();
+                        return Err(err(format!("expected one of {}, got `{policy}`", names.join(", "))));
+                    };
+                    fall_off_end = Some(found);
+                }
+                Some((kind @ ("data" | "rodata"), rest)) => {
+                    let (start, words) = parse_segment(rest.trim()).map_err(err)?;
+                    segments.push((line.number, start, words, kind == "rodata"));
+                }
+                _ => return Err(err(format!("unknown directive `.{directive}`"))),
+            }
+        } else if !line.body.is_empty() {
+            code.push(encode_line(line.body, &labels).map_err(err)?);
+            numbers.push(line.number);
+        }
+    }
+
+    let last_line = lines.last().map_or(1, |l| l.number);
+    let mut program = Program::new(code).map_err(|e| AsmError { line: last_line, message: e.to_string() })?;
+    if let Some((line, entry)) = entry {
+        program.set_entry(entry).map_err(|e| AsmError { line, message: e.to_string() })?;
+    }
+    if let Some((line, words)) = memory {
+        program.set_memory_words(words).map_err(|e| AsmError { line, message: e.to_string() })?;
+    }
+    if let Some(policy) = fall_off_end {
+        program.set_fall_off_end(policy);
+    }
+    // after .memory wherever that was, segments have to fit the final size
+    for (line, start, words, read_only) in segments {
+        program.add_segment(start, words, read_only).map_err(|e| AsmError { line, message: e.to_string() })?;
+    }
+    for (pc, line) in numbers.into_iter().enumerate() {
+        program.set_line(pc, line);
+    }
+    for (label, pc) in labels {
+        // a label after the last instruction has nothing to name
+        if pc < program.code().len() {
+            program.set_name(pc, label).expect("label is inside the code");
+        }
+    }
+    Ok(program)
+}
+
+// source that assembles back into `program`. jump targets with a name are written as
+// that label, the rest as the pc. superinstructions have no syntax, so a fused program
+// can't be written out
+pub fn disassemble(program: &Program) -> Result<String, String> {
+    let label = |pc: usize| program.name_at(pc).filter(|name| is_identifier(name));
+    let target = |pc: u16| label(pc as usize).map_or(pc.to_string(), str::to_string);
+    let mut out = String::new();
+    if program.entry() != 0 {
+        writeln!(out, ".entry {}", label(program.entry()).map_or(program.entry().to_string(), str::to_string)).unwrap();
+    }
+    if program.memory_words() != DEFAULT_MEMORY_WORDS {
+        writeln!(out, ".memory {}", program.memory_words()).unwrap();
+    }
+    if program.fall_off_end() != FallOffEnd::Reject {
+        writeln!(out, ".fall-off-end {}", program.fall_off_end().name()).unwrap();
+    }
+    for segment in program.segments() {
+        let words: Vec<String> = segment.words.iter().map(i64::to_string).collect();
+        let kind = if segment.read_only { "rodata" } else { "data" };
+        writeln!(out, ".{kind} {}, {}", segment.start, words.join(", ")).unwrap();
+    }
+
+    for (pc, &word) in program.code().iter().enumerate() {
+        let ins = Instruction::decode(word).expect("a Program only holds known opcodes");
+        let &(mnemonic, _, shape) = MNEMONICS
+            .iter()
+            .find(|(_, op, _)| *op == ins.op)
+            .ok_or_else(|| format!("pc {pc}: {:?} is a superinstruction, there's no syntax for it", ins.op))?;
+        let imm = (ins.a as u16) | ((ins.b as u16) << 8);
+        let (d, a, b) = (format!("r{}", ins.dst), format!("r{}", ins.a), format!("r{}", ins.b));
+        let operands = match shape {
+            Shape::Nothing => String::new(),
+            Shape::Imm => imm.to_string(),
+            Shape::Target => target(imm),
+            Shape::Reg => d,
+            Shape::RegImm => format!("{d}, {imm}"),
+            Shape::RegReg => format!("{d}, {a}"),
+            Shape::RegRegReg => format!("{d}, {a}, {b}"),
+            Shape::RegRegOff => format!("{d}, {a}, {}", ins.b),
+            Shape::RegTarget => format!("{d}, {}", target(imm)),
+        };
+        if let Some(name) = label(pc) {
+            writeln!(out, "{name}:").unwrap();
+        }
+        let line = format!("        {mnemonic:<7} {operands}");
+        match semantics::comment(ins) {
+            Some(comment) => writeln!(out, "{line:<34}; {comment}").unwrap(),
+            None => writeln!(out, "{}", line.trim_end()).unwrap(),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernels;
+
+    #[test]
+    fn disassembles_back_to_the_same_program() {
+        let sources = [
+            include_str!("../programs/sum_poly.vmasm"),
+            include_str!("../programs/fib.vmasm"),
+            include_str!("../programs/sieve.vmasm"),
+            include_str!("../programs/collatz.vmasm"),
+            ".entry start\n.memory 64\n.rodata 8, -1, 0x7f\n\
+             start: load r1, r0, 8\nclz r2, r1\ninc r2\ntrap 0x1f\nhalt r2",
+            ".fall-off-end halt\nloadi r0, 7\ninc r0",
+        ];
+        for src in sources {
+            let program = assemble(src).unwrap();
+            let listing = disassemble(&program).unwrap();
+            let again = assemble(&listing).unwrap_or_else(|e| panic!("{e} in\n{listing}"));
+            assert_eq!(again.code(), program.code(), "{listing}");
+            assert_eq!(again.entry(), program.entry());
+            assert_eq!(again.names(), program.names());
+            assert_eq!(again.memory_words(), program.memory_words());
+            assert_eq!(again.segments(), program.segments());
+            assert_eq!(again.fall_off_end(), program.fall_off_end());
+        }
+        let listing = disassemble(&assemble(sources[0]).unwrap()).unwrap();
+        assert!(listing.contains("mul     r4, r3, r3        ; r4 = r3 * r3"), "{listing}");
+        assert!(disassemble(&assemble(sources[0]).unwrap().fused()).unwrap_err().contains("superinstruction"));
+    }
+
+    #[test]
+    fn assembles_sum_poly() {
+        let program = assemble(include_str!("../programs/sum_poly.vmasm")).unwrap();
+        assert_eq!(program.code(), kernels::sum_poly());
+        assert_eq!(program.name_at(2), Some("loop"));
+    }
+
+    #[test]
+    fn assembles_fib() {
+        let program = assemble(include_str!("../programs/fib.vmasm")).unwrap();
+        assert_eq!(program.code(), kernels::fib());
+        assert_eq!(crate::Vm::new(program.verify().unwrap()).run(&[20]).unwrap(), 6765);
+    }
+
+    #[test]
+    fn assembles_sieve() {
+        let program = assemble(include_str!("../programs/sieve.vmasm")).unwrap();
+        assert_eq!(program.code(), kernels::sieve());
+        assert_eq!(program.memory_words(), 10_000);
+        assert_eq!(crate::Vm::new(program.verify().unwrap()).run(&[10_000]).unwrap(), 1229);
+    }
+
+    #[test]
+    fn assembles_collatz() {
+        let program = assemble(include_str!("../programs/collatz.vmasm")).unwrap();
+        assert_eq!(program.code(), kernels::collatz());
+        // 0 + 1 + 7 + 2 + 5 + 8 + 16 steps
+        assert_eq!(crate::Vm::new(program.verify().unwrap()).run(&[7]).unwrap(), 39);
+    }
+
+    #[test]
+    fn reports_errors_with_lines() {
+        let cases = [
+            ("halt r16", 1, "there's no register r16"),
+            ("\nloadi r1, 70000", 2, "doesn't fit"),
+            ("jmpnz r0, nowhere", 1, "no label called `nowhere`"),
+            ("a:\na: halt r0", 2, "defined twice"),
+            ("add r1, r2", 1, "takes 3 operand(s)"),
+            ("ret r1", 1, "takes 0 operand(s)"),
+            ("load r1, r2, 256", 1, "doesn't fit in 8 bits"),
+            ("halt r0\n.memory lots", 2, "expected a word count"),
+            ("halt r0\n.memory 99999999999", 2, "the most is"),
+            ("halt r0\n.data 4", 2, "expected the words"),
+            ("halt r0\n.rodata x, 1", 2, "expected a word address"),
+            ("halt r0\n.data 0, 1, two", 2, "expected a number"),
+            ("halt r0\n.memory 4\n.data 3, 1, 2", 3, "don't fit in 4 words"),
+            ("halt r0\n.fall-off-end maybe", 2, "expected one of reject, halt, trap"),
+            ("halt r0\n.data 0, 1, 2\n.rodata 1, 5", 3, "overlaps the data at 0"),
+            ("frob r1", 1, "unknown instruction"),
+            ("; nothing\n", 1, "no instructions"),
+        ];
+        for (src, line, needle) in cases {
+            let err = assemble(src).unwrap_err();
+            assert_eq!(err.line, line, "{src:?}: {err}");
+            assert!(err.message.contains(needle), "{src:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn register_aliases() {
+        let aliased = assemble("add ret, arg0, arg7\nmul acc, tmp0, tmp5\nhalt ret").unwrap();
+        let plain = assemble("add r0, r0, r7\nmul r8, r9, r14\nhalt r0").unwrap();
+        assert_eq!(aliased.code(), plain.code());
+        for bad in ["arg8", "tmp6", "r16x", "accumulator"] {
+            let err = assemble(&format!("halt {bad}")).unwrap_err();
+            assert!(err.message.contains("expected a register"), "{bad}: {err}");
+        }
+    }
+
+    #[test]
+    fn named_entry_points() {
+        let src = "
+                    halt  r0
+            square: mul   ret, arg0, arg0
+                    ret
+            add3:   add   ret, arg0, arg1
+                    add   ret, ret, arg2
+                    ret
+            twice:  push  arg0
+                    call  square
+                    pop   tmp0
+                    add   ret, ret, ret
+                    halt  ret
+        ";
+        let program = assemble(src).unwrap().verify().unwrap();
+        for &kind in crate::DispatchKind::ALL {
+            let mut vm = crate::Vm::with_dispatch(program.clone(), kind).unwrap();
+            assert_eq!(vm.call("square", &[7]), Ok(49), "{kind:?}");
+            assert_eq!(vm.call("add3", &[1, 2, 3]), Ok(6), "{kind:?}");
+            // a name that HALTs instead of returning works too
+            assert_eq!(vm.call("twice", &[3]), Ok(18), "{kind:?}");
+            assert_eq!(vm.call("square", &[-4]), Ok(16), "{kind:?}");
+        }
+        let mut vm = crate::Vm::new(program);
+        assert_eq!(vm.call("cube", &[2]), Err(crate::VmError::NoSuchEntry("cube".into())));
+        let err = crate::VmError::TooManyArgs { given: crate::ARG_REGS + 1, max: crate::ARG_REGS };
+        assert_eq!(vm.call("add3", &[0; crate::ARG_REGS + 1]), Err(err));
+    }
+
+    #[test]
+    fn data_segments() {
+        // sum of the constant pool at 8..12, then overwrite it
+        let src = "
+                    loadi r1, 8
+                    loadi r2, 4
+                    loadi r0, 0
+            next:   load  r3, r1, 0
+                    add   r0, r0, r3
+                    inc   r1
+                    dec   r2
+                    jmpnz r2, next
+                    jmpnz arg5, write
+                    halt  r0
+            write:  loadi r4, 0
+                    memset r4, r0, arg5
+                    halt  r0
+            .rodata 8, 1, 2, -0x3, 40
+            .data   0, 7
+        ";
+        let program = assemble(src).unwrap();
+        assert_eq!(program.segments().len(), 2);
+        let program = program.verify().unwrap();
+        for &kind in crate::DispatchKind::ALL {
+            let vm = crate::Vm::with_dispatch(program.clone(), kind).unwrap();
+            assert_eq!(vm.run(&[0, 0, 0, 0, 0, 0]), Ok(40), "{kind:?}");
+            // memset over the writable word is fine, into the read-only ones isn't
+            assert_eq!(vm.run(&[0, 0, 0, 0, 0, 1]), Ok(40), "{kind:?}");
+            assert_eq!(vm.run(&[0, 0, 0, 0, 0, 9]), Ok(-1), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn entry_directive() {
+        let program = assemble("halt r0\nstart: inc r0\n halt r0\n.entry start").unwrap();
+        assert_eq!(program.entry(), 1);
+        assert_eq!(crate::Vm::new(program.verify().unwrap()).run(&[41]).unwrap(), 42);
+    }
+}
@@ -0,0 +1,57 @@
+// Pick the variant per program: `--auto-tune`
+//
+// the default benchmark runs every variant for the full budget and leaves the choice
+// to whoever reads the table. an adaptive interpreter can't do that, it has one
+// program and has to commit to a loop early. this does what it would: Vm::tune runs
+// the workload through every variant the library offers for a short prefix
+// (PREFIX_BUDGET each), keeps the fastest, and only that one gets the full run. the
+// prefix numbers are noisy on purpose, they're what the decision had to go on, and the
+// full run says whether it was a good one
+//
+// variants the library doesn't offer as a DispatchKind (external tools, plugins) can't
+// be tuned over and are skipped with a note
+
+use std::time::{Duration, Instant};
+
+use rust_goto::{DispatchKind, Variant, Vm};
+
+use crate::{BlackBox, Budget, Workload, measure_variant};
+
+// per variant, short next to the 2 s the full run gets
+const PREFIX_BUDGET: Duration = Duration::from_millis(20);
+
+pub fn run(workload: &Workload, variants: &[&Variant], budget: Budget) -> Result<(), String> {
+    let Workload { description, program, args, .. } = workload;
+    println!("auto-tune: {}ms prefix per variant, then the full run on the fastest", PREFIX_BUDGET.as_millis());
+    println!("Program: {description}\n");
+    let mut kinds = Vec::new();
+    for v in variants {
+        match DispatchKind::by_name(v.name) {
+            Some(kind) => kinds.push(kind),
+            None => println!("{:>24}: skipped, not a DispatchKind", v.label),
+        }
+    }
+    let mut vm = Vm::new(program.clone());
+    let start = Instant::now();
+    let tuning = vm.tune(&kinds, args, PREFIX_BUDGET).map_err(|e| e.to_string())?;
+    let spent = start.elapsed();
+    for &kind in &kinds {
+        let label = kind.variant().label;
+        match tuning.samples.iter().find(|(k, _)| *k == kind) {
+            Some((_, ns)) => {
+                let mark = if kind == tuning.chosen { "  <- chosen" } else { "" };
+                println!("{label:>24}: {ns:8.1} ns/iter (prefix){mark}");
+            }
+            None => println!("{label:>24}: skipped, can't take the program"),
+        }
+    }
+    println!("\ntuning took {:.1} ms, chose {}\n", spent.as_secs_f64() * 1e3, tuning.chosen.name());
+
+    let chosen = tuning.chosen.variant();
+    let m = measure_variant(chosen, program, args, budget, BlackBox::All).map_err(|e| e.to_string())?;
+    println!("{}", m.line(chosen.label));
+    if m.result != tuning.result {
+        return Err(format!("{} returned {} on the full run, {} while tuning", chosen.name, m.result, tuning.result));
+    }
+    Ok(())
+}
@@ -0,0 +1,301 @@
+// Chunked translation: basic blocks decoded when execution first gets to them
+//
+// run_predecoded pays for the whole program before the first instruction runs: every
+// word gets decoded, and the translation is as big as the program. for a program of
+// tens of thousands of instructions whose hot loop touches a few hundred, most of that
+// is spent on code that runs once or never. a baseline dynamic translator does it the
+// other way around: when execution reaches a pc with nothing translated there, it
+// decodes the basic block starting at that pc (up to and including the next
+// instruction that can change pc) and keeps it for the next time, up to `capacity`
+// decoded instructions. past that, the block entered least recently goes first.
+//
+// blocks are looked up by their first pc once per block entered, that and keeping the
+// LRU order are what this costs over run_predecoded in the steady state. a branch back
+// into the block it's in (a loop that's a single block) stays in the block without a
+// lookup. a jump into the middle of a block starts a block of its own, overlapping
+// the first one, same as in any translator that doesn't split blocks
+//
+// `compare-blocks` runs it against whole-program predecoding on the giant:F kernels
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use crate::memory::Memory;
+use crate::semantics::{self, apply};
+use crate::{DecodedInstr, Stack, VerifiedProgram, address, fused_binops, handle, has_target, imm16, seed_regs};
+use crate::{
+    OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL, OP_MULSUB,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RESOLVE_CALL, OP_RET, OP_STORE, OP_SUB, OP_TRAP, OP_WAITEVENT, is_opcode,
+};
+
+// no slot, the ends of the LRU list
+const NIL: usize = usize::MAX;
+
+// pcs are small integers, SipHash is wasted on them. a multiplicative hash spreads
+// them well enough for the table
+#[derive(Default)]
+struct PcHasher(u64);
+
+impl Hasher for PcHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 << 8 | b as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        }
+    }
+
+    fn write_usize(&mut self, pc: usize) {
+        self.0 = (pc as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+}
+
+// one translated block, linked into the LRU list by slot index
+struct Slot {
+    start: usize,
+    code: Vec<DecodedInstr>,
+    // towards the most and the least recently entered block
+    newer: usize,
+    older: usize,
+}
+
+// what the cache did, since it was created
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockStats {
+    // block entries that needed a lookup
+    pub entered: u64,
+    // blocks decoded, a block evicted and needed again counts each time
+    pub translated: u64,
+    // instructions decoded over all of those
+    pub decoded: u64,
+    pub evicted: u64,
+    // the most decoded instructions held at once
+    pub peak_resident: usize,
+}
+
+struct BlockCache {
+    capacity: usize,
+    index: HashMap<usize, usize, BuildHasherDefault<PcHasher>>,
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    newest: usize,
+    oldest: usize,
+    resident: usize,
+    stats: BlockStats,
+}
+
+impl BlockCache {
+    fn new(capacity: usize) -> BlockCache {
+        BlockCache {
+            capacity,
+            index: HashMap::default(),
+            slots: Vec::new(),
+            free: Vec::new(),
+            newest: NIL,
+            oldest: NIL,
+            resident: 0,
+            stats: BlockStats::default(),
+        }
+    }
+
+    // the slot of the block starting at `pc`, translated now if it isn't there, and
+    // made the most recently entered one
+    fn enter(&mut self, code: &[u32], pc: usize) -> usize {
+        self.stats.entered += 1;
+        let slot = match self.index.get(&pc) {
+            Some(&slot) => {
+                self.unlink(slot);
+                slot
+            }
+            None => self.translate(code, pc),
+        };
+        self.push_newest(slot);
+        slot
+    }
+
+    fn translate(&mut self, code: &[u32], start: usize) -> usize {
+        let block = decode_block(code, start);
+        // a block bigger than the whole capacity still gets in, alone
+        while self.resident + block.len() > self.capacity && self.oldest != NIL {
+            self.evict(self.oldest);
+        }
+        self.stats.translated += 1;
+        self.stats.decoded += block.len() as u64;
+        self.resident += block.len();
+        self.stats.peak_resident = self.stats.peak_resident.max(self.resident);
+
+        let slot = Slot { start, code: block, newer: NIL, older: NIL };
+        let at = match self.free.pop() {
+            Some(at) => {
+                self.slots[at] = slot;
+                at
+            }
+            None => {
+                self.slots.push(slot);
+                self.slots.len() - 1
+            }
+        };
+        self.index.insert(start, at);
+        at
+    }
+
+    fn evict(&mut self, slot: usize) {
+        self.unlink(slot);
+        let evicted = std::mem::take(&mut self.slots[slot].code);
+        self.resident -= evicted.len();
+        self.index.remove(&self.slots[slot].start);
+        self.free.push(slot);
+        self.stats.evicted += 1;
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Slot { newer, older, .. } = self.slots[slot];
+        match newer {
+            NIL => self.newest = older,
+            newer => self.slots[newer].older = older,
+        }
+        match older {
+            NIL => self.oldest = newer,
+            older => self.slots[older].newer = newer,
+        }
+    }
+
+    fn push_newest(&mut self, slot: usize) {
+        self.slots[slot].newer = NIL;
+        self.slots[slot].older = self.newest;
+        match self.newest {
+            NIL => self.oldest = slot,
+            newest => self.slots[newest].newer = slot,
+        }
+        self.newest = slot;
+    }
+}
+
+// the block starting at `start`: everything up to and including the first instruction
+// that can change pc. a superinstruction's second half comes along, it's a plain
+// instruction at its own pc and the handler steps over it. verification makes the last
+// instruction a HALT, a RET or a JMP, so there's always an end
+fn decode_block(code: &[u32], start: usize) -> Vec<DecodedInstr> {
+    let mut block = Vec::new();
+    let mut at = start;
+    while let Some(&word) = code.get(at) {
+        let op = word as u8;
+        block.push(DecodedInstr::of(word));
+        at += 1;
+        if has_target(op) || matches!(op, OP_HALT | OP_RET | OP_WAITEVENT | OP_TRAP | OP_RESOLVE_CALL) || !is_opcode(op) {
+            break;
+        }
+        if matches!(op, OP_ADDADD | OP_MULSUB)
+            && let Some(&second) = code.get(at)
+        {
+            block.push(DecodedInstr::of(second));
+            at += 1;
+        }
+    }
+    block
+}
+
+// a verified program with the cache its blocks get translated into. the cache lives
+// as long as this does, so later runs start with whatever the earlier ones left in it
+pub struct LazyBlocks {
+    program: VerifiedProgram,
+    cache: RefCell<BlockCache>,
+}
+
+impl LazyBlocks {
+    // `capacity` is in decoded instructions, usize::MAX for no limit
+    pub fn new(program: VerifiedProgram, capacity: usize) -> LazyBlocks {
+        LazyBlocks { program, cache: RefCell::new(BlockCache::new(capacity)) }
+    }
+
+    pub fn stats(&self) -> BlockStats {
+        self.cache.borrow().stats
+    }
+
+    // decoded instructions held right now
+    pub fn resident(&self) -> usize {
+        self.cache.borrow().resident
+    }
+}
+
+#[inline(never)]
+pub fn run_blocks(program: &LazyBlocks, args: &[i64]) -> i64 {
+    let code = program.program.code();
+    let mut cache = program.cache.borrow_mut();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem: Memory = program.program.memory();
+
+    loop {
+        let slot = cache.enter(code, pc);
+        let Slot { start, code: ref block, .. } = cache.slots[slot];
+        while let Some(&DecodedInstr { op, dst, a, b }) = block.get(pc.wrapping_sub(start)) {
+            pc += 1;
+            handle!(regs, stack, mem, pc, op, dst as usize, a, b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{kernels, run_central, verify};
+
+    // a block bigger than the capacity gets in anyway, and none of these kernels has one
+    // longer than a giant:F function
+    const LONGEST_BLOCK: usize = kernels::GIANT_BODY;
+
+    #[test]
+    fn blocks_match_central_at_any_capacity() {
+        for name in ["sum-poly", "fib", "sieve", "collatz", "fsm:8", "giant:40"] {
+            let (code, args) = kernels::kernel(name, 50).unwrap();
+            let program = verify(&code).unwrap();
+            let fused = program.program().fused().verify().unwrap();
+            let expected = run_central(&program, &args);
+            for capacity in [1, 16, 200, usize::MAX] {
+                for program in [&program, &fused] {
+                    let blocks = LazyBlocks::new(program.clone(), capacity);
+                    for run in 0..2 {
+                        assert_eq!(run_blocks(&blocks, &args), expected, "{name}, capacity {capacity}, run {run}");
+                    }
+                    if capacity != usize::MAX {
+                        assert!(blocks.resident() <= capacity.max(LONGEST_BLOCK), "{name}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn the_hot_set_stays_and_the_rest_gets_evicted() {
+        let (code, args) = kernels::kernel("giant:100", 10).unwrap();
+        let program = verify(&code).unwrap();
+        let expected = run_central(&program, &args);
+        let hot = kernels::GIANT_HOT as usize * kernels::GIANT_BODY;
+        let main = 100 + kernels::GIANT_HOT as usize + 4;
+
+        // room for everything: every block gets decoded exactly once
+        let all = LazyBlocks::new(program.clone(), usize::MAX);
+        assert_eq!(run_blocks(&all, &args), expected);
+        let first = all.stats();
+        assert_eq!(run_blocks(&all, &args), expected);
+        assert_eq!(all.stats().translated, first.translated);
+        assert_eq!(all.stats().evicted, 0);
+
+        // room for main and the hot functions only: the first pass over every function
+        // pushes the cold ones through and out, after that the hot loop runs from the
+        // cache, so ten more trips round it decode nothing more
+        let translated = |n: i64| {
+            let lru = LazyBlocks::new(program.clone(), main + 2 * hot);
+            assert_eq!(run_blocks(&lru, &[n]), run_central(&program, &[n]));
+            assert!(lru.stats().evicted > 0 && lru.resident() <= main + 2 * hot);
+            lru.stats().translated
+        };
+        assert_eq!(translated(10), translated(20));
+    }
+}
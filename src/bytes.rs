@@ -0,0 +1,361 @@
+// Same VM, but the bytecode is a `&[u8]` stream instead of `u32` words
+//
+// CPython, the JVM and most real VMs use byte streams: one opcode byte followed by
+// however many operand bytes that opcode needs. That changes the fetch (narrow loads,
+// variable length, pc advance depends on the opcode) which could interact with the
+// dispatch strategy, so we run the same two flavours over it
+//
+// encoding, pc is a byte offset:
+//
+//   HALT  dst             2 bytes
+//   LOADI dst lo hi       4 bytes
+//   ADD/SUB/MUL/DIV/MOD
+//         dst a b         4 bytes
+//   INC/DEC dst           2 bytes
+//   JMPNZ dst lo hi       4 bytes   (target is a byte offset)
+//   MOV   dst a           3 bytes
+//   CALL  lo hi           3 bytes   (target is a byte offset)
+//   RET                   1 byte
+//   PUSH/POP dst          2 bytes
+//   LOAD/STORE dst a off  4 bytes
+//   MEMCPY/MEMSET dst a b 4 bytes
+//   CMP   dst a b         4 bytes
+//   JMP   lo hi           3 bytes   (target is a byte offset)
+//   JEQ/JNE/JLT/JGE dst lo hi
+//                         4 bytes   (target is a byte offset)
+//   ADDADD/MULSUB dst a b 4 bytes   (registers packed as nibbles, see fuse.rs)
+//   DECJNZ dst lo hi      4 bytes   (target is a byte offset)
+//   CLZ/CTZ/POPCNT dst a  3 bytes
+//
+// a superinstruction skips its second half (an ADD, SUB or JMPNZ, 4 bytes as well)
+
+use std::fmt;
+
+use crate::{
+    OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL, OP_MULSUB,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RET, OP_STORE, OP_SUB, Stack, VerifiedProgram, address, branch_taken,
+    fused_binops, imm16, seed_regs, semantics,
+};
+use crate::memory::{Memory, MemoryImage};
+
+fn encoded_len(op: u8) -> usize {
+    match op {
+        OP_RET => 1,
+        OP_HALT | OP_INC | OP_DEC | OP_PUSH | OP_POP => 2,
+        OP_MOV | OP_CLZ | OP_CTZ | OP_POPCNT | OP_CALL | OP_JMP => 3,
+        _ => 4,
+    }
+}
+
+// only built by translating a VerifiedProgram: every jump lands on an opcode byte and
+// the last instruction is a HALT, a RET or a JMP, so the byte variants can read unchecked
+pub struct VerifiedBytes {
+    bytes: Vec<u8>,
+    memory: MemoryImage,
+}
+
+impl VerifiedBytes {
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn memory(&self) -> Memory {
+        self.memory.fresh()
+    }
+}
+
+// the word encoding addresses 65536 instructions, the byte one only 65536 bytes, so
+// a big enough program has no byte translation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TooLarge {
+    pub bytes: usize,
+}
+
+impl fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes of bytecode, jump targets only reach {}", self.bytes, u16::MAX as usize + 1)
+    }
+}
+
+impl std::error::Error for TooLarge {}
+
+// translates a word program, jump targets get remapped from instruction indices to
+// byte offsets
+pub fn to_bytes(program: &VerifiedProgram) -> Result<VerifiedBytes, TooLarge> {
+    let code = program.code();
+    let mut offsets = Vec::with_capacity(code.len() + 1);
+    let mut at = 0;
+    for &instr in code {
+        offsets.push(at);
+        at += encoded_len((instr & 0xFF) as u8);
+    }
+    offsets.push(at);
+    if at > u16::MAX as usize + 1 {
+        return Err(TooLarge { bytes: at });
+    }
+
+    let mut out = Vec::with_capacity(at);
+    for &instr in code {
+        let op = (instr & 0xFF) as u8;
+        let dst = ((instr >> 8) & 0xFF) as u8;
+        let a = ((instr >> 16) & 0xFF) as u8;
+        let b = ((instr >> 24) & 0xFF) as u8;
+        match op {
+            OP_RET => out.push(op),
+            OP_HALT | OP_INC | OP_DEC | OP_PUSH | OP_POP => out.extend([op, dst]),
+            OP_MOV | OP_CLZ | OP_CTZ | OP_POPCNT => out.extend([op, dst, a]),
+            OP_JMPNZ | OP_JEQ | OP_JNE | OP_JLT | OP_JGE | OP_DECJNZ => {
+                let target = offsets[imm16(a, b) as usize] as u16;
+                out.extend([op, dst, target as u8, (target >> 8) as u8]);
+            }
+            OP_CALL | OP_JMP => {
+                let target = offsets[imm16(a, b) as usize] as u16;
+                out.extend([op, target as u8, (target >> 8) as u8]);
+            }
+            _ => out.extend([op, dst, a, b]),
+        }
+    }
+    Ok(VerifiedBytes { bytes: out, memory: program.memory_image().clone() })
+}
+
+#[cfg(not(feature = "safe-only"))]
+macro_rules! byte_at {
+    ($code:expr, $i:expr) => {
+        *unsafe { $code.get_unchecked($i) }
+    };
+}
+
+#[cfg(feature = "safe-only")]
+macro_rules! byte_at {
+    ($code:expr, $i:expr) => {
+        $code[$i]
+    };
+}
+
+// $pc points just past the opcode byte. each arm reads its own operands, moves $pc to
+// the next opcode, then runs $tail. central dispatch passes an empty tail, the
+// threaded version passes a whole inline dispatch of the next instruction
+macro_rules! handle_bytes {
+    ($code:expr, $regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $tail:block) => {
+        match $op {
+            OP_HALT => return $regs[byte_at!($code, $pc) as usize],
+            OP_LOADI => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = imm16(byte_at!($code, $pc + 1), byte_at!($code, $pc + 2));
+                $pc += 3;
+                $tail
+            }
+            OP_ADD => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::add($regs[a], $regs[b]);
+                $tail
+            }
+            OP_SUB => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::sub($regs[a], $regs[b]);
+                $tail
+            }
+            OP_MUL => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::mul($regs[a], $regs[b]);
+                $tail
+            }
+            OP_DIV => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::div($regs[a], $regs[b]);
+                $tail
+            }
+            OP_MOD => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::rem($regs[a], $regs[b]);
+                $tail
+            }
+            OP_INC => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::inc($regs[dst]);
+                $pc += 1;
+                $tail
+            }
+            OP_DEC => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::dec($regs[dst]);
+                $pc += 1;
+                $tail
+            }
+            OP_JMPNZ => {
+                let dst = byte_at!($code, $pc) as usize;
+                if $regs[dst] != 0 {
+                    $pc = imm16(byte_at!($code, $pc + 1), byte_at!($code, $pc + 2)) as usize;
+                } else {
+                    $pc += 3;
+                }
+                $tail
+            }
+            OP_JEQ | OP_JNE | OP_JLT | OP_JGE => {
+                let dst = byte_at!($code, $pc) as usize;
+                if branch_taken($op, $regs[dst]) {
+                    $pc = imm16(byte_at!($code, $pc + 1), byte_at!($code, $pc + 2)) as usize;
+                } else {
+                    $pc += 3;
+                }
+                $tail
+            }
+            OP_JMP => {
+                $pc = imm16(byte_at!($code, $pc), byte_at!($code, $pc + 1)) as usize;
+                $tail
+            }
+            OP_MOV => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::mov($regs[byte_at!($code, $pc + 1) as usize]);
+                $pc += 2;
+                $tail
+            }
+            OP_CLZ => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::clz($regs[byte_at!($code, $pc + 1) as usize]);
+                $pc += 2;
+                $tail
+            }
+            OP_CTZ => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::ctz($regs[byte_at!($code, $pc + 1) as usize]);
+                $pc += 2;
+                $tail
+            }
+            OP_POPCNT => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::popcnt($regs[byte_at!($code, $pc + 1) as usize]);
+                $pc += 2;
+                $tail
+            }
+            OP_CMP => {
+                let (dst, a, b) = operands3!($code, $pc);
+                $regs[dst] = semantics::cmp($regs[a], $regs[b]);
+                $tail
+            }
+            OP_CALL => {
+                let target = imm16(byte_at!($code, $pc), byte_at!($code, $pc + 1)) as usize;
+                if !$stack.call($pc + 2) {
+                    return -1;
+                }
+                $pc = target;
+                $tail
+            }
+            OP_RET => {
+                let Some(ret) = $stack.ret() else { return -1 };
+                $pc = ret;
+                $tail
+            }
+            OP_PUSH => {
+                if !$stack.push($regs[byte_at!($code, $pc) as usize]) {
+                    return -1;
+                }
+                $pc += 1;
+                $tail
+            }
+            OP_POP => {
+                let Some(v) = $stack.pop() else { return -1 };
+                $regs[byte_at!($code, $pc) as usize] = v;
+                $pc += 1;
+                $tail
+            }
+            OP_LOAD => {
+                let (dst, a, off) = operands3!($code, $pc);
+                let Some(&v) = $mem.get(address($regs[a], off as u8)) else { return -1 };
+                $regs[dst] = v;
+                $tail
+            }
+            OP_STORE => {
+                let (src, a, off) = operands3!($code, $pc);
+                let Ok(slot) = $mem.get_mut(address($regs[a], off as u8)) else { return -1 };
+                *slot = $regs[src];
+                $tail
+            }
+            OP_MEMCPY => {
+                let (dst, a, b) = operands3!($code, $pc);
+                if $mem.copy($regs[dst], $regs[a], $regs[b]).is_err() {
+                    return -1;
+                }
+                $tail
+            }
+            OP_MEMSET => {
+                let (dst, a, b) = operands3!($code, $pc);
+                if $mem.fill($regs[dst], $regs[a], $regs[b]).is_err() {
+                    return -1;
+                }
+                $tail
+            }
+            OP_ADDADD => {
+                let (dst, a, b) = operands3!($code, $pc);
+                fused_binops(&mut $regs, dst as u8, a as u8, b as u8, semantics::add, semantics::add);
+                $pc += 4;
+                $tail
+            }
+            OP_MULSUB => {
+                let (dst, a, b) = operands3!($code, $pc);
+                fused_binops(&mut $regs, dst as u8, a as u8, b as u8, semantics::mul, semantics::sub);
+                $pc += 4;
+                $tail
+            }
+            OP_DECJNZ => {
+                let dst = byte_at!($code, $pc) as usize;
+                $regs[dst] = semantics::dec($regs[dst]);
+                if $regs[dst] != 0 {
+                    $pc = imm16(byte_at!($code, $pc + 1), byte_at!($code, $pc + 2)) as usize;
+                } else {
+                    $pc += 3 + 4;
+                }
+                $tail
+            }
+            _ => return -1,
+        }
+    };
+}
+
+macro_rules! operands3 {
+    ($code:expr, $pc:expr) => {{
+        let ops = (
+            byte_at!($code, $pc) as usize,
+            byte_at!($code, $pc + 1) as usize,
+            byte_at!($code, $pc + 2) as usize,
+        );
+        $pc += 3;
+        ops
+    }};
+}
+
+#[inline(never)]
+pub fn run_bytes_central(program: &VerifiedBytes, args: &[i64]) -> i64 {
+    let code = program.bytes();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let op = byte_at!(code, pc);
+        pc += 1;
+        handle_bytes!(code, regs, stack, mem, pc, op, {});
+    }
+}
+
+// 2 levels, same idea as run_threaded: every handler ends with its own copy of the dispatch
+#[inline(never)]
+pub fn run_bytes_threaded(program: &VerifiedBytes, args: &[i64]) -> i64 {
+    let code = program.bytes();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let op = byte_at!(code, pc);
+        pc += 1;
+        handle_bytes!(code, regs, stack, mem, pc, op, {
+            let op2 = byte_at!(code, pc);
+            pc += 1;
+            handle_bytes!(code, regs, stack, mem, pc, op2, {});
+        });
+    }
+}
@@ -0,0 +1,32 @@
+// FFI to compare/goto.c, the same ISA written in C with `goto *table[op]`, which is
+// the thing this whole project is trying to imitate. built by build.rs with
+// `--features c-reference`
+
+use crate::VerifiedProgram;
+
+unsafe extern "C" {
+    fn rg_run_goto(
+        code: *const u32,
+        args: *const i64,
+        nargs: usize,
+        mem: *mut i64,
+        mem_words: usize,
+        read_only: *const u64,
+    ) -> i64;
+}
+
+#[inline(never)]
+pub fn run_c_goto(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    assert!(args.len() <= crate::NREGS, "{} arguments but only {} registers", args.len(), crate::NREGS);
+    // the C side doesn't check anything either, it relies on verification the same
+    // way the unchecked Rust variants do
+    // memory comes from here, zeroed like the Rust variants get it, and the C side
+    // bounds-checks every access against mem_words, and every write against the
+    // protection bitmap, which is all of memory or NULL
+    let code = program.code();
+    let mut mem = program.memory();
+    let bits = mem.read_only_bits();
+    let read_only = if bits.is_empty() { std::ptr::null() } else { bits.as_ptr() };
+    let words = mem.words_mut();
+    unsafe { rg_run_goto(code.as_ptr(), args.as_ptr(), args.len(), words.as_mut_ptr(), words.len(), read_only) }
+}
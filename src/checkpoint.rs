@@ -0,0 +1,258 @@
+// Checkpoints for very long single runs
+//
+// a generated program that runs for minutes on a shared machine can get preempted
+// halfway, and then the whole run is gone. this runs it in slices of N instructions
+// through central dispatch and hands back the full machine state in between, which the
+// driver writes to a file (`--checkpoint-every N`) and can pick up again (`--resume`)
+//
+// the state is small: pc, the registers, the call and value stacks, the non-zero
+// words of memory, and how far along the run is. it's tied to the program it came from by a hash of the lowered code,
+// resuming with anything else is refused, and the pc and every return address are
+// checked against the code before anything gets executed
+
+use std::fmt;
+
+use crate::memory::Memory;
+use crate::{
+    NREGS, STACK_DEPTH, Stack, VerifiedProgram, address, exec_one, fetch, fused_binops, handle, imm16, seed_regs,
+    semantics,
+};
+use crate::semantics::apply;
+use crate::{
+    OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL, OP_MULSUB,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RESOLVE_CALL, OP_RET, OP_STORE, OP_SUB, OP_TRAP, OP_WAITEVENT,
+};
+
+const HEADER: &str = "rust-goto checkpoint 2";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckpointError {
+    Malformed(String),
+    WrongProgram,
+    PcOutOfRange { pc: usize, len: usize },
+    StackTooDeep { depth: usize },
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckpointError::Malformed(what) => write!(f, "malformed checkpoint: {what}"),
+            CheckpointError::WrongProgram => write!(f, "checkpoint was taken on a different program"),
+            CheckpointError::PcOutOfRange { pc, len } => {
+                write!(f, "checkpoint pc {pc} is past the end of the program ({len} instructions)")
+            }
+            CheckpointError::StackTooDeep { depth } => {
+                write!(f, "checkpoint has a stack {depth} deep, the limit is {STACK_DEPTH}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckpointError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    program_hash: u64,
+    pc: usize,
+    regs: [i64; NREGS],
+    stack: Stack,
+    mem: Memory,
+    // instructions retired so far
+    pub executed: u64,
+    // wall time spent so far, the library doesn't time anything, the driver keeps it here
+    pub elapsed_ns: u64,
+}
+
+// FNV-1a over the lowered words, enough to catch resuming with the wrong file
+fn program_hash(program: &VerifiedProgram) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for &word in program.code() {
+        for byte in word.to_le_bytes() {
+            h = (h ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    h
+}
+
+impl Checkpoint {
+    // the start of a run, registers seeded from `args` like every variant does
+    pub fn start(program: &VerifiedProgram, args: &[i64]) -> Checkpoint {
+        Checkpoint {
+            program_hash: program_hash(program),
+            pc: 0,
+            regs: seed_regs(args),
+            stack: Stack::default(),
+            mem: program.memory(),
+            executed: 0,
+            elapsed_ns: 0,
+        }
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn regs(&self) -> &[i64; NREGS] {
+        &self.regs
+    }
+
+    pub fn to_text(&self) -> String {
+        fn list<T: ToString>(items: &[T]) -> String {
+            items.iter().map(T::to_string).collect::<Vec<_>>().join(" ")
+        }
+        // memory is mostly zeros, only `address:value` for the rest
+        let memory: Vec<String> =
+            self.mem.words().iter().enumerate().filter(|&(_, &v)| v != 0).map(|(i, v)| format!("{i}:{v}")).collect();
+        format!(
+            "{HEADER}\nprogram {:016x}\npc {}\nexecuted {}\nelapsed_ns {}\nregs {}\ncalls {}\nvalues {}\nmemory {}\n",
+            self.program_hash,
+            self.pc,
+            self.executed,
+            self.elapsed_ns,
+            list(&self.regs),
+            list(&self.stack.calls),
+            list(&self.stack.values),
+            list(&memory)
+        )
+    }
+
+    // reads a checkpoint back, for `program` and nothing else
+    pub fn parse(text: &str, program: &VerifiedProgram) -> Result<Checkpoint, CheckpointError> {
+        let bad = |what: &str| CheckpointError::Malformed(what.to_string());
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(bad("not a rust-goto checkpoint, or another version"));
+        }
+        // empty lists come out as `calls` with nothing after it, no trailing space
+        let mut field = |key: &str| {
+            lines
+                .next()
+                .and_then(|l| l.strip_prefix(key))
+                .and_then(|v| if v.is_empty() { Some(v) } else { v.strip_prefix(' ') })
+                .ok_or_else(|| CheckpointError::Malformed(format!("expected `{key}`")))
+        };
+        let hash = u64::from_str_radix(field("program")?, 16).map_err(|_| bad("bad program hash"))?;
+        let pc = field("pc")?.parse().map_err(|_| bad("bad pc"))?;
+        let executed = field("executed")?.parse().map_err(|_| bad("bad instruction count"))?;
+        let elapsed_ns = field("elapsed_ns")?.parse().map_err(|_| bad("bad elapsed time"))?;
+        let values = field("regs")?
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|_| bad("bad register value"))?;
+        let regs: [i64; NREGS] = values.try_into().map_err(|_| bad("wrong number of registers"))?;
+        let calls = field("calls")?
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| bad("bad return address"))?;
+        let values = field("values")?
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<i64>, _>>()
+            .map_err(|_| bad("bad stack value"))?;
+        // the snapshot has every non-zero word, data segments included
+        let mut mem = program.memory();
+        mem.words_mut().fill(0);
+        for entry in field("memory")?.split_whitespace() {
+            let (at, v) = entry.split_once(':').ok_or_else(|| bad("bad memory entry"))?;
+            let at: usize = at.parse().map_err(|_| bad("bad memory address"))?;
+            let slot = mem.words_mut().get_mut(at).ok_or_else(|| bad("memory address past the end"))?;
+            *slot = v.parse().map_err(|_| bad("bad memory value"))?;
+        }
+
+        if hash != program_hash(program) {
+            return Err(CheckpointError::WrongProgram);
+        }
+        // the variants fetch unchecked, a pc from a file gets no such trust, and neither
+        // does anything RET could jump to
+        let len = program.code().len();
+        if let Some(&pc) = calls.iter().chain([&pc]).find(|&&pc| pc >= len) {
+            return Err(CheckpointError::PcOutOfRange { pc, len });
+        }
+        if let Some(depth) = [calls.len(), values.len()].into_iter().find(|&d| d > STACK_DEPTH) {
+            return Err(CheckpointError::StackTooDeep { depth });
+        }
+        let stack = Stack { calls, values };
+        Ok(Checkpoint { program_hash: hash, pc, regs, stack, mem, executed, elapsed_ns })
+    }
+}
+
+// central dispatch that stops after `fuel` instructions. handle! returns straight out
+// of the function on HALT, so a pause can't be a distinct return value: it's the one
+// way out that leaves `fuel` at 0 (HALT returns before it gets counted)
+#[inline(never)]
+fn run_slice(code: &[u32], cp: &mut Checkpoint, fuel: &mut u64) -> i64 {
+    let mut regs = cp.regs;
+    let mut pc = cp.pc;
+    let mut stack = std::mem::take(&mut cp.stack);
+    let mut mem = std::mem::take(&mut cp.mem);
+
+    loop {
+        if *fuel == 0 {
+            cp.regs = regs;
+            cp.pc = pc;
+            cp.stack = stack;
+            cp.mem = mem;
+            return 0;
+        }
+        let (op, dst, a, b) = exec_one!(code, regs, pc);
+        handle!(regs, stack, mem, pc, op, dst, a, b);
+        *fuel -= 1;
+    }
+}
+
+// runs at most `fuel` instructions from `cp`. Some(result) once the program halts,
+// None if it's still going, with `cp` moved up to where it stopped
+pub fn run_for(program: &VerifiedProgram, cp: &mut Checkpoint, fuel: u64) -> Option<i64> {
+    assert_eq!(cp.program_hash, program_hash(program), "checkpoint is for another program");
+    let mut left = fuel;
+    let result = run_slice(program.code(), cp, &mut left);
+    if left == 0 {
+        cp.executed += fuel;
+        None
+    } else {
+        cp.executed += fuel - left + 1;
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CountHook, kernels, run_central, run_hooked, verify};
+
+    #[test]
+    fn resumed_runs_match_straight_runs() {
+        // fib has something on both stacks most of the time, sieve fills memory
+        for name in ["loop-nest:3", "fib", "sieve"] {
+            let (code, args) = kernels::kernel(name, 20).unwrap();
+            let program = verify(&code).unwrap();
+            let expected = run_central(&program, &args);
+            let mut counts = CountHook::default();
+            run_hooked(&program, &args, &mut counts);
+
+            for every in [1, 7, 1000] {
+                let mut cp = Checkpoint::start(&program, &args);
+                let result = loop {
+                    // through the text format every time, like a preempted job would
+                    cp = Checkpoint::parse(&cp.to_text(), &program).unwrap();
+                    if let Some(r) = run_for(&program, &mut cp, every) {
+                        break r;
+                    }
+                };
+                assert_eq!(result, expected, "{name} every {every}");
+                assert_eq!(cp.executed, counts.instructions, "{name} every {every}");
+            }
+        }
+
+        let (code, args) = kernels::kernel("loop-nest:3", 20).unwrap();
+        let program = verify(&code).unwrap();
+        let other = verify(&kernels::sum_poly()).unwrap();
+        let text = Checkpoint::start(&program, &args).to_text();
+        assert_eq!(Checkpoint::parse(&text, &other).unwrap_err(), CheckpointError::WrongProgram);
+        let far = text.replace("\npc 0\n", "\npc 99999\n");
+        assert!(matches!(Checkpoint::parse(&far, &program), Err(CheckpointError::PcOutOfRange { .. })));
+    }
+}
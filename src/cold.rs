@@ -0,0 +1,116 @@
+// Latency of one invocation from cold: `--cold N`
+//
+// the default benchmark times thousands of back-to-back runs, which is steady-state
+// throughput: the handlers, the program and the branch history are all warm. an
+// embedder running a filter program once per request sees something else, every call
+// starts with the interpreter out of the caches and, for the variants that translate
+// first (bytes, bundles, decoded fields, the jit), pays for the translation too. this
+// times exactly one Variant::exec per trial, translation included, after evicting the
+// caches by writing over EVICT_BYTES of scratch memory, then once more straight after
+// for the warm number. N trials per variant, round-robin over the variants so drift
+// hits them all alike, and the distribution rather than a mean: the tail is what a
+// latency budget is about
+//
+// what isn't reset: the branch predictors and the TLB (partly, the scratch writes
+// push out most entries). so "cold" is cold caches, an upper bound on what a host that
+// ran other work in between would see, not a fresh process
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_goto::{CountHook, Variant, VerifiedProgram, run_hooked};
+
+use crate::Workload;
+use crate::report::Stats;
+
+// bigger than the last-level cache of anything this runs on
+const EVICT_BYTES: usize = 64 << 20;
+const CACHE_LINE: usize = 64;
+
+pub fn run(workload: &Workload, variants: &[&Variant], trials: usize) {
+    let Workload { description, program, args, .. } = workload;
+    let expected = run_hooked(program, args, &mut CountHook::default());
+    let mut scratch = vec![0u8; EVICT_BYTES];
+
+    // variants that can't take the program drop out before the first trial
+    let mut rows: Vec<(&Variant, Vec<f64>, Vec<f64>)> = Vec::new();
+    for &v in variants {
+        match v.exec(program, args) {
+            Ok(result) if result == expected => rows.push((v, Vec::new(), Vec::new())),
+            Ok(result) => println!("{:>24}: MISMATCH: {result}, expected {expected}", v.label),
+            Err(e) => println!("{:>24}: skipped, {e}", v.label),
+        }
+    }
+    for trial in 0..trials {
+        for (v, cold, warm) in &mut rows {
+            evict(&mut scratch, trial);
+            cold.push(time_once(v, program, args));
+            warm.push(time_once(v, program, args));
+        }
+    }
+
+    println!("one invocation from cold, translation included, us ({trials} trials per variant)");
+    println!("Program: {description}\n");
+    println!(
+        "{:>24} {:>9} {:>9} {:>9} {:>9} {:>9}   {:>9}",
+        "variant", "min", "median", "p90", "p99", "max", "warm"
+    );
+    for (v, cold, warm) in &rows {
+        let stats = Stats::of(cold);
+        println!(
+            "{:>24} {:>9.2} {:>9.2} {:>9.2} {:>9.2} {:>9.2}   {:>9.2}",
+            v.label,
+            stats.min,
+            stats.median,
+            percentile(cold, 0.90),
+            percentile(cold, 0.99),
+            stats.max,
+            Stats::of(warm).median
+        );
+    }
+    println!();
+    println!("cold: after writing over {} MiB, warm: the same call again right after", EVICT_BYTES >> 20);
+}
+
+// us for one exec, translation included. black_box on both ends so the call can't be
+// specialized for the variant or moved out of the timed region
+fn time_once(v: &Variant, program: &VerifiedProgram, args: &[i64]) -> f64 {
+    let start = Instant::now();
+    let result = black_box(v).exec(black_box(program), args);
+    let elapsed = start.elapsed();
+    black_box(result).expect("the variant took the program before");
+    elapsed.as_secs_f64() * 1e6
+}
+
+// a write to every cache line of `scratch`, different each trial so the stores can't
+// be skipped as redundant
+fn evict(scratch: &mut [u8], trial: usize) {
+    for line in scratch.chunks_mut(CACHE_LINE) {
+        line[0] = line[0].wrapping_add(trial as u8 | 1);
+    }
+    black_box(scratch);
+}
+
+// nearest-rank percentile, `p` in 0..=1
+fn percentile(samples: &[f64], p: f64) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = ((p * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_are_nearest_rank() {
+        let samples: Vec<f64> = (1..=100).rev().map(f64::from).collect();
+        assert_eq!(percentile(&samples, 0.90), 90.0);
+        assert_eq!(percentile(&samples, 0.99), 99.0);
+        assert_eq!(percentile(&samples, 1.0), 100.0);
+        // with fewer samples than 1/(1-p), p99 is the slowest one
+        assert_eq!(percentile(&[3.0, 1.0, 2.0], 0.99), 3.0);
+        assert_eq!(percentile(&[3.0, 1.0, 2.0], 0.0), 1.0);
+    }
+}
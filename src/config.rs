@@ -0,0 +1,276 @@
+// How to build a Vm, in one checked-in file
+//
+// everything a host decides about a Vm besides the program itself: the dispatch
+// strategy, how much memory a program gets, what running off the end does, which ISA
+// features it lets programs use. embedders read it with VmConfig::from_toml, the
+// benchmark with --config, so both go through the same keys and the same checks
+// instead of each growing its own list of arguments. the format is rust_goto::toml:
+//
+//   strategy = "threaded2"         # a DispatchKind by name, default central
+//   memory_words = 4096            # instead of what the program asks for
+//   fall_off_end = "halt"          # reject, halt or trap, see FallOffEnd
+//   features = ["calls", "memory"] # the ISA features programs may use, default all
+//                                  # of SUPPORTED_FEATURES
+//   reset = "keep"                 # zero (the default) or keep what the last run left
+//                                  # in registers and memory, see Vm::set_zero_on_reset
+//   div = "trap"                   # zero (the default, the ISA's x / 0) or trap with
+//                                  # TrapKind::DivByZero, see Vm::set_div_by_zero_traps
+//
+// every key is optional, and one the list doesn't have is an error rather than
+// silently ignored. keys that aren't in the file leave the program's own choice alone
+// (the memory a .memory directive asked for, a FallOffEnd set by the assembler), keys
+// that are win
+//
+// memory is the one limit here. the stack depths are STACK_DEPTH, a const because
+// the variants, goto.c and the JIT all size their stacks with it, the conformance
+// vectors overflow at it, and a per-Vm depth would have to reach every one of them.
+// fuel isn't a Vm's at all: `resume` takes it per call, so the host already picks
+// it every time
+
+use std::fmt;
+
+use crate::toml::{self, Value, expect_positive, expect_str, expect_strs};
+use crate::{
+    DispatchKind, FallOffEnd, MAX_MEMORY_WORDS, Program, ProgramError, SUPPORTED_FEATURES, VerifiedProgram, Vm,
+    VmError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    // 0 for what isn't about one line
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => f.write_str(&self.message),
+            line => write!(f, "line {line}: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VmConfig {
+    // None leaves the choice to whoever builds the Vm, `build` takes central
+    pub dispatch: Option<DispatchKind>,
+    pub memory_words: Option<usize>,
+    pub fall_off_end: Option<FallOffEnd>,
+    // the ISA features a program may rely on, a subset of SUPPORTED_FEATURES
+    pub features: Vec<String>,
+    pub zero_on_reset: bool,
+    // DIV and MOD by zero trap instead of giving 0
+    pub div_traps: bool,
+}
+
+impl Default for VmConfig {
+    fn default() -> VmConfig {
+        VmConfig {
+            dispatch: None,
+            memory_words: None,
+            fall_off_end: None,
+            features: SUPPORTED_FEATURES.iter().map(|f| f.to_string()).collect(),
+            zero_on_reset: true,
+            div_traps: false,
+        }
+    }
+}
+
+impl VmConfig {
+    pub fn from_toml(text: &str) -> Result<VmConfig, ConfigError> {
+        let pairs = toml::pairs(text).map_err(|message| ConfigError { line: 0, message })?;
+        let mut config = VmConfig::default();
+        for toml::Pair { line, key, value } in pairs {
+            let err = |message: String| ConfigError { line, message };
+            let key = key.as_str();
+            match key {
+                "strategy" => {
+                    let name = expect_str(key, value).map_err(err)?;
+                    let dispatch = DispatchKind::by_name(&name).ok_or_else(|| {
+                        let known: Vec<_> = DispatchKind::ALL.iter().map(|k| k.name()).collect();
+                        err(format!("unknown strategy `{name}`, known: {}", known.join(", ")))
+                    })?;
+                    config.dispatch = Some(dispatch);
+                }
+                "memory_words" => {
+                    let words = match value {
+                        Value::Int(0) => 0,
+                        v => expect_positive(key, v).map_err(err)? as usize,
+                    };
+                    if words > MAX_MEMORY_WORDS {
+                        return Err(err(ProgramError::MemoryTooLarge { words }.to_string()));
+                    }
+                    config.memory_words = Some(words);
+                }
+                "fall_off_end" => {
+                    let name = expect_str(key, value).map_err(err)?;
+                    let policy = FallOffEnd::ALL.iter().find(|p| p.name() == name).ok_or_else(|| {
+                        let known: Vec<_> = FallOffEnd::ALL.iter().map(|p| p.name()).collect();
+                        err(format!("unknown fall_off_end `{name}`, known: {}", known.join(", ")))
+                    })?;
+                    config.fall_off_end = Some(*policy);
+                }
+                "features" => {
+                    let features = expect_strs(key, value).map_err(err)?;
+                    if let Some(bad) = features.iter().find(|f| !SUPPORTED_FEATURES.contains(&f.as_str())) {
+                        let known = SUPPORTED_FEATURES.join(", ");
+                        return Err(err(format!("unknown feature `{bad}`, known: {known}")));
+                    }
+                    config.features = features;
+                }
+                "reset" => {
+                    config.zero_on_reset = match expect_str(key, value).map_err(err)?.as_str() {
+                        "zero" => true,
+                        "keep" => false,
+                        other => return Err(err(format!("unknown reset `{other}`, known: zero, keep"))),
+                    };
+                }
+                "div" => {
+                    config.div_traps = match expect_str(key, value).map_err(err)?.as_str() {
+                        "zero" => false,
+                        "trap" => true,
+                        other => return Err(err(format!("unknown div `{other}`, known: zero, trap"))),
+                    };
+                }
+                _ => return Err(err(format!("unknown key `{key}`"))),
+            }
+        }
+        Ok(config)
+    }
+
+    // the program with the config's memory and FallOffEnd, verified. fails on a feature
+    // the config doesn't enable, or code that doesn't verify under its FallOffEnd
+    pub fn prepare(&self, mut program: Program) -> Result<VerifiedProgram, VmError> {
+        if let Some(bad) = program.features().iter().find(|f| !self.features.contains(f)) {
+            return Err(VmError::Program(ProgramError::UnsupportedFeature(bad.clone())));
+        }
+        if let Some(words) = self.memory_words {
+            program.set_memory_words(words).map_err(VmError::Program)?;
+        }
+        if let Some(policy) = self.fall_off_end {
+            program.set_fall_off_end(policy);
+        }
+        program.verify().map_err(VmError::Verify)
+    }
+
+    // `prepare`, then a Vm with the config's dispatch
+    pub fn build(&self, program: Program) -> Result<Vm, VmError> {
+        let program = self.prepare(program)?;
+        let mut vm = Vm::with_dispatch(program, self.dispatch.unwrap_or(DispatchKind::Central))?;
+        vm.set_zero_on_reset(self.zero_on_reset);
+        vm.set_div_by_zero_traps(self.div_traps);
+        Ok(vm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Instruction, OP_DIV, OP_HALT, OP_LOAD, OP_LOADI, Opcode, Status, TrapKind, encode};
+
+    fn add_then_halt() -> Program {
+        Program::new(vec![
+            Instruction::new(Opcode::Add, 2, 0, 1).encode(),
+            Instruction::new(Opcode::Halt, 2, 0, 0).encode(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_every_key() {
+        let config = VmConfig::from_toml(
+            r#"
+            # a comment
+            strategy = "threaded2"
+            memory_words = 4_096
+            fall_off_end = "trap"
+            features = ["calls", "memory"]
+            reset = "keep"
+            div = "trap"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.dispatch, Some(DispatchKind::Threaded2));
+        assert_eq!(config.memory_words, Some(4096));
+        assert_eq!(config.fall_off_end, Some(FallOffEnd::Trap));
+        assert_eq!(config.features, ["calls", "memory"]);
+        assert!(!config.zero_on_reset);
+        assert!(config.div_traps);
+        assert_eq!(VmConfig::from_toml("").unwrap(), VmConfig::default());
+    }
+
+    #[test]
+    fn rejects_bad_configs() {
+        let cases = [
+            ("colour = \"red\"", "line 1: unknown key `colour`"),
+            ("strategy = \"warp-drive\"", "unknown strategy `warp-drive`"),
+            ("strategy = 3", "should be a string"),
+            ("memory_words = -1", "has to be positive"),
+            ("memory_words = 999_999_999", "the most is"),
+            ("fall_off_end = \"loop\"", "unknown fall_off_end `loop`"),
+            ("features = [\"threads\"]", "unknown feature `threads`"),
+            ("div = \"nan\"", "unknown div `nan`"),
+            ("div = 0", "should be a string"),
+            ("reset = \"never\"", "unknown reset `never`"),
+            ("just words", "expected `key = value`"),
+        ];
+        for (text, needle) in cases {
+            let err = VmConfig::from_toml(text).unwrap_err().to_string();
+            assert!(err.contains(needle), "{text}: {err}");
+        }
+    }
+
+    #[test]
+    fn builds_the_vm_it_describes() {
+        for &kind in DispatchKind::ALL {
+            let config = VmConfig { dispatch: Some(kind), ..VmConfig::default() };
+            let vm = config.build(add_then_halt()).unwrap();
+            assert_eq!(vm.dispatch(), kind);
+            assert_eq!(vm.run(&[40, 2]), Ok(42), "{kind:?}");
+        }
+        assert_eq!(VmConfig::default().build(add_then_halt()).unwrap().dispatch(), DispatchKind::Central);
+    }
+
+    #[test]
+    fn div_says_what_dividing_by_zero_does() {
+        // r1 = 0, then r0 / r1
+        let program = Program::new(vec![
+            encode(OP_LOADI, 1, 0, 0),
+            encode(OP_DIV, 2, 0, 1),
+            encode(OP_HALT, 2, 0, 0),
+        ])
+        .unwrap();
+        let mut vm = VmConfig::from_toml("div = \"zero\"").unwrap().build(program.clone()).unwrap();
+        vm.reset(&[7]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(0));
+        assert_eq!(vm.trap(), None);
+
+        let mut vm = VmConfig::from_toml("div = \"trap\"").unwrap().build(program).unwrap();
+        vm.reset(&[7]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        assert_eq!(vm.trap().map(|t| (t.kind(), t.pc())), Some((TrapKind::DivByZero, 1)));
+        // the run-to-completion path keeps the ISA's 0
+        assert_eq!(vm.run(&[7]), Ok(0));
+    }
+
+    #[test]
+    fn applies_fall_off_end_and_limits() {
+        // ADD without a HALT after it only verifies once running off the end halts
+        let open_ended = Program::new(vec![encode(crate::OP_ADD, 0, 0, 1)]).unwrap();
+        assert!(matches!(VmConfig::default().prepare(open_ended.clone()), Err(VmError::Verify(_))));
+        let config = VmConfig::from_toml("fall_off_end = \"halt\"").unwrap();
+        assert_eq!(config.build(open_ended).unwrap().run(&[40, 2]), Ok(42));
+
+        let load = Program::new(vec![encode(OP_LOAD, 0, 0, 0), encode(OP_HALT, 0, 0, 0)]).unwrap();
+        let config = VmConfig::from_toml("memory_words = 8").unwrap();
+        assert_eq!(config.prepare(load.clone()).unwrap().program().memory_words(), 8);
+        let config = VmConfig::from_toml("features = [\"calls\"]").unwrap();
+        assert_eq!(
+            config.prepare(load).unwrap_err(),
+            VmError::Program(ProgramError::UnsupportedFeature("memory".into()))
+        );
+    }
+}
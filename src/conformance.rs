@@ -0,0 +1,432 @@
+// Conformance vectors: programs, their inputs and the state they end in, as JSON
+//
+// the Rust VM is the reference (Vm::step, see fuzz.rs for why that one), everything
+// else is supposed to agree with it: the variants here, goto.c, the plugin handlers,
+// and anyone writing their own interpreter for the ISA without reading this crate.
+// the last two can't link against a test suite written in Rust, so the cases are
+// data instead, one file per vector in conformance/ (the README there describes the
+// format for them):
+//
+//   {
+//     "format": 1,
+//     "name": "alu-div",
+//     "description": "DIV r0, r0, r1 then HALT r0",
+//     "code": [16777221, 0],
+//     "memory_words": 0,
+//     "segments": [],
+//     "cases": [
+//       {"args": ["7", "3"], "result": "2", "retired": 2,
+//        "regs": ["2", "3", "0", ...], "memory": []}
+//     ]
+//   }
+//
+// code is the instruction words in the dense opcode numbering (so none of this exists
+// with sparse-opcodes), memory the words that aren't 0 once the program stopped, as
+// [address, value]. every i64 is a decimal string, JSON numbers are doubles in most
+// readers and i64::MIN isn't one. a case that traps has result "-1", and regs,
+// retired and memory are what the reference had when it stopped: the trapping
+// instruction counts as retired, and whatever it would have written it didn't
+//
+// `check` runs a vector through the reference, which has to reproduce all of it, and
+// through every variant built in, which only report the result. WAITEVENT, handled
+// TRAPs, resolved RESOLVE_CALLs and the superinstructions aren't in the builtin suite:
+// the first three need a host on the other side, the last ones only exist as fuse's
+// output
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::json::{JsonValue, json_string, parse_json};
+use crate::semantics::ALU_OPS;
+use crate::{
+    NREGS, OP_ADD, OP_CALL, OP_HALT, OP_INC, OP_JEQ, OP_JGE, OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI,
+    OP_MEMCPY, OP_MEMSET, OP_MUL, OP_POP, OP_PUSH, OP_RESOLVE_CALL, OP_RET, OP_STORE, OP_TRAP, Program, Status, VARIANTS,
+    VerifiedProgram, Vm, encode, kernels,
+};
+
+// bumped when a field changes meaning, readers should refuse what they don't know
+pub const FORMAT: u64 = 1;
+
+// far more than any vector runs, a program that gets here is a bug in the vector
+const FUEL: u64 = 100_000_000;
+
+// memory for the vectors that use it, small enough to read a dump of
+const MEMORY_WORDS: usize = 16;
+// the kernels' instead of DEFAULT_MEMORY_WORDS, sieve only needs its argument's worth
+const KERNEL_MEMORY_WORDS: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct Vector {
+    pub name: String,
+    pub description: String,
+    pub program: VerifiedProgram,
+    pub cases: Vec<Case>,
+}
+
+// one run of a vector's program and where it ended up
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case {
+    pub args: Vec<i64>,
+    pub result: i64,
+    pub retired: u64,
+    pub regs: [i64; NREGS],
+    // (address, value) of every word that isn't 0, by address
+    pub memory: Vec<(usize, i64)>,
+}
+
+impl Vector {
+    // records a case per entry of `inputs` by running the reference on it
+    pub fn record(name: &str, description: &str, program: Program, inputs: &[&[i64]]) -> Result<Vector, String> {
+        let program = program.verify().map_err(|e| format!("{name}: {e}"))?;
+        let cases = inputs.iter().map(|args| record(&program, args)).collect::<Result<_, _>>()?;
+        Ok(Vector { name: name.to_string(), description: description.to_string(), program, cases })
+    }
+
+    pub fn to_json(&self) -> String {
+        let strs =
+            |values: &mut dyn Iterator<Item = i64>| values.map(|v| format!("\"{v}\"")).collect::<Vec<_>>().join(", ");
+        let program = self.program.program();
+        let mut out = String::new();
+        writeln!(out, "{{").unwrap();
+        writeln!(out, "  \"format\": {FORMAT},").unwrap();
+        writeln!(out, "  \"name\": {},", json_string(&self.name)).unwrap();
+        writeln!(out, "  \"description\": {},", json_string(&self.description)).unwrap();
+        let code: Vec<String> = program.code().iter().map(u32::to_string).collect();
+        writeln!(out, "  \"code\": [{}],", code.join(", ")).unwrap();
+        writeln!(out, "  \"memory_words\": {},", self.program.memory_words()).unwrap();
+        let segments: Vec<String> = program
+            .segments()
+            .iter()
+            .map(|s| {
+                let words = strs(&mut s.words.iter().copied());
+                format!("{{\"start\": {}, \"words\": [{words}], \"read_only\": {}}}", s.start, s.read_only)
+            })
+            .collect();
+        writeln!(out, "  \"segments\": [{}],", segments.join(", ")).unwrap();
+        writeln!(out, "  \"cases\": [").unwrap();
+        for (i, case) in self.cases.iter().enumerate() {
+            let memory: Vec<String> = case.memory.iter().map(|(at, v)| format!("[{at}, \"{v}\"]")).collect();
+            write!(
+                out,
+                "    {{\"args\": [{}], \"result\": \"{}\", \"retired\": {},\n     \"regs\": [{}],\n     \"memory\": [{}]}}",
+                strs(&mut case.args.iter().copied()),
+                case.result,
+                case.retired,
+                strs(&mut case.regs.iter().copied()),
+                memory.join(", ")
+            )
+            .unwrap();
+            out.push_str(if i + 1 < self.cases.len() { ",\n" } else { "\n" });
+        }
+        out.push_str("  ]\n}\n");
+        out
+    }
+
+    pub fn parse(text: &str) -> Result<Vector, String> {
+        let doc = parse_json(text)?;
+        let field = |key: &str| doc.get(key).ok_or(format!("no `{key}`"));
+        let format = count(field("format")?, "format")?;
+        if format != FORMAT {
+            return Err(format!("format {format}, this reader knows {FORMAT}"));
+        }
+        let name = string(field("name")?, "name")?;
+        let in_vector = |e: String| format!("{name}: {e}");
+        let description = string(field("description")?, "description").map_err(in_vector)?;
+        let code = array(field("code")?, "code")
+            .and_then(|words| words.iter().map(|w| count(w, "code").and_then(word)).collect())
+            .map_err(in_vector)?;
+        let mut program = Program::new(code).map_err(|e| in_vector(e.to_string()))?;
+        let memory_words = count(field("memory_words")?, "memory_words").map_err(in_vector)?;
+        program.set_memory_words(memory_words as usize).map_err(|e| in_vector(e.to_string()))?;
+        for s in array(field("segments")?, "segments").map_err(in_vector)? {
+            let part = |key: &str| s.get(key).ok_or(format!("a segment without `{key}`"));
+            let start = count(part("start").map_err(in_vector)?, "start").map_err(in_vector)?;
+            let words = ints(part("words").map_err(in_vector)?, "words").map_err(in_vector)?;
+            let read_only = match part("read_only").map_err(in_vector)? {
+                &JsonValue::Bool(b) => b,
+                _ => return Err(in_vector("`read_only` should be true or false".to_string())),
+            };
+            program.add_segment(start as usize, words, read_only).map_err(|e| in_vector(e.to_string()))?;
+        }
+        let program = program.verify().map_err(|e| in_vector(e.to_string()))?;
+        let cases = array(field("cases")?, "cases")
+            .and_then(|cases| cases.iter().map(parse_case).collect())
+            .map_err(in_vector)?;
+        Ok(Vector { name, description, program, cases })
+    }
+
+    // every case through the reference, which has to end up exactly where the vector
+    // says, and through every variant, which have to return the same result. the
+    // first thing that doesn't agree is the error
+    pub fn check(&self) -> Result<(), String> {
+        for (i, case) in self.cases.iter().enumerate() {
+            let fail = |what: &str, expected: String, got: String| {
+                format!("{} case {i} (args {:?}): {what} should be {expected}, got {got}", self.name, case.args)
+            };
+            let got = record(&self.program, &case.args).map_err(|e| format!("{} case {i}: {e}", self.name))?;
+            if got.result != case.result {
+                return Err(fail("the result", case.result.to_string(), got.result.to_string()));
+            }
+            if got.retired != case.retired {
+                return Err(fail("retired", case.retired.to_string(), got.retired.to_string()));
+            }
+            if got.regs != case.regs {
+                return Err(fail("regs", format!("{:?}", case.regs), format!("{:?}", got.regs)));
+            }
+            if got.memory != case.memory {
+                return Err(fail("memory", format!("{:?}", case.memory), format!("{:?}", got.memory)));
+            }
+            for v in VARIANTS {
+                // the byte variants can't hold everything, none of the vectors come close
+                let Ok(result) = v.exec(&self.program, &case.args) else { continue };
+                if result != case.result {
+                    return Err(fail(&format!("{}'s result", v.label), case.result.to_string(), result.to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// runs `program` on `args` in the reference and takes down where it stopped
+pub fn record(program: &VerifiedProgram, args: &[i64]) -> Result<Case, String> {
+    let mut vm = Vm::new(program.clone());
+    vm.reset(args).map_err(|e| e.to_string())?;
+    let result = match vm.resume(FUEL) {
+        Status::Halted(result) => result,
+        status => return Err(format!("args {args:?}: stopped with {status:?} instead of halting")),
+    };
+    let memory = vm.memory().iter().enumerate().filter(|&(_, &v)| v != 0).map(|(at, &v)| (at, v)).collect();
+    Ok(Case { args: args.to_vec(), result, retired: vm.retired(), regs: *vm.regs(), memory })
+}
+
+// the *.json files in `dir`, by file name
+pub fn load_dir(dir: &Path) -> Result<Vec<Vector>, String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("can't read {}: {e}", dir.display()))?;
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()))?;
+            Vector::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+        })
+        .collect()
+}
+
+fn parse_case(case: &JsonValue) -> Result<Case, String> {
+    let field = |key: &str| case.get(key).ok_or(format!("a case without `{key}`"));
+    let args = ints(field("args")?, "args")?;
+    let result = int(field("result")?, "result")?;
+    let retired = count(field("retired")?, "retired")?;
+    let regs = ints(field("regs")?, "regs")?;
+    let regs = regs.try_into().map_err(|regs: Vec<i64>| format!("{} regs, expected {NREGS}", regs.len()))?;
+    let memory = array(field("memory")?, "memory")?
+        .iter()
+        .map(|pair| match (pair.index(0), pair.index(1)) {
+            (Some(at), Some(v)) => Ok((count(at, "memory")? as usize, int(v, "memory")?)),
+            _ => Err("`memory` should hold [address, value] pairs".to_string()),
+        })
+        .collect::<Result<_, String>>()?;
+    Ok(Case { args, result, retired, regs, memory })
+}
+
+fn array<'a>(v: &'a JsonValue, key: &str) -> Result<&'a [JsonValue], String> {
+    match v {
+        JsonValue::Array(items) => Ok(items),
+        _ => Err(format!("`{key}` should be an array")),
+    }
+}
+
+fn string(v: &JsonValue, key: &str) -> Result<String, String> {
+    v.as_str().map(str::to_string).ok_or(format!("`{key}` should be a string"))
+}
+
+// a JSON number that's a whole one, for what never gets near 2^53
+fn count(v: &JsonValue, key: &str) -> Result<u64, String> {
+    match v.as_f64() {
+        Some(n) if n >= 0.0 && n.fract() == 0.0 && n < 2f64.powi(53) => Ok(n as u64),
+        _ => Err(format!("`{key}` should hold non-negative integers")),
+    }
+}
+
+fn word(n: u64) -> Result<u32, String> {
+    u32::try_from(n).map_err(|_| format!("code word {n} doesn't fit in 32 bits"))
+}
+
+// an i64, written as a decimal string
+fn int(v: &JsonValue, key: &str) -> Result<i64, String> {
+    v.as_str().and_then(|s| s.parse().ok()).ok_or(format!("`{key}` should hold i64s as decimal strings"))
+}
+
+fn ints(v: &JsonValue, key: &str) -> Result<Vec<i64>, String> {
+    array(v, key)?.iter().map(|it| int(it, key)).collect()
+}
+
+// operands for the register ops: the usual, the sign and zero cases, and the
+// extremes, i64::MIN / -1 being the one division that overflows
+const ALU_INPUTS: &[&[i64]] = &[
+    &[7, 3],
+    &[-7, 2],
+    &[5, 0],
+    &[0, 0],
+    &[-1, 63],
+    &[i64::MIN, -1],
+    &[i64::MAX, 1],
+    &[i64::MIN, i64::MAX],
+    &[0x00F0_0000_0000_0F00, 4],
+];
+
+// values a branch tests, taken or not depending on the condition
+const BRANCH_INPUTS: &[&[i64]] = &[&[0], &[1], &[-1], &[i64::MIN], &[i64::MAX]];
+
+// the vectors checked in under conformance/, what `rust-goto conformance --write`
+// writes there
+pub fn builtin() -> Vec<Vector> {
+    let vector = |name: &str, description: &str, program: Program, inputs: &[&[i64]]| {
+        Vector::record(name, description, program, inputs).expect("the builtin vectors halt")
+    };
+    let program = |code: Vec<u32>| Program::new(code).expect("the builtin vectors decode");
+    let with_memory = |code: Vec<u32>, segments: &[(usize, &[i64], bool)]| {
+        let mut p = program(code);
+        p.set_memory_words(MEMORY_WORDS).expect("small");
+        for &(start, words, read_only) in segments {
+            p.add_segment(start, words.to_vec(), read_only).expect("inside the memory");
+        }
+        p
+    };
+    let mut vectors = Vec::new();
+
+    // every register op as r0 = r0 op r1, the in-place ones on r0 alone
+    for &(op, mnemonic) in ALU_OPS {
+        let code = vec![encode(op, 0, 0, 1), encode(OP_HALT, 0, 0, 0)];
+        let description = format!("{} r0, r0, r1 then HALT r0", mnemonic.to_uppercase());
+        vectors.push(vector(&format!("alu-{mnemonic}"), &description, program(code), ALU_INPUTS));
+    }
+
+    let loadi = vec![encode(OP_LOADI, 0, 0x34, 0x12), encode(OP_LOADI, 1, 0xFF, 0xFF), encode(OP_HALT, 0, 0, 0)];
+    vectors.push(vector("loadi", "LOADI's 16-bit immediate, zero-extended", program(loadi), &[&[], &[-5, -5]]));
+
+    // taken: HALT with 2, not taken: HALT with 1
+    for (op, mnemonic) in [(OP_JMPNZ, "jmpnz"), (OP_JEQ, "jeq"), (OP_JNE, "jne"), (OP_JLT, "jlt"), (OP_JGE, "jge")] {
+        let code = vec![
+            encode(op, 0, 3, 0),
+            encode(OP_LOADI, 1, 1, 0),
+            encode(OP_HALT, 1, 0, 0),
+            encode(OP_LOADI, 1, 2, 0),
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        let description = format!("{} on r0, r1 = 2 if taken and 1 if not", mnemonic.to_uppercase());
+        vectors.push(vector(&format!("branch-{mnemonic}"), &description, program(code), BRANCH_INPUTS));
+    }
+    let jmp = vec![encode(OP_JMP, 0, 2, 0), encode(OP_INC, 0, 0, 0), encode(OP_HALT, 0, 0, 0)];
+    vectors.push(vector("branch-jmp", "JMP over an INC", program(jmp), &[&[5]]));
+
+    // r0 = r0 * r0 + r0 in a subroutine, pushed and popped around the MUL, then + 1
+    let call = vec![
+        encode(OP_CALL, 0, 3, 0),
+        encode(OP_INC, 0, 0, 0),
+        encode(OP_HALT, 0, 0, 0),
+        encode(OP_PUSH, 0, 0, 0),
+        encode(OP_MUL, 0, 0, 0),
+        encode(OP_POP, 1, 0, 0),
+        encode(OP_ADD, 0, 0, 1),
+        encode(OP_RET, 0, 0, 0),
+    ];
+    vectors.push(vector("call-ret", "CALL, PUSH, POP and RET: r0 * r0 + r0 + 1", program(call), &[&[3], &[-4]]));
+    let underflow = vec![encode(OP_POP, 0, 0, 0), encode(OP_HALT, 0, 0, 0)];
+    vectors.push(vector("pop-empty", "POP on an empty stack traps", program(underflow), &[&[9]]));
+    vectors.push(vector(
+        "ret-empty",
+        "RET with no CALL to return to traps",
+        program(vec![encode(OP_RET, 0, 0, 0)]),
+        &[&[9]],
+    ));
+    let recurse = vec![encode(OP_CALL, 0, 0, 0), encode(OP_HALT, 0, 0, 0)];
+    let description = "CALL without end overflows the call stack after STACK_DEPTH (1024) calls";
+    vectors.push(vector("call-overflow", description, program(recurse), &[&[]]));
+    let push = vec![encode(OP_PUSH, 0, 0, 0), encode(OP_JMP, 0, 0, 0)];
+    let description = "PUSH without end overflows the value stack after STACK_DEPTH (1024) values";
+    vectors.push(vector("push-overflow", description, program(push), &[&[]]));
+    let trap = vec![encode(OP_TRAP, 0, 7, 0), encode(OP_HALT, 0, 0, 0)];
+    vectors.push(vector("trap-unhandled", "TRAP with no host handler stops with -1", program(trap), &[&[3]]));
+    let resolve = vec![encode(OP_RESOLVE_CALL, 0, 7, 0), encode(OP_HALT, 0, 0, 0)];
+    let description = "RESOLVE_CALL with no host resolver stops with -1";
+    vectors.push(vector("resolve-unresolved", description, program(resolve), &[&[3]]));
+
+    // r2 = mem[r0 + 8], mem[r1] = r2, out of bounds and the read-only segment trap
+    let load_store = vec![encode(OP_LOAD, 2, 0, 8), encode(OP_STORE, 2, 1, 0), encode(OP_HALT, 2, 0, 0)];
+    vectors.push(vector(
+        "load-store",
+        "LOAD r2, [r0 + 8] and STORE r2, [r1], mem[8..10] read-only",
+        with_memory(load_store, &[(8, &[5, -6], true)]),
+        &[&[0, 0], &[1, 3], &[8, 0], &[-9, 0], &[0, 8], &[0, 16], &[0, -1]],
+    ));
+    // MEMCPY r0 <- r1, r2 words, then MEMSET r3 to r4, r5 words
+    let bulk = vec![encode(OP_MEMCPY, 0, 1, 2), encode(OP_MEMSET, 3, 4, 5), encode(OP_HALT, 0, 0, 0)];
+    vectors.push(vector(
+        "memcpy-memset",
+        "MEMCPY r0, r1, r2 then MEMSET r3, r4, r5 over mem[0..4] = 1, 2, 3, 4",
+        with_memory(bulk, &[(0, &[1, 2, 3, 4], false)]),
+        &[
+            &[8, 0, 4, 12, 7, 2],
+            &[1, 0, 3, 0, -1, 0],
+            &[0, 1, 3, 15, 9, 1],
+            &[0, 0, 0, 16, 9, 0],
+            &[8, 0, 9, 0, 0, 0],
+            &[0, 0, 0, 14, 9, 3],
+            &[0, 0, -1, 0, 0, 0],
+        ],
+    ));
+
+    // whole programs, small enough inputs that the memory dumps stay short
+    for (name, args) in [("sum-poly", 10), ("fib", 10), ("sieve", 30), ("collatz", 7), ("bitscan", 20)] {
+        let (code, _) = kernels::kernel(name, 1).expect("a kernel");
+        let description = format!("the {name} kernel, see kernels.rs");
+        let mut p = program(code);
+        p.set_memory_words(KERNEL_MEMORY_WORDS).expect("small");
+        vectors.push(vector(&format!("kernel-{name}"), &description, p, &[&[args]]));
+    }
+    vectors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors_read_back() {
+        for v in builtin() {
+            let back = Vector::parse(&v.to_json()).unwrap();
+            assert_eq!((back.name.as_str(), &back.cases), (v.name.as_str(), &v.cases));
+            assert_eq!(back.to_json(), v.to_json());
+        }
+    }
+
+    #[test]
+    fn a_wrong_state_gets_caught() {
+        let mut v = builtin().into_iter().find(|v| v.name == "memcpy-memset").unwrap();
+        v.check().unwrap();
+        v.cases[0].memory[0].1 += 1;
+        assert!(v.check().unwrap_err().contains("memory should be"));
+        let json = v.to_json().replace("\"format\": 1", "\"format\": 2");
+        assert!(Vector::parse(&json).unwrap_err().contains("format 2"));
+    }
+
+    // the files under conformance/ are what builtin() writes, and pass
+    #[test]
+    fn checked_in_vectors_are_current() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("conformance");
+        let files = load_dir(&dir).unwrap();
+        let mut builtin = builtin();
+        builtin.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(files.len(), builtin.len(), "run `rust-goto conformance --write conformance`");
+        for (file, v) in files.iter().zip(&builtin) {
+            assert_eq!(file.to_json(), v.to_json(), "run `rust-goto conformance --write conformance`");
+            file.check().unwrap();
+        }
+    }
+}
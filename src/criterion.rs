@@ -0,0 +1,170 @@
+// `--criterion <baseline>`: the default benchmark's rows written the way Criterion
+// writes its own, so the tools built around it (critcmp, CI plotters) read our numbers
+// without a converter
+//
+// the layout is Criterion's, under CARGO_TARGET_DIR or the crate's target/:
+//
+//   criterion/rust-goto/<label>/<workload>/<baseline>/benchmark.json   ids and titles
+//                                                     estimates.json   mean, median, ...
+//                                                     sample.json      iters and times
+//                                                     tukey.json       outlier fences
+//
+// the group is always rust-goto, the function is the row label (central-dispatch,
+// an external tool, a plugin row) and the value is the workload, so runs on different
+// programs sit side by side. like Criterion, writing `new` first moves the previous
+// `new` to `base`, other names just replace whatever was there
+//
+// the samples are flat (every batch has the same number of iterations), so there's no
+// slope estimate. the confidence intervals are normal approximations from the
+// standard error, not Criterion's bootstrap, close enough for comparing runs
+
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_goto::analyze;
+use rust_goto::json::json_string;
+
+use crate::report::Row;
+
+const GROUP: &str = "rust-goto";
+const CONFIDENCE: f64 = 0.95;
+// two-sided z for CONFIDENCE
+const Z: f64 = 1.959964;
+
+pub fn check_baseline_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(format!("`{name}` can't be a baseline name, it ends up as a directory name"));
+    }
+    Ok(())
+}
+
+fn criterion_dir() -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR").map(PathBuf::from);
+    target.unwrap_or_else(|| analyze::crate_root().join("target")).join("criterion")
+}
+
+// Criterion's own replacement of the characters that can't go in a path
+fn directory_name(s: &str) -> String {
+    s.chars().map(|c| if "?\"/\\*<>:|^".contains(c) { '_' } else { c }).collect()
+}
+
+// writes every row, returns the directory it wrote under
+pub fn write(workload: &str, baseline: &str, rows: &[Row]) -> Result<PathBuf, String> {
+    let root = criterion_dir();
+    for row in rows {
+        let dir = root.join(GROUP).join(directory_name(&row.label)).join(directory_name(workload));
+        let out = dir.join(baseline);
+        if baseline == "new" && out.exists() {
+            let base = dir.join("base");
+            let _ = fs::remove_dir_all(&base);
+            fs::rename(&out, &base).map_err(|e| format!("can't move {} to base: {e}", out.display()))?;
+        }
+        fs::create_dir_all(&out).map_err(|e| format!("can't create {}: {e}", out.display()))?;
+        let files = [
+            ("benchmark.json", benchmark_json(&row.label, workload)),
+            ("estimates.json", estimates_json(&row.samples)),
+            ("sample.json", sample_json(row)),
+            ("tukey.json", tukey_json(&row.samples)),
+        ];
+        for (name, text) in files {
+            let path = out.join(name);
+            fs::write(&path, text).map_err(|e| format!("can't write {}: {e}", path.display()))?;
+        }
+    }
+    Ok(root.join(GROUP))
+}
+
+fn benchmark_json(label: &str, workload: &str) -> String {
+    let id = json_string(&format!("{GROUP}/{label}/{workload}"));
+    let dir = json_string(&format!("{GROUP}/{}/{}", directory_name(label), directory_name(workload)));
+    format!(
+        "{{\"group_id\":{},\"function_id\":{},\"value_str\":{},\"throughput\":null,\"full_id\":{id},\
+         \"directory_name\":{dir},\"title\":{id}}}",
+        json_string(GROUP),
+        json_string(label),
+        json_string(workload)
+    )
+}
+
+fn estimate(point: f64, standard_error: f64) -> String {
+    format!(
+        "{{\"confidence_interval\":{{\"confidence_level\":{CONFIDENCE},\"lower_bound\":{},\"upper_bound\":{}}},\
+         \"point_estimate\":{point},\"standard_error\":{standard_error}}}",
+        point - Z * standard_error,
+        point + Z * standard_error
+    )
+}
+
+fn median_of(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n % 2 == 1 { sorted[n / 2] } else { (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0 }
+}
+
+// per iteration, over every sample: Criterion keeps outliers in its estimates and
+// only classifies them
+fn estimates_json(samples: &[f64]) -> String {
+    let n = samples.len() as f64;
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mean = samples.iter().sum::<f64>() / n;
+    let std_dev = (samples.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / (n - 1.0).max(1.0)).sqrt();
+    let median = median_of(&sorted);
+    let mut deviations: Vec<f64> = samples.iter().map(|s| (s - median).abs()).collect();
+    deviations.sort_by(f64::total_cmp);
+    // scaled to estimate the standard deviation of normal data, as Criterion does
+    let mad = 1.4826 * median_of(&deviations);
+    // the median's standard error is sqrt(pi / 2) times the mean's for normal data
+    let se = std_dev / n.sqrt();
+    format!(
+        "{{\"mean\":{},\"median\":{},\"median_abs_dev\":{},\"slope\":null,\"std_dev\":{}}}",
+        estimate(mean, se),
+        estimate(median, 1.2533 * se),
+        estimate(mad, 1.2533 * mad / n.sqrt()),
+        estimate(std_dev, std_dev / (2.0 * (n - 1.0).max(1.0)).sqrt())
+    )
+}
+
+// times are per sample, iters how many iterations each one ran
+fn sample_json(row: &Row) -> String {
+    let per_sample = row.iters / row.samples.len().max(1) as u64;
+    let mut iters = String::new();
+    let mut times = String::new();
+    for (i, ns) in row.samples.iter().enumerate() {
+        let sep = if i == 0 { "" } else { "," };
+        write!(iters, "{sep}{:.1}", per_sample as f64).unwrap();
+        write!(times, "{sep}{:.1}", ns * per_sample as f64).unwrap();
+    }
+    format!("{{\"sampling_mode\":\"Flat\",\"iters\":[{iters}],\"times\":[{times}]}}")
+}
+
+// low severe, low mild, high mild, high severe
+fn tukey_json(samples: &[f64]) -> String {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let (q1, q3) = (sorted[sorted.len() / 4], sorted[(3 * sorted.len()) / 4]);
+    let iqr = q3 - q1;
+    format!("[{},{},{},{}]", q1 - 3.0 * iqr, q1 - 1.5 * iqr, q3 + 1.5 * iqr, q3 + 3.0 * iqr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Stats;
+    use rust_goto::json::parse_json;
+
+    #[test]
+    fn writes_what_criterion_tools_read() {
+        let samples = vec![10.0, 11.0, 9.0, 10.0];
+        let row = Row { label: "odd/label".into(), result: 1, iters: 400, stats: Stats::of(&samples), samples };
+        let estimates = parse_json(&estimates_json(&row.samples)).unwrap();
+        let mean = estimates.get("mean").and_then(|m| m.get("point_estimate"));
+        assert_eq!(mean.and_then(|m| m.as_f64()), Some(10.0));
+        assert!(estimates.get("slope").is_some_and(|s| s.as_f64().is_none()));
+        let sample = parse_json(&sample_json(&row)).unwrap();
+        assert_eq!(sample.get("times").unwrap().index(1).and_then(|t| t.as_f64()), Some(1100.0));
+        let bench = parse_json(&benchmark_json(&row.label, "x")).unwrap();
+        assert_eq!(bench.get("directory_name").unwrap().as_str(), Some("rust-goto/odd_label/x"));
+        assert!(check_baseline_name("../up").is_err() && check_baseline_name("before").is_ok());
+    }
+}
@@ -0,0 +1,45 @@
+// How deep to duplicate the dispatch: `--depth N`
+//
+// versions B and C inline one and two more dispatches at the tail of every handler, and
+// each level is another copy of every handler in the function. more copies give the
+// branch predictor more places to learn from, up to the point where the loop no longer
+// fits the icache (or the uop cache) and every level costs more in misses than it saves.
+// this runs the loop at depths 1 ..= N on the workload (run_central, run_threaded,
+// run_threaded_deep, then run_threaded_4 and up from threaded_dispatch! in lib.rs) and
+// prints each next to its machine code size, the knee is where the ns/iter column
+// stops falling while the code column keeps growing
+
+use std::hint::black_box;
+
+use rust_goto::{CountHook, THREADED_DEPTHS, run_hooked};
+
+use crate::footprint::SymbolSizes;
+use crate::{BlackBox, Budget, Workload, measure_placed};
+
+pub fn run(workload: &Workload, max_depth: usize, budget: Budget) {
+    let Workload { description, program, args, .. } = workload;
+    let sizes = match SymbolSizes::of_current_exe() {
+        Ok(sizes) => Some(sizes),
+        Err(e) => {
+            println!("note: no code sizes, {e}");
+            None
+        }
+    };
+    let expected = run_hooked(program, args, &mut CountHook::default());
+
+    println!("threaded dispatch by depth, ns/iter ({budget} per cell)");
+    println!("Program: {description}\n");
+    println!("{:>6} {:>10} {:>10} {:>10}", "depth", "ns/iter", "vs depth 1", "code");
+    let mut depth_1 = None;
+    for (depth, &f) in THREADED_DEPTHS.iter().enumerate().take(max_depth).map(|(i, f)| (i + 1, f)) {
+        // opaque like the benchmark, see measure_variant
+        let m = measure_placed(program, args, budget, black_box(f), BlackBox::All);
+        if m.result != expected {
+            println!("{depth:>6} MISMATCH: {}, expected {expected}", m.result);
+            continue;
+        }
+        let base = *depth_1.get_or_insert(m.ns_per_iter);
+        let code = sizes.as_ref().and_then(|s| s.function_size(f as usize)).map_or("-".to_string(), |n| format!("{n} B"));
+        println!("{depth:>6} {:>10.1} {:>9.2}x {code:>10}", m.ns_per_iter, base / m.ns_per_iter);
+    }
+}
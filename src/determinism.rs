@@ -0,0 +1,143 @@
+// Run-to-run determinism: `--verify-determinism N`
+//
+// every timing comparison in here assumes a run is a pure function of the program and
+// its arguments. nothing breaks that today, but host calls, events or threading could,
+// and a variant that sometimes takes a different path would show up as noise in the
+// benchmark rather than as a bug. this runs the program N times down every path and
+// checks that each path gives the same thing every time:
+//
+//   stepping       the full machine state after the run: result, registers, pc,
+//                  memory and the retired count (Vm::resume)
+//   checkpointed   result and retired count through checkpoint::run_for
+//   hooked         result and retired count through run_hooked with a CountHook
+//   every variant  the result, all the run_* functions hand back (the --strategy ones)
+//
+// a path that's stable but disagrees with the stepping run is reported too, that's a
+// wrong variant rather than a nondeterministic one (the fuzzer's department)
+
+use rust_goto::checkpoint::{self, Checkpoint};
+use rust_goto::{CountHook, Status, Variant, Vm, VmState, run_hooked};
+
+use crate::Workload;
+
+// what a stepping run ends in
+#[derive(Debug, PartialEq, Eq)]
+struct Stepped {
+    status: Status,
+    state: VmState,
+}
+
+// runs `once` `runs` times, Err with the first run that differs from the first one
+fn repeat<T: PartialEq>(runs: usize, mut once: impl FnMut() -> T) -> Result<T, (usize, T, T)> {
+    let first = once();
+    for run in 1..runs {
+        let again = once();
+        if again != first {
+            return Err((run, first, again));
+        }
+    }
+    Ok(first)
+}
+
+fn report<T: PartialEq + std::fmt::Debug>(
+    name: &str,
+    outcome: Result<T, (usize, T, T)>,
+    agrees: impl Fn(&T) -> bool,
+) -> bool {
+    match outcome {
+        Ok(value) if agrees(&value) => {
+            println!("{name:>24}: ok");
+            true
+        }
+        Ok(value) => {
+            println!("{name:>24}: deterministic, but disagrees with the stepping run: {value:?}");
+            false
+        }
+        Err((run, first, again)) => {
+            println!("{name:>24}: NONDETERMINISTIC, run {} gave {again:?}, run 1 gave {first:?}", run + 1);
+            false
+        }
+    }
+}
+
+pub fn run(workload: &Workload, variants: &[&Variant], runs: usize) -> Result<(), String> {
+    let Workload { description, program, args, .. } = workload;
+    println!("Determinism: {description}, {runs} runs per path\n");
+
+    let mut failed = 0;
+    let mut paths = 0;
+    let mut check = |ok: bool| {
+        paths += 1;
+        failed += !ok as usize;
+    };
+
+    let stepped = repeat(runs, || {
+        let mut vm = Vm::new(program.clone());
+        vm.reset(args).expect("the workload's arguments fit in the registers");
+        let status = vm.resume(u64::MAX);
+        Stepped { status, state: vm.state().clone() }
+    });
+    let (result, retired) = match stepped {
+        Ok(Stepped { status: Status::Halted(result), state }) => (result, state.retired()),
+        Ok(Stepped { status, .. }) => return Err(format!("the program didn't run to the end: {status:?}")),
+        // all of memory is too much to print, what differs isn't
+        Err((run, first, again)) => {
+            println!("{:>24}: NONDETERMINISTIC, run {} ended in a different state than run 1:", "stepping", run + 1);
+            if first.status != again.status {
+                println!("status {:?} -> {:?}", first.status, again.status);
+            }
+            println!("{}", first.state.diff(&again.state));
+            return Err("the stepping run isn't deterministic, nothing to compare the rest against".to_string());
+        }
+    };
+    println!("result = {result}, {retired} instructions retired\n");
+    check(report("stepping", Ok(()), |_| true));
+
+    let checkpointed = repeat(runs, || {
+        let mut cp = Checkpoint::start(program, args);
+        let result = checkpoint::run_for(program, &mut cp, u64::MAX);
+        (result, cp.executed)
+    });
+    check(report("checkpointed", checkpointed, |&outcome| outcome == (Some(result), retired)));
+
+    let hooked = repeat(runs, || {
+        let mut hook = CountHook::default();
+        (run_hooked(program, args, &mut hook), hook.instructions)
+    });
+    check(report("hooked", hooked, |&outcome| outcome == (result, retired)));
+
+    for v in variants {
+        if v.exec(program, args).is_err() {
+            println!("{:>24}: skipped, the program doesn't fit its translation", v.label);
+            continue;
+        }
+        let outcome = repeat(runs, || v.exec(program, args).expect("it fit the first time"));
+        check(report(v.label, outcome, |&r| r == result));
+    }
+
+    println!();
+    if failed > 0 {
+        return Err(format!("{failed} of {paths} paths failed the determinism check"));
+    }
+    println!("all {paths} paths gave the same result on every run");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_names_the_first_run_that_differs() {
+        assert_eq!(repeat(5, || 7), Ok(7));
+        // the fourth run is the first one that's different
+        let mut n = 0;
+        assert_eq!(
+            repeat(5, || {
+                n += 1;
+                n / 4
+            }),
+            Err((3, 0, 1))
+        );
+    }
+}
@@ -0,0 +1,284 @@
+// Experiment descriptors: a measurement campaign (which programs, which variants,
+// which sizes to sweep, how long, how many times) written down in one checked-in
+// file so it can be rerun exactly, instead of living in someone's shell history
+
+// the format is the small TOML subset in rust_goto::toml:
+//
+//   name = "n-sweep"
+//   programs = ["sum-poly", "poly:4"]                  # see kernels.rs
+//   variants = ["central", "threaded2", "threaded3"]   # optional, default: all
+//   sizes = [100, 1000, 10000]                          # the `n` each program is run with
+//   budget_ms = 500                                     # optional, default: 2000
+//   repetitions = 3                                     # optional, default: 1
+//   flags = ["black-box=args", "keep-going"]            # optional, see below
+//
+// flags are the per-campaign knobs that don't deserve a key of their own:
+//
+//   black-box=<placement>  where the timing loop puts black_box (none, code, args,
+//                          result, all), same as the --black-box-matrix columns.
+//                          default all
+//   keep-going             a variant disagreeing on the result gets reported and the
+//                          campaign goes on, instead of stopping right there
+
+use std::fs;
+use std::time::Duration;
+
+use rust_goto::kernels::{KERNELS, kernel};
+use rust_goto::toml::{self, expect_ints, expect_positive, expect_str, expect_strs};
+use rust_goto::{UNAVAILABLE_VARIANTS, VARIANTS, Variant, variant_by_name, verify};
+
+use crate::{BLACK_BOX_PLACEMENTS, BlackBox, Budget, measure_variant};
+
+pub struct Descriptor {
+    pub name: String,
+    pub programs: Vec<String>,
+    pub variants: Vec<String>,
+    pub sizes: Vec<i64>,
+    pub budget: Duration,
+    pub repetitions: u32,
+    pub black_box: BlackBox,
+    pub keep_going: bool,
+}
+
+pub fn run_file(path: &str) -> Result<(), String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    let desc = parse(&text).map_err(|e| format!("{path}: {e}"))?;
+    run(&desc)
+}
+
+// a variant of this build by name, or what's wrong with the name: not built in (and
+// what it takes), or not a variant at all. for descriptors and `--strategy`
+pub fn find_variant(name: &str) -> Result<&'static Variant, String> {
+    if let Some(v) = variant_by_name(name) {
+        return Ok(v);
+    }
+    if let Some((_, why)) = UNAVAILABLE_VARIANTS.iter().find(|(n, _)| *n == name) {
+        return Err(format!("variant `{name}` isn't in this build, it {why}"));
+    }
+    let known: Vec<_> = VARIANTS.iter().map(|v| v.name).collect();
+    Err(format!("unknown variant `{name}`, known: {}", known.join(", ")))
+}
+
+fn parse(text: &str) -> Result<Descriptor, String> {
+    let mut name = None;
+    let mut programs = None;
+    let mut variants = None;
+    let mut sizes = None;
+    let mut budget = Duration::from_secs(2);
+    let mut repetitions = 1;
+    let mut black_box = BlackBox::All;
+    let mut keep_going = false;
+
+    for toml::Pair { line, key, value } in toml::pairs(text)? {
+        let err = |msg: String| format!("line {line}: {msg}");
+        let key = key.as_str();
+        match key {
+            "name" => name = Some(expect_str(key, value).map_err(err)?),
+            "programs" => {
+                let list = expect_strs(key, value).map_err(err)?;
+                if let Some(bad) = list.iter().find(|p| kernel(p, 1).is_none()) {
+                    return Err(err(format!("unknown program `{bad}`, known: {}", KERNELS.join(", "))));
+                }
+                programs = Some(list);
+            }
+            "variants" => {
+                let list = expect_strs(key, value).map_err(err)?;
+                if let Some(e) = list.iter().find_map(|v| find_variant(v).err()) {
+                    return Err(err(e));
+                }
+                variants = Some(list);
+            }
+            "sizes" => {
+                let list = expect_ints(key, value).map_err(err)?;
+                if let Some(bad) = list.iter().find(|&&n| n <= 0) {
+                    return Err(err(format!("sizes have to be positive, got {bad}")));
+                }
+                sizes = Some(list);
+            }
+            "budget_ms" => {
+                let ms = expect_positive(key, value).map_err(err)?;
+                budget = Duration::from_millis(ms);
+            }
+            "repetitions" => {
+                let n = expect_positive(key, value).map_err(err)?;
+                repetitions = u32::try_from(n).map_err(|_| err("too many repetitions".into()))?;
+            }
+            "flags" => {
+                for flag in expect_strs(key, value).map_err(err)? {
+                    match flag.split_once('=') {
+                        Some(("black-box", placement)) => {
+                            black_box = BLACK_BOX_PLACEMENTS
+                                .iter()
+                                .find(|(_, name)| *name == placement)
+                                .map(|&(bb, _)| bb)
+                                .ok_or_else(|| {
+                                    let known: Vec<_> = BLACK_BOX_PLACEMENTS.iter().map(|(_, n)| *n).collect();
+                                    let known = known.join(", ");
+                                    err(format!("unknown black-box placement `{placement}`, known: {known}"))
+                                })?;
+                        }
+                        None if flag == "keep-going" => keep_going = true,
+                        _ => {
+                            let known = "black-box=<placement>, keep-going";
+                            return Err(err(format!("unknown flag `{flag}`, known: {known}")));
+                        }
+                    }
+                }
+            }
+            _ => return Err(err(format!("unknown key `{key}`"))),
+        }
+    }
+
+    let programs: Vec<String> = programs.ok_or("missing `programs`")?;
+    let sizes: Vec<i64> = sizes.ok_or("missing `sizes`")?;
+    if programs.is_empty() || sizes.is_empty() {
+        return Err("`programs` and `sizes` can't be empty".into());
+    }
+    Ok(Descriptor {
+        name: name.unwrap_or_else(|| "unnamed".into()),
+        programs,
+        variants: variants.unwrap_or_else(|| VARIANTS.iter().map(|v| v.name.to_string()).collect()),
+        sizes,
+        budget,
+        repetitions,
+        black_box,
+        keep_going,
+    })
+}
+
+fn run(desc: &Descriptor) -> Result<(), String> {
+    println!("Experiment: {}", desc.name);
+    println!(
+        "{} program(s) x {} size(s) x {} variant(s), {} repetition(s), {}ms budget each\n",
+        desc.programs.len(),
+        desc.sizes.len(),
+        desc.variants.len(),
+        desc.repetitions,
+        desc.budget.as_millis()
+    );
+    println!("{:>12} {:>6} {:>16}  {:>12} {:>12} {:>12}", "program", "n", "variant", "median ns", "min ns", "max ns");
+
+    let mut mismatches = Vec::new();
+
+    for program in &desc.programs {
+        for &n in &desc.sizes {
+            let (code, args) = kernel(program, n).expect("programs are checked while parsing");
+            let code = verify(&code).expect("kernels verify");
+            let mut expected = None;
+            for name in &desc.variants {
+                let variant = variant_by_name(name).expect("variants are checked while parsing");
+                let mut samples = Vec::with_capacity(desc.repetitions as usize);
+                for _ in 0..desc.repetitions {
+                    let m = match measure_variant(variant, &code, &args, Budget::Time(desc.budget), desc.black_box) {
+                        Ok(m) => m,
+                        Err(e) => {
+                            println!("{program:>12} {n:>6} {name:>16}  skipped, {e}");
+                            break;
+                        }
+                    };
+                    // every variant has to agree, otherwise the timings mean nothing
+                    if *expected.get_or_insert(m.result) != m.result {
+                        let msg = format!(
+                            "{program} (n = {n}): {name} returned {}, an earlier variant returned {}",
+                            m.result,
+                            expected.unwrap()
+                        );
+                        if !desc.keep_going {
+                            return Err(msg);
+                        }
+                        mismatches.push(msg);
+                    }
+                    samples.push(m.ns_per_iter);
+                }
+                if samples.is_empty() {
+                    continue;
+                }
+                samples.sort_by(f64::total_cmp);
+                let median = samples[samples.len() / 2];
+                println!(
+                    "{program:>12} {n:>6} {name:>16}  {median:>12.1} {:>12.1} {:>12.1}",
+                    samples[0],
+                    samples[samples.len() - 1]
+                );
+            }
+        }
+    }
+    if !mismatches.is_empty() {
+        println!();
+        for msg in &mismatches {
+            println!("MISMATCH: {msg}");
+        }
+        return Err(format!("{} result mismatch(es), the timings above aren't comparable", mismatches.len()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_err(text: &str) -> String {
+        match parse(text) {
+            Ok(_) => panic!("{text:?} parsed"),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn parses_a_full_descriptor() {
+        let desc = parse(
+            r#"
+            # a comment line
+            name = "sweep # not a comment"   # this one is
+            programs = ["sum-poly", "poly:4",]
+            variants = ["central", "threaded2"]
+            sizes = [10, 1_000]
+            budget_ms = 250
+            repetitions = 3
+            flags = ["black-box=args", "keep-going"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(desc.name, "sweep # not a comment");
+        assert_eq!(desc.programs, ["sum-poly", "poly:4"]);
+        assert_eq!(desc.variants, ["central", "threaded2"]);
+        assert_eq!(desc.sizes, [10, 1000]);
+        assert_eq!(desc.budget, Duration::from_millis(250));
+        assert_eq!(desc.repetitions, 3);
+        assert!(desc.black_box == BlackBox::Args && desc.keep_going);
+    }
+
+    #[test]
+    fn defaults() {
+        let desc = parse("programs = [\"fib\"]\nsizes = [5]").unwrap();
+        assert_eq!(desc.name, "unnamed");
+        assert_eq!(desc.variants.len(), VARIANTS.len());
+        assert_eq!(desc.budget, Duration::from_secs(2));
+        assert_eq!(desc.repetitions, 1);
+        assert!(desc.black_box == BlackBox::All && !desc.keep_going);
+    }
+
+    #[test]
+    fn rejects_bad_descriptors() {
+        let base = "programs = [\"fib\"]\nsizes = [5]\n";
+        let cases = [
+            ("colour = \"red\"", "line 3: unknown key `colour`"),
+            ("just words", "expected `key = value`"),
+            ("variants = [\"warp-drive\"]", "unknown variant `warp-drive`"),
+            ("programs = [\"nope\"]", "unknown program `nope`"),
+            ("sizes = [0]", "sizes have to be positive"),
+            ("budget_ms = \"fast\"", "should be an integer"),
+            ("flags = [\"turbo\"]", "unknown flag `turbo`"),
+            ("flags = [\"black-box=everywhere\"]", "unknown black-box placement"),
+        ];
+        for (line, needle) in cases {
+            let err = parse_err(&format!("{base}{line}"));
+            assert!(err.contains(needle), "{line}: {err}");
+        }
+        assert!(parse_err("sizes = [5]").contains("missing `programs`"));
+        // variants that exist, just not in this build, say what's missing
+        for (name, _) in UNAVAILABLE_VARIANTS {
+            assert!(parse_err(&format!("{base}variants = [\"{name}\"]")).contains("isn't in this build"));
+        }
+    }
+}
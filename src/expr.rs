@@ -0,0 +1,709 @@
+// Expression-language frontend, for benchmark programs shaped like compiler output
+//
+// hand-written kernels and .vmasm files use registers the way a person does: every
+// value has its own register for the whole program. code coming out of a real compiler
+// looks different, values get moved around and share registers, and once there are
+// more live values than registers some of them live in memory and every use is a LOAD.
+// this compiles a small imperative language down to goto ISA code with a real register
+// allocator, so that shape of code gets benchmarked too
+//
+//   # sum(i*i - i + 1) for i in 1..=arg0
+//   let acc = 0;
+//   while arg0 {
+//       acc = acc + arg0 * arg0 - arg0 + 1;
+//       arg0 = arg0 - 1;
+//   }
+//   return acc;
+//
+// statements are `let x = e;`, `x = e;`, `return e;`, `while c { ... }` and
+// `if c { ... } else { ... }`. expressions are + - * / % with the usual precedence,
+// unary minus, parentheses, variables and literals that fit LOADI's 16 bits. a
+// condition is an expression (true when nonzero) or two compared with < <= > >= ==
+// !=. arg0..arg7 are the host arguments (r0..r7) and can be assigned like anything
+// else, `#` starts a comment. arithmetic wraps, like the ISA's
+//
+// the pipeline is the textbook one:
+//
+//   - lowering to three-address code on virtual registers, one per variable and one
+//     per intermediate value
+//   - liveness by dataflow over the basic blocks (live-in = used ∪ (live-out − defined),
+//     iterated to a fixed point), then a live interval per virtual register from the
+//     first to the last instruction it's live at, so a value live around a loop
+//     covers the whole loop
+//   - linear scan (Poletto and Sarkar): intervals in order of start, each gets a free
+//     register, and when there's none the one ending last goes to memory instead
+//
+// spilled values get a word of guest memory each, addressed off BASE_REG. an
+// instruction reading one loads it into a scratch register first and one writing it
+// stores it right after, so the registers left for allocation are r0..r11
+// (MAX_REGISTERS). `compile` can be given fewer, to turn up the spilling
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{
+    ARG_REGS, OP_ADD, OP_CMP, OP_DIV, OP_HALT, OP_JEQ, OP_JGE, OP_JLT, OP_JMP, OP_JNE, OP_LOAD, OP_LOADI, OP_MOD,
+    OP_MOV, OP_MUL, OP_STORE, OP_SUB, Program, encode,
+};
+
+// registers the allocator hands out, r0..r11
+pub const MAX_REGISTERS: usize = 12;
+// operands of an instruction that live in memory get loaded into these
+const SCRATCH: [u8; 2] = [12, 13];
+// always 0 once anything is spilled, the spill slots are at BASE_REG + slot
+const BASE_REG: u8 = 14;
+// LOAD/STORE offsets are a byte
+const MAX_SLOTS: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+fn error<T>(line: usize, message: impl Into<String>) -> Result<T, CompileError> {
+    Err(CompileError { line, message: message.into() })
+}
+
+// a compiled program and what the allocator did to it
+#[derive(Debug, Clone)]
+pub struct Compiled {
+    pub program: Program,
+    // virtual registers, variables and intermediate values
+    pub values: usize,
+    // how many of them ended up in memory
+    pub spilled: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(i64),
+    Name(String),
+    Sym(&'static str),
+}
+
+const SYMBOLS: &[&str] = &["<=", ">=", "==", "!=", "<", ">", "=", "+", "-", "*", "/", "%", "(", ")", "{", "}", ";"];
+
+fn lex(src: &str) -> Result<Vec<(Token, usize)>, CompileError> {
+    let mut tokens = Vec::new();
+    for (i, line) in src.lines().enumerate() {
+        let line_no = i + 1;
+        let mut rest = line.split('#').next().unwrap_or("").trim_start();
+        while !rest.is_empty() {
+            let c = rest.chars().next().unwrap();
+            let len = if c.is_ascii_digit() {
+                let len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+                let text = &rest[..len];
+                let value = match text.strip_prefix("0x") {
+                    Some(hex) => i64::from_str_radix(hex, 16),
+                    None => text.parse(),
+                };
+                let Ok(value) = value else { return error(line_no, format!("bad number `{text}`")) };
+                tokens.push((Token::Num(value), line_no));
+                len
+            } else if c.is_ascii_alphabetic() || c == '_' {
+                let len = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+                tokens.push((Token::Name(rest[..len].to_string()), line_no));
+                len
+            } else if let Some(sym) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+                tokens.push((Token::Sym(sym), line_no));
+                sym.len()
+            } else {
+                return error(line_no, format!("unexpected `{c}`"));
+            };
+            rest = rest[len..].trim_start();
+        }
+    }
+    Ok(tokens)
+}
+
+// three-address code, on virtual registers
+#[derive(Debug, Clone, Copy)]
+enum Ir {
+    // the host argument in `reg`, before anything else runs
+    Param { dst: usize, reg: u8 },
+    Const { dst: usize, value: u16 },
+    Bin { op: u8, dst: usize, a: usize, b: usize },
+    Copy { dst: usize, src: usize },
+    // JEQ/JNE/JLT/JGE on `cond`, the way the ISA's branches test a CMP result
+    Branch { op: u8, cond: usize, label: usize },
+    Jump { label: usize },
+    Label(usize),
+    Return(usize),
+}
+
+impl Ir {
+    fn uses(&self) -> Vec<usize> {
+        match *self {
+            Ir::Bin { a, b, .. } => vec![a, b],
+            Ir::Copy { src, .. } => vec![src],
+            Ir::Branch { cond, .. } | Ir::Return(cond) => vec![cond],
+            _ => Vec::new(),
+        }
+    }
+
+    fn def(&self) -> Option<usize> {
+        match *self {
+            Ir::Param { dst, .. } | Ir::Const { dst, .. } | Ir::Bin { dst, .. } | Ir::Copy { dst, .. } => Some(dst),
+            _ => None,
+        }
+    }
+
+    fn ends_block(&self) -> bool {
+        matches!(self, Ir::Branch { .. } | Ir::Jump { .. } | Ir::Return(_))
+    }
+}
+
+type Operand = fn(&mut Lowering) -> Result<usize, CompileError>;
+
+// recursive descent straight to IR, there's no tree in between
+struct Lowering {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    ir: Vec<Ir>,
+    vars: HashMap<String, usize>,
+    values: usize,
+    labels: usize,
+}
+
+impl Lowering {
+    fn line(&self) -> usize {
+        self.tokens.get(self.pos).or(self.tokens.last()).map_or(1, |t| t.1)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.0)
+    }
+
+    fn at(&self, sym: &str) -> bool {
+        matches!(self.peek(), Some(Token::Sym(s)) if *s == sym)
+    }
+
+    fn eat(&mut self, sym: &str) -> bool {
+        let at = self.at(sym);
+        self.pos += at as usize;
+        at
+    }
+
+    fn expect(&mut self, sym: &str) -> Result<(), CompileError> {
+        if self.eat(sym) { Ok(()) } else { error(self.line(), format!("expected `{sym}`")) }
+    }
+
+    fn keyword(&mut self, word: &str) -> bool {
+        let at = matches!(self.peek(), Some(Token::Name(n)) if n == word);
+        self.pos += at as usize;
+        at
+    }
+
+    fn name(&mut self) -> Result<String, CompileError> {
+        match self.peek() {
+            Some(Token::Name(n)) => {
+                let n = n.clone();
+                self.pos += 1;
+                Ok(n)
+            }
+            _ => error(self.line(), "expected a name"),
+        }
+    }
+
+    fn value(&mut self) -> usize {
+        self.values += 1;
+        self.values - 1
+    }
+
+    fn label(&mut self) -> usize {
+        self.labels += 1;
+        self.labels - 1
+    }
+
+    fn var(&self, name: &str) -> Result<usize, CompileError> {
+        match self.vars.get(name) {
+            Some(&v) => Ok(v),
+            None => error(self.line(), format!("`{name}` isn't defined")),
+        }
+    }
+
+    // the host arguments the program mentions, as the first instructions
+    fn params(&mut self) {
+        for reg in 0..ARG_REGS as u8 {
+            let name = format!("arg{reg}");
+            if self.tokens.iter().any(|t| t.0 == Token::Name(name.clone())) {
+                let dst = self.value();
+                self.ir.push(Ir::Param { dst, reg });
+                self.vars.insert(name, dst);
+            }
+        }
+    }
+
+    // statements up to a `}` or the end, whether the last one was a return
+    fn block(&mut self) -> Result<bool, CompileError> {
+        let mut returned = false;
+        while self.peek().is_some() && !self.at("}") {
+            returned = self.statement()?;
+        }
+        Ok(returned)
+    }
+
+    fn braced(&mut self) -> Result<bool, CompileError> {
+        self.expect("{")?;
+        let returned = self.block()?;
+        self.expect("}")?;
+        Ok(returned)
+    }
+
+    fn statement(&mut self) -> Result<bool, CompileError> {
+        if self.keyword("return") {
+            let v = self.expr()?;
+            self.expect(";")?;
+            self.ir.push(Ir::Return(v));
+            return Ok(true);
+        }
+        if self.keyword("while") {
+            let (top, exit) = (self.label(), self.label());
+            self.ir.push(Ir::Label(top));
+            self.condition(exit)?;
+            self.braced()?;
+            self.ir.push(Ir::Jump { label: top });
+            self.ir.push(Ir::Label(exit));
+            return Ok(false);
+        }
+        if self.keyword("if") {
+            let (otherwise, done) = (self.label(), self.label());
+            self.condition(otherwise)?;
+            let then_returned = self.braced()?;
+            if !self.keyword("else") {
+                self.ir.push(Ir::Label(otherwise));
+                return Ok(false);
+            }
+            // nothing to jump over if the then branch never gets to the end
+            if !then_returned {
+                self.ir.push(Ir::Jump { label: done });
+            }
+            self.ir.push(Ir::Label(otherwise));
+            let else_returned = self.braced()?;
+            self.ir.push(Ir::Label(done));
+            return Ok(then_returned && else_returned);
+        }
+        let line = self.line();
+        let is_let = self.keyword("let");
+        let name = self.name()?;
+        let dst = match (is_let, self.vars.get(&name)) {
+            (true, Some(_)) => return error(line, format!("`{name}` is already defined")),
+            (true, None) => {
+                let v = self.value();
+                self.vars.insert(name, v);
+                v
+            }
+            (false, _) => self.var(&name)?,
+        };
+        self.expect("=")?;
+        let first_new = self.values;
+        let v = self.expr()?;
+        self.expect(";")?;
+        // a fresh value was computed by the last instruction, which can write the
+        // variable directly instead of going through a copy
+        match self.ir.last_mut() {
+            Some(Ir::Const { dst: d, .. } | Ir::Bin { dst: d, .. }) if v >= first_new && *d == v => *d = dst,
+            _ => self.ir.push(Ir::Copy { dst, src: v }),
+        }
+        Ok(false)
+    }
+
+    // branches to `otherwise` when the condition is false
+    fn condition(&mut self, otherwise: usize) -> Result<(), CompileError> {
+        let a = self.expr()?;
+        // a < b is CMP a b < 0, a > b the same with the operands swapped
+        let (op, swap) = match self.peek() {
+            Some(Token::Sym("<")) => (OP_JGE, false),
+            Some(Token::Sym(">=")) => (OP_JLT, false),
+            Some(Token::Sym(">")) => (OP_JGE, true),
+            Some(Token::Sym("<=")) => (OP_JLT, true),
+            Some(Token::Sym("==")) => (OP_JNE, false),
+            Some(Token::Sym("!=")) => (OP_JEQ, false),
+            _ => {
+                self.ir.push(Ir::Branch { op: OP_JEQ, cond: a, label: otherwise });
+                return Ok(());
+            }
+        };
+        self.pos += 1;
+        let b = self.expr()?;
+        let (a, b) = if swap { (b, a) } else { (a, b) };
+        let dst = self.value();
+        self.ir.push(Ir::Bin { op: OP_CMP, dst, a, b });
+        self.ir.push(Ir::Branch { op, cond: dst, label: otherwise });
+        Ok(())
+    }
+
+    // left-associative `next (op next)*`
+    fn binary(&mut self, ops: &[(&str, u8)], next: Operand) -> Result<usize, CompileError> {
+        let mut a = next(self)?;
+        while let Some(&(sym, op)) = ops.iter().find(|(sym, _)| self.at(sym)) {
+            self.eat(sym);
+            let b = next(self)?;
+            let dst = self.value();
+            self.ir.push(Ir::Bin { op, dst, a, b });
+            a = dst;
+        }
+        Ok(a)
+    }
+
+    fn expr(&mut self) -> Result<usize, CompileError> {
+        self.binary(&[("+", OP_ADD), ("-", OP_SUB)], Self::term)
+    }
+
+    fn term(&mut self) -> Result<usize, CompileError> {
+        self.binary(&[("*", OP_MUL), ("/", OP_DIV), ("%", OP_MOD)], Self::unary)
+    }
+
+    fn unary(&mut self) -> Result<usize, CompileError> {
+        if !self.eat("-") {
+            return self.atom();
+        }
+        let b = self.unary()?;
+        let (zero, dst) = (self.value(), self.value());
+        self.ir.push(Ir::Const { dst: zero, value: 0 });
+        self.ir.push(Ir::Bin { op: OP_SUB, dst, a: zero, b });
+        Ok(dst)
+    }
+
+    fn atom(&mut self) -> Result<usize, CompileError> {
+        let line = self.line();
+        match self.peek().cloned() {
+            Some(Token::Num(n)) => {
+                self.pos += 1;
+                let Ok(value) = u16::try_from(n) else {
+                    return error(line, format!("{n} doesn't fit in 16 bits, LOADI can't load it"));
+                };
+                let dst = self.value();
+                self.ir.push(Ir::Const { dst, value });
+                Ok(dst)
+            }
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                self.var(&name)
+            }
+            Some(Token::Sym("(")) => {
+                self.pos += 1;
+                let v = self.expr()?;
+                self.expect(")")?;
+                Ok(v)
+            }
+            _ => error(line, "expected a number, a name or `(`"),
+        }
+    }
+}
+
+// for every instruction, the values live right after it
+fn liveness(ir: &[Ir], values: usize) -> Vec<Vec<bool>> {
+    // basic blocks as [start, end) ranges of instructions
+    let mut starts = vec![0];
+    for (i, ins) in ir.iter().enumerate() {
+        if matches!(ins, Ir::Label(_)) && i > 0 {
+            starts.push(i);
+        }
+        if ins.ends_block() && i + 1 < ir.len() {
+            starts.push(i + 1);
+        }
+    }
+    starts.dedup();
+    let blocks: Vec<(usize, usize)> =
+        starts.iter().enumerate().map(|(b, &s)| (s, starts.get(b + 1).copied().unwrap_or(ir.len()))).collect();
+    let block_of_label: HashMap<usize, usize> = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(b, &(s, _))| match ir[s] {
+            Ir::Label(l) => Some((l, b)),
+            _ => None,
+        })
+        .collect();
+    let successors: Vec<Vec<usize>> = blocks
+        .iter()
+        .enumerate()
+        .map(|(b, &(_, e))| match ir[e - 1] {
+            Ir::Branch { label, .. } => vec![block_of_label[&label], b + 1],
+            Ir::Jump { label } => vec![block_of_label[&label]],
+            Ir::Return(_) => Vec::new(),
+            _ if b + 1 < blocks.len() => vec![b + 1],
+            _ => Vec::new(),
+        })
+        .collect();
+
+    // live-in of a block from its live-out, walking its instructions backwards
+    let transfer = |&(s, e): &(usize, usize), live: &mut Vec<bool>, after: &mut [Vec<bool>]| {
+        for i in (s..e).rev() {
+            after[i].clone_from(live);
+            if let Some(d) = ir[i].def() {
+                live[d] = false;
+            }
+            for u in ir[i].uses() {
+                live[u] = true;
+            }
+        }
+    };
+    let mut live_in = vec![vec![false; values]; blocks.len()];
+    let mut after = vec![Vec::new(); ir.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in (0..blocks.len()).rev() {
+            let mut live = vec![false; values];
+            for &s in &successors[b] {
+                live.iter_mut().zip(&live_in[s]).for_each(|(l, &i)| *l |= i);
+            }
+            transfer(&blocks[b], &mut live, &mut after);
+            if live != live_in[b] {
+                live_in[b] = live;
+                changed = true;
+            }
+        }
+    }
+    after
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Location {
+    Reg(u8),
+    Slot(u8),
+}
+
+// linear scan over the live intervals, a location for every value and how many slots
+fn allocate(ir: &[Ir], values: usize, registers: usize) -> Result<(Vec<Location>, usize), CompileError> {
+    let mut intervals: Vec<Option<(usize, usize)>> = vec![None; values];
+    let mut extend = |v: usize, i: usize| {
+        let interval = intervals[v].get_or_insert((i, i));
+        interval.0 = interval.0.min(i);
+        interval.1 = interval.1.max(i);
+    };
+    for (i, live) in liveness(ir, values).iter().enumerate() {
+        live.iter().enumerate().filter(|(_, l)| **l).for_each(|(v, _)| extend(v, i));
+        ir[i].uses().into_iter().chain(ir[i].def()).for_each(|v| extend(v, i));
+    }
+    // a parameter starts out in its argument register, so it gets that one if it can
+    let params: HashMap<usize, u8> =
+        ir.iter().filter_map(|ins| if let Ir::Param { dst, reg } = *ins { Some((dst, reg)) } else { None }).collect();
+
+    let mut order: Vec<usize> = (0..values).filter(|&v| intervals[v].is_some()).collect();
+    order.sort_by_key(|&v| intervals[v].unwrap().0);
+    let end = |v: usize| intervals[v].unwrap().1;
+    let mut location = vec![Location::Slot(0); values];
+    let mut slots = 0;
+    let mut spill = |location: &mut Vec<Location>, v: usize| {
+        location[v] = Location::Slot(slots as u8);
+        slots += 1;
+    };
+    let mut free: Vec<u8> = (0..registers as u8).rev().collect();
+    // sorted by end
+    let mut active: Vec<usize> = Vec::new();
+    for v in order {
+        let start = intervals[v].unwrap().0;
+        // an instruction reads its operands before it writes, so a value whose last
+        // use is where this one is defined can hand it its register
+        active.retain(|&a| {
+            let expired = end(a) <= start;
+            if let (true, Location::Reg(r)) = (expired, location[a]) {
+                free.push(r);
+            }
+            !expired
+        });
+        let reg = match params.get(&v) {
+            Some(reg) => free.iter().position(|r| r == reg).map(|i| free.remove(i)),
+            None => free.pop(),
+        };
+        let reg = match reg {
+            Some(reg) => reg,
+            // nothing free: whichever value is live the longest goes to memory
+            None => match active.last() {
+                Some(&last) if end(last) > end(v) && !params.contains_key(&v) => {
+                    let Location::Reg(reg) = location[last] else { unreachable!() };
+                    spill(&mut location, last);
+                    active.pop();
+                    reg
+                }
+                _ => {
+                    spill(&mut location, v);
+                    continue;
+                }
+            },
+        };
+        location[v] = Location::Reg(reg);
+        let at = active.partition_point(|&a| end(a) <= end(v));
+        active.insert(at, v);
+    }
+    if slots > MAX_SLOTS {
+        return error(0, format!("{slots} values spilled, only {MAX_SLOTS} fit in LOAD/STORE's offsets"));
+    }
+    Ok((location, slots))
+}
+
+fn emit(ir: &[Ir], location: &[Location], slots: usize) -> Vec<u32> {
+    let mut code = Vec::new();
+    let mut labels = HashMap::new();
+    // (pc, label) of every jump, patched once the labels are all placed
+    let mut fixups = Vec::new();
+    if slots > 0 {
+        code.push(encode(OP_LOADI, BASE_REG, 0, 0));
+    }
+    // the register holding `v`, loading it into `scratch` if it's in memory
+    let read = |code: &mut Vec<u32>, v: usize, scratch: u8| match location[v] {
+        Location::Reg(r) => r,
+        Location::Slot(s) => {
+            code.push(encode(OP_LOAD, scratch, BASE_REG, s));
+            scratch
+        }
+    };
+    let target = |v: usize| match location[v] {
+        Location::Reg(r) => r,
+        Location::Slot(_) => SCRATCH[0],
+    };
+    let write_back = |code: &mut Vec<u32>, v: usize| {
+        if let Location::Slot(s) = location[v] {
+            code.push(encode(OP_STORE, SCRATCH[0], BASE_REG, s));
+        }
+    };
+    for ins in ir {
+        match *ins {
+            Ir::Param { dst, reg } => {
+                if let Location::Slot(s) = location[dst] {
+                    code.push(encode(OP_STORE, reg, BASE_REG, s));
+                } else if location[dst] != Location::Reg(reg) {
+                    code.push(encode(OP_MOV, target(dst), reg, 0));
+                }
+            }
+            Ir::Const { dst, value } => {
+                code.push(encode(OP_LOADI, target(dst), value as u8, (value >> 8) as u8));
+                write_back(&mut code, dst);
+            }
+            Ir::Bin { op, dst, a, b } => {
+                let (a, b) = (read(&mut code, a, SCRATCH[0]), read(&mut code, b, SCRATCH[1]));
+                code.push(encode(op, target(dst), a, b));
+                write_back(&mut code, dst);
+            }
+            Ir::Copy { dst, src } => {
+                if location[dst] != location[src] {
+                    let src = read(&mut code, src, SCRATCH[0]);
+                    match location[dst] {
+                        Location::Reg(r) => code.push(encode(OP_MOV, r, src, 0)),
+                        Location::Slot(s) => code.push(encode(OP_STORE, src, BASE_REG, s)),
+                    }
+                }
+            }
+            Ir::Branch { op, cond, label } => {
+                let cond = read(&mut code, cond, SCRATCH[0]);
+                fixups.push((code.len(), label));
+                code.push(encode(op, cond, 0, 0));
+            }
+            Ir::Jump { label } => {
+                fixups.push((code.len(), label));
+                code.push(encode(OP_JMP, 0, 0, 0));
+            }
+            Ir::Label(label) => {
+                labels.insert(label, code.len());
+            }
+            Ir::Return(v) => {
+                let v = read(&mut code, v, SCRATCH[0]);
+                code.push(encode(OP_HALT, v, 0, 0));
+            }
+        }
+    }
+    for (pc, label) in fixups {
+        let target = labels[&label] as u32;
+        code[pc] = (code[pc] & 0xFFFF) | (target << 16);
+    }
+    code
+}
+
+// compiles `src` using registers r0..r<registers - 1> (at most MAX_REGISTERS) for
+// values, spilling the rest to memory
+pub fn compile(src: &str, registers: usize) -> Result<Compiled, CompileError> {
+    if !(1..=MAX_REGISTERS).contains(&registers) {
+        return error(0, format!("can allocate 1 to {MAX_REGISTERS} registers, not {registers}"));
+    }
+    let tokens = lex(src)?;
+    let mut lowering = Lowering { tokens, pos: 0, ir: Vec::new(), vars: HashMap::new(), values: 0, labels: 0 };
+    lowering.params();
+    let returned = lowering.block()?;
+    if lowering.peek().is_some() {
+        return error(lowering.line(), "`}` with no block to close");
+    }
+    if !returned {
+        return error(lowering.line(), "the program can run past its end, finish it with `return`");
+    }
+    let Lowering { ir, values, .. } = lowering;
+    let (location, slots) = allocate(&ir, values, registers)?;
+    let code = emit(&ir, &location, slots);
+    if code.len() > u16::MAX as usize {
+        return error(0, format!("{} instructions, jump targets only reach {}", code.len(), u16::MAX));
+    }
+    let mut program = Program::new(code).map_err(|e| CompileError { line: 0, message: e.to_string() })?;
+    if slots > 0 {
+        program.set_memory_words(slots).map_err(|e| CompileError { line: 0, message: e.to_string() })?;
+    }
+    Ok(Compiled { program, values, spilled: slots })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VARIANTS;
+
+    const GCD: &str = "
+        # Euclid by subtraction, with a negative-number detour
+        let a = arg0;
+        let b = arg1;
+        if a < 0 { a = -a; }
+        while a != b {
+            if a > b { a = a - b; } else { b = b - a; }
+        }
+        return a;
+    ";
+
+    fn run(src: &str, registers: usize, args: &[i64]) -> (i64, usize) {
+        let compiled = compile(src, registers).unwrap();
+        let program = compiled.program.verify().unwrap();
+        let results: Vec<i64> = VARIANTS.iter().map(|v| v.exec(&program, args).unwrap()).collect();
+        assert!(results.iter().all(|&r| r == results[0]), "variants disagree: {results:?}");
+        (results[0], compiled.spilled)
+    }
+
+    #[test]
+    fn spilling_doesnt_change_the_answer() {
+        let pressure = include_str!("../programs/pressure.expr");
+        let (expected, spilled) = run(pressure, MAX_REGISTERS, &[50]);
+        assert_eq!(spilled, 0);
+        let mut last_spilled = 0;
+        for registers in (1..MAX_REGISTERS).rev() {
+            let (result, spilled) = run(pressure, registers, &[50]);
+            assert_eq!(result, expected, "{registers} registers");
+            assert!(spilled >= last_spilled, "{registers} registers spill less than {}", registers + 1);
+            last_spilled = spilled;
+        }
+        assert!(last_spilled > 0);
+        for registers in [1, 2, MAX_REGISTERS] {
+            assert_eq!(run(GCD, registers, &[-84, 36]).0, 12);
+            assert_eq!(run(GCD, registers, &[7, 7]).0, 7);
+        }
+        assert_eq!(run("return (2 + 3) * -4 % 7 - 100 / 9;", 1, &[]).0, (2 + 3) * -4 % 7 - 100 / 9);
+    }
+
+    #[test]
+    fn errors_say_where() {
+        let err = |src: &str| compile(src, MAX_REGISTERS).unwrap_err().to_string();
+        assert_eq!(err("let a = 1;\nreturn b;"), "line 2: `b` isn't defined");
+        assert_eq!(err("let a = 1;\nlet a = 2;\nreturn a;"), "line 2: `a` is already defined");
+        assert_eq!(err("let a = 70000;"), "line 1: 70000 doesn't fit in 16 bits, LOADI can't load it");
+        assert_eq!(
+            err("while arg0 {\n  return 1;\n}"),
+            "line 3: the program can run past its end, finish it with `return`"
+        );
+        assert_eq!(err("return 1 $ 2;"), "line 1: unexpected `$`");
+        assert!(compile("return 1;", 0).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// External competitors: another implementation of the same ISA, living in its own
+// process (a C computed-goto interpreter, some other language, whatever), benchmarked
+// on the same programs as the Rust variants
+//
+// the protocol is deliberately dumb so anything can implement it, see compare/README.md:
+//
+//   <command> <program-file> <iters> [args...]
+//
+// the program file starts with a `memory <words>` line, how many words of zeroed
+// linear memory every run gets (0 for code that never touches memory), then holds
+// one instruction word per line in hex. the tool runs the program `iters` times with
+// r0, r1, ... seeded from args and prints one line:
+//
+//   <total-ns> <result>
+//
+// timing happens inside the tool so process startup doesn't count. there's no way to
+// hand a tool data segments, programs with any are refused
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use crate::{Budget, Measurement};
+use crate::report::SAMPLES;
+use rust_goto::VerifiedProgram;
+
+// iterations for the first run, used to estimate how many fit in the budget
+const PROBE_ITERS: u64 = 1000;
+
+pub struct External {
+    pub label: String,
+    command: Vec<String>,
+}
+
+// `label=command with args`, eg. `c-goto=./compare/goto`
+pub fn parse_spec(spec: &str) -> Result<External, String> {
+    let (label, command) = spec
+        .split_once('=')
+        .ok_or_else(|| format!("expected <label>=<command>, got `{spec}`"))?;
+    let command: Vec<String> = command.split_whitespace().map(String::from).collect();
+    if label.is_empty() || command.is_empty() {
+        return Err(format!("expected <label>=<command>, got `{spec}`"));
+    }
+    Ok(External { label: label.to_string(), command })
+}
+
+fn write_program(path: &Path, code: &[u32], memory_words: usize) -> Result<(), String> {
+    let mut text = String::with_capacity(code.len() * 11 + 20);
+    text.push_str(&format!("memory {memory_words}\n"));
+    for instr in code {
+        text.push_str(&format!("{instr:08x}\n"));
+    }
+    fs::write(path, text).map_err(|e| format!("can't write {}: {e}", path.display()))
+}
+
+impl External {
+    pub fn measure(&self, program: &VerifiedProgram, args: &[i64], budget: Budget) -> Result<Measurement, String> {
+        if program.memory_words() > 0 && !program.program().segments().is_empty() {
+            return Err("the protocol can't pass the program's data segments".to_string());
+        }
+        let code = program.code();
+        let path = std::env::temp_dir().join(format!("rust-goto-{}-{}.hex", std::process::id(), self.label));
+        write_program(&path, code, program.memory_words())?;
+        let result = self.measure_file(&path, args, budget);
+        let _ = fs::remove_file(&path);
+        result
+    }
+
+    // one invocation per sample, like the batches of the in-process variants
+    fn measure_file(&self, path: &Path, args: &[i64], budget: Budget) -> Result<Measurement, String> {
+        // a fixed count doesn't need the probe
+        let probe_ns = match budget {
+            Budget::Time(_) => Some(self.invoke(path, PROBE_ITERS, args)?.0),
+            Budget::Iters(_) => None,
+        };
+        let per_sample = budget.per_sample(|| probe_ns.map_or(1.0, |ns| (ns as f64 / PROBE_ITERS as f64).max(1.0)));
+
+        let mut samples = Vec::with_capacity(SAMPLES as usize);
+        let mut result = 0;
+        for _ in 0..SAMPLES {
+            let (total_ns, r) = self.invoke(path, per_sample, args)?;
+            samples.push(total_ns as f64 / per_sample as f64);
+            result = r;
+        }
+        Ok(Measurement::from_samples(&samples, per_sample * SAMPLES, result))
+    }
+
+    fn invoke(&self, path: &Path, iters: u64, args: &[i64]) -> Result<(u64, i64), String> {
+        let output = Command::new(&self.command[0])
+            .args(&self.command[1..])
+            .arg(path)
+            .arg(iters.to_string())
+            .args(args.iter().map(i64::to_string))
+            .output()
+            .map_err(|e| format!("can't run `{}`: {e}", self.command[0]))?;
+        if !output.status.success() {
+            return Err(format!(
+                "exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut fields = stdout.split_whitespace();
+        let parsed = (
+            fields.next().and_then(|f| f.parse().ok()),
+            fields.next().and_then(|f| f.parse().ok()),
+        );
+        match parsed {
+            (Some(ns), Some(result)) => Ok((ns, result)),
+            _ => Err(format!("expected `<total-ns> <result>`, got `{}`", stdout.trim())),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// What each variant costs besides time: `--footprint`
+//
+// embedders picking a dispatch strategy weigh throughput against how much code it
+// drags in, how long the load step takes for the variants that translate the program
+// first (bytes, bundles, the jit), and how much memory a Vm holds on to. per variant:
+//
+//   code       bytes of machine code in the function, from the symbol table of the
+//              running binary (ELF only, `-` elsewhere or when it's stripped). for the
+//              fn-pointer table that's only the loop, its handlers are functions of
+//              their own
+//   load       translating the program into the form the variant runs, median of
+//              LOAD_RUNS, `-` for the ones that run the verified words as they are
+//   translated bytes that translation takes, machine code for the jit
+//   vm         Vm::resident_bytes of a Vm built with that strategy, `-` for variants
+//              the library doesn't offer as a DispatchKind
+//
+// plus what every run allocates on top, whatever the variant: its linear memory
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use rust_goto::{
+    DispatchKind, Runner, Variant, VerifiedProgram, Vm, analyze, bytes, run_central, to_bundles,
+    to_decoded, to_loop_bodies,
+};
+
+use crate::Workload;
+
+const LOAD_RUNS: usize = 51;
+
+pub fn run(workload: &Workload, variants: &[&Variant]) {
+    let Workload { description, program, .. } = workload;
+    let sizes = match SymbolSizes::of_current_exe() {
+        Ok(sizes) => Some(sizes),
+        Err(e) => {
+            println!("note: no code sizes, {e}");
+            None
+        }
+    };
+
+    println!("Footprint per variant");
+    println!("Program: {description} ({} instructions)\n", program.code().len());
+    println!("{:>24}  {:>8}  {:>10}  {:>10}  {:>10}", "variant", "code", "load", "translated", "vm");
+    for v in variants {
+        let code = sizes.as_ref().and_then(|s| s.code_size(v)).map_or("-".to_string(), |n| format!("{n} B"));
+        let (load, translated) = match load_step(v, program) {
+            Ok(Some((time, len))) => (format!("{:.1} us", time.as_secs_f64() * 1e6), format!("{len} B")),
+            Ok(None) => ("-".to_string(), "-".to_string()),
+            Err(e) => {
+                println!("{:>24}: skipped, {e}", v.label);
+                continue;
+            }
+        };
+        let vm = DispatchKind::by_name(v.name)
+            .and_then(|kind| Vm::with_dispatch(program.clone(), kind).ok())
+            .map_or("-".to_string(), |vm| format!("{} B", vm.resident_bytes()));
+        println!("{:>24}  {code:>8}  {load:>10}  {translated:>10}  {vm:>10}", v.label);
+    }
+    println!();
+    println!("every run also allocates {} B of linear memory", program.memory_words() * size_of::<i64>());
+}
+
+// median time and size of the translation the variant runs from, None if it runs the
+// verified words directly
+fn load_step(v: &Variant, program: &VerifiedProgram) -> Result<Option<(Duration, usize)>, String> {
+    let once = match v.run {
+        Runner::Words(_) => return Ok(None),
+        Runner::Bytes(_) => |program: &VerifiedProgram| -> Result<usize, String> {
+            Ok(bytes::to_bytes(program).map_err(|e| e.to_string())?.bytes().len())
+        },
+        Runner::Bundles(_) => |program: &VerifiedProgram| Ok(size_of_val(to_bundles(program).bundles())),
+        Runner::Decoded(_) => |program: &VerifiedProgram| Ok(size_of_val(to_decoded(program).code())),
+        Runner::Loops(_) => |program: &VerifiedProgram| Ok(size_of_val(to_loop_bodies(program).code())),
+        #[cfg(feature = "jit")]
+        Runner::Native(_) => |program: &VerifiedProgram| Ok(rust_goto::jit::compile(program).code_bytes()),
+    };
+    let len = once(program)?;
+    let mut times: Vec<Duration> = (0..LOAD_RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let _ = std::hint::black_box(once(std::hint::black_box(program)));
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    Ok(Some((times[LOAD_RUNS / 2], len)))
+}
+
+// function sizes by address, from the ELF symbol table of /proc/self/exe. addresses
+// in the file and in memory differ by the load offset (PIE), run_central is the
+// landmark that gives it. the c-goto variant is a Rust wrapper around rg_run_goto,
+// its row counts both
+pub struct SymbolSizes {
+    by_address: HashMap<u64, u64>,
+    bias: u64,
+    c_goto: u64,
+}
+
+impl SymbolSizes {
+    pub fn of_current_exe() -> Result<SymbolSizes, String> {
+        let file = std::fs::read("/proc/self/exe").map_err(|e| format!("can't read /proc/self/exe: {e}"))?;
+        let symbols = elf_functions(&file).ok_or("the binary isn't a 64-bit little-endian ELF with a symbol table")?;
+        let landmark = symbols
+            .iter()
+            .find(|(name, _, _)| analyze::is_symbol(name, "run_central"))
+            .ok_or("run_central isn't in the symbol table")?;
+        let bias = (run_central as *const () as u64).wrapping_sub(landmark.1);
+        let by_address = symbols.iter().map(|&(_, addr, size)| (addr, size)).collect();
+        let c_goto = symbols.iter().find(|(name, _, _)| *name == "rg_run_goto").map_or(0, |s| s.2);
+        Ok(SymbolSizes { by_address, bias, c_goto })
+    }
+
+    fn code_size(&self, v: &Variant) -> Option<u64> {
+        let addr = match v.run {
+            Runner::Words(f) => f as usize,
+            Runner::Bytes(f) => f as usize,
+            Runner::Bundles(f) => f as usize,
+            Runner::Decoded(f) => f as usize,
+            Runner::Loops(f) => f as usize,
+            #[cfg(feature = "jit")]
+            Runner::Native(f) => f as usize,
+        };
+        let size = self.function_size(addr)?;
+        Some(size + if v.name == "c-goto" { self.c_goto } else { 0 })
+    }
+
+    // size of the function at `addr` in this process, eg. `run_threaded_4 as usize`
+    pub fn function_size(&self, addr: usize) -> Option<u64> {
+        self.by_address.get(&(addr as u64).wrapping_sub(self.bias)).copied()
+    }
+}
+
+// (name, address, size) of every function symbol
+fn elf_functions(file: &[u8]) -> Option<Vec<(&str, u64, u64)>> {
+    let u16_at = |at: usize| Some(u16::from_le_bytes(file.get(at..at + 2)?.try_into().ok()?));
+    let u32_at = |at: usize| Some(u32::from_le_bytes(file.get(at..at + 4)?.try_into().ok()?));
+    let u64_at = |at: usize| Some(u64::from_le_bytes(file.get(at..at + 8)?.try_into().ok()?));
+    // 64-bit, little-endian
+    if file.get(..6)? != b"\x7fELF\x02\x01" {
+        return None;
+    }
+    let (shoff, shentsize, shnum) = (u64_at(0x28)? as usize, u16_at(0x3A)? as usize, u16_at(0x3C)? as usize);
+    let section = |i: usize| shoff + i * shentsize;
+    const SHT_SYMTAB: u32 = 2;
+    const STT_FUNC: u8 = 2;
+    let symtab = (0..shnum).map(section).find(|&s| u32_at(s + 4) == Some(SHT_SYMTAB))?;
+    let (offset, size, entsize) = (u64_at(symtab + 24)? as usize, u64_at(symtab + 32)? as usize, u64_at(symtab + 56)?);
+    let strtab = section(u32_at(symtab + 40)? as usize);
+    let strings = file.get(u64_at(strtab + 24)? as usize..)?;
+
+    let mut out = Vec::new();
+    for sym in (offset..offset + size).step_by(entsize.max(1) as usize) {
+        if file.get(sym + 4)? & 0xF != STT_FUNC {
+            continue;
+        }
+        let name = strings.get(u32_at(sym)? as usize..)?;
+        let name = std::str::from_utf8(&name[..name.iter().position(|&b| b == 0)?]).ok()?;
+        out.push((name, u64_at(sym + 8)?, u64_at(sym + 16)?));
+    }
+    Some(out)
+}
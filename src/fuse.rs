@@ -0,0 +1,202 @@
+// Superinstructions: common opcode pairs fused into one instruction word
+//
+// every instruction pays for a dispatch (an indirect jump, a compare tree or a call,
+// depending on the variant), so the cheapest dispatch is the one that doesn't happen.
+// `fuse` rewrites the pairs that make up the kernels' hot loops into one opcode doing
+// the work of both:
+//
+//   MUL d1 a1 b1 ; SUB d2 a2 b2      MULSUB      sum-poly's i*i - i
+//   ADD d1 a1 b1 ; ADD d2 a2 b2      ADDADD      sum-poly, poly:D accumulating
+//   DEC r        ; JMPNZ r target    DECJNZ r    the back edge of every counted loop
+//
+// nothing moves: the fused word replaces the first instruction of the pair and the
+// second one stays where it was, as the fused word's "second half". the fused handler
+// does both and steps over it, a jump landing on it runs it as the plain instruction it
+// still is. so every pc and every target is the same as before, no relocation, and the
+// entry, names and call stubs of a Program stay valid (Program::fused). the verifier
+// checks every fused word is followed by its second half (VerifyError::BrokenPair)
+//
+// ADDADD and MULSUB need six registers in three bytes: the first op's dst/a/b go in the
+// low nibbles, the second op's in the high ones. there are 16 registers, they always
+// fit. DECJNZ is DEC and JMPNZ on the same register, with JMPNZ's target
+//
+// pairs are taken left to right without overlapping, so in ADD ADD ADD only the first
+// two fuse, and fusing fused code changes nothing. `fuse_guided` picks from the same
+// candidates by a profile instead (--profile counts how often each pair ran back to
+// back): pairs that never ran stay as they are, and where two overlap the hotter wins
+
+use crate::{OP_ADD, OP_ADDADD, OP_DEC, OP_DECJNZ, OP_JMPNZ, OP_MUL, OP_MULSUB, OP_SUB, encode};
+
+fn split(word: u32) -> (u8, u8, u8, u8) {
+    (word as u8, (word >> 8) as u8, (word >> 16) as u8, (word >> 24) as u8)
+}
+
+// the same code with every pair in the table above fused, same length, same pcs
+pub fn fuse(code: &[u32]) -> Vec<u32> {
+    let mut out = code.to_vec();
+    let mut pc = 0;
+    while pc + 1 < code.len() {
+        // already fused, its second half isn't free to pair with anything
+        if halves(code[pc]).is_some() {
+            pc += 2;
+            continue;
+        }
+        match fuse_pair(code[pc], code[pc + 1]) {
+            Some(fused) => {
+                out[pc] = fused;
+                pc += 2;
+            }
+            None => pc += 1,
+        }
+    }
+    out
+}
+
+// the code as it was before `fuse`, every fused word back to the first half of its pair
+pub fn unfuse(code: &[u32]) -> Vec<u32> {
+    code.iter().map(|&word| halves(word).map_or(word, |[first, _]| first)).collect()
+}
+
+// the same code with the pairs that ran back to back fused, hottest first.
+// `back_to_back[pc]` is how often the instruction at pc+1 ran right after the one at pc
+// (0 past the end of the slice)
+pub fn fuse_guided(code: &[u32], back_to_back: &[u64]) -> Vec<u32> {
+    let count = |pc: usize| back_to_back.get(pc).copied().unwrap_or(0);
+    let free = |pc: usize| halves(code[pc]).is_none() && (pc == 0 || halves(code[pc - 1]).is_none());
+    let mut candidates: Vec<usize> = (0..code.len().saturating_sub(1))
+        .filter(|&pc| count(pc) > 0 && free(pc) && free(pc + 1) && fuse_pair(code[pc], code[pc + 1]).is_some())
+        .collect();
+    candidates.sort_by_key(|&pc| (std::cmp::Reverse(count(pc)), pc));
+
+    let mut out = code.to_vec();
+    let mut taken = vec![false; code.len()];
+    for pc in candidates {
+        if taken[pc] || taken[pc + 1] {
+            continue;
+        }
+        out[pc] = fuse_pair(code[pc], code[pc + 1]).expect("a candidate fuses");
+        taken[pc] = true;
+        taken[pc + 1] = true;
+    }
+    out
+}
+
+// the superinstruction for `op1` followed by `op2`, if fuse has one. DEC and JMPNZ also
+// need the same register, see fuse_pair
+pub fn superinstruction(op1: u8, op2: u8) -> Option<u8> {
+    match (op1, op2) {
+        (OP_DEC, OP_JMPNZ) => Some(OP_DECJNZ),
+        (OP_ADD, OP_ADD) => Some(OP_ADDADD),
+        (OP_MUL, OP_SUB) => Some(OP_MULSUB),
+        _ => None,
+    }
+}
+
+fn fuse_pair(first: u32, second: u32) -> Option<u32> {
+    let (op1, d1, a1, b1) = split(first);
+    let (op2, d2, a2, b2) = split(second);
+    let fused = match superinstruction(op1, op2)? {
+        OP_DECJNZ if d1 == d2 => return Some(encode(OP_DECJNZ, d1, a2, b2)),
+        OP_DECJNZ => return None,
+        fused => fused,
+    };
+    // unverified code can name registers that don't exist, those stay as they are
+    if [d1, a1, b1, d2, a2, b2].iter().any(|&r| r > 0xF) {
+        return None;
+    }
+    Some(encode(fused, d1 | (d2 << 4), a1 | (a2 << 4), b1 | (b2 << 4)))
+}
+
+// the plain instructions a fused word stands for, None if it isn't one. the second
+// one is what has to follow it in the code
+pub fn halves(word: u32) -> Option<[u32; 2]> {
+    let (op, dst, a, b) = split(word);
+    let (lo, hi) = (|r: u8| r & 0xF, |r: u8| r >> 4);
+    let (first, second) = match op {
+        OP_DECJNZ => return Some([encode(OP_DEC, dst, 0, 0), encode(OP_JMPNZ, dst, a, b)]),
+        OP_ADDADD => (OP_ADD, OP_ADD),
+        OP_MULSUB => (OP_MUL, OP_SUB),
+        _ => return None,
+    };
+    Some([encode(first, lo(dst), lo(a), lo(b)), encode(second, hi(dst), hi(a), hi(b))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OP_HALT, VARIANTS, Vm, VerifyError, kernels, verify};
+
+    #[test]
+    fn fused_kernels_agree_with_unfused_everywhere() {
+        for name in ["sum-poly", "poly:4", "loop-nest:3", "fsm:7", "fib", "sieve", "array-sum", "collatz"] {
+            let (code, args) = kernels::kernel(name, 50).unwrap();
+            let fused = fuse(&code);
+            assert_eq!(unfuse(&fused), code, "{name}");
+            assert_eq!(fuse(&fused), fused, "{name}");
+            let expected = crate::run_central(&verify(&code).unwrap(), &args);
+            let program = verify(&fused).unwrap();
+            for v in VARIANTS {
+                assert_eq!(v.exec(&program, &args).unwrap(), expected, "{name} on {}", v.name);
+            }
+            let mut vm = Vm::new(program);
+            vm.reset(&args).unwrap();
+            assert_eq!(vm.resume(u64::MAX), crate::Status::Halted(expected), "{name} stepped");
+        }
+        // sum-poly's loop body is three pairs
+        let fused = fuse(&kernels::sum_poly());
+        let ops: Vec<u8> = fused.iter().map(|&w| w as u8).collect();
+        assert_eq!(&ops[3..9], [OP_MULSUB, OP_SUB, OP_ADDADD, OP_ADD, OP_DECJNZ, OP_JMPNZ]);
+    }
+
+    #[test]
+    fn jumps_into_the_second_half_run_it_plain() {
+        // r3 = r1 + r2 and r4 += r3 through the whole pair, then the loop jumps straight
+        // to its second half for r4 += r3 once more
+        let code = [
+            encode(OP_ADD, 3, 1, 2),
+            encode(OP_ADD, 4, 4, 3), // pc = 1
+            encode(OP_DEC, 5, 0, 0),
+            encode(OP_JMPNZ, 5, 1, 0),
+            encode(OP_HALT, 4, 0, 0),
+        ];
+        let fused = fuse(&code);
+        assert_eq!(fused[0] as u8, OP_ADDADD);
+        let program = verify(&fused).unwrap();
+        for v in VARIANTS {
+            assert_eq!(v.exec(&program, &[0, 1, 2, 0, 0, 2]).unwrap(), 6, "{}", v.name);
+        }
+    }
+
+    #[test]
+    fn guided_fusing_follows_the_profile() {
+        // everything ran as often as everything else: the same as the fixed rules
+        for name in ["sum-poly", "poly:4", "loop-nest:3", "fsm:7", "fib", "sieve", "collatz"] {
+            let (code, _) = kernels::kernel(name, 50).unwrap();
+            assert_eq!(fuse_guided(&code, &vec![1; code.len()]), fuse(&code), "{name}");
+        }
+        // ADD ADD ADD: left to right fuses the first two, the profile says the last two
+        // are the loop
+        let code = [
+            encode(OP_ADD, 1, 1, 2),
+            encode(OP_ADD, 3, 3, 1), // pc = 1
+            encode(OP_ADD, 4, 4, 3),
+            encode(OP_HALT, 4, 0, 0),
+        ];
+        let guided = fuse_guided(&code, &[1, 100, 0, 0]);
+        assert_eq!(guided.iter().map(|&w| w as u8).collect::<Vec<_>>(), [OP_ADD, OP_ADDADD, OP_ADD, OP_HALT]);
+        assert_eq!(unfuse(&guided), code);
+        // and pairs that never ran stay apart
+        assert_eq!(fuse_guided(&code, &[0, 0, 0, 0]), code);
+        assert_eq!(fuse_guided(&code, &[]), code);
+    }
+
+    #[test]
+    fn the_verifier_wants_the_second_half_in_place() {
+        let mut code = fuse(&kernels::sum_poly());
+        code[4] = encode(OP_SUB, 5, 4, 4);
+        assert_eq!(verify(&code).unwrap_err(), VerifyError::BrokenPair { pc: 3 });
+        // registers past r15 don't fit a nibble
+        let code = [encode(OP_ADD, 1, 2, 3), encode(OP_ADD, 16, 2, 3), encode(OP_HALT, 1, 0, 0)];
+        assert_eq!(fuse(&code), code);
+    }
+}
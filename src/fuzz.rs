@@ -0,0 +1,529 @@
+// Differential fuzzing: random programs through every variant, against Vm::step
+//
+// the handlers are duplicated by hand (handle!, the preload arms, the table functions,
+// the byte handlers, goto.c, the jit), so one of them getting an opcode slightly wrong
+// is the easiest bug to write in this crate and the
+// per-opcode tests only catch it for the operands they try. the arithmetic of the
+// register ops comes from one place now (semantics.rs), but which registers a handler
+// reads, where it writes and how it moves the pc are still each handler's own, and
+// goto.c and the jit have their own arithmetic too. this throws generated programs at
+// all of them and compares the results with the reference: Vm::step, which is written
+// against Opcode, bounds-checks everything and shares nothing with the handlers but
+// the semantics table
+//
+// a generated program is verifiable by construction:
+//
+//   - loops are counted, LOADI c n ... DEC c ; JMPNZ c head, with one counter register
+//     per nesting level that nothing else writes, so every loop ends
+//   - every other branch (JMPNZ, JEQ, JNE, JLT, JGE, JMP) goes forward, over a block
+//   - CALLs go to one subroutine after the HALT that ends in RET and doesn't loop
+//   - memory addresses come from LOADIs, mostly inside the memory but not always, and
+//     PUSH/POP don't have to balance: the traps have to agree as much as the math
+//
+// the variants only return the halt value, so the program ends by folding every
+// register into r0 and halting with that (or running off the end into an implicit
+// HALT r0, see FallOffEnd): a register one variant got wrong changes the
+// result even when nothing read it afterwards. run_hooked sees the registers at the
+// HALT too, those are compared with the reference's directly. every program also runs
+// through fuse, so the superinstruction handlers get the same treatment
+//
+// `run` checks a number of programs, `soak` as many as fit in a time budget, for
+// leaving it on overnight: a divergence that takes a billion programs to show up
+
+use std::fmt::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::blocks::{LazyBlocks, run_blocks};
+use crate::{
+    ExecHook, FallOffEnd, Instruction, NREGS, OP_ADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DIV, OP_HALT, OP_INC,
+    OP_JEQ, OP_JGE, OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RET, OP_STORE, OP_SUB, Program, Status, THREADED_DEPTHS, VARIANTS, Variant,
+    VerifiedProgram, Vm, encode, run_hooked,
+};
+
+// decoded instructions for run_blocks, a handful of blocks
+const BLOCK_CAPACITY: usize = 16;
+
+// r0..r11 hold data, r12..r14 count loops (one per nesting level), r15 holds addresses
+const DATA_REGS: u8 = 12;
+const COUNTERS: [u8; 3] = [12, 13, 14];
+const ADDR_REG: u8 = 15;
+// small, so a run doesn't spend its time zeroing memory, and addresses hit it often
+const MEMORY_WORDS: usize = 64;
+// far more than any generated program runs, 3 nested loops of at most 4 trips each
+const FUEL: u64 = 10_000_000;
+// programs between looks at the clock in `soak`
+const SOAK_BATCH: u64 = 100;
+
+// xorshift64*, reproducible from the seed, which is all a fuzzer needs
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // 0..n
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn data_reg(&mut self) -> u8 {
+        self.below(DATA_REGS as u64) as u8
+    }
+
+    // mostly small, sometimes an extreme, so overflow and division edges come up
+    fn value(&mut self) -> i64 {
+        match self.below(8) {
+            0 => [i64::MIN, i64::MAX, -1, 0][self.below(4) as usize],
+            1 => self.next_u64() as i64,
+            _ => self.below(200) as i64 - 100,
+        }
+    }
+}
+
+// one generated program and the args it runs with
+pub struct Case {
+    pub seed: u64,
+    pub program: Program,
+    pub args: Vec<i64>,
+}
+
+struct Builder {
+    rng: Rng,
+    code: Vec<u32>,
+    // pcs of CALLs, pointed at the subroutine once it exists
+    calls: Vec<usize>,
+    // PUSHes minus POPs emitted so far in the current block. POPs stay below it and a
+    // loop or branch body starts from zero, so underflows only happen when a branch
+    // skipped a PUSH, often enough to test without drowning everything else
+    pushed: usize,
+}
+
+impl Builder {
+    fn emit(&mut self, word: u32) -> usize {
+        self.code.push(word);
+        self.code.len() - 1
+    }
+
+    fn set_target(&mut self, pc: usize, target: usize) {
+        let target = target as u32;
+        self.code[pc] = (self.code[pc] & 0xFFFF) | (target << 16);
+    }
+
+    fn loadi(&mut self, reg: u8, imm: u64) {
+        self.emit(encode(OP_LOADI, reg, imm as u8, (imm >> 8) as u8));
+    }
+
+    fn block(&mut self, len: u64, depth: usize, in_subroutine: bool) {
+        for _ in 0..len {
+            match self.rng.below(12) {
+                // a counted loop around a smaller block
+                0 | 1 if depth < COUNTERS.len() && !in_subroutine => {
+                    let counter = COUNTERS[depth];
+                    let trips = 1 + self.rng.below(4);
+                    self.loadi(counter, trips);
+                    let head = self.code.len();
+                    let body = 1 + self.rng.below(6);
+                    self.nested(body, depth + 1, in_subroutine);
+                    self.emit(encode(OP_DEC, counter, 0, 0));
+                    let back = self.emit(encode(OP_JMPNZ, counter, 0, 0));
+                    self.set_target(back, head);
+                }
+                // a forward branch over a smaller block
+                2 => {
+                    let ops = [OP_JMPNZ, OP_JEQ, OP_JNE, OP_JLT, OP_JGE, OP_JMP];
+                    let op = ops[self.rng.below(ops.len() as u64) as usize];
+                    let reg = self.rng.data_reg();
+                    if op != OP_JMPNZ && op != OP_JMP && self.rng.below(2) == 0 {
+                        let (a, b) = (self.rng.data_reg(), self.rng.data_reg());
+                        self.emit(encode(OP_CMP, reg, a, b));
+                    }
+                    let branch = self.emit(encode(op, reg, 0, 0));
+                    let skipped = 1 + self.rng.below(4);
+                    self.nested(skipped, depth, in_subroutine);
+                    let target = self.code.len();
+                    self.set_target(branch, target);
+                }
+                3 => self.memory_op(),
+                4 if !in_subroutine => {
+                    let call = self.emit(encode(OP_CALL, 0, 0, 0));
+                    self.calls.push(call);
+                }
+                5 => {
+                    let op = if self.pushed > 0 && self.rng.below(2) == 0 { OP_POP } else { OP_PUSH };
+                    self.pushed = if op == OP_POP { self.pushed - 1 } else { self.pushed + 1 };
+                    let reg = self.rng.data_reg();
+                    self.emit(encode(op, reg, 0, 0));
+                }
+                _ => self.alu_op(),
+            }
+        }
+    }
+
+    // a block that may run more than once or not at all, with its own PUSH/POP balance
+    fn nested(&mut self, len: u64, depth: usize, in_subroutine: bool) {
+        let outer = std::mem::take(&mut self.pushed);
+        self.block(len, depth, in_subroutine);
+        self.pushed = outer;
+    }
+
+    fn alu_op(&mut self) {
+        let d = self.rng.data_reg();
+        match self.rng.below(14) {
+            0 => {
+                let imm = self.rng.below(1 << 16);
+                self.loadi(d, imm);
+            }
+            1 => {
+                self.emit(encode(OP_INC, d, 0, 0));
+            }
+            2 => {
+                self.emit(encode(OP_DEC, d, 0, 0));
+            }
+            n => {
+                let ops = [OP_ADD, OP_SUB, OP_MUL, OP_DIV, OP_MOD, OP_MOV, OP_CMP, OP_ADD, OP_CLZ, OP_CTZ, OP_POPCNT];
+                let (a, b) = (self.rng.data_reg(), self.rng.data_reg());
+                self.emit(encode(ops[n as usize - 3], d, a, b));
+            }
+        }
+    }
+
+    // an address at the end of memory now and then, so the bounds checks get a turn
+    fn memory_op(&mut self) {
+        let words = MEMORY_WORDS as u64;
+        let addr = if self.rng.below(8) == 0 { words - 4 + self.rng.below(8) } else { self.rng.below(words - 8) };
+        self.loadi(ADDR_REG, addr);
+        let reg = self.rng.data_reg();
+        let offset = self.rng.below(4) as u8;
+        match self.rng.below(4) {
+            0 => {
+                self.emit(encode(OP_LOAD, reg, ADDR_REG, offset));
+            }
+            1 => {
+                self.emit(encode(OP_STORE, reg, ADDR_REG, offset));
+            }
+            op => {
+                // MEMCPY to ADDR_REG from `from`, MEMSET at ADDR_REG with `reg`, `len` words
+                let (from, len) = (self.rng.data_reg(), self.rng.data_reg());
+                let (count, source) = (self.rng.below(8), self.rng.below(words - 8));
+                self.loadi(len, count);
+                if op == 2 {
+                    self.loadi(from, source);
+                    self.emit(encode(OP_MEMCPY, ADDR_REG, from, len));
+                } else {
+                    self.emit(encode(OP_MEMSET, ADDR_REG, reg, len));
+                }
+            }
+        }
+    }
+}
+
+pub fn generate(seed: u64) -> Case {
+    let mut b = Builder { rng: Rng::new(seed), code: Vec::new(), calls: Vec::new(), pushed: 0 };
+    for reg in 0..DATA_REGS {
+        if b.rng.below(2) == 0 {
+            let imm = b.rng.below(1 << 16);
+            b.loadi(reg, imm);
+        }
+    }
+    let len = 4 + b.rng.below(24);
+    b.block(len, 0, false);
+    // r0 = fold of every other register, the counters are free by now
+    let k = COUNTERS[0];
+    b.loadi(k, 31);
+    for reg in (1..NREGS as u8).filter(|&r| r != k) {
+        b.emit(encode(OP_MUL, 0, 0, k));
+        b.emit(encode(OP_ADD, 0, 0, reg));
+    }
+    // with nothing after it, some leave the HALT out and run off the end into the
+    // implicit one (FallOffEnd::Halt)
+    let implicit_halt = b.calls.is_empty() && b.rng.below(4) == 0;
+    if !implicit_halt {
+        b.emit(encode(OP_HALT, 0, 0, 0));
+    }
+    if !b.calls.is_empty() {
+        let subroutine = b.code.len();
+        let len = 1 + b.rng.below(6);
+        b.nested(len, 0, true);
+        b.emit(encode(OP_RET, 0, 0, 0));
+        for call in std::mem::take(&mut b.calls) {
+            b.set_target(call, subroutine);
+        }
+    }
+
+    let mut program = Program::new(b.code).expect("the generator only names registers that exist");
+    program.set_memory_words(MEMORY_WORDS).expect("less than the default");
+    if implicit_halt {
+        program.set_fall_off_end(FallOffEnd::Halt);
+    }
+    if b.rng.below(3) == 0 {
+        let words: Vec<i64> = (0..1 + b.rng.below(8)).map(|_| b.rng.value()).collect();
+        let start = b.rng.below((MEMORY_WORDS - words.len()) as u64) as usize;
+        let read_only = b.rng.below(2) == 0;
+        program.add_segment(start, words, read_only).expect("the segment fits");
+    }
+    let args = (0..b.rng.below(5)).map(|_| b.rng.value()).collect();
+    Case { seed, program, args }
+}
+
+// what didn't agree, with everything needed to look at it again
+#[derive(Debug)]
+pub struct Mismatch {
+    pub seed: u64,
+    // the variant, or "registers at HALT"
+    pub what: String,
+    pub fused: bool,
+    pub expected: String,
+    pub got: String,
+    pub code: Vec<u32>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fused = if self.fused { ", fused" } else { "" };
+        writeln!(f, "seed {}{fused}: {} got {}, the reference {}", self.seed, self.what, self.got, self.expected)?;
+        write_listing(f, &self.code)
+    }
+}
+
+fn write_listing(out: &mut impl Write, code: &[u32]) -> fmt::Result {
+    for (pc, &word) in code.iter().enumerate() {
+        let ins = Instruction::decode(word).expect("generated code decodes");
+        writeln!(out, "  {pc:>4}  {:<10} {:>3} {:>3} {:>3}", format!("{:?}", ins.op), ins.dst, ins.a, ins.b)?;
+    }
+    Ok(())
+}
+
+// what a program did in the reference: its result, the registers if it got to a HALT
+// (a trap leaves them wherever the trapping instruction found them), and how many
+// instructions it took
+pub struct Outcome {
+    pub result: i64,
+    pub regs: Option<[i64; NREGS]>,
+    pub retired: u64,
+}
+
+pub fn reference(program: &VerifiedProgram, args: &[i64]) -> Outcome {
+    let mut vm = Vm::new(program.clone());
+    vm.reset(args).expect("at most 4 args");
+    match vm.resume(FUEL) {
+        Status::Halted(result) => {
+            let code = program.program().code();
+            // an implicit HALT leaves pc at the end instead of after it
+            let implicit = vm.pc() == code.len() && program.program().fall_off_end() == FallOffEnd::Halt;
+            let halted = implicit || vm.pc() > 0 && code[vm.pc() - 1] as u8 == OP_HALT;
+            Outcome { result, regs: halted.then(|| *vm.regs()), retired: vm.retired() }
+        }
+        status => panic!("generated programs halt, this one stopped with {status:?}"),
+    }
+}
+
+// the registers at HALT, for comparing with the reference's
+#[derive(Default)]
+struct FinalRegs(Option<[i64; NREGS]>);
+
+impl ExecHook for FinalRegs {
+    fn post(&mut self, _pc: usize, op: u8, regs: &[i64; NREGS]) {
+        if op == OP_HALT {
+            self.0 = Some(*regs);
+        }
+    }
+}
+
+// runs the case as generated and fused through `variants` and run_hooked, what the
+// reference did if everything agreed with it
+pub fn check_against(case: &Case, variants: &[Variant]) -> Result<Outcome, Box<Mismatch>> {
+    let plain = case.program.clone().verify().expect("generated programs verify");
+    let expected = reference(&plain, &case.args);
+    let fused = case.program.fused().verify().expect("fusing keeps the control flow verified");
+    for (program, is_fused) in [(&plain, false), (&fused, true)] {
+        let mismatch = |what: &str, expected: String, got: String| {
+            let what = what.to_string();
+            let code = program.program().code().to_vec();
+            Box::new(Mismatch { seed: case.seed, what, fused: is_fused, expected, got, code })
+        };
+        for v in variants {
+            // the byte variants can't hold everything, generated programs are far from it
+            let Ok(got) = v.exec(program, &case.args) else { continue };
+            if got != expected.result {
+                return Err(mismatch(v.label, expected.result.to_string(), got.to_string()));
+            }
+        }
+        // run_threaded_4 and up aren't variants, but they're handle! in new places. through
+        // black_box, or the unrolled loop calls them directly and the threading check
+        // counts that as a specialized harness
+        for (depth, &f) in THREADED_DEPTHS.iter().enumerate() {
+            let got = std::hint::black_box(f)(program, &case.args);
+            if got != expected.result {
+                let what = format!("run_threaded_{}", depth + 1);
+                return Err(mismatch(&what, expected.result.to_string(), got.to_string()));
+            }
+        }
+        // block translation with a cache too small to hold the program, so blocks get
+        // evicted and translated again, twice so the second run starts from a warm cache
+        let blocks = LazyBlocks::new(program.clone(), BLOCK_CAPACITY);
+        for run in ["run_blocks", "run_blocks, warm"] {
+            let got = std::hint::black_box(run_blocks)(&blocks, &case.args);
+            if got != expected.result {
+                return Err(mismatch(run, expected.result.to_string(), got.to_string()));
+            }
+        }
+        let mut hook = FinalRegs::default();
+        run_hooked(program, &case.args, &mut hook);
+        if hook.0 != expected.regs {
+            return Err(mismatch("registers at HALT", format!("{:?}", expected.regs), format!("{:?}", hook.0)));
+        }
+    }
+    Ok(expected)
+}
+
+pub fn check(case: &Case) -> Result<Outcome, Box<Mismatch>> {
+    check_against(case, VARIANTS)
+}
+
+// what has to hold on top of agreeing with the reference, the symptoms a bad read in
+// an unchecked path would leave behind: the reference run again on the same Vm after a
+// reset ends up exactly where it did the first time (nothing leaks from one run into
+// the next), and one variant per program, in turn, gives the same result on a second
+// run of the same code
+fn check_invariants(case: &Case, expected: &Outcome) -> Result<(), Box<Mismatch>> {
+    let program = case.program.clone().verify().expect("generated programs verify");
+    let mismatch = |what: String, expected: String, got: String| {
+        let code = program.program().code().to_vec();
+        Box::new(Mismatch { seed: case.seed, what, fused: false, expected, got, code })
+    };
+    let mut vm = Vm::new(program.clone());
+    let run = |vm: &mut Vm| {
+        vm.reset(&case.args).expect("at most 4 args");
+        (vm.resume(FUEL), vm.state().clone())
+    };
+    let (first, second) = (run(&mut vm), run(&mut vm));
+    if first != second {
+        // the second run's status and what it left differently, on one line
+        let got = format!("{:?}, {}", second.0, first.1.diff(&second.1)).replace('\n', ", ");
+        return Err(mismatch("the reference, reset and run again".to_string(), format!("{:?}", first.0), got));
+    }
+    let v = &VARIANTS[(case.seed % VARIANTS.len() as u64) as usize];
+    if let Ok(got) = v.exec(&program, &case.args)
+        && got != expected.result
+    {
+        return Err(mismatch(format!("{}, run again", v.label), expected.result.to_string(), got.to_string()));
+    }
+    Ok(())
+}
+
+// totals over a fuzzing run
+#[derive(Default, Debug)]
+pub struct Summary {
+    pub programs: u64,
+    pub instructions: u64,
+    // programs that stopped with -1, out of `programs`
+    pub trapped: u64,
+}
+
+impl Summary {
+    // checks the program `seed` generates, everywhere, and counts it
+    fn check_seed(&mut self, seed: u64) -> Result<(), Box<Mismatch>> {
+        let case = generate(seed);
+        let outcome = check(&case)?;
+        check_invariants(&case, &outcome)?;
+        self.instructions += outcome.retired;
+        self.trapped += outcome.regs.is_none() as u64;
+        self.programs += 1;
+        Ok(())
+    }
+}
+
+// `count` programs from seeds `first_seed`.., stops at the first mismatch
+pub fn run(first_seed: u64, count: u64) -> Result<Summary, Box<Mismatch>> {
+    let mut summary = Summary::default();
+    for seed in first_seed..first_seed.saturating_add(count) {
+        summary.check_seed(seed)?;
+    }
+    Ok(summary)
+}
+
+// `run` for `duration` instead of a count, the overnight version: seeds from
+// `first_seed` on until the time is up, with the running totals and the time so far
+// handed to `progress` about every `every`. a mismatch stops it like it stops `run`,
+// and its seed reproduces it there
+pub fn soak(
+    first_seed: u64,
+    duration: Duration,
+    every: Duration,
+    mut progress: impl FnMut(&Summary, Duration),
+) -> Result<Summary, Box<Mismatch>> {
+    let start = Instant::now();
+    let mut summary = Summary::default();
+    let mut next_report = every;
+    let mut seed = first_seed;
+    while start.elapsed() < duration {
+        // the clock only every so often, a program takes microseconds
+        for _ in 0..SOAK_BATCH {
+            summary.check_seed(seed)?;
+            seed = seed.wrapping_add(1);
+        }
+        if start.elapsed() >= next_report {
+            progress(&summary, start.elapsed());
+            next_report += every;
+        }
+    }
+    Ok(summary)
+}
+
+// the program and args a seed generates, for `fuzz --show <seed>`
+pub fn listing(case: &Case) -> String {
+    let mut out = format!("seed {}, args {:?}\n", case.seed, case.args);
+    write_listing(&mut out, case.program.code()).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Runner;
+
+    #[test]
+    fn generated_programs_agree_everywhere() {
+        let summary = run(0, 300).unwrap_or_else(|m| panic!("{m}"));
+        // the traps get exercised, and so do programs that make it to the end
+        assert!(summary.trapped > 0 && summary.trapped < summary.programs, "{summary:?}");
+        assert_eq!(generate(7).program.code(), generate(7).program.code());
+    }
+
+    #[test]
+    fn soaking_reports_as_it_goes() {
+        let mut reports = Vec::new();
+        let summary = soak(1000, Duration::from_millis(300), Duration::from_millis(100), |s, elapsed| {
+            reports.push((s.programs, elapsed));
+        })
+        .unwrap_or_else(|m| panic!("{m}"));
+        assert!(summary.programs >= SOAK_BATCH && summary.programs % SOAK_BATCH == 0, "{summary:?}");
+        assert!(!reports.is_empty() && reports.windows(2).all(|w| w[0].0 < w[1].0), "{reports:?}");
+    }
+
+    #[test]
+    fn a_wrong_handler_gets_caught() {
+        // a central loop that subtracts for the first ADD it sees, fused or not
+        fn wrong_add(program: &VerifiedProgram, args: &[i64]) -> i64 {
+            let mut code = crate::fuse::unfuse(program.code());
+            if let Some(pc) = code.iter().position(|&w| w as u8 == OP_ADD) {
+                code[pc] = (code[pc] & !0xFF) | OP_SUB as u32;
+            }
+            crate::run_central(&crate::verify(&code).unwrap(), args)
+        }
+        let broken = [Variant { name: "broken", label: "broken", run: Runner::Words(wrong_add) }];
+        let caught = (0..50).find_map(|seed| check_against(&generate(seed), &broken).err());
+        let mismatch = caught.expect("some program has an ADD that matters");
+        assert_eq!(mismatch.what, "broken");
+        assert!(mismatch.to_string().contains(&format!("seed {}", mismatch.seed)));
+    }
+}
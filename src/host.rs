@@ -0,0 +1,130 @@
+// What a TRAP handler gets to touch
+//
+// a handler is host code running in the middle of a guest, and a slip there (an index
+// one past the registers, a write into the constant pool, a pc set to nowhere) would
+// corrupt the guest in ways verification promised couldn't happen. so it doesn't get
+// the machine, it gets a HostCtx: the TRAP's immediate and where it is, the registers
+// through checked accessors, memory through loads and stores that trap like the
+// guest's own would, read-only segments included, and the value stack to look at. the
+// pc, the call stack and everything else of the Vm stay out of reach, the TrapAction a
+// handler returns is its only say in where the guest goes next
+//
+// host functions follow the calling convention (ARG_REGS): arguments come in
+// arg0..arg7, results go back from ret (r0) up, so a function returning a pair writes
+// r0 and r1
+//
+//   vm.set_trap_handler(|ctx| {
+//       let [x, y] = ctx.args::<2>();
+//       ctx.set_results([x / y, x % y]);
+//       TrapAction::Continue
+//   });
+//
+// anything bigger goes through memory by address, `ctx.read`/`ctx.write` with
+// marshal's layout
+
+use crate::marshal::{Marshal, MarshalError, Reader, Writer};
+use crate::memory::{DirtyPages, Memory};
+use crate::{ARG_REGS, NREGS, TrapKind};
+
+pub struct HostCtx<'a> {
+    imm: u16,
+    pc: usize,
+    retired: u64,
+    regs: &'a mut [i64; NREGS],
+    mem: &'a mut Memory,
+    // what the handler wrote, for the next reset
+    dirty: &'a mut DirtyPages,
+    stack: &'a [i64],
+}
+
+impl<'a> HostCtx<'a> {
+    pub(crate) fn new(
+        imm: u16,
+        pc: usize,
+        retired: u64,
+        regs: &'a mut [i64; NREGS],
+        mem: &'a mut Memory,
+        dirty: &'a mut DirtyPages,
+        stack: &'a [i64],
+    ) -> HostCtx<'a> {
+        HostCtx { imm, pc, retired, regs, mem, dirty, stack }
+    }
+
+    // the TRAP's 16-bit immediate, which host function the guest means
+    pub fn imm(&self) -> u16 {
+        self.imm
+    }
+
+    // where the TRAP is
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    // instructions retired before this TRAP
+    pub fn retired(&self) -> u64 {
+        self.retired
+    }
+
+    // what PUSH left, the bottom first
+    pub fn stack(&self) -> &[i64] {
+        self.stack
+    }
+
+    // arg0..arg{N-1}, more than ARG_REGS doesn't compile
+    pub fn args<const N: usize>(&self) -> [i64; N] {
+        const { assert!(N <= ARG_REGS, "more arguments than argument registers") };
+        self.regs[..N].try_into().expect("N registers")
+    }
+
+    // `values` into ret and up, the same registers as the arguments
+    pub fn set_results<const M: usize>(&mut self, values: [i64; M]) {
+        const { assert!(M <= ARG_REGS, "more results than argument registers") };
+        self.regs[..M].copy_from_slice(&values);
+    }
+
+    // any register, None past the last one
+    pub fn reg(&self, r: usize) -> Option<i64> {
+        self.regs.get(r).copied()
+    }
+
+    // false past the last register, which stays as it was
+    pub fn set_reg(&mut self, r: usize, value: i64) -> bool {
+        let Some(slot) = self.regs.get_mut(r) else { return false };
+        *slot = value;
+        true
+    }
+
+    // the word at `addr`, MemoryOutOfBounds past the end like a guest LOAD
+    pub fn load(&self, addr: usize) -> Result<i64, TrapKind> {
+        self.mem.get(addr).copied().ok_or(TrapKind::MemoryOutOfBounds)
+    }
+
+    // like a guest STORE: WriteProtected in a read-only segment, MemoryOutOfBounds
+    // past the end, and nothing written either way
+    pub fn store(&mut self, addr: usize, value: i64) -> Result<(), TrapKind> {
+        *self.mem.get_mut(addr)? = value;
+        self.dirty.mark(addr);
+        Ok(())
+    }
+
+    // all of memory, to read
+    pub fn memory(&self) -> &[i64] {
+        self.mem.words()
+    }
+
+    // marshal::read from guest memory
+    pub fn read<T: Marshal>(&self, at: usize) -> Result<T, MarshalError> {
+        T::read(&mut Reader::new(self.mem.words(), at))
+    }
+
+    // marshal::write into guest memory, stopping at a read-only word with
+    // MarshalError::WriteProtected. returns how many words it took
+    pub fn write<T: Marshal>(&mut self, at: usize, value: &T) -> Result<usize, MarshalError> {
+        let (words, read_only) = self.mem.split();
+        let mut w = Writer::protected(words, read_only, at);
+        let written = value.write(&mut w);
+        let end = w.at();
+        self.dirty.mark_range(at..end);
+        written.map(|()| end - at)
+    }
+}
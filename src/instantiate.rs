@@ -0,0 +1,154 @@
+// What loading a program costs: `--instantiate`
+//
+// everything else here times execution, with translation kept out of the timed region
+// on purpose. an embedder that loads a fresh program per request pays for the other
+// half too: the source gets assembled, the words checked, the verifier walks every
+// target, and the variant translates it into its own form before the first
+// instruction runs. this times each of those stages on the workload, in memory (no
+// file reads), and prints them as MB/s of input and million instructions per second,
+// so "the verifier" or "the byte translation" gets a number instead of a shrug
+//
+//   assemble    asm::assemble of the program's disassembly, input is the source text
+//   check       Program::new, decoding every word and checking its registers
+//   verify      Program::verify: targets, the last instruction, lowering a non-zero
+//               entry. there's no separate link step, that lowering is the closest
+//   predecode   to_decoded, fields split out for the predecoded variant
+//   bundle      to_bundles, the bundled-fetch layout
+//   bytes       bytes::to_bytes, the variable-length encoding (skipped past 64 KiB)
+//   jit         jit::compile, with --features jit
+//   vm          Vm::with_dispatch under central, what a host pays on top of verify
+//
+// input for everything but assemble is the code itself, 4 bytes per instruction.
+// stages that take their input by value clone it inside the timed region, a host
+// handing over a fresh Vec pays about the same
+//
+// a second table is per invocation rather than per load, for a host calling the same
+// guest over and over: each one runs the program to the end with the workload's args
+// and is timed whole, so the differences between the rows are what getting a clean
+// machine costs
+//
+//   run         Vm::run, central dispatch on memory it allocates zeroed every call
+//   new         a Vm built for the invocation, reset, resumed to the end
+//   reset       one Vm reset and resumed every time, the pages the last run wrote
+//               rewritten
+//   reset-keep  the same with Vm::set_zero_on_reset(false), only the data segments
+//               copied back over what the last run left
+//   pool        VmPool::checkout, resumed, checked back in
+//   pool-keep   the same with its Vm's set_zero_on_reset(false)
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_goto::{DispatchKind, Program, Status, Vm, VmPool, asm, bytes, to_bundles, to_decoded};
+
+use crate::Workload;
+use crate::report::{SAMPLES, Stats};
+
+// per stage, split over SAMPLES batches
+const STAGE_BUDGET_NS: f64 = 200e6;
+
+// what an invocation gets to halt in, a program waiting for args it wasn't given
+// (collatz from 0) would run forever
+const INVOCATION_FUEL: u64 = 100_000_000;
+
+pub fn run(workload: &Workload) {
+    let Workload { description, program, args, .. } = workload;
+    let code = program.program().code().to_vec();
+    let instrs = code.len();
+    let code_bytes = instrs * size_of::<u32>();
+    let source = asm::disassemble(program.program());
+
+    println!("load pipeline, in memory, median of {SAMPLES} batches per stage");
+    println!("Program: {description}, {instrs} instructions\n");
+    println!("{:>10} {:>12} {:>10} {:>12} {:>12}", "stage", "input", "us", "MB/s", "M instr/s");
+
+    let line = |stage: &str, input: usize, ns: f64| {
+        let (mb_s, minstr_s) = throughput(input, instrs, ns);
+        println!("{stage:>10} {:>10} B {:>10.2} {mb_s:>12.1} {minstr_s:>12.1}", input, ns / 1e3);
+    };
+
+    match &source {
+        Ok(src) => line("assemble", src.len(), time_stage(|| asm::assemble(black_box(src)).is_ok())),
+        Err(e) => println!("{:>10} skipped, {e}", "assemble"),
+    }
+    line("check", code_bytes, time_stage(|| Program::new(black_box(&code).clone()).is_ok()));
+    let unverified = program.program().clone();
+    line("verify", code_bytes, time_stage(|| black_box(&unverified).clone().verify().is_ok()));
+    line("predecode", code_bytes, time_stage(|| to_decoded(black_box(program)).code().len()));
+    line("bundle", code_bytes, time_stage(|| to_bundles(black_box(program)).bundles().len()));
+    match bytes::to_bytes(program) {
+        Ok(_) => line("bytes", code_bytes, time_stage(|| bytes::to_bytes(black_box(program)).is_ok())),
+        Err(e) => println!("{:>10} skipped, {e}", "bytes"),
+    }
+    #[cfg(feature = "jit")]
+    line("jit", code_bytes, time_stage(|| rust_goto::jit::compile(black_box(program)).code_bytes()));
+    line(
+        "vm",
+        code_bytes,
+        time_stage(|| Vm::with_dispatch(black_box(program).clone(), DispatchKind::Central).is_ok()),
+    );
+
+    let memory_bytes = program.memory_words() * size_of::<i64>();
+    let mut vm = Vm::new(program.clone());
+    vm.reset(args).expect("args checked by the workload");
+    if !matches!(vm.resume(INVOCATION_FUEL), Status::Halted(_)) {
+        println!("\nper invocation skipped, the program doesn't halt within {INVOCATION_FUEL} steps");
+        return;
+    }
+    println!("\nper invocation, run to the end with the workload's args, {memory_bytes} B of memory\n");
+    println!("{:>10} {:>10} {:>14}", "", "us", "invocations/s");
+    let invocation = |name: &str, ns: f64| println!("{name:>10} {:>10.2} {:>14.0}", ns / 1e3, 1e9 / ns);
+    let to_end = |vm: &mut Vm| black_box(vm.resume(INVOCATION_FUEL));
+    invocation("run", time_stage(|| vm.run(black_box(args))));
+    invocation(
+        "new",
+        time_stage(|| {
+            let mut vm = Vm::new(black_box(program).clone());
+            vm.reset(black_box(args)).expect("args checked by the workload");
+            to_end(&mut vm)
+        }),
+    );
+    let reset = |vm: &mut Vm| {
+        vm.reset(black_box(args)).expect("args checked by the workload");
+        to_end(vm)
+    };
+    invocation("reset", time_stage(|| reset(&mut vm)));
+    vm.set_zero_on_reset(false);
+    invocation("reset-keep", time_stage(|| reset(&mut vm)));
+    let mut pool = VmPool::new(program.clone(), DispatchKind::Central).expect("central takes any program");
+    let pooled = |pool: &mut VmPool| {
+        let mut vm = pool.checkout(black_box(args)).expect("args checked by the workload");
+        let status = to_end(&mut vm);
+        pool.checkin(vm);
+        status
+    };
+    invocation("pool", time_stage(|| pooled(&mut pool)));
+    let mut vm = pool.checkout(args).expect("args checked by the workload");
+    vm.set_zero_on_reset(false);
+    pool.checkin(vm);
+    invocation("pool-keep", time_stage(|| pooled(&mut pool)));
+}
+
+// ns per call of `stage`, the median batch. the first call sizes the batches
+fn time_stage<R>(mut stage: impl FnMut() -> R) -> f64 {
+    let start = Instant::now();
+    black_box(stage());
+    let once = start.elapsed().as_nanos().max(1) as f64;
+    let per_batch = ((STAGE_BUDGET_NS / once) as u64 / SAMPLES).max(1);
+    let samples: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..per_batch {
+                black_box(stage());
+            }
+            start.elapsed().as_nanos() as f64 / per_batch as f64
+        })
+        .collect();
+    Stats::of(&samples).median
+}
+
+// (MB/s of input, million instructions/s) for one call taking `ns`
+fn throughput(input_bytes: usize, instrs: usize, ns: f64) -> (f64, f64) {
+    // bytes per ns is GB/s, instructions per ns is G instr/s
+    (input_bytes as f64 / ns * 1e3, instrs as f64 / ns * 1e3)
+}
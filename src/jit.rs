@@ -0,0 +1,510 @@
+// A template JIT, as the ceiling the interpreter variants are measured against
+//
+// every instruction of the verified (lowered) code becomes a fixed x86-64 sequence,
+// one after the other, with no register allocation and no optimisation across
+// instructions: guest registers stay in memory (a Frame pointed to by rbx), every
+// instruction loads what it reads and stores what it writes. what's gone is
+// everything dispatch costs: no fetch, no decode, no indirect branch, a JMPNZ is a
+// cmp and a jne to the target's code. so it's not what a real JIT would get out of
+// these programs, it's what's left once dispatch is free
+//
+// host registers while the code runs:
+//
+//   rbx    the Frame, guest register r at [rbx + 8*r]
+//   r12    linear memory, r13 its size in words
+//   r14    the PUSH/POP value stack, r15 how many values are on it
+//   rbp    rsp before it got aligned for a call out (MEMCPY/MEMSET)
+//
+// CALL and RET are the native call and ret, with the depth counted in the Frame so
+// the STACK_DEPTH limit still traps: return addresses are only ever pushed by a CALL
+// and a RET lands on the code of the instruction after it, same as `Stack::call`.
+// every trap, at any call depth, resets rsp to where it was on entry and returns -1
+//
+// x86-64 Linux only (compile_error otherwise), and not in a safe-only build: the code
+// lives in an mmap'd buffer made executable with mprotect
+
+use std::ffi::{c_int, c_void};
+use std::mem::offset_of;
+
+use crate::memory::{Memory, MemoryImage};
+use crate::{
+    NREGS, OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ,
+    OP_JGE, OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL,
+    OP_MULSUB, OP_POP, OP_POPCNT, OP_PUSH, OP_RET, OP_STORE, OP_SUB, STACK_DEPTH, VerifiedProgram, imm16,
+    seed_regs,
+};
+
+// what the code gets in rdi, one per run
+#[repr(C)]
+struct Frame {
+    regs: [i64; NREGS],
+    mem: *mut i64,
+    mem_words: usize,
+    // one bit per word of memory, or null when nothing is read-only
+    read_only: *const u64,
+    values: *mut i64,
+    depth: usize,
+    saved_rsp: usize,
+    memory: *mut Memory,
+}
+
+const MEM: i32 = offset_of!(Frame, mem) as i32;
+const MEM_WORDS: i32 = offset_of!(Frame, mem_words) as i32;
+const READ_ONLY: i32 = offset_of!(Frame, read_only) as i32;
+const VALUES: i32 = offset_of!(Frame, values) as i32;
+const DEPTH: i32 = offset_of!(Frame, depth) as i32;
+const SAVED_RSP: i32 = offset_of!(Frame, saved_rsp) as i32;
+
+const fn reg(r: usize) -> i32 {
+    (r * size_of::<i64>()) as i32
+}
+
+// host registers, by their encoding
+const RAX: u8 = 0;
+const RCX: u8 = 1;
+const RDX: u8 = 2;
+const RSP: u8 = 4;
+const RSI: u8 = 6;
+const R12: u8 = 12;
+const R13: u8 = 13;
+const R14: u8 = 14;
+
+// condition codes, the low nibble of jcc
+const CC_B: u8 = 0x2;
+const CC_AE: u8 = 0x3;
+const CC_E: u8 = 0x4;
+const CC_NE: u8 = 0x5;
+const CC_L: u8 = 0xC;
+const CC_GE: u8 = 0xD;
+
+// just the encodings the templates use, every jump rel32 and patched once all the
+// labels are bound
+#[derive(Default)]
+struct Asm {
+    code: Vec<u8>,
+    labels: Vec<Option<usize>>,
+    fixups: Vec<(usize, usize)>,
+}
+
+impl Asm {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.code.extend_from_slice(bytes);
+    }
+
+    fn label(&mut self) -> usize {
+        self.labels.push(None);
+        self.labels.len() - 1
+    }
+
+    fn bind(&mut self, label: usize) {
+        self.labels[label] = Some(self.code.len());
+    }
+
+    fn rel32(&mut self, label: usize) {
+        self.fixups.push((self.code.len(), label));
+        self.bytes(&[0; 4]);
+    }
+
+    fn jmp(&mut self, label: usize) {
+        self.bytes(&[0xE9]);
+        self.rel32(label);
+    }
+
+    fn jcc(&mut self, cc: u8, label: usize) {
+        self.bytes(&[0x0F, 0x80 | cc]);
+        self.rel32(label);
+    }
+
+    fn call(&mut self, label: usize) {
+        self.bytes(&[0xE8]);
+        self.rel32(label);
+    }
+
+    // `op` with a 64-bit register (or a /digit) and the Frame slot at `disp`
+    fn frame(&mut self, op: &[u8], reg: u8, disp: i32) {
+        self.bytes(&[0x48 | (reg >> 3) << 2]);
+        self.bytes(op);
+        self.bytes(&[0x80 | (reg & 7) << 3 | 3]);
+        self.bytes(&disp.to_le_bytes());
+    }
+
+    fn load(&mut self, reg: u8, disp: i32) {
+        self.frame(&[0x8B], reg, disp);
+    }
+
+    fn store(&mut self, reg: u8, disp: i32) {
+        self.frame(&[0x89], reg, disp);
+    }
+
+    // `op` with a 64-bit register and [base + index*8]
+    fn indexed(&mut self, op: u8, reg: u8, base: u8, index: u8) {
+        self.bytes(&[0x48 | (reg >> 3) << 2 | (index >> 3) << 1 | base >> 3, op]);
+        self.bytes(&[(reg & 7) << 3 | 4, 0xC0 | (index & 7) << 3 | base & 7]);
+    }
+
+    // guest regs[dst] = regs[a] op regs[b], with op one of add, sub, imul
+    fn binop(&mut self, op: &[u8], dst: usize, a: usize, b: usize) {
+        self.load(RAX, reg(a));
+        self.frame(op, RAX, reg(b));
+        self.store(RAX, reg(dst));
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        for (at, label) in self.fixups {
+            let target = self.labels[label].expect("every label the code jumps to is bound");
+            let rel = target as i64 - (at as i64 + 4);
+            self.code[at..at + 4].copy_from_slice(&(rel as i32).to_le_bytes());
+        }
+        self.code
+    }
+}
+
+const ADD: &[u8] = &[0x03];
+const SUB: &[u8] = &[0x2B];
+const IMUL: &[u8] = &[0x0F, 0xAF];
+
+type Helper = extern "sysv64" fn(&mut Frame, i64, i64, i64) -> u32;
+
+// MEMCPY and MEMSET go through Memory, which already knows the rules (bounds,
+// overlap, read-only). 0 when it went through, 1 for a trap
+extern "sysv64" fn call_memcpy(frame: &mut Frame, to: i64, from: i64, len: i64) -> u32 {
+    // Safety: `memory` is the run's Memory, alive and otherwise untouched for as long
+    // as the code runs. copy doesn't reallocate, so r12 stays valid
+    let memory = unsafe { &mut *frame.memory };
+    memory.copy(to, from, len).is_err() as u32
+}
+
+extern "sysv64" fn call_memset(frame: &mut Frame, to: i64, value: i64, len: i64) -> u32 {
+    // Safety: as in call_memcpy
+    let memory = unsafe { &mut *frame.memory };
+    memory.fill(to, value, len).is_err() as u32
+}
+
+fn translate(code: &[u32], read_only: bool) -> Vec<u8> {
+    let mut asm = Asm::default();
+    // one label per pc, and one past the end for the second half of a superinstruction
+    // at the very end
+    let pcs: Vec<usize> = (0..=code.len()).map(|_| asm.label()).collect();
+    let (trap, exit) = (asm.label(), asm.label());
+    let popcnt = std::is_x86_feature_detected!("popcnt");
+
+    // push rbx, rbp, r12..r15; rbx = rdi; remember rsp; load the rest
+    asm.bytes(&[0x53, 0x55, 0x41, 0x54, 0x41, 0x55, 0x41, 0x56, 0x41, 0x57, 0x48, 0x89, 0xFB]);
+    asm.store(RSP, SAVED_RSP);
+    asm.load(R12, MEM);
+    asm.load(R13, MEM_WORDS);
+    asm.load(R14, VALUES);
+    asm.bytes(&[0x45, 0x31, 0xFF]); // xor r15d, r15d
+
+    for (pc, &word) in code.iter().enumerate() {
+        asm.bind(pcs[pc]);
+        let (op, dst, a, b) = (word as u8, (word >> 8) as u8 as usize, (word >> 16) as u8, (word >> 24) as u8);
+        let target = || pcs[imm16(a, b) as usize];
+        let (a, b) = (a as usize, b as usize);
+        match op {
+            OP_HALT => {
+                asm.load(RAX, reg(dst));
+                asm.jmp(exit);
+            }
+            OP_LOADI => {
+                asm.frame(&[0xC7], 0, reg(dst));
+                asm.bytes(&(imm16(a as u8, b as u8) as i32).to_le_bytes());
+            }
+            OP_ADD => asm.binop(ADD, dst, a, b),
+            OP_SUB => asm.binop(SUB, dst, a, b),
+            OP_MUL => asm.binop(IMUL, dst, a, b),
+            // idiv faults on 0 and on i64::MIN / -1, both are handled before it
+            OP_DIV | OP_MOD => {
+                let (zero, minus_one, done) = (asm.label(), asm.label(), asm.label());
+                asm.load(RCX, reg(b));
+                asm.bytes(&[0x48, 0x85, 0xC9]); // test rcx, rcx
+                asm.jcc(CC_E, zero);
+                asm.bytes(&[0x48, 0x83, 0xF9, 0xFF]); // cmp rcx, -1
+                asm.jcc(CC_E, minus_one);
+                asm.load(RAX, reg(a));
+                asm.bytes(&[0x48, 0x99, 0x48, 0xF7, 0xF9]); // cqo; idiv rcx
+                asm.store(if op == OP_DIV { RAX } else { RDX }, reg(dst));
+                asm.jmp(done);
+                asm.bind(minus_one);
+                if op == OP_DIV {
+                    asm.load(RAX, reg(a));
+                    asm.bytes(&[0x48, 0xF7, 0xD8]); // neg rax
+                    asm.store(RAX, reg(dst));
+                    asm.jmp(done);
+                }
+                asm.bind(zero);
+                asm.frame(&[0xC7], 0, reg(dst));
+                asm.bytes(&0i32.to_le_bytes());
+                asm.bind(done);
+            }
+            OP_INC | OP_DEC => {
+                asm.frame(&[0x83], if op == OP_INC { 0 } else { 5 }, reg(dst));
+                asm.bytes(&[1]);
+            }
+            OP_JMPNZ | OP_JNE | OP_JEQ | OP_JLT | OP_JGE => {
+                let cc = match op {
+                    OP_JEQ => CC_E,
+                    OP_JLT => CC_L,
+                    OP_JGE => CC_GE,
+                    _ => CC_NE,
+                };
+                asm.frame(&[0x83], 7, reg(dst));
+                asm.bytes(&[0]);
+                asm.jcc(cc, target());
+            }
+            OP_JMP => asm.jmp(target()),
+            OP_MOV => {
+                asm.load(RAX, reg(a));
+                asm.store(RAX, reg(dst));
+            }
+            // bsr/bsf leave the destination alone on 0, which gives 64 for both
+            OP_CLZ | OP_CTZ => {
+                let store = asm.label();
+                asm.load(RAX, reg(a));
+                asm.bytes(&[0xB9, 64, 0, 0, 0, 0x48, 0x85, 0xC0]); // mov ecx, 64; test rax, rax
+                asm.jcc(CC_E, store);
+                if op == OP_CLZ {
+                    // bsr rax, rax; mov ecx, 63; sub ecx, eax
+                    asm.bytes(&[0x48, 0x0F, 0xBD, 0xC0, 0xB9, 63, 0, 0, 0, 0x29, 0xC1]);
+                } else {
+                    asm.bytes(&[0x48, 0x0F, 0xBC, 0xC8]); // bsf rcx, rax
+                }
+                asm.bind(store);
+                asm.store(RCX, reg(dst));
+            }
+            OP_POPCNT if popcnt => {
+                asm.bytes(&[0xF3]);
+                asm.frame(&[0x0F, 0xB8], RAX, reg(a));
+                asm.store(RAX, reg(dst));
+            }
+            // no popcnt instruction: clear the lowest set bit until there's none
+            OP_POPCNT => {
+                let (again, done) = (asm.label(), asm.label());
+                asm.bytes(&[0x31, 0xC9]); // xor ecx, ecx
+                asm.load(RAX, reg(a));
+                asm.bind(again);
+                asm.bytes(&[0x48, 0x85, 0xC0]); // test rax, rax
+                asm.jcc(CC_E, done);
+                // lea rdx, [rax - 1]; and rax, rdx; inc rcx
+                asm.bytes(&[0x48, 0x8D, 0x50, 0xFF, 0x48, 0x21, 0xD0, 0x48, 0xFF, 0xC1]);
+                asm.jmp(again);
+                asm.bind(done);
+                asm.store(RCX, reg(dst));
+            }
+            OP_CMP => {
+                asm.bytes(&[0x31, 0xC9, 0x31, 0xD2]); // xor ecx, ecx; xor edx, edx
+                asm.load(RAX, reg(a));
+                asm.frame(&[0x3B], RAX, reg(b));
+                // setg cl; setl dl; sub rcx, rdx
+                asm.bytes(&[0x0F, 0x9F, 0xC1, 0x0F, 0x9C, 0xC2, 0x48, 0x29, 0xD1]);
+                asm.store(RCX, reg(dst));
+            }
+            OP_CALL => {
+                asm.frame(&[0x81], 7, DEPTH);
+                asm.bytes(&(STACK_DEPTH as i32).to_le_bytes());
+                asm.jcc(CC_AE, trap);
+                asm.frame(&[0x83], 0, DEPTH);
+                asm.bytes(&[1]);
+                asm.call(target());
+            }
+            OP_RET => {
+                asm.frame(&[0x83], 7, DEPTH);
+                asm.bytes(&[0]);
+                asm.jcc(CC_E, trap);
+                asm.frame(&[0x83], 5, DEPTH);
+                asm.bytes(&[1, 0xC3]);
+            }
+            OP_PUSH => {
+                asm.bytes(&[0x49, 0x81, 0xFF]); // cmp r15, STACK_DEPTH
+                asm.bytes(&(STACK_DEPTH as i32).to_le_bytes());
+                asm.jcc(CC_AE, trap);
+                asm.load(RAX, reg(dst));
+                asm.bytes(&[0x4B, 0x89, 0x04, 0xFE, 0x49, 0xFF, 0xC7]); // mov [r14 + r15*8], rax; inc r15
+            }
+            OP_POP => {
+                asm.bytes(&[0x4D, 0x85, 0xFF]); // test r15, r15
+                asm.jcc(CC_E, trap);
+                asm.bytes(&[0x49, 0xFF, 0xCF, 0x4B, 0x8B, 0x04, 0xFE]); // dec r15; mov rax, [r14 + r15*8]
+                asm.store(RAX, reg(dst));
+            }
+            // address as in `address`: wrapping add, then unsigned against the size
+            OP_LOAD | OP_STORE => {
+                asm.load(RAX, reg(a));
+                asm.bytes(&[0x48, 0x05]); // add rax, offset
+                asm.bytes(&(b as i32).to_le_bytes());
+                asm.bytes(&[0x4C, 0x39, 0xE8]); // cmp rax, r13
+                asm.jcc(CC_AE, trap);
+                if op == OP_LOAD {
+                    asm.indexed(0x8B, RCX, R12, RAX);
+                    asm.store(RCX, reg(dst));
+                } else {
+                    if read_only {
+                        // bit rax of the bitmap, which covers all of memory
+                        asm.bytes(&[0x48, 0x89, 0xC1, 0x48, 0xC1, 0xE9, 0x06]); // mov rcx, rax; shr rcx, 6
+                        asm.load(RDX, READ_ONLY);
+                        asm.indexed(0x8B, RDX, RDX, RCX);
+                        asm.bytes(&[0x48, 0x0F, 0xA3, 0xC2]); // bt rdx, rax
+                        asm.jcc(CC_B, trap);
+                    }
+                    asm.load(RCX, reg(dst));
+                    asm.indexed(0x89, RCX, R12, RAX);
+                }
+            }
+            OP_MEMCPY | OP_MEMSET => {
+                let helper: Helper = if op == OP_MEMCPY { call_memcpy } else { call_memset };
+                // mov rbp, rsp; and rsp, -16; mov rdi, rbx
+                asm.bytes(&[0x48, 0x89, 0xE5, 0x48, 0x83, 0xE4, 0xF0, 0x48, 0x89, 0xDF]);
+                asm.load(RSI, reg(dst));
+                asm.load(RDX, reg(a));
+                asm.load(RCX, reg(b));
+                asm.bytes(&[0x48, 0xB8]); // mov rax, helper
+                asm.bytes(&(helper as usize as u64).to_le_bytes());
+                // call rax; mov rsp, rbp; test eax, eax
+                asm.bytes(&[0xFF, 0xD0, 0x48, 0x89, 0xEC, 0x85, 0xC0]);
+                asm.jcc(CC_NE, trap);
+            }
+            OP_ADDADD | OP_MULSUB => {
+                let (first, second) = if op == OP_ADDADD { (ADD, ADD) } else { (IMUL, SUB) };
+                asm.binop(first, dst & 0xF, a & 0xF, b & 0xF);
+                asm.binop(second, dst >> 4, a >> 4, b >> 4);
+                asm.jmp(pcs[pc + 2]);
+            }
+            OP_DECJNZ => {
+                asm.frame(&[0x83], 5, reg(dst));
+                asm.bytes(&[1]);
+                asm.jcc(CC_NE, target());
+                asm.jmp(pcs[pc + 2]);
+            }
+            // WAITEVENT, TRAP and RESOLVE_CALL, which have no host to wait for or call.
+            // nothing else gets past the verifier
+            _ => asm.jmp(trap),
+        }
+    }
+    asm.bind(pcs[code.len()]);
+    asm.bind(trap);
+    asm.bytes(&[0x48, 0xC7, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF]); // mov rax, -1
+    asm.bind(exit);
+    asm.load(RSP, SAVED_RSP);
+    // pop r15..r12, rbp, rbx
+    asm.bytes(&[0x41, 0x5F, 0x41, 0x5E, 0x41, 0x5D, 0x41, 0x5C, 0x5D, 0x5B, 0xC3]);
+    asm.finish()
+}
+
+unsafe extern "C" {
+    fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, offset: i64) -> *mut c_void;
+    fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+}
+
+const PROT_READ: c_int = 1;
+const PROT_WRITE: c_int = 2;
+const PROT_EXEC: c_int = 4;
+const MAP_PRIVATE: c_int = 2;
+const MAP_ANONYMOUS: c_int = 0x20;
+
+// the machine code, read-only and executable once it's in
+struct Executable {
+    ptr: *mut c_void,
+    len: usize,
+}
+
+// Safety: the mapping is never written after `new`, sharing it is sharing read-only memory
+unsafe impl Send for Executable {}
+unsafe impl Sync for Executable {}
+
+impl Executable {
+    fn new(code: &[u8]) -> Executable {
+        // Safety: a fresh private mapping of code.len() bytes, written while it's
+        // writable and only then made executable
+        unsafe {
+            let ptr = mmap(std::ptr::null_mut(), code.len(), PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+            assert!(ptr as isize != -1, "mmap of {} bytes for the jit failed", code.len());
+            std::ptr::copy_nonoverlapping(code.as_ptr(), ptr.cast(), code.len());
+            assert_eq!(mprotect(ptr, code.len(), PROT_READ | PROT_EXEC), 0, "mprotect of the jit code failed");
+            Executable { ptr, len: code.len() }
+        }
+    }
+}
+
+impl Drop for Executable {
+    fn drop(&mut self) {
+        // Safety: our mapping, and nothing runs from it once the JitProgram is gone
+        unsafe { munmap(self.ptr, self.len) };
+    }
+}
+
+// a verified program compiled to native code, with what each run starts from
+pub struct JitProgram {
+    code: Executable,
+    memory: MemoryImage,
+}
+
+impl JitProgram {
+    // bytes of machine code
+    pub fn code_bytes(&self) -> usize {
+        self.code.len
+    }
+}
+
+pub fn compile(program: &VerifiedProgram) -> JitProgram {
+    let memory = program.memory_image().clone();
+    let read_only = program.program().segments().iter().any(|s| s.read_only && !s.words.is_empty());
+    JitProgram { code: Executable::new(&translate(program.code(), read_only)), memory }
+}
+
+#[inline(never)]
+pub fn run_jit(program: &JitProgram, args: &[i64]) -> i64 {
+    let mut memory = program.memory.fresh();
+    let mut values: Vec<i64> = Vec::with_capacity(STACK_DEPTH);
+    let bits = memory.read_only_bits();
+    let read_only = if bits.is_empty() { std::ptr::null() } else { bits.as_ptr() };
+    let words = memory.words_mut();
+    let (mem, mem_words) = (words.as_mut_ptr(), words.len());
+    let mut frame = Frame {
+        regs: seed_regs(args),
+        mem,
+        mem_words,
+        read_only,
+        values: values.as_mut_ptr(),
+        depth: 0,
+        saved_rsp: 0,
+        memory: &mut memory,
+    };
+    // Safety: the code was translated from verified code (jump targets in range, no
+    // fall-through past the end), and keeps every memory and stack access inside
+    // what the Frame hands it: `mem_words` words of memory and STACK_DEPTH values
+    unsafe {
+        let entry: extern "sysv64" fn(&mut Frame) -> i64 = std::mem::transmute(program.code.ptr);
+        entry(&mut frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OP_INC, OP_JEQ, encode, run_central, verify};
+
+    // recursion 1000 deep has to pair every native call with its ret, 2000 deep goes
+    // past STACK_DEPTH and the trap has to get back out through all of them
+    #[test]
+    fn recursion_returns_and_traps_through_native_calls() {
+        for (depth, expected) in [(1000, 1000), (2000, -1)] {
+            let code = [
+                encode(OP_LOADI, 1, depth as u8, (depth >> 8) as u8),
+                encode(OP_CALL, 0, 3, 0),
+                encode(OP_HALT, 0, 0, 0),
+                encode(OP_INC, 0, 0, 0),
+                encode(OP_DEC, 1, 0, 0),
+                encode(OP_JEQ, 1, 7, 0),
+                encode(OP_CALL, 0, 3, 0),
+                encode(OP_RET, 0, 0, 0),
+            ];
+            let program = verify(&code).unwrap();
+            let jit = compile(&program);
+            assert_eq!(run_central(&program, &[]), expected);
+            // twice, nothing carries over from one run to the next
+            assert_eq!(run_jit(&jit, &[]), expected);
+            assert_eq!(run_jit(&jit, &[]), expected);
+        }
+    }
+}
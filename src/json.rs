@@ -0,0 +1,298 @@
+// Just enough JSON to read our own documents back: the benchmark's --output json rows
+// (report.rs), criterion's files, the conformance vectors. no serde, the crate has no
+// dependencies and these documents don't need more than this
+//
+// numbers are f64, so a 64-bit integer past 2^53 doesn't survive a round trip. the
+// documents that carry those (conformance.rs) write them as strings
+
+use std::fmt::Write;
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[derive(Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn index(&self, i: usize) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Array(items) => items.get(i),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            JsonValue::Num(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+// the whole of `text` as one value, trailing whitespace allowed
+pub fn parse_json(text: &str) -> Result<JsonValue, String> {
+    let mut parser = Json { s: text.as_bytes(), at: 0 };
+    let value = parser.value()?;
+    parser.skip_ws();
+    if parser.at != text.len() {
+        return Err(format!("trailing input at byte {}", parser.at));
+    }
+    Ok(value)
+}
+
+struct Json<'a> {
+    s: &'a [u8],
+    at: usize,
+}
+
+impl Json<'_> {
+    fn skip_ws(&mut self) {
+        while self.s.get(self.at).is_some_and(u8::is_ascii_whitespace) {
+            self.at += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), String> {
+        self.skip_ws();
+        if self.s.get(self.at) != Some(&b) {
+            return Err(format!("expected `{}` at byte {}", b as char, self.at));
+        }
+        self.at += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.skip_ws();
+        let rest = &self.s[self.at..];
+        for (word, value) in [("null", JsonValue::Null), ("true", JsonValue::Bool(true)), ("false", JsonValue::Bool(false))]
+        {
+            if rest.starts_with(word.as_bytes()) {
+                self.at += word.len();
+                return Ok(value);
+            }
+        }
+        match rest.first() {
+            Some(b'"') => self.string().map(JsonValue::Str),
+            Some(b'[') => {
+                self.at += 1;
+                let mut items = Vec::new();
+                self.skip_ws();
+                if self.s.get(self.at) == Some(&b']') {
+                    self.at += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                loop {
+                    items.push(self.value()?);
+                    self.skip_ws();
+                    if self.s.get(self.at) == Some(&b',') {
+                        self.at += 1;
+                        continue;
+                    }
+                    self.expect(b']')?;
+                    return Ok(JsonValue::Array(items));
+                }
+            }
+            Some(b'{') => {
+                self.at += 1;
+                let mut fields = Vec::new();
+                self.skip_ws();
+                if self.s.get(self.at) == Some(&b'}') {
+                    self.at += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                loop {
+                    self.skip_ws();
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    fields.push((key, self.value()?));
+                    self.skip_ws();
+                    if self.s.get(self.at) == Some(&b',') {
+                        self.at += 1;
+                        continue;
+                    }
+                    self.expect(b'}')?;
+                    return Ok(JsonValue::Object(fields));
+                }
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let len = rest.iter().position(|b| !matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'));
+                let len = len.unwrap_or(rest.len());
+                let text = std::str::from_utf8(&rest[..len]).unwrap();
+                self.at += len;
+                text.parse().map(JsonValue::Num).map_err(|_| format!("bad number `{text}`"))
+            }
+            _ => Err(format!("unexpected input at byte {}", self.at)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.s.get(self.at) != Some(&b'"') {
+            return Err(format!("expected a string at byte {}", self.at));
+        }
+        self.at += 1;
+        let mut out = Vec::new();
+        loop {
+            match self.s.get(self.at).copied() {
+                None => return Err("unterminated string".to_string()),
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    let escaped = self.s.get(self.at + 1).copied().ok_or("unterminated string")?;
+                    self.at += 1;
+                    match escaped {
+                        b'"' | b'\\' | b'/' => out.push(escaped),
+                        b'b' => out.push(0x08),
+                        b'f' => out.push(0x0c),
+                        b'n' => out.push(b'\n'),
+                        b't' => out.push(b'\t'),
+                        b'r' => out.push(b'\r'),
+                        b'u' => {
+                            let c = self.unicode_escape()?;
+                            out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        }
+                        other => return Err(format!("unknown escape `\\{}` at byte {}", other as char, self.at - 1)),
+                    }
+                }
+                Some(b) => out.push(b),
+            }
+            self.at += 1;
+        }
+        self.at += 1;
+        String::from_utf8(out).map_err(|_| "a string isn't UTF-8".to_string())
+    }
+
+    // the character after a `\u`, self.at on the `u` and left on the last hex digit.
+    // outside the BMP it's two escapes, a UTF-16 surrogate pair, and half of one on
+    // its own isn't a character
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        if !(0xD800..0xE000).contains(&high) {
+            return Ok(char::from_u32(high).expect("not a surrogate"));
+        }
+        let at = self.at - 5;
+        if high >= 0xDC00 || self.s.get(self.at + 1..self.at + 3) != Some(b"\\u") {
+            return Err(format!("unpaired surrogate at byte {at}"));
+        }
+        self.at += 2;
+        let low = self.hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(format!("unpaired surrogate at byte {at}"));
+        }
+        Ok(char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)).expect("a surrogate pair"))
+    }
+
+    // the four hex digits after self.at, which is left on the last one
+    fn hex4(&mut self) -> Result<u32, String> {
+        let hex = self.s.get(self.at + 1..self.at + 5).ok_or("short \\u escape")?;
+        if !hex.iter().all(u8::is_ascii_hexdigit) {
+            return Err(format!("bad \\u escape at byte {}", self.at - 1));
+        }
+        self.at += 4;
+        Ok(u32::from_str_radix(std::str::from_utf8(hex).unwrap(), 16).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn str_of(json: &str) -> Result<String, String> {
+        parse_json(json).map(|v| v.as_str().expect("a string").to_string())
+    }
+
+    #[test]
+    fn decodes_every_escape() {
+        assert_eq!(str_of(r#""\"\\\/\b\f\n\r\t""#).unwrap(), "\"\\/\u{8}\u{c}\n\r\t");
+        assert_eq!(str_of(r#""caf\u00e9 \u20AC""#).unwrap(), "café €");
+        // outside the BMP, a surrogate pair is one character
+        assert_eq!(str_of(r#""\ud83d\ude00""#).unwrap(), "\u{1f600}");
+        assert_eq!(str_of(r#""\ud83d\ude00""#).unwrap().chars().count(), 1);
+        // what json_string writes comes back the same
+        let s = "tab\t \"quoted\" back\\slash \u{1} \u{1f600}";
+        assert_eq!(str_of(&json_string(s)).unwrap(), s);
+    }
+
+    #[test]
+    fn rejects_bad_escapes() {
+        let cases = [
+            (r#""\x41""#, "unknown escape `\\x` at byte 1"),
+            (r#""\'""#, "unknown escape"),
+            (r#""\u12""#, "short \\u escape"),
+            (r#""\u12g4""#, "bad \\u escape at byte 1"),
+            (r#""\u+123""#, "bad \\u escape"),
+            (r#""\ud83d""#, "unpaired surrogate at byte 1"),
+            (r#""\ud83d\u0041""#, "unpaired surrogate"),
+            (r#""\ude00\ud83d""#, "unpaired surrogate"),
+            (r#""\"#, "unterminated string"),
+        ];
+        for (json, needle) in cases {
+            let err = parse_json(json).unwrap_err();
+            assert!(err.contains(needle), "{json}: {err}");
+        }
+    }
+
+    #[test]
+    fn nests() {
+        let json = r#" {"rows": [{"name": "fib", "ns": [1, 2.5]}, []], "ok": true, "none": null} "#;
+        let value = parse_json(json).unwrap();
+        let row = value.get("rows").and_then(|rows| rows.index(0)).unwrap();
+        assert_eq!(row.get("name").and_then(JsonValue::as_str), Some("fib"));
+        assert_eq!(row.get("ns").and_then(|ns| ns.index(1)).and_then(JsonValue::as_f64), Some(2.5));
+        assert_eq!(value.get("rows").and_then(|rows| rows.index(1)), Some(&JsonValue::Array(vec![])));
+        assert_eq!(value.get("ok"), Some(&JsonValue::Bool(true)));
+        assert_eq!(value.get("none"), Some(&JsonValue::Null));
+        assert_eq!(value.get("missing"), None);
+        assert_eq!(parse_json("{}").unwrap(), JsonValue::Object(vec![]));
+        assert!(parse_json(r#"{"a": [1, 2}"#).unwrap_err().contains("expected `]`"));
+        assert!(parse_json(r#"{"a" 1}"#).unwrap_err().contains("expected `:`"));
+    }
+
+    #[test]
+    fn numbers() {
+        for (json, n) in [("0", 0.0), ("-7", -7.0), ("12.25", 12.25), ("-1.5e3", -1500.0), ("2E-2", 0.02)] {
+            assert_eq!(parse_json(json).unwrap().as_f64(), Some(n), "{json}");
+        }
+        // past 2^53 an integer gets rounded, why conformance.rs writes those as strings
+        assert_eq!(parse_json("9007199254740993").unwrap().as_f64(), Some(9007199254740992.0));
+        assert_eq!(parse_json("1-2").unwrap_err(), "bad number `1-2`");
+    }
+
+    #[test]
+    fn only_whitespace_may_follow() {
+        assert_eq!(parse_json("[1]\n\t ").unwrap(), JsonValue::Array(vec![JsonValue::Num(1.0)]));
+        assert_eq!(parse_json("[1] 2").unwrap_err(), "trailing input at byte 4");
+        assert_eq!(parse_json(r#""a" "b""#).unwrap_err(), "trailing input at byte 4");
+        assert!(parse_json("").unwrap_err().contains("unexpected input at byte 0"));
+    }
+}
@@ -0,0 +1,573 @@
+// Benchmark programs ("kernels"), most of them parameterized by shape
+//
+// one program only tells you how one instruction mix responds to each dispatch
+// strategy. these generators let the shape vary along one axis at a time:
+//
+//   poly:D       Horner evaluation of a degree-D polynomial per loop iteration,
+//                long straight-line bodies of MUL/LOADI/ADD
+//   loop-nest:K  K nested counting loops with a one-instruction body, almost
+//                nothing but DEC/JMPNZ and very short blocks
+//   fsm:S        a state machine with S states driven by a PRNG, dispatched
+//                through a compare-and-branch chain, lots of data-dependent branches
+//   fib          naive recursive fibonacci, CALL/RET/PUSH/POP and short blocks
+//   sieve        sieve of Eratosthenes over memory, counts primes below n
+//   array-sum    fills n words of memory, then sums them back, LOAD/STORE heavy
+//   collatz      collatz sequences from 1..=m, CMP and the whole branch family, with
+//                a parity branch that's close to a coin flip
+//   bitscan      a 64-bit LCG, and the leading zeros, trailing zeros and set bits of
+//                every value it produces, CLZ/CTZ/POPCNT
+//   pressure:R   programs/pressure.expr compiled with R registers to allocate (see
+//                expr.rs), the same loop with more of its values spilled to memory
+//                the fewer registers it gets
+//   giant:F      F functions of GIANT_BODY instructions each (up to ~62k instructions
+//                for F = 1000), every one called once on the way in, then r0 rounds
+//                of calls to the GIANT_HOT of them spread across the code. a big
+//                program whose hot part is small and scattered, for blocks.rs
+//
+// every kernel takes its trip count from the host in r0, and `n` is always roughly
+// "how many loop iterations" (calls, for fib), so sizes mean the same thing across
+// kernels. the memory kernels use one word per element and get clamped to the
+// default memory size (DEFAULT_MEMORY_WORDS)
+
+use crate::{
+    DEFAULT_MEMORY_WORDS, OP_ADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MOD, OP_MOV, OP_MUL, OP_POP, OP_POPCNT, OP_PUSH, OP_RET,
+    OP_STORE, OP_SUB, encode, expr,
+};
+
+// names accepted by `kernel`, with their parameter
+pub const KERNELS: &[&str] = &[
+    "sum-poly", "filter", "poly:<degree>", "loop-nest:<depth>", "fsm:<states>", "fib", "sieve", "array-sum", "collatz",
+    "bitscan", "pressure:<registers>", "giant:<functions>",
+];
+
+// builds the kernel called `name` (with its `:param` if it takes one) and the host
+// arguments that run it for about `n` iterations
+pub fn kernel(name: &str, n: i64) -> Option<(Vec<u32>, Vec<i64>)> {
+    let (base, param) = match name.split_once(':') {
+        Some((base, param)) => (base, Some(param.parse::<u32>().ok()?)),
+        None => (name, None),
+    };
+    match (base, param) {
+        ("sum-poly", None) => Some((sum_poly(), vec![n])),
+        ("filter", None) => Some((filter(), vec![n, n ^ 0x5555])),
+        ("poly", Some(degree)) if degree <= 64 => Some((poly(degree), vec![n])),
+        ("loop-nest", Some(depth @ 1..=MAX_NEST)) => {
+            // n iterations of the innermost body in total
+            let trip = ((n as f64).powf(1.0 / depth as f64).round() as i64).max(1);
+            Some((loop_nest(depth), vec![trip]))
+        }
+        ("fsm", Some(states @ 1..=256)) => Some((fsm(states), vec![n])),
+        ("fib", None) => Some((fib(), vec![fib_arg(n)])),
+        ("sieve", None) => Some((sieve(), vec![n.clamp(2, DEFAULT_MEMORY_WORDS as i64)])),
+        ("array-sum", None) => Some((array_sum(), vec![n.clamp(1, DEFAULT_MEMORY_WORDS as i64)])),
+        ("collatz", None) => Some((collatz(), vec![collatz_arg(n)])),
+        ("bitscan", None) => Some((bitscan(), vec![n])),
+        ("pressure", Some(registers @ 1..=12)) => Some((pressure(registers as usize), vec![n])),
+        ("giant", Some(functions @ 1..=MAX_GIANT)) => Some((giant(functions), vec![n])),
+        _ => None,
+    }
+}
+
+// a little helper for programs with forward jumps: emit a JMPNZ with a dummy target
+// and patch it once the target is known
+struct Builder {
+    code: Vec<u32>,
+}
+
+impl Builder {
+    fn new() -> Self {
+        Builder { code: Vec::new() }
+    }
+
+    fn emit(&mut self, op: u8, dst: u8, a: u8, b: u8) {
+        self.code.push(encode(op, dst, a, b));
+    }
+
+    fn loadi(&mut self, dst: u8, imm: u16) {
+        self.emit(OP_LOADI, dst, imm as u8, (imm >> 8) as u8);
+    }
+
+    fn here(&self) -> u16 {
+        u16::try_from(self.code.len()).expect("kernel too long for 16-bit jump targets")
+    }
+
+    fn jmpnz(&mut self, reg: u8, target: u16) {
+        self.emit(OP_JMPNZ, reg, target as u8, (target >> 8) as u8);
+    }
+
+    // JMPNZ to be patched later, returns where it is
+    fn jmpnz_fwd(&mut self, reg: u8) -> usize {
+        self.jmpnz(reg, 0);
+        self.code.len() - 1
+    }
+
+    fn patch(&mut self, at: usize, target: u16) {
+        let reg = ((self.code[at] >> 8) & 0xFF) as u8;
+        self.code[at] = encode(OP_JMPNZ, reg, target as u8, (target >> 8) as u8);
+    }
+}
+
+// here's our test program, it just computes :
+//
+// sum = 0;
+// for i in (0..N) {
+//    sum += i*i - i + 1
+// }
+//
+// N comes in from the host as the first argument (r0)
+pub fn sum_poly() -> Vec<u32> {
+    vec![
+        encode(OP_LOADI, 1, 0, 0),    // r1 = 0 (le accumulator)
+        encode(OP_LOADI, 2, 1, 0),    // r2 = 1
+        // loop: (pc = 2)
+        encode(OP_MOV, 3, 0, 0),      // r3 = r0
+        encode(OP_MUL, 4, 3, 3),      // r4 = r3*r3
+        encode(OP_SUB, 5, 4, 3),      // r5 = r4 - r3
+        encode(OP_ADD, 5, 5, 2),      // r5 = r5 + 1
+        encode(OP_ADD, 1, 1, 5),      // r1 += r5
+        encode(OP_DEC, 0, 0, 0),      // r0--
+        encode(OP_JMPNZ, 0, 2, 0),   // if r0 != 0 goto 2
+
+        encode(OP_HALT, 1, 0, 0),     // return r1
+    ]
+}
+
+// keep a row when (x*3 + y) % 7 != 0, x and y come in as r0 and r1
+pub fn filter() -> Vec<u32> {
+    vec![
+        encode(OP_LOADI, 2, 3, 0),    // r2 = 3
+        encode(OP_MUL, 3, 0, 2),      // r3 = x*3
+        encode(OP_ADD, 3, 3, 1),      // r3 += y
+        encode(OP_LOADI, 4, 7, 0),    // r4 = 7
+        encode(OP_MOD, 3, 3, 4),      // r3 %= 7
+        encode(OP_HALT, 3, 0, 0),     // nonzero = keep
+    ]
+}
+
+// coefficient k of the poly:D polynomial, small and all different
+pub fn poly_coeff(k: u32) -> u16 {
+    (k * 7 + 3) as u16
+}
+
+// sum over i = N..1 of p(i), p(x) = c_D x^D + ... + c_1 x + c_0, evaluated with Horner
+pub fn poly(degree: u32) -> Vec<u32> {
+    let mut b = Builder::new();
+    b.loadi(1, 0); // r1 = sum
+    let top = b.here();
+    b.emit(OP_MOV, 2, 0, 0); // r2 = x
+    b.loadi(3, poly_coeff(degree)); // r3 = c_D
+    for k in (0..degree).rev() {
+        b.emit(OP_MUL, 3, 3, 2); // acc *= x
+        b.loadi(4, poly_coeff(k));
+        b.emit(OP_ADD, 3, 3, 4); // acc += c_k
+    }
+    b.emit(OP_ADD, 1, 1, 3);
+    b.emit(OP_DEC, 0, 0, 0);
+    b.jmpnz(0, top);
+    b.emit(OP_HALT, 1, 0, 0);
+    b.code
+}
+
+// counters live in r2.., r1 counts innermost iterations
+const MAX_NEST: u32 = 13;
+
+// K nested loops, each running r0 times: returns r0^K
+pub fn loop_nest(depth: u32) -> Vec<u32> {
+    let mut b = Builder::new();
+    b.loadi(1, 0);
+    let mut tops = Vec::new();
+    for level in 0..depth {
+        let counter = 2 + level as u8;
+        b.emit(OP_MOV, counter, 0, 0); // reset this level's counter
+        tops.push(b.here());
+    }
+    b.emit(OP_INC, 1, 0, 0);
+    for level in (0..depth).rev() {
+        let counter = 2 + level as u8;
+        b.emit(OP_DEC, counter, 0, 0);
+        b.jmpnz(counter, tops[level as usize]);
+    }
+    b.emit(OP_HALT, 1, 0, 0);
+    b.code
+}
+
+// fib(r0) the slow way, 2 * fib(r0 + 1) - 1 calls. programs/fib.vmasm is the same
+// thing in assembler, with labels
+pub fn fib() -> Vec<u32> {
+    vec![
+        encode(OP_CALL, 0, 2, 0),     // r1 = fib(r0)
+        encode(OP_HALT, 1, 0, 0),
+        encode(OP_MOV, 1, 0, 0),      // fib: r1 = n, which is the answer for 0 and 1
+        encode(OP_JMPNZ, 0, 5, 0),
+        encode(OP_RET, 0, 0, 0),
+        encode(OP_MOV, 2, 0, 0),      // pc = 5
+        encode(OP_DEC, 2, 0, 0),
+        encode(OP_JMPNZ, 2, 9, 0),
+        encode(OP_RET, 0, 0, 0),
+        encode(OP_PUSH, 0, 0, 0),     // pc = 9, n >= 2
+        encode(OP_DEC, 0, 0, 0),
+        encode(OP_CALL, 0, 2, 0),     // r1 = fib(n - 1)
+        encode(OP_POP, 0, 0, 0),
+        encode(OP_PUSH, 1, 0, 0),
+        encode(OP_DEC, 0, 0, 0),
+        encode(OP_DEC, 0, 0, 0),
+        encode(OP_CALL, 0, 2, 0),     // r1 = fib(n - 2)
+        encode(OP_POP, 2, 0, 0),
+        encode(OP_ADD, 1, 1, 2),
+        encode(OP_RET, 0, 0, 0),
+    ]
+}
+
+// the smallest argument that makes at least n calls
+fn fib_arg(n: i64) -> i64 {
+    let (mut k, mut f, mut next) = (0, 0i64, 1i64); // f = fib(k), next = fib(k + 1)
+    while 2 * next - 1 < n {
+        (f, next) = (next, f + next);
+        k += 1;
+    }
+    k
+}
+
+// how many primes are below r0 (r0 >= 2). mem[k] gets set once k is known composite,
+// every prime p crosses out 2p, 3p, ... up to r0 - 1. programs/sieve.vmasm is the
+// same thing in assembler
+pub fn sieve() -> Vec<u32> {
+    vec![
+        encode(OP_LOADI, 1, 0, 0),    // r1 = primes found
+        encode(OP_LOADI, 2, 2, 0),    // r2 = i = 2
+        encode(OP_MOV, 3, 0, 0),
+        encode(OP_DEC, 3, 0, 0),      // r3 = n - 1, the last candidate
+        encode(OP_LOADI, 9, 1, 0),    // r9 = 1, for marking and unconditional jumps
+        encode(OP_MOV, 4, 3, 0),
+        encode(OP_DEC, 4, 0, 0),      // r4 = n - 2 candidates left
+        encode(OP_JMPNZ, 4, 9, 0),
+        encode(OP_HALT, 1, 0, 0),     // n = 2, nothing below it
+        encode(OP_LOAD, 5, 2, 0),     // outer: (pc = 9) r5 = mem[i]
+        encode(OP_JMPNZ, 5, 21, 0),   // composite, next
+        encode(OP_INC, 1, 0, 0),
+        encode(OP_DIV, 6, 3, 2),
+        encode(OP_DEC, 6, 0, 0),      // r6 = (n - 1) / i - 1 multiples to cross out
+        encode(OP_JMPNZ, 6, 16, 0),
+        encode(OP_JMPNZ, 9, 21, 0),   // none, next
+        encode(OP_ADD, 7, 2, 2),      // r7 = j = 2i
+        encode(OP_STORE, 9, 7, 0),    // mark: (pc = 17) mem[j] = 1
+        encode(OP_ADD, 7, 7, 2),
+        encode(OP_DEC, 6, 0, 0),
+        encode(OP_JMPNZ, 6, 17, 0),
+        encode(OP_INC, 2, 0, 0),      // next: (pc = 21)
+        encode(OP_DEC, 4, 0, 0),
+        encode(OP_JMPNZ, 4, 9, 0),
+        encode(OP_HALT, 1, 0, 0),
+    ]
+}
+
+// mem[k] = k * k for k < r0 (r0 >= 1), then the sum of all of it read back
+pub fn array_sum() -> Vec<u32> {
+    vec![
+        encode(OP_LOADI, 1, 0, 0),    // r1 = sum
+        encode(OP_MOV, 2, 0, 0),      // r2 = k = n
+        encode(OP_DEC, 2, 0, 0),      // fill: (pc = 2)
+        encode(OP_MUL, 3, 2, 2),
+        encode(OP_STORE, 3, 2, 0),    // mem[k] = k * k
+        encode(OP_JMPNZ, 2, 2, 0),
+        encode(OP_MOV, 2, 0, 0),
+        encode(OP_DEC, 2, 0, 0),      // sum: (pc = 7)
+        encode(OP_LOAD, 3, 2, 0),
+        encode(OP_ADD, 1, 1, 3),
+        encode(OP_JMPNZ, 2, 7, 0),
+        encode(OP_HALT, 1, 0, 0),
+    ]
+}
+
+// total collatz steps to reach 1 from every start in 1..=r0 (r0 >= 1). every step is
+// a compare against 1, then a branch on the parity of x, which the predictor can only
+// guess at. programs/collatz.vmasm is the same thing in assembler
+pub fn collatz() -> Vec<u32> {
+    vec![
+        encode(OP_LOADI, 1, 0, 0),    // r1 = steps
+        encode(OP_LOADI, 2, 1, 0),    // r2 = 1
+        encode(OP_LOADI, 3, 2, 0),    // r3 = 2
+        encode(OP_LOADI, 4, 3, 0),    // r4 = 3
+        encode(OP_MOV, 5, 0, 0),      // start: (pc = 4) r5 = x = r0
+        encode(OP_CMP, 6, 5, 2),      // step: (pc = 5)
+        encode(OP_JEQ, 6, 15, 0),     // x == 1, done
+        encode(OP_INC, 1, 0, 0),
+        encode(OP_MOD, 7, 5, 3),
+        encode(OP_JNE, 7, 12, 0),     // odd
+        encode(OP_DIV, 5, 5, 3),      // x /= 2
+        encode(OP_JMP, 0, 5, 0),
+        encode(OP_MUL, 5, 5, 4),      // odd: (pc = 12) x = 3x + 1
+        encode(OP_INC, 5, 0, 0),
+        encode(OP_JMP, 0, 5, 0),
+        encode(OP_DEC, 0, 0, 0),      // done: (pc = 15)
+        encode(OP_CMP, 6, 0, 2),
+        encode(OP_JGE, 6, 4, 0),      // r0 >= 1, next start
+        encode(OP_HALT, 1, 0, 0),
+    ]
+}
+
+// the smallest argument that makes at least n steps
+fn collatz_arg(n: i64) -> i64 {
+    let (mut m, mut steps) = (1, 0);
+    while steps < n {
+        m += 1;
+        let mut x = m;
+        while x != 1 {
+            x = if x % 2 == 0 { x / 2 } else { 3 * x + 1 };
+            steps += 1;
+        }
+    }
+    m
+}
+
+// multiplier and increment of the bitscan LCG, x = x * MUL + INC wrapping at 64 bits
+pub const BITSCAN_MUL: i64 = 40503;
+pub const BITSCAN_INC: i64 = 12345;
+
+// sum over r0 LCG steps of clz(x) + ctz(x) + popcnt(x). the multiplier pushes x's
+// low bits up into the high ones quickly, so all three counts vary from step to step
+pub fn bitscan() -> Vec<u32> {
+    let mut b = Builder::new();
+    b.loadi(1, 0); // r1 = sum
+    b.loadi(2, 1); // r2 = x
+    b.loadi(3, BITSCAN_MUL as u16);
+    b.loadi(4, BITSCAN_INC as u16);
+    let top = b.here();
+    b.emit(OP_MUL, 2, 2, 3);
+    b.emit(OP_ADD, 2, 2, 4);
+    for op in [OP_CLZ, OP_CTZ, OP_POPCNT] {
+        b.emit(op, 5, 2, 0);
+        b.emit(OP_ADD, 1, 1, 5);
+    }
+    b.emit(OP_DEC, 0, 0, 0);
+    b.jmpnz(0, top);
+    b.emit(OP_HALT, 1, 0, 0);
+    b.code
+}
+
+// r0 iterations of programs/pressure.expr, values in r0..r<registers - 1> and memory
+pub fn pressure(registers: usize) -> Vec<u32> {
+    let compiled = expr::compile(include_str!("../programs/pressure.expr"), registers).expect("pressure.expr compiles");
+    compiled.program.code().to_vec()
+}
+
+// giant:F. the body of function k folds GIANT_PAIRS constants into r1, alternating
+// between adding, multiplying and subtracting, and adds 1 halfway if r1 isn't negative
+// at that point, so each function is three basic blocks
+pub const GIANT_PAIRS: u32 = 28;
+pub const GIANT_BODY: usize = 2 * GIANT_PAIRS as usize + 5;
+pub const GIANT_HOT: u32 = 16;
+const MAX_GIANT: u32 = 1000;
+
+// the i-th constant of function k and what it does with it
+pub fn giant_step(k: u32, i: u32) -> (u8, u16) {
+    let op = [OP_ADD, OP_MUL, OP_SUB][((k + i) % 3) as usize];
+    (op, ((k * 31 + i * 7) % 97 + 1) as u16)
+}
+
+// which functions the hot loop calls, in order
+pub fn giant_hot(functions: u32) -> impl Iterator<Item = u32> {
+    (0..GIANT_HOT).map(move |j| j * functions / GIANT_HOT)
+}
+
+pub fn giant(functions: u32) -> Vec<u32> {
+    // main: one CALL per function, the hot loop, HALT. the functions come after it
+    let main_len = functions as usize + GIANT_HOT as usize + 4;
+    let entry = |k: u32| u16::try_from(main_len + k as usize * GIANT_BODY).expect("giant fits 16-bit targets");
+    let mut b = Builder::new();
+    b.loadi(1, 0); // r1 = acc
+    for k in 0..functions {
+        b.emit(OP_CALL, 0, entry(k) as u8, (entry(k) >> 8) as u8);
+    }
+    let top = b.here();
+    for k in giant_hot(functions) {
+        b.emit(OP_CALL, 0, entry(k) as u8, (entry(k) >> 8) as u8);
+    }
+    b.emit(OP_DEC, 0, 0, 0);
+    b.jmpnz(0, top);
+    b.emit(OP_HALT, 1, 0, 0);
+    debug_assert_eq!(b.code.len(), main_len);
+
+    for k in 0..functions {
+        for i in 0..GIANT_PAIRS {
+            if i == GIANT_PAIRS / 2 {
+                // r3 = 0, r4 = cmp(r1, 0), skip the INC if r1 < 0
+                let skip = b.here() + 4;
+                b.loadi(3, 0);
+                b.emit(OP_CMP, 4, 1, 3);
+                b.emit(OP_JLT, 4, skip as u8, (skip >> 8) as u8);
+                b.emit(OP_INC, 1, 0, 0);
+            }
+            let (op, c) = giant_step(k, i);
+            b.loadi(2, c);
+            b.emit(op, 1, 1, 2);
+        }
+        b.emit(OP_RET, 0, 0, 0);
+    }
+    b.code
+}
+
+// multiplier and modulus of the PRNG driving the fsm (Lehmer, x = 75x mod 65521)
+pub const FSM_MUL: i64 = 75;
+pub const FSM_MOD: i64 = 65521;
+
+// r0 steps of an S-state machine. every step draws x from the PRNG, then the current
+// state k is found by testing state == 0, state == 1, ... in turn. state k adds
+// x * (k + 1) to the accumulator and moves to state (x + k) % S
+pub fn fsm(states: u32) -> Vec<u32> {
+    const ACC: u8 = 1;
+    const STATE: u8 = 2;
+    const X: u8 = 3;
+    const TMP: u8 = 4;
+    const MUL: u8 = 5;
+    const NSTATES: u8 = 6;
+    const TMP2: u8 = 7;
+    const MODULUS: u8 = 8;
+    const ONE: u8 = 9;
+
+    let mut b = Builder::new();
+    b.loadi(ACC, 0);
+    b.loadi(STATE, 0);
+    b.loadi(X, 1);
+    b.loadi(MUL, FSM_MUL as u16);
+    b.loadi(NSTATES, states as u16);
+    b.loadi(MODULUS, FSM_MOD as u16);
+    b.loadi(ONE, 1);
+
+    let top = b.here();
+    b.emit(OP_MUL, X, X, MUL);
+    b.emit(OP_MOD, X, X, MODULUS);
+
+    let mut to_tail = Vec::new();
+    let mut next_check: Option<usize> = None;
+    for k in 0..states {
+        if let Some(at) = next_check.take() {
+            let here = b.here();
+            b.patch(at, here);
+        }
+        // the last state doesn't need a test, it's whatever is left
+        if k + 1 < states {
+            b.loadi(TMP, k as u16);
+            b.emit(OP_SUB, TMP, STATE, TMP);
+            next_check = Some(b.jmpnz_fwd(TMP));
+        }
+        b.loadi(TMP2, (k + 1) as u16);
+        b.emit(OP_MUL, TMP2, TMP2, X);
+        b.emit(OP_ADD, ACC, ACC, TMP2);
+        b.loadi(TMP, k as u16);
+        b.emit(OP_ADD, STATE, X, TMP);
+        b.emit(OP_MOD, STATE, STATE, NSTATES);
+        if k + 1 < states {
+            to_tail.push(b.jmpnz_fwd(ONE));
+        }
+    }
+    let tail = b.here();
+    for at in to_tail {
+        b.patch(at, tail);
+    }
+    b.emit(OP_DEC, 0, 0, 0);
+    b.jmpnz(0, top);
+    b.emit(OP_HALT, ACC, 0, 0);
+    b.code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // what each kernel is supposed to compute, in plain Rust
+    fn expected(name: &str, n: i64) -> i64 {
+        let (base, param) = name.split_once(':').map_or((name, 0), |(b, p)| (b, p.parse().unwrap()));
+        match base {
+            "sum-poly" => (1..=n).map(|i| i * i - i + 1).sum(),
+            "poly" => (1..=n)
+                .map(|x| (0..=param).rev().fold(0i64, |acc, k| acc.wrapping_mul(x).wrapping_add(poly_coeff(k) as i64)))
+                .fold(0, i64::wrapping_add),
+            "loop-nest" => {
+                let trip = ((n as f64).powf(1.0 / param as f64).round() as i64).max(1);
+                trip.pow(param)
+            }
+            "fsm" => {
+                let (mut acc, mut state, mut x) = (0i64, 0i64, 1i64);
+                for _ in 0..n {
+                    x = x * FSM_MUL % FSM_MOD;
+                    acc = acc.wrapping_add(x * (state + 1));
+                    state = (x + state) % param as i64;
+                }
+                acc
+            }
+            "sieve" => {
+                let n = n.clamp(2, DEFAULT_MEMORY_WORDS as i64);
+                (2..n).filter(|&k| (2..k).take_while(|d| d * d <= k).all(|d| k % d != 0)).count() as i64
+            }
+            "array-sum" => (0..n.clamp(1, DEFAULT_MEMORY_WORDS as i64)).map(|k| k * k).sum(),
+            "collatz" => (1..=collatz_arg(n))
+                .map(|start| {
+                    let mut x = start;
+                    std::iter::from_fn(|| (x != 1).then(|| x = if x % 2 == 0 { x / 2 } else { 3 * x + 1 })).count() as i64
+                })
+                .sum(),
+            "bitscan" => {
+                let (mut sum, mut x) = (0i64, 1i64);
+                for _ in 0..n {
+                    x = x.wrapping_mul(BITSCAN_MUL).wrapping_add(BITSCAN_INC);
+                    sum += (x.leading_zeros() + x.trailing_zeros() + x.count_ones()) as i64;
+                }
+                sum
+            }
+            "pressure" => {
+                let (mut acc, mut a, mut b, mut c, mut d, mut e, mut f) = (0i64, 1i64, 2i64, 3i64, 5i64, 7i64, 11i64);
+                for n in (1..=n).rev() {
+                    let (x, y) = (a * b + c, d * e - f);
+                    acc += x % 1000 - y % 1000;
+                    (a, b, c, d) = (b + 1, c % 97, d + x % 13, e % 89);
+                    (e, f) = (f + y % 7, a + n % 31);
+                }
+                acc
+            }
+            "giant" => {
+                let function = |k: u32, acc: i64| {
+                    (0..GIANT_PAIRS).fold(acc, |acc, i| {
+                        let acc = if i == GIANT_PAIRS / 2 && acc >= 0 { acc.wrapping_add(1) } else { acc };
+                        let (op, c) = giant_step(k, i);
+                        let c = c as i64;
+                        match op {
+                            OP_ADD => acc.wrapping_add(c),
+                            OP_MUL => acc.wrapping_mul(c),
+                            _ => acc.wrapping_sub(c),
+                        }
+                    })
+                };
+                let mut acc = (0..param).fold(0, |acc, k| function(k, acc));
+                for _ in 0..n {
+                    acc = giant_hot(param).fold(acc, |acc, k| function(k, acc));
+                }
+                acc
+            }
+            "fib" => {
+                let (mut f, mut next) = (0i64, 1i64);
+                for _ in 0..fib_arg(n) {
+                    (f, next) = (next, f + next);
+                }
+                f
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn kernels_compute_what_they_say() {
+        let names = [
+            "sum-poly", "poly:0", "poly:1", "poly:5", "loop-nest:1", "loop-nest:3", "fsm:1", "fsm:2", "fsm:9", "fib",
+            "sieve", "array-sum", "collatz", "bitscan", "pressure:1", "pressure:5", "pressure:12", "giant:1",
+            "giant:40", "giant:1000",
+        ];
+        for name in names {
+            for n in [1, 2, 3, 7, 100] {
+                let (code, args) = kernel(name, n).unwrap();
+                assert_eq!(crate::run_central(&crate::verify(&code).unwrap(), &args), expected(name, n), "{name} n = {n}");
+            }
+        }
+    }
+}
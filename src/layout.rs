@@ -0,0 +1,127 @@
+// VmState layout under several threads: `--state-layout`
+//
+// the run-to-completion variants keep the machine in locals, but `step` and `resume`
+// write a VmState in memory on every instruction: pc, retired, the destination
+// register. a host running one Vm per thread keeps them next to each other (a
+// Vec<Vm>, a pool), and when one Vm's registers share a cache line with the Vm next
+// to it, two cores writing their own Vms fight over that line anyway. this steps one
+// Vm per thread, the Vms built together into one Vec, for 1, 2, 4... threads up to the
+// machine's, and prints ns per step and thread: flat as threads get added is what
+// no sharing looks like
+//
+// which layout it measures is the build's, `--features ordered-state,aligned-state`
+// (src/state.rs). `cargo xtask state-layout` builds all four and prints them side by
+// side. the header says where the registers ended up: their offset in the VmState,
+// how many cache lines they span, and in how many Vms of the Vec they share a line
+// with a neighbouring Vm
+
+use std::thread;
+use std::time::Instant;
+
+use rust_goto::{Status, Vm, VmState};
+
+use crate::Workload;
+use crate::report::{SAMPLES, Stats};
+
+const CACHE_LINE: usize = 64;
+// per thread and sample
+const STEPS: u64 = 2_000_000;
+// what one resume gets, a reset in between when the program ends sooner
+const CHUNK: u64 = 10_000;
+const MAX_THREADS: usize = 16;
+
+const LAYOUT: &str = match (cfg!(feature = "ordered-state"), cfg!(feature = "aligned-state")) {
+    (false, false) => "default",
+    (true, false) => "ordered",
+    (false, true) => "aligned",
+    (true, true) => "ordered+aligned",
+};
+
+pub fn run(workload: &Workload) {
+    let Workload { description, args, .. } = workload;
+    let vms = build(workload, 2);
+    let regs_at = vms[0].state().regs().as_ptr() as usize - (vms[0].state() as *const VmState as usize);
+    println!("one Vm per thread stepped with resume, ns per step and thread, median of {SAMPLES} samples");
+    println!("Program: {description}");
+    println!(
+        "layout: {LAYOUT}, VmState {} B aligned to {}, registers at +{regs_at} over {} cache lines, Vm {} B\n",
+        size_of::<VmState>(),
+        align_of::<VmState>(),
+        lines_of(&vms[0]),
+        size_of::<Vm>(),
+    );
+
+    for threads in thread_counts() {
+        let mut vms = build(workload, threads);
+        let shared = (0..threads).filter(|&i| shares_a_line(&vms, i)).count();
+        let samples: Vec<f64> = (0..SAMPLES)
+            .map(|_| {
+                let start = Instant::now();
+                thread::scope(|s| {
+                    for vm in &mut vms {
+                        s.spawn(|| step_for(vm, args, STEPS));
+                    }
+                });
+                start.elapsed().as_nanos() as f64 / STEPS as f64
+            })
+            .collect();
+        let label = format!("{threads} thread{}", if threads == 1 { "" } else { "s" });
+        println!(
+            "{label:>12}: {:>8.2} ns/step  (registers sharing a line with a neighbour in {shared} of {threads})",
+            Stats::of(&samples).median
+        );
+    }
+}
+
+// `n` Vms reset with the workload's args, in one allocation next to each other
+fn build(workload: &Workload, n: usize) -> Vec<Vm> {
+    let mut vms = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut vm = Vm::new(workload.program.clone());
+        vm.reset(&workload.args).expect("args checked by the workload");
+        vms.push(vm);
+    }
+    vms
+}
+
+fn step_for(vm: &mut Vm, args: &[i64], steps: u64) {
+    let mut left = steps;
+    while left > 0 {
+        let before = vm.state().retired();
+        let status = vm.resume(left.min(CHUNK));
+        // a program stuck on WAITEVENT retires nothing, counting it as one keeps this going
+        left = left.saturating_sub((vm.state().retired() - before).max(1));
+        if status != Status::OutOfFuel {
+            vm.reset(args).expect("args checked by the workload");
+        }
+    }
+}
+
+fn lines_of(vm: &Vm) -> usize {
+    let (first, last) = register_lines(vm);
+    last - first + 1
+}
+
+// the first and the last cache line the registers touch
+fn register_lines(vm: &Vm) -> (usize, usize) {
+    let regs = vm.state().regs();
+    let start = regs.as_ptr() as usize;
+    (start / CACHE_LINE, (start + size_of_val(regs) - 1) / CACHE_LINE)
+}
+
+// whether vms[i]'s registers are on a line vms[i - 1] or vms[i + 1] has bytes in
+fn shares_a_line(vms: &[Vm], i: usize) -> bool {
+    let (first, last) = register_lines(&vms[i]);
+    let line = |vm: &Vm, end: usize| (vm as *const Vm as usize + end) / CACHE_LINE;
+    let before = i > 0 && line(&vms[i - 1], size_of::<Vm>() - 1) >= first;
+    let after = i + 1 < vms.len() && line(&vms[i + 1], 0) <= last;
+    before || after
+}
+
+// 1, 2, 4... up to the cores there are, that many as the last one
+fn thread_counts() -> Vec<usize> {
+    let cores = thread::available_parallelism().map_or(1, |n| n.get()).min(MAX_THREADS);
+    let mut counts: Vec<usize> = (0..).map(|p| 1 << p).take_while(|&n| n < cores).collect();
+    counts.push(cores);
+    counts
+}
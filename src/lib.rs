@@ -0,0 +1,3812 @@
+// Central loop versus a Duplicated-Match Dispatch
+
+// Made by VBXQ (Haydar)(Celeste) - 2026
+
+// Here's the question: 
+// 
+// If every opcode handler ends with its own copy of the full
+// dispatch match, will LLVM merge/inline them into computed-goto-style
+// threaded dispatch? 
+// 
+// Or does it just bloat code with redundant matches?
+
+// This is my try on optimizing virtual machine/interpreters written in Rust
+// Made a really simple VM here just showcase it 
+
+// TLDR;- it works ! 
+// This file is the VM itself, as a library: the opcodes, every dispatch variant and
+// the typed `Vm` API at the bottom for embedding it. src/main.rs is the benchmark
+// driver on top
+
+// `--features safe-only` swaps every unchecked fetch for a normal bounds-checked one
+// (the README shows the dispatch structure survives that), and this makes sure no
+// unsafe sneaks back in for that build
+#![cfg_attr(feature = "safe-only", forbid(unsafe_code))]
+// `become` for the tailcall variant, the only thing in the crate that needs nightly
+#![cfg_attr(feature = "nightly-tailcall", feature(explicit_tail_calls), allow(incomplete_features))]
+
+#[cfg(all(feature = "safe-only", feature = "c-reference"))]
+compile_error!("the c-reference variant is called through FFI, it can't be part of a safe-only build");
+
+#[cfg(all(feature = "safe-only", feature = "plugins"))]
+compile_error!("plugins are loaded and called through FFI, they can't be part of a safe-only build");
+
+#[cfg(all(feature = "safe-only", feature = "jit"))]
+compile_error!("the jit writes machine code into an mmap'd buffer and calls it, it can't be part of a safe-only build");
+
+#[cfg(all(feature = "jit", not(all(target_arch = "x86_64", target_os = "linux"))))]
+compile_error!("the jit emits x86-64 and maps its code with Linux syscalls, it only builds for x86_64 Linux");
+
+#[cfg(all(feature = "sparse-opcodes", any(feature = "c-reference", feature = "plugins")))]
+compile_error!("compare/goto.c and the plugin ABI number opcodes densely, they can't be used with sparse-opcodes");
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use memory::{DirtyPages, Memory, MemoryImage};
+use semantics::apply;
+
+pub mod analyze;
+pub mod asm;
+pub mod blocks;
+pub mod bytes;
+pub mod checkpoint;
+pub mod config;
+#[cfg(not(feature = "sparse-opcodes"))]
+pub mod conformance;
+pub mod expr;
+pub mod fuse;
+pub mod fuzz;
+pub mod host;
+#[cfg(feature = "c-reference")]
+pub mod c_reference;
+#[cfg(feature = "jit")]
+pub mod jit;
+pub mod json;
+pub mod kernels;
+pub mod marshal;
+pub mod memory;
+#[cfg(feature = "plugins")]
+pub mod plugin;
+pub mod pool;
+pub mod program;
+pub mod scheduler;
+pub mod semantics;
+pub mod stack_machine;
+pub mod state;
+pub mod structured;
+#[cfg(feature = "nightly-tailcall")]
+pub mod tailcall;
+pub mod toml;
+pub mod verify;
+
+pub use config::{ConfigError, VmConfig};
+pub use fuse::fuse;
+pub use host::HostCtx;
+pub use memory::Segment;
+pub use pool::VmPool;
+pub use state::{StateDiff, VmState};
+pub use program::{DEFAULT_MEMORY_WORDS, FallOffEnd, MAX_MEMORY_WORDS, Program, ProgramError, SUPPORTED_FEATURES};
+pub use verify::{VerifiedProgram, VerifyError, verify};
+
+// opcode numbers, dense by default. `--features sparse-opcodes` spreads the same
+// opcodes over the byte with irregular gaps: too sparse for LLVM to build a jump
+// table, so every `match op` becomes a tree of compares instead. real VMs with holes
+// in their opcode space get exactly that, this lets us see what it does to each
+// dispatch strategy without touching any of them
+macro_rules! opcodes {
+    ($($name:ident = $dense:expr, $sparse:expr;)*) => {
+        $(
+            #[cfg(not(feature = "sparse-opcodes"))]
+            pub const $name: u8 = $dense;
+            #[cfg(feature = "sparse-opcodes")]
+            pub const $name: u8 = $sparse;
+        )*
+    };
+}
+
+opcodes! {
+    OP_HALT = 0, 0x07;
+    OP_LOADI = 1, 0x1D;
+    OP_ADD = 2, 0x2E;
+    OP_SUB = 3, 0x4B;
+    OP_MUL = 4, 0x60;
+    OP_DIV = 5, 0x85;
+    OP_MOD = 6, 0x9A;
+    OP_INC = 7, 0xB3;
+    OP_DEC = 8, 0xC9;
+    OP_JMPNZ = 9, 0xE2;
+    OP_MOV = 10, 0xF4;
+    OP_CALL = 11, 0x38;
+    OP_RET = 12, 0x71;
+    OP_PUSH = 13, 0xA6;
+    OP_POP = 14, 0xD7;
+    OP_LOAD = 15, 0x13;
+    OP_STORE = 16, 0x8C;
+    OP_MEMCPY = 17, 0x5A;
+    OP_MEMSET = 18, 0xBD;
+    OP_CMP = 19, 0x26;
+    OP_JMP = 20, 0x52;
+    OP_JEQ = 21, 0x69;
+    OP_JNE = 22, 0x7C;
+    OP_JLT = 23, 0xA1;
+    OP_JGE = 24, 0xEB;
+    OP_WAITEVENT = 25, 0x44;
+    // superinstructions, only ever written by fuse::fuse
+    OP_ADDADD = 26, 0x5F;
+    OP_MULSUB = 27, 0x93;
+    OP_DECJNZ = 28, 0xCE;
+    // bit scans, one host instruction each where the target has them (lzcnt, tzcnt,
+    // popcnt on x86-64 with the right target-cpu, clz/rbit/cnt on aarch64)
+    OP_CLZ = 29, 0x3B;
+    OP_CTZ = 30, 0x78;
+    OP_POPCNT = 31, 0xDA;
+    // calls the host's trap handler with the 16-bit immediate in a/b, see Vm::set_trap_handler
+    OP_TRAP = 32, 0xAF;
+    // a host call bound on first use: asks the Vm's resolver which host function the
+    // 16-bit immediate means and patches itself into a TRAP to it, see Vm::set_resolver
+    OP_RESOLVE_CALL = 33, 0xC2;
+}
+
+// not an opcode in either numbering: what comes after the last instruction, where
+// there's anything at all. it pads bundles and ends the lowered code of a program that
+// traps when it runs off the end (FallOffEnd::Trap), and handle! stops there with
+// TrapKind::FellOffEnd. every other variant has no handler for it, so -1 all the same
+pub const OP_PAST_END: u8 = 0xFF;
+
+// how many opcodes there are. with the dense numbering it's also one past the last
+// one, anything >= this is rejected with -1
+pub const OP_COUNT: u8 = 34;
+
+// whatever the numbering
+#[inline(always)]
+pub fn is_opcode(op: u8) -> bool {
+    matches!(
+        op,
+        OP_HALT
+            | OP_LOADI
+            | OP_ADD
+            | OP_SUB
+            | OP_MUL
+            | OP_DIV
+            | OP_MOD
+            | OP_INC
+            | OP_DEC
+            | OP_JMPNZ
+            | OP_MOV
+            | OP_CALL
+            | OP_RET
+            | OP_PUSH
+            | OP_POP
+            | OP_LOAD
+            | OP_STORE
+            | OP_MEMCPY
+            | OP_MEMSET
+            | OP_CMP
+            | OP_JMP
+            | OP_JEQ
+            | OP_JNE
+            | OP_JLT
+            | OP_JGE
+            | OP_WAITEVENT
+            | OP_ADDADD
+            | OP_MULSUB
+            | OP_DECJNZ
+            | OP_CLZ
+            | OP_CTZ
+            | OP_POPCNT
+            | OP_TRAP
+            | OP_RESOLVE_CALL
+    )
+}
+
+// the opcodes with a 16-bit code address in a/b: every jump, and CALL
+#[inline(always)]
+pub fn has_target(op: u8) -> bool {
+    matches!(op, OP_JMPNZ | OP_CALL | OP_JMP | OP_JEQ | OP_JNE | OP_JLT | OP_JGE | OP_DECJNZ)
+}
+
+// whether a conditional branch on `v` is taken (JMP always is, JNE is JMPNZ under
+// another name). false for anything that isn't a branch
+#[inline(always)]
+fn branch_taken(op: u8, v: i64) -> bool {
+    match op {
+        OP_JMP => true,
+        // DECJNZ tests the register after the decrement
+        OP_JMPNZ | OP_JNE | OP_DECJNZ => v != 0,
+        OP_JEQ => v == 0,
+        OP_JLT => v < 0,
+        OP_JGE => v >= 0,
+        _ => false,
+    }
+}
+
+#[inline(always)]
+pub fn encode(op: u8, dst: u8, a: u8, b: u8) -> u32 {
+    (op as u32) | ((dst as u32) << 8) | ((a as u32) << 16) | ((b as u32) << 24)
+}
+
+#[inline(always)]
+fn imm16(a: u8, b: u8) -> i64 {
+    ((a as u16) | ((b as u16) << 8)) as i64
+}
+
+// ADDADD/MULSUB: two 3-register ops in one word (see fuse.rs), the first one's
+// registers in the low nibbles of dst/a/b and the second one's in the high nibbles.
+// the second runs after the first, so it sees what the first wrote
+#[inline(always)]
+fn fused_binops<F, G>(regs: &mut [i64; NREGS], dst: u8, a: u8, b: u8, first: F, second: G)
+where
+    F: Fn(i64, i64) -> i64,
+    G: Fn(i64, i64) -> i64,
+{
+    let (lo, hi) = (|r: u8| (r & 0xF) as usize, |r: u8| (r >> 4) as usize);
+    regs[lo(dst)] = first(regs[lo(a)], regs[lo(b)]);
+    regs[hi(dst)] = second(regs[hi(a)], regs[hi(b)]);
+}
+
+pub const NREGS: usize = 16;
+
+// how deep CALL can nest, and separately how many values PUSH can stack up. going
+// past either stops the program with -1, same for RET or POP on an empty stack
+pub const STACK_DEPTH: usize = 1024;
+
+// LOAD/STORE address: the base register plus the unsigned 8-bit offset in the b byte.
+// memory is bounds-checked on every access, addresses are data and the verifier
+// can't say anything about them. negative ones wrap to something huge and miss too
+#[inline(always)]
+fn address(base: i64, offset: u8) -> usize {
+    base.wrapping_add(offset as i64) as usize
+}
+
+// CALL/RET/PUSH/POP state. return addresses and pushed values live apart on purpose:
+// RET jumps to whatever it pops without a bounds check, so only CALL gets to put
+// addresses there, and those are always the instruction after a verified CALL. POP
+// can't hand RET a made-up pc that way
+//
+// empty Vecs don't allocate, programs without calls never touch the heap for this
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Stack {
+    calls: Vec<usize>,
+    values: Vec<i64>,
+}
+
+impl Stack {
+    // false on overflow
+    #[inline(always)]
+    fn call(&mut self, ret: usize) -> bool {
+        if self.calls.len() == STACK_DEPTH {
+            return false;
+        }
+        self.calls.push(ret);
+        true
+    }
+
+    #[inline(always)]
+    fn ret(&mut self) -> Option<usize> {
+        self.calls.pop()
+    }
+
+    // false on overflow
+    #[inline(always)]
+    fn push(&mut self, v: i64) -> bool {
+        if self.values.len() == STACK_DEPTH {
+            return false;
+        }
+        self.values.push(v);
+        true
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<i64> {
+        self.values.pop()
+    }
+
+    fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    // empty again, keeping what both stacks allocated
+    fn clear(&mut self) {
+        self.calls.clear();
+        self.values.clear();
+    }
+}
+
+// fetch code[pc], unchecked unless we're building safe-only. sound because every
+// variant only runs a VerifiedProgram (or a translation of one), see verify.rs
+#[cfg(not(feature = "safe-only"))]
+macro_rules! fetch {
+    ($code:expr, $pc:expr) => {
+        *unsafe { $code.get_unchecked($pc) }
+    };
+}
+
+#[cfg(feature = "safe-only")]
+macro_rules! fetch {
+    ($code:expr, $pc:expr) => {
+        $code[$pc]
+    };
+}
+
+// execute one opcode, mutating regs/pc, and returns Some(val) on halt, it's shared by both versions so the actual computation is identiacal
+macro_rules! exec_one {
+    ($code:expr, $regs:expr, $pc:expr) => {{
+        let instr = fetch!($code, $pc);
+        let op = (instr & 0xFF) as u8;
+        let dst = ((instr >> 8) & 0xFF) as usize;
+        let a = ((instr >> 16) & 0xFF) as u8;
+        let b = ((instr >> 24) & 0xFF) as u8;
+        $pc += 1;
+        (op, dst, a, b)
+    }};
+}
+
+// why a program stopped with -1, for hooks. a guest trap handler (Vm::set_trap_vector)
+// gets it as a number, its position here: BadOpcode is 0, Unresolved 9
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrapKind {
+    BadOpcode,
+    // CALL past STACK_DEPTH return addresses, or PUSH past STACK_DEPTH values
+    StackOverflow,
+    // RET or POP with nothing to pop
+    StackUnderflow,
+    // LOAD, STORE, MEMCPY or MEMSET outside the linear memory
+    MemoryOutOfBounds,
+    // STORE, MEMCPY or MEMSET into a read-only data segment
+    WriteProtected,
+    // WAITEVENT in a run-to-completion variant, where no host is around to post one
+    NoEvent,
+    // TRAP with no handler to call: in a run-to-completion variant, or in a Vm nobody
+    // gave one with set_trap_handler
+    Unhandled,
+    // past the last instruction of a program with FallOffEnd::Trap
+    FellOffEnd,
+    // DIV or MOD by zero, only in a Vm with a trap vector for it or set_div_by_zero_traps:
+    // the ISA says x / 0 is 0, a guest or a host that wants to catch it asks
+    DivByZero,
+    // RESOLVE_CALL with nothing to bind it to: in a run-to-completion variant, in a Vm
+    // with no resolver, or when the resolver doesn't know the name
+    Unresolved,
+}
+
+impl TrapKind {
+    pub const ALL: [TrapKind; 10] = [
+        TrapKind::BadOpcode,
+        TrapKind::StackOverflow,
+        TrapKind::StackUnderflow,
+        TrapKind::MemoryOutOfBounds,
+        TrapKind::WriteProtected,
+        TrapKind::NoEvent,
+        TrapKind::Unhandled,
+        TrapKind::FellOffEnd,
+        TrapKind::DivByZero,
+        TrapKind::Unresolved,
+    ];
+
+    // what a guest trap handler pops
+    pub fn code(self) -> i64 {
+        self as i64
+    }
+}
+
+// a trap a Vm stepped into, with the guest calls it happened under: `Vm::trap` after
+// `step` or `resume` came back with -1. only the stepping side keeps one, it has the
+// call stack at hand. the run-to-completion variants return -1 and that's all, hooks
+// get the kind and the pc
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trap {
+    kind: TrapKind,
+    frames: Vec<Frame>,
+}
+
+// one pc of a backtrace, with what the program knows about it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub pc: usize,
+    // the name at or before pc and the distance from it, see Program::symbolize
+    pub function: Option<(String, usize)>,
+    // the source line, for assembled programs
+    pub line: Option<usize>,
+}
+
+impl Frame {
+    fn at(program: &Program, pc: usize) -> Frame {
+        let function = program.symbolize(pc).map(|(name, offset)| (name.to_string(), offset));
+        Frame { pc, function, line: program.line_at(pc) }
+    }
+}
+
+impl Trap {
+    // the trapping pc first, then every CALL still waiting for its RET, innermost
+    // first. a return address is the pc after its CALL
+    fn capture(kind: TrapKind, pc: usize, calls: &[usize], program: &Program) -> Trap {
+        let callers = calls.iter().rev().map(|&ret| ret - 1);
+        Trap { kind, frames: std::iter::once(pc).chain(callers).map(|pc| Frame::at(program, pc)).collect() }
+    }
+
+    pub fn kind(&self) -> TrapKind {
+        self.kind
+    }
+
+    // where it happened, the program's length for TrapKind::FellOffEnd
+    pub fn pc(&self) -> usize {
+        self.frames[0].pc
+    }
+
+    // the trapping instruction, then the CALLs that led there, innermost first
+    pub fn backtrace(&self) -> &[Frame] {
+        &self.frames
+    }
+}
+
+// `fib+2 (pc 7, line 12)`, or just `pc 7` with nothing to go on
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pc = self.pc;
+        match (&self.function, self.line) {
+            (Some((name, 0)), Some(line)) => write!(f, "{name} (pc {pc}, line {line})"),
+            (Some((name, offset)), Some(line)) => write!(f, "{name}+{offset} (pc {pc}, line {line})"),
+            (Some((name, 0)), None) => write!(f, "{name} (pc {pc})"),
+            (Some((name, offset)), None) => write!(f, "{name}+{offset} (pc {pc})"),
+            (None, Some(line)) => write!(f, "pc {pc}, line {line}"),
+            (None, None) => write!(f, "pc {pc}"),
+        }
+    }
+}
+
+// the kind and where, then one `called from` line per frame
+impl fmt::Display for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} at {}", self.kind, self.frames[0])?;
+        for frame in &self.frames[1..] {
+            write!(f, "\n  called from {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+// macro that does the work for one decoded instruction., Some(val) on Halt, and None otherwise
+macro_rules! handle {
+    ($regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr) => {
+        handle!($regs, $stack, $mem, $pc, $op, $dst, $a, $b, on_trap: |_trap| {}, then: {})
+    };
+    ($regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr,
+     on_trap: |$trap:ident| $on_trap:block) => {
+        handle!($regs, $stack, $mem, $pc, $op, $dst, $a, $b, on_trap: |$trap| $on_trap, then: {})
+    };
+    ($regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr, then: $then:block) => {
+        handle!($regs, $stack, $mem, $pc, $op, $dst, $a, $b, on_trap: |_trap| {}, then: $then)
+    };
+    // $on_trap runs with $trap bound to the TrapKind right before any -1 exit, for run_hooked.
+    // $then goes at the end of every arm that carries on, each arm gets a copy of its
+    // own: that's how threaded_dispatch! puts the next dispatch at the tail of each handler
+    ($regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr,
+     on_trap: |$trap:ident| $on_trap:block, then: $then:block) => {
+        match $op {
+            OP_HALT => return $regs[$dst],
+            OP_LOADI => {
+                $regs[$dst] = imm16($a, $b);
+                $then
+            }
+            OP_ADD => {
+                apply::add(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_SUB => {
+                apply::sub(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_MUL => {
+                apply::mul(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_DIV => {
+                apply::div(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_MOD => {
+                apply::rem(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_INC => {
+                apply::inc(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_DEC => {
+                apply::dec(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_JMPNZ => {
+                if $regs[$dst] != 0 { $pc = imm16($a, $b) as usize; }
+                $then
+            }
+            OP_JMP => {
+                $pc = imm16($a, $b) as usize;
+                $then
+            }
+            OP_JEQ => {
+                if $regs[$dst] == 0 { $pc = imm16($a, $b) as usize; }
+                $then
+            }
+            OP_JNE => {
+                if $regs[$dst] != 0 { $pc = imm16($a, $b) as usize; }
+                $then
+            }
+            OP_JLT => {
+                if $regs[$dst] < 0 { $pc = imm16($a, $b) as usize; }
+                $then
+            }
+            OP_JGE => {
+                if $regs[$dst] >= 0 { $pc = imm16($a, $b) as usize; }
+                $then
+            }
+            OP_MOV => {
+                apply::mov(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_CLZ => {
+                apply::clz(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_CTZ => {
+                apply::ctz(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_POPCNT => {
+                apply::popcnt(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_CMP => {
+                apply::cmp(&mut $regs, $dst, $a, $b);
+                $then
+            }
+            OP_CALL => {
+                if !$stack.call($pc) { handle!(@trap StackOverflow, $trap, $on_trap); }
+                $pc = imm16($a, $b) as usize;
+                $then
+            }
+            OP_RET => {
+                match $stack.ret() {
+                    Some(ret) => $pc = ret,
+                    None => handle!(@trap StackUnderflow, $trap, $on_trap),
+                }
+                $then
+            }
+            OP_PUSH => {
+                if !$stack.push($regs[$dst]) { handle!(@trap StackOverflow, $trap, $on_trap); }
+                $then
+            }
+            OP_POP => {
+                match $stack.pop() {
+                    Some(v) => $regs[$dst] = v,
+                    None => handle!(@trap StackUnderflow, $trap, $on_trap),
+                }
+                $then
+            }
+            OP_LOAD => {
+                match $mem.get(address($regs[$a as usize], $b)) {
+                    Some(&v) => $regs[$dst] = v,
+                    None => handle!(@trap MemoryOutOfBounds, $trap, $on_trap),
+                }
+                $then
+            }
+            OP_STORE => {
+                match $mem.get_mut(address($regs[$a as usize], $b)) {
+                    Ok(slot) => *slot = $regs[$dst],
+                    Err(trap) => handle!(@raise trap, $trap, $on_trap),
+                }
+                $then
+            }
+            OP_MEMCPY => {
+                if let Err(trap) = $mem.copy($regs[$dst], $regs[$a as usize], $regs[$b as usize]) {
+                    handle!(@raise trap, $trap, $on_trap);
+                }
+                $then
+            }
+            OP_MEMSET => {
+                if let Err(trap) = $mem.fill($regs[$dst], $regs[$a as usize], $regs[$b as usize]) {
+                    handle!(@raise trap, $trap, $on_trap);
+                }
+                $then
+            }
+            // only Vm::resume can suspend, everywhere else waiting would be forever
+            OP_WAITEVENT => handle!(@trap NoEvent, $trap, $on_trap),
+            // same for TRAP, the handler belongs to a Vm
+            OP_TRAP => handle!(@trap Unhandled, $trap, $on_trap),
+            // and RESOLVE_CALL, with nobody to ask and no code it could patch
+            OP_RESOLVE_CALL => handle!(@trap Unresolved, $trap, $on_trap),
+            // superinstructions (fuse.rs): both halves, then step over the second one,
+            // which is still there as a plain instruction for jumps that land on it
+            OP_ADDADD => {
+                fused_binops(&mut $regs, $dst as u8, $a, $b, semantics::add, semantics::add);
+                $pc += 1;
+                $then
+            }
+            OP_MULSUB => {
+                fused_binops(&mut $regs, $dst as u8, $a, $b, semantics::mul, semantics::sub);
+                $pc += 1;
+                $then
+            }
+            OP_DECJNZ => {
+                apply::dec(&mut $regs, $dst, $a, $b);
+                if $regs[$dst] != 0 { $pc = imm16($a, $b) as usize; } else { $pc += 1; }
+                $then
+            }
+            // off the end is the one unknown opcode a verified program can get to, told
+            // apart here on the way out rather than with an arm of its own in the dispatch
+            _ => {
+                let kind = match $op {
+                    $crate::OP_PAST_END => $crate::TrapKind::FellOffEnd,
+                    _ => $crate::TrapKind::BadOpcode,
+                };
+                handle!(@raise kind, $trap, $on_trap)
+            }
+        }
+    };
+    (@trap $kind:ident, $trap:ident, $on_trap:block) => {
+        handle!(@raise $crate::TrapKind::$kind, $trap, $on_trap)
+    };
+    (@raise $value:expr, $trap:ident, $on_trap:block) => {{
+        #[allow(unused_variables)]
+        let $trap = $value;
+        $on_trap
+        return -1;
+    }};
+}
+
+// the plugin loop and the checkpointed runner reuse the same handlers
+pub(crate) use {exec_one, fetch, handle};
+
+// the calling convention, for hosts (Vm::call) and for the assembler's register
+// aliases (`arg0`, `ret`, `acc`, `tmp0`):
+//
+//   r0..r7    arg0..arg7, the arguments, seeded by the host before the first instruction
+//   r0        ret, where a subroutine leaves its result
+//   r8        acc, an accumulator
+//   r9..r14   tmp0..tmp5, scratch
+//   r15       no alias: a program that leaves it alone can have a non-zero entry,
+//             see Program::lowered
+//
+// nothing enforces it, the handlers don't care which register is which
+pub const ARG_REGS: usize = 8;
+pub const RET_REG: u8 = 0;
+pub const ACC_REG: u8 = 8;
+pub const TMP_REGS: std::ops::Range<u8> = 9..15;
+
+// host-provided arguments go into r0, r1, ... before the first instruction, the
+// rest of the registers start at zero. that way inputs don't have to be baked into
+// LOADIs when the program is built (and aren't limited to 16-bit immediates).
+// more than NREGS args panics here, in every run_* function: they're the benchmark's
+// hot path and the driver never passes that many. Vm::run/reset check first and
+// return VmError::TooManyArgs instead
+#[inline(always)]
+fn seed_regs(args: &[i64]) -> [i64; NREGS] {
+    assert!(args.len() <= NREGS, "{} arguments but only {NREGS} registers", args.len());
+    let mut regs = [0i64; NREGS];
+    regs[..args.len()].copy_from_slice(args);
+    regs
+}
+
+
+//////////////////////////////////////////////////////
+// VERSION A : Classic dispatch loop
+//////////////////////////////////////////////////////
+// one decode+math per iteration, all arms jump back to loop head!
+#[inline(never)]
+pub fn run_central(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    let code = program.code();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let (op, dst, a, b) = exec_one!(code, regs, pc);
+        handle!(regs, stack, mem, pc, op, dst, a, b);
+    }
+}
+
+//////////////////////////////////////////////////////
+// N levels of dispatch, from a macro
+//////////////////////////////////////////////////////
+// B and C below are this loop unrolled to 2 and 3 levels: every level-1 arm does its
+// work, then decodes and dispatches the next instruction inline, and at depth 3 once
+// more after that, before falling back to the loop. they used to be written out by
+// hand, a wall of arms per level; threaded_dispatch! writes them for any depth, so
+// where more copies stop paying for themselves is a --depth sweep instead of another
+// thousand lines. `threaded_dispatch!(run_threaded_4, depth = 4)` is
+//
+//   loop {
+//       level 1: decode, dispatch, the handler, then at the tail of each arm:
+//           level 2: decode, dispatch, the handler
+//           ...
+//           level N: decode, dispatch, the handler
+//   }
+//
+// so the code grows linearly, N-1 copies of handle! per opcode. depth 1 would be
+// run_central
+macro_rules! threaded_dispatch {
+    ($name:ident, depth = 2) => { threaded_dispatch!(@fn $name [_]); };
+    ($name:ident, depth = 3) => { threaded_dispatch!(@fn $name [_ _]); };
+    ($name:ident, depth = 4) => { threaded_dispatch!(@fn $name [_ _ _]); };
+    ($name:ident, depth = 5) => { threaded_dispatch!(@fn $name [_ _ _ _]); };
+    ($name:ident, depth = 6) => { threaded_dispatch!(@fn $name [_ _ _ _ _]); };
+    ($name:ident, depth = 7) => { threaded_dispatch!(@fn $name [_ _ _ _ _ _]); };
+    ($name:ident, depth = 8) => { threaded_dispatch!(@fn $name [_ _ _ _ _ _ _]); };
+    // one `_` per level after the first
+    (@fn $name:ident [$($level:tt)*]) => {
+        #[inline(never)]
+        pub fn $name(program: &VerifiedProgram, args: &[i64]) -> i64 {
+            let code = program.code();
+            let mut regs = seed_regs(args);
+            let mut pc: usize = 0;
+            let mut stack = Stack::default();
+            let mut mem = program.memory();
+
+            loop {
+                let (op, dst, a, b) = exec_one!(code, regs, pc);
+                handle!(regs, stack, mem, pc, op, dst, a, b, then: {
+                    $(threaded_dispatch!(@level $level code, regs, stack, mem, pc);)*
+                });
+            }
+        }
+    };
+    (@level _ $code:ident, $regs:ident, $stack:ident, $mem:ident, $pc:ident) => {
+        let (op, dst, a, b) = exec_one!($code, $regs, $pc);
+        handle!($regs, $stack, $mem, $pc, op, dst, a, b);
+    };
+}
+
+//////////////////////////////////////////////////////
+// VERSION B : Duplicated match at tail of every handler
+//////////////////////////////////////////////////////
+// here's my strategy : each match arms executes the handler, then inline decodes the next instruction, and dispatches it
+// through a second inner match, the inner match arms do their work and continue the outer loop
+
+// so, this gives LLVM a copy of the dispatch table at the tail of each handler, one per opcode
+// if LLVM threads the dispatch, each of those copies becomes an indirect branch! so, computed goto
+// if LLVM tail merges them, they collapse into one so same as version A
+
+// the outer loop here is only needed as a "safety net", in a fully threaded execution the contiinue at the bottom
+// of the inner match keeps bouncing through outer => handler => inner dispatch => handler and so on
+threaded_dispatch!(run_threaded, depth = 2);
+
+//////////////////////////////////////////////////////
+// VERSION C : deeper unrolling, 3 levels of inline dispatch
+//////////////////////////////////////////////////////
+// if 2 level isn't enough for LLVM to see the pattern, we can try 3 levels
+threaded_dispatch!(run_threaded_deep, depth = 3);
+
+// and deeper, for --depth. each level is another ~70 KB of code in one function, and
+// one huge function is what LLVM is slowest at: 5 to 8 add minutes to the release
+// build, so they only come with `--features deep-dispatch`
+threaded_dispatch!(run_threaded_4, depth = 4);
+#[cfg(feature = "deep-dispatch")]
+threaded_dispatch!(run_threaded_5, depth = 5);
+#[cfg(feature = "deep-dispatch")]
+threaded_dispatch!(run_threaded_6, depth = 6);
+#[cfg(feature = "deep-dispatch")]
+threaded_dispatch!(run_threaded_7, depth = 7);
+#[cfg(feature = "deep-dispatch")]
+threaded_dispatch!(run_threaded_8, depth = 8);
+
+// the loop at depth N is THREADED_DEPTHS[N - 1], depth 1 being central dispatch
+pub const THREADED_DEPTHS: &[RunFn] = &[
+    run_central,
+    run_threaded,
+    run_threaded_deep,
+    run_threaded_4,
+    #[cfg(feature = "deep-dispatch")]
+    run_threaded_5,
+    #[cfg(feature = "deep-dispatch")]
+    run_threaded_6,
+    #[cfg(feature = "deep-dispatch")]
+    run_threaded_7,
+    #[cfg(feature = "deep-dispatch")]
+    run_threaded_8,
+];
+
+//////////////////////////////////////////////////////
+// VERSION D : threaded dispatch + operand pre-load
+//////////////////////////////////////////////////////
+// same 2-level structure as B, but the tail of each handler also loads the next
+// instruction's source registers *before* the indirect jump. the question is whether
+// an OoO core can hide the register-file load latency behind the branch, instead of
+// the handler having to wait for it after landing
+//
+// the preload reads regs[dst], regs[a] and regs[b] whatever the opcode is. for LOADI
+// and friends a/b are immediates, not registers, so the indices get masked to stay in
+// the register file (the loaded value just goes unused). real register operands are
+// always < NREGS already, Program::new refuses anything else, so the mask never
+// changes which register a handler sees
+macro_rules! decode_preload {
+    ($code:expr, $regs:expr, $pc:expr) => {{
+        let (op, dst, a, b) = exec_one!($code, $regs, $pc);
+        let vd = $regs[dst & (NREGS - 1)];
+        let va = $regs[a as usize & (NREGS - 1)];
+        let vb = $regs[b as usize & (NREGS - 1)];
+        (op, dst, a, b, vd, va, vb)
+    }};
+}
+
+// handle! working off the preloaded values
+macro_rules! handle_preloaded {
+    ($regs:expr, $stack:expr, $mem:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr, $vd:expr, $va:expr, $vb:expr) => {
+        match $op {
+            OP_HALT => return $vd,
+            OP_LOADI => { $regs[$dst] = imm16($a, $b); }
+            OP_ADD => { $regs[$dst] = semantics::add($va, $vb); }
+            OP_SUB => { $regs[$dst] = semantics::sub($va, $vb); }
+            OP_MUL => { $regs[$dst] = semantics::mul($va, $vb); }
+            OP_DIV => { $regs[$dst] = semantics::div($va, $vb); }
+            OP_MOD => { $regs[$dst] = semantics::rem($va, $vb); }
+            OP_INC => { $regs[$dst] = semantics::inc($vd); }
+            OP_DEC => { $regs[$dst] = semantics::dec($vd); }
+            OP_JMPNZ => {
+                if $vd != 0 { $pc = imm16($a, $b) as usize; }
+            }
+            OP_JMP => { $pc = imm16($a, $b) as usize; }
+            OP_JEQ => {
+                if $vd == 0 { $pc = imm16($a, $b) as usize; }
+            }
+            OP_JNE => {
+                if $vd != 0 { $pc = imm16($a, $b) as usize; }
+            }
+            OP_JLT => {
+                if $vd < 0 { $pc = imm16($a, $b) as usize; }
+            }
+            OP_JGE => {
+                if $vd >= 0 { $pc = imm16($a, $b) as usize; }
+            }
+            OP_MOV => { $regs[$dst] = semantics::mov($va); }
+            OP_CLZ => { $regs[$dst] = semantics::clz($va); }
+            OP_CTZ => { $regs[$dst] = semantics::ctz($va); }
+            OP_POPCNT => { $regs[$dst] = semantics::popcnt($va); }
+            OP_CMP => { $regs[$dst] = semantics::cmp($va, $vb); }
+            OP_CALL => {
+                if !$stack.call($pc) { return -1; }
+                $pc = imm16($a, $b) as usize;
+            }
+            OP_RET => match $stack.ret() {
+                Some(ret) => $pc = ret,
+                None => return -1,
+            },
+            OP_PUSH => {
+                if !$stack.push($vd) { return -1; }
+            }
+            OP_POP => match $stack.pop() {
+                Some(v) => $regs[$dst] = v,
+                None => return -1,
+            },
+            OP_LOAD => match $mem.get(address($va, $b)) {
+                Some(&v) => $regs[$dst] = v,
+                None => return -1,
+            },
+            OP_STORE => match $mem.get_mut(address($va, $b)) {
+                Ok(slot) => *slot = $vd,
+                Err(_) => return -1,
+            },
+            OP_MEMCPY => {
+                if $mem.copy($vd, $va, $vb).is_err() { return -1; }
+            }
+            OP_MEMSET => {
+                if $mem.fill($vd, $va, $vb).is_err() { return -1; }
+            }
+            // the packed registers aren't what got preloaded, these read the file
+            OP_ADDADD => {
+                fused_binops(&mut $regs, $dst as u8, $a, $b, semantics::add, semantics::add);
+                $pc += 1;
+            }
+            OP_MULSUB => {
+                fused_binops(&mut $regs, $dst as u8, $a, $b, semantics::mul, semantics::sub);
+                $pc += 1;
+            }
+            // vd - 1 != 0, the branch doesn't wait on the subtraction
+            OP_DECJNZ => {
+                $regs[$dst] = semantics::dec($vd);
+                if $vd != 1 { $pc = imm16($a, $b) as usize; } else { $pc += 1; }
+            }
+            _ => return -1,
+        }
+    };
+}
+
+#[inline(never)]
+pub fn run_threaded_preload(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    let code = program.code();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let (op, dst, a, b, vd, va, vb) = decode_preload!(code, regs, pc);
+        match op {
+            OP_HALT => return vd,
+            OP_LOADI => {
+                regs[dst] = imm16(a, b);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_ADD => {
+                regs[dst] = semantics::add(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_SUB => {
+                regs[dst] = semantics::sub(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MUL => {
+                regs[dst] = semantics::mul(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_DIV => {
+                regs[dst] = semantics::div(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MOD => {
+                regs[dst] = semantics::rem(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_INC => {
+                regs[dst] = semantics::inc(vd);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_DEC => {
+                regs[dst] = semantics::dec(vd);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JMPNZ => {
+                if vd != 0 { pc = imm16(a, b) as usize; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JMP => {
+                pc = imm16(a, b) as usize;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JEQ => {
+                if vd == 0 { pc = imm16(a, b) as usize; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JNE => {
+                if vd != 0 { pc = imm16(a, b) as usize; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JLT => {
+                if vd < 0 { pc = imm16(a, b) as usize; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_JGE => {
+                if vd >= 0 { pc = imm16(a, b) as usize; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MOV => {
+                regs[dst] = semantics::mov(va);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_CLZ => {
+                regs[dst] = semantics::clz(va);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_CTZ => {
+                regs[dst] = semantics::ctz(va);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_POPCNT => {
+                regs[dst] = semantics::popcnt(va);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_CMP => {
+                regs[dst] = semantics::cmp(va, vb);
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_CALL => {
+                if !stack.call(pc) { return -1; }
+                pc = imm16(a, b) as usize;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_RET => {
+                let Some(ret) = stack.ret() else { return -1 };
+                pc = ret;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_PUSH => {
+                if !stack.push(vd) { return -1; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_POP => {
+                let Some(v) = stack.pop() else { return -1 };
+                regs[dst] = v;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_LOAD => {
+                let Some(&v) = mem.get(address(va, b)) else { return -1 };
+                regs[dst] = v;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_STORE => {
+                let Ok(slot) = mem.get_mut(address(va, b)) else { return -1 };
+                *slot = vd;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MEMCPY => {
+                if mem.copy(vd, va, vb).is_err() { return -1; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MEMSET => {
+                if mem.fill(vd, va, vb).is_err() { return -1; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_ADDADD => {
+                fused_binops(&mut regs, dst as u8, a, b, semantics::add, semantics::add);
+                pc += 1;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_MULSUB => {
+                fused_binops(&mut regs, dst as u8, a, b, semantics::mul, semantics::sub);
+                pc += 1;
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            OP_DECJNZ => {
+                regs[dst] = semantics::dec(vd);
+                if vd != 1 { pc = imm16(a, b) as usize; } else { pc += 1; }
+                let (op2, dst2, a2, b2, vd2, va2, vb2) = decode_preload!(code, regs, pc);
+                handle_preloaded!(regs, stack, mem, pc, op2, dst2, a2, b2, vd2, va2, vb2);
+            }
+            _ => return -1,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////
+// VERSION E : fetch bundles of 4 instructions
+//////////////////////////////////////////////////////
+// another axis next to replication depth: instead of fetching one word per dispatch,
+// every outer iteration loads an aligned [u32; 4] and runs through it with inline
+// sequential dispatch, one copy of the dispatch per slot. a taken branch leaves the
+// bundle early and the outer loop refetches wherever it landed (possibly mid-bundle)
+//
+// programs get padded to a whole number of bundles with an invalid opcode (in both
+// numberings), so the fetch never reads past the end of a verified program
+const BUNDLE_PAD: u32 = OP_PAST_END as u32;
+
+// only built from a VerifiedProgram, so run_bundled can fetch unchecked
+pub struct VerifiedBundles {
+    bundles: Vec<[u32; 4]>,
+    memory: MemoryImage,
+}
+
+impl VerifiedBundles {
+    pub fn bundles(&self) -> &[[u32; 4]] {
+        &self.bundles
+    }
+
+    fn memory(&self) -> Memory {
+        self.memory.fresh()
+    }
+}
+
+pub fn to_bundles(program: &VerifiedProgram) -> VerifiedBundles {
+    let bundles = program
+        .code()
+        .chunks(4)
+        .map(|chunk| {
+            let mut bundle = [BUNDLE_PAD; 4];
+            bundle[..chunk.len()].copy_from_slice(chunk);
+            bundle
+        })
+        .collect();
+    VerifiedBundles { bundles, memory: program.memory_image().clone() }
+}
+
+// runs slot $i of the bundle at $base, leaves the bundle if it branched
+macro_rules! bundle_slot {
+    ($bundle:expr, $regs:expr, $stack:expr, $mem:expr, $pc:expr, $base:expr, $i:expr, $outer:lifetime) => {
+        let instr = $bundle[$i];
+        let op = (instr & 0xFF) as u8;
+        let dst = ((instr >> 8) & 0xFF) as usize;
+        let a = ((instr >> 16) & 0xFF) as u8;
+        let b = ((instr >> 24) & 0xFF) as u8;
+        $pc = $base + $i + 1;
+        handle!($regs, $stack, $mem, $pc, op, dst, a, b);
+        if $pc != $base + $i + 1 {
+            continue $outer;
+        }
+    };
+}
+
+#[inline(never)]
+pub fn run_bundled(program: &VerifiedBundles, args: &[i64]) -> i64 {
+    let code = program.bundles();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    'outer: loop {
+        let bundle: [u32; 4] = fetch!(code, pc >> 2);
+        let base = pc & !3;
+        let start = pc & 3;
+        if start == 0 {
+            bundle_slot!(bundle, regs, stack, mem, pc, base, 0, 'outer);
+        }
+        if start <= 1 {
+            bundle_slot!(bundle, regs, stack, mem, pc, base, 1, 'outer);
+        }
+        if start <= 2 {
+            bundle_slot!(bundle, regs, stack, mem, pc, base, 2, 'outer);
+        }
+        bundle_slot!(bundle, regs, stack, mem, pc, base, 3, 'outer);
+    }
+}
+
+//////////////////////////////////////////////////////
+// VERSION F : table of handler function pointers
+//////////////////////////////////////////////////////
+// the classic third contender: no match at all, every opcode is its own function and
+// dispatch is an indirect call through a 256-entry table. this is what you'd write in
+// C without computed goto, and what a lot of Rust interpreters do
+//
+// the handlers return to the loop instead of tail-calling the next one through the
+// table: without guaranteed tail calls in stable Rust a debug build would overflow
+// the stack on the first long loop. so this is call-threading, one indirect call and
+// one return per instruction, see the README for how it compares
+//
+// handlers get the instruction word as an argument (it's in a register already,
+// going through the state would be a store and a reload)
+struct TableState<'a> {
+    code: &'a [u32],
+    regs: [i64; NREGS],
+    pc: usize,
+    stack: Stack,
+    mem: Memory,
+}
+
+enum Control {
+    Next,
+    Halt(i64),
+}
+
+type TableHandler = fn(&mut TableState, u32) -> Control;
+
+// (dst, a, b) out of an instruction word
+#[inline(always)]
+fn operands(instr: u32) -> (usize, u8, u8) {
+    (((instr >> 8) & 0xFF) as usize, ((instr >> 16) & 0xFF) as u8, ((instr >> 24) & 0xFF) as u8)
+}
+
+// one handler per op in the semantics table, table_alu::add and so on
+mod table_alu {
+    use super::{Control, TableState, operands};
+    use crate::semantics::{apply, for_each_alu_op};
+
+    macro_rules! table_alu {
+        ($($shape:ident $name:ident = $op:ident, $mnemonic:literal, |$($arg:ident),+| $body:expr, $text:literal;)*) => {
+            $(
+                pub(super) fn $name(st: &mut TableState, instr: u32) -> Control {
+                    let (dst, a, b) = operands(instr);
+                    apply::$name(&mut st.regs, dst, a, b);
+                    Control::Next
+                }
+            )*
+        };
+    }
+
+    for_each_alu_op!(table_alu);
+}
+
+fn t_halt(st: &mut TableState, instr: u32) -> Control {
+    Control::Halt(st.regs[operands(instr).0])
+}
+
+fn t_loadi(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    st.regs[dst] = imm16(a, b);
+    Control::Next
+}
+
+fn t_jmpnz(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    if st.regs[dst] != 0 {
+        st.pc = imm16(a, b) as usize;
+    }
+    Control::Next
+}
+
+// the rest of the branch family, one function each like every other opcode
+macro_rules! table_branch {
+    ($($name:ident: $op:expr;)*) => {
+        $(
+            fn $name(st: &mut TableState, instr: u32) -> Control {
+                let (dst, a, b) = operands(instr);
+                if branch_taken($op, st.regs[dst]) {
+                    st.pc = imm16(a, b) as usize;
+                }
+                Control::Next
+            }
+        )*
+    };
+}
+
+table_branch! {
+    t_jmp: OP_JMP;
+    t_jeq: OP_JEQ;
+    t_jne: OP_JNE;
+    t_jlt: OP_JLT;
+    t_jge: OP_JGE;
+}
+
+fn t_call(st: &mut TableState, instr: u32) -> Control {
+    let (_, a, b) = operands(instr);
+    if !st.stack.call(st.pc) {
+        return Control::Halt(-1);
+    }
+    st.pc = imm16(a, b) as usize;
+    Control::Next
+}
+
+fn t_ret(st: &mut TableState, _instr: u32) -> Control {
+    match st.stack.ret() {
+        Some(ret) => {
+            st.pc = ret;
+            Control::Next
+        }
+        None => Control::Halt(-1),
+    }
+}
+
+fn t_push(st: &mut TableState, instr: u32) -> Control {
+    if st.stack.push(st.regs[operands(instr).0]) { Control::Next } else { Control::Halt(-1) }
+}
+
+fn t_pop(st: &mut TableState, instr: u32) -> Control {
+    match st.stack.pop() {
+        Some(v) => {
+            st.regs[operands(instr).0] = v;
+            Control::Next
+        }
+        None => Control::Halt(-1),
+    }
+}
+
+fn t_load(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    match st.mem.get(address(st.regs[a as usize], b)) {
+        Some(&v) => {
+            st.regs[dst] = v;
+            Control::Next
+        }
+        None => Control::Halt(-1),
+    }
+}
+
+fn t_store(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    match st.mem.get_mut(address(st.regs[a as usize], b)) {
+        Ok(slot) => {
+            *slot = st.regs[dst];
+            Control::Next
+        }
+        Err(_) => Control::Halt(-1),
+    }
+}
+
+fn t_memcpy(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    let (to, from, len) = (st.regs[dst], st.regs[a as usize], st.regs[b as usize]);
+    if st.mem.copy(to, from, len).is_ok() { Control::Next } else { Control::Halt(-1) }
+}
+
+fn t_memset(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    let (to, value, len) = (st.regs[dst], st.regs[a as usize], st.regs[b as usize]);
+    if st.mem.fill(to, value, len).is_ok() { Control::Next } else { Control::Halt(-1) }
+}
+
+// superinstructions, see fuse.rs. each one steps over the second half of its pair
+fn t_addadd(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    fused_binops(&mut st.regs, dst as u8, a, b, semantics::add, semantics::add);
+    st.pc += 1;
+    Control::Next
+}
+
+fn t_mulsub(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    fused_binops(&mut st.regs, dst as u8, a, b, semantics::mul, semantics::sub);
+    st.pc += 1;
+    Control::Next
+}
+
+fn t_decjnz(st: &mut TableState, instr: u32) -> Control {
+    let (dst, a, b) = operands(instr);
+    apply::dec(&mut st.regs, dst, a, b);
+    if st.regs[dst] != 0 {
+        st.pc = imm16(a, b) as usize;
+    } else {
+        st.pc += 1;
+    }
+    Control::Next
+}
+
+fn t_bad(_st: &mut TableState, _instr: u32) -> Control {
+    Control::Halt(-1)
+}
+
+static TABLE: [TableHandler; 256] = {
+    let mut t: [TableHandler; 256] = [t_bad; 256];
+    t[OP_HALT as usize] = t_halt;
+    t[OP_LOADI as usize] = t_loadi;
+    t[OP_ADD as usize] = table_alu::add;
+    t[OP_SUB as usize] = table_alu::sub;
+    t[OP_MUL as usize] = table_alu::mul;
+    t[OP_DIV as usize] = table_alu::div;
+    t[OP_MOD as usize] = table_alu::rem;
+    t[OP_INC as usize] = table_alu::inc;
+    t[OP_DEC as usize] = table_alu::dec;
+    t[OP_JMPNZ as usize] = t_jmpnz;
+    t[OP_MOV as usize] = table_alu::mov;
+    t[OP_CLZ as usize] = table_alu::clz;
+    t[OP_CTZ as usize] = table_alu::ctz;
+    t[OP_POPCNT as usize] = table_alu::popcnt;
+    t[OP_CALL as usize] = t_call;
+    t[OP_RET as usize] = t_ret;
+    t[OP_PUSH as usize] = t_push;
+    t[OP_POP as usize] = t_pop;
+    t[OP_LOAD as usize] = t_load;
+    t[OP_STORE as usize] = t_store;
+    t[OP_MEMCPY as usize] = t_memcpy;
+    t[OP_MEMSET as usize] = t_memset;
+    t[OP_CMP as usize] = table_alu::cmp;
+    t[OP_JMP as usize] = t_jmp;
+    t[OP_JEQ as usize] = t_jeq;
+    t[OP_JNE as usize] = t_jne;
+    t[OP_JLT as usize] = t_jlt;
+    t[OP_JGE as usize] = t_jge;
+    t[OP_ADDADD as usize] = t_addadd;
+    t[OP_MULSUB as usize] = t_mulsub;
+    t[OP_DECJNZ as usize] = t_decjnz;
+    t
+};
+
+#[inline(never)]
+pub fn run_table(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    let mut st =
+        TableState { code: program.code(), regs: seed_regs(args), pc: 0, stack: Stack::default(), mem: program.memory() };
+
+    loop {
+        let instr = fetch!(st.code, st.pc);
+        st.pc += 1;
+        if let Control::Halt(result) = TABLE[(instr & 0xFF) as usize](&mut st, instr) {
+            return result;
+        }
+    }
+}
+
+//////////////////////////////////////////////////////
+// VERSION G : dispatch over pre-decoded instructions
+//////////////////////////////////////////////////////
+// every variant above decodes inside the loop: one u32 load, then shifts and masks to
+// get the four fields out, so their timings are dispatch and decode together. this
+// one splits every word into its fields once, up front, and runs run_central's loop
+// with the decode replaced by loading the fields. next to central-dispatch that prices
+// the decode on its own, and whatever the threaded variants win over central beyond
+// that difference is dispatch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodedInstr {
+    pub op: u8,
+    pub dst: u8,
+    pub a: u8,
+    pub b: u8,
+}
+
+impl DecodedInstr {
+    pub fn of(word: u32) -> DecodedInstr {
+        DecodedInstr { op: word as u8, dst: (word >> 8) as u8, a: (word >> 16) as u8, b: (word >> 24) as u8 }
+    }
+}
+
+// only built from a VerifiedProgram, so run_predecoded can fetch unchecked
+pub struct VerifiedDecoded {
+    code: Vec<DecodedInstr>,
+    memory: MemoryImage,
+}
+
+impl VerifiedDecoded {
+    pub fn code(&self) -> &[DecodedInstr] {
+        &self.code
+    }
+
+    fn memory(&self) -> Memory {
+        self.memory.fresh()
+    }
+}
+
+pub fn to_decoded(program: &VerifiedProgram) -> VerifiedDecoded {
+    let code = program.code().iter().map(|&word| DecodedInstr::of(word)).collect();
+    VerifiedDecoded { code, memory: program.memory_image().clone() }
+}
+
+#[inline(never)]
+pub fn run_predecoded(program: &VerifiedDecoded, args: &[i64]) -> i64 {
+    let code = program.code();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let DecodedInstr { op, dst, a, b } = fetch!(code, pc);
+        pc += 1;
+        handle!(regs, stack, mem, pc, op, dst as usize, a, b);
+    }
+}
+
+//////////////////////////////////////////////////////
+// VERSION H : whole loop bodies per dispatch
+//////////////////////////////////////////////////////
+// how far knowing about loops takes dispatch short of a JIT. at load time, every loop
+// whose body is straight-line register code (LOADI and the ops in the semantics
+// table, at most MAX_LOOP_BODY of them) closed by a conditional branch back to its
+// first instruction gets lifted out: that instruction's slot becomes OP_LOOP_BODY,
+// and dispatching it runs the body and tests the branch over and over without going
+// back to the dispatch. the body runs in a copy of iterate() monomorphized on its
+// length, so the walk over it is unrolled and every position gets its own copy of the
+// match on the op: a branch the predictor learns per position, where the other
+// variants share one indirect jump between every instruction. sum_poly's loop is
+// exactly that shape
+//
+// everything else, and a body entered anywhere but its first instruction, runs the way
+// predecoded runs it, with one more compare per dispatch
+pub const MAX_LOOP_BODY: usize = 16;
+
+// not an opcode in either numbering, and not OP_PAST_END. a/b hold the body's index
+const OP_LOOP_BODY: u8 = 0xFE;
+
+struct LoopBody {
+    ops: [DecodedInstr; MAX_LOOP_BODY],
+    len: usize,
+    branch: DecodedInstr,
+    // the instruction after the branch
+    exit: usize,
+}
+
+// only built from a VerifiedProgram, so run_loop_bodies can fetch unchecked
+pub struct VerifiedLoops {
+    code: Vec<DecodedInstr>,
+    bodies: Vec<LoopBody>,
+    memory: MemoryImage,
+}
+
+impl VerifiedLoops {
+    pub fn code(&self) -> &[DecodedInstr] {
+        &self.code
+    }
+
+    // how many loops got lifted out
+    pub fn loops(&self) -> usize {
+        self.bodies.len()
+    }
+
+    fn memory(&self) -> Memory {
+        self.memory.fresh()
+    }
+}
+
+pub fn to_loop_bodies(program: &VerifiedProgram) -> VerifiedLoops {
+    let mut code: Vec<DecodedInstr> = program.code().iter().map(|&word| DecodedInstr::of(word)).collect();
+    let straight = |ins: &DecodedInstr| ins.op == OP_LOADI || semantics::ALU_OPS.iter().any(|&(op, _)| op == ins.op);
+    let mut bodies = Vec::new();
+    for j in 0..code.len() {
+        let branch = code[j];
+        if !matches!(branch.op, OP_JMPNZ | OP_JEQ | OP_JNE | OP_JLT | OP_JGE) {
+            continue;
+        }
+        let head = imm16(branch.a, branch.b) as usize;
+        // two branches back to the same head close bodies of different lengths, the
+        // first one to get lifted keeps it
+        if head >= j || j - head > MAX_LOOP_BODY || !code[head..j].iter().all(straight) {
+            continue;
+        }
+        let mut ops = [DecodedInstr::of(0); MAX_LOOP_BODY];
+        ops[..j - head].copy_from_slice(&code[head..j]);
+        let index = bodies.len() as u16;
+        bodies.push(LoopBody { ops, len: j - head, branch, exit: j + 1 });
+        code[head] = DecodedInstr { op: OP_LOOP_BODY, dst: 0, a: index as u8, b: (index >> 8) as u8 };
+    }
+    VerifiedLoops { code, bodies, memory: program.memory_image().clone() }
+}
+
+// runs the body until the branch falls through. the ops are LOADI or in the semantics
+// table, so eval only comes back empty for LOADI
+#[inline(always)]
+fn iterate<const N: usize>(ops: &[DecodedInstr; MAX_LOOP_BODY], branch: DecodedInstr, regs: &mut [i64; NREGS]) {
+    loop {
+        for &DecodedInstr { op, dst, a, b } in &ops[..N] {
+            regs[dst as usize] = semantics::eval(op, regs, dst, a, b).unwrap_or_else(|| imm16(a, b));
+        }
+        if !branch_taken(branch.op, regs[branch.dst as usize]) {
+            return;
+        }
+    }
+}
+
+macro_rules! iterate_by_len {
+    ($body:expr, $regs:expr, $($n:literal)*) => {
+        match $body.len {
+            $($n => iterate::<$n>(&$body.ops, $body.branch, $regs),)*
+            _ => unreachable!("to_loop_bodies only lifts 1 to MAX_LOOP_BODY instructions"),
+        }
+    };
+}
+
+#[inline(never)]
+pub fn run_loop_bodies(program: &VerifiedLoops, args: &[i64]) -> i64 {
+    let code = program.code();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let DecodedInstr { op, dst, a, b } = fetch!(code, pc);
+        if op == OP_LOOP_BODY {
+            let body = &program.bodies[imm16(a, b) as usize];
+            iterate_by_len!(body, &mut regs, 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16);
+            pc = body.exit;
+            continue;
+        }
+        pc += 1;
+        handle!(regs, stack, mem, pc, op, dst as usize, a, b);
+    }
+}
+
+//////////////////////////////////////////////////////
+// HOOKS : one extension point for tracing/profiling/debugging
+//////////////////////////////////////////////////////
+// anything that wants to watch execution implements ExecHook. run_hooked is generic
+// over it, so every hook gets its own monomorphized copy of the central loop and the
+// calls are inlined: with NoHook the methods are empty and the loop compiles to the
+// same thing as run_central (`cargo xtask verify-threading` checks the two stay the
+// same size)
+pub trait ExecHook {
+    // before the instruction at `pc` runs. one that traps gets this too, then on_trap
+    // (checking first costs run_hooked an extra dispatch branch, even with NoHook)
+    #[inline(always)]
+    fn pre(&mut self, _pc: usize, _op: u8, _regs: &[i64; NREGS]) {}
+
+    // after it ran, `regs` already has its result
+    #[inline(always)]
+    fn post(&mut self, _pc: usize, _op: u8, _regs: &[i64; NREGS]) {}
+
+    // control flow at `from` to `to`: a conditional branch taken or not, JMP, CALL and
+    // RET always taken
+    // (`to` is the return address for RET)
+    #[inline(always)]
+    fn on_branch(&mut self, _from: usize, _to: usize, _taken: bool) {}
+
+    // the instruction at `pc` stopped execution with -1, and why. no post after it
+    #[inline(always)]
+    fn on_trap(&mut self, _pc: usize, _op: u8, _trap: TrapKind) {}
+}
+
+pub struct NoHook;
+
+impl ExecHook for NoHook {}
+
+#[inline(always)]
+pub fn run_hooked<H: ExecHook>(program: &VerifiedProgram, args: &[i64], hook: &mut H) -> i64 {
+    let code = program.code();
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = program.memory();
+
+    loop {
+        let at = pc;
+        let (op, dst, a, b) = exec_one!(code, regs, pc);
+        hook.pre(at, op, &regs);
+        if op == OP_HALT {
+            hook.post(at, op, &regs);
+        }
+        handle!(regs, stack, mem, pc, op, dst, a, b, on_trap: |trap| { hook.on_trap(at, op, trap); });
+        hook.post(at, op, &regs);
+        match op {
+            // branches don't write their register, so this is what they tested. DECJNZ
+            // does, and tests what it wrote
+            OP_JMPNZ | OP_JMP | OP_JEQ | OP_JNE | OP_JLT | OP_JGE | OP_DECJNZ => {
+                hook.on_branch(at, imm16(a, b) as usize, branch_taken(op, regs[dst]))
+            }
+            OP_CALL | OP_RET => hook.on_branch(at, pc, true),
+            _ => {}
+        }
+    }
+}
+
+// the no-op instantiation under its own symbol, so its asm can be compared with run_central
+#[inline(never)]
+pub fn run_central_nohook(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    run_hooked(program, args, &mut NoHook)
+}
+
+// counts retired instructions and taken branches, for the benchmark header
+#[derive(Default)]
+pub struct CountHook {
+    pub instructions: u64,
+    pub branches_taken: u64,
+}
+
+impl ExecHook for CountHook {
+    fn pre(&mut self, _pc: usize, _op: u8, _regs: &[i64; NREGS]) {
+        self.instructions += 1;
+    }
+
+    fn on_branch(&mut self, _from: usize, _to: usize, taken: bool) {
+        self.branches_taken += taken as u64;
+    }
+}
+
+//////////////////////////////////////////////////////
+// STREAMING : instructions pulled from an iterator
+//////////////////////////////////////////////////////
+// for stream-processing hosts (think filter expressions over rows): the program is
+// tiny and straight-line, runs millions of times over different inputs, and might
+// not even be materialized in a slice. there's no random access into an iterator so
+// backward jumps are out, forward jumps just skip instructions. a forward CALL works
+// but its RET always goes backward, so subroutines can't come back. there's no memory
+// either (a filter runs per row, allocating for it would be most of the cost), every
+// LOAD and STORE misses.
+// nothing verified the stream, so every instruction gets its opcode and registers
+// checked as it's pulled. a bad one, running out of instructions or jumping backward
+// all return -1
+pub fn run_stream<I: IntoIterator<Item = u32>>(instrs: I, args: &[i64]) -> i64 {
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+    let mut stack = Stack::default();
+    let mut mem = Memory::default();
+    let mut instrs = instrs.into_iter();
+
+    loop {
+        let Some(instr) = instrs.next() else { return -1 };
+        let Some(ins) = Instruction::decode(instr) else { return -1 };
+        let (uses_dst, uses_a, uses_b) = ins.op.register_operands();
+        let regs_used = [(uses_dst, ins.dst), (uses_a, ins.a), (uses_b, ins.b)];
+        if regs_used.iter().any(|&(used, r)| used && r as usize >= NREGS) {
+            return -1;
+        }
+        let op = (instr & 0xFF) as u8;
+        let dst = ((instr >> 8) & 0xFF) as usize;
+        let a = ((instr >> 16) & 0xFF) as u8;
+        let b = ((instr >> 24) & 0xFF) as u8;
+        pc += 1;
+        let next = pc;
+        handle!(regs, stack, mem, pc, op, dst, a, b);
+        if pc != next {
+            // a jump was taken
+            if pc < next {
+                return -1;
+            }
+            if instrs.nth(pc - next - 1).is_none() {
+                return -1;
+            }
+        }
+    }
+}
+
+// every dispatch variant, under a short name (for descriptors and the like) and the
+// label printed in the benchmark table
+pub type RunFn = fn(&VerifiedProgram, &[i64]) -> i64;
+pub type RunBytesFn = fn(&bytes::VerifiedBytes, &[i64]) -> i64;
+pub type RunBundlesFn = fn(&VerifiedBundles, &[i64]) -> i64;
+pub type RunDecodedFn = fn(&VerifiedDecoded, &[i64]) -> i64;
+pub type RunLoopsFn = fn(&VerifiedLoops, &[i64]) -> i64;
+#[cfg(feature = "jit")]
+pub type RunNativeFn = fn(&jit::JitProgram, &[i64]) -> i64;
+
+// what a variant executes: the word bytecode directly, its u8-stream translation, the
+// words padded into 4-instruction bundles, the words split into their fields, those
+// with the straight-line loops lifted out, or (with `--features jit`) machine code
+// compiled from them
+#[derive(Clone, Copy)]
+pub enum Runner {
+    Words(RunFn),
+    Bytes(RunBytesFn),
+    Bundles(RunBundlesFn),
+    Decoded(RunDecodedFn),
+    Loops(RunLoopsFn),
+    #[cfg(feature = "jit")]
+    Native(RunNativeFn),
+}
+
+pub struct Variant {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub run: Runner,
+}
+
+pub const VARIANTS: &[Variant] = &[
+    Variant { name: "central", label: "central-dispatch", run: Runner::Words(run_central) },
+    Variant { name: "predecoded", label: "predecoded-central", run: Runner::Decoded(run_predecoded) },
+    Variant { name: "threaded2", label: "threaded-2level", run: Runner::Words(run_threaded) },
+    Variant { name: "threaded3", label: "threaded-3level", run: Runner::Words(run_threaded_deep) },
+    Variant { name: "preload2", label: "threaded-preload", run: Runner::Words(run_threaded_preload) },
+    Variant { name: "central-nohook", label: "central-nohook", run: Runner::Words(run_central_nohook) },
+    Variant { name: "table", label: "fn-pointer-table", run: Runner::Words(run_table) },
+    Variant { name: "bundle4", label: "bundled-fetch-4", run: Runner::Bundles(run_bundled) },
+    Variant { name: "bytes-central", label: "bytes-central", run: Runner::Bytes(bytes::run_bytes_central) },
+    Variant { name: "bytes-threaded2", label: "bytes-threaded-2level", run: Runner::Bytes(bytes::run_bytes_threaded) },
+    Variant { name: "loop-bodies", label: "loop-bodies-unrolled", run: Runner::Loops(run_loop_bodies) },
+    #[cfg(feature = "c-reference")]
+    Variant { name: "c-goto", label: "c-computed-goto", run: Runner::Words(c_reference::run_c_goto) },
+    #[cfg(feature = "nightly-tailcall")]
+    Variant { name: "tailcall", label: "tail-call-threaded", run: Runner::Words(tailcall::run_tailcall) },
+    #[cfg(feature = "jit")]
+    Variant { name: "jit", label: "jit-compiled", run: Runner::Native(jit::run_jit) },
+];
+
+// variants that exist but aren't compiled into this build, and what it takes to get
+// them. the rule for anything optional: nothing that needs a nightly compiler or an
+// extra toolchain can be the only way to run something. it goes behind a cargo
+// feature, lands here when the feature is off, and its stable counterpart (picked
+// with the opposite cfg) keeps running the same programs, so `cargo run --release`
+// on stable always has the whole benchmark
+pub const UNAVAILABLE_VARIANTS: &[(&str, &str)] = &[
+    #[cfg(not(feature = "c-reference"))]
+    ("c-goto", "needs `--features c-reference` and a C compiler"),
+    #[cfg(not(feature = "nightly-tailcall"))]
+    ("tailcall", "needs `--features nightly-tailcall` and a nightly compiler, `table` is the stable one"),
+    #[cfg(not(feature = "jit"))]
+    ("jit", "needs `--features jit` on x86_64 Linux"),
+];
+
+pub fn variant_by_name(name: &str) -> Option<&'static Variant> {
+    VARIANTS.iter().find(|v| v.name == name)
+}
+
+impl Variant {
+    // one-off run, translating the program if the variant needs it
+    pub fn exec(&self, program: &VerifiedProgram, args: &[i64]) -> Result<i64, VmError> {
+        Ok(match self.run {
+            Runner::Words(f) => f(program, args),
+            Runner::Bytes(f) => f(&bytes::to_bytes(program)?, args),
+            Runner::Bundles(f) => f(&to_bundles(program), args),
+            Runner::Decoded(f) => f(&to_decoded(program), args),
+            Runner::Loops(f) => f(&to_loop_bodies(program), args),
+            #[cfg(feature = "jit")]
+            Runner::Native(f) => f(&jit::compile(program), args),
+        })
+    }
+}
+
+//////////////////////////////////////////////////////
+// LIBRARY API : embedding the interpreter
+//////////////////////////////////////////////////////
+// everything above works on raw u32 words and free functions, because that's what the
+// benchmark wants to look at. this is the typed way in for embedding: build the
+// program from Instructions, verify it, pick a DispatchKind, run it through a Vm
+//
+//   let program = Program::new(vec![
+//       Instruction::new(Opcode::Add, 2, 0, 1).encode(),
+//       Instruction::new(Opcode::Halt, 2, 0, 0).encode(),
+//   ])?
+//   .verify()?;
+//   let vm = Vm::with_dispatch(program, DispatchKind::Threaded2)?;
+//   assert_eq!(vm.run(&[40, 2])?, 42);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Opcode {
+    Halt = OP_HALT,
+    Loadi = OP_LOADI,
+    Add = OP_ADD,
+    Sub = OP_SUB,
+    Mul = OP_MUL,
+    Div = OP_DIV,
+    Mod = OP_MOD,
+    Inc = OP_INC,
+    Dec = OP_DEC,
+    Jmpnz = OP_JMPNZ,
+    Mov = OP_MOV,
+    Call = OP_CALL,
+    Ret = OP_RET,
+    Push = OP_PUSH,
+    Pop = OP_POP,
+    Load = OP_LOAD,
+    Store = OP_STORE,
+    Memcpy = OP_MEMCPY,
+    Memset = OP_MEMSET,
+    Cmp = OP_CMP,
+    Jmp = OP_JMP,
+    Jeq = OP_JEQ,
+    Jne = OP_JNE,
+    Jlt = OP_JLT,
+    Jge = OP_JGE,
+    Waitevent = OP_WAITEVENT,
+    AddAdd = OP_ADDADD,
+    MulSub = OP_MULSUB,
+    DecJnz = OP_DECJNZ,
+    Clz = OP_CLZ,
+    Ctz = OP_CTZ,
+    Popcnt = OP_POPCNT,
+    Trap = OP_TRAP,
+    ResolveCall = OP_RESOLVE_CALL,
+}
+
+impl Opcode {
+    pub fn from_u8(op: u8) -> Option<Opcode> {
+        Some(match op {
+            OP_HALT => Opcode::Halt,
+            OP_LOADI => Opcode::Loadi,
+            OP_ADD => Opcode::Add,
+            OP_SUB => Opcode::Sub,
+            OP_MUL => Opcode::Mul,
+            OP_DIV => Opcode::Div,
+            OP_MOD => Opcode::Mod,
+            OP_INC => Opcode::Inc,
+            OP_DEC => Opcode::Dec,
+            OP_JMPNZ => Opcode::Jmpnz,
+            OP_MOV => Opcode::Mov,
+            OP_CALL => Opcode::Call,
+            OP_RET => Opcode::Ret,
+            OP_PUSH => Opcode::Push,
+            OP_POP => Opcode::Pop,
+            OP_LOAD => Opcode::Load,
+            OP_STORE => Opcode::Store,
+            OP_MEMCPY => Opcode::Memcpy,
+            OP_MEMSET => Opcode::Memset,
+            OP_CMP => Opcode::Cmp,
+            OP_JMP => Opcode::Jmp,
+            OP_JEQ => Opcode::Jeq,
+            OP_JNE => Opcode::Jne,
+            OP_JLT => Opcode::Jlt,
+            OP_JGE => Opcode::Jge,
+            OP_WAITEVENT => Opcode::Waitevent,
+            OP_ADDADD => Opcode::AddAdd,
+            OP_MULSUB => Opcode::MulSub,
+            OP_DECJNZ => Opcode::DecJnz,
+            OP_CLZ => Opcode::Clz,
+            OP_CTZ => Opcode::Ctz,
+            OP_POPCNT => Opcode::Popcnt,
+            OP_TRAP => Opcode::Trap,
+            OP_RESOLVE_CALL => Opcode::ResolveCall,
+            _ => return None,
+        })
+    }
+
+    // (is dst a register, is a, is b). for LOADI, CALL and the branches a/b are the
+    // immediate, the single-register ops ignore them, RET, JMP, TRAP and RESOLVE_CALL
+    // have no register operands at all. LOAD/STORE have a register in dst (loaded into / stored from)
+    // and the base in a, b is the offset. MEMCPY/MEMSET and CMP take all three as
+    // registers. ADDADD and MULSUB pack two registers per byte, 4 bits each, so
+    // none of their bytes is a register index as such (fuse::halves unpacks them)
+    pub fn register_operands(self) -> (bool, bool, bool) {
+        match self {
+            Opcode::Call
+            | Opcode::Ret
+            | Opcode::Jmp
+            | Opcode::Trap
+            | Opcode::ResolveCall
+            | Opcode::AddAdd
+            | Opcode::MulSub => (false, false, false),
+            Opcode::Halt
+            | Opcode::Loadi
+            | Opcode::Inc
+            | Opcode::Dec
+            | Opcode::Jmpnz
+            | Opcode::Jeq
+            | Opcode::Jne
+            | Opcode::Jlt
+            | Opcode::Jge
+            | Opcode::DecJnz
+            | Opcode::Push
+            | Opcode::Pop
+            | Opcode::Waitevent => (true, false, false),
+            Opcode::Mov | Opcode::Clz | Opcode::Ctz | Opcode::Popcnt | Opcode::Load | Opcode::Store => {
+                (true, true, false)
+            }
+            _ => (true, true, true),
+        }
+    }
+}
+
+// one decoded instruction word. LOADI, CALL, TRAP and the branches keep their 16-bit immediate in
+// a (low byte) and b (high byte), see `with_imm`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Opcode,
+    pub dst: u8,
+    pub a: u8,
+    pub b: u8,
+}
+
+impl Instruction {
+    pub fn new(op: Opcode, dst: u8, a: u8, b: u8) -> Instruction {
+        Instruction { op, dst, a, b }
+    }
+
+    pub fn with_imm(op: Opcode, dst: u8, imm: u16) -> Instruction {
+        Instruction { op, dst, a: imm as u8, b: (imm >> 8) as u8 }
+    }
+
+    pub fn encode(self) -> u32 {
+        encode(self.op as u8, self.dst, self.a, self.b)
+    }
+
+    // None if the opcode byte isn't one we know
+    pub fn decode(word: u32) -> Option<Instruction> {
+        let op = Opcode::from_u8((word & 0xFF) as u8)?;
+        Some(Instruction { op, dst: (word >> 8) as u8, a: (word >> 16) as u8, b: (word >> 24) as u8 })
+    }
+}
+
+// the dispatch flavors a Vm can run with, each one is a VARIANTS entry under `name()`.
+// picked when the Vm is built (Vm::with_dispatch) or per run (Vm::run_with)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DispatchKind {
+    Central,
+    Predecoded,
+    Threaded2,
+    Threaded3,
+    Preload2,
+    FnTable,
+    Bundle4,
+    BytesCentral,
+    BytesThreaded2,
+    LoopBodies,
+    #[cfg(feature = "c-reference")]
+    CGoto,
+    #[cfg(feature = "nightly-tailcall")]
+    TailCall,
+    #[cfg(feature = "jit")]
+    Jit,
+}
+
+impl DispatchKind {
+    pub const ALL: &[DispatchKind] = &[
+        DispatchKind::Central,
+        DispatchKind::Predecoded,
+        DispatchKind::Threaded2,
+        DispatchKind::Threaded3,
+        DispatchKind::Preload2,
+        DispatchKind::FnTable,
+        DispatchKind::Bundle4,
+        DispatchKind::BytesCentral,
+        DispatchKind::BytesThreaded2,
+        DispatchKind::LoopBodies,
+        #[cfg(feature = "c-reference")]
+        DispatchKind::CGoto,
+        #[cfg(feature = "nightly-tailcall")]
+        DispatchKind::TailCall,
+        #[cfg(feature = "jit")]
+        DispatchKind::Jit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DispatchKind::Central => "central",
+            DispatchKind::Predecoded => "predecoded",
+            DispatchKind::Threaded2 => "threaded2",
+            DispatchKind::Threaded3 => "threaded3",
+            DispatchKind::Preload2 => "preload2",
+            DispatchKind::FnTable => "table",
+            DispatchKind::Bundle4 => "bundle4",
+            DispatchKind::BytesCentral => "bytes-central",
+            DispatchKind::BytesThreaded2 => "bytes-threaded2",
+            DispatchKind::LoopBodies => "loop-bodies",
+            #[cfg(feature = "c-reference")]
+            DispatchKind::CGoto => "c-goto",
+            #[cfg(feature = "nightly-tailcall")]
+            DispatchKind::TailCall => "tailcall",
+            #[cfg(feature = "jit")]
+            DispatchKind::Jit => "jit",
+        }
+    }
+
+    // the inverse of `name`, for whatever reads the names from a file or a command line
+    pub fn by_name(name: &str) -> Option<DispatchKind> {
+        DispatchKind::ALL.iter().copied().find(|k| k.name() == name)
+    }
+
+    pub fn variant(self) -> &'static Variant {
+        variant_by_name(self.name()).expect("every strategy has a VARIANTS entry")
+    }
+}
+
+// the program in whatever form the strategy executes, translated once up front
+enum Prepared {
+    Words(RunFn),
+    Bytes(RunBytesFn, bytes::VerifiedBytes),
+    Bundles(RunBundlesFn, VerifiedBundles),
+    Decoded(RunDecodedFn, VerifiedDecoded),
+    Loops(RunLoopsFn, VerifiedLoops),
+    #[cfg(feature = "jit")]
+    Native(RunNativeFn, jit::JitProgram),
+}
+
+impl Prepared {
+    // fails when the strategy executes a translation the program doesn't fit in
+    fn new(program: &VerifiedProgram, dispatch: DispatchKind) -> Result<Prepared, VmError> {
+        Ok(match dispatch.variant().run {
+            Runner::Words(f) => Prepared::Words(f),
+            Runner::Bytes(f) => Prepared::Bytes(f, bytes::to_bytes(program)?),
+            Runner::Bundles(f) => Prepared::Bundles(f, to_bundles(program)),
+            Runner::Decoded(f) => Prepared::Decoded(f, to_decoded(program)),
+            Runner::Loops(f) => Prepared::Loops(f, to_loop_bodies(program)),
+            #[cfg(feature = "jit")]
+            Runner::Native(f) => Prepared::Native(f, jit::compile(program)),
+        })
+    }
+
+    // `program` is what this was prepared from, the word variants run it as is
+    fn run(&self, program: &VerifiedProgram, args: &[i64]) -> i64 {
+        match self {
+            Prepared::Words(f) => f(program, args),
+            Prepared::Bytes(f, code) => f(code, args),
+            Prepared::Bundles(f, code) => f(code, args),
+            Prepared::Decoded(f, code) => f(code, args),
+            Prepared::Loops(f, code) => f(code, args),
+            #[cfg(feature = "jit")]
+            Prepared::Native(f, code) => f(code, args),
+        }
+    }
+
+    fn translated_bytes(&self) -> usize {
+        match self {
+            Prepared::Words(_) => 0,
+            Prepared::Bytes(_, code) => code.bytes().len(),
+            Prepared::Bundles(_, code) => size_of_val(code.bundles()),
+            Prepared::Decoded(_, code) => size_of_val(code.code()),
+            Prepared::Loops(_, code) => size_of_val(code.code()) + size_of_val(code.bodies.as_slice()),
+            #[cfg(feature = "jit")]
+            Prepared::Native(_, code) => code.code_bytes(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VmError {
+    // the strategy runs a translation of the program that can't hold it
+    TooLarge(bytes::TooLarge),
+    // args seed r0, r1, ... so there can't be more than NREGS of them, or more than
+    // ARG_REGS for Vm::call
+    TooManyArgs { given: usize, max: usize },
+    // Vm::call on a name the program doesn't have
+    NoSuchEntry(String),
+    // the named entry can't be reached from a call stub, see Program::call_stub
+    Program(ProgramError),
+    // Vm::patch with an instruction the program can't have there
+    Patch(VerifyError),
+    // VmConfig::prepare on code that doesn't verify under the config's FallOffEnd
+    Verify(VerifyError),
+    // Vm::tune with no kinds to pick from
+    NothingToTune,
+    // Vm::set_trap_vector to a pc past the end, or for FellOffEnd, which has no
+    // instruction after it to return to
+    BadTrapVector { kind: TrapKind, pc: usize },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::TooLarge(e) => e.fmt(f),
+            VmError::TooManyArgs { given, max } => write!(f, "{given} arguments, there's room for {max}"),
+            VmError::NoSuchEntry(name) => write!(f, "no entry point called `{name}`"),
+            VmError::Program(e) => e.fmt(f),
+            VmError::Patch(e) => e.fmt(f),
+            VmError::Verify(e) => e.fmt(f),
+            VmError::NothingToTune => write!(f, "no dispatch kinds to tune over"),
+            VmError::BadTrapVector { kind, pc } => write!(f, "no trap vector for {kind:?} at pc {pc}"),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+impl From<bytes::TooLarge> for VmError {
+    fn from(e: bytes::TooLarge) -> VmError {
+        VmError::TooLarge(e)
+    }
+}
+
+fn check_args(args: &[i64], max: usize) -> Result<(), VmError> {
+    if args.len() > max {
+        return Err(VmError::TooManyArgs { given: args.len(), max });
+    }
+    Ok(())
+}
+
+// where Vm::resume stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Halted(i64),
+    // on a WAITEVENT with nothing posted, resumes once Vm::post_event gives it something
+    Waiting,
+    // used up the fuel it was given, resumes where it left off
+    OutOfFuel,
+    // on a TRAP whose handler said TrapAction::Break, with the TRAP's immediate
+    Break(u16),
+}
+
+// what Vm::patch changed, for the hooks that keep something derived from the code
+// (counters per pc, compiled blocks, a disassembly view) in step with it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Patched {
+    pub pc: usize,
+    pub old: u32,
+    pub new: u32,
+}
+
+pub type PatchHook = Box<dyn FnMut(Patched) + Send>;
+
+// what a trap handler wants done next
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrapAction {
+    // on with the instruction after the TRAP: logging, an assertion that held
+    Continue,
+    // stop `resume` with Status::Break and stay on the TRAP, a breakpoint. the next
+    // step calls the handler again, so a debugger patches the original back first
+    Break,
+    // end the program as if it had HALTed with this, say -1 for an assertion that failed
+    Halt(i64),
+}
+
+pub type TrapHandler = Box<dyn FnMut(&mut HostCtx<'_>) -> TrapAction + Send>;
+
+// what a RESOLVE_CALL's immediate names -> the TRAP immediate of the host function that
+// is, None for a name the host doesn't have
+pub type Resolver = Box<dyn FnMut(u16) -> Option<u16> + Send>;
+
+// what Vm::tune measured and what it went with
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuning {
+    // the fastest, the Vm's dispatch from then on
+    pub chosen: DispatchKind,
+    // ns per run of every kind that took the program, in the order they were tried
+    pub samples: Vec<(DispatchKind, f64)>,
+    // what the program returned, the same through every kind
+    pub result: i64,
+}
+
+pub struct Vm {
+    program: VerifiedProgram,
+    dispatch: DispatchKind,
+    prepared: Prepared,
+    // what `run_with` asked for besides `dispatch`, translated on first use
+    others: HashMap<DispatchKind, Prepared>,
+    // state of the single-stepping interface, `run` doesn't use it
+    state: VmState,
+    // the pages of the state's memory written since the last reset, what the next one
+    // puts back
+    dirty: DirtyPages,
+    // posted by the host, taken one by one by WAITEVENT
+    events: VecDeque<i64>,
+    // call stubs built by `call`, by name, each under the same strategy
+    entries: HashMap<String, Vm>,
+    // told about every patch, see `on_patch`
+    patch_hooks: Vec<PatchHook>,
+    // called by TRAP in `step` and `resume`, see `set_trap_handler`
+    trap_handler: Option<TrapHandler>,
+    // asked by RESOLVE_CALL in `step` and `resume`, see `set_resolver`
+    resolver: Option<Resolver>,
+    // the immediate of the TRAP the last step broke on, for `resume`
+    broke: Option<u16>,
+    // what the last step trapped on, see `trap`
+    trap: Option<Trap>,
+    // guest handlers by TrapKind::code, see `set_trap_vector`
+    trap_vectors: [Option<usize>; TrapKind::ALL.len()],
+    // whether `reset` zeroes registers and memory, see `set_zero_on_reset`
+    zero_on_reset: bool,
+    // whether DIV and MOD by zero trap without a vector, see `set_div_by_zero_traps`
+    div_traps: bool,
+}
+
+impl Vm {
+    // central dispatch runs any verified program as is, so this one can't fail
+    pub fn new(program: VerifiedProgram) -> Vm {
+        Vm::with_dispatch(program, DispatchKind::Central).expect("central runs the words directly")
+    }
+
+    // fails when the strategy executes a translation the program doesn't fit in
+    // (the byte variants, past 64 KiB of bytecode)
+    pub fn with_dispatch(program: VerifiedProgram, dispatch: DispatchKind) -> Result<Vm, VmError> {
+        let prepared = Prepared::new(&program, dispatch)?;
+        let state = VmState::new(program.program().entry(), program.memory());
+        let (events, entries) = (VecDeque::new(), HashMap::new());
+        let (patch_hooks, trap_handler, broke, trap) = (Vec::new(), None, None, None);
+        Ok(Vm {
+            program,
+            dispatch,
+            prepared,
+            others: HashMap::new(),
+            state,
+            dirty: DirtyPages::default(),
+            events,
+            entries,
+            patch_hooks,
+            trap_handler,
+            resolver: None,
+            broke,
+            trap,
+            trap_vectors: [None; TrapKind::ALL.len()],
+            zero_on_reset: true,
+            div_traps: false,
+        })
+    }
+
+    pub fn program(&self) -> &Program {
+        self.program.program()
+    }
+
+    pub fn dispatch(&self) -> DispatchKind {
+        self.dispatch
+    }
+
+    // runs the whole program with r0, r1, ... seeded from `args`
+    pub fn run(&self, args: &[i64]) -> Result<i64, VmError> {
+        check_args(args, NREGS)?;
+        Ok(self.prepared.run(&self.program, args))
+    }
+
+    // `run` through another dispatch loop, so a host can A/B them on its own program
+    // and inputs with one Vm. the first run with a kind translates the program for it
+    // (and fails where `with_dispatch` would), later ones reuse that until a patch
+    pub fn run_with(&mut self, dispatch: DispatchKind, args: &[i64]) -> Result<i64, VmError> {
+        check_args(args, NREGS)?;
+        if dispatch == self.dispatch {
+            return Ok(self.prepared.run(&self.program, args));
+        }
+        if !self.others.contains_key(&dispatch) {
+            self.others.insert(dispatch, Prepared::new(&self.program, dispatch)?);
+        }
+        Ok(self.others[&dispatch].run(&self.program, args))
+    }
+
+    // `run` goes through `dispatch` from now on. the translation it was using is kept
+    // for `run_with`, and call stubs get rebuilt under the new one
+    pub fn set_dispatch(&mut self, dispatch: DispatchKind) -> Result<(), VmError> {
+        if dispatch == self.dispatch {
+            return Ok(());
+        }
+        let prepared = match self.others.remove(&dispatch) {
+            Some(prepared) => prepared,
+            None => Prepared::new(&self.program, dispatch)?,
+        };
+        let old = std::mem::replace(&mut self.prepared, prepared);
+        self.others.insert(self.dispatch, old);
+        self.dispatch = dispatch;
+        self.entries.clear();
+        Ok(())
+    }
+
+    // what an adaptive interpreter does on load: runs `args` through each of `kinds`
+    // for about `budget` (at least once), then `set_dispatch`es the fastest so every
+    // `run` after this gets it. kinds that can't take the program are left out, and if
+    // none can it fails like `with_dispatch` on the first of them. the budget is the
+    // short prefix, spend it on inputs that look like the real ones: the winner on a
+    // tiny input isn't always the winner on a big one
+    pub fn tune(&mut self, kinds: &[DispatchKind], args: &[i64], budget: Duration) -> Result<Tuning, VmError> {
+        let mut samples = Vec::new();
+        let mut result = None;
+        let mut first_err = None;
+        for &kind in kinds {
+            // the first run translates, it isn't timed
+            let r = match self.run_with(kind, args) {
+                Ok(r) => r,
+                Err(e) => {
+                    first_err.get_or_insert(e);
+                    continue;
+                }
+            };
+            debug_assert!(result.is_none_or(|expected| expected == r), "{kind:?} disagrees on the result");
+            result = Some(r);
+            let (start, mut runs) = (Instant::now(), 0u64);
+            while runs == 0 || start.elapsed() < budget {
+                black_box(self.run_with(black_box(kind), black_box(args))?);
+                runs += 1;
+            }
+            samples.push((kind, start.elapsed().as_nanos() as f64 / runs as f64));
+        }
+        let Some(&(chosen, _)) = samples.iter().min_by(|a, b| a.1.total_cmp(&b.1)) else {
+            return Err(first_err.unwrap_or(VmError::NothingToTune));
+        };
+        self.set_dispatch(chosen)?;
+        Ok(Tuning { chosen, samples, result: result.expect("a kind ran") })
+    }
+
+    // runs the code at the name `name` like a host calling into guest code: `args` go
+    // into arg0.., the name gets CALLed, and the result is what it leaves in ret when
+    // it returns (or what it HALTs with). see ARG_REGS for the convention. the call
+    // stub is built and translated on the first call, later ones reuse it
+    pub fn call(&mut self, name: &str, args: &[i64]) -> Result<i64, VmError> {
+        check_args(args, ARG_REGS)?;
+        if !self.entries.contains_key(name) {
+            let pc = self.program().pc_of(name).ok_or_else(|| VmError::NoSuchEntry(name.to_string()))?;
+            let stub = self.program().call_stub(pc).map_err(VmError::Program)?;
+            let stub = stub.verify().expect("a call stub keeps the verified control flow");
+            self.entries.insert(name.to_string(), Vm::with_dispatch(stub, self.dispatch)?);
+        }
+        self.entries[name].run(args)
+    }
+
+    // replaces the instruction at `pc` (a pc in the program as written, not lowered)
+    // without building a new Vm: breakpoints by substitution, a guest rewriting its own
+    // hot path. the code is verified again where it changed and everything derived from
+    // it follows: a bundle slot or a decoded instruction gets rewritten, the byte
+    // translation redone (every offset after the patch can move), the loops lifted out
+    // again, the jit code recompiled, call stubs and `run_with` translations rebuilt on
+    // their next use, and every `on_patch` hook called. a Vm suspended in `resume`
+    // runs the new instruction when it gets there. returns the instruction that was at
+    // `pc`
+    pub fn patch(&mut self, pc: usize, word: u32) -> Result<u32, VmError> {
+        let had_memory = self.program.memory_words() > 0;
+        let old = self.program.patch(pc, word).map_err(VmError::Patch)?;
+        let new_memory = !had_memory && self.program.memory_words() > 0;
+        match &mut self.prepared {
+            Prepared::Words(_) => {}
+            Prepared::Bundles(_, code) if new_memory => *code = to_bundles(&self.program),
+            Prepared::Bundles(_, code) => {
+                let (at, lowered) = self.program.program().lowered_at(pc);
+                code.bundles[at / 4][at % 4] = lowered;
+            }
+            Prepared::Decoded(_, code) if new_memory => *code = to_decoded(&self.program),
+            Prepared::Decoded(_, code) => {
+                let (at, lowered) = self.program.program().lowered_at(pc);
+                code.code[at] = DecodedInstr::of(lowered);
+            }
+            // the patch can make or break a loop anywhere around it
+            Prepared::Loops(_, code) => *code = to_loop_bodies(&self.program),
+            Prepared::Bytes(_, code) => match bytes::to_bytes(&self.program) {
+                Ok(translated) => *code = translated,
+                Err(e) => {
+                    self.program.patch(pc, old).expect("the old instruction verified before");
+                    return Err(e.into());
+                }
+            },
+            #[cfg(feature = "jit")]
+            Prepared::Native(_, code) => *code = jit::compile(&self.program),
+        }
+        if new_memory {
+            self.state.mem = self.program.memory();
+        }
+        self.entries.clear();
+        self.others.clear();
+        for hook in &mut self.patch_hooks {
+            hook(Patched { pc, old, new: word });
+        }
+        Ok(old)
+    }
+
+    // `hook` gets called after every successful patch, for state the host keeps per
+    // instruction that a patch makes stale
+    pub fn on_patch(&mut self, hook: impl FnMut(Patched) + Send + 'static) {
+        self.patch_hooks.push(Box::new(hook));
+    }
+
+    // what TRAP calls when this Vm steps over one, replacing the handler set before.
+    // only `step` and `resume` have one to call: `run`, `call` and the variants stop
+    // on a TRAP with -1 (TrapKind::Unhandled), same as stepping one with no handler set.
+    // the handler sees the guest through a HostCtx, checked registers and memory
+    pub fn set_trap_handler(&mut self, handler: impl FnMut(&mut HostCtx<'_>) -> TrapAction + Send + 'static) {
+        self.trap_handler = Some(Box::new(handler));
+    }
+
+    // what RESOLVE_CALL asks which host function its immediate means, replacing the
+    // resolver set before. the first time one steps, the answer gets patched over it
+    // as `TRAP index` (with `patch`, so on_patch hooks hear about it) and that TRAP
+    // runs right away, as the same step: later runs of it go straight to the trap
+    // handler without asking. a resolver that doesn't know the name, or none at all,
+    // stops the program with -1 (TrapKind::Unresolved) and leaves the RESOLVE_CALL to
+    // ask again after the next reset. patched code stays patched across resets, and a
+    // VmPool drops a Vm that resolved anything since its code isn't the program's any
+    // more. like TRAP, only `step` and `resume` resolve
+    pub fn set_resolver(&mut self, resolver: impl FnMut(u16) -> Option<u16> + Send + 'static) {
+        self.resolver = Some(Box::new(resolver));
+    }
+
+    // a trap of `kind` under `step`/`resume` jumps to guest code at `pc` instead of
+    // ending the program, None takes that back. it goes like a CALL made by the
+    // faulting instruction that also PUSHed the kind's code: the handler POPs the
+    // code, does what recovering means to it, and RETs to the instruction after the
+    // one that trapped. a TRAP goes to the host's handler if there is one and only
+    // comes here as Unhandled without, and DivByZero only happens with a vector for
+    // it or set_div_by_zero_traps. when the stacks have no room for the delivery (a CALL that overflowed, say)
+    // the program stops with -1 after all, Vm::trap says why
+    pub fn set_trap_vector(&mut self, kind: TrapKind, pc: Option<usize>) -> Result<(), VmError> {
+        if let Some(at) = pc
+            && (at >= self.program().code().len() || kind == TrapKind::FellOffEnd)
+        {
+            return Err(VmError::BadTrapVector { kind, pc: at });
+        }
+        self.trap_vectors[kind.code() as usize] = pc;
+        Ok(())
+    }
+
+    // back to the entry point, registers seeded from `args`, events not taken yet
+    // dropped. memory comes back the way `new` built it, but only the pages written
+    // since the last reset get rewritten (a 4 KiB page per bit, marked by STORE, MEMCPY
+    // and MEMSET as they step), so it costs what the last run touched rather than the
+    // size of memory. the registers are 16 words, seeding them all beats tracking them
+    pub fn reset(&mut self, args: &[i64]) -> Result<(), VmError> {
+        check_args(args, NREGS)?;
+        let state = &mut self.state;
+        if self.zero_on_reset {
+            state.regs = seed_regs(args);
+            self.program.memory_image().restore(&mut state.mem, &mut self.dirty);
+        } else {
+            state.regs[..args.len()].copy_from_slice(args);
+            self.program.memory_image().refill(&mut state.mem);
+        }
+        state.pc = self.program.program().entry();
+        state.stack.clear();
+        state.retired = 0;
+        self.events.clear();
+        self.broke = None;
+        self.trap = None;
+        Ok(())
+    }
+
+    // off, `reset` leaves the registers past `args` and the memory outside the data
+    // segments as the last run left them, instead of zeroing them (the pages it wrote).
+    // for hosts calling a tiny guest over and over, where clearing even one page costs
+    // more than running it. nothing checks the guest writes before it reads, so
+    // this is only for code written that way: a program that reads a register it didn't
+    // set sees the previous invocation's value, which is a different answer, not
+    // undefined behaviour. that's also why the registers aren't left MaybeUninit: it
+    // would save the 16 stores, which `--instantiate` can't tell from noise (pool
+    // against pool-keep on fib), and make such a read UB. `run` doesn't go through
+    // `reset` and always starts zeroed
+    pub fn set_zero_on_reset(&mut self, zero: bool) {
+        self.zero_on_reset = zero;
+    }
+
+    // whether DIV and MOD by zero stop the program with -1 (TrapKind::DivByZero) instead
+    // of giving 0, for a host that would rather hear about it than have the guest go on
+    // with a 0 it didn't expect. a vector for DivByZero catches it either way. like
+    // vectors, only `step` and `resume` trap: `run`, `call` and the variants give 0,
+    // the ISA's answer, and the trap costs them nothing
+    pub fn set_div_by_zero_traps(&mut self, traps: bool) {
+        self.div_traps = traps;
+    }
+
+    // queues `val` for the next WAITEVENT, events are taken in the order they're posted
+    pub fn post_event(&mut self, val: i64) {
+        self.events.push_back(val);
+    }
+
+    // whether the next step is a WAITEVENT with nothing to take
+    pub fn waiting(&self) -> bool {
+        self.events.is_empty()
+            && self.program.program().code().get(self.state.pc).is_some_and(|&word| (word & 0xFF) as u8 == OP_WAITEVENT)
+    }
+
+    // single-steps from where the last step or resume left off (after `reset`), up to
+    // `fuel` instructions. a WAITEVENT with nothing posted suspends right away without
+    // spending any, so a guest event loop costs nothing while it's idle. a TRAP whose
+    // handler breaks stops it too, on the TRAP, also without spending any
+    pub fn resume(&mut self, fuel: u64) -> Status {
+        let mut fuel = fuel;
+        loop {
+            if self.waiting() {
+                return Status::Waiting;
+            }
+            if fuel == 0 {
+                return Status::OutOfFuel;
+            }
+            fuel -= 1;
+            if let Some(result) = self.step() {
+                return Status::Halted(result);
+            }
+            if let Some(imm) = self.broke {
+                return Status::Break(imm);
+            }
+        }
+    }
+
+    // roughly what this Vm keeps alive: the struct, the program as written and lowered,
+    // whatever the strategy translated it into, the memory the single-stepping side
+    // works on and the call stubs built so far. the big buffers, not allocator overhead
+    pub fn resident_bytes(&self) -> usize {
+        let words = (self.program().code().len() + self.program.code().len()) * size_of::<u32>();
+        // the segments are kept twice, by the program and by its memory image
+        let segments: usize = self.program().segments().iter().map(|s| 2 * size_of_val(&s.words[..])).sum();
+        let others: usize = self.others.values().map(Prepared::translated_bytes).sum();
+        let translated = self.prepared.translated_bytes() + others;
+        let stepping = size_of_val(self.state.memory()) + self.events.len() * size_of::<i64>();
+        let entries: usize = self.entries.values().map(Vm::resident_bytes).sum();
+        size_of::<Vm>() + words + segments + translated + stepping + entries
+    }
+
+    // why the last step ended the program with -1, with the guest backtrace: None
+    // after anything else, a HALT -1 included. `resume` stops on the same step
+    pub fn trap(&self) -> Option<&Trap> {
+        self.trap.as_ref()
+    }
+
+    // everything `step` and `resume` work on, to clone and diff against later
+    pub fn state(&self) -> &VmState {
+        &self.state
+    }
+
+    pub fn regs(&self) -> &[i64; NREGS] {
+        self.state.regs()
+    }
+
+    pub fn pc(&self) -> usize {
+        self.state.pc()
+    }
+
+    // linear memory as `step` and `resume` left it
+    pub fn memory(&self) -> &[i64] {
+        self.state.memory()
+    }
+
+    // instructions executed by `step` and `resume` since the last reset, HALT included
+    pub fn retired(&self) -> u64 {
+        self.state.retired()
+    }
+
+    // executes one instruction, returns Some(result) once the program is done. a
+    // WAITEVENT with nothing posted doesn't move, `waiting` says when that's the case,
+    // and neither does a TRAP its handler broke on.
+    // verification already ruled out bad opcodes and registers, and running off the end
+    // unless the program's FallOffEnd says what that does: the implicit HALT r0 or trap
+    // is a step of its own, like the word the variants run there, and leaves pc at the end
+    pub fn step(&mut self) -> Option<i64> {
+        self.broke = None;
+        self.trap = None;
+        let state = &mut self.state;
+        let at = state.pc;
+        let Some(&word) = self.program.program().code().get(at) else {
+            state.retired += 1;
+            return match self.program.program().fall_off_end() {
+                FallOffEnd::Halt => Some(state.regs[0]),
+                FallOffEnd::Reject | FallOffEnd::Trap => self.trapped(TrapKind::FellOffEnd, at),
+            };
+        };
+        let Instruction { op, dst, a, b } = Instruction::decode(word).expect("checked by verify");
+
+        let (d, x, y) = (dst as usize, a as usize, b as usize);
+        let catches_div = self.div_traps || self.trap_vectors[TrapKind::DivByZero.code() as usize].is_some();
+        let regs = &mut state.regs;
+        state.pc += 1;
+        state.retired += 1;
+        match op {
+            Opcode::Halt => return Some(regs[d]),
+            Opcode::Div | Opcode::Mod if regs[y] == 0 && catches_div => return self.trapped(TrapKind::DivByZero, at),
+            Opcode::Loadi => regs[d] = imm16(a, b),
+            // the register ops, straight from the semantics table
+            Opcode::Add
+            | Opcode::Sub
+            | Opcode::Mul
+            | Opcode::Div
+            | Opcode::Mod
+            | Opcode::Inc
+            | Opcode::Dec
+            | Opcode::Mov
+            | Opcode::Clz
+            | Opcode::Ctz
+            | Opcode::Popcnt
+            | Opcode::Cmp => regs[d] = semantics::eval(op as u8, regs, dst, a, b).expect("in the semantics table"),
+            Opcode::Jmpnz | Opcode::Jmp | Opcode::Jeq | Opcode::Jne | Opcode::Jlt | Opcode::Jge => {
+                if branch_taken(op as u8, regs[d]) {
+                    state.pc = imm16(a, b) as usize;
+                }
+            }
+            // one step for the pair, like in the variants
+            Opcode::AddAdd => {
+                fused_binops(regs, dst, a, b, semantics::add, semantics::add);
+                state.pc += 1;
+            }
+            Opcode::MulSub => {
+                fused_binops(regs, dst, a, b, semantics::mul, semantics::sub);
+                state.pc += 1;
+            }
+            Opcode::DecJnz => {
+                regs[d] = semantics::dec(regs[d]);
+                state.pc = if regs[d] != 0 { imm16(a, b) as usize } else { state.pc + 1 };
+            }
+            // the stepper works in unlowered pcs, which is what CALL pushes here
+            Opcode::Call => {
+                if !state.stack.call(state.pc) {
+                    return self.trapped(TrapKind::StackOverflow, at);
+                }
+                state.pc = imm16(a, b) as usize;
+            }
+            Opcode::Ret => match state.stack.ret() {
+                Some(ret) => state.pc = ret,
+                None => return self.trapped(TrapKind::StackUnderflow, at),
+            },
+            Opcode::Push => {
+                if !state.stack.push(regs[d]) {
+                    return self.trapped(TrapKind::StackOverflow, at);
+                }
+            }
+            Opcode::Pop => match state.stack.pop() {
+                Some(v) => regs[d] = v,
+                None => return self.trapped(TrapKind::StackUnderflow, at),
+            },
+            Opcode::Load => match state.mem.get(address(regs[x], b)) {
+                Some(&v) => regs[d] = v,
+                None => return self.trapped(TrapKind::MemoryOutOfBounds, at),
+            },
+            Opcode::Store => {
+                let addr = address(regs[x], b);
+                match state.mem.get_mut(addr) {
+                    Ok(slot) => *slot = regs[d],
+                    Err(kind) => return self.trapped(kind, at),
+                }
+                self.dirty.mark(addr);
+            }
+            // past the check the range is inside memory, `as usize` can't wrap
+            Opcode::Memcpy => {
+                if let Err(kind) = state.mem.copy(regs[d], regs[x], regs[y]) {
+                    return self.trapped(kind, at);
+                }
+                self.dirty.mark_range(regs[d] as usize..(regs[d] + regs[y]) as usize);
+            }
+            Opcode::Memset => {
+                if let Err(kind) = state.mem.fill(regs[d], regs[x], regs[y]) {
+                    return self.trapped(kind, at);
+                }
+                self.dirty.mark_range(regs[d] as usize..(regs[d] + regs[y]) as usize);
+            }
+            // nothing posted: stays on the WAITEVENT, see `waiting`
+            Opcode::Waitevent => match self.events.pop_front() {
+                Some(v) => regs[d] = v,
+                // didn't happen, as far as the pc and the count are concerned
+                None => {
+                    state.pc -= 1;
+                    state.retired -= 1;
+                }
+            },
+            Opcode::Trap => {
+                let Some(handler) = &mut self.trap_handler else { return self.trapped(TrapKind::Unhandled, at) };
+                let imm = imm16(a, b) as u16;
+                let stack = state.stack.values();
+                let mut ctx = HostCtx::new(imm, at, state.retired - 1, regs, &mut state.mem, &mut self.dirty, stack);
+                match handler(&mut ctx) {
+                    TrapAction::Continue => {}
+                    // like a WAITEVENT with nothing posted, it didn't happen
+                    TrapAction::Break => {
+                        state.pc -= 1;
+                        state.retired -= 1;
+                        self.broke = Some(imm);
+                    }
+                    TrapAction::Halt(result) => return Some(result),
+                }
+            }
+            Opcode::ResolveCall => {
+                let Some(index) = self.resolver.as_mut().and_then(|resolve| resolve(imm16(a, b) as u16)) else {
+                    return self.trapped(TrapKind::Unresolved, at);
+                };
+                let call = Instruction::with_imm(Opcode::Trap, 0, index).encode();
+                self.patch(at, call).expect("a TRAP verifies wherever a RESOLVE_CALL did");
+                // the call is the TRAP's to make, on the same step
+                self.state.pc = at;
+                self.state.retired -= 1;
+                return self.step();
+            }
+        }
+        None
+    }
+
+    // hands `kind` at `pc` to the guest's handler for it, or ends the program keeping
+    // the backtrace for `trap`
+    fn trapped(&mut self, kind: TrapKind, pc: usize) -> Option<i64> {
+        if let Some(vector) = self.trap_vectors[kind.code() as usize] {
+            let stack = &mut self.state.stack;
+            if stack.call(pc + 1) {
+                if stack.push(kind.code()) {
+                    self.state.pc = vector;
+                    return None;
+                }
+                stack.ret();
+            }
+        }
+        self.trap = Some(Trap::capture(kind, pc, &self.state.stack.calls, self.program.program()));
+        Some(-1)
+    }
+}
+
+// one test per opcode: each runs a tiny program through every variant and checks
+// the result against what the opcode is supposed to do (semantics::eval for the
+// register ops), so a handler that reads the wrong operand or skips a write fails here
+// instead of skewing the benchmark
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // operand values, negatives and zero divisors included, and the extremes: they
+    // come in through args, so nothing limits them to what LOADI can build, and
+    // i64::MIN / -1 is the one division that overflows
+    const INPUTS: &[(i64, i64)] = &[
+        (0, 0), (1, 2), (7, 3), (-7, 3), (7, -3), (-7, -3),
+        (5, 0), (-1, -1), (65535, 65535), (1000, 1), (12345, 678),
+        (i64::MIN, -1), (i64::MIN, 1), (i64::MIN, i64::MIN), (i64::MAX, -1), (i64::MAX, i64::MAX),
+        (i64::MIN, i64::MAX), (i64::MAX, 2), (-5, i64::MIN),
+    ];
+
+    fn check(opname: &str, case: &str, code: &[u32], args: &[i64], expected: i64) {
+        let program = verify(code).unwrap();
+        for v in VARIANTS {
+            let got = v.exec(&program, args).unwrap();
+            assert_eq!(got, expected, "{opname} {case} on {}: got {got}, expected {expected}", v.name);
+        }
+        for (depth, f) in THREADED_DEPTHS.iter().enumerate() {
+            let got = f(&program, args);
+            assert_eq!(got, expected, "{opname} {case} on run_threaded_{}: got {got}", depth + 1);
+        }
+        assert_eq!(step_to_end(code, args), expected, "{opname} {case} stepped through Vm::step");
+    }
+
+    fn step_to_end(code: &[u32], args: &[i64]) -> i64 {
+        let mut vm = Vm::new(verify(code).unwrap());
+        vm.reset(args).unwrap();
+        loop {
+            if let Some(result) = vm.step() {
+                return result;
+            }
+        }
+    }
+
+    // what the semantics table says `op` leaves in regs[dst] when the program starts
+    // with `args`
+    fn expected(op: u8, args: &[i64], dst: u8, a: u8, b: u8) -> i64 {
+        semantics::eval(op, &seed_regs(args), dst, a, b).unwrap()
+    }
+
+    // r3 = r1 OP r2, or OP r1 with r2 ignored
+    fn check_binop(opname: &str, op: u8) {
+        for &(x, y) in INPUTS {
+            let code = [encode(op, 3, 1, 2), encode(OP_HALT, 3, 0, 0)];
+            check(opname, &format!("({x}, {y})"), &code, &[0, x, y], expected(op, &[0, x, y], 3, 1, 2));
+        }
+    }
+
+    // r1 = OP r1
+    fn check_unop(opname: &str, op: u8) {
+        for &(x, _) in INPUTS {
+            let code = [encode(op, 1, 0, 0), encode(OP_HALT, 1, 0, 0)];
+            check(opname, &format!("({x})"), &code, &[0, x], expected(op, &[0, x], 1, 0, 0));
+        }
+    }
+
+    // one test per row of the semantics table, alu::add and so on
+    macro_rules! alu_tests {
+        ($($shape:ident $name:ident = $op:ident, $mnemonic:literal, |$($arg:ident),+| $body:expr, $text:literal;)*) => {
+            mod alu {
+                use super::*;
+
+                $( #[test] fn $name() { alu_tests!(@check $shape $mnemonic, $op); } )*
+            }
+        };
+        (@check binary $mnemonic:literal, $op:ident) => { check_binop($mnemonic, $op) };
+        (@check unary $mnemonic:literal, $op:ident) => { check_binop($mnemonic, $op) };
+        (@check in_place $mnemonic:literal, $op:ident) => { check_unop($mnemonic, $op) };
+    }
+
+    semantics::for_each_alu_op!(alu_tests);
+
+    #[test]
+    fn op_halt() {
+        for &(x, _) in INPUTS {
+            let code = [
+                encode(OP_HALT, 7, 0, 0),
+                // never reached, halt has to stop right there
+                encode(OP_INC, 7, 0, 0),
+                encode(OP_HALT, 7, 0, 0),
+            ];
+            check("op_halt", &format!("({x})"), &code, &[0, 0, 0, 0, 0, 0, 0, x], x);
+        }
+    }
+
+    #[test]
+    fn op_loadi() {
+        for imm in [0u16, 1, 0xFF, 0x100, 0x1234, 0xFFFF] {
+            let code = [
+                encode(OP_LOADI, 4, (imm & 0xFF) as u8, (imm >> 8) as u8),
+                encode(OP_HALT, 4, 0, 0),
+            ];
+            check("op_loadi", &format!("({imm:#x})"), &code, &[], imm as i64);
+        }
+    }
+
+    #[test]
+    fn args_seed_registers() {
+        // r0..r2 come from the host, r3 wasn't passed so it starts at zero
+        let code = [
+            encode(OP_ADD, 4, 0, 1),
+            encode(OP_MUL, 4, 4, 2),
+            encode(OP_ADD, 4, 4, 3),
+            encode(OP_HALT, 4, 0, 0),
+        ];
+        let program = verify(&code).unwrap();
+        for v in VARIANTS {
+            assert_eq!(v.exec(&program, &[-40, 1 << 40, 3]).unwrap(), ((1i64 << 40) - 40) * 3, "{}", v.name);
+        }
+    }
+
+    // the opcode tests run each instruction at level 1 and the HALT after it at level 2,
+    // the kernels get every handler to every level
+    #[test]
+    fn threaded_depths_match_central() {
+        for name in ["sum-poly", "poly:4", "loop-nest:3", "fsm:8", "fib", "sieve", "array-sum", "collatz", "bitscan"] {
+            let (code, args) = kernels::kernel(name, 100).unwrap();
+            let program = verify(&code).unwrap();
+            let expected = run_central(&program, &args);
+            for (depth, f) in THREADED_DEPTHS.iter().enumerate() {
+                assert_eq!(f(&program, &args), expected, "{name} on run_threaded_{}", depth + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn loop_bodies_lift_straight_loops_only() {
+        for name in ["sum-poly", "poly:4", "loop-nest:3", "fsm:8", "fib", "sieve", "array-sum", "collatz", "bitscan"] {
+            let (code, args) = kernels::kernel(name, 100).unwrap();
+            let program = verify(&code).unwrap();
+            assert_eq!(run_loop_bodies(&to_loop_bodies(&program), &args), run_central(&program, &args), "{name}");
+        }
+        let sum_poly = verify(&kernels::sum_poly()).unwrap();
+        assert_eq!(to_loop_bodies(&sum_poly).loops(), 1);
+        // ADDADD isn't in the semantics table
+        assert_eq!(to_loop_bodies(&verify(&fuse::fuse(&kernels::sum_poly())).unwrap()).loops(), 0);
+
+        // r1 += r0 while --r0, entered halfway through its body the first time round
+        let code = [
+            encode(OP_LOADI, 1, 10, 0),
+            encode(OP_JMP, 0, 3, 0),
+            encode(OP_ADD, 1, 1, 0), // 2: the loop
+            encode(OP_DEC, 0, 0, 0),
+            encode(OP_JMPNZ, 0, 2, 0),
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        let program = verify(&code).unwrap();
+        let lifted = to_loop_bodies(&program);
+        assert_eq!((lifted.loops(), lifted.code()[2].op), (1, OP_LOOP_BODY));
+        assert_eq!(run_loop_bodies(&lifted, &[5]), 10 + 4 + 3 + 2 + 1);
+    }
+
+    #[test]
+    fn stream_matches_slice() {
+        let filter = kernels::filter();
+        let program = verify(&filter).unwrap();
+        for i in -50..50 {
+            let args = [i * 37, i ^ 0x55];
+            assert_eq!(run_stream(filter.iter().copied(), &args), run_central(&program, &args));
+        }
+    }
+
+    #[test]
+    fn stream_jumps() {
+        // forward jump taken: skips the LOADI 99
+        let code = [
+            encode(OP_LOADI, 1, 1, 0),
+            encode(OP_JMPNZ, 1, 3, 0),
+            encode(OP_LOADI, 1, 99, 0),
+            encode(OP_HALT, 1, 0, 0), // pc = 3
+        ];
+        assert_eq!(run_stream(code, &[]), 1);
+        // backward jump is rejected
+        let code = [encode(OP_LOADI, 1, 1, 0), encode(OP_JMPNZ, 1, 0, 0), encode(OP_HALT, 1, 0, 0)];
+        assert_eq!(run_stream(code, &[]), -1);
+        // so is running out of instructions, before or after a jump
+        assert_eq!(run_stream([encode(OP_INC, 1, 0, 0)], &[]), -1);
+        let code = [encode(OP_LOADI, 1, 1, 0), encode(OP_JMPNZ, 1, 9, 0), encode(OP_HALT, 1, 0, 0)];
+        assert_eq!(run_stream(code, &[]), -1);
+        // nobody verified the stream: registers that don't exist and unknown opcodes
+        // stop it instead of indexing out of bounds
+        assert_eq!(run_stream([encode(OP_INC, 200, 0, 0)], &[]), -1);
+        assert_eq!(run_stream([encode(OP_ADD, 1, 2, NREGS as u8), encode(OP_HALT, 1, 0, 0)], &[]), -1);
+        assert_eq!(run_stream([encode(OP_MOV, 1, 255, 0), encode(OP_HALT, 1, 0, 0)], &[]), -1);
+        assert_eq!(run_stream([0xEE, encode(OP_HALT, 1, 0, 0)], &[]), -1);
+        // LOADI's operands are an immediate, those can be anything
+        assert_eq!(run_stream([encode(OP_LOADI, 1, 200, 1), encode(OP_HALT, 1, 0, 0)], &[]), 456);
+    }
+
+    #[test]
+    fn op_jmpnz() {
+        // count r1 down from n, bumping r2 each time around: taken while r1 != 0,
+        // falls through once it hits zero
+        for n in [1u8, 2, 3, 10, 200] {
+            let code = [
+                encode(OP_LOADI, 1, n, 0),
+                encode(OP_LOADI, 2, 0, 0),
+                encode(OP_INC, 2, 0, 0), // pc = 2
+                encode(OP_DEC, 1, 0, 0),
+                encode(OP_JMPNZ, 1, 2, 0),
+                encode(OP_HALT, 2, 0, 0),
+            ];
+            check("op_jmpnz", &format!("(n = {n})"), &code, &[], n as i64);
+        }
+        // not taken on the first go
+        let code = [
+            encode(OP_LOADI, 1, 0, 0),
+            encode(OP_JMPNZ, 1, 3, 0),
+            encode(OP_HALT, 1, 0, 0),
+            encode(OP_LOADI, 1, 99, 0), // pc = 3
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        check("op_jmpnz", "(not taken)", &code, &[], 0);
+    }
+
+    #[test]
+    fn op_branches() {
+        // 1 if the branch on r1 is taken, 0 if it falls through
+        type Taken = fn(i64) -> bool;
+        let cases: &[(&str, u8, Taken)] = &[
+            ("op_jmp", OP_JMP, |_| true),
+            ("op_jeq", OP_JEQ, |v| v == 0),
+            ("op_jne", OP_JNE, |v| v != 0),
+            ("op_jlt", OP_JLT, |v| v < 0),
+            ("op_jge", OP_JGE, |v| v >= 0),
+        ];
+        for &(opname, op, taken) in cases {
+            for v in [0, 1, -1, 7, -7, i64::MIN, i64::MAX] {
+                let code = [
+                    encode(op, 1, 3, 0),
+                    encode(OP_HALT, 2, 0, 0),
+                    encode(OP_HALT, 2, 0, 0),
+                    encode(OP_INC, 2, 0, 0), // pc = 3
+                    encode(OP_HALT, 2, 0, 0),
+                ];
+                check(opname, &format!("({v})"), &code, &[0, v], taken(v) as i64);
+            }
+        }
+        // CMP feeding JLT as a counted loop, and a JMP backwards as the last instruction
+        let code = [
+            encode(OP_LOADI, 2, 0, 0),
+            encode(OP_INC, 2, 0, 0), // pc = 1
+            encode(OP_CMP, 3, 2, 1),
+            encode(OP_JLT, 3, 1, 0),
+            encode(OP_JMP, 0, 6, 0),
+            encode(OP_HALT, 0, 0, 0),
+            encode(OP_HALT, 2, 0, 0), // pc = 6
+        ];
+        check("op_jlt", "(loop)", &code, &[0, 10], 10);
+        let code = [encode(OP_JMP, 0, 2, 0), encode(OP_HALT, 1, 0, 0), encode(OP_INC, 1, 0, 0), encode(OP_JMP, 0, 1, 0)];
+        check("op_jmp", "(last)", &code, &[0, 4], 5);
+    }
+
+    #[test]
+    fn op_call_ret_push_pop() {
+        // r1 = 5, then a subroutine that saves r1, clobbers it, restores it and adds 1
+        let code = [
+            encode(OP_LOADI, 1, 5, 0),
+            encode(OP_CALL, 0, 3, 0),
+            encode(OP_HALT, 1, 0, 0),
+            encode(OP_PUSH, 1, 0, 0), // pc = 3
+            encode(OP_LOADI, 1, 99, 0),
+            encode(OP_POP, 1, 0, 0),
+            encode(OP_INC, 1, 0, 0),
+            encode(OP_RET, 0, 0, 0),
+        ];
+        check("op_call", "(save/restore)", &code, &[], 6);
+        // values come back last in, first out
+        let code = [
+            encode(OP_LOADI, 1, 1, 0),
+            encode(OP_LOADI, 2, 2, 0),
+            encode(OP_PUSH, 1, 0, 0),
+            encode(OP_PUSH, 2, 0, 0),
+            encode(OP_POP, 3, 0, 0),
+            encode(OP_POP, 4, 0, 0),
+            encode(OP_SUB, 5, 3, 4),
+            encode(OP_HALT, 5, 0, 0),
+        ];
+        check("op_push", "(lifo)", &code, &[], 1);
+    }
+
+    #[test]
+    fn stack_errors_stop_with_minus_one() {
+        check("op_ret", "(empty call stack)", &[encode(OP_RET, 0, 0, 0)], &[], -1);
+        check("op_pop", "(empty value stack)", &[encode(OP_POP, 1, 0, 0), encode(OP_HALT, 1, 0, 0)], &[], -1);
+        // unbounded recursion and an unbounded push loop
+        check("op_call", "(overflow)", &[encode(OP_CALL, 0, 0, 0), encode(OP_HALT, 0, 0, 0)], &[], -1);
+        let code = [encode(OP_LOADI, 1, 1, 0), encode(OP_PUSH, 1, 0, 0), encode(OP_JMPNZ, 1, 1, 0), encode(OP_HALT, 1, 0, 0)];
+        check("op_push", "(overflow)", &code, &[], -1);
+    }
+
+    #[test]
+    fn op_load_store() {
+        // mem[r1 + 3] = 42, then read it back through another base, r2 + 0 = 8 + 3
+        let code = [
+            encode(OP_LOADI, 1, 8, 0),
+            encode(OP_LOADI, 3, 42, 0),
+            encode(OP_STORE, 3, 1, 3),
+            encode(OP_LOADI, 2, 11, 0),
+            encode(OP_LOAD, 4, 2, 0),
+            encode(OP_HALT, 4, 0, 0),
+        ];
+        check("op_store", "(round trip)", &code, &[], 42);
+        // memory starts zeroed, on every run
+        check("op_load", "(fresh memory)", &[encode(OP_LOAD, 1, 0, 7), encode(OP_HALT, 1, 0, 0)], &[], 0);
+    }
+
+    #[test]
+    fn op_memcpy_memset() {
+        // mem[8..12] = 5, then copy mem[r1..r1 + len] to mem[r2..] and read mem[r4] back
+        let code = |from: u8, to: u8, len: u8, at: u8| {
+            [
+                encode(OP_LOADI, 1, 8, 0),
+                encode(OP_LOADI, 2, 5, 0),
+                encode(OP_LOADI, 3, 4, 0),
+                encode(OP_MEMSET, 1, 2, 3),
+                encode(OP_LOADI, 1, from, 0),
+                encode(OP_LOADI, 2, to, 0),
+                encode(OP_LOADI, 3, len, 0),
+                encode(OP_MEMCPY, 2, 1, 3),
+                encode(OP_LOAD, 4, 0, at),
+                encode(OP_HALT, 4, 0, 0),
+            ]
+        };
+        check("op_memset", "(fill)", &code(0, 0, 0, 11), &[], 5);
+        check("op_memset", "(only len words)", &code(0, 0, 0, 12), &[], 0);
+        check("op_memcpy", "(disjoint)", &code(8, 20, 4, 23), &[], 5);
+        // overlapping both ways copies as if through a temporary, like memmove
+        check("op_memcpy", "(overlap forward)", &code(6, 8, 4, 10), &[], 5);
+        check("op_memcpy", "(overlap back)", &code(10, 8, 4, 9), &[], 5);
+        check("op_memcpy", "(overlap back, tail)", &code(10, 8, 4, 11), &[], 0);
+    }
+
+    #[test]
+    fn memory_errors_stop_with_minus_one() {
+        let top = DEFAULT_MEMORY_WORDS as i64;
+        // the last word is fine, one past it isn't, and neither is anything negative
+        for (base, expected) in [(top - 1, 0), (top, -1), (-1, -1), (i64::MIN, -1), (i64::MAX, -1)] {
+            let code = [encode(OP_LOAD, 1, 0, 0), encode(OP_HALT, 1, 0, 0)];
+            let program = verify(&code).unwrap();
+            for v in VARIANTS {
+                assert_eq!(v.exec(&program, &[base]).unwrap(), expected, "load from {base} on {}", v.name);
+            }
+            let mut vm = Vm::new(verify(&code).unwrap());
+            vm.reset(&[base]).unwrap();
+            assert_eq!(vm.step(), Some(expected).filter(|&e| e == -1), "load from {base} stepped");
+        }
+        // a range has to fit whole, and a negative length is a huge one
+        for (to, len, expected) in [(top - 2, 2, 0), (top - 2, 3, -1), (0, -1, -1), (-1, 1, -1), (top, 0, 0)] {
+            let fill = [encode(OP_MEMSET, 0, 2, 1), encode(OP_HALT, 3, 0, 0)];
+            let copy = [encode(OP_MEMCPY, 0, 2, 1), encode(OP_HALT, 3, 0, 0)];
+            check("op_memset", &format!("(to {to}, len {len})"), &fill, &[to, len, 0], expected);
+            check("op_memcpy", &format!("(to {to}, len {len})"), &copy, &[to, len, 0], expected);
+            let from_expected = if to < 0 { -1 } else { expected };
+            check("op_memcpy", &format!("(from {to}, len {len})"), &copy, &[0, len, to], from_expected);
+        }
+        // the size comes from the program, and only programs that use memory get any
+        let code = [encode(OP_LOADI, 1, 1, 0), encode(OP_STORE, 1, 0, 4), encode(OP_HALT, 1, 0, 0)];
+        let mut small = Program::new(code.to_vec()).unwrap();
+        small.set_memory_words(4).unwrap();
+        let small = small.verify().unwrap();
+        for v in VARIANTS {
+            assert_eq!(v.exec(&small, &[]).unwrap(), -1, "store past 4 words on {}", v.name);
+        }
+        assert_eq!(verify(&kernels::sum_poly()).unwrap().memory_words(), 0);
+        assert!(Program::new(code.to_vec()).unwrap().set_memory_words(MAX_MEMORY_WORDS + 1).is_err());
+    }
+
+    #[test]
+    fn read_only_data_is_write_protected() {
+        // r3 = mem[4] + mem[5], then write r3 through whatever r2 says (0 = nothing),
+        // to r1: 1 STORE, 2 MEMCPY from 0, 3 MEMSET
+        let code = [
+            encode(OP_LOAD, 3, 0, 4),
+            encode(OP_LOAD, 4, 0, 5),
+            encode(OP_ADD, 3, 3, 4),
+            encode(OP_LOADI, 5, 1, 0),
+            encode(OP_SUB, 6, 2, 5),
+            encode(OP_JMPNZ, 2, 7, 0),
+            encode(OP_HALT, 3, 0, 0),
+            encode(OP_JMPNZ, 6, 10, 0),
+            encode(OP_STORE, 3, 1, 0),
+            encode(OP_HALT, 3, 0, 0),
+            encode(OP_SUB, 6, 6, 5),
+            encode(OP_JMPNZ, 6, 14, 0),
+            encode(OP_MEMCPY, 1, 0, 5),
+            encode(OP_HALT, 3, 0, 0),
+            encode(OP_MEMSET, 1, 3, 5),
+            encode(OP_HALT, 3, 0, 0),
+        ];
+        let mut program = Program::new(code.to_vec()).unwrap();
+        program.add_segment(4, vec![20, 22], true).unwrap();
+        program.add_segment(6, vec![-1], false).unwrap();
+        let program = program.verify().unwrap();
+        for kind in 0..=3 {
+            // the writable word right after the read-only ones, then each of those
+            for (at, expected) in [(6, 42), (5, -1), (4, -1)] {
+                let expected = if kind == 0 { 42 } else { expected };
+                for v in VARIANTS {
+                    let got = v.exec(&program, &[0, at, kind]).unwrap();
+                    assert_eq!(got, expected, "write {kind} to {at} on {}", v.name);
+                }
+            }
+        }
+        // every run starts from the data again, written over or not
+        let vm = Vm::new(program.clone());
+        assert_eq!(vm.run(&[0, 6, 1]), Ok(42));
+        assert_eq!(vm.run(&[0, 6, 0]), Ok(42));
+        let mut rec = Recorder::default();
+        assert_eq!(run_hooked(&program, &[0, 5, 3], &mut rec), -1);
+        assert_eq!(rec.traps, [(14, OP_MEMSET, TrapKind::WriteProtected)]);
+        assert!(matches!(
+            Program::new(code.to_vec()).unwrap().add_segment(DEFAULT_MEMORY_WORDS - 1, vec![1, 2], true),
+            Err(ProgramError::SegmentOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn reset_without_zeroing_keeps_what_the_last_run_left() {
+        // mem[1] += r0, and the sum written over the data at mem[0] too
+        let code = [
+            encode(OP_LOAD, 2, 1, 1),
+            encode(OP_ADD, 2, 2, 0),
+            encode(OP_STORE, 2, 1, 1),
+            encode(OP_STORE, 2, 1, 0),
+            encode(OP_HALT, 2, 0, 0),
+        ];
+        let mut program = Program::new(code.to_vec()).unwrap();
+        program.add_segment(0, vec![100], false).unwrap();
+        let mut vm = Vm::new(program.verify().unwrap());
+        let invoke = |vm: &mut Vm| {
+            vm.reset(&[5]).unwrap();
+            loop {
+                if let Some(r) = vm.step() {
+                    break r;
+                }
+            }
+        };
+        assert_eq!([invoke(&mut vm), invoke(&mut vm)], [5, 5]);
+        vm.set_zero_on_reset(false);
+        assert_eq!([invoke(&mut vm), invoke(&mut vm)], [10, 15]);
+        // the data comes back, the register past the args doesn't get cleared
+        vm.reset(&[5]).unwrap();
+        assert_eq!((vm.memory()[..2].to_vec(), vm.regs()[2]), (vec![100, 15], 15));
+        // run never goes through reset
+        assert_eq!(vm.run(&[5]), Ok(5));
+    }
+
+    #[test]
+    fn reset_rewrites_what_was_written_back_to_the_image() {
+        // MEMSET r0 words from r1 to 9, over pages and the data at 1000 alike
+        let code = [encode(OP_LOADI, 2, 9, 0), encode(OP_MEMSET, 1, 2, 0), encode(OP_HALT, 0, 0, 0)];
+        let mut program = Program::new(code.to_vec()).unwrap();
+        program.set_memory_words(5000).unwrap();
+        program.add_segment(1000, vec![1, 2, 3], false).unwrap();
+        program.add_segment(4999, vec![4], false).unwrap();
+        let program = program.verify().unwrap();
+        let fresh = Vm::new(program.clone()).memory().to_vec();
+        let mut vm = Vm::new(program);
+        for args in [[1500, 0], [2, 999], [1, 4999], [0, 0], [5000, 0]] {
+            vm.reset(&args).unwrap();
+            assert_eq!(vm.memory(), fresh, "before {args:?}");
+            assert_eq!(vm.resume(10), Status::Halted(args[0]));
+        }
+        // what the runs without zeroing wrote gets rewritten once zeroing is back on
+        vm.set_zero_on_reset(false);
+        vm.reset(&[10, 4000]).unwrap();
+        vm.resume(10);
+        vm.reset(&[10, 4000]).unwrap();
+        vm.set_zero_on_reset(true);
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.memory(), fresh);
+    }
+
+    #[test]
+    fn vm_strategies_agree() {
+        for name in ["sum-poly", "poly:3", "loop-nest:2", "fsm:5", "fib", "sieve", "array-sum"] {
+            let (code, args) = kernels::kernel(name, 50).unwrap();
+            let expected = run_central(&verify(&code).unwrap(), &args);
+            for &kind in DispatchKind::ALL {
+                let vm = Vm::with_dispatch(verify(&code).unwrap(), kind).unwrap();
+                assert_eq!(vm.run(&args).unwrap(), expected, "{name} on {kind:?}");
+            }
+            assert_eq!(step_to_end(&code, &args), expected, "{name} stepped");
+            for &word in &code {
+                assert_eq!(Instruction::decode(word).map(Instruction::encode), Some(word));
+            }
+        }
+        // running off the end can't get as far as the stepper
+        assert!(verify(&[Instruction::new(Opcode::Inc, 1, 0, 0).encode()]).is_err());
+    }
+
+    #[test]
+    fn run_with_picks_the_loop_per_run() {
+        let mut vm = Vm::new(verify(&kernels::sum_poly()).unwrap());
+        for &kind in DispatchKind::ALL {
+            assert_eq!(DispatchKind::by_name(kind.name()), Some(kind));
+            assert_eq!(vm.run_with(kind, &[100]), Ok(333_400), "{kind:?}");
+        }
+        assert_eq!(DispatchKind::by_name("warp-drive"), None);
+        assert_eq!(vm.dispatch(), DispatchKind::Central);
+        // a patch reaches the translations run_with made too
+        vm.patch(2, encode(OP_JMP, 0, 9, 0)).unwrap();
+        for &kind in DispatchKind::ALL {
+            assert_eq!(vm.run_with(kind, &[100]), Ok(0), "{kind:?}");
+        }
+        assert!(matches!(vm.run_with(DispatchKind::Threaded2, &[1; NREGS + 1]), Err(VmError::TooManyArgs { .. })));
+    }
+
+    #[test]
+    fn tune_keeps_the_fastest() {
+        let mut vm = Vm::new(verify(&kernels::sum_poly()).unwrap());
+        let tuning = vm.tune(DispatchKind::ALL, &[100], Duration::from_millis(1)).unwrap();
+        assert_eq!(tuning.result, 333_400);
+        assert_eq!(tuning.samples.len(), DispatchKind::ALL.len());
+        let fastest = tuning.samples.iter().map(|s| s.1).fold(f64::INFINITY, f64::min);
+        assert!(tuning.samples.contains(&(tuning.chosen, fastest)));
+        assert_eq!(vm.dispatch(), tuning.chosen);
+        assert_eq!(vm.run(&[100]), Ok(333_400));
+        // set_dispatch back and forth goes through the kept translations
+        vm.set_dispatch(DispatchKind::Bundle4).unwrap();
+        assert_eq!((vm.dispatch(), vm.run(&[10])), (DispatchKind::Bundle4, Ok(340)));
+        assert_eq!(vm.tune(&[], &[100], Duration::ZERO), Err(VmError::NothingToTune));
+    }
+
+    #[test]
+    fn program_validation_and_entry() {
+        let mov = |a| Instruction::new(Opcode::Mov, 1, a, 0).encode();
+        let halt = Instruction::new(Opcode::Halt, 1, 0, 0).encode();
+        assert_eq!(Program::new(vec![]).unwrap_err(), ProgramError::Empty);
+        assert_eq!(Program::new(vec![mov(200), halt]).unwrap_err(), ProgramError::BadRegister { pc: 0, reg: 200 });
+        assert!(matches!(Program::new(vec![0xFF, halt]), Err(ProgramError::BadOpcode { pc: 0, .. })));
+        // LOADI's operands are an immediate, not registers
+        assert_eq!(Program::new(vec![Instruction::with_imm(Opcode::Loadi, 3, 0xFFFF).encode()]).unwrap().nregs_required(), 4);
+
+        // r1 = r0 + r0, entering past the first HALT, looping once through the JMPNZ
+        let code = vec![
+            halt,
+            Instruction::new(Opcode::Add, 1, 0, 0).encode(), // entry
+            Instruction::new(Opcode::Dec, 0, 0, 0).encode(),
+            Instruction::with_imm(Opcode::Jmpnz, 0, 1).encode(),
+            halt,
+        ];
+        let mut program = Program::new(code).unwrap();
+        assert!(program.features().is_empty());
+        assert_eq!(program.set_entry(5).unwrap_err(), ProgramError::EntryOutOfRange { entry: 5, len: 5 });
+        program.set_entry(1).unwrap();
+        program.set_name(1, "top").unwrap();
+        assert_eq!(program.name_at(1), Some("top"));
+        for &kind in DispatchKind::ALL {
+            let vm = Vm::with_dispatch(program.clone().verify().unwrap(), kind).unwrap();
+            assert_eq!(vm.run(&[2]).unwrap(), 2, "{kind:?}");
+        }
+        let mut vm = Vm::new(program.verify().unwrap());
+        assert_eq!(vm.run(&[1; NREGS + 1]).unwrap_err(), VmError::TooManyArgs { given: NREGS + 1, max: NREGS });
+        assert_eq!(vm.reset(&[1; NREGS + 1]).unwrap_err(), VmError::TooManyArgs { given: NREGS + 1, max: NREGS });
+        vm.reset(&[2]).unwrap();
+        let result = loop {
+            if let Some(r) = vm.step() {
+                break r;
+            }
+        };
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn patches_reach_every_translation() {
+        let code = kernels::sum_poly();
+        let fused = fuse::fuse(&code);
+        let mut program = Program::new(code.clone()).unwrap();
+        // a non-zero entry shifts every target in the lowered code, the patched ones too
+        for entry in [0, 1] {
+            program.set_entry(entry).unwrap();
+            for &kind in DispatchKind::ALL {
+                let mut vm = Vm::with_dispatch(program.clone().verify().unwrap(), kind).unwrap();
+                let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+                let log = seen.clone();
+                vm.on_patch(move |p| log.lock().unwrap().push(p.pc));
+                assert_eq!(vm.run(&[100]).unwrap(), 333_400);
+                // straight to the HALT, and back
+                let old = vm.patch(2, encode(OP_JMP, 0, 9, 0)).unwrap();
+                assert_eq!(vm.run(&[100]).unwrap(), 0, "{kind:?}");
+                vm.patch(2, old).unwrap();
+                // the guest's loop fused in place, first halves only
+                for pc in [3, 5, 7] {
+                    assert_eq!(vm.patch(pc, fused[pc]).unwrap(), code[pc]);
+                }
+                assert_eq!(vm.run(&[100]).unwrap(), 333_400, "{kind:?}");
+                assert_eq!(vm.program().code(), fused);
+
+                let refused = [
+                    (4, encode(OP_SUB, 5, 4, 4), VerifyError::BrokenPair { pc: 3 }),
+                    (9, encode(OP_INC, 1, 0, 0), VerifyError::FallsOffEnd { last_pc: 9 }),
+                    (2, encode(OP_JMP, 0, 10, 0), VerifyError::JumpOutOfRange { pc: 2, target: 10, len: 10 }),
+                    (10, code[0], VerifyError::Program(ProgramError::PatchOutOfRange { pc: 10, len: 10 })),
+                ];
+                for (pc, word, err) in refused {
+                    assert_eq!(vm.patch(pc, word), Err(VmError::Patch(err)));
+                }
+                assert_eq!(vm.run(&[100]).unwrap(), 333_400, "{kind:?}");
+                assert_eq!(*seen.lock().unwrap(), [2, 2, 3, 5, 7]);
+            }
+        }
+    }
+
+    #[test]
+    fn breakpoints_by_substitution() {
+        // a WAITEVENT suspends resume without spending fuel, so it makes a breakpoint:
+        // patched over the DEC it stops there once per iteration of sum-poly's loop
+        let mut vm = Vm::new(verify(&kernels::sum_poly()).unwrap());
+        vm.reset(&[100]).unwrap();
+        let dec = vm.patch(7, encode(OP_WAITEVENT, 0, 0, 0)).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Waiting);
+        assert_eq!((vm.pc(), vm.regs()[1]), (7, 9901));
+        // stepping over it: the real instruction back for one step, then the breakpoint
+        vm.patch(7, dec).unwrap();
+        assert_eq!(vm.step(), None);
+        vm.patch(7, encode(OP_WAITEVENT, 0, 0, 0)).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Waiting);
+        assert_eq!((vm.pc(), vm.regs()[1]), (7, 9901 + 9703));
+        vm.patch(7, dec).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Halted(333_400));
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        branches: Vec<(usize, usize, bool)>,
+        traps: Vec<(usize, u8, TrapKind)>,
+    }
+
+    impl ExecHook for Recorder {
+        fn on_branch(&mut self, from: usize, to: usize, taken: bool) {
+            self.branches.push((from, to, taken));
+        }
+
+        fn on_trap(&mut self, pc: usize, op: u8, trap: TrapKind) {
+            self.traps.push((pc, op, trap));
+        }
+    }
+
+    fn hooked(code: &[u32], args: &[i64]) -> (i64, Recorder) {
+        let mut rec = Recorder::default();
+        let result = run_hooked(&verify(code).unwrap(), args, &mut rec);
+        (result, rec)
+    }
+
+    #[test]
+    fn hooks_see_calls_and_every_trap() {
+        let halt = encode(OP_HALT, 0, 0, 0);
+        let ret = encode(OP_RET, 0, 0, 0);
+
+        // CALL 2, HALT, RET: the call and the return both show up as taken branches
+        let (result, rec) = hooked(&[encode(OP_CALL, 0, 2, 0), halt, ret], &[7]);
+        assert_eq!(result, 7);
+        assert_eq!(rec.branches, [(0, 2, true), (2, 1, true)]);
+        assert!(rec.traps.is_empty());
+        let (_, rec) = hooked(&[encode(OP_JMPNZ, 0, 1, 0), halt], &[0]);
+        assert_eq!(rec.branches, [(0, 1, false)]);
+        let (_, rec) = hooked(&[encode(OP_JEQ, 0, 2, 0), halt, encode(OP_JMP, 0, 1, 0)], &[0]);
+        assert_eq!(rec.branches, [(0, 2, true), (2, 1, true)]);
+
+        let cases: &[(&[u32], TrapKind)] = &[
+            // CALL 0 forever
+            (&[encode(OP_CALL, 0, 0, 0), halt], TrapKind::StackOverflow),
+            // PUSH r0 forever
+            (&[encode(OP_PUSH, 0, 0, 0), encode(OP_JMPNZ, 1, 0, 0), halt], TrapKind::StackOverflow),
+            (&[ret], TrapKind::StackUnderflow),
+            (&[encode(OP_POP, 0, 0, 0), halt], TrapKind::StackUnderflow),
+            (&[encode(OP_LOAD, 0, 2, 0), halt], TrapKind::MemoryOutOfBounds),
+            (&[encode(OP_STORE, 0, 2, 0), halt], TrapKind::MemoryOutOfBounds),
+        ];
+        for &(code, trap) in cases {
+            // r1 keeps the PUSH loop going, r2 is an address past the end of memory
+            let (result, rec) = hooked(code, &[0, 1, DEFAULT_MEMORY_WORDS as i64]);
+            assert_eq!(result, -1, "{trap:?}");
+            let &(pc, op, got) = rec.traps.last().unwrap();
+            assert_eq!((rec.traps.len(), got, op), (1, trap, (code[pc] & 0xFF) as u8));
+        }
+    }
+
+    #[test]
+    fn falling_off_the_end_does_what_the_program_says() {
+        // r0 += 1, then a JMPNZ on r1 that isn't taken: the next pc is past the end
+        let program = Program::new(vec![encode(OP_INC, 0, 0, 0), encode(OP_JMPNZ, 1, 0, 0)]).unwrap();
+        assert_eq!(program.clone().verify().unwrap_err(), VerifyError::FallsOffEnd { last_pc: 1 });
+
+        for (policy, expected) in [(FallOffEnd::Halt, 41), (FallOffEnd::Trap, -1)] {
+            let mut program = program.clone();
+            program.set_fall_off_end(policy);
+            let plain = program.clone().verify().unwrap();
+            let fused = program.fused().verify().unwrap();
+            for program in [&plain, &fused] {
+                for v in VARIANTS {
+                    assert_eq!(v.exec(program, &[40, 0]).unwrap(), expected, "{policy:?}, {}", v.name);
+                }
+                // the implicit HALT or trap is one more instruction, in the stepper too
+                let mut count = CountHook::default();
+                assert_eq!(run_hooked(program, &[40, 0], &mut count), expected);
+                let mut vm = Vm::new(program.clone());
+                vm.reset(&[40, 0]).unwrap();
+                assert_eq!(vm.resume(100), Status::Halted(expected), "{policy:?}");
+                assert_eq!((vm.pc(), vm.retired()), (2, 3));
+            }
+            let mut rec = Recorder::default();
+            run_hooked(&plain, &[40, 0], &mut rec);
+            let traps = if policy == FallOffEnd::Trap { vec![(2, OP_PAST_END, TrapKind::FellOffEnd)] } else { vec![] };
+            assert_eq!(rec.traps, traps);
+        }
+
+        // a subroutine called from the host falls off the end the same way
+        let mut program = Program::new(vec![encode(OP_HALT, 0, 0, 0), encode(OP_INC, 0, 0, 0)]).unwrap();
+        program.set_name(1, "bump").unwrap();
+        program.set_fall_off_end(FallOffEnd::Halt);
+        let mut vm = Vm::new(program.verify().unwrap());
+        assert_eq!(vm.call("bump", &[6]), Ok(7));
+    }
+
+    #[test]
+    fn waitevent_suspends_until_posted() {
+        // sums events until a 0 comes in
+        let code = [
+            encode(OP_LOADI, 1, 0, 0),
+            encode(OP_WAITEVENT, 2, 0, 0), // pc = 1
+            encode(OP_JEQ, 2, 5, 0),
+            encode(OP_ADD, 1, 1, 2),
+            encode(OP_JMP, 0, 1, 0),
+            encode(OP_HALT, 1, 0, 0), // pc = 5
+        ];
+        let program = verify(&code).unwrap();
+        assert_eq!(program.program().features(), ["events"]);
+        // nobody posts anything in a run-to-completion variant
+        for v in VARIANTS {
+            assert_eq!(v.exec(&program, &[]).unwrap(), -1, "{}", v.name);
+        }
+        let (_, rec) = hooked(&code, &[]);
+        assert_eq!(rec.traps, [(1, OP_WAITEVENT, TrapKind::NoEvent)]);
+
+        let mut vm = Vm::new(program);
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Waiting);
+        assert!(vm.waiting());
+        // waiting doesn't spend fuel
+        assert_eq!(vm.resume(0), Status::Waiting);
+        vm.post_event(5);
+        vm.post_event(7);
+        assert!(!vm.waiting());
+        assert_eq!(vm.resume(1000), Status::Waiting);
+        assert_eq!((vm.pc(), vm.regs()[1]), (1, 12));
+        vm.post_event(3);
+        assert_eq!(vm.resume(2), Status::OutOfFuel);
+        vm.post_event(0);
+        assert_eq!(vm.resume(1000), Status::Halted(15));
+        // reset drops whatever wasn't taken
+        vm.reset(&[]).unwrap();
+        vm.post_event(9);
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Waiting);
+    }
+
+    #[test]
+    fn trap_calls_the_vm_handler() {
+        // r1 = r0 * 2 with a TRAP 1 to log r1 and a TRAP 2 asserting it's under 100
+        let code = [
+            encode(OP_LOADI, 2, 2, 0),
+            encode(OP_MUL, 1, 0, 2),
+            encode(OP_TRAP, 0, 1, 0), // pc = 2
+            encode(OP_TRAP, 0, 2, 0),
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        let program = verify(&code).unwrap();
+        assert_eq!(program.program().features(), ["traps"]);
+        // nobody to call outside a stepping Vm
+        for v in VARIANTS {
+            assert_eq!(v.exec(&program, &[21]).unwrap(), -1, "{}", v.name);
+        }
+        let (_, rec) = hooked(&code, &[21]);
+        assert_eq!(rec.traps, [(2, OP_TRAP, TrapKind::Unhandled)]);
+        let mut vm = Vm::new(program.clone());
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Halted(-1));
+
+        let (log, logged) = std::sync::mpsc::channel();
+        vm.set_trap_handler(move |ctx| match ctx.imm() {
+            1 => {
+                log.send((ctx.pc(), ctx.args::<2>()[1], ctx.retired())).unwrap();
+                TrapAction::Continue
+            }
+            _ if ctx.reg(1) < Some(100) => TrapAction::Continue,
+            _ => TrapAction::Halt(-1),
+        });
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Halted(42));
+        assert_eq!(vm.retired(), 5);
+        vm.reset(&[50]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Halted(-1));
+        assert_eq!(logged.try_iter().collect::<Vec<_>>(), [(2, 42, 2), (2, 100, 2)]);
+
+        // the handler can change what it sees, through the accessors
+        vm.set_trap_handler(|ctx| {
+            let [r0, r1] = ctx.args();
+            ctx.set_results([r0, r1 + ctx.imm() as i64]);
+            assert!(!ctx.set_reg(NREGS, 0) && ctx.reg(NREGS).is_none());
+            TrapAction::Continue
+        });
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.resume(1000), Status::Halted(45));
+    }
+
+    #[test]
+    fn host_functions_return_several_registers_and_respect_read_only_memory() {
+        // TRAP 1 is divmod(arg0, arg1) into r0, r1. TRAP 2 stores arg0 at mem[arg1]
+        let code = [
+            encode(OP_TRAP, 0, 1, 0),
+            encode(OP_MUL, 2, 0, 1),
+            encode(OP_LOADI, 1, 10, 0),
+            encode(OP_TRAP, 0, 2, 0),
+            encode(OP_LOAD, 3, 1, 0),
+            encode(OP_ADD, 2, 2, 3),
+            encode(OP_HALT, 2, 0, 0),
+        ];
+        let mut program = Program::new(code.to_vec()).unwrap();
+        program.set_memory_words(16).unwrap();
+        program.add_segment(12, vec![5], true).unwrap();
+        let mut vm = Vm::new(program.verify().unwrap());
+        let (seen, stored) = std::sync::mpsc::channel();
+        vm.set_trap_handler(move |ctx| {
+            let [x, y] = ctx.args();
+            match ctx.imm() {
+                1 => ctx.set_results([x / y, x % y]),
+                _ => seen.send(ctx.store(y as usize, x).map(|()| ctx.load(y as usize))).unwrap(),
+            }
+            TrapAction::Continue
+        });
+        // 47 / 5 = 9 r 2, 9 * 2 = 18, then 9 stored at 10 and loaded back
+        vm.reset(&[47, 5]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(27));
+        assert_eq!(stored.try_recv().unwrap(), Ok(Ok(9)));
+        // no store into the read-only segment at 12, or past the end
+        vm.patch(2, encode(OP_LOADI, 1, 12, 0)).unwrap();
+        vm.reset(&[47, 5]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(18 + 5));
+        assert_eq!(stored.try_recv().unwrap(), Err(TrapKind::WriteProtected));
+        vm.patch(2, encode(OP_LOADI, 1, 0xFF, 0xFF)).unwrap();
+        vm.reset(&[47, 5]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        assert_eq!(stored.try_recv().unwrap(), Err(TrapKind::MemoryOutOfBounds));
+        // what a handler stores gets undone by the next reset like a guest STORE
+        vm.patch(4, encode(OP_HALT, 0, 0, 0)).unwrap();
+        vm.patch(2, encode(OP_LOADI, 1, 10, 0)).unwrap();
+        vm.reset(&[47, 5]).unwrap();
+        vm.resume(100);
+        vm.reset(&[0, 5]).unwrap();
+        assert_eq!(vm.memory()[10], 0);
+    }
+
+    #[test]
+    fn traps_inside_calls_come_with_a_backtrace() {
+        let src = "\
+start:  loadi r1, 3
+        call  outer
+        halt  r1
+outer:  inc   r1
+        call  inner
+        ret
+inner:  pop   r2        ; nothing was pushed
+        ret";
+        let mut vm = Vm::new(asm::assemble(src).unwrap().verify().unwrap());
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        let trap = vm.trap().unwrap();
+        assert_eq!((trap.kind(), trap.pc()), (TrapKind::StackUnderflow, 6));
+        let frames: Vec<_> = trap.backtrace().iter().map(|f| (f.pc, f.line)).collect();
+        assert_eq!(frames, [(6, Some(7)), (4, Some(5)), (1, Some(2))]);
+        assert_eq!(
+            trap.to_string(),
+            "StackUnderflow at inner (pc 6, line 7)\n  called from outer+1 (pc 4, line 5)\n  \
+             called from start+1 (pc 1, line 2)"
+        );
+        // gone with the next reset, and a HALT isn't a trap
+        vm.patch(6, encode(OP_PUSH, 1, 0, 0)).unwrap();
+        vm.reset(&[]).unwrap();
+        assert!(vm.trap().is_none());
+        assert_eq!(vm.resume(100), Status::Halted(4));
+        assert!(vm.trap().is_none());
+        // no names, no lines: just the pcs
+        let code =
+            [encode(OP_CALL, 0, 2, 0), encode(OP_HALT, 0, 0, 0), encode(OP_STORE, 0, 1, 0), encode(OP_RET, 0, 0, 0)];
+        let mut program = Program::new(code.to_vec()).unwrap();
+        program.set_memory_words(1).unwrap();
+        let mut vm = Vm::new(program.verify().unwrap());
+        vm.reset(&[0, 5]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        assert_eq!(vm.trap().unwrap().to_string(), "MemoryOutOfBounds at pc 2\n  called from pc 0");
+    }
+
+    #[test]
+    fn a_guest_handler_recovers_from_divide_by_zero() {
+        let src = "\
+start:  div   r2, r0, r1
+        halt  r2
+on_div: pop   r3        ; the kind's code
+        loadi r2, 99
+        ret";
+        let program = asm::assemble(src).unwrap();
+        let on_div = program.pc_of("on_div");
+        let mut vm = Vm::new(program.verify().unwrap());
+        // no vector, the old rule: x / 0 is 0
+        vm.reset(&[10, 0]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(0));
+        vm.set_trap_vector(TrapKind::DivByZero, on_div).unwrap();
+        vm.reset(&[10, 0]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(99));
+        assert_eq!(vm.regs()[3], TrapKind::DivByZero.code());
+        assert!(vm.trap().is_none() && vm.state().calls().is_empty());
+        vm.reset(&[10, 2]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(5));
+
+        // other kinds go the same way, a POP on an empty stack lands in the handler too
+        vm.patch(0, encode(OP_POP, 2, 0, 0)).unwrap();
+        vm.set_trap_vector(TrapKind::StackUnderflow, on_div).unwrap();
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(99));
+        assert_eq!(vm.regs()[3], TrapKind::StackUnderflow.code());
+
+        assert_eq!(
+            vm.set_trap_vector(TrapKind::DivByZero, Some(5)),
+            Err(VmError::BadTrapVector { kind: TrapKind::DivByZero, pc: 5 })
+        );
+        assert!(vm.set_trap_vector(TrapKind::FellOffEnd, Some(0)).is_err());
+        vm.set_trap_vector(TrapKind::DivByZero, None).unwrap();
+        vm.patch(0, encode(OP_DIV, 2, 0, 1)).unwrap();
+        vm.reset(&[10, 0]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(0));
+    }
+
+    #[test]
+    fn trap_breakpoints_by_substitution() {
+        // like breakpoints_by_substitution, with a TRAP over sum-poly's DEC that breaks
+        // and the handler keeping count
+        let mut vm = Vm::new(verify(&kernels::sum_poly()).unwrap());
+        vm.reset(&[100]).unwrap();
+        let hits = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let seen = hits.clone();
+        vm.set_trap_handler(move |_| {
+            seen.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            TrapAction::Break
+        });
+        let dec = vm.patch(7, encode(OP_TRAP, 0, 0x34, 0x12)).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Break(0x1234));
+        assert_eq!((vm.pc(), vm.regs()[1], vm.retired()), (7, 9901, 7));
+        // still on it: stepping asks the handler again
+        assert_eq!(vm.step(), None);
+        assert_eq!(vm.pc(), 7);
+        vm.patch(7, dec).unwrap();
+        assert_eq!(vm.step(), None);
+        vm.patch(7, encode(OP_TRAP, 0, 0x34, 0x12)).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Break(0x1234));
+        assert_eq!((vm.pc(), vm.regs()[1]), (7, 9901 + 9703));
+        vm.patch(7, dec).unwrap();
+        assert_eq!(vm.resume(u64::MAX), Status::Halted(333_400));
+        assert_eq!(hits.load(std::sync::atomic::Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn resolve_call_binds_once_and_patches_itself_into_a_trap() {
+        // RESOLVE_CALL 7 for "double r0", which the resolver says is host function 2
+        let code = [encode(OP_RESOLVE_CALL, 0, 7, 0), encode(OP_HALT, 0, 0, 0)];
+        let program = verify(&code).unwrap();
+        assert_eq!(program.program().features(), ["traps"]);
+        for v in VARIANTS {
+            assert_eq!(v.exec(&program, &[21]).unwrap(), -1, "{}", v.name);
+        }
+        // nobody to ask, then a resolver that doesn't know 7: -1 and nothing patched
+        let mut vm = Vm::new(program);
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        assert_eq!(vm.trap().map(|t| (t.kind(), t.pc())), Some((TrapKind::Unresolved, 0)));
+        vm.set_resolver(|_| None);
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.resume(100), Status::Halted(-1));
+        assert_eq!(vm.program().code()[0], code[0]);
+
+        let asked = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let counter = asked.clone();
+        vm.set_resolver(move |name| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            (name == 7).then_some(2)
+        });
+        vm.set_trap_handler(|ctx| {
+            assert_eq!(ctx.imm(), 2);
+            let [x] = ctx.args();
+            ctx.set_results([x * 2]);
+            TrapAction::Continue
+        });
+        // resolving and the call it makes are the one step
+        vm.reset(&[21]).unwrap();
+        assert_eq!(vm.step(), None);
+        assert_eq!((vm.pc(), vm.regs()[0], vm.retired()), (1, 42, 1));
+        assert_eq!(vm.program().code()[0], encode(OP_TRAP, 0, 2, 0));
+        assert_eq!(vm.step(), Some(42));
+        // later runs take the TRAP without asking again
+        for x in [5, 100] {
+            vm.reset(&[x]).unwrap();
+            assert_eq!(vm.resume(100), Status::Halted(x * 2));
+        }
+        assert_eq!(asked.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn features_are_inferred() {
+        for (name, features) in [("fib", &["calls"][..]), ("sieve", &["memory"]), ("sum-poly", &[])] {
+            let (code, _) = kernels::kernel(name, 10).unwrap();
+            assert_eq!(Program::new(code).unwrap().features(), features, "{name}");
+        }
+        // memory only gets allocated for programs that have the feature
+        let halt = Instruction::new(Opcode::Halt, 0, 0, 0).encode();
+        let mut program = Program::new(vec![halt]).unwrap();
+        assert_eq!(program.clone().verify().unwrap().memory_words(), 0);
+        assert_eq!(program.require_feature("vectors").unwrap_err(), ProgramError::UnsupportedFeature("vectors".into()));
+        program.require_feature("memory").unwrap();
+        program.require_feature("memory").unwrap();
+        assert_eq!(program.features(), ["memory"]);
+        assert_eq!(program.verify().unwrap().memory_words(), DEFAULT_MEMORY_WORDS);
+    }
+
+    #[test]
+    fn entry_and_targets_at_the_16_bit_edge() {
+        let halt = Instruction::new(Opcode::Halt, 0, 0, 0).encode();
+        let top = u16::MAX as usize - 2;
+        // r0 += 1, then a jump to the last pc, whose shifted target is exactly u16::MAX
+        let mut code = vec![halt; top + 1];
+        code[top - 2] = Instruction::new(Opcode::Inc, 0, 0, 0).encode();
+        code[top - 1] = Instruction::with_imm(Opcode::Jmpnz, 0, top as u16).encode();
+        let mut program = Program::new(code.clone()).unwrap();
+        for (entry, expected) in [(top - 2, 42), (top, 41)] {
+            program.set_entry(entry).unwrap();
+            for &kind in DispatchKind::ALL {
+                // 64K instructions are way past what byte offsets can address
+                match Vm::with_dispatch(program.clone().verify().unwrap(), kind) {
+                    Ok(vm) => assert_eq!(vm.run(&[41]).unwrap(), expected, "{kind:?}"),
+                    Err(e) => assert!(matches!((e, kind.variant().run), (VmError::TooLarge(_), Runner::Bytes(_)))),
+                }
+            }
+            let mut vm = Vm::new(program.clone().verify().unwrap());
+            vm.reset(&[41]).unwrap();
+            let result = loop {
+                if let Some(r) = vm.step() {
+                    break r;
+                }
+            };
+            assert_eq!(result, expected);
+        }
+
+        // one further and the shift would wrap, so they're refused instead
+        code.push(halt);
+        let mut program = Program::new(code.clone()).unwrap();
+        assert_eq!(program.set_entry(top + 1).unwrap_err(), ProgramError::EntryTooFar { entry: top + 1 });
+        code[top - 1] = Instruction::with_imm(Opcode::Jmpnz, 0, top as u16 + 1).encode();
+        let mut program = Program::new(code).unwrap();
+        let err = ProgramError::ShiftedTargetTooFar { pc: top - 1, target: top + 1 };
+        assert_eq!(program.set_entry(1).unwrap_err(), err);
+        assert_eq!(program.entry(), 0);
+    }
+}
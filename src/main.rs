@@ -1,347 +1,1571 @@
-// Central loop versus a Duplicated-Match Dispatch
+// Benchmark driver over the rust_goto library (src/lib.rs): times every dispatch
+// variant on the same program, plus the experiment runner, external competitors
+// and plugins
 
-// Made by VBXQ (Haydar)(Celeste) - 2026
+#![cfg_attr(feature = "safe-only", forbid(unsafe_code))]
 
-// Here's the question: 
-// 
-// If every opcode handler ends with its own copy of the full
-// dispatch match, will LLVM merge/inline them into computed-goto-style
-// threaded dispatch? 
-// 
-// Or does it just bloat code with redundant matches?
+#[cfg(all(feature = "safe-only", feature = "perf"))]
+compile_error!("the perf counters are opened through raw syscalls, they can't be part of a safe-only build");
 
-// This is my try on optimizing virtual machine/interpreters written in Rust
-// Made a really simple VM here just showcase it 
-
-// TLDR;- it works ! 
+mod autotune;
+mod cold;
+mod criterion;
+mod depth;
+mod determinism;
+mod experiment;
+mod external;
+mod footprint;
+mod instantiate;
+mod layout;
+#[cfg(feature = "perf")]
+mod perf;
+mod profile;
+mod report;
 
 use std::hint::black_box;
-use std::time::Instant;
-
-const OP_HALT: u8 = 0;
-const OP_LOADI: u8 = 1;
-const OP_ADD: u8 = 2;
-const OP_SUB: u8 = 3;
-const OP_MUL: u8 = 4;
-const OP_DIV: u8 = 5;
-const OP_MOD: u8 = 6;
-const OP_INC: u8 = 7;
-const OP_DEC: u8 = 8;
-const OP_JMPNZ: u8 = 9;
-const OP_MOV: u8 = 10;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-#[inline(always)]
-fn encode(op: u8, dst: u8, a: u8, b: u8) -> u32 {
-    (op as u32) | ((dst as u32) << 8) | ((a as u32) << 16) | ((b as u32) << 24)
-}
+use rust_goto::analyze;
+use rust_goto::blocks::{LazyBlocks, run_blocks};
+use rust_goto::checkpoint::Checkpoint;
+use rust_goto::stack_machine::{self, StackProgram};
+use rust_goto::structured::{self, StructuredProgram};
+use rust_goto::{
+    CountHook, NREGS, RunFn, Runner, THREADED_DEPTHS, UNAVAILABLE_VARIANTS, VARIANTS, Variant, VerifiedDecoded,
+    VerifiedProgram, VmConfig, asm, bytes, checkpoint, expr, fuzz, kernels, run_central, run_hooked, run_predecoded,
+    run_stream, run_threaded, to_bundles, to_decoded, to_loop_bodies, verify,
+};
+#[cfg(feature = "plugins")]
+use rust_goto::plugin;
 
-#[inline(always)]
-fn imm16(a: u8, b: u8) -> i64 {
-    ((a as u16) | ((b as u16) << 8)) as i64
-}
-
-const NREGS: usize = 16;
-
-// execute one opcode, mutating regs/pc, and returns Some(val) on halt, it's shared by both versions so the actual computation is identiacal
-macro_rules! exec_one {
-    ($code:expr, $regs:expr, $pc:expr) => {{
-        let instr = *unsafe { $code.get_unchecked($pc) };
-        let op = (instr & 0xFF) as u8;
-        let dst = ((instr >> 8) & 0xFF) as usize;
-        let a = ((instr >> 16) & 0xFF) as u8;
-        let b = ((instr >> 24) & 0xFF) as u8;
-        $pc += 1;
-        (op, dst, a, b)
-    }};
-}
-
-// macro that does the work for one decoded instruction., Some(val) on Halt, and None otherwise
-macro_rules! handle {
-    ($regs:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr) => {
-        match $op {
-            OP_HALT => return $regs[$dst],
-            OP_LOADI => { $regs[$dst] = imm16($a, $b); }
-            OP_ADD => { $regs[$dst] = $regs[$a as usize].wrapping_add($regs[$b as usize]); }
-            OP_SUB => { $regs[$dst] = $regs[$a as usize].wrapping_sub($regs[$b as usize]); }
-            OP_MUL => { $regs[$dst] = $regs[$a as usize].wrapping_mul($regs[$b as usize]); }
-            OP_DIV => {
-                let d = $regs[$b as usize];
-                $regs[$dst] = if d != 0 { $regs[$a as usize] / d } else { 0 };
-            }
-            OP_MOD => {
-                let d = $regs[$b as usize];
-                $regs[$dst] = if d != 0 { $regs[$a as usize] % d } else { 0 };
-            }
-            OP_INC => { $regs[$dst] = $regs[$dst].wrapping_add(1); }
-            OP_DEC => { $regs[$dst] = $regs[$dst].wrapping_sub(1); }
-            OP_JMPNZ => {
-                if $regs[$dst] != 0 { $pc = imm16($a, $b) as usize; }
-            }
-            OP_MOV => { $regs[$dst] = $regs[$a as usize]; }
-            _ => return -1,
+use report::{Baseline, Format, Row, SAMPLES, Stats};
+
+// the human-readable report goes to stdout, unless --output json|csv has it
+macro_rules! say {
+    ($output:expr) => {
+        say!($output, "")
+    };
+    ($output:expr, $($arg:tt)*) => {
+        if $output == Format::Text {
+            println!($($arg)*)
+        } else {
+            eprintln!($($arg)*)
         }
     };
 }
 
-// here's our test program, it just computes :
+// le benchmark
 //
-// sum = 0;
-// for i in (0..N) {
-//    sum += i*i - i + 1
-// }
+// instead of a fixed iteration count we give every variant the same time budget:
+// time doubling batches until one takes long enough to trust the clock, derive
+// ns/iter from it, then size the real run so it lands around `budget`
+// this way a huge program doesn't take forever and a tiny one still gets plenty of samples
+// the real run is SAMPLES batches, one sample of ns/iter each, see src/report.rs
 //
+// `--iters N` asks for the fixed count after all, for CI jobs that want the same work
+// done on every run whatever the machine: N iterations, split over the SAMPLES batches
+const CALIBRATION_MIN: Duration = Duration::from_millis(20);
 
-fn make_program(n: u16) -> Vec<u32> {
-    let nh = (n & 0xFF) as u8;
-    let nl = ((n >> 8) & 0xFF) as u8;
-    vec![
-        encode(OP_LOADI, 0, nh, nl),  // r0 = N
-        encode(OP_LOADI, 1, 0, 0),    // r1 = 0 (le accumulator)
-        encode(OP_LOADI, 2, 1, 0),    // r2 = 1
-        // loop: (pc = 3)
-        encode(OP_MOV, 3, 0, 0),      // r3 = r0
-        encode(OP_MUL, 4, 3, 3),      // r4 = r3*r3
-        encode(OP_SUB, 5, 4, 3),      // r5 = r4 - r3
-        encode(OP_ADD, 5, 5, 2),      // r5 = r5 + 1
-        encode(OP_ADD, 1, 1, 5),      // r1 += r5
-        encode(OP_DEC, 0, 0, 0),      // r0--
-        encode(OP_JMPNZ, 0, 3, 0),   // if r0 != 0 goto 3
-
-        encode(OP_HALT, 1, 0, 0),     // return r1
-    ]
+// per variant in the default benchmark, and per cell in the tables that time every
+// variant several ways
+const DEFAULT_BUDGET: Duration = Duration::from_secs(2);
+const CELL_BUDGET: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Budget {
+    Time(Duration),
+    Iters(u64),
 }
 
+impl Budget {
+    // iterations per sample, `ns_per_iter` is only asked for (and only warms up) when
+    // they have to fit in a time budget
+    fn per_sample(self, ns_per_iter: impl FnOnce() -> f64) -> u64 {
+        match self {
+            Budget::Time(time) => ((time.as_nanos() as f64 / ns_per_iter()) as u64 / SAMPLES).max(1),
+            Budget::Iters(n) => n.div_ceil(SAMPLES),
+        }
+    }
+}
 
-//////////////////////////////////////////////////////
-// VERSION A : Classic dispatch loop
-//////////////////////////////////////////////////////
-// one decode+math per iteration, all arms jump back to loop head!
-#[inline(never)]
-fn run_central(code: &[u32]) -> i64 {
-    let mut regs = [0i64; NREGS];
-    let mut pc: usize = 0;
+impl std::fmt::Display for Budget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Budget::Time(time) => write!(f, "{}ms budget", time.as_millis()),
+            Budget::Iters(n) => write!(f, "{n} iterations"),
+        }
+    }
+}
+
+// where the timing loop puts black_box. normally everything is boxed: the code slice
+// and the arguments (so LLVM can't specialize the interpreter on a known program or
+// seed) and the result (so the call can't be dropped). the other placements exist to
+// show how sensitive the numbers are to that, see `--black-box-matrix`
+#[derive(Clone, Copy, PartialEq)]
+enum BlackBox {
+    Nothing,
+    Code,
+    Args,
+    Result,
+    All,
+}
+
+const BLACK_BOX_PLACEMENTS: &[(BlackBox, &str)] = &[
+    (BlackBox::Nothing, "none"),
+    (BlackBox::Code, "code"),
+    (BlackBox::Args, "args"),
+    (BlackBox::Result, "result"),
+    (BlackBox::All, "all"),
+];
+
+// the placement is a const parameter so that "not boxed" really is visible to the
+// optimizer, a runtime flag would hide it just as well as black_box does
+#[inline(always)]
+fn run_batch<C, F, const CODE: bool, const ARGS: bool, const RESULT: bool>(code: &C, args: &[i64], n: u64, f: &F)
+where
+    C: ?Sized,
+    F: Fn(&C, &[i64]) -> i64,
+{
+    for _ in 0..n {
+        let c = if CODE { black_box(code) } else { code };
+        let a = if ARGS { black_box(args) } else { args };
+        let r = f(c, a);
+        if RESULT {
+            black_box(r);
+        }
+    }
+}
+
+fn timed_batch<C: ?Sized, F: Fn(&C, &[i64]) -> i64>(code: &C, args: &[i64], n: u64, f: &F, bb: BlackBox) -> Duration {
+    let start = Instant::now();
+    match bb {
+        BlackBox::Nothing => run_batch::<C, F, false, false, false>(code, args, n, f),
+        BlackBox::Code => run_batch::<C, F, true, false, false>(code, args, n, f),
+        BlackBox::Args => run_batch::<C, F, false, true, false>(code, args, n, f),
+        BlackBox::Result => run_batch::<C, F, false, false, true>(code, args, n, f),
+        BlackBox::All => run_batch::<C, F, true, true, true>(code, args, n, f),
+    }
+    start.elapsed()
+}
 
+fn calibrate<C: ?Sized, F: Fn(&C, &[i64]) -> i64>(code: &C, args: &[i64], f: &F, bb: BlackBox) -> f64 {
+    let mut batch: u64 = 1;
     loop {
-        let (op, dst, a, b) = exec_one!(code, regs, pc);
-        handle!(regs, pc, op, dst, a, b);
+        let elapsed = timed_batch(code, args, batch, f, bb);
+        if elapsed >= CALIBRATION_MIN {
+            return elapsed.as_nanos() as f64 / batch as f64;
+        }
+        batch *= 2;
     }
 }
 
-//////////////////////////////////////////////////////
-// VERSION B : Duplicated match at tail of every handler
-//////////////////////////////////////////////////////
-// here's my strategy : each match arms executes the handler, then inline decodes the next instruction, and dispatches it
-// through a second inner match, the inner match arms do their work and continue the outer loop
+struct Measurement {
+    // the median sample
+    ns_per_iter: f64,
+    iters: u64,
+    result: i64,
+    stats: Stats,
+    // ns/iter of every batch, in the order they ran
+    samples: Vec<f64>,
+}
 
-// so, this gives LLVM 11 copieis of the dispatch table, one at the tail of each handler
-// if LLVM threads the dispatch, each of those 11 copies becomes an indirect branch! so, computed goto
-// if LLVM tail merges them, they collapse into one so same as version A
+impl Measurement {
+    fn from_samples(samples: &[f64], iters: u64, result: i64) -> Measurement {
+        let stats = Stats::of(samples);
+        Measurement { ns_per_iter: stats.median, iters, result, stats, samples: samples.to_vec() }
+    }
 
-// the outer loop here is only needed as a "safety net", in a fully threaded execution the contiinue at the bottom
-// of the inner match keeps bouncing through outer => handler => inner dispatch => handler and so on
-#[inline(never)]
-fn run_threaded(code: &[u32]) -> i64 {
-    let mut regs = [0i64; NREGS];
-    let mut pc: usize = 0;
+    // the benchmark line
+    fn line(&self, label: &str) -> String {
+        let Measurement { ns_per_iter, iters, result, stats, .. } = self;
+        let outliers = match stats.outliers {
+            0 => String::new(),
+            n => format!(", {n} outlier(s)"),
+        };
+        format!(
+            "{label:>24}: {ns_per_iter:8.1} ns/iter ±{:6.1}  (result = {result}, {iters} iters{outliers})",
+            stats.stddev
+        )
+    }
 
-    loop {
-        let (op, dst, a, b) = exec_one!(code, regs, pc);
-        match op {
-            OP_HALT => return regs[dst],
-            OP_LOADI => {
-                regs[dst] = imm16(a, b);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+    fn row(&self, label: &str) -> Row {
+        let (label, samples) = (label.to_string(), self.samples.clone());
+        Row { label, result: self.result, iters: self.iters, stats: self.stats, samples }
+    }
+}
+
+fn measure_placed<C, F>(code: &C, args: &[i64], budget: Budget, f: F, bb: BlackBox) -> Measurement
+where
+    C: ?Sized,
+    F: Fn(&C, &[i64]) -> i64,
+{
+    // calibrating doubles as warmup
+    let per_sample = budget.per_sample(|| calibrate(code, args, &f, bb));
+
+    let samples: Vec<f64> = (0..SAMPLES)
+        .map(|_| timed_batch(code, args, per_sample, &f, bb).as_nanos() as f64 / per_sample as f64)
+        .collect();
+
+    let result = f(code, args);
+    Measurement::from_samples(&samples, per_sample * SAMPLES, result)
+}
+
+// translation happens up front, only execution is timed
+//
+// the function pointer goes through black_box: otherwise LLVM sees which variant each
+// call site measures and clones the whole timing loop per variant with a direct call
+// inside, so every variant gets its own harness (with its own alignment and its own
+// inlining decisions) and we'd partly be comparing those. this way there's one
+// harness and an indirect call, `cargo xtask verify-threading` checks for direct calls
+//
+// Err when the variant can't hold the program at all (the byte variants, past 64 KiB
+// of bytecode), callers skip it and say so
+fn measure_variant(
+    v: &Variant,
+    program: &VerifiedProgram,
+    args: &[i64],
+    budget: Budget,
+    bb: BlackBox,
+) -> Result<Measurement, bytes::TooLarge> {
+    Ok(match v.run {
+        Runner::Words(f) => measure_placed(program, args, budget, black_box(f), bb),
+        Runner::Bytes(f) => measure_placed(&bytes::to_bytes(program)?, args, budget, black_box(f), bb),
+        Runner::Bundles(f) => measure_placed(&to_bundles(program), args, budget, black_box(f), bb),
+        Runner::Decoded(f) => measure_placed(&to_decoded(program), args, budget, black_box(f), bb),
+        Runner::Loops(f) => measure_placed(&to_loop_bodies(program), args, budget, black_box(f), bb),
+        #[cfg(feature = "jit")]
+        Runner::Native(f) => measure_placed(&rust_goto::jit::compile(program), args, budget, black_box(f), bb),
+    })
+}
+
+#[cfg(feature = "perf")]
+type PerfCounters = perf::Counters;
+#[cfg(not(feature = "perf"))]
+type PerfCounters = std::convert::Infallible;
+
+// what it printed, None if it was skipped. with counters, a second line with what
+// they saw per interpreted instruction over a twentieth of the timed runs
+fn bench(
+    v: &Variant,
+    program: &VerifiedProgram,
+    args: &[i64],
+    budget: Budget,
+    counters: Option<&PerfCounters>,
+    output: Format,
+) -> Option<Measurement> {
+    match measure_variant(v, program, args, budget, BlackBox::All) {
+        Ok(m) => {
+            say!(output, "{}", m.line(v.label));
+            #[cfg(feature = "perf")]
+            if let Some(counters) = counters {
+                say!(output, "{}", perf::report(counters, v, program, args, m.iters / 20));
             }
-            OP_ADD => {
-                regs[dst] = regs[a as usize].wrapping_add(regs[b as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            #[cfg(not(feature = "perf"))]
+            let _ = counters;
+            Some(m)
+        }
+        Err(e) => {
+            say!(output, "{:>24}: skipped, {e}", v.label);
+            None
+        }
+    }
+}
+
+const USAGE: &str = "usage: rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] [--iters <n>]
+                 [--config <vm.toml>]
+                 [--external <label>=<command>]... [--plugin <library>]...
+                 [--output text|json|csv] [--baseline <file>] [--criterion <name>]
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] [--iters <n>]
+                 --black-box-matrix | --fused
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] --footprint
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--iters <n>] --depth <max>
+       rust-goto [--program <file|kernel>] --instantiate
+       rust-goto [--program <file|kernel> [--args <n,...>]] --state-layout
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] --cold <trials>
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] [--iters <n>] --auto-tune
+       rust-goto [--program <file|kernel> [--args <n,...>]] [--strategy <name,...>] --verify-determinism <runs>
+       rust-goto [--program <file|kernel> [--args <n,...>]] --profile | --trace
+       rust-goto [--program <file|kernel> [--args <n,...>]] --checkpoint-every <n> [--checkpoint-file <path>]
+       rust-goto [--program <file|kernel>] --resume <checkpoint> [--checkpoint-every <n>]
+       rust-goto run-experiment <descriptor.toml>
+       rust-goto compare-isa [<n>]
+       rust-goto compare-stack [<n>]
+       rust-goto compare-blocks [<n>]
+       rust-goto fuzz [<programs> | --soak <minutes>] [--seed <n>] | fuzz --show <seed>
+       rust-goto conformance [<dir>] | conformance --write <dir>
+       rust-goto analyze [<file.s>]
+       rust-goto --list-variants | --list-programs";
+
+// a line of running totals from `fuzz --soak`
+const SOAK_REPORT_EVERY: Duration = Duration::from_secs(60);
+
+// instructions between checkpoints when resuming without --checkpoint-every
+const DEFAULT_CHECKPOINT_EVERY: u64 = 100_000_000;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("run-experiment") {
+        let Some(path) = args.get(1) else {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        };
+        if let Err(e) = experiment::run_file(path) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().map(String::as_str) == Some("--list-variants") {
+        list_variants();
+        return;
+    }
+    if args.first().map(String::as_str) == Some("--list-programs") {
+        list_programs();
+        return;
+    }
+    if args.first().map(String::as_str) == Some("analyze") {
+        if args.len() > 2 {
+            eprintln!("error: analyze takes at most one .s file\n{USAGE}");
+            std::process::exit(2);
+        }
+        if let Err(e) = run_analyze(args.get(1).map(String::as_str)) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().map(String::as_str) == Some("fuzz") {
+        if let Err(e) = run_fuzz(&args[1..]) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().map(String::as_str) == Some("conformance") {
+        if let Err(e) = run_conformance(&args[1..]) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if args.first().map(String::as_str) == Some("compare-isa") {
+        let n = match args.get(1).map(|n| n.parse::<i64>()) {
+            None => 1000,
+            Some(Ok(n)) if n > 0 => n,
+            Some(_) => {
+                eprintln!("error: compare-isa takes a positive iteration count\n{USAGE}");
+                std::process::exit(2);
+            }
+        };
+        compare_isa(n);
+        return;
+    }
+    if args.first().map(String::as_str) == Some("compare-stack") {
+        let n = match args.get(1).map(|n| n.parse::<i64>()) {
+            None => 1000,
+            Some(Ok(n)) if n > 0 => n,
+            Some(_) => {
+                eprintln!("error: compare-stack takes a positive iteration count\n{USAGE}");
+                std::process::exit(2);
             }
-            OP_SUB => {
-                regs[dst] = regs[a as usize].wrapping_sub(regs[b as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+        };
+        compare_stack(n);
+        return;
+    }
+    if args.first().map(String::as_str) == Some("compare-blocks") {
+        let n = match args.get(1).map(|n| n.parse::<i64>()) {
+            None => 100,
+            Some(Ok(n)) if n > 0 => n,
+            Some(_) => {
+                eprintln!("error: compare-blocks takes a positive iteration count\n{USAGE}");
+                std::process::exit(2);
+            }
+        };
+        compare_blocks(n);
+        return;
+    }
+
+    let mut externals = Vec::new();
+    let mut plugins = Vec::new();
+    let mut program_path = None;
+    let mut program_args = None;
+    let mut black_box_matrix = false;
+    let mut footprint = false;
+    let mut fused = false;
+    let mut auto_tune = false;
+    let mut instantiate = false;
+    let mut state_layout = false;
+    // Some(trace) for --profile or --trace
+    let mut profile = None;
+    // Some(runs) for --verify-determinism
+    let mut determinism = None;
+    // Some(max) for --depth
+    let mut depth = None;
+    // Some(trials) for --cold
+    let mut cold = None;
+    let mut strategies = None;
+    let mut config = None;
+    let mut iters = None;
+    let mut output = Format::Text;
+    let mut baseline = None;
+    let mut criterion_baseline = None;
+    let mut checkpoint_every = None;
+    let mut checkpoint_file = None;
+    let mut resume = None;
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        let parsed = match arg.as_str() {
+            // the program files would have sparse opcode numbers nobody else understands
+            "--external" if cfg!(feature = "sparse-opcodes") => {
+                Err("external tools speak the dense opcode numbering, not available with sparse-opcodes".to_string())
             }
-            OP_MUL => {
-                regs[dst] = regs[a as usize].wrapping_mul(regs[b as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--external" => match rest.next() {
+                Some(spec) => external::parse_spec(spec).map(|e| externals.push(e)),
+                None => Err("--external needs a value".to_string()),
+            },
+            "--plugin" => match rest.next() {
+                Some(path) => load_plugin(path).map(|p| plugins.push(p)),
+                None => Err("--plugin needs a value".to_string()),
+            },
+            "--program" => match rest.next() {
+                Some(path) => {
+                    program_path = Some(path.clone());
+                    Ok(())
+                }
+                None => Err("--program needs a value".to_string()),
+            },
+            "--args" => match rest.next() {
+                Some(list) => parse_arg_list(list).map(|a| program_args = Some(a)),
+                None => Err("--args needs a value".to_string()),
+            },
+            "--black-box-matrix" => {
+                black_box_matrix = true;
+                Ok(())
             }
-            OP_DIV => {
-                let d = regs[b as usize];
-                regs[dst] = if d != 0 { regs[a as usize] / d } else { 0 };
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--footprint" => {
+                footprint = true;
+                Ok(())
             }
-            OP_MOD => {
-                let d = regs[b as usize];
-                regs[dst] = if d != 0 { regs[a as usize] % d } else { 0 };
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--fused" => {
+                fused = true;
+                Ok(())
             }
-            OP_INC => {
-                regs[dst] = regs[dst].wrapping_add(1);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--auto-tune" => {
+                auto_tune = true;
+                Ok(())
             }
-            OP_DEC => {
-                regs[dst] = regs[dst].wrapping_sub(1);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--instantiate" => {
+                instantiate = true;
+                Ok(())
             }
-            OP_JMPNZ => {
-                if regs[dst] != 0 { pc = imm16(a, b) as usize; }
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--state-layout" => {
+                state_layout = true;
+                Ok(())
             }
-            OP_MOV => {
-                regs[dst] = regs[a as usize];
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle!(regs, pc, op2, dst2, a2, b2);
+            "--profile" => {
+                profile = Some(profile.unwrap_or(false));
+                Ok(())
             }
-            _ => return -1,
-        }
-    }
-}
-
-//////////////////////////////////////////////////////
-// VERSION C : deeper unrolling, 3 levels of inline dispatch
-//////////////////////////////////////////////////////
-// if 2 level isn't enough for LLVM to see the pattern, we can try 3 levels
-macro_rules! handle_and_dispatch {
-    ($code:expr, $regs:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr) => {
-        match $op {
-            OP_HALT => return $regs[$dst],
-            OP_LOADI => { $regs[$dst] = imm16($a, $b); }
-            OP_ADD => { $regs[$dst] = $regs[$a as usize].wrapping_add($regs[$b as usize]); }
-            OP_SUB => { $regs[$dst] = $regs[$a as usize].wrapping_sub($regs[$b as usize]); }
-            OP_MUL => { $regs[$dst] = $regs[$a as usize].wrapping_mul($regs[$b as usize]); }
-            OP_DIV => {
-                let d = $regs[$b as usize];
-                $regs[$dst] = if d != 0 { $regs[$a as usize] / d } else { 0 };
+            "--trace" => {
+                profile = Some(true);
+                Ok(())
             }
-            OP_MOD => {
-                let d = $regs[$b as usize];
-                $regs[$dst] = if d != 0 { $regs[$a as usize] % d } else { 0 };
+            "--strategy" => match rest.next() {
+                Some(list) => list
+                    .split(',')
+                    .map(|name| experiment::find_variant(name.trim()))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|list| strategies = Some(list)),
+                None => Err("--strategy needs a value".to_string()),
+            },
+            "--config" => match rest.next() {
+                Some(path) => load_config(path).map(|c| config = Some(c)),
+                None => Err("--config needs a value".to_string()),
+            },
+            "--iters" => match rest.next().map(|n| n.parse::<u64>()) {
+                Some(Ok(n)) if n > 0 => {
+                    iters = Some(n);
+                    Ok(())
+                }
+                Some(_) => Err("--iters needs a positive iteration count".to_string()),
+                None => Err("--iters needs a value".to_string()),
+            },
+            "--verify-determinism" => match rest.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(n)) if n >= 2 => {
+                    determinism = Some(n);
+                    Ok(())
+                }
+                Some(_) => Err("--verify-determinism needs a run count of at least 2".to_string()),
+                None => Err("--verify-determinism needs a value".to_string()),
+            },
+            "--depth" => match rest.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(n)) if (1..=THREADED_DEPTHS.len()).contains(&n) => {
+                    depth = Some(n);
+                    Ok(())
+                }
+                Some(_) => {
+                    let more = if cfg!(feature = "deep-dispatch") { "" } else { ", 8 with --features deep-dispatch" };
+                    Err(format!("--depth needs a depth from 1 to {}{more}", THREADED_DEPTHS.len()))
+                }
+                None => Err("--depth needs a value".to_string()),
+            },
+            "--cold" => match rest.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => {
+                    cold = Some(n);
+                    Ok(())
+                }
+                Some(_) => Err("--cold needs a positive trial count".to_string()),
+                None => Err("--cold needs a value".to_string()),
+            },
+            "--output" => match rest.next() {
+                Some(format) => Format::parse(format).map(|f| output = f),
+                None => Err("--output needs a value".to_string()),
+            },
+            "--baseline" => match rest.next() {
+                Some(path) => {
+                    baseline = Some(path.clone());
+                    Ok(())
+                }
+                None => Err("--baseline needs a value".to_string()),
+            },
+            "--criterion" => match rest.next() {
+                Some(name) => criterion::check_baseline_name(name).map(|()| criterion_baseline = Some(name.clone())),
+                None => Err("--criterion needs a baseline name (`new` is what cargo bench writes)".to_string()),
+            },
+            "--checkpoint-every" => match rest.next().map(|n| n.parse::<u64>()) {
+                Some(Ok(n)) if n > 0 => {
+                    checkpoint_every = Some(n);
+                    Ok(())
+                }
+                Some(_) => Err("--checkpoint-every needs a positive instruction count".to_string()),
+                None => Err("--checkpoint-every needs a value".to_string()),
+            },
+            "--checkpoint-file" => match rest.next() {
+                Some(path) => {
+                    checkpoint_file = Some(path.clone());
+                    Ok(())
+                }
+                None => Err("--checkpoint-file needs a value".to_string()),
+            },
+            "--resume" => match rest.next() {
+                Some(path) => {
+                    resume = Some(path.clone());
+                    Ok(())
+                }
+                None => Err("--resume needs a value".to_string()),
+            },
+            other => Err(format!("unknown argument `{other}`")),
+        };
+        if let Err(e) = parsed {
+            eprintln!("error: {e}\n{USAGE}");
+            std::process::exit(2);
+        }
+    }
+
+    let workload = match (program_path, program_args) {
+        (None, None) => Workload::sum_poly(),
+        (None, Some(_)) => {
+            eprintln!("error: --args only goes with --program\n{USAGE}");
+            std::process::exit(2);
+        }
+        (Some(spec), args) => match Workload::from_spec(&spec, args) {
+            Ok(w) => w,
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::exit(1);
             }
-            OP_INC => { $regs[$dst] = $regs[$dst].wrapping_add(1); }
-            OP_DEC => { $regs[$dst] = $regs[$dst].wrapping_sub(1); }
-            OP_JMPNZ => {
-                if $regs[$dst] != 0 { $pc = imm16($a, $b) as usize; }
+        },
+    };
+    // a config's memory, fall_off_end and features apply to whatever program runs, its
+    // strategy stands in for --strategy unless that's given too
+    let workload = match &config {
+        Some(config) => match workload.configured(config) {
+            Ok(w) => w,
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::exit(1);
             }
-            OP_MOV => { $regs[$dst] = $regs[$a as usize]; }
-            _ => return -1,
+        },
+        None => workload,
+    };
+    let strategies = strategies.or_else(|| config.and_then(|c| c.dispatch).map(|k| vec![k.variant()]));
+    // all about the default benchmark's rows
+    let exporting = output != Format::Text || baseline.is_some() || criterion_baseline.is_some();
+    let other_mode = black_box_matrix
+        || footprint
+        || fused
+        || auto_tune
+        || instantiate
+        || state_layout
+        || profile.is_some()
+        || determinism.is_some();
+    if exporting && (other_mode || depth.is_some() || cold.is_some()) {
+        eprintln!("error: --output, --baseline and --criterion only go with the default benchmark\n{USAGE}");
+        std::process::exit(2);
+    }
+    if iters.is_some()
+        && (footprint || instantiate || state_layout || profile.is_some() || determinism.is_some() || cold.is_some())
+    {
+        eprintln!(
+            "error: --iters only goes with the default benchmark, --black-box-matrix, --fused, --auto-tune \
+             and --depth\n{USAGE}"
+        );
+        std::process::exit(2);
+    }
+    if strategies.is_some() && instantiate {
+        eprintln!("error: --instantiate times loading the program, --strategy doesn't go with it\n{USAGE}");
+        std::process::exit(2);
+    }
+    if strategies.is_some() && state_layout {
+        eprintln!("error: --state-layout steps the Vm, --strategy doesn't go with it\n{USAGE}");
+        std::process::exit(2);
+    }
+    if strategies.is_some() && profile.is_some() {
+        eprintln!("error: --profile and --trace run the hooked central loop, --strategy doesn't go with them\n{USAGE}");
+        std::process::exit(2);
+    }
+    if cold.is_some() && (other_mode || depth.is_some()) {
+        eprintln!("error: --cold is a benchmark of its own, it doesn't go with another mode\n{USAGE}");
+        std::process::exit(2);
+    }
+    if depth.is_some() && (other_mode || strategies.is_some()) {
+        eprintln!("error: --depth sweeps the threaded loop by depth on its own, not the variants\n{USAGE}");
+        std::process::exit(2);
+    }
+    let strategies_given = strategies.is_some();
+    let variants: Vec<&Variant> = strategies.unwrap_or_else(|| VARIANTS.iter().collect());
+    let budget = |time| iters.map_or(Budget::Time(time), Budget::Iters);
+    // read before spending a minute on the benchmark, not after
+    let baseline = match baseline.map(|path| Baseline::load(&path)).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(1);
         }
-        // level 3: decode + handle next instruction, then fall through to loop
-        let (op3, dst3, a3, b3) = exec_one!($code, $regs, $pc);
-        handle!($regs, $pc, op3, dst3, a3, b3);
     };
+    let checkpointing = checkpoint_every.is_some() || resume.is_some();
+    if checkpointing {
+        let conflict = if black_box_matrix {
+            Some("--black-box-matrix")
+        } else if footprint {
+            Some("--footprint")
+        } else if fused {
+            Some("--fused")
+        } else if auto_tune {
+            Some("--auto-tune")
+        } else if instantiate {
+            Some("--instantiate")
+        } else if state_layout {
+            Some("--state-layout")
+        } else if profile.is_some() {
+            Some("--profile or --trace")
+        } else if determinism.is_some() {
+            Some("--verify-determinism")
+        } else if depth.is_some() {
+            Some("--depth")
+        } else if cold.is_some() {
+            Some("--cold")
+        } else if strategies_given {
+            Some("--strategy")
+        } else if iters.is_some() {
+            Some("--iters")
+        } else if !externals.is_empty() {
+            Some("--external")
+        } else if !plugins.is_empty() {
+            Some("--plugin")
+        } else if exporting {
+            Some("--output, --baseline or --criterion")
+        } else if resume.is_some() && checkpoint_file.is_some() {
+            Some("--checkpoint-file (a resumed run keeps writing to the file it resumed from)")
+        } else {
+            None
+        };
+        if let Some(flag) = conflict {
+            eprintln!("error: a checkpointed run is a single run, it doesn't go with {flag}\n{USAGE}");
+            std::process::exit(2);
+        }
+        let every = checkpoint_every.unwrap_or(DEFAULT_CHECKPOINT_EVERY);
+        let (path, resuming) = match resume {
+            Some(path) => (path, true),
+            None => (checkpoint_file.unwrap_or_else(|| "rust-goto.checkpoint".to_string()), false),
+        };
+        if let Err(e) = run_checkpointed(&workload, every, &path, resuming) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    } else if checkpoint_file.is_some() {
+        eprintln!("error: --checkpoint-file only goes with --checkpoint-every\n{USAGE}");
+        std::process::exit(2);
+    } else if black_box_matrix {
+        run_black_box_matrix(&workload, &variants, budget(CELL_BUDGET));
+    } else if footprint {
+        footprint::run(&workload, &variants);
+    } else if let Some(trials) = cold {
+        cold::run(&workload, &variants, trials);
+    } else if let Some(max) = depth {
+        depth::run(&workload, max, budget(CELL_BUDGET));
+    } else if fused {
+        run_fused(&workload, &variants, budget(CELL_BUDGET));
+    } else if instantiate {
+        instantiate::run(&workload);
+    } else if state_layout {
+        layout::run(&workload);
+    } else if auto_tune {
+        if let Err(e) = autotune::run(&workload, &variants, budget(DEFAULT_BUDGET)) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    } else if let Some(trace) = profile {
+        profile::run(&workload, trace);
+    } else if let Some(runs) = determinism {
+        if let Err(e) = determinism::run(&workload, &variants, runs) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    } else {
+        let export = Export { output, baseline: baseline.as_ref(), criterion: criterion_baseline.as_deref() };
+        if let Err(e) = run_default(&workload, &variants, budget(DEFAULT_BUDGET), &externals, &plugins, export) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+    }
 }
 
-#[inline(never)]
-fn run_threaded_deep(code: &[u32]) -> i64 {
-    let mut regs = [0i64; NREGS];
-    let mut pc: usize = 0;
+// `--program <kernel>` builds the kernel for about this many iterations, like the
+// default sum_poly run
+const KERNEL_SIZE: i64 = 1000;
 
-    loop {
-        // level 1: decode + dispatch
-        let (op1, dst1, a1, b1) = exec_one!(code, regs, pc);
-        match op1 {
-            OP_HALT => return regs[dst1],
-            OP_LOADI => {
-                regs[dst1] = imm16(a1, b1);
-                // level 2: full inline dispatch
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
-            }
-            OP_ADD => {
-                regs[dst1] = regs[a1 as usize].wrapping_add(regs[b1 as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
-            }
-            OP_SUB => {
-                regs[dst1] = regs[a1 as usize].wrapping_sub(regs[b1 as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
-            }
-            OP_MUL => {
-                regs[dst1] = regs[a1 as usize].wrapping_mul(regs[b1 as usize]);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
-            }
-            OP_DIV => {
-                let d = regs[b1 as usize];
-                regs[dst1] = if d != 0 { regs[a1 as usize] / d } else { 0 };
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
-            }
-            OP_MOD => {
-                let d = regs[b1 as usize];
-                regs[dst1] = if d != 0 { regs[a1 as usize] % d } else { 0 };
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
+// the program every variant runs: sum_poly unless --program says otherwise
+struct Workload {
+    // short, for file names: the kernel or the file stem
+    id: String,
+    description: String,
+    program: VerifiedProgram,
+    args: Vec<i64>,
+}
+
+impl Workload {
+    fn sum_poly() -> Workload {
+        Workload {
+            id: "sum-poly".to_string(),
+            description: "sum(i*i - i + 1) for i in 1..=1000".to_string(),
+            program: verify(&kernels::sum_poly()).expect("sum_poly verifies"),
+            args: vec![1000],
+        }
+    }
+
+    // a kernel by name (see kernels.rs) unless there's a file by that name, then a
+    // program file. `args` replace the ones the kernel comes with
+    fn from_spec(spec: &str, args: Option<Vec<i64>>) -> Result<Workload, String> {
+        if std::path::Path::new(spec).exists() {
+            return Workload::load(spec, args.unwrap_or_default());
+        }
+        let Some((code, kernel_args)) = kernels::kernel(spec, KERNEL_SIZE) else {
+            return Err(format!("`{spec}` is neither a file nor a kernel, see --list-programs"));
+        };
+        let program = verify(&code).map_err(|e| format!("{spec}: {e}"))?;
+        let args = args.unwrap_or(kernel_args);
+        let description = format!("kernel {spec}, args {args:?}");
+        Ok(Workload { id: spec.to_string(), description, program, args })
+    }
+
+    // a .vmasm file (see src/asm.rs), or a .expr one compiled with every register
+    // the allocator has (see src/expr.rs)
+    fn load(path: &str, args: Vec<i64>) -> Result<Workload, String> {
+        let src = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+        let program = if path.ends_with(".expr") {
+            expr::compile(&src, expr::MAX_REGISTERS).map(|c| c.program).map_err(|e| format!("{path}: {e}"))?
+        } else {
+            asm::assemble(&src).map_err(|e| format!("{path}: {e}"))?
+        };
+        let program = program.verify().map_err(|e| format!("{path}: {e}"))?;
+        let description = format!("{path}, args {args:?}");
+        let id = std::path::Path::new(path).file_stem().map_or(path.into(), |s| s.to_string_lossy().into_owned());
+        Ok(Workload { id, description, program, args })
+    }
+
+    // the same program run the way `config` says, see VmConfig::prepare
+    fn configured(self, config: &VmConfig) -> Result<Workload, String> {
+        let program = config.prepare(self.program.program().clone()).map_err(|e| format!("{}: {e}", self.id))?;
+        Ok(Workload { program, ..self })
+    }
+}
+
+fn load_config(path: &str) -> Result<VmConfig, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    VmConfig::from_toml(&text).map_err(|e| format!("{path}: {e}"))
+}
+
+// `1000` or `3,-7,12`, they seed r0, r1, ...
+fn parse_arg_list(list: &str) -> Result<Vec<i64>, String> {
+    let args = list
+        .split(',')
+        .map(|a| a.trim().parse::<i64>().map_err(|_| format!("bad argument `{a}` in `{list}`")))
+        .collect::<Result<Vec<_>, _>>()?;
+    if args.len() > NREGS {
+        return Err(format!("{} arguments but only {NREGS} registers", args.len()));
+    }
+    Ok(args)
+}
+
+#[cfg(feature = "plugins")]
+type LoadedPlugin = plugin::Plugin;
+#[cfg(not(feature = "plugins"))]
+type LoadedPlugin = std::convert::Infallible;
+
+#[cfg(feature = "plugins")]
+fn load_plugin(path: &str) -> Result<LoadedPlugin, String> {
+    // Safety: `--plugin` is the user telling us this library is a plugin, we can't
+    // check more than the ABI version, see Plugin::load
+    unsafe { plugin::Plugin::load(path) }
+}
+
+#[cfg(not(feature = "plugins"))]
+fn load_plugin(_path: &str) -> Result<LoadedPlugin, String> {
+    Err("this build has no plugin support, rebuild with `--features plugins`".to_string())
+}
+
+// where the default benchmark's rows go besides the table
+#[derive(Clone, Copy)]
+struct Export<'a> {
+    output: Format,
+    baseline: Option<&'a Baseline>,
+    // a baseline name under target/criterion, see src/criterion.rs
+    criterion: Option<&'a str>,
+}
+
+impl Export<'_> {
+    const NONE: Export<'static> = Export { output: Format::Text, baseline: None, criterion: None };
+}
+
+// every row has to agree with the counting run on the result, like in run-experiment,
+// otherwise the timings mean nothing. mismatches get listed after the table and make
+// it an error, and so do regressions against the baseline
+fn run_default(
+    workload: &Workload,
+    variants: &[&Variant],
+    budget: Budget,
+    externals: &[external::External],
+    plugins: &[LoadedPlugin],
+    export: Export,
+) -> Result<(), String> {
+    let Export { output, baseline, criterion } = export;
+    let Workload { description, program, args, .. } = workload;
+
+    let mut counts = CountHook::default();
+    let expected = run_hooked(program, args, &mut counts);
+    let mut rows = Vec::new();
+    let mut mismatches = Vec::new();
+    let mut check = |label: &str, m: Measurement| {
+        if m.result != expected {
+            mismatches.push(format!("{label} returned {}, central dispatch returned {expected}", m.result));
+        }
+        rows.push(m.row(label));
+    };
+
+    say!(output, "VM Dispatch Benchmark");
+    say!(output, "Program: {description}");
+    say!(
+        output,
+        "Executes {} instructions per run ({} taken branches)",
+        counts.instructions,
+        counts.branches_taken
+    );
+    match budget {
+        Budget::Time(time) => say!(output, "Time budget: {}s per variant, {SAMPLES} samples\n", time.as_secs_f64()),
+        Budget::Iters(_) => {
+            let iters = budget.per_sample(|| 0.0) * SAMPLES;
+            say!(output, "Iterations: {iters} per variant, {SAMPLES} samples\n");
+        }
+    }
+
+    #[cfg(feature = "perf")]
+    let counters = match perf::Counters::open() {
+        Ok(counters) => {
+            say!(output, "{}", perf::header());
+            Some(counters)
+        }
+        Err(e) => {
+            say!(output, "note: no hardware counters, {e}\n");
+            None
+        }
+    };
+    #[cfg(not(feature = "perf"))]
+    let counters = None;
+    for v in variants {
+        if let Some(m) = bench(v, program, args, budget, counters.as_ref(), output) {
+            check(v.label, m);
+        }
+    }
+    for ext in externals {
+        match ext.measure(program, args, budget) {
+            Ok(m) => {
+                say!(output, "{}", m.line(&ext.label));
+                check(&ext.label, m);
             }
-            OP_INC => {
-                regs[dst1] = regs[dst1].wrapping_add(1);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
+            Err(e) => say!(output, "{:>24}: failed, {e}", ext.label),
+        }
+    }
+    #[cfg(feature = "plugins")]
+    for p in plugins {
+        for (label, m) in bench_plugin(p, program, args, budget, output) {
+            check(&label, m);
+        }
+    }
+    #[cfg(not(feature = "plugins"))]
+    let _ = plugins;
+    if !mismatches.is_empty() {
+        say!(output);
+        for msg in &mismatches {
+            say!(output, "MISMATCH: {msg}");
+        }
+        return Err(format!("{} result mismatch(es), the timings above aren't comparable", mismatches.len()));
+    }
+
+    match output {
+        Format::Text => {}
+        Format::Json => print!("{}", report::to_json(description, counts.instructions, &rows)),
+        Format::Csv => print!("{}", report::to_csv(&rows)),
+    }
+    if let Some(name) = criterion {
+        let dir = criterion::write(&workload.id, name, &rows)?;
+        say!(output, "\nwrote {} rows to {}", rows.len(), dir.display());
+    }
+    if let Some(baseline) = baseline {
+        say!(output);
+        say!(output, "Against {}:", baseline.path);
+        let (lines, regressions) = baseline.compare(&rows);
+        for line in lines {
+            say!(output, "{line}");
+        }
+        if regressions > 0 {
+            return Err(format!("{regressions} regression(s) against {}", baseline.path));
+        }
+    }
+    if output != Format::Text {
+        return Ok(());
+    }
+
+    println!();
+    stream_filter_demo();
+
+    println!();
+    println!("To check whether the dispatch got threaded:");
+    println!("  cargo run --release -- analyze");
+    println!("  (or look in target/release/deps/rust_goto-*.s after cargo rustc --release --lib -- --emit=asm)");
+    println!();
+    println!("To disable tail-merging (force LLVM to keep duplicated dispatch):");
+    println!("  set RUSTFLAGS=-C llvm-args=-tail-merge-threshold=0");
+    println!("  cargo rustc --release --lib -- --emit=asm");
+    Ok(())
+}
+
+// the dispatch sites of every run_* function in `asm_path`, or in the library asm
+// emitted for this build's features, then the default benchmark so the two can be
+// read together
+fn run_analyze(asm_path: Option<&str>) -> Result<(), String> {
+    let path = match asm_path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let features: Vec<&str> = [
+                ("safe-only", cfg!(feature = "safe-only")),
+                ("c-reference", cfg!(feature = "c-reference")),
+                ("sparse-opcodes", cfg!(feature = "sparse-opcodes")),
+            ]
+            .into_iter()
+            .filter_map(|(name, on)| on.then_some(name))
+            .collect();
+            let root = analyze::crate_root();
+            analyze::emit_asm(&root, "--lib", &root.join("target"), &features)?
+        }
+    };
+    let asm = std::fs::read_to_string(&path).map_err(|e| format!("can't read {}: {e}", path.display()))?;
+
+    println!("asm: {}", path.display());
+    println!();
+    println!("{:>23}  {:>6}  {:>11}  {:>12}  verdict", "function", "lines", "jump tables", "indirect jmp");
+    let mut collapsed = 0;
+    for (name, should_thread, stats) in analyze::analyze(&asm) {
+        let Some(stats) = stats else {
+            println!("{name:>23}  (not found)");
+            continue;
+        };
+        let verdict = match (should_thread, stats.is_threaded()) {
+            (true, true) => "threaded",
+            (true, false) => {
+                collapsed += 1;
+                "TAIL-MERGED, one dispatch site left"
             }
-            OP_DEC => {
-                regs[dst1] = regs[dst1].wrapping_sub(1);
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
+            (false, _) => "central, as intended",
+        };
+        println!(
+            "{name:>23}  {:>6}  {:>11}  {:>12}  {verdict}",
+            stats.lines, stats.jump_tables, stats.indirect_jumps
+        );
+    }
+    if collapsed > 0 {
+        println!();
+        println!("{collapsed} threaded variant(s) got their dispatch merged back into a central loop");
+    }
+    println!();
+    print_dispatch_sites();
+    println!();
+    let variants: Vec<&Variant> = VARIANTS.iter().collect();
+    run_default(&Workload::sum_poly(), &variants, Budget::Time(DEFAULT_BUDGET), &[], &[], Export::NONE)
+}
+
+// where the dispatch sites of this binary (the one about to run the benchmark) ended
+// up, and the pairs that share a BTB entry. `analyze` goes on without it when there's
+// no objdump
+fn print_dispatch_sites() {
+    let objdump = std::env::current_exe().map_err(|e| e.to_string()).and_then(|exe| analyze::disassemble_binary(&exe));
+    let sites = match objdump {
+        Ok(objdump) => analyze::dispatch_sites(&objdump),
+        Err(e) => {
+            println!("dispatch sites: skipped, {e}");
+            return;
+        }
+    };
+    let (block, sets) = (1 << analyze::BTB_BLOCK_BITS, 1 << analyze::BTB_INDEX_BITS);
+    println!("dispatch sites in this binary, address (+offset in its cache line)");
+    println!("pairs that alias in a BTB of {block}-byte blocks x {sets} sets are listed under them");
+    let aliasing = analyze::aliasing(&sites);
+    for &(name, _) in analyze::DISPATCH_FUNCTIONS {
+        let own: Vec<(usize, &analyze::Site)> = sites.iter().enumerate().filter(|(_, s)| s.function == name).collect();
+        if own.is_empty() {
+            continue;
+        }
+        let pairs: Vec<_> = aliasing.iter().filter(|&&(i, _, _)| sites[i].function == name).collect();
+        println!("{name:>23}  {} sites, {} aliasing pairs", own.len(), pairs.len());
+        for row in own.chunks(6) {
+            let row: Vec<String> = row.iter().map(|(_, s)| format!("{:#x}(+{})", s.address, s.line_offset())).collect();
+            println!("{:>25}{}", "", row.join(" "));
+        }
+        for &&(i, j, alias) in &pairs {
+            let why = match alias {
+                analyze::Alias::SameBlock => format!("same {block}-byte block"),
+                analyze::Alias::SameSet => "same set".to_string(),
+            };
+            println!("{:>25}{:#x} and {:#x}: {why}", "", sites[i].address, sites[j].address);
+        }
+    }
+}
+
+// a row for the plugin's handlers (if it has any) and one for its own interpreter
+#[cfg(feature = "plugins")]
+// every row it printed
+fn bench_plugin(
+    p: &plugin::Plugin,
+    program: &VerifiedProgram,
+    args: &[i64],
+    budget: Budget,
+    output: Format,
+) -> Vec<(String, Measurement)> {
+    let (replaced, added) = p.describe_ops();
+    let name = format!("plugin {}", p.name);
+    say!(output, "{name:>24}  ({replaced} built-in handlers replaced, {added} opcodes added)");
+    let mut rows = Vec::new();
+    if p.has_handlers() {
+        let run = |c: &VerifiedProgram, a: &[i64]| p.run_handlers(c, a);
+        let m = measure_placed(program, args, budget, run, BlackBox::All);
+        rows.push((format!("{}-handlers", p.name), m));
+    }
+    if let Some(run) = p.run_fn() {
+        rows.push((format!("{}-run", p.name), measure_placed(program, args, budget, run, BlackBox::All)));
+    }
+    for (label, m) in &rows {
+        say!(output, "{}", m.line(label));
+    }
+    rows
+}
+
+// one long run through central dispatch, in slices of `every` instructions with the
+// state written to `path` in between. the file is replaced atomically (write + rename)
+// so a job killed mid-write still leaves the previous checkpoint, and it's removed once
+// the program halts. the time reported is summed over every resumed piece of the run
+fn run_checkpointed(workload: &Workload, every: u64, path: &str, resuming: bool) -> Result<(), String> {
+    let Workload { description, program, args, .. } = workload;
+    let mut cp = if resuming {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+        Checkpoint::parse(&text, program).map_err(|e| format!("{path}: {e}"))?
+    } else {
+        Checkpoint::start(program, args)
+    };
+
+    println!("Checkpointed run");
+    println!("Program: {description}");
+    if resuming {
+        println!(
+            "Resuming from {path}: {} instructions in, {:.3}s so far",
+            cp.executed,
+            cp.elapsed_ns as f64 / 1e9
+        );
+    }
+    println!("Checkpoint every {every} instructions to {path}\n");
+
+    let tmp = format!("{path}.tmp");
+    let result = loop {
+        let start = Instant::now();
+        let done = checkpoint::run_for(program, &mut cp, every);
+        cp.elapsed_ns += start.elapsed().as_nanos() as u64;
+        if let Some(result) = done {
+            break result;
+        }
+        std::fs::write(&tmp, cp.to_text())
+            .and_then(|()| std::fs::rename(&tmp, path))
+            .map_err(|e| format!("can't write checkpoint {path}: {e}"))?;
+        println!("{:>16} instructions, {:8.3}s", cp.executed, cp.elapsed_ns as f64 / 1e9);
+    };
+    // nothing left to resume, a stale checkpoint would only be confusing
+    if let Err(e) = std::fs::remove_file(path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        eprintln!("warning: can't remove {path}: {e}");
+    }
+
+    println!();
+    println!(
+        "result = {result}, {} instructions in {:.3}s ({:.2} ns/instruction)",
+        cp.executed,
+        cp.elapsed_ns as f64 / 1e9,
+        cp.elapsed_ns as f64 / cp.executed as f64
+    );
+    Ok(())
+}
+
+// every variant under every black_box placement. if a row moves a lot between
+// placements, the harness is part of what's being measured for that variant
+fn run_black_box_matrix(workload: &Workload, variants: &[&Variant], budget: Budget) {
+    let Workload { program, args, .. } = workload;
+
+    println!("black_box placement matrix, ns/iter ({budget} per cell)\n");
+    print!("{:>24}", "");
+    for (_, name) in BLACK_BOX_PLACEMENTS {
+        print!(" {name:>10}");
+    }
+    println!(" {:>11}", "max/min");
+
+    for v in variants {
+        print!("{:>24}", v.label);
+        let mut times = Vec::new();
+        for &(bb, _) in BLACK_BOX_PLACEMENTS {
+            let Ok(m) = measure_variant(v, program, args, budget, bb) else { break };
+            print!(" {:>10.1}", m.ns_per_iter);
+            times.push(m.ns_per_iter);
+        }
+        if times.is_empty() {
+            println!(" skipped, the program doesn't fit this variant");
+            continue;
+        }
+        let min = times.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = times.iter().copied().fold(0.0, f64::max);
+        println!(" {:>11.2}", max / min);
+    }
+}
+
+// every variant on the program as written, after the superinstruction pass (src/fuse.rs)
+// and after the pass guided by a profile of this run (fuse_guided, see --profile). fewer
+// dispatches always help, the question is how much each strategy gets out of it: the
+// cheaper a variant's dispatch already is, the less there is to win
+fn run_fused(workload: &Workload, variants: &[&Variant], budget: Budget) {
+    let Workload { program, args, .. } = workload;
+    let fused = program.program().fused().verify().expect("fusing keeps the control flow verified");
+    let mut profile = profile::ProfileHook::new(program.code(), false);
+    let expected = run_hooked(program, args, &mut profile);
+    let guided = program.program().fused_guided(&profile.back_to_back);
+    let guided = guided.verify().expect("fusing keeps the control flow verified");
+
+    let mut fused_counts = CountHook::default();
+    let mut guided_counts = CountHook::default();
+    run_hooked(&fused, args, &mut fused_counts);
+    run_hooked(&guided, args, &mut guided_counts);
+    println!(
+        "superinstructions, ns/iter ({budget} per cell): {} instructions per run unfused, {} fused, {} guided\n",
+        profile.instructions,
+        fused_counts.instructions,
+        guided_counts.instructions
+    );
+    println!("{:>24} {:>10} {:>10} {:>8} {:>10} {:>8}", "", "unfused", "fused", "speedup", "guided", "speedup");
+    for v in variants {
+        let (Ok(plain), Ok(fast), Ok(picked)) = (
+            measure_variant(v, program, args, budget, BlackBox::All),
+            measure_variant(v, &fused, args, budget, BlackBox::All),
+            measure_variant(v, &guided, args, budget, BlackBox::All),
+        ) else {
+            println!("{:>24} skipped, the program doesn't fit this variant", v.label);
+            continue;
+        };
+        if [plain.result, fast.result, picked.result] != [expected; 3] {
+            println!(
+                "{:>24} MISMATCH: {} unfused, {} fused, {} guided, expected {expected}",
+                v.label, plain.result, fast.result, picked.result
+            );
+            continue;
+        }
+        println!(
+            "{:>24} {:>10.1} {:>10.1} {:>7.2}x {:>10.1} {:>7.2}x",
+            v.label,
+            plain.ns_per_iter,
+            fast.ns_per_iter,
+            plain.ns_per_iter / fast.ns_per_iter,
+            picked.ns_per_iter,
+            plain.ns_per_iter / picked.ns_per_iter
+        );
+    }
+}
+
+// what --program takes besides a file
+fn list_programs() {
+    println!("kernels (for --program and experiment descriptors, a <param> is a number):");
+    for name in kernels::KERNELS {
+        println!("  {name}");
+    }
+    println!("\nor a program file: .vmasm (src/asm.rs) or .expr (src/expr.rs)");
+    let Ok(dir) = std::fs::read_dir("programs") else { return };
+    let mut files: Vec<String> = dir.filter_map(|e| Some(e.ok()?.file_name().to_string_lossy().into_owned())).collect();
+    files.sort();
+    println!("\nin programs/:");
+    for file in files {
+        println!("  programs/{file}");
+    }
+}
+
+// what this build can run, so nobody has to read Cargo.toml and the cfgs to find out
+// why a descriptor naming c-goto fails
+fn list_variants() {
+    let features: Vec<&str> = [
+        ("safe-only", cfg!(feature = "safe-only")),
+        ("c-reference", cfg!(feature = "c-reference")),
+        ("plugins", cfg!(feature = "plugins")),
+        ("sparse-opcodes", cfg!(feature = "sparse-opcodes")),
+        ("perf", cfg!(feature = "perf")),
+        ("jit", cfg!(feature = "jit")),
+        ("nightly-tailcall", cfg!(feature = "nightly-tailcall")),
+        ("deep-dispatch", cfg!(feature = "deep-dispatch")),
+    ]
+    .into_iter()
+    .filter_map(|(name, on)| on.then_some(name))
+    .collect();
+    let features = if features.is_empty() { "none".to_string() } else { features.join(", ") };
+    println!("build features: {features}\n");
+
+    println!("variants (names for experiment descriptors):");
+    for v in VARIANTS {
+        let input = match v.run {
+            Runner::Words(_) => "u32 words",
+            Runner::Bytes(_) => "u8 stream",
+            Runner::Bundles(_) => "4-word bundles",
+            Runner::Decoded(_) => "decoded fields",
+            Runner::Loops(_) => "decoded, loops lifted",
+            #[cfg(feature = "jit")]
+            Runner::Native(_) => "x86-64 code",
+        };
+        println!("  {:<16} {:<24} {input}", v.name, v.label);
+    }
+    println!("\nstructured ISA, `compare-isa` only:");
+    println!("  {:<16} {:<24} structured words", "struct-central", "run_structured_central");
+    println!("  {:<16} {:<24} structured words", "struct-threaded2", "run_structured_threaded");
+    println!("\nstack-machine ISA, `compare-stack` only:");
+    println!("  {:<16} {:<24} stack words", "stack-central", "run_stack_central");
+    println!("  {:<16} {:<24} stack words", "stack-threaded2", "run_stack_threaded");
+    println!("\nblock-at-a-time translation, `compare-blocks` only:");
+    println!("  {:<16} {:<24} decoded blocks, LRU", "blocks", "run_blocks");
+    println!("\nthreaded deeper than threaded3, `--depth` only:");
+    for depth in 4..=THREADED_DEPTHS.len() {
+        println!("  {:<16} {:<24} u32 words", format!("--depth {depth}"), format!("run_threaded_{depth}"));
+    }
+
+    if !UNAVAILABLE_VARIANTS.is_empty() {
+        println!("\nnot in this build:");
+        for (name, why) in UNAVAILABLE_VARIANTS {
+            println!("  {name:<16} {why}");
+        }
+    }
+    if cfg!(feature = "plugins") {
+        println!("\nmore with --plugin <library>, see plugins/rg_plugin.h");
+    } else {
+        println!("\n--plugin needs `--features plugins`");
+    }
+}
+
+// goto vs structured bytecode (src/structured.rs): the same kernels in both ISAs,
+// central and 2-level threaded dispatch over each, and how much threading buys in
+// each one
+// `fuzz [<programs>] [--seed <n>]`: that many generated programs through every variant
+// against the reference stepper (src/fuzz.rs). without a seed it picks one from the
+// clock so every run covers new programs, and prints it, a failure reproduces with it
+// checks the vectors in a directory (conformance/ by default) against the reference
+// and every variant, or writes the builtin ones there. the files hold dense opcode
+// numbers, like the external tools' programs
+#[cfg(feature = "sparse-opcodes")]
+fn run_conformance(_args: &[String]) -> Result<(), String> {
+    Err("conformance vectors use the dense opcode numbering, not available with sparse-opcodes".to_string())
+}
+
+#[cfg(not(feature = "sparse-opcodes"))]
+fn run_conformance(args: &[String]) -> Result<(), String> {
+    use rust_goto::conformance;
+
+    match args {
+        [flag, dir] if flag == "--write" => {
+            std::fs::create_dir_all(dir).map_err(|e| format!("can't create {dir}: {e}"))?;
+            let vectors = conformance::builtin();
+            for v in &vectors {
+                let path = PathBuf::from(dir).join(format!("{}.json", v.name));
+                std::fs::write(&path, v.to_json()).map_err(|e| format!("can't write {}: {e}", path.display()))?;
             }
-            OP_JMPNZ => {
-                if regs[dst1] != 0 { pc = imm16(a1, b1) as usize; }
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
+            println!("wrote {} vectors to {dir}", vectors.len());
+            Ok(())
+        }
+        [] | [_] => {
+            let dir = args.first().map_or("conformance", String::as_str);
+            let vectors = conformance::load_dir(dir.as_ref())?;
+            if vectors.is_empty() {
+                return Err(format!("no vectors in {dir}"));
             }
-            OP_MOV => {
-                regs[dst1] = regs[a1 as usize];
-                let (op2, dst2, a2, b2) = exec_one!(code, regs, pc);
-                handle_and_dispatch!(code, regs, pc, op2, dst2, a2, b2);
+            let cases: usize = vectors.iter().map(|v| v.cases.len()).sum();
+            for v in &vectors {
+                v.check()?;
             }
-            _ => return -1,
+            println!("{} vectors, {cases} cases: the reference and {} variants agree", vectors.len(), VARIANTS.len());
+            Ok(())
         }
+        _ => Err(format!("conformance takes a directory, or --write and one\n{USAGE}")),
     }
 }
 
-// le benchmark
-fn bench<F: Fn(&[u32]) -> i64>(name: &str, code: &[u32], iters: u32, f: F) {
-    for _ in 0..100 {
-        black_box(f(black_box(code)));
+fn run_fuzz(args: &[String]) -> Result<(), String> {
+    let mut programs = 10_000;
+    let mut seed = None;
+    let mut soak = None;
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        let number = |value: Option<&String>| {
+            value.and_then(|v| v.parse::<u64>().ok()).ok_or_else(|| format!("{arg} takes a number\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--seed" => seed = Some(number(rest.next())?),
+            "--soak" => match number(rest.next())? {
+                0 => return Err(format!("--soak takes a positive number of minutes\n{USAGE}")),
+                minutes => match minutes.checked_mul(60) {
+                    Some(secs) => soak = Some((minutes, Duration::from_secs(secs))),
+                    None => return Err(format!("--soak {minutes} minutes doesn't fit in a u64 of seconds\n{USAGE}")),
+                },
+            },
+            "--show" => {
+                print!("{}", fuzz::listing(&fuzz::generate(number(rest.next())?)));
+                return Ok(());
+            }
+            _ => programs = number(Some(arg))?,
+        }
     }
-
+    let seed = seed.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+        now.as_nanos() as u64
+    });
     let start = Instant::now();
-    for _ in 0..iters {
-        black_box(f(black_box(code)));
+    let outcome = match soak {
+        Some((minutes, duration)) => {
+            println!("soaking for {minutes} minutes from seed {seed}, {} variants, plain and fused", VARIANTS.len());
+            let report = |s: &fuzz::Summary, elapsed: Duration| {
+                let secs = elapsed.as_secs_f64();
+                println!(
+                    "[{:7.1} min] {} programs ({:.0}/s), {} instructions, {:.1}% stopped by a trap, last seed {}",
+                    secs / 60.0,
+                    s.programs,
+                    s.programs as f64 / secs,
+                    s.instructions,
+                    100.0 * s.trapped as f64 / s.programs as f64,
+                    seed.wrapping_add(s.programs - 1)
+                );
+            };
+            fuzz::soak(seed, duration, SOAK_REPORT_EVERY, report)
+        }
+        None => {
+            println!("fuzzing {programs} programs from seed {seed}, {} variants, plain and fused", VARIANTS.len());
+            fuzz::run(seed, programs)
+        }
+    };
+    match outcome {
+        Ok(summary) => {
+            println!(
+                "all agreed with the reference: {} programs, {} instructions, {} stopped by a trap ({:.1}s)",
+                summary.programs,
+                summary.instructions,
+                summary.trapped,
+                start.elapsed().as_secs_f64()
+            );
+            Ok(())
+        }
+        Err(mismatch) => {
+            println!("\nMISMATCH {mismatch}");
+            Err(format!("reproduce with `rust-goto fuzz 1 --seed {}`", mismatch.seed))
+        }
     }
-    let elapsed = start.elapsed();
+}
 
-    let result = f(code);
-    let ns_per_iter = elapsed.as_nanos() as f64 / iters as f64;
-    println!("{name:>24}: {ns_per_iter:8.1} ns/iter  (result = {result})");
+fn compare_isa(n: i64) {
+    let budget = Budget::Time(CELL_BUDGET);
+    type StructuredFn = fn(&StructuredProgram, &[i64]) -> i64;
+    let goto: [RunFn; 2] = [run_central, run_threaded];
+    let structured: [StructuredFn; 2] = [structured::run_structured_central, structured::run_structured_threaded];
+
+    println!("goto vs structured bytecode, ns/iter, n = {n} ({budget} per cell)\n");
+    println!(
+        "{:>14} {:>11} {:>11} {:>8}   {:>11} {:>11} {:>8}",
+        "kernel", "goto", "goto-thr2", "speedup", "struct", "struct-thr2", "speedup"
+    );
+    for name in ["sum-poly", "poly:4", "loop-nest:1", "loop-nest:3", "fsm:2", "fsm:8", "fsm:32"] {
+        let (code, args) = kernels::kernel(name, n).expect("goto kernel exists");
+        let program = verify(&code).expect("kernels verify");
+        let (scode, sargs) = structured::kernel(name, n).expect("structured kernel exists");
+        let sprogram = StructuredProgram::new(scode).expect("structured kernels validate");
+
+        // opaque like the benchmark, see measure_variant
+        let g = goto.map(|f| measure_placed(&program, &args, budget, black_box(f), BlackBox::All));
+        let s = structured.map(|f| measure_placed(&sprogram, &sargs, budget, black_box(f), BlackBox::All));
+        if let Some(m) = g.iter().chain(&s).find(|m| m.result != g[0].result) {
+            println!("{name:>14} results disagree: {} vs {}", g[0].result, m.result);
+            continue;
+        }
+        println!(
+            "{name:>14} {:>11.1} {:>11.1} {:>7.2}x   {:>11.1} {:>11.1} {:>7.2}x",
+            g[0].ns_per_iter,
+            g[1].ns_per_iter,
+            g[0].ns_per_iter / g[1].ns_per_iter,
+            s[0].ns_per_iter,
+            s[1].ns_per_iter,
+            s[0].ns_per_iter / s[1].ns_per_iter
+        );
+    }
 }
 
-fn main() {
-    let program = make_program(1000);
-    let iters = 100_000;
+// register vs stack bytecode (src/stack_machine.rs): each kernel translated to the stack
+// ISA, central and 2-level threaded dispatch over both, next to how many stack
+// instructions the same work takes. more, cheaper instructions should make dispatch a
+// bigger share of the time, and threading worth more
+fn compare_stack(n: i64) {
+    let budget = Budget::Time(CELL_BUDGET);
+    type StackFn = fn(&StackProgram, &[i64]) -> i64;
+    let goto: [RunFn; 2] = [run_central, run_threaded];
+    let stack: [StackFn; 2] = [stack_machine::run_stack_central, stack_machine::run_stack_threaded];
+
+    println!("register vs stack bytecode, ns/iter, n = {n} ({budget} per cell)\n");
+    println!(
+        "{:>14} {:>10}   {:>11} {:>11} {:>8}   {:>11} {:>11} {:>8}",
+        "kernel", "stack/goto", "goto", "goto-thr2", "speedup", "stack", "stack-thr2", "speedup"
+    );
+    for name in ["sum-poly", "poly:4", "loop-nest:1", "loop-nest:3", "fsm:8", "collatz", "bitscan"] {
+        let (code, args) = kernels::kernel(name, n).expect("goto kernel exists");
+        let program = verify(&code).expect("kernels verify");
+        let translated = stack_machine::translate(&program).expect("these kernels have a stack version");
+        let (goto_count, stack_count) = translated.executed(&program, &args);
+
+        // opaque like the benchmark, see measure_variant
+        let g = goto.map(|f| measure_placed(&program, &args, budget, black_box(f), BlackBox::All));
+        let s = stack.map(|f| measure_placed(&translated.program, &args, budget, black_box(f), BlackBox::All));
+        if let Some(m) = g.iter().chain(&s).find(|m| m.result != g[0].result) {
+            println!("{name:>14} results disagree: {} vs {}", g[0].result, m.result);
+            continue;
+        }
+        println!(
+            "{name:>14} {:>9.2}x   {:>11.1} {:>11.1} {:>7.2}x   {:>11.1} {:>11.1} {:>7.2}x",
+            stack_count as f64 / goto_count as f64,
+            g[0].ns_per_iter,
+            g[1].ns_per_iter,
+            g[0].ns_per_iter / g[1].ns_per_iter,
+            s[0].ns_per_iter,
+            s[1].ns_per_iter,
+            s[0].ns_per_iter / s[1].ns_per_iter
+        );
+    }
+}
 
-    println!("VM Dispatch Benchmark");
-    println!("Program: sum(i*i - i + 1) for i in 1..=1000");
-    println!("Iterations: {iters}\n");
+// whole-program predecoding vs translating a block at a time (src/blocks.rs) on the
+// giant:F kernels: F functions run once, then a loop over 16 of them n times. predecode
+// pays for all of it up front, blocks for what runs, and with a capacity smaller than
+// the hot set the LRU keeps evicting what the loop needs next
+fn compare_blocks(n: i64) {
+    let budget = Budget::Time(CELL_BUDGET);
+    let capacities = [("blocks", usize::MAX), ("blocks 4096", 4096), ("blocks 512", 512)];
+    // fn pointers, opaque like the benchmark, see measure_variant
+    let predecoded: fn(&VerifiedDecoded, &[i64]) -> i64 = black_box(run_predecoded);
+    let blocks: fn(&LazyBlocks, &[i64]) -> i64 = black_box(run_blocks);
 
-    bench("central-dispatch", &program, iters, run_central);
-    bench("threaded-2level", &program, iters, run_threaded);
-    bench("threaded-3level", &program, iters, run_threaded_deep);
+    println!("whole-program predecode vs block-at-a-time translation, n = {n} ({budget} per cell)\n");
+    println!(
+        "{:>11} {:>12} {:>10} {:>10}   {:>10} {:>10} {:>10}",
+        "kernel", "variant", "first us", "ns/iter", "decoded", "per run", "resident"
+    );
+    for name in ["giant:100", "giant:1000"] {
+        let (code, args) = kernels::kernel(name, n).expect("giant kernels exist");
+        let program = verify(&code).expect("kernels verify");
+        let expected = run_hooked(&program, &args, &mut CountHook::default());
 
+        // the first run, translation included, once: what a program that's loaded, run
+        // and dropped costs
+        let start = Instant::now();
+        let decoded = to_decoded(black_box(&program));
+        let result = predecoded(&decoded, &args);
+        let first = start.elapsed().as_secs_f64() * 1e6;
+        let m = measure_placed(&decoded, &args, budget, predecoded, BlackBox::All);
+        if result != expected || m.result != expected {
+            println!("{name:>11} {:>12} MISMATCH: {result}, expected {expected}", "predecode");
+            continue;
+        }
+        let len = code.len();
+        println!(
+            "{name:>11} {:>12} {first:>10.1} {:>10.1}   {len:>10} {:>10} {len:>10}",
+            "predecode", m.ns_per_iter, 0
+        );
+
+        for (label, capacity) in capacities {
+            let start = Instant::now();
+            let lazy = LazyBlocks::new(black_box(&program).clone(), capacity);
+            let result = blocks(&lazy, &args);
+            let first = start.elapsed().as_secs_f64() * 1e6;
+            let decoded = lazy.stats().decoded;
+            let m = measure_placed(&lazy, &args, budget, blocks, BlackBox::All);
+            // one more run on the warm cache for what steady state decodes again
+            let before = lazy.stats().decoded;
+            blocks(&lazy, &args);
+            let per_run = lazy.stats().decoded - before;
+            if result != expected || m.result != expected {
+                println!("{name:>11} {label:>12} MISMATCH: {result}, expected {expected}");
+                continue;
+            }
+            println!(
+                "{name:>11} {label:>12} {first:>10.1} {:>10.1}   {decoded:>10} {per_run:>10} {:>10}",
+                m.ns_per_iter,
+                lazy.stats().peak_resident
+            );
+        }
+    }
     println!();
-    println!("To inspect assembly:");
-    println!("  cargo rustc --release -- --emit=asm");
-    println!("  Look in target/release/deps/vm_dispatch_bench-*.s");
-    println!();
-    println!("To disable tail-merging (force LLVM to keep duplicated dispatch):");
-    println!("  set RUSTFLAGS=-C llvm-args=-tail-merge-threshold=0");
-    println!("  cargo rustc --release -- --emit=asm");
+    println!("decoded: instructions decoded by the first run, per run: by each run after that,");
+    println!("resident: the most decoded instructions held at once");
+}
+
+// the same filter over a bunch of rows, once from the slice through central dispatch,
+// once pulled instruction by instruction from an iterator
+fn stream_filter_demo() {
+    const ROWS: i64 = 1_000_000;
+    let filter = kernels::filter();
+    let verified = verify(&filter).expect("filter verifies");
+    println!("Filter expression over {ROWS} rows:");
+
+    let start = Instant::now();
+    // opaque like the benchmark, see measure_variant
+    let central: RunFn = black_box(run_central);
+    let kept = (0..ROWS).filter(|&i| central(black_box(&verified), &[i, i ^ 0x5555]) != 0).count();
+    let ns = start.elapsed().as_nanos() as f64 / ROWS as f64;
+    println!("{:>24}: {ns:8.1} ns/row   (kept = {kept})", "slice-central");
+
+    let start = Instant::now();
+    let kept = (0..ROWS)
+        .filter(|&i| run_stream(black_box(&filter).iter().copied(), &[i, i ^ 0x5555]) != 0)
+        .count();
+    let ns = start.elapsed().as_nanos() as f64 / ROWS as f64;
+    println!("{:>24}: {ns:8.1} ns/row   (kept = {kept})", "stream");
 }
@@ -0,0 +1,414 @@
+// Host values in guest memory and back, for TRAP handlers
+//
+// a host function gets a HostCtx (host.rs) with the guest's memory behind it, and the
+// guest hands it addresses in registers. without this every handler packs its own
+// arguments word by word and every one of them gets the layout slightly different.
+// the layout, the same on every host:
+//
+//   integers, bool  one word each, sign-extended (u64 as its bit pattern, bool 0 or 1).
+//                   reading one back checks it fits the type
+//   bytes, String   a length word, then the bytes 8 to a word, the first one in the
+//                   low byte (little-endian whatever the host is), the last word
+//                   padded with zeros. a String has to be UTF-8 on the way back
+//   Vec<T>          a length word, then the elements
+//   [T; N], tuples  the elements, no length
+//   structs         the fields in order, see marshal_struct!
+//
+// a value that runs out of memory halfway is left half-written, the fields before
+// the one that didn't fit are there. a word run (bytes, a String) goes in whole or not
+// at all. the same goes for a write through HostCtx that runs into a read-only
+// segment, which is WriteProtected rather than written over
+//
+// memory is addressed in words, so everything is word-aligned by construction and
+// there's no padding between fields. a guest walks the same layout with LOAD: a
+// struct at r1 has its first field at [r1 + 0], the second at [r1 + 1], and so on
+//
+//   struct Point { x: i64, y: i64 }
+//   marshal_struct!(Point { x, y });
+//   vm.set_trap_handler(|ctx| {
+//       let [_, at] = ctx.args::<2>();
+//       let p: Point = ctx.read(at as usize).unwrap();
+//       ...
+//   });
+
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarshalError {
+    // the value doesn't fit between `at` and the end of memory
+    OutOfBounds { at: usize, words: usize, memory_words: usize },
+    // a word that isn't a valid `what`: an i64 where an i32 was expected, a bool of 2
+    BadValue { at: usize, what: &'static str, value: i64 },
+    BadUtf8 { at: usize },
+    // a word of a read-only segment in the way, see HostCtx::write
+    WriteProtected { at: usize },
+}
+
+impl fmt::Display for MarshalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarshalError::OutOfBounds { at, words, memory_words } => {
+                write!(f, "{words} words at {at} don't fit in {memory_words} words of memory")
+            }
+            MarshalError::BadValue { at, what, value } => write!(f, "word {at}: {value} isn't a valid {what}"),
+            MarshalError::BadUtf8 { at } => write!(f, "the string at {at} isn't UTF-8"),
+            MarshalError::WriteProtected { at } => write!(f, "word {at} is read-only"),
+        }
+    }
+}
+
+impl std::error::Error for MarshalError {}
+
+// a value with a layout in guest memory
+pub trait Marshal: Sized {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError>;
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError>;
+}
+
+// `value` at word address `at`, returns how many words it took
+pub fn write<T: Marshal>(memory: &mut [i64], at: usize, value: &T) -> Result<usize, MarshalError> {
+    let mut w = Writer::new(memory, at);
+    value.write(&mut w)?;
+    Ok(w.at() - at)
+}
+
+pub fn read<T: Marshal>(memory: &[i64], at: usize) -> Result<T, MarshalError> {
+    T::read(&mut Reader::new(memory, at))
+}
+
+// writes values one after the other from a word address on
+pub struct Writer<'a> {
+    memory: &'a mut [i64],
+    // one bit per word like memory.rs keeps it, empty for none
+    read_only: &'a [u64],
+    at: usize,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(memory: &'a mut [i64], at: usize) -> Writer<'a> {
+        Writer { memory, read_only: &[], at }
+    }
+
+    // a writer that won't write the words set in `read_only`
+    pub(crate) fn protected(memory: &'a mut [i64], read_only: &'a [u64], at: usize) -> Writer<'a> {
+        Writer { memory, read_only, at }
+    }
+
+    // where the next value goes
+    pub fn at(&self) -> usize {
+        self.at
+    }
+
+    pub fn put<T: Marshal>(&mut self, value: &T) -> Result<(), MarshalError> {
+        value.write(self)
+    }
+
+    pub fn word(&mut self, value: i64) -> Result<(), MarshalError> {
+        self.words(&[value])
+    }
+
+    // all of them or, if they don't fit, none
+    pub fn words(&mut self, values: &[i64]) -> Result<(), MarshalError> {
+        let range = span(self.memory.len(), self.at, values.len())?;
+        let protected = |at: usize| self.read_only.get(at / 64).is_some_and(|bits| bits >> (at % 64) & 1 != 0);
+        if let Some(at) = range.clone().find(|&at| protected(at)) {
+            return Err(MarshalError::WriteProtected { at });
+        }
+        self.memory[range].copy_from_slice(values);
+        self.at += values.len();
+        Ok(())
+    }
+
+    // the length, then the bytes packed 8 to a word
+    pub fn bytes(&mut self, bytes: &[u8]) -> Result<(), MarshalError> {
+        let mut words = vec![bytes.len() as i64];
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            words.push(i64::from_le_bytes(word));
+        }
+        self.words(&words)
+    }
+}
+
+// reads values one after the other from a word address on
+pub struct Reader<'a> {
+    memory: &'a [i64],
+    at: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(memory: &'a [i64], at: usize) -> Reader<'a> {
+        Reader { memory, at }
+    }
+
+    // where the next value comes from
+    pub fn at(&self) -> usize {
+        self.at
+    }
+
+    pub fn get<T: Marshal>(&mut self) -> Result<T, MarshalError> {
+        T::read(self)
+    }
+
+    pub fn word(&mut self) -> Result<i64, MarshalError> {
+        Ok(self.words(1)?[0])
+    }
+
+    pub fn words(&mut self, n: usize) -> Result<&'a [i64], MarshalError> {
+        let range = span(self.memory.len(), self.at, n)?;
+        self.at += n;
+        Ok(&self.memory[range])
+    }
+
+    // what Writer::bytes wrote
+    pub fn bytes(&mut self) -> Result<Vec<u8>, MarshalError> {
+        let len = self.length()?;
+        let words = self.words(len.div_ceil(8))?;
+        let mut bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        bytes.truncate(len);
+        Ok(bytes)
+    }
+
+    // a length word, checked against what's left of memory before anything gets
+    // allocated for it: a garbage length is an error, not a huge Vec
+    fn length(&mut self) -> Result<usize, MarshalError> {
+        let at = self.at;
+        let len = self.word()?;
+        let left = self.memory.len() - self.at;
+        match usize::try_from(len) {
+            Ok(n) if n.div_ceil(8) <= left => Ok(n),
+            _ => Err(MarshalError::BadValue { at, what: "length", value: len }),
+        }
+    }
+}
+
+fn span(memory_words: usize, at: usize, words: usize) -> Result<std::ops::Range<usize>, MarshalError> {
+    match at.checked_add(words) {
+        Some(end) if end <= memory_words => Ok(at..end),
+        _ => Err(MarshalError::OutOfBounds { at, words, memory_words }),
+    }
+}
+
+macro_rules! marshal_ints {
+    ($($t:ty),*) => {$(
+        impl Marshal for $t {
+            fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+                w.word(*self as i64)
+            }
+
+            fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+                let at = r.at();
+                let value = r.word()?;
+                <$t>::try_from(value).map_err(|_| MarshalError::BadValue { at, what: stringify!($t), value })
+            }
+        }
+    )*};
+}
+
+marshal_ints!(i64, i32, u32, i16, u16, i8, u8);
+
+// the bit pattern, a guest sees u64::MAX as -1
+impl Marshal for u64 {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+        w.word(*self as i64)
+    }
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+        Ok(r.word()? as u64)
+    }
+}
+
+impl Marshal for bool {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+        w.word(*self as i64)
+    }
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+        let at = r.at();
+        match r.word()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(MarshalError::BadValue { at, what: "bool", value }),
+        }
+    }
+}
+
+impl Marshal for String {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+        w.bytes(self.as_bytes())
+    }
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+        let at = r.at();
+        String::from_utf8(r.bytes()?).map_err(|_| MarshalError::BadUtf8 { at })
+    }
+}
+
+impl<T: Marshal> Marshal for Vec<T> {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+        w.word(self.len() as i64)?;
+        self.iter().try_for_each(|item| item.write(w))
+    }
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+        let len = r.length()?;
+        (0..len).map(|_| T::read(r)).collect()
+    }
+}
+
+impl<T: Marshal, const N: usize> Marshal for [T; N] {
+    fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+        self.iter().try_for_each(|item| item.write(w))
+    }
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+        let items = (0..N).map(|_| T::read(r)).collect::<Result<Vec<T>, _>>()?;
+        Ok(items.try_into().unwrap_or_else(|_| unreachable!("N items were read")))
+    }
+}
+
+macro_rules! marshal_tuples {
+    ($(($($t:ident $i:tt),*)),*) => {$(
+        impl<$($t: Marshal),*> Marshal for ($($t,)*) {
+            fn write(&self, w: &mut Writer<'_>) -> Result<(), MarshalError> {
+                $(self.$i.write(w)?;)*
+                Ok(())
+            }
+
+            fn read(r: &mut Reader<'_>) -> Result<Self, MarshalError> {
+                Ok(($($t::read(r)?,)*))
+            }
+        }
+    )*};
+}
+
+marshal_tuples!((A 0, B 1), (A 0, B 1, C 2), (A 0, B 1, C 2, D 3));
+
+// implements Marshal for a struct as its fields in order:
+//
+//   struct Rect { origin: (i64, i64), size: [u32; 2], label: String }
+//   marshal_struct!(Rect { origin, size, label });
+#[macro_export]
+macro_rules! marshal_struct {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::marshal::Marshal for $name {
+            fn write(&self, w: &mut $crate::marshal::Writer<'_>) -> Result<(), $crate::marshal::MarshalError> {
+                $($crate::marshal::Marshal::write(&self.$field, w)?;)*
+                Ok(())
+            }
+
+            fn read(r: &mut $crate::marshal::Reader<'_>) -> Result<Self, $crate::marshal::MarshalError> {
+                Ok($name { $($field: $crate::marshal::Marshal::read(r)?),* })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OP_ADD, OP_HALT, OP_LOAD, OP_LOADI, OP_TRAP, TrapAction, Vm, encode, verify};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Rect {
+        origin: (i64, i64),
+        size: [u32; 2],
+        visible: bool,
+        label: String,
+        tags: Vec<u8>,
+    }
+
+    marshal_struct!(Rect { origin, size, visible, label, tags });
+
+    fn rect() -> Rect {
+        let (origin, size, visible) = ((-3, i64::MAX), [640, 480], true);
+        Rect { origin, size, visible, label: "héllo, world".to_string(), tags: vec![1, 255] }
+    }
+
+    #[test]
+    fn values_round_trip_in_the_documented_layout() {
+        let mut memory = [0; 16];
+        let words = write(&mut memory, 1, &rect()).unwrap();
+        // 2 + 2 + 1 + (1 + 2, 13 bytes) + (1 + 2)
+        assert_eq!(words, 11);
+        assert_eq!(memory[..6], [0, -3, i64::MAX, 640, 480, 1]);
+        assert_eq!(memory[6], 13);
+        assert_eq!(memory[7], i64::from_le_bytes(*b"h\xc3\xa9llo, "));
+        assert_eq!(memory[8], i64::from_le_bytes(*b"world\0\0\0"));
+        assert_eq!(memory[9..12], [2, 1, 255]);
+        assert_eq!(read::<Rect>(&memory, 1).unwrap(), rect());
+
+        let mut r = Reader::new(&memory, 1);
+        assert_eq!(r.get::<(i64, u64)>().unwrap(), (-3, i64::MAX as u64));
+        assert_eq!(r.at(), 3);
+    }
+
+    #[test]
+    fn bad_words_and_short_memory_are_errors() {
+        let mut memory = [0; 4];
+        let err = write(&mut memory, 2, &rect()).unwrap_err();
+        assert_eq!(err, MarshalError::OutOfBounds { at: 4, words: 1, memory_words: 4 });
+        // a string that doesn't fit isn't written at all
+        let mut memory = [0; 4];
+        let err = write(&mut memory, 0, &"too long for 4 words, by far".to_string()).unwrap_err();
+        assert_eq!(err.to_string(), "5 words at 0 don't fit in 4 words of memory");
+        assert_eq!(memory, [0; 4]);
+
+        let memory = [2, 300, -1, i64::MAX];
+        assert_eq!(read::<bool>(&memory, 0), Err(MarshalError::BadValue { at: 0, what: "bool", value: 2 }));
+        assert_eq!(read::<u8>(&memory, 1), Err(MarshalError::BadValue { at: 1, what: "u8", value: 300 }));
+        assert_eq!(read::<u64>(&memory, 2), Ok(u64::MAX));
+        assert!(matches!(read::<Vec<i64>>(&memory, 3), Err(MarshalError::BadValue { what: "length", .. })));
+        let bad = [1, 0xFF];
+        assert_eq!(read::<String>(&bad, 0), Err(MarshalError::BadUtf8 { at: 0 }));
+    }
+
+    #[test]
+    fn a_trap_handler_reads_and_writes_guest_structs() {
+        // r1 = 8, TRAP 1 fills in a Rect there, then r0 = its origin.x + size[0]
+        let code = [
+            encode(OP_LOADI, 1, 8, 0),
+            encode(OP_TRAP, 0, 1, 0),
+            encode(OP_LOAD, 0, 1, 0),
+            encode(OP_LOAD, 2, 1, 2),
+            encode(OP_ADD, 0, 0, 2),
+            encode(OP_TRAP, 0, 2, 0),
+            encode(OP_HALT, 0, 0, 0),
+        ];
+        let mut vm = Vm::new(verify(&code).unwrap());
+        let (seen, label) = std::sync::mpsc::channel();
+        vm.set_trap_handler(move |ctx| {
+            let [_, at] = ctx.args::<2>();
+            match ctx.imm() {
+                1 => ctx.write(at as usize, &rect()).map(|_| TrapAction::Continue),
+                _ => ctx.read::<Rect>(at as usize).map(|r| {
+                    seen.send(r.label).unwrap();
+                    TrapAction::Continue
+                }),
+            }
+            .unwrap_or(TrapAction::Halt(-1))
+        });
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(100), crate::Status::Halted(637));
+        assert_eq!(label.try_recv().unwrap(), "héllo, world");
+
+        // the same Rect over a constant pool stops at its first read-only word, what
+        // came before it written
+        let mut program = crate::Program::new(code.to_vec()).unwrap();
+        program.add_segment(12, vec![0; 4], true).unwrap();
+        let mut vm = Vm::new(program.verify().unwrap());
+        let (seen, error) = std::sync::mpsc::channel();
+        vm.set_trap_handler(move |ctx| match ctx.write(8, &rect()) {
+            Ok(_) => TrapAction::Continue,
+            Err(e) => {
+                seen.send(e).unwrap();
+                TrapAction::Halt(-1)
+            }
+        });
+        vm.reset(&[]).unwrap();
+        assert_eq!(vm.resume(100), crate::Status::Halted(-1));
+        assert_eq!(error.try_recv().unwrap(), MarshalError::WriteProtected { at: 12 });
+        assert_eq!(vm.memory()[8..14], [-3, i64::MAX, 640, 480, 0, 0]);
+    }
+}
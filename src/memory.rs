@@ -0,0 +1,244 @@
+// Linear memory, and what it holds when a run starts
+//
+// memory starts zeroed, except for the data segments a program asks for
+// (Program::add_segment, `.data`/`.rodata` in the assembler). a read-only segment is
+// a constant pool: LOAD reads it like anything else, but a STORE, MEMCPY or MEMSET
+// that would write into it stops the program with -1 (TrapKind::WriteProtected), same as
+// a write outside memory. protection is one bit per word, so segments don't have to
+// line up with anything
+//
+// only programs with read-only data have a bitmap at all, everyone else pays for one
+// check of an empty slice per write, and reads never look at it. there's no no-exec
+// bit because there's nothing for it to protect: code and memory are separate address
+// spaces (Harvard, like wasm), the fetch path never reads memory and no LOAD can read
+// code, so a program can't execute its data or look at its own instructions
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::TrapKind;
+
+// words that are in memory from the start of every run, from word address `start` on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub start: usize,
+    pub words: Vec<i64>,
+    pub read_only: bool,
+}
+
+impl Segment {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.words.len()
+    }
+}
+
+// what a run's memory gets built from: the size, the segments to copy in, and the
+// protection bitmap, shared with every Memory built from it
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MemoryImage {
+    words: usize,
+    segments: Vec<Segment>,
+    read_only: Arc<[u64]>,
+}
+
+impl MemoryImage {
+    // `segments` already checked to fit in `words` (Program does that)
+    pub(crate) fn new(words: usize, segments: &[Segment]) -> MemoryImage {
+        // a bitmap covering all of memory or none of it, so the C side can index it
+        // without a length
+        let read_only: Arc<[u64]> = if segments.iter().any(|s| s.read_only && !s.words.is_empty()) {
+            let mut bits = vec![0u64; words.div_ceil(64)];
+            for addr in segments.iter().filter(|s| s.read_only).flat_map(Segment::range) {
+                bits[addr / 64] |= 1 << (addr % 64);
+            }
+            bits.into()
+        } else {
+            Arc::new([])
+        };
+        MemoryImage { words, segments: segments.to_vec(), read_only }
+    }
+
+    pub(crate) fn words(&self) -> usize {
+        self.words
+    }
+
+    // zeroed memory with the segments copied in, fresh for every run
+    pub(crate) fn fresh(&self) -> Memory {
+        let mut words = vec![0; self.words];
+        for segment in &self.segments {
+            words[segment.range()].copy_from_slice(&segment.words);
+        }
+        Memory { words, read_only: self.read_only.clone() }
+    }
+
+    // `mem` ready for another run without zeroing it: the segments copied back in over
+    // whatever the last run left, everything else kept. only a memory of another size
+    // (or none yet) gets built fresh
+    pub(crate) fn refill(&self, mem: &mut Memory) {
+        if mem.words.len() != self.words {
+            *mem = self.fresh();
+            return;
+        }
+        for segment in &self.segments {
+            mem.words[segment.range()].copy_from_slice(&segment.words);
+        }
+    }
+
+    // `mem` as `fresh` would build it, rewriting only the pages `dirty` says were
+    // written: zeroed, and whatever part of a segment falls in them copied back. a
+    // memory of another size gets built fresh
+    pub(crate) fn restore(&self, mem: &mut Memory, dirty: &mut DirtyPages) {
+        if mem.words.len() != self.words {
+            *mem = self.fresh();
+        } else {
+            for page in dirty.pages() {
+                let range = page * PAGE_WORDS..((page + 1) * PAGE_WORDS).min(self.words);
+                mem.words[range.clone()].fill(0);
+                for segment in &self.segments {
+                    let (start, end) = (segment.start.max(range.start), segment.range().end.min(range.end));
+                    if start < end {
+                        let from = start - segment.start;
+                        mem.words[start..end].copy_from_slice(&segment.words[from..from + (end - start)]);
+                    }
+                }
+            }
+        }
+        dirty.clear();
+    }
+}
+
+// words per page for dirty tracking, 4 KiB of memory
+pub(crate) const PAGE_WORDS: usize = 512;
+
+// which pages of a Memory got written since it was last restored, one bit per page,
+// grown as pages get marked
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DirtyPages {
+    bits: Vec<u64>,
+}
+
+impl DirtyPages {
+    #[inline(always)]
+    pub(crate) fn mark(&mut self, addr: usize) {
+        let page = addr / PAGE_WORDS;
+        if page / 64 >= self.bits.len() {
+            self.bits.resize(page / 64 + 1, 0);
+        }
+        self.bits[page / 64] |= 1 << (page % 64);
+    }
+
+    // `range` has to be inside memory, what a MEMCPY or MEMSET that went through wrote
+    pub(crate) fn mark_range(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        for page in range.start / PAGE_WORDS..=(range.end - 1) / PAGE_WORDS {
+            self.mark(page * PAGE_WORDS);
+        }
+    }
+
+    fn pages(&self) -> impl Iterator<Item = usize> + '_ {
+        let set = |(i, &bits): (usize, &u64)| (0..64).filter(move |b| bits >> b & 1 != 0).map(move |b| i * 64 + b);
+        self.bits.iter().enumerate().flat_map(set)
+    }
+
+    // keeps the bitmap's allocation, for the next run. a loop rather than `fill(0)`,
+    // which calls memset even for no words at all and costs more than a reset without
+    // pages to restore
+    fn clear(&mut self) {
+        for bits in &mut self.bits {
+            *bits = 0;
+        }
+    }
+}
+
+// the default is no memory at all, every access misses (run_stream gets that)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Memory {
+    words: Vec<i64>,
+    read_only: Arc<[u64]>,
+}
+
+impl Memory {
+    #[inline(always)]
+    pub(crate) fn get(&self, addr: usize) -> Option<&i64> {
+        self.words.get(addr)
+    }
+
+    // the slot a STORE writes, unless it's outside memory or read-only
+    #[inline(always)]
+    pub(crate) fn get_mut(&mut self, addr: usize) -> Result<&mut i64, TrapKind> {
+        if self.is_read_only(addr) {
+            return Err(TrapKind::WriteProtected);
+        }
+        self.words.get_mut(addr).ok_or(TrapKind::MemoryOutOfBounds)
+    }
+
+    #[inline(always)]
+    fn is_read_only(&self, addr: usize) -> bool {
+        self.read_only.get(addr / 64).is_some_and(|bits| bits >> (addr % 64) & 1 != 0)
+    }
+
+    // MEMCPY/MEMSET work on `len` words from word address `start`, all of which have
+    // to be inside memory. like with `address`, a negative start or length wraps to
+    // something huge and misses, so the length is bounded by the memory size
+    #[inline(always)]
+    fn range(&self, start: i64, len: i64) -> Result<Range<usize>, TrapKind> {
+        let start = start as usize;
+        let end = start.checked_add(len as usize).ok_or(TrapKind::MemoryOutOfBounds)?;
+        if end > self.words.len() {
+            return Err(TrapKind::MemoryOutOfBounds);
+        }
+        Ok(start..end)
+    }
+
+    #[inline(always)]
+    fn writable(&self, start: i64, len: i64) -> Result<Range<usize>, TrapKind> {
+        let range = self.range(start, len)?;
+        if !self.read_only.is_empty() && range.clone().any(|addr| self.is_read_only(addr)) {
+            return Err(TrapKind::WriteProtected);
+        }
+        Ok(range)
+    }
+
+    // MEMCPY dst a b: regs[b] words from mem[regs[a]..] to mem[regs[dst]..], overlapping
+    // ranges copy like memmove. on a miss nothing was written
+    #[inline(always)]
+    pub(crate) fn copy(&mut self, to: i64, from: i64, len: i64) -> Result<(), TrapKind> {
+        let from = self.range(from, len)?;
+        let to = self.writable(to, len)?;
+        self.words.copy_within(from, to.start);
+        Ok(())
+    }
+
+    // MEMSET dst a b: regs[b] words from mem[regs[dst]..] set to regs[a]
+    #[inline(always)]
+    pub(crate) fn fill(&mut self, to: i64, value: i64, len: i64) -> Result<(), TrapKind> {
+        let to = self.writable(to, len)?;
+        self.words[to].fill(value);
+        Ok(())
+    }
+
+    pub(crate) fn words(&self) -> &[i64] {
+        &self.words
+    }
+
+    // the words and the protection bitmap (empty for none) at once, for a writer that
+    // checks the bitmap itself (HostCtx::write)
+    pub(crate) fn split(&mut self) -> (&mut [i64], &[u64]) {
+        (&mut self.words, &self.read_only)
+    }
+
+    // past the protection, for whoever restores a snapshot (checkpoint) or hands the
+    // memory to code that checks it itself (the C side, plugins)
+    pub(crate) fn words_mut(&mut self) -> &mut [i64] {
+        &mut self.words
+    }
+
+    // one bit per word, bit `addr % 64` of `read_only_bits()[addr / 64]`, and empty
+    // when nothing is read-only
+    #[cfg(any(feature = "c-reference", feature = "plugins", feature = "jit"))]
+    pub(crate) fn read_only_bits(&self) -> &[u64] {
+        &self.read_only
+    }
+}
@@ -0,0 +1,252 @@
+// Hardware counters for the benchmark, `--features perf` (Linux only)
+//
+// ns/iter says which variant wins, not why. the counters do: a threaded variant
+// should show fewer branch misses per interpreted instruction than central dispatch
+// (one indirect jump per handler instead of one shared by all of them), and pay for
+// it in L1i misses once the duplicated dispatch stops fitting. per interpreted
+// instruction, after the timed run, bench() runs a batch with these counting:
+//
+//   instr      instructions retired
+//   branches   branch instructions retired
+//   br-miss    mispredicted branches
+//   L1i-miss   L1 instruction cache read misses
+//
+// user space only, the way perf_event_paranoid = 2 (the usual default) allows it.
+// each counter is opened on its own so a CPU or VM without one of them (L1i is the
+// one that's often missing) still gets the others. the harness loop is counted too,
+// it's the same for every variant
+
+use std::hint::black_box;
+
+use rust_goto::{CountHook, Runner, Variant, VerifiedProgram, bytes, run_hooked, to_bundles, to_decoded, to_loop_bodies};
+
+use crate::{BlackBox, timed_batch};
+
+// runs of the counted batch, enough to drown the syscalls around it
+const COUNTED_RUNS_MIN: u64 = 1_000;
+
+const PERF_TYPE_HARDWARE: u32 = 0;
+const PERF_TYPE_HW_CACHE: u32 = 3;
+const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+const PERF_COUNT_HW_BRANCH_INSTRUCTIONS: u64 = 4;
+const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+// cache id | op << 8 | result << 16
+const PERF_COUNT_HW_CACHE_L1I_READ_MISS: u64 = 1 | (1 << 16);
+
+const EVENTS: [(&str, u32, u64); 4] = [
+    ("instr", PERF_TYPE_HARDWARE, PERF_COUNT_HW_INSTRUCTIONS),
+    ("branches", PERF_TYPE_HARDWARE, PERF_COUNT_HW_BRANCH_INSTRUCTIONS),
+    ("br-miss", PERF_TYPE_HARDWARE, PERF_COUNT_HW_BRANCH_MISSES),
+    ("L1i-miss", PERF_TYPE_HW_CACHE, PERF_COUNT_HW_CACHE_L1I_READ_MISS),
+];
+
+// one file descriptor per EVENTS entry, None for the ones the kernel refused
+pub struct Counters {
+    fds: [Option<i32>; EVENTS.len()],
+}
+
+impl Counters {
+    // Err only if none of them could be opened, that's when it's worth telling the
+    // user about perf_event_paranoid
+    pub fn open() -> Result<Counters, String> {
+        let mut fds = [None; EVENTS.len()];
+        let mut last_error = 0;
+        for (fd, &(_, kind, config)) in fds.iter_mut().zip(&EVENTS) {
+            match sys::open(kind, config) {
+                Ok(f) => *fd = Some(f),
+                Err(errno) => last_error = errno,
+            }
+        }
+        if fds.iter().all(Option::is_none) {
+            return Err(match last_error {
+                sys::EACCES | sys::EPERM => {
+                    "no permission, see /proc/sys/kernel/perf_event_paranoid (needs 2 or lower)".to_string()
+                }
+                sys::ENOENT | sys::ENODEV | sys::EOPNOTSUPP => "the CPU or VM exposes no hardware counters".to_string(),
+                sys::ENOSYS => "perf_event_open isn't supported here".to_string(),
+                errno => format!("perf_event_open failed, errno {errno}"),
+            });
+        }
+        Ok(Counters { fds })
+    }
+
+    // the counts over `f`, None where the counter isn't there
+    fn count(&self, f: impl FnOnce()) -> [Option<u64>; EVENTS.len()] {
+        for fd in self.fds.iter().flatten() {
+            sys::reset_and_enable(*fd);
+        }
+        f();
+        for fd in self.fds.iter().flatten() {
+            sys::disable(*fd);
+        }
+        self.fds.map(|fd| fd.and_then(sys::read_count))
+    }
+}
+
+impl Drop for Counters {
+    fn drop(&mut self) {
+        for fd in self.fds.iter().flatten() {
+            sys::close_fd(*fd);
+        }
+    }
+}
+
+// the header bench() prints above the first row of counters
+pub fn header() -> String {
+    let names: Vec<String> = EVENTS.iter().map(|(name, _, _)| format!("{name:>9}")).collect();
+    format!("{:>24}  {}  (per interpreted instruction)", "", names.join(" "))
+}
+
+// counters per interpreted instruction over `runs` runs of the variant, as the line
+// bench() prints under its timing
+pub fn report(counters: &Counters, v: &Variant, program: &VerifiedProgram, args: &[i64], runs: u64) -> String {
+    let mut hook = CountHook::default();
+    run_hooked(program, args, &mut hook);
+    let runs = runs.max(COUNTED_RUNS_MIN);
+    let counts = match v.run {
+        Runner::Words(f) => counters.count(|| {
+            timed_batch(program, args, runs, &black_box(f), BlackBox::All);
+        }),
+        Runner::Bytes(f) => {
+            let Ok(code) = bytes::to_bytes(program) else { return String::new() };
+            counters.count(|| {
+                timed_batch(&code, args, runs, &black_box(f), BlackBox::All);
+            })
+        }
+        Runner::Bundles(f) => {
+            let code = to_bundles(program);
+            counters.count(|| {
+                timed_batch(&code, args, runs, &black_box(f), BlackBox::All);
+            })
+        }
+        Runner::Decoded(f) => {
+            let code = to_decoded(program);
+            counters.count(|| {
+                timed_batch(&code, args, runs, &black_box(f), BlackBox::All);
+            })
+        }
+        Runner::Loops(f) => {
+            let code = to_loop_bodies(program);
+            counters.count(|| {
+                timed_batch(&code, args, runs, &black_box(f), BlackBox::All);
+            })
+        }
+        #[cfg(feature = "jit")]
+        Runner::Native(f) => {
+            let code = rust_goto::jit::compile(program);
+            counters.count(|| {
+                timed_batch(&code, args, runs, &black_box(f), BlackBox::All);
+            })
+        }
+    };
+    let interpreted = (hook.instructions * runs) as f64;
+    let cells: Vec<String> = counts
+        .iter()
+        .map(|c| c.map_or(format!("{:>9}", "-"), |n| format!("{:>9.3}", n as f64 / interpreted)))
+        .collect();
+    format!("{:>24}  {}", "", cells.join(" "))
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::ffi::{c_int, c_long, c_ulong, c_void};
+
+    pub const EPERM: i32 = 1;
+    pub const ENOENT: i32 = 2;
+    pub const EACCES: i32 = 13;
+    pub const ENODEV: i32 = 19;
+    pub const ENOSYS: i32 = 38;
+    pub const EOPNOTSUPP: i32 = 95;
+
+    #[cfg(target_arch = "x86_64")]
+    const SYS_PERF_EVENT_OPEN: c_long = 298;
+    #[cfg(target_arch = "aarch64")]
+    const SYS_PERF_EVENT_OPEN: c_long = 241;
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    const SYS_PERF_EVENT_OPEN: c_long = -1;
+
+    const PERF_EVENT_IOC_ENABLE: c_ulong = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: c_ulong = 0x2401;
+    const PERF_EVENT_IOC_RESET: c_ulong = 0x2403;
+
+    // perf_event_attr bits in the word at offset 40
+    const DISABLED: u64 = 1 << 0;
+    const EXCLUDE_KERNEL: u64 = 1 << 5;
+    const EXCLUDE_HV: u64 = 1 << 6;
+
+    unsafe extern "C" {
+        fn syscall(number: c_long, ...) -> c_long;
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+        fn read(fd: c_int, buf: *mut c_void, count: usize) -> isize;
+        fn close(fd: c_int) -> c_int;
+        fn __errno_location() -> *mut c_int;
+    }
+
+    // Ok(fd) or Err(errno)
+    pub fn open(kind: u32, config: u64) -> Result<i32, i32> {
+        if SYS_PERF_EVENT_OPEN < 0 {
+            return Err(ENOSYS);
+        }
+        // perf_event_attr, 128 bytes (PERF_ATTR_SIZE_VER7), zero except for type,
+        // size, config and the flags
+        let mut attr = [0u64; 16];
+        attr[0] = kind as u64 | ((size_of_val(&attr) as u64) << 32);
+        attr[1] = config;
+        attr[5] = DISABLED | EXCLUDE_KERNEL | EXCLUDE_HV;
+        // Safety: attr outlives the call and is as big as the size it declares;
+        // pid 0 / cpu -1 is this thread on any CPU, no group, no flags
+        let (pid, cpu, group, flags) = (0 as c_int, -1 as c_int, -1 as c_int, 0 as c_ulong);
+        let fd = unsafe { syscall(SYS_PERF_EVENT_OPEN, attr.as_ptr(), pid, cpu, group, flags) };
+        if fd < 0 {
+            // Safety: errno is thread-local and valid right after the failed call
+            return Err(unsafe { *__errno_location() });
+        }
+        Ok(fd as i32)
+    }
+
+    pub fn reset_and_enable(fd: i32) {
+        // Safety: fd is a perf event fd we opened and still own
+        unsafe {
+            ioctl(fd, PERF_EVENT_IOC_RESET, 0 as c_ulong);
+            ioctl(fd, PERF_EVENT_IOC_ENABLE, 0 as c_ulong);
+        }
+    }
+
+    pub fn disable(fd: i32) {
+        // Safety: as in reset_and_enable
+        unsafe { ioctl(fd, PERF_EVENT_IOC_DISABLE, 0 as c_ulong) };
+    }
+
+    // the default read_format is a single u64
+    pub fn read_count(fd: i32) -> Option<u64> {
+        let mut value = 0u64;
+        // Safety: value is 8 writable bytes
+        let n = unsafe { read(fd, (&raw mut value).cast(), size_of::<u64>()) };
+        (n == size_of::<u64>() as isize).then_some(value)
+    }
+
+    pub fn close_fd(fd: i32) {
+        // Safety: called once per fd, from Counters::drop
+        unsafe { close(fd) };
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    pub const EPERM: i32 = 1;
+    pub const ENOENT: i32 = 2;
+    pub const EACCES: i32 = 13;
+    pub const ENODEV: i32 = 19;
+    pub const ENOSYS: i32 = 38;
+    pub const EOPNOTSUPP: i32 = 95;
+
+    pub fn open(_kind: u32, _config: u64) -> Result<i32, i32> {
+        Err(ENOSYS)
+    }
+    pub fn reset_and_enable(_fd: i32) {}
+    pub fn disable(_fd: i32) {}
+    pub fn read_count(_fd: i32) -> Option<u64> {
+        None
+    }
+    pub fn close_fd(_fd: i32) {}
+}
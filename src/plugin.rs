@@ -0,0 +1,200 @@
+// Handler libraries loaded at runtime, `--plugin path/to/libfoo.so`
+//
+// a plugin is a separately compiled shared library speaking the C ABI in
+// plugins/rg_plugin.h, so it can come from another compiler, other flags, another
+// language. it exports `rg_plugin_describe()` returning a descriptor with:
+//
+//   - per-opcode handlers: replacements for built-in opcodes or new ones (>= OP_COUNT,
+//     but not OP_PAST_END), run from a central loop where every opcode without a
+//     plugin handler falls back to handle!()
+//   - optionally a whole interpreter, benchmarked as its own row
+//
+// the ABI is versioned, a plugin built against another version is refused at load
+// time instead of crashing halfway through a benchmark. plugins/example is one
+// written in Rust
+//
+// dlopen/dlsym are declared by hand to stay dependency-free, which is unix only
+
+use std::ffi::{CStr, CString, c_char, c_int, c_void};
+
+use crate::{
+    NREGS, OP_COUNT, OP_PAST_END, Program, Stack, VerifiedProgram, VerifyError, address, exec_one, fetch, fused_binops,
+    handle, imm16, seed_regs, semantics,
+};
+use crate::semantics::apply;
+use crate::{
+    OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL, OP_MULSUB,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RESOLVE_CALL, OP_RET, OP_STORE, OP_SUB, OP_TRAP, OP_WAITEVENT,
+};
+
+// bump on any layout or contract change, and in rg_plugin.h
+pub const ABI_VERSION: u32 = 12;
+
+const DESCRIBE_SYMBOL: &CStr = c"rg_plugin_describe";
+
+const RTLD_NOW: c_int = 2;
+
+unsafe extern "C" {
+    fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlerror() -> *mut c_char;
+}
+
+// returns 0 to keep going, anything else stops the program with -1
+type Handler = unsafe extern "C" fn(regs: *mut i64, nregs: usize, dst: u8, a: u8, b: u8) -> i32;
+type RunRaw = unsafe extern "C" fn(
+    code: *const u32,
+    args: *const i64,
+    nargs: usize,
+    mem: *mut i64,
+    mem_words: usize,
+    read_only: *const u64,
+) -> i64;
+
+#[repr(C)]
+struct RawOp {
+    opcode: u8,
+    handler: Handler,
+}
+
+#[repr(C)]
+struct RawPlugin {
+    abi_version: u32,
+    name: *const c_char,
+    ops: *const RawOp,
+    nops: usize,
+    run: Option<RunRaw>,
+}
+
+pub struct Plugin {
+    pub name: String,
+    handlers: [Option<Handler>; 256],
+    run: Option<RunRaw>,
+}
+
+fn last_dl_error() -> String {
+    let err = unsafe { dlerror() };
+    if err.is_null() {
+        "unknown error".to_string()
+    } else {
+        unsafe { CStr::from_ptr(err) }.to_string_lossy().into_owned()
+    }
+}
+
+impl Plugin {
+    // the library is never unloaded, handlers stay callable for the whole run
+    //
+    /// # Safety
+    ///
+    /// dlopen runs the library's initializers and everything after that calls into
+    /// it, so this is only as sound as the library. the caller vouches that `path` is
+    /// a plugin built against plugins/rg_plugin.h: rg_plugin_describe returns NULL or
+    /// a descriptor that stays valid forever, whose name, ops and run pointers are
+    /// what the header says they are, and whose handlers and interpreter only touch
+    /// the memory they're handed. the ABI version check catches stale builds, not
+    /// arbitrary libraries
+    pub unsafe fn load(path: &str) -> Result<Plugin, String> {
+        let c_path = CString::new(path).map_err(|_| format!("bad path `{path}`"))?;
+        let lib = unsafe { dlopen(c_path.as_ptr(), RTLD_NOW) };
+        if lib.is_null() {
+            return Err(format!("can't load {path}: {}", last_dl_error()));
+        }
+        let sym = unsafe { dlsym(lib, DESCRIBE_SYMBOL.as_ptr()) };
+        if sym.is_null() {
+            return Err(format!("{path} doesn't export {}", DESCRIBE_SYMBOL.to_string_lossy()));
+        }
+        let describe: unsafe extern "C" fn() -> *const RawPlugin = unsafe { std::mem::transmute(sym) };
+        let raw = unsafe { describe() };
+        if raw.is_null() {
+            return Err(format!("{path}: rg_plugin_describe returned NULL"));
+        }
+        // the version is checked before anything else in the descriptor is trusted
+        let version = unsafe { (*raw).abi_version };
+        if version != ABI_VERSION {
+            return Err(format!("{path} is built for plugin ABI {version}, this is ABI {ABI_VERSION}"));
+        }
+        let raw = unsafe { &*raw };
+
+        let name = if raw.name.is_null() {
+            path.to_string()
+        } else {
+            unsafe { CStr::from_ptr(raw.name) }.to_string_lossy().into_owned()
+        };
+        let ops: &[RawOp] = if raw.nops == 0 {
+            &[]
+        } else if raw.ops.is_null() {
+            return Err(format!("{name}: {} handlers but no table", raw.nops));
+        } else {
+            unsafe { std::slice::from_raw_parts(raw.ops, raw.nops) }
+        };
+        let mut handlers = [None; 256];
+        for op in ops {
+            if op.opcode == OP_PAST_END {
+                return Err(format!("{name}: opcode {OP_PAST_END} is what runs past the end of a program, not free"));
+            }
+            if handlers[op.opcode as usize].replace(op.handler).is_some() {
+                return Err(format!("{name}: two handlers for opcode {}", op.opcode));
+            }
+        }
+        Ok(Plugin { name, handlers, run: raw.run })
+    }
+
+    // how many built-in opcodes it replaces and how many it adds
+    pub fn describe_ops(&self) -> (usize, usize) {
+        let replaced = self.handlers[..OP_COUNT as usize].iter().flatten().count();
+        let added = self.handlers[OP_COUNT as usize..].iter().flatten().count();
+        (replaced, added)
+    }
+
+    pub fn has_handlers(&self) -> bool {
+        self.handlers.iter().any(Option::is_some)
+    }
+
+    // like crate::verify, except the opcodes this plugin adds are accepted too
+    pub fn verify(&self, code: &[u32]) -> Result<VerifiedProgram, VerifyError> {
+        let is_extension = |op: u8| op >= OP_COUNT && self.handlers[op as usize].is_some();
+        Program::with_extensions(code.to_vec(), &is_extension)?.verify()
+    }
+
+    // central loop, plugin handlers first, handle!() for the rest
+    #[inline(never)]
+    pub fn run_handlers(&self, program: &VerifiedProgram, args: &[i64]) -> i64 {
+        let code = program.code();
+        let mut regs = seed_regs(args);
+        let mut pc: usize = 0;
+        let mut stack = Stack::default();
+        let mut mem = program.memory();
+
+        loop {
+            let (op, dst, a, b) = exec_one!(code, regs, pc);
+            if let Some(h) = self.handlers[op as usize] {
+                if unsafe { h(regs.as_mut_ptr(), NREGS, dst as u8, a, b) } != 0 {
+                    return -1;
+                }
+                // a plugin can replace HALT, and then the last instruction falls
+                // through, which is the one thing verification counted on not happening
+                if pc == code.len() {
+                    return -1;
+                }
+                continue;
+            }
+            handle!(regs, stack, mem, pc, op, dst, a, b);
+        }
+    }
+
+    // the plugin's own interpreter, if it has one
+    pub fn run_fn(&self) -> Option<impl Fn(&VerifiedProgram, &[i64]) -> i64 + '_> {
+        let run = self.run?;
+        Some(move |program: &VerifiedProgram, args: &[i64]| {
+            assert!(args.len() <= NREGS, "{} arguments but only {NREGS} registers", args.len());
+            // same as c_reference: the plugin gets verified code and nothing else to go on
+            let code = program.code();
+            let mut mem = program.memory();
+            let bits = mem.read_only_bits();
+            let read_only = if bits.is_empty() { std::ptr::null() } else { bits.as_ptr() };
+            let words = mem.words_mut();
+            unsafe { run(code.as_ptr(), args.as_ptr(), args.len(), words.as_mut_ptr(), words.len(), read_only) }
+        })
+    }
+}
@@ -0,0 +1,121 @@
+// Vms to run a program with over and over, without building one per invocation
+//
+// a host serving millions of short guest calls a second spends more on a fresh Vm
+// (the translation, the stacks, allocating and zeroing linear memory) than on running
+// the guest. a VmPool builds them once and hands them out again: `checkout` takes an
+// idle one (or builds one when none is idle) and resets it with the invocation's
+// args, `checkin` puts it back. what makes that cheap is `Vm::reset`, which rewrites
+// only the memory pages the last run wrote, so an invocation that touched one page of
+// a 512 KiB memory pays for one page
+//
+//   let mut pool = VmPool::new(program, DispatchKind::Central)?;
+//   let mut vm = pool.checkout(&[n])?;
+//   let status = vm.resume(FUEL);
+//   pool.checkin(vm);
+//
+// a checked-out Vm is the host's until it comes back, settings included: a trap
+// handler or `set_zero_on_reset(false)` stays set for whoever checks it out next. one
+// that got patched or switched to another dispatch isn't running this program any
+// more, `checkin` drops it instead of keeping it
+
+use crate::{DispatchKind, VerifiedProgram, Vm, VmError};
+
+pub struct VmPool {
+    program: VerifiedProgram,
+    dispatch: DispatchKind,
+    idle: Vec<Vm>,
+    // Vms checkin turned away
+    dropped: u64,
+}
+
+impl VmPool {
+    // fails like `Vm::with_dispatch` when the strategy can't take the program, the one
+    // Vm built to find out is the first idle one
+    pub fn new(program: VerifiedProgram, dispatch: DispatchKind) -> Result<VmPool, VmError> {
+        let first = Vm::with_dispatch(program.clone(), dispatch)?;
+        Ok(VmPool { program, dispatch, idle: vec![first], dropped: 0 })
+    }
+
+    // a Vm reset with `args`, ready to `step` or `resume`. a Vm built here can't fail,
+    // `new` already built one the same way
+    pub fn checkout(&mut self, args: &[i64]) -> Result<Vm, VmError> {
+        let mut vm = match self.idle.pop() {
+            Some(vm) => vm,
+            None => Vm::with_dispatch(self.program.clone(), self.dispatch).expect("built the same way in new"),
+        };
+        if let Err(e) = vm.reset(args) {
+            self.idle.push(vm);
+            return Err(e);
+        }
+        Ok(vm)
+    }
+
+    // `vm` back for the next `checkout`, unless it isn't this program's any more. that
+    // compares the code, a tiny guest's is a few words
+    pub fn checkin(&mut self, vm: Vm) {
+        if vm.dispatch() != self.dispatch || vm.program().code() != self.program.program().code() {
+            self.dropped += 1;
+            return;
+        }
+        self.idle.push(vm);
+    }
+
+    // how many Vms are waiting for a checkout
+    pub fn idle(&self) -> usize {
+        self.idle.len()
+    }
+
+    // how many Vms checkin dropped because they were patched or redispatched
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OP_HALT, OP_LOAD, OP_LOADI, OP_STORE, Program, Status, encode};
+
+    // returns what word r0 held, then stores 7 there: a second run on memory the
+    // first one left would return 7
+    fn leaves_a_mark() -> VerifiedProgram {
+        let code = vec![
+            encode(OP_LOAD, 1, 0, 0),
+            encode(OP_LOADI, 2, 7, 0),
+            encode(OP_STORE, 2, 0, 0),
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        let mut program = Program::new(code).unwrap();
+        program.set_memory_words(4096).unwrap();
+        program.verify().unwrap()
+    }
+
+    #[test]
+    fn checked_out_vms_start_clean_and_get_reused() {
+        let mut pool = VmPool::new(leaves_a_mark(), DispatchKind::Central).unwrap();
+        for at in [0, 1000, 3000, 1000] {
+            let mut vm = pool.checkout(&[at]).unwrap();
+            assert_eq!(vm.resume(100), Status::Halted(0), "word {at} after reuse");
+            assert_eq!(vm.memory()[at as usize], 7);
+            pool.checkin(vm);
+            assert_eq!(pool.idle(), 1);
+        }
+        // two out at once, the second gets built
+        let (a, b) = (pool.checkout(&[0]).unwrap(), pool.checkout(&[0]).unwrap());
+        pool.checkin(a);
+        pool.checkin(b);
+        assert_eq!(pool.idle(), 2);
+    }
+
+    #[test]
+    fn patched_vms_dont_come_back() {
+        let mut pool = VmPool::new(leaves_a_mark(), DispatchKind::Central).unwrap();
+        let mut vm = pool.checkout(&[0]).unwrap();
+        vm.patch(1, encode(OP_LOADI, 2, 9, 0)).unwrap();
+        pool.checkin(vm);
+        assert_eq!((pool.idle(), pool.dropped()), (0, 1));
+        let mut vm = pool.checkout(&[0]).unwrap();
+        vm.resume(100);
+        assert_eq!(vm.memory()[0], 7);
+    }
+}
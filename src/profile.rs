@@ -0,0 +1,249 @@
+// What the program does, before asking how fast each variant does it: `--profile`
+//
+// a variant that's 20% faster on one program and even on another usually differs in
+// the instruction mix, not in anything about dispatch. this runs the program once
+// through run_hooked with a hook that counts, and prints:
+//
+//   - every opcode with how many times it ran, its share of the dynamic instruction
+//     count and a bar, most frequent first
+//   - per branch site (pc), how often it was taken and not taken, the busiest first.
+//     JMP, CALL and RET are always taken, they're in there for the count
+//   - the most frequent opcode pairs and triples in the order they retired, with the
+//     superinstruction a pair would fuse into (fuse.rs) if there's one
+//   - what fusing would save: the dispatches the fixed fuse rules take out of this run,
+//     and how many fuse_guided takes out when it picks its pairs by this profile. the
+//     pair counts per pc (`back_to_back`) are what Program::fused_guided wants, --fused
+//     times the result
+//
+// `--trace` prints every instruction as it retires on top of that (pc, the
+// instruction, what it does if it's a register op, and the registers it changed with
+// their new values), so it's for small runs
+//
+// none of this goes near the benchmark loops: run_hooked is generic over the hook,
+// ProfileHook gets its own copy of the central loop and nothing else changes
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, BufWriter, Write};
+
+use rust_goto::{ExecHook, Instruction, NREGS, Opcode, TrapKind, fuse, run_hooked, semantics};
+
+use crate::Workload;
+
+// branch sites listed, the rest only counted in the totals
+const MAX_SITES: usize = 20;
+// pairs and triples listed
+const MAX_SEQUENCES: usize = 10;
+const BAR_WIDTH: usize = 40;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Site {
+    pub op: u8,
+    pub taken: u64,
+    pub not_taken: u64,
+}
+
+pub struct ProfileHook<'a> {
+    code: &'a [u32],
+    // by opcode number, which goes up to 255 with sparse-opcodes
+    pub counts: [u64; 256],
+    pub instructions: u64,
+    pub sites: BTreeMap<usize, Site>,
+    // opcodes in the order they retired, jumps or not
+    pub pairs: HashMap<[u8; 2], u64>,
+    pub triples: HashMap<[u8; 3], u64>,
+    // back_to_back[pc]: how often pc+1 ran right after pc, what fuse_guided reads
+    pub back_to_back: Vec<u64>,
+    pub trap: Option<(usize, u8, TrapKind)>,
+    // the last two instructions, (pc, op), the most recent second
+    recent: [Option<(usize, u8)>; 2],
+    trace: Option<BufWriter<io::StdoutLock<'static>>>,
+    // the registers before the instruction being traced
+    before: [i64; NREGS],
+}
+
+impl<'a> ProfileHook<'a> {
+    pub fn new(code: &'a [u32], trace: bool) -> Self {
+        ProfileHook {
+            code,
+            counts: [0; 256],
+            instructions: 0,
+            sites: BTreeMap::new(),
+            pairs: HashMap::new(),
+            triples: HashMap::new(),
+            back_to_back: vec![0; code.len()],
+            trap: None,
+            recent: [None; 2],
+            trace: trace.then(|| BufWriter::new(io::stdout().lock())),
+            before: [0; NREGS],
+        }
+    }
+}
+
+fn op_name(op: u8) -> String {
+    Opcode::from_u8(op).map_or(format!("op{op}"), |o| format!("{o:?}").to_uppercase())
+}
+
+impl ExecHook for ProfileHook<'_> {
+    fn pre(&mut self, pc: usize, op: u8, regs: &[i64; NREGS]) {
+        self.instructions += 1;
+        self.counts[op as usize] += 1;
+        if let Some((prev_pc, prev_op)) = self.recent[1] {
+            *self.pairs.entry([prev_op, op]).or_default() += 1;
+            if pc == prev_pc + 1 {
+                self.back_to_back[prev_pc] += 1;
+            }
+            if let Some((_, first_op)) = self.recent[0] {
+                *self.triples.entry([first_op, prev_op, op]).or_default() += 1;
+            }
+        }
+        self.recent = [self.recent[1], Some((pc, op))];
+        if self.trace.is_some() {
+            self.before = *regs;
+        }
+    }
+
+    fn post(&mut self, pc: usize, op: u8, regs: &[i64; NREGS]) {
+        let Some(out) = &mut self.trace else { return };
+        let ins = Instruction::decode(self.code[pc]).expect("verified code decodes");
+        let does = semantics::comment(ins).map_or(String::new(), |c| format!("; {c}"));
+        let mut line = format!("{pc:>6}  {:<10} {:>3} {:>3} {:>3}  {does:<24}", op_name(op), ins.dst, ins.a, ins.b);
+        for (r, (new, old)) in regs.iter().zip(&self.before).enumerate() {
+            if new != old {
+                line += &format!("  r{r} = {new}");
+            }
+        }
+        // a trace cut short by a closed pipe is still a trace
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+
+    fn on_branch(&mut self, from: usize, _to: usize, taken: bool) {
+        let op = self.code[from] as u8;
+        let site = self.sites.entry(from).or_insert(Site { op, ..Site::default() });
+        if taken {
+            site.taken += 1;
+        } else {
+            site.not_taken += 1;
+        }
+    }
+
+    fn on_trap(&mut self, pc: usize, op: u8, trap: TrapKind) {
+        self.trap = Some((pc, op, trap));
+    }
+}
+
+pub fn run(workload: &Workload, trace: bool) {
+    let Workload { description, program, args, .. } = workload;
+    let mut hook = ProfileHook::new(program.code(), trace);
+    let result = run_hooked(program, args, &mut hook);
+    if let Some(mut out) = hook.trace.take() {
+        let _ = out.flush();
+    }
+
+    println!("Profile: {description}");
+    match hook.trap {
+        Some((pc, op, trap)) => println!("trapped at pc {pc} ({}): {trap:?}", op_name(op)),
+        None => println!("result = {result}"),
+    }
+    let (taken, not_taken) = hook.sites.values().fold((0, 0), |(t, n), s| (t + s.taken, n + s.not_taken));
+    println!("{} instructions, {taken} branches taken, {not_taken} not taken\n", hook.instructions);
+
+    let mut ops: Vec<(u8, u64)> =
+        hook.counts.iter().enumerate().filter(|(_, c)| **c > 0).map(|(op, &c)| (op as u8, c)).collect();
+    ops.sort_by_key(|&(op, c)| (std::cmp::Reverse(c), op));
+    let max = ops.first().map_or(1, |o| o.1);
+    println!("{:<10} {:>14} {:>7}", "opcode", "count", "share");
+    for (op, count) in ops {
+        let share = 100.0 * count as f64 / hook.instructions as f64;
+        let bar = "#".repeat(((count as f64 / max as f64) * BAR_WIDTH as f64).ceil() as usize);
+        println!("{:<10} {count:>14} {share:>6.1}%  {bar}", op_name(op));
+    }
+
+    print_sequences("pair", &hook.pairs, hook.instructions, |&[a, b]| {
+        let fused = fuse::superinstruction(a, b).map_or(String::new(), |f| format!("  fuses into {}", op_name(f)));
+        (format!("{} {}", op_name(a), op_name(b)), fused)
+    });
+    print_sequences("triple", &hook.triples, hook.instructions, |&[a, b, c]| {
+        (format!("{} {} {}", op_name(a), op_name(b), op_name(c)), String::new())
+    });
+    print_fusing(program.code(), &hook);
+
+    if hook.sites.is_empty() {
+        return;
+    }
+    let mut sites: Vec<(usize, Site)> = hook.sites.into_iter().collect();
+    sites.sort_by_key(|(pc, s)| (std::cmp::Reverse(s.taken + s.not_taken), *pc));
+    println!("\n{:>6}  {:<10} {:>14} {:>14} {:>7}", "pc", "branch", "taken", "not taken", "taken%");
+    for (pc, site) in sites.iter().take(MAX_SITES) {
+        let share = 100.0 * site.taken as f64 / (site.taken + site.not_taken) as f64;
+        println!("{pc:>6}  {:<10} {:>14} {:>14} {share:>6.1}%", op_name(site.op), site.taken, site.not_taken);
+    }
+    if sites.len() > MAX_SITES {
+        println!("... and {} more sites", sites.len() - MAX_SITES);
+    }
+}
+
+// the MAX_SEQUENCES most frequent, `describe` gives the opcodes and a note
+fn print_sequences<K: Ord>(
+    what: &str,
+    counts: &HashMap<K, u64>,
+    instructions: u64,
+    describe: impl Fn(&K) -> (String, String),
+) {
+    if counts.is_empty() {
+        return;
+    }
+    let mut top: Vec<(&K, u64)> = counts.iter().map(|(k, &c)| (k, c)).collect();
+    top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    println!("\n{:<26} {:>14} {:>7}", format!("opcode {what}"), "count", "share");
+    for (key, count) in top.iter().take(MAX_SEQUENCES) {
+        let (ops, note) = describe(key);
+        let share = 100.0 * *count as f64 / instructions as f64;
+        println!("{ops:<26} {count:>14} {share:>6.1}%{note}");
+    }
+    if top.len() > MAX_SEQUENCES {
+        println!("... and {} more", top.len() - MAX_SEQUENCES);
+    }
+}
+
+// dispatches the fused code would save in this run: a fused word runs both halves in one
+// dispatch every time it runs, and its first half never jumps, so that's how often the
+// pair ran back to back
+fn print_fusing(code: &[u32], hook: &ProfileHook) {
+    let saved = |fused: &[u32]| -> u64 {
+        let newly_fused = |pc: &usize| fuse::halves(fused[*pc]).is_some() && fuse::halves(code[*pc]).is_none();
+        (0..code.len()).filter(newly_fused).map(|pc| hook.back_to_back[pc]).sum()
+    };
+    let fixed = saved(&fuse::fuse(code));
+    let guided = saved(&fuse::fuse_guided(code, &hook.back_to_back));
+    let percent = |n: u64| 100.0 * n as f64 / hook.instructions.max(1) as f64;
+    println!(
+        "\nfusing saves {fixed} dispatches ({:.1}%) with the fixed rules, {guided} ({:.1}%) guided by this profile",
+        percent(fixed),
+        percent(guided)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_goto::{OP_DEC, OP_JMPNZ, OP_MOV, OP_MUL, OP_SUB, kernels, verify};
+
+    #[test]
+    fn counts_add_up_to_the_run() {
+        let program = verify(&kernels::sum_poly()).unwrap();
+        let mut hook = ProfileHook::new(program.code(), false);
+        assert_eq!(run_hooked(&program, &[10], &mut hook), (1..=10).map(|i| i * i - i + 1).sum());
+        // two LOADIs, ten trips through seven instructions, HALT
+        assert_eq!(hook.instructions, 2 + 10 * 7 + 1);
+        assert_eq!(hook.counts.iter().sum::<u64>(), hook.instructions);
+        assert_eq!((hook.counts[OP_MUL as usize], hook.counts[OP_DEC as usize]), (10, 10));
+        assert_eq!(hook.sites.len(), 1);
+        assert_eq!(hook.sites[&8], Site { op: OP_JMPNZ, taken: 9, not_taken: 1 });
+        assert!(hook.trap.is_none());
+        // the loop body as pairs, and every pc of it ran back to back with the next one
+        // each time around, except the JMPNZ: that falls through to the HALT once
+        assert_eq!((hook.pairs[&[OP_MUL, OP_SUB]], hook.pairs[&[OP_JMPNZ, OP_MOV]]), (10, 9));
+        assert_eq!(hook.triples.values().sum::<u64>(), hook.instructions - 2);
+        assert_eq!(hook.back_to_back[2..9], [10, 10, 10, 10, 10, 10, 1]);
+    }
+}
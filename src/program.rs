@@ -0,0 +1,456 @@
+// A program plus what tools want to know about it
+//
+// the variants run the lowered code of a VerifiedProgram (verify.rs), that's what
+// gets benchmarked. everything public (Vm, and whatever linker/optimizer/debugger
+// comes later) goes through Program, which carries:
+//
+//   entry           pc of the first instruction, 0 unless said otherwise
+//   nregs_required  one past the highest register the code touches, computed
+//   features        optional ISA features the code relies on, by name, so a host
+//                   without them refuses the program instead of misrunning it.
+//                   inferred from the opcodes, see SUPPORTED_FEATURES
+//   names           labels for code addresses, for disassemblers and debuggers
+//   lines           the source line each instruction came from, when it came from
+//                   source (the assembler), for backtraces
+//   memory_words    size of the linear memory LOAD/STORE work on, in i64 words
+//   segments        data in that memory from the start of every run, read-only or
+//                   not (see memory.rs)
+//   fall_off_end    what running past the last instruction does, see FallOffEnd
+//
+// construction checks every instruction decodes and only names registers that exist.
+// whether control flow stays inside the code is the verifier's business, not this
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::memory::Segment;
+use crate::{
+    Instruction, NREGS, OP_CALL, OP_HALT, OP_JMPNZ, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_POP, OP_PUSH,
+    OP_RESOLVE_CALL, OP_RET, OP_STORE, OP_TRAP, OP_WAITEVENT, RET_REG, encode, fuse, has_target,
+};
+
+// linear memory a program gets unless it asks otherwise (512 KiB), and the most it
+// can ask for (128 MiB). it's allocated zeroed for every run, only if the code has a
+// LOAD, STORE, MEMCPY or MEMSET at all
+pub const DEFAULT_MEMORY_WORDS: usize = 1 << 16;
+pub const MAX_MEMORY_WORDS: usize = 1 << 24;
+
+// ISA features this build can run, on top of the plain register machine:
+//
+//   calls   CALL/RET/PUSH/POP, so a call stack
+//   memory  LOAD/STORE/MEMCPY/MEMSET, so a linear memory gets allocated for every run
+//   events  WAITEVENT, which needs a host posting events (Vm::post_event, Vm::resume).
+//           the run-to-completion variants have nobody to wait for and stop with -1
+//   traps   TRAP, which calls the host's handler (Vm::set_trap_handler) from Vm::step
+//           and Vm::resume, and RESOLVE_CALL, which becomes one (Vm::set_resolver).
+//           everywhere else there's no handler and they stop with -1
+pub const SUPPORTED_FEATURES: &[&str] = &["calls", "memory", "events", "traps"];
+
+fn feature_of(op: u8) -> Option<&'static str> {
+    match op {
+        OP_CALL | OP_RET | OP_PUSH | OP_POP => Some("calls"),
+        OP_LOAD | OP_STORE | OP_MEMCPY | OP_MEMSET => Some("memory"),
+        OP_WAITEVENT => Some("events"),
+        OP_TRAP | OP_RESOLVE_CALL => Some("traps"),
+        _ => None,
+    }
+}
+
+// how far `lowered` moves everything for a non-zero entry
+const SHIFT: usize = 2;
+
+// what happens when execution gets past the last instruction. Reject is what the
+// verifier always did: the last instruction has to be HALT, RET or JMP, so it can't
+// happen. with the other two any program verifies and the lowered code gets one more
+// word, the same for every variant since they all run that code:
+//
+//   Halt  HALT r0, so a program can end by running out of instructions
+//   Trap  a word no variant has a handler for, so the run stops with -1 like any other
+//         trap, TrapKind::FellOffEnd to a hook and Vm::step
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FallOffEnd {
+    #[default]
+    Reject,
+    Halt,
+    Trap,
+}
+
+impl FallOffEnd {
+    pub const ALL: &[FallOffEnd] = &[FallOffEnd::Reject, FallOffEnd::Halt, FallOffEnd::Trap];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FallOffEnd::Reject => "reject",
+            FallOffEnd::Halt => "halt",
+            FallOffEnd::Trap => "trap",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramError {
+    Empty,
+    BadOpcode { pc: usize, op: u8 },
+    BadRegister { pc: usize, reg: u8 },
+    EntryOutOfRange { entry: usize, len: usize },
+    // a non-zero entry needs one register the code doesn't use, see `lowered`
+    NoFreeRegister,
+    // the trampoline shifts the entry and every target by 2, they have to stay 16-bit
+    EntryTooFar { entry: usize },
+    ShiftedTargetTooFar { pc: usize, target: usize },
+    NameOutOfRange { pc: usize, len: usize },
+    UnsupportedFeature(String),
+    MemoryTooLarge { words: usize },
+    SegmentOutOfRange { start: usize, len: usize, memory_words: usize },
+    SegmentsOverlap { start: usize, other: usize },
+    PatchOutOfRange { pc: usize, len: usize },
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramError::Empty => write!(f, "program has no instructions"),
+            ProgramError::BadOpcode { pc, op } => write!(f, "pc {pc}: unknown opcode {op:#04x}"),
+            ProgramError::BadRegister { pc, reg } => write!(f, "pc {pc}: register r{reg} (there are {NREGS})"),
+            ProgramError::EntryOutOfRange { entry, len } => write!(f, "entry {entry} is past the end ({len} instructions)"),
+            ProgramError::NoFreeRegister => {
+                write!(f, "a non-zero entry needs a register the program doesn't use, it uses all {NREGS}")
+            }
+            ProgramError::EntryTooFar { entry } => {
+                let most = u16::MAX as usize - SHIFT;
+                write!(f, "entry {entry} is too far to jump to, the trampoline needs it at {most} at most")
+            }
+            ProgramError::ShiftedTargetTooFar { pc, target } => {
+                write!(f, "pc {pc}: target {target} doesn't fit in 16 bits once shifted for a non-zero entry")
+            }
+            ProgramError::NameOutOfRange { pc, len } => write!(f, "name for pc {pc} is past the end ({len} instructions)"),
+            ProgramError::UnsupportedFeature(name) => write!(f, "program needs ISA feature `{name}`, not supported here"),
+            ProgramError::MemoryTooLarge { words } => {
+                write!(f, "{words} words of memory asked for, the most is {MAX_MEMORY_WORDS}")
+            }
+            ProgramError::SegmentOutOfRange { start, len, memory_words } => {
+                write!(f, "{len} words of data at {start} don't fit in {memory_words} words of memory")
+            }
+            ProgramError::SegmentsOverlap { start, other } => {
+                write!(f, "data at {start} overlaps the data at {other}")
+            }
+            ProgramError::PatchOutOfRange { pc, len } => {
+                write!(f, "can't patch pc {pc}, past the end ({len} instructions)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProgramError {}
+
+// whether `word` decodes and only names registers that exist, and if so one past the
+// highest register it touches
+fn check_word(pc: usize, word: u32) -> Result<usize, ProgramError> {
+    let op = (word & 0xFF) as u8;
+    let ins = Instruction::decode(word).ok_or(ProgramError::BadOpcode { pc, op })?;
+    // a superinstruction uses the registers of both halves of its pair
+    let plain = match fuse::halves(word) {
+        Some([first, second]) => [Instruction::decode(first), Instruction::decode(second)],
+        None => [Some(ins), None],
+    };
+    let mut nregs = 0;
+    for ins in plain.into_iter().flatten() {
+        let (uses_dst, uses_a, uses_b) = ins.op.register_operands();
+        let regs = [uses_dst.then_some(ins.dst), uses_a.then_some(ins.a), uses_b.then_some(ins.b)];
+        for reg in regs.into_iter().flatten() {
+            if reg as usize >= NREGS {
+                return Err(ProgramError::BadRegister { pc, reg });
+            }
+            nregs = nregs.max(reg as usize + 1);
+        }
+    }
+    Ok(nregs)
+}
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    code: Vec<u32>,
+    entry: usize,
+    nregs_required: usize,
+    features: Vec<String>,
+    names: BTreeMap<usize, String>,
+    lines: BTreeMap<usize, usize>,
+    memory_words: usize,
+    segments: Vec<Segment>,
+    fall_off_end: FallOffEnd,
+}
+
+impl Program {
+    pub fn new(code: Vec<u32>) -> Result<Program, ProgramError> {
+        Program::checked(code, &|_| false)
+    }
+
+    // opcodes a plugin handles on top of the built-in ones. their operands mean
+    // whatever the plugin says, so nothing gets checked there
+    #[cfg(feature = "plugins")]
+    pub(crate) fn with_extensions(code: Vec<u32>, is_extension: &dyn Fn(u8) -> bool) -> Result<Program, ProgramError> {
+        Program::checked(code, is_extension)
+    }
+
+    fn checked(code: Vec<u32>, is_extension: &dyn Fn(u8) -> bool) -> Result<Program, ProgramError> {
+        if code.is_empty() {
+            return Err(ProgramError::Empty);
+        }
+        let mut nregs_required = 0;
+        let mut features: Vec<String> = Vec::new();
+        for (pc, &word) in code.iter().enumerate() {
+            let op = (word & 0xFF) as u8;
+            if is_extension(op) {
+                continue;
+            }
+            if let Some(name) = feature_of(op)
+                && !features.iter().any(|f| f == name)
+            {
+                features.push(name.to_string());
+            }
+            nregs_required = nregs_required.max(check_word(pc, word)?);
+        }
+        Ok(Program {
+            code,
+            entry: 0,
+            nregs_required,
+            features,
+            names: BTreeMap::new(),
+            lines: BTreeMap::new(),
+            memory_words: DEFAULT_MEMORY_WORDS,
+            segments: Vec::new(),
+            fall_off_end: FallOffEnd::Reject,
+        })
+    }
+
+    pub fn code(&self) -> &[u32] {
+        &self.code
+    }
+
+    pub fn entry(&self) -> usize {
+        self.entry
+    }
+
+    pub fn nregs_required(&self) -> usize {
+        self.nregs_required
+    }
+
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.features.iter().any(|f| f == name)
+    }
+
+    pub fn names(&self) -> &BTreeMap<usize, String> {
+        &self.names
+    }
+
+    pub fn memory_words(&self) -> usize {
+        self.memory_words
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn fall_off_end(&self) -> FallOffEnd {
+        self.fall_off_end
+    }
+
+    pub fn name_at(&self, pc: usize) -> Option<&str> {
+        self.names.get(&pc).map(String::as_str)
+    }
+
+    pub fn pc_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().find(|(_, n)| *n == name).map(|(&pc, _)| pc)
+    }
+
+    // the name at or before `pc` and how far past it `pc` is, the function it's in as
+    // far as the names can tell
+    pub fn symbolize(&self, pc: usize) -> Option<(&str, usize)> {
+        self.names.range(..=pc).next_back().map(|(&at, name)| (name.as_str(), pc - at))
+    }
+
+    pub fn line_at(&self, pc: usize) -> Option<usize> {
+        self.lines.get(&pc).copied()
+    }
+
+    pub fn set_entry(&mut self, entry: usize) -> Result<(), ProgramError> {
+        if entry >= self.code.len() {
+            return Err(ProgramError::EntryOutOfRange { entry, len: self.code.len() });
+        }
+        if entry != 0 {
+            if self.nregs_required >= NREGS {
+                return Err(ProgramError::NoFreeRegister);
+            }
+            self.check_shift(entry)?;
+        }
+        self.entry = entry;
+        Ok(())
+    }
+
+    // `shifted` has to be able to jump to `entry` and keep every target 16-bit
+    fn check_shift(&self, entry: usize) -> Result<(), ProgramError> {
+        if entry + SHIFT > u16::MAX as usize {
+            return Err(ProgramError::EntryTooFar { entry });
+        }
+        for (pc, &word) in self.code.iter().enumerate() {
+            let target = (word >> 16) as usize;
+            if has_target((word & 0xFF) as u8) && target + SHIFT > u16::MAX as usize {
+                return Err(ProgramError::ShiftedTargetTooFar { pc, target });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_name(&mut self, pc: usize, name: impl Into<String>) -> Result<(), ProgramError> {
+        if pc >= self.code.len() {
+            return Err(ProgramError::NameOutOfRange { pc, len: self.code.len() });
+        }
+        self.names.insert(pc, name.into());
+        Ok(())
+    }
+
+    // for the front ends, which know a line for every pc they emit
+    pub(crate) fn set_line(&mut self, pc: usize, line: usize) {
+        assert!(pc < self.code.len(), "line for pc {pc} past the end");
+        self.lines.insert(pc, line);
+    }
+
+    pub fn set_memory_words(&mut self, words: usize) -> Result<(), ProgramError> {
+        if words > MAX_MEMORY_WORDS {
+            return Err(ProgramError::MemoryTooLarge { words });
+        }
+        if let Some(s) = self.segments.iter().find(|s| s.range().end > words) {
+            return Err(ProgramError::SegmentOutOfRange { start: s.start, len: s.words.len(), memory_words: words });
+        }
+        self.memory_words = words;
+        Ok(())
+    }
+
+    pub fn set_fall_off_end(&mut self, policy: FallOffEnd) {
+        self.fall_off_end = policy;
+    }
+
+    // `words` in memory from word address `start` on at the start of every run. a
+    // read-only segment can be read but not written, see memory.rs
+    pub fn add_segment(&mut self, start: usize, words: Vec<i64>, read_only: bool) -> Result<(), ProgramError> {
+        if start.checked_add(words.len()).is_none_or(|end| end > self.memory_words) {
+            let (len, memory_words) = (words.len(), self.memory_words);
+            return Err(ProgramError::SegmentOutOfRange { start, len, memory_words });
+        }
+        let segment = Segment { start, words, read_only };
+        let range = segment.range();
+        if let Some(other) = self.segments.iter().find(|s| s.range().start < range.end && range.start < s.range().end) {
+            return Err(ProgramError::SegmentsOverlap { start, other: other.start });
+        }
+        self.segments.push(segment);
+        Ok(())
+    }
+
+    // replaces the instruction at `pc` with `word`, checked like everything Program::new
+    // takes, and returns the one it replaced. the registers and features the program
+    // needs only ever grow: a patch that drops the last LOAD still gets memory
+    // allocated, which is harmless, and patching it back in doesn't have to find out.
+    // this is the raw edit, a Vm patches through VerifiedProgram::patch so that the
+    // control flow gets checked again and what it translated follows along
+    pub fn patch(&mut self, pc: usize, word: u32) -> Result<u32, ProgramError> {
+        let len = self.code.len();
+        if pc >= len {
+            return Err(ProgramError::PatchOutOfRange { pc, len });
+        }
+        let nregs = check_word(pc, word)?;
+        let op = (word & 0xFF) as u8;
+        let target = (word >> 16) as usize;
+        if self.entry != 0 && has_target(op) && target + SHIFT > u16::MAX as usize {
+            return Err(ProgramError::ShiftedTargetTooFar { pc, target });
+        }
+        if let Some(name) = feature_of(op) {
+            self.require_feature(name)?;
+        }
+        self.nregs_required = self.nregs_required.max(nregs);
+        Ok(std::mem::replace(&mut self.code[pc], word))
+    }
+
+    // where `pc` ends up in `lowered` and what the word there is
+    pub(crate) fn lowered_at(&self, pc: usize) -> (usize, u32) {
+        let word = self.code[pc];
+        if self.entry == 0 {
+            return (pc, word);
+        }
+        (pc + SHIFT, shift_target(word))
+    }
+
+    // the same program with superinstructions (fuse.rs). pcs don't move, so the entry,
+    // the names, the memory and the features all carry over as they are
+    pub fn fused(&self) -> Program {
+        Program { code: fuse::fuse(&self.code), ..self.clone() }
+    }
+
+    // the same, fusing only the pairs a profile saw run back to back, hottest first
+    // (fuse::fuse_guided)
+    pub fn fused_guided(&self, back_to_back: &[u64]) -> Program {
+        Program { code: fuse::fuse_guided(&self.code, back_to_back), ..self.clone() }
+    }
+
+    // on top of the inferred ones, e.g. a host that wants "memory" allocated even
+    // though the code itself doesn't LOAD/STORE
+    pub fn require_feature(&mut self, name: &str) -> Result<(), ProgramError> {
+        if !SUPPORTED_FEATURES.contains(&name) {
+            return Err(ProgramError::UnsupportedFeature(name.to_string()));
+        }
+        if !self.has_feature(name) {
+            self.features.push(name.to_string());
+        }
+        Ok(())
+    }
+
+    // the code as the variants want it, starting at pc 0. with a non-zero entry that
+    // means a 2-instruction trampoline in front (LOADI 1 into a register the code
+    // never touches, JMPNZ on it) and every jump and call target shifted by 2.
+    // set_entry made sure all of those still fit in 16 bits
+    pub fn lowered(&self) -> Vec<u32> {
+        if self.entry == 0 {
+            return self.code.clone();
+        }
+        let tmp = (NREGS - 1) as u8;
+        let target = (self.entry + SHIFT) as u16;
+        self.shifted([encode(OP_LOADI, tmp, 1, 0), encode(OP_JMPNZ, tmp, target as u8, (target >> 8) as u8)])
+    }
+
+    // the same code behind a CALL to `pc` and a HALT on whatever the callee left in
+    // ret, so a host can run a subroutine as if it were a program (Vm::call). the
+    // stub starts at pc 0 whatever the entry is, and needs no free register
+    pub(crate) fn call_stub(&self, pc: usize) -> Result<Program, ProgramError> {
+        self.check_shift(pc)?;
+        let target = (pc + SHIFT) as u16;
+        let call = encode(OP_CALL, 0, target as u8, (target >> 8) as u8);
+        let code = self.shifted([call, encode(OP_HALT, RET_REG, 0, 0)]);
+        let mut stub = Program::new(code)?;
+        stub.memory_words = self.memory_words;
+        stub.segments = self.segments.clone();
+        stub.fall_off_end = self.fall_off_end;
+        for feature in &self.features {
+            stub.require_feature(feature)?;
+        }
+        Ok(stub)
+    }
+
+    fn shifted(&self, prologue: [u32; SHIFT]) -> Vec<u32> {
+        let mut out = Vec::with_capacity(self.code.len() + SHIFT);
+        out.extend(prologue);
+        out.extend(self.code.iter().map(|&word| shift_target(word)));
+        out
+    }
+}
+
+// `word` as it reads SHIFT instructions further down, its target moved along
+fn shift_target(word: u32) -> u32 {
+    if !has_target((word & 0xFF) as u8) {
+        return word;
+    }
+    let new = (word >> 16) as u16 + SHIFT as u16;
+    (word & 0xFFFF) | ((new as u32) << 16)
+}
@@ -0,0 +1,281 @@
+// What the default benchmark does with its numbers: statistics over the samples of
+// every row, `--output json|csv` for machines, `--baseline <file>` to diff against a
+// saved run
+//
+// a row's time budget is split into SAMPLES batches. a batch is one sample of ns/iter,
+// the row reports their median (what gets compared) plus the mean and stddev of the
+// samples left after rejecting outliers (Tukey's fences, 1.5 IQR past the quartiles:
+// a batch that got preempted or migrated shouldn't widen the spread of all the others)
+//
+// the json and csv documents hold the same rows, either one can be read back as a
+// baseline. a row is a regression when its median is slower than the baseline's by
+// more than REGRESSION_THRESHOLD *and* by more than the noise of the two runs
+// (NOISE_SIGMAS combined standard deviations), so a noisy row doesn't fail CI on
+// every other run
+
+use std::fmt::Write;
+
+use rust_goto::json::{JsonValue, json_string, parse_json};
+
+// batches per row
+pub const SAMPLES: u64 = 30;
+
+const TUKEY_K: f64 = 1.5;
+const REGRESSION_THRESHOLD: f64 = 0.03;
+const NOISE_SIGMAS: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median: f64,
+    // over the samples that aren't outliers
+    pub mean: f64,
+    pub stddev: f64,
+    // over all of them
+    pub min: f64,
+    pub max: f64,
+    pub samples: usize,
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn of(samples: &[f64]) -> Stats {
+        assert!(!samples.is_empty(), "statistics of no samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len();
+        let median = if n % 2 == 1 { sorted[n / 2] } else { (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0 };
+        let (q1, q3) = (sorted[n / 4], sorted[(3 * n) / 4]);
+        let (low, high) = (q1 - TUKEY_K * (q3 - q1), q3 + TUKEY_K * (q3 - q1));
+        let kept: Vec<f64> = sorted.iter().copied().filter(|&s| low <= s && s <= high).collect();
+        let mean = kept.iter().sum::<f64>() / kept.len() as f64;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / (kept.len() - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            median,
+            mean,
+            stddev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[n - 1],
+            samples: n,
+            outliers: n - kept.len(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!("unknown output format `{other}`, expected text, json or csv")),
+        }
+    }
+}
+
+// one line of the benchmark: a variant, an external tool or a plugin row
+pub struct Row {
+    pub label: String,
+    pub result: i64,
+    pub iters: u64,
+    pub stats: Stats,
+    // ns/iter of every batch
+    pub samples: Vec<f64>,
+}
+
+pub fn to_json(program: &str, instructions: u64, rows: &[Row]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{{").unwrap();
+    writeln!(out, "  \"program\": {},", json_string(program)).unwrap();
+    writeln!(out, "  \"instructions\": {instructions},").unwrap();
+    writeln!(out, "  \"results\": [").unwrap();
+    for (i, row) in rows.iter().enumerate() {
+        let Stats { median, mean, stddev, min, max, samples, outliers } = row.stats;
+        write!(
+            out,
+            "    {{\"label\": {}, \"result\": {}, \"iters\": {}, \"median_ns\": {median:.3}, \"mean_ns\": {mean:.3}, \
+             \"stddev_ns\": {stddev:.3}, \"min_ns\": {min:.3}, \"max_ns\": {max:.3}, \"samples\": {samples}, \
+             \"outliers\": {outliers}}}",
+            json_string(&row.label),
+            row.result,
+            row.iters
+        )
+        .unwrap();
+        out.push_str(if i + 1 < rows.len() { ",\n" } else { "\n" });
+    }
+    out.push_str("  ]\n}\n");
+    out
+}
+
+const CSV_HEADER: &str = "label,result,iters,median_ns,mean_ns,stddev_ns,min_ns,max_ns,samples,outliers";
+
+pub fn to_csv(rows: &[Row]) -> String {
+    let mut out = format!("{CSV_HEADER}\n");
+    for row in rows {
+        let Stats { median, mean, stddev, min, max, samples, outliers } = row.stats;
+        let label = if row.label.contains([',', '"']) {
+            format!("\"{}\"", row.label.replace('"', "\"\""))
+        } else {
+            row.label.clone()
+        };
+        writeln!(
+            out,
+            "{label},{},{},{median:.3},{mean:.3},{stddev:.3},{min:.3},{max:.3},{samples},{outliers}",
+            row.result, row.iters
+        )
+        .unwrap();
+    }
+    out
+}
+
+// (label, median, stddev) of every row of a saved run, json or csv
+pub struct Baseline {
+    pub path: String,
+    rows: Vec<(String, f64, f64)>,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Baseline, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+        let rows = if text.trim_start().starts_with('{') { parse_json_rows(&text) } else { parse_csv_rows(&text) };
+        let rows = rows.map_err(|e| format!("{path}: {e}"))?;
+        Ok(Baseline { path: path.to_string(), rows })
+    }
+
+    // a line per row found in both runs, and how many of them regressed
+    pub fn compare(&self, rows: &[Row]) -> (Vec<String>, usize) {
+        let mut lines = Vec::new();
+        let mut regressions = 0;
+        for row in rows {
+            let Some(&(_, old, old_sd)) = self.rows.iter().find(|(label, _, _)| *label == row.label) else {
+                lines.push(format!("{:>24}: not in the baseline", row.label));
+                continue;
+            };
+            let new = row.stats.median;
+            let change = (new - old) / old;
+            let noise = NOISE_SIGMAS * (old_sd * old_sd + row.stats.stddev * row.stats.stddev).sqrt();
+            let verdict = if (new - old).abs() <= noise || change.abs() <= REGRESSION_THRESHOLD {
+                "within noise"
+            } else if change > 0.0 {
+                regressions += 1;
+                "REGRESSION"
+            } else {
+                "faster"
+            };
+            lines.push(format!(
+                "{:>24}: {old:8.1} -> {new:8.1} ns/iter  {:+6.1}%  {verdict}",
+                row.label,
+                change * 100.0
+            ));
+        }
+        (lines, regressions)
+    }
+}
+
+fn parse_csv_rows(text: &str) -> Result<Vec<(String, f64, f64)>, String> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header: Vec<&str> = lines.next().ok_or("empty file")?.split(',').collect();
+    let column = |name: &str| header.iter().position(|&h| h == name).ok_or(format!("no `{name}` column"));
+    let (label, median, stddev) = (column("label")?, column("median_ns")?, column("stddev_ns")?);
+    lines
+        .map(|line| {
+            let fields = split_csv(line);
+            let field = |i: usize| fields.get(i).ok_or(format!("short line `{line}`"));
+            let number = |i: usize| field(i)?.parse::<f64>().map_err(|_| format!("bad number in `{line}`"));
+            Ok((field(label)?.clone(), number(median)?, number(stddev)?))
+        })
+        .collect()
+}
+
+// fields of one csv line, "quoted" ones may hold commas and "" for a quote
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn parse_json_rows(text: &str) -> Result<Vec<(String, f64, f64)>, String> {
+    let doc = parse_json(text)?;
+    let results = match doc.get("results") {
+        Some(JsonValue::Array(results)) => results,
+        _ => return Err("no `results` array".to_string()),
+    };
+    results
+        .iter()
+        .map(|r| match (r.get("label"), r.get("median_ns"), r.get("stddev_ns")) {
+            (Some(JsonValue::Str(label)), Some(&JsonValue::Num(median)), Some(&JsonValue::Num(sd))) => {
+                Ok((label.clone(), median, sd))
+            }
+            _ => Err("a result without label, median_ns and stddev_ns".to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(label: &str, samples: &[f64]) -> Row {
+        let (label, samples) = (label.to_string(), samples.to_vec());
+        Row { label, result: 42, iters: 1000, stats: Stats::of(&samples), samples }
+    }
+
+    #[test]
+    fn outliers_dont_move_the_spread() {
+        let s = Stats::of(&[10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 55.0]);
+        assert_eq!((s.median, s.outliers, s.samples, s.max), (10.0, 1, 7, 55.0));
+        assert!((s.mean - 10.0).abs() < 1e-9 && s.stddev < 0.2, "{s:?}");
+        assert_eq!(Stats::of(&[3.0, 1.0]).median, 2.0);
+    }
+
+    #[test]
+    fn json_and_csv_read_back_as_baselines() {
+        let rows = [row("central", &[10.0, 10.5, 9.5]), row("odd, \"label\"", &[20.0])];
+        let json = parse_json_rows(&to_json("sum \"poly\"\n", 7003, &rows)).unwrap();
+        let csv = parse_csv_rows(&to_csv(&rows)).unwrap();
+        assert_eq!(json, csv);
+        assert_eq!(json, [("central".to_string(), 10.0, 0.5), ("odd, \"label\"".to_string(), 20.0, 0.0)]);
+        assert!(parse_json_rows("{\"results\": [{\"label\": 1}]}").is_err());
+        assert!(parse_csv_rows("label,median_ns\nx,1").unwrap_err().contains("stddev_ns"));
+    }
+
+    #[test]
+    fn flags_regressions_beyond_the_noise() {
+        let baseline = Baseline {
+            path: String::new(),
+            rows: vec![("slower".into(), 100.0, 0.5), ("noisy".into(), 100.0, 20.0), ("faster".into(), 100.0, 0.5)],
+        };
+        let rows = [
+            row("slower", &[110.0, 110.5, 109.5]),
+            row("noisy", &[110.0, 110.5, 109.5]),
+            row("faster", &[90.0]),
+            row("new", &[1.0]),
+        ];
+        let (lines, regressions) = baseline.compare(&rows);
+        assert_eq!(regressions, 1);
+        assert!(lines[0].ends_with("REGRESSION") && lines[1].ends_with("within noise"), "{lines:?}");
+        assert!(lines[2].ends_with("faster") && lines[3].ends_with("not in the baseline"), "{lines:?}");
+    }
+}
@@ -0,0 +1,337 @@
+// Multi-tenant scheduling: many guests on one host thread, each a Vm stepped with
+// Vm::resume, interleaved by weighted round-robin with per-tenant fuel accounting
+//
+// a host running untrusted guests for several customers needs three things from
+// this: a guest can't hog the thread (it only ever runs for the fuel it's handed),
+// the split is fair by weight (a weight 3 tenant gets three times the instructions of
+// a weight 1 tenant while both are busy), and every instruction is billed to the
+// tenant that ran it so the host can charge for it or cut it off
+//
+// the split is deficit round-robin: every round, each runnable tenant gets QUANTUM *
+// weight of credit and runs until it's spent, whatever it didn't use is kept for the
+// next round. a tenant that has nothing to do (halted, waiting for an event, out of
+// budget) loses its credit, so idling can't be banked into a burst that starves
+// everyone else later. a round visits every tenant, so each one that can run does, at
+// least QUANTUM * weight instructions per round
+//
+// the budget is the billing side: an optional cap on the instructions a tenant may
+// retire in total, topped up with `grant`. a tenant that reaches it is Throttled
+// until it gets more, the others keep going
+
+use std::fmt;
+
+use crate::{Status, Vm};
+
+// instructions per unit of weight per round
+pub const DEFAULT_QUANTUM: u64 = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TenantId(usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TenantState {
+    Runnable,
+    // on a WAITEVENT with nothing posted, see Scheduler::post_event
+    Waiting,
+    // spent its whole budget, see Scheduler::grant
+    Throttled,
+    // on a TRAP its handler broke on (the TRAP's immediate), see Scheduler::release
+    Break(u16),
+    Halted(i64),
+}
+
+// per-tenant accounting, since the tenant was added
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TenantStats {
+    // instructions run on behalf of the tenant, what it gets billed for
+    pub retired: u64,
+    // times it was given the thread and ran at least one instruction
+    pub slices: u64,
+    // rounds it couldn't run because it was waiting, throttled or stopped on a break
+    pub idle_rounds: u64,
+    // times it ran into its budget
+    pub throttled: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchedulerError {
+    NoSuchTenant(TenantId),
+    // a tenant needs a weight of at least 1 to ever get the thread
+    ZeroWeight,
+}
+
+impl fmt::Display for SchedulerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulerError::NoSuchTenant(TenantId(id)) => write!(f, "no tenant #{id}"),
+            SchedulerError::ZeroWeight => write!(f, "a tenant's weight has to be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerError {}
+
+struct Tenant {
+    vm: Vm,
+    weight: u64,
+    // fuel it may still spend, None for unlimited
+    budget: Option<u64>,
+    credit: u64,
+    state: TenantState,
+    stats: TenantStats,
+}
+
+pub struct Scheduler {
+    quantum: u64,
+    tenants: Vec<Tenant>,
+    rounds: u64,
+}
+
+impl Default for Scheduler {
+    fn default() -> Scheduler {
+        Scheduler::new(DEFAULT_QUANTUM)
+    }
+}
+
+impl Scheduler {
+    pub fn new(quantum: u64) -> Scheduler {
+        Scheduler { quantum: quantum.max(1), tenants: Vec::new(), rounds: 0 }
+    }
+
+    // `vm` runs from wherever it is now (reset it first to start from the entry point),
+    // `budget` caps what it may retire in total, None for no cap
+    pub fn add(&mut self, vm: Vm, weight: u64, budget: Option<u64>) -> Result<TenantId, SchedulerError> {
+        if weight == 0 {
+            return Err(SchedulerError::ZeroWeight);
+        }
+        let state = if budget == Some(0) { TenantState::Throttled } else { TenantState::Runnable };
+        let stats = TenantStats::default();
+        self.tenants.push(Tenant { vm, weight, budget, credit: 0, state, stats });
+        Ok(TenantId(self.tenants.len() - 1))
+    }
+
+    fn tenant(&mut self, id: TenantId) -> Result<&mut Tenant, SchedulerError> {
+        self.tenants.get_mut(id.0).ok_or(SchedulerError::NoSuchTenant(id))
+    }
+
+    // queues an event for the tenant's next WAITEVENT and makes it runnable again if
+    // that's what it was waiting for
+    pub fn post_event(&mut self, id: TenantId, val: i64) -> Result<(), SchedulerError> {
+        let tenant = self.tenant(id)?;
+        tenant.vm.post_event(val);
+        if tenant.state == TenantState::Waiting {
+            tenant.state = TenantState::Runnable;
+        }
+        Ok(())
+    }
+
+    // more budget, a throttled tenant runs again from the next round
+    pub fn grant(&mut self, id: TenantId, fuel: u64) -> Result<(), SchedulerError> {
+        let tenant = self.tenant(id)?;
+        if let Some(budget) = &mut tenant.budget {
+            *budget = budget.saturating_add(fuel);
+            if tenant.state == TenantState::Throttled && *budget > 0 {
+                tenant.state = TenantState::Runnable;
+            }
+        }
+        Ok(())
+    }
+
+    // a tenant stopped on a break runs again from the next round, starting with the
+    // TRAP it stopped on: its handler gets asked again
+    pub fn release(&mut self, id: TenantId) -> Result<(), SchedulerError> {
+        let tenant = self.tenant(id)?;
+        if matches!(tenant.state, TenantState::Break(_)) {
+            tenant.state = TenantState::Runnable;
+        }
+        Ok(())
+    }
+
+    pub fn set_weight(&mut self, id: TenantId, weight: u64) -> Result<(), SchedulerError> {
+        if weight == 0 {
+            return Err(SchedulerError::ZeroWeight);
+        }
+        self.tenant(id)?.weight = weight;
+        Ok(())
+    }
+
+    pub fn state(&self, id: TenantId) -> Option<TenantState> {
+        self.tenants.get(id.0).map(|t| t.state)
+    }
+
+    pub fn stats(&self, id: TenantId) -> Option<TenantStats> {
+        self.tenants.get(id.0).map(|t| t.stats)
+    }
+
+    // what's left of its budget, None when it has none
+    pub fn budget(&self, id: TenantId) -> Option<u64> {
+        self.tenants.get(id.0).and_then(|t| t.budget)
+    }
+
+    pub fn vm(&self, id: TenantId) -> Option<&Vm> {
+        self.tenants.get(id.0).map(|t| &t.vm)
+    }
+
+    pub fn tenants(&self) -> impl Iterator<Item = TenantId> + use<> {
+        (0..self.tenants.len()).map(TenantId)
+    }
+
+    pub fn rounds(&self) -> u64 {
+        self.rounds
+    }
+
+    // one pass over every tenant, the instructions retired by all of them together
+    pub fn round(&mut self) -> u64 {
+        self.rounds += 1;
+        let mut total = 0;
+        for tenant in &mut self.tenants {
+            if tenant.state != TenantState::Runnable {
+                if matches!(tenant.state, TenantState::Waiting | TenantState::Throttled | TenantState::Break(_)) {
+                    tenant.stats.idle_rounds += 1;
+                }
+                tenant.credit = 0;
+                continue;
+            }
+            tenant.credit = tenant.credit.saturating_add(self.quantum.saturating_mul(tenant.weight));
+            let fuel = tenant.budget.map_or(tenant.credit, |b| b.min(tenant.credit));
+            let before = tenant.vm.retired();
+            let status = tenant.vm.resume(fuel);
+            let used = tenant.vm.retired() - before;
+
+            total += used;
+            tenant.credit -= used;
+            tenant.stats.retired += used;
+            if used > 0 {
+                tenant.stats.slices += 1;
+            }
+            if let Some(budget) = &mut tenant.budget {
+                *budget -= used;
+            }
+            tenant.state = match status {
+                Status::Halted(result) => TenantState::Halted(result),
+                Status::Waiting => TenantState::Waiting,
+                Status::Break(imm) => TenantState::Break(imm),
+                Status::OutOfFuel if tenant.budget == Some(0) => {
+                    tenant.stats.throttled += 1;
+                    TenantState::Throttled
+                }
+                Status::OutOfFuel => TenantState::Runnable,
+            };
+            if tenant.state != TenantState::Runnable {
+                tenant.credit = 0;
+            }
+        }
+        total
+    }
+
+    // rounds until nobody can run (every tenant halted, waiting, throttled or on a break) or
+    // `max_rounds` have gone by, the instructions retired over all of them
+    pub fn run(&mut self, max_rounds: u64) -> u64 {
+        let mut total = 0;
+        for _ in 0..max_rounds {
+            if !self.tenants.iter().any(|t| t.state == TenantState::Runnable) {
+                break;
+            }
+            total += self.round();
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OP_ADD, OP_HALT, OP_JEQ, OP_JMP, OP_LOADI, OP_TRAP, OP_WAITEVENT, TrapAction, encode, kernels, verify};
+
+    fn spinner() -> Vm {
+        // never halts: r0 += 1 forever
+        let code = [encode(OP_LOADI, 1, 1, 0), encode(OP_ADD, 0, 0, 1), encode(OP_JMP, 0, 1, 0)];
+        let mut vm = Vm::new(verify(&code).unwrap());
+        vm.reset(&[]).unwrap();
+        vm
+    }
+
+    #[test]
+    fn splits_by_weight_and_bills_every_instruction() {
+        let mut s = Scheduler::new(100);
+        let light = s.add(spinner(), 1, None).unwrap();
+        let heavy = s.add(spinner(), 3, None).unwrap();
+        assert_eq!(s.run(10), 4000);
+        let (l, h) = (s.stats(light).unwrap(), s.stats(heavy).unwrap());
+        assert_eq!((l.retired, h.retired, l.slices, h.slices), (1000, 3000, 10, 10));
+        assert_eq!(s.vm(heavy).unwrap().retired(), 3000);
+        assert_eq!(s.add(spinner(), 0, None), Err(SchedulerError::ZeroWeight));
+    }
+
+    #[test]
+    fn budgets_throttle_and_idle_tenants_dont_bank_credit() {
+        let mut s = Scheduler::new(100);
+        let capped = s.add(spinner(), 1, Some(250)).unwrap();
+        let other = s.add(spinner(), 1, None).unwrap();
+        s.run(5);
+        assert_eq!(s.state(capped), Some(TenantState::Throttled));
+        assert_eq!((s.stats(capped).unwrap().retired, s.budget(capped)), (250, Some(0)));
+        assert_eq!(s.stats(other).unwrap().retired, 500);
+        s.grant(capped, 1000).unwrap();
+        s.round();
+        // a quantum, not the rounds it sat out
+        assert_eq!(s.stats(capped).unwrap().retired, 350);
+
+        // sums events until a 0, then halts with the sum
+        let code = [
+            encode(OP_LOADI, 1, 0, 0),
+            encode(OP_WAITEVENT, 2, 0, 0),
+            encode(OP_JEQ, 2, 5, 0),
+            encode(OP_ADD, 1, 1, 2),
+            encode(OP_JMP, 0, 1, 0),
+            encode(OP_HALT, 1, 0, 0),
+        ];
+        let mut vm = Vm::new(verify(&code).unwrap());
+        vm.reset(&[]).unwrap();
+        let guest = s.add(vm, 1, None).unwrap();
+        s.round();
+        assert_eq!(s.state(guest), Some(TenantState::Waiting));
+        s.round();
+        assert_eq!(s.stats(guest).unwrap().idle_rounds, 1);
+        s.post_event(guest, 4).unwrap();
+        s.post_event(guest, 0).unwrap();
+        s.round();
+        assert_eq!(s.state(guest), Some(TenantState::Halted(4)));
+    }
+
+    #[test]
+    fn a_halting_tenant_leaves_the_others_running() {
+        let mut s = Scheduler::new(1000);
+        let mut vm = Vm::new(verify(&kernels::sum_poly()).unwrap());
+        vm.reset(&[100]).unwrap();
+        let done = s.add(vm, 1, None).unwrap();
+        let spin = s.add(spinner(), 1, None).unwrap();
+        s.run(3);
+        assert_eq!(s.state(done), Some(TenantState::Halted(333_400)));
+        assert_eq!(s.stats(spin).unwrap().retired, 3000);
+        assert_eq!(s.post_event(TenantId(9), 1), Err(SchedulerError::NoSuchTenant(TenantId(9))));
+    }
+
+    #[test]
+    fn a_break_parks_the_tenant_until_released() {
+        // r0 = 5, TRAP 7, halt r0. the handler breaks the first time it's asked
+        let code = [encode(OP_LOADI, 0, 5, 0), encode(OP_TRAP, 0, 7, 0), encode(OP_HALT, 0, 0, 0)];
+        let mut vm = Vm::new(verify(&code).unwrap());
+        vm.reset(&[]).unwrap();
+        let mut asked = 0;
+        vm.set_trap_handler(move |_| {
+            asked += 1;
+            if asked == 1 { TrapAction::Break } else { TrapAction::Continue }
+        });
+        let mut s = Scheduler::new(100);
+        let guest = s.add(vm, 1, None).unwrap();
+        let spin = s.add(spinner(), 1, None).unwrap();
+        s.run(3);
+        assert_eq!(s.state(guest), Some(TenantState::Break(7)));
+        assert_eq!((s.stats(guest).unwrap().retired, s.stats(guest).unwrap().idle_rounds), (1, 2));
+        assert_eq!(s.stats(spin).unwrap().retired, 300);
+        s.release(guest).unwrap();
+        s.round();
+        assert_eq!(s.state(guest), Some(TenantState::Halted(5)));
+    }
+}
@@ -0,0 +1,157 @@
+// Opcode semantics, written once
+//
+// what the register-to-register ops compute used to be spelled out by hand in every
+// interpreter: handle!, the threaded arms, the table handlers, bytes.rs, structured.rs,
+// Vm::step and the tests each had their own `if d != 0 { x.wrapping_div(d) } else { 0 }`,
+// and a fix that reached one copy and not the others is how versions A, B and C came to
+// disagree about i64::MIN / -1. now each of those ops is one line of the table below:
+// the opcode, its mnemonic, which registers it reads, the expression and what a listing
+// says it does. from that line come
+//
+//   - an #[inline(always)] fn per op on values (`semantics::div(x, y)`) and one on a
+//     register file (`apply::div(&mut regs, dst, a, b)`), which is all any interpreter
+//     arm calls, so the arms keep their own dispatch and can't drift on the arithmetic
+//   - `eval`, the reference model: what the op leaves in dst given the registers.
+//     Vm::step runs it, the per-op tests check every variant against it
+//   - `comment`, the `r3 = r1 / r2` that --trace and asm::disassemble put next to the
+//     instruction
+//   - one test per op in lib.rs, through every variant (`for_each_alu_op!`)
+//
+// operand shapes: `binary` reads a and b, `unary` reads a, `in_place` reads dst itself.
+// jumps, memory and the stacks stay hand-written, they're control flow and state rather
+// than an expression over registers. the jit and compare/goto.c are other languages and
+// keep their own copies, the fuzzer holds them to this one
+
+use crate::{Instruction, NREGS};
+use crate::{OP_ADD, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DIV, OP_INC, OP_MOD, OP_MOV, OP_MUL, OP_POPCNT, OP_SUB};
+
+// the table. hands its rows to `$callback!`, so anything else that wants one item per op
+// (the tests in lib.rs) reads the same rows
+macro_rules! for_each_alu_op {
+    ($callback:ident) => {
+        $callback! {
+            binary add = OP_ADD, "add", |x, y| x.wrapping_add(y), "{d} = {a} + {b}";
+            binary sub = OP_SUB, "sub", |x, y| x.wrapping_sub(y), "{d} = {a} - {b}";
+            binary mul = OP_MUL, "mul", |x, y| x.wrapping_mul(y), "{d} = {a} * {b}";
+            // x / 0 = x % 0 = 0, and wrapping, so i64::MIN / -1 is i64::MIN and not a panic
+            // (compare/goto.c does the same)
+            binary div = OP_DIV, "div", |x, y| if y != 0 { x.wrapping_div(y) } else { 0 }, "{d} = {a} / {b}";
+            binary rem = OP_MOD, "mod", |x, y| if y != 0 { x.wrapping_rem(y) } else { 0 }, "{d} = {a} % {b}";
+            // -1, 0 or 1 as x is less than, equal to or greater than y, which is what
+            // JLT/JEQ/JGE test for
+            binary cmp = OP_CMP, "cmp", |x, y| x.cmp(&y) as i64, "{d} = cmp({a}, {b})";
+            unary mov = OP_MOV, "mov", |x| x, "{d} = {a}";
+            unary clz = OP_CLZ, "clz", |x| x.leading_zeros() as i64, "{d} = clz({a})";
+            unary ctz = OP_CTZ, "ctz", |x| x.trailing_zeros() as i64, "{d} = ctz({a})";
+            unary popcnt = OP_POPCNT, "popcnt", |x| x.count_ones() as i64, "{d} = popcnt({a})";
+            in_place inc = OP_INC, "inc", |x| x.wrapping_add(1), "{d} += 1";
+            in_place dec = OP_DEC, "dec", |x| x.wrapping_sub(1), "{d} -= 1";
+        }
+    };
+}
+pub(crate) use for_each_alu_op;
+
+macro_rules! alu_ops {
+    ($($shape:ident $name:ident = $op:ident, $mnemonic:literal, |$($arg:ident),+| $body:expr, $text:literal;)*) => {
+        $(
+            #[inline(always)]
+            pub fn $name($($arg: i64),+) -> i64 {
+                $body
+            }
+        )*
+
+        // regs[dst] = the op on whichever registers its shape reads
+        pub mod apply {
+            use crate::NREGS;
+
+            $(
+                #[inline(always)]
+                #[allow(unused_variables)]
+                pub fn $name(regs: &mut [i64; NREGS], dst: usize, a: u8, b: u8) {
+                    regs[dst] = alu_ops!(@call $shape super::$name, regs, dst, a, b);
+                }
+            )*
+        }
+
+        // every op in the table as (opcode, mnemonic)
+        pub const ALU_OPS: &[(u8, &str)] = &[$(($op, $mnemonic)),*];
+
+        // what `op` leaves in regs[dst], None for anything that isn't in the table
+        #[inline(always)]
+        pub fn eval(op: u8, regs: &[i64; NREGS], dst: u8, a: u8, b: u8) -> Option<i64> {
+            let dst = dst as usize;
+            match op {
+                $($op => Some(alu_ops!(@call $shape $name, regs, dst, a, b)),)*
+                _ => None,
+            }
+        }
+
+        // `r3 = r1 + r2` and so on, None for anything that isn't in the table
+        pub fn comment(ins: Instruction) -> Option<String> {
+            let reg = |r: u8| format!("r{r}");
+            match ins.op as u8 {
+                $($op => Some(alu_ops!(@comment $shape $text, reg, ins)),)*
+                _ => None,
+            }
+        }
+    };
+    // $dst is a usize already, $a and $b the operand bytes
+    (@call binary $f:path, $regs:ident, $dst:ident, $a:ident, $b:ident) => {
+        $f($regs[$a as usize], $regs[$b as usize])
+    };
+    (@call unary $f:path, $regs:ident, $dst:ident, $a:ident, $b:ident) => {
+        $f($regs[$a as usize])
+    };
+    (@call in_place $f:path, $regs:ident, $dst:ident, $a:ident, $b:ident) => {
+        $f($regs[$dst])
+    };
+    (@comment binary $text:literal, $reg:ident, $ins:ident) => {
+        format!($text, d = $reg($ins.dst), a = $reg($ins.a), b = $reg($ins.b))
+    };
+    (@comment unary $text:literal, $reg:ident, $ins:ident) => {
+        format!($text, d = $reg($ins.dst), a = $reg($ins.a))
+    };
+    (@comment in_place $text:literal, $reg:ident, $ins:ident) => {
+        format!($text, d = $reg($ins.dst))
+    };
+}
+
+for_each_alu_op!(alu_ops);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Opcode;
+
+    #[test]
+    fn comments_name_the_registers() {
+        let ins = |op, dst, a, b| Instruction::new(op, dst, a, b);
+        assert_eq!(comment(ins(Opcode::Div, 3, 1, 2)).as_deref(), Some("r3 = r1 / r2"));
+        assert_eq!(comment(ins(Opcode::Popcnt, 4, 5, 0)).as_deref(), Some("r4 = popcnt(r5)"));
+        assert_eq!(comment(ins(Opcode::Inc, 7, 0, 0)).as_deref(), Some("r7 += 1"));
+        assert_eq!(comment(ins(Opcode::Jmp, 0, 4, 0)), None);
+    }
+
+    // the table is the reference now, so the reference gets checked against numbers
+    // worked out by hand, the edge cases the interpreters used to disagree on
+    #[test]
+    fn known_answers() {
+        assert_eq!(div(i64::MIN, -1), i64::MIN);
+        assert_eq!(rem(i64::MIN, -1), 0);
+        assert_eq!((div(7, 0), rem(7, 0)), (0, 0));
+        assert_eq!((div(-7, 2), rem(-7, 2)), (-3, -1));
+        assert_eq!((cmp(-5, 3), cmp(3, 3), cmp(4, 3)), (-1, 0, 1));
+        assert_eq!((clz(0), ctz(0), popcnt(-1)), (64, 64, 64));
+        assert_eq!((clz(1), ctz(8), popcnt(0b1011)), (63, 3, 3));
+        assert_eq!((inc(i64::MAX), dec(i64::MIN)), (i64::MIN, i64::MAX));
+        assert_eq!(mul(i64::MAX, 2), -2);
+    }
+
+    #[test]
+    fn every_op_has_an_opcode() {
+        for &(op, mnemonic) in ALU_OPS {
+            let name = format!("{:?}", Opcode::from_u8(op).unwrap()).to_lowercase();
+            assert_eq!(name, mnemonic);
+        }
+    }
+}
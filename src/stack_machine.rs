@@ -0,0 +1,485 @@
+// A stack-machine ISA next to the register one, JVM/Wasm style: operands live on an
+// operand stack, the registers become locals that only GET and SET touch
+//
+//   r5 = r5 + r2        GET 5 ; GET 2 ; ADD ; SET 5
+//   jmpnz r0, loop      GET 0 ; JNZ loop
+//
+// the same work takes two to four times the instructions, each one doing less, so
+// whatever a dispatch costs is a bigger share of the total. that's the question this is
+// for: does the gap between central and duplicated dispatch grow with instructions per
+// unit of work? `compare-stack` runs the goto kernels both ways and prints the gap next
+// to how many stack instructions every goto instruction became
+//
+// encoding, one u32 per instruction: the opcode in the low byte, a 16-bit argument
+// above it (the immediate, the local or the jump target), the top byte unused
+//
+//   HALT                   returns the top of the stack
+//   PUSHI imm16            pushes imm (unsigned, like LOADI)
+//   GET local, SET local   pushes a local / pops into it. locals start out as the args
+//   DUP, DROP, SWAP
+//   ADD SUB MUL DIV MOD CMP        pop y, pop x, push x op y
+//   INC DEC CLZ CTZ POPCNT         replace the top
+//   JMP target
+//   JNZ JZ JLT JGE target  pop, branch if != 0, == 0, < 0, >= 0
+//
+// the arithmetic is the semantics table's (semantics.rs). there's no CALL, memory or
+// events, programs that use them don't translate
+
+use std::fmt;
+
+use crate::{ExecHook, NREGS, VerifiedProgram, fetch, run_hooked, seed_regs, semantics};
+use crate::{
+    OP_ADD, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE, OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE,
+    OP_LOADI, OP_MOD, OP_MOV, OP_MUL, OP_POPCNT, OP_SUB,
+};
+
+pub const HALT: u8 = 0;
+pub const PUSHI: u8 = 1;
+pub const GET: u8 = 2;
+pub const SET: u8 = 3;
+pub const DUP: u8 = 4;
+pub const DROP: u8 = 5;
+pub const SWAP: u8 = 6;
+pub const ADD: u8 = 7;
+pub const SUB: u8 = 8;
+pub const MUL: u8 = 9;
+pub const DIV: u8 = 10;
+pub const MOD: u8 = 11;
+pub const CMP: u8 = 12;
+pub const INC: u8 = 13;
+pub const DEC: u8 = 14;
+pub const CLZ: u8 = 15;
+pub const CTZ: u8 = 16;
+pub const POPCNT: u8 = 17;
+pub const JMP: u8 = 18;
+pub const JNZ: u8 = 19;
+pub const JZ: u8 = 20;
+pub const JLT: u8 = 21;
+pub const JGE: u8 = 22;
+
+// how deep the operand stack can get. translated code never goes past 2, this is room
+// for hand-written programs. validation rejects anything that could go deeper
+pub const MAX_STACK: usize = 32;
+
+#[inline(always)]
+pub fn encode(op: u8, arg: u16) -> u32 {
+    (op as u32) | ((arg as u32) << 8)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StackError {
+    Empty,
+    BadOpcode { pc: usize, op: u8 },
+    BadLocal { pc: usize, local: u16 },
+    BadTarget { pc: usize, target: u16 },
+    // an instruction that pops more than the stack can hold there
+    Underflow { pc: usize },
+    Overflow { pc: usize },
+    // two paths reach `pc` with different stack depths
+    DepthMismatch { pc: usize, one: usize, other: usize },
+    FallsOffEnd { last_pc: usize },
+    // translate: a goto opcode with no stack version (calls, memory, events, superinstructions)
+    Untranslatable { pc: usize, op: u8 },
+    // translate: the stack version needs more than 16-bit jump targets
+    TooLarge { len: usize },
+}
+
+impl fmt::Display for StackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StackError::Empty => write!(f, "program has no instructions"),
+            StackError::BadOpcode { pc, op } => write!(f, "pc {pc}: unknown opcode {op}"),
+            StackError::BadLocal { pc, local } => {
+                write!(f, "pc {pc}: local {local} doesn't exist, only 0..{}", NREGS - 1)
+            }
+            StackError::BadTarget { pc, target } => write!(f, "pc {pc}: jump target {target} is outside the code"),
+            StackError::Underflow { pc } => write!(f, "pc {pc}: pops more values than the stack holds there"),
+            StackError::Overflow { pc } => write!(f, "pc {pc}: the stack can get deeper than {MAX_STACK} values"),
+            StackError::DepthMismatch { pc, one, other } => {
+                write!(f, "pc {pc}: reached with {one} values on the stack on one path and {other} on another")
+            }
+            StackError::FallsOffEnd { last_pc } => {
+                write!(f, "pc {last_pc}: execution can run off the end of the code")
+            }
+            StackError::Untranslatable { pc, op } => write!(f, "pc {pc}: opcode {op} has no stack-machine version"),
+            StackError::TooLarge { len } => {
+                write!(f, "the stack version is {len} instructions, past what 16-bit jump targets reach")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StackError {}
+
+// (values it needs on the stack, how many it leaves there instead), None for a bad opcode
+fn stack_effect(op: u8) -> Option<(usize, usize)> {
+    Some(match op {
+        HALT => (1, 0),
+        PUSHI | GET => (0, 1),
+        DUP => (1, 2),
+        SET | DROP | JNZ | JZ | JLT | JGE => (1, 0),
+        SWAP => (2, 2),
+        ADD | SUB | MUL | DIV | MOD | CMP => (2, 1),
+        INC | DEC | CLZ | CTZ | POPCNT => (1, 1),
+        JMP => (0, 0),
+        _ => return None,
+    })
+}
+
+// a validated stack program. like VerifiedProgram for the goto ISA, this is the only
+// way into the interpreters, which fetch, pop and index locals unchecked
+#[derive(Clone, Debug)]
+pub struct StackProgram {
+    code: Vec<u32>,
+}
+
+impl StackProgram {
+    // checks opcodes, locals and jump targets everywhere, then follows every path from
+    // pc 0 and checks the stack depth: never below what an instruction pops, never past
+    // MAX_STACK, the same whichever way a pc is reached, and no path runs off the end.
+    // HALT pops, so there's always a value to return
+    pub fn new(code: Vec<u32>) -> Result<StackProgram, StackError> {
+        if code.is_empty() {
+            return Err(StackError::Empty);
+        }
+        for (pc, &word) in code.iter().enumerate() {
+            let (op, arg) = (word as u8, (word >> 8) as u16);
+            stack_effect(op).ok_or(StackError::BadOpcode { pc, op })?;
+            match op {
+                GET | SET if arg as usize >= NREGS => return Err(StackError::BadLocal { pc, local: arg }),
+                JMP | JNZ | JZ | JLT | JGE if arg as usize >= code.len() => {
+                    return Err(StackError::BadTarget { pc, target: arg });
+                }
+                _ => {}
+            }
+        }
+
+        let mut depth: Vec<Option<usize>> = vec![None; code.len()];
+        let mut work = vec![(0, 0)];
+        while let Some((pc, d)) = work.pop() {
+            if pc == code.len() {
+                return Err(StackError::FallsOffEnd { last_pc: pc - 1 });
+            }
+            match depth[pc] {
+                Some(seen) if seen == d => continue,
+                Some(seen) => return Err(StackError::DepthMismatch { pc, one: seen, other: d }),
+                None => depth[pc] = Some(d),
+            }
+            let (op, target) = (code[pc] as u8, (code[pc] >> 8) as u16 as usize);
+            let (pops, pushes) = stack_effect(op).expect("checked above");
+            let after = d.checked_sub(pops).ok_or(StackError::Underflow { pc })? + pushes;
+            if after > MAX_STACK {
+                return Err(StackError::Overflow { pc });
+            }
+            match op {
+                HALT => {}
+                JMP => work.push((target, after)),
+                JNZ | JZ | JLT | JGE => work.extend([(target, after), (pc + 1, after)]),
+                _ => work.push((pc + 1, after)),
+            }
+        }
+        Ok(StackProgram { code })
+    }
+
+    pub fn code(&self) -> &[u32] {
+        &self.code
+    }
+}
+
+// a goto program as stack code, and where each goto instruction's stack version starts
+pub struct Translation {
+    pub program: StackProgram,
+    starts: Vec<usize>,
+}
+
+// how many times each pc ran
+struct PcCounts(Vec<u64>);
+
+impl ExecHook for PcCounts {
+    fn pre(&mut self, pc: usize, _op: u8, _regs: &[i64; NREGS]) {
+        self.0[pc] += 1;
+    }
+}
+
+impl Translation {
+    // (goto instructions, stack instructions) a run of `program`, the one this was
+    // translated from, executes with `args`. every goto instruction became a straight
+    // line that runs all the way through, so counting the goto pcs is enough
+    pub fn executed(&self, program: &VerifiedProgram, args: &[i64]) -> (u64, u64) {
+        let mut counts = PcCounts(vec![0; program.code().len()]);
+        run_hooked(program, args, &mut counts);
+        let goto = counts.0.iter().sum();
+        let stack = counts.0.iter().zip(self.starts.windows(2)).map(|(&count, w)| count * (w[1] - w[0]) as u64).sum();
+        (goto, stack)
+    }
+}
+
+// every register becomes the local with its number, so the arguments go in the same way
+// and the result is the same. each instruction turns into a sequence that starts and
+// ends with an empty stack
+pub fn translate(program: &VerifiedProgram) -> Result<Translation, StackError> {
+    let code = program.code();
+    let mut sequences = Vec::with_capacity(code.len());
+    for (pc, &word) in code.iter().enumerate() {
+        let (op, dst, a, b) = (word as u8, (word >> 8) as u8 as u16, (word >> 16) as u8, (word >> 24) as u8);
+        let (a16, b16) = (a as u16, b as u16);
+        // the goto target, patched to a stack pc below
+        let target = a16 | (b16 << 8);
+        let seq: Vec<(u8, u16)> = match op {
+            OP_HALT => vec![(GET, dst), (HALT, 0)],
+            OP_LOADI => vec![(PUSHI, target), (SET, dst)],
+            OP_MOV => vec![(GET, a16), (SET, dst)],
+            OP_ADD | OP_SUB | OP_MUL | OP_DIV | OP_MOD | OP_CMP => {
+                let op = match op {
+                    OP_ADD => ADD,
+                    OP_SUB => SUB,
+                    OP_MUL => MUL,
+                    OP_DIV => DIV,
+                    OP_MOD => MOD,
+                    _ => CMP,
+                };
+                vec![(GET, a16), (GET, b16), (op, 0), (SET, dst)]
+            }
+            OP_INC => vec![(GET, dst), (INC, 0), (SET, dst)],
+            OP_DEC => vec![(GET, dst), (DEC, 0), (SET, dst)],
+            OP_CLZ => vec![(GET, a16), (CLZ, 0), (SET, dst)],
+            OP_CTZ => vec![(GET, a16), (CTZ, 0), (SET, dst)],
+            OP_POPCNT => vec![(GET, a16), (POPCNT, 0), (SET, dst)],
+            OP_JMP => vec![(JMP, target)],
+            OP_JMPNZ | OP_JNE => vec![(GET, dst), (JNZ, target)],
+            OP_JEQ => vec![(GET, dst), (JZ, target)],
+            OP_JLT => vec![(GET, dst), (JLT, target)],
+            OP_JGE => vec![(GET, dst), (JGE, target)],
+            _ => return Err(StackError::Untranslatable { pc, op }),
+        };
+        sequences.push(seq);
+    }
+
+    let mut starts = Vec::with_capacity(code.len() + 1);
+    let mut len = 0;
+    for seq in &sequences {
+        starts.push(len);
+        len += seq.len();
+    }
+    starts.push(len);
+    if len > u16::MAX as usize + 1 {
+        return Err(StackError::TooLarge { len });
+    }
+    let words = sequences
+        .iter()
+        .flatten()
+        .map(|&(op, arg)| match op {
+            JMP | JNZ | JZ | JLT | JGE => encode(op, starts[arg as usize] as u16),
+            _ => encode(op, arg),
+        })
+        .collect();
+    let program = StackProgram::new(words).expect("translated code validates");
+    Ok(Translation { program, starts })
+}
+
+// an operand stack slot or a local, unchecked unless we're building safe-only. sound
+// because StackProgram::new bounded the depth and the locals on every path
+#[cfg(not(feature = "safe-only"))]
+macro_rules! slot {
+    ($array:expr, $i:expr) => {
+        *unsafe { $array.get_unchecked_mut($i) }
+    };
+}
+
+#[cfg(feature = "safe-only")]
+macro_rules! slot {
+    ($array:expr, $i:expr) => {
+        $array[$i]
+    };
+}
+
+// one instruction, $pc already points past it. $tail runs after every handler that
+// doesn't halt, empty for central dispatch, a whole inline dispatch for the threaded one
+macro_rules! handle_stack {
+    ($regs:expr, $stack:expr, $sp:expr, $pc:expr, $op:expr, $arg:expr, $tail:block) => {
+        match $op {
+            HALT => return slot!($stack, $sp - 1),
+            PUSHI => {
+                slot!($stack, $sp) = $arg as i64;
+                $sp += 1;
+                $tail
+            }
+            GET => {
+                slot!($stack, $sp) = slot!($regs, $arg as usize);
+                $sp += 1;
+                $tail
+            }
+            SET => {
+                $sp -= 1;
+                slot!($regs, $arg as usize) = slot!($stack, $sp);
+                $tail
+            }
+            DUP => {
+                slot!($stack, $sp) = slot!($stack, $sp - 1);
+                $sp += 1;
+                $tail
+            }
+            DROP => {
+                $sp -= 1;
+                $tail
+            }
+            SWAP => {
+                let top = slot!($stack, $sp - 1);
+                slot!($stack, $sp - 1) = slot!($stack, $sp - 2);
+                slot!($stack, $sp - 2) = top;
+                $tail
+            }
+            ADD => handle_stack!(@binary semantics::add, $stack, $sp, $tail),
+            SUB => handle_stack!(@binary semantics::sub, $stack, $sp, $tail),
+            MUL => handle_stack!(@binary semantics::mul, $stack, $sp, $tail),
+            DIV => handle_stack!(@binary semantics::div, $stack, $sp, $tail),
+            MOD => handle_stack!(@binary semantics::rem, $stack, $sp, $tail),
+            CMP => handle_stack!(@binary semantics::cmp, $stack, $sp, $tail),
+            INC => handle_stack!(@unary semantics::inc, $stack, $sp, $tail),
+            DEC => handle_stack!(@unary semantics::dec, $stack, $sp, $tail),
+            CLZ => handle_stack!(@unary semantics::clz, $stack, $sp, $tail),
+            CTZ => handle_stack!(@unary semantics::ctz, $stack, $sp, $tail),
+            POPCNT => handle_stack!(@unary semantics::popcnt, $stack, $sp, $tail),
+            JMP => {
+                $pc = $arg as usize;
+                $tail
+            }
+            JNZ => handle_stack!(@branch |v| v != 0, $stack, $sp, $pc, $arg, $tail),
+            JZ => handle_stack!(@branch |v| v == 0, $stack, $sp, $pc, $arg, $tail),
+            JLT => handle_stack!(@branch |v| v < 0, $stack, $sp, $pc, $arg, $tail),
+            JGE => handle_stack!(@branch |v| v >= 0, $stack, $sp, $pc, $arg, $tail),
+            _ => return -1,
+        }
+    };
+    (@binary $f:path, $stack:expr, $sp:expr, $tail:block) => {{
+        $sp -= 1;
+        let (x, y) = (slot!($stack, $sp - 1), slot!($stack, $sp));
+        slot!($stack, $sp - 1) = $f(x, y);
+        $tail
+    }};
+    (@unary $f:path, $stack:expr, $sp:expr, $tail:block) => {{
+        let x = slot!($stack, $sp - 1);
+        slot!($stack, $sp - 1) = $f(x);
+        $tail
+    }};
+    (@branch |$v:ident| $taken:expr, $stack:expr, $sp:expr, $pc:expr, $arg:expr, $tail:block) => {{
+        $sp -= 1;
+        let $v = slot!($stack, $sp);
+        if $taken {
+            $pc = $arg as usize;
+        }
+        $tail
+    }};
+}
+
+macro_rules! decode {
+    ($code:expr, $pc:expr) => {{
+        let word = fetch!($code, $pc);
+        $pc += 1;
+        (word as u8, (word >> 8) as u16)
+    }};
+}
+
+#[inline(never)]
+pub fn run_stack_central(program: &StackProgram, args: &[i64]) -> i64 {
+    let code = &program.code[..];
+    let mut regs = seed_regs(args);
+    let mut stack = [0i64; MAX_STACK];
+    let mut sp: usize = 0;
+    let mut pc: usize = 0;
+
+    loop {
+        let (op, arg) = decode!(code, pc);
+        handle_stack!(regs, stack, sp, pc, op, arg, {});
+    }
+}
+
+// 2 levels, like run_threaded and run_structured_threaded
+#[inline(never)]
+pub fn run_stack_threaded(program: &StackProgram, args: &[i64]) -> i64 {
+    let code = &program.code[..];
+    let mut regs = seed_regs(args);
+    let mut stack = [0i64; MAX_STACK];
+    let mut sp: usize = 0;
+    let mut pc: usize = 0;
+
+    loop {
+        let (op, arg) = decode!(code, pc);
+        handle_stack!(regs, stack, sp, pc, op, arg, {
+            let (op2, arg2) = decode!(code, pc);
+            handle_stack!(regs, stack, sp, pc, op2, arg2, {});
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CountHook, kernels, run_central, verify};
+
+    #[test]
+    fn translated_kernels_match_goto_kernels() {
+        for name in ["sum-poly", "filter", "poly:3", "loop-nest:3", "fsm:5", "collatz", "bitscan"] {
+            for n in [1, 7, 100] {
+                let (code, args) = kernels::kernel(name, n).unwrap();
+                let program = verify(&code).unwrap();
+                let expected = run_central(&program, &args);
+                let translated = translate(&program).unwrap();
+                assert_eq!(run_stack_central(&translated.program, &args), expected, "{name} n = {n}");
+                assert_eq!(run_stack_threaded(&translated.program, &args), expected, "{name} n = {n}");
+
+                let mut total = CountHook::default();
+                run_hooked(&program, &args, &mut total);
+                let (goto, stack) = translated.executed(&program, &args);
+                assert_eq!(goto, total.instructions);
+                assert!(stack > 2 * goto, "{name}: {stack} stack instructions for {goto} goto ones");
+            }
+        }
+        let fib = verify(&kernels::fib()).unwrap();
+        assert!(matches!(translate(&fib), Err(StackError::Untranslatable { .. })));
+    }
+
+    #[test]
+    fn hand_written_stack_code() {
+        // (args[0] + 5) swapped under a 3, then 3 - that, doubled with dup
+        let code = vec![
+            encode(GET, 0),
+            encode(PUSHI, 5),
+            encode(ADD, 0),
+            encode(PUSHI, 3),
+            encode(SWAP, 0),
+            encode(SUB, 0),
+            encode(DUP, 0),
+            encode(ADD, 0),
+            encode(PUSHI, 9),
+            encode(DROP, 0),
+            encode(HALT, 0),
+        ];
+        let program = StackProgram::new(code).unwrap();
+        assert_eq!(run_stack_central(&program, &[10]), 2 * (3 - 15));
+        assert_eq!(run_stack_threaded(&program, &[10]), 2 * (3 - 15));
+    }
+
+    #[test]
+    fn validation() {
+        let new = |code: &[u32]| StackProgram::new(code.to_vec());
+        let halt = encode(HALT, 0);
+        assert_eq!(new(&[]).unwrap_err(), StackError::Empty);
+        assert_eq!(new(&[encode(99, 0)]).unwrap_err(), StackError::BadOpcode { pc: 0, op: 99 });
+        assert_eq!(new(&[encode(GET, 16), halt]).unwrap_err(), StackError::BadLocal { pc: 0, local: 16 });
+        assert_eq!(new(&[encode(JMP, 5)]).unwrap_err(), StackError::BadTarget { pc: 0, target: 5 });
+        assert_eq!(new(&[halt]).unwrap_err(), StackError::Underflow { pc: 0 });
+        assert_eq!(new(&[encode(PUSHI, 1), encode(ADD, 0), halt]).unwrap_err(), StackError::Underflow { pc: 1 });
+        // pushes one more on every trip round the loop
+        assert_eq!(new(&[encode(PUSHI, 1), encode(JMP, 0)]).unwrap_err(), StackError::DepthMismatch {
+            pc: 0,
+            one: 0,
+            other: 1
+        });
+        let deep: Vec<u32> = (0..=MAX_STACK).map(|_| encode(PUSHI, 1)).chain([halt]).collect();
+        assert_eq!(new(&deep).unwrap_err(), StackError::Overflow { pc: MAX_STACK });
+        assert_eq!(new(&[encode(PUSHI, 1)]).unwrap_err(), StackError::FallsOffEnd { last_pc: 0 });
+        // unreachable code only has to decode
+        assert!(new(&[encode(PUSHI, 1), halt, encode(ADD, 0)]).is_ok());
+    }
+}
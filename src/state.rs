@@ -0,0 +1,210 @@
+// What a Vm is in the middle of, and what changed between two of those
+//
+// the stepping side of a Vm (step, resume, reset) works on a VmState: the registers,
+// pc, the call and value stacks, linear memory and how many instructions retired.
+// everything else in a Vm is set up by the host and stays put while a program runs.
+// the variants keep the same things in locals and never hand them out
+//
+// VmState::diff lists what differs between two of them, for a debugger showing what a
+// step did and for tests comparing whole machines: an assert over all of memory prints
+// 64k numbers to squint at, a StateDiff prints the few words that differ
+//
+//   let before = vm.state().clone();
+//   vm.step();
+//   println!("{}", before.diff(vm.state()));   // pc 3 -> 4, r1 0 -> 7
+//
+// every step writes pc, retired and a register or two, so the layout matters once
+// several Vms step on several threads. two features pick it, `--state-layout` and
+// `cargo xtask state-layout` time what they do:
+//
+//   ordered-state  repr(C), the fields in the order below: pc, retired and the
+//                  registers first, the 144 bytes a step writes in three cache lines
+//                  and not spread between the stacks' and memory's pointers
+//   aligned-state  repr(align(64)), a state starts on a cache line, so one Vm's hot
+//                  words never share a line with what's next to it in a Vec<Vm> or
+//                  another thread's Vm. costs up to 63 bytes of padding per Vm
+//
+// without either the compiler picks the order and the alignment is 8. 64 is the only
+// alignment on offer because repr(align) takes a literal, not a const, so every other
+// value would be one more feature and one more build to time. 64 is the cache line of
+// x86-64 and of most aarch64 cores. 128 would cover Apple's cores and Intel's
+// prefetcher pulling lines in pairs, for up to 127 bytes of padding per Vm, and
+// nothing measured here says the default needs it
+
+use std::fmt;
+
+use crate::memory::Memory;
+use crate::{NREGS, Stack};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ordered-state", repr(C))]
+#[cfg_attr(feature = "aligned-state", repr(align(64)))]
+pub struct VmState {
+    pub(crate) pc: usize,
+    pub(crate) retired: u64,
+    pub(crate) regs: [i64; NREGS],
+    pub(crate) stack: Stack,
+    pub(crate) mem: Memory,
+}
+
+impl VmState {
+    pub(crate) fn new(pc: usize, mem: Memory) -> VmState {
+        VmState { pc, retired: 0, regs: [0; NREGS], stack: Stack::default(), mem }
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    // instructions executed since the last reset, HALT included
+    pub fn retired(&self) -> u64 {
+        self.retired
+    }
+
+    pub fn regs(&self) -> &[i64; NREGS] {
+        &self.regs
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        self.mem.words()
+    }
+
+    // return addresses CALL left, the outermost first
+    pub fn calls(&self) -> &[usize] {
+        &self.stack.calls
+    }
+
+    // what PUSH left, the bottom first
+    pub fn values(&self) -> &[i64] {
+        self.stack.values()
+    }
+
+    // everything that differs from `self` to `after`, empty when they're the same
+    pub fn diff(&self, after: &VmState) -> StateDiff {
+        let regs = (0..NREGS)
+            .filter(|&r| self.regs[r] != after.regs[r])
+            .map(|r| (r, self.regs[r], after.regs[r]))
+            .collect();
+        // a word one side doesn't have reads as 0 there, the size change gets its own line
+        let (old, new) = (self.memory(), after.memory());
+        let word = |words: &[i64], at: usize| words.get(at).copied().unwrap_or(0);
+        let memory = (0..old.len().max(new.len()))
+            .filter(|&at| word(old, at) != word(new, at))
+            .map(|at| (at, word(old, at), word(new, at)))
+            .collect();
+        StateDiff {
+            pc: changed(self.pc, after.pc),
+            retired: changed(self.retired, after.retired),
+            regs,
+            memory_words: changed(old.len(), new.len()),
+            memory,
+            calls: changed(self.calls(), after.calls()).map(|(a, b)| (a.to_vec(), b.to_vec())),
+            values: changed(self.values(), after.values()).map(|(a, b)| (a.to_vec(), b.to_vec())),
+        }
+    }
+}
+
+fn changed<T: PartialEq>(before: T, after: T) -> Option<(T, T)> {
+    (before != after).then_some((before, after))
+}
+
+// what VmState::diff found, every entry (before, after)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    pub pc: Option<(usize, usize)>,
+    pub retired: Option<(u64, u64)>,
+    // (register, before, after)
+    pub regs: Vec<(usize, i64, i64)>,
+    pub memory_words: Option<(usize, usize)>,
+    // (word address, before, after), in address order
+    pub memory: Vec<(usize, i64, i64)>,
+    pub calls: Option<(Vec<usize>, Vec<usize>)>,
+    pub values: Option<(Vec<i64>, Vec<i64>)>,
+}
+
+impl StateDiff {
+    pub fn is_empty(&self) -> bool {
+        *self == StateDiff::default()
+    }
+}
+
+// one change per line, "no change" for none
+impl fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no change");
+        }
+        let mut lines = Vec::new();
+        if let Some((a, b)) = self.pc {
+            lines.push(format!("pc {a} -> {b}"));
+        }
+        if let Some((a, b)) = self.retired {
+            lines.push(format!("retired {a} -> {b}"));
+        }
+        lines.extend(self.regs.iter().map(|(r, a, b)| format!("r{r} {a} -> {b}")));
+        if let Some((a, b)) = self.memory_words {
+            lines.push(format!("memory {a} -> {b} words"));
+        }
+        lines.extend(self.memory.iter().map(|(at, a, b)| format!("mem[{at}] {a} -> {b}")));
+        if let Some((a, b)) = &self.calls {
+            lines.push(format!("calls {a:?} -> {b:?}"));
+        }
+        if let Some((a, b)) = &self.values {
+            lines.push(format!("values {a:?} -> {b:?}"));
+        }
+        f.write_str(&lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::offset_of;
+
+    use super::VmState;
+    use crate::{OP_CALL, OP_HALT, OP_LOADI, OP_PUSH, OP_RET, OP_STORE, Vm, encode, verify};
+
+    // whichever of the layout features this got built with
+    #[test]
+    fn layout_features_do_what_they_say() {
+        if cfg!(feature = "ordered-state") {
+            assert_eq!((offset_of!(VmState, pc), offset_of!(VmState, retired), offset_of!(VmState, regs)), (0, 8, 16));
+        }
+        if cfg!(feature = "aligned-state") {
+            assert_eq!(align_of::<VmState>(), 64);
+            assert_eq!(size_of::<VmState>() % 64, 0);
+        }
+    }
+
+    #[test]
+    fn diffs_list_what_a_step_changed() {
+        let code = [
+            encode(OP_LOADI, 1, 7, 0),
+            encode(OP_STORE, 1, 2, 3),
+            encode(OP_CALL, 0, 4, 0),
+            encode(OP_HALT, 1, 0, 0),
+            encode(OP_PUSH, 1, 0, 0),
+            encode(OP_RET, 0, 0, 0),
+        ];
+        let mut vm = Vm::new(verify(&code).unwrap());
+        vm.reset(&[]).unwrap();
+        let start = vm.state().clone();
+        assert!(start.diff(vm.state()).is_empty());
+        assert_eq!(start.diff(vm.state()).to_string(), "no change");
+
+        vm.step();
+        let diff = start.diff(vm.state());
+        assert_eq!((diff.pc, diff.regs.clone()), (Some((0, 1)), vec![(1, 0, 7)]));
+        assert_eq!(diff.to_string(), "pc 0 -> 1\nretired 0 -> 1\nr1 0 -> 7");
+
+        // r2 + 3 is word 3
+        let before = vm.state().clone();
+        vm.step();
+        assert_eq!(before.diff(vm.state()).memory, [(3, 0, 7)]);
+        let before = vm.state().clone();
+        vm.step();
+        vm.step();
+        let diff = before.diff(vm.state());
+        assert_eq!((diff.calls, diff.values), (Some((vec![], vec![3])), Some((vec![], vec![7]))));
+        assert!(diff.regs.is_empty() && diff.memory.is_empty());
+    }
+}
@@ -0,0 +1,410 @@
+// A second ISA with structured control flow, Wasm style: no jump targets in the
+// code, only nested BLOCK/LOOP ... END and branches that name an enclosing block by
+// depth. BR 0 leaves the innermost BLOCK (to just after its END) or goes back to the
+// top of the innermost LOOP, BR 1 does the same for the one around it, and so on
+//
+// the point is comparing dispatch strategies across the two styles of bytecode. the
+// arithmetic is the same as the goto ISA (same word layout, same registers), what
+// differs is everything about control flow:
+//
+//   - BLOCK/LOOP/END stay in the stream and get dispatched like anything else, they
+//     just don't do anything. that's extra dispatches on exactly the short blocks
+//     threading is supposed to help with
+//   - a branch doesn't know where it goes, the target comes from a side table built
+//     at validation time (what in-place Wasm interpreters do instead of rewriting
+//     the code), so every taken branch is one more load
+//
+// encoding, dst/a/b are bytes like in the goto ISA:
+//
+//   HALT dst, LOADI dst imm16, ADD/SUB/MUL/DIV/MOD dst a b, INC/DEC dst, MOV dst a
+//   BLOCK, LOOP, END
+//   BR depth16
+//   BR_IF dst depth16      branch if dst != 0
+//
+// there's no CALL here, kernels that need one (fib) only exist in the goto ISA
+
+use std::fmt;
+
+use crate::semantics::apply;
+use crate::{NREGS, encode, fetch, imm16, seed_regs};
+
+pub const HALT: u8 = 0;
+pub const LOADI: u8 = 1;
+pub const ADD: u8 = 2;
+pub const SUB: u8 = 3;
+pub const MUL: u8 = 4;
+pub const DIV: u8 = 5;
+pub const MOD: u8 = 6;
+pub const INC: u8 = 7;
+pub const DEC: u8 = 8;
+pub const MOV: u8 = 9;
+pub const BLOCK: u8 = 10;
+pub const LOOP: u8 = 11;
+pub const END: u8 = 12;
+pub const BR: u8 = 13;
+pub const BR_IF: u8 = 14;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StructuredError {
+    Empty,
+    BadOpcode { pc: usize, op: u8 },
+    BadRegister { pc: usize, reg: u8 },
+    UnmatchedEnd { pc: usize },
+    Unclosed { pc: usize },
+    BadDepth { pc: usize, depth: u16, open: usize },
+    FallsOffEnd { last_pc: usize },
+}
+
+impl fmt::Display for StructuredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructuredError::Empty => write!(f, "program has no instructions"),
+            StructuredError::BadOpcode { pc, op } => write!(f, "pc {pc}: unknown opcode {op}"),
+            StructuredError::BadRegister { pc, reg } => {
+                write!(f, "pc {pc}: register r{reg} doesn't exist, only r0..r{}", NREGS - 1)
+            }
+            StructuredError::UnmatchedEnd { pc } => write!(f, "pc {pc}: END with no open BLOCK or LOOP"),
+            StructuredError::Unclosed { pc } => write!(f, "pc {pc}: BLOCK or LOOP is never closed"),
+            StructuredError::BadDepth { pc, depth, open } => {
+                write!(f, "pc {pc}: branch to depth {depth}, but only {open} block(s) are open")
+            }
+            StructuredError::FallsOffEnd { last_pc } => {
+                write!(f, "pc {last_pc}: last instruction isn't HALT, execution could run off the end")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StructuredError {}
+
+// a validated structured program and its branch side table. like VerifiedProgram for
+// the goto ISA, this is the only way into the interpreters, which fetch unchecked
+#[derive(Clone, Debug)]
+pub struct StructuredProgram {
+    code: Vec<u32>,
+    // for every BR/BR_IF, the pc it lands on. same length as code, the other slots are 0
+    targets: Vec<u32>,
+}
+
+impl StructuredProgram {
+    // checks opcodes, registers and nesting, every branch depth names an open block,
+    // and the last instruction is HALT. then every pc a branch can produce is inside
+    // the code: after an END there's always something, since the last thing is HALT
+    pub fn new(code: Vec<u32>) -> Result<StructuredProgram, StructuredError> {
+        if code.is_empty() {
+            return Err(StructuredError::Empty);
+        }
+        let mut targets = vec![0u32; code.len()];
+        // open blocks: where a branch to it goes for a LOOP, or the branches still
+        // waiting for the END for a BLOCK
+        enum Open {
+            Loop(u32),
+            Block { start: usize, pending: Vec<usize> },
+        }
+        let mut open: Vec<Open> = Vec::new();
+
+        for (pc, &word) in code.iter().enumerate() {
+            let op = (word & 0xFF) as u8;
+            let regs: &[u8] = match op {
+                HALT | LOADI | INC | DEC | BR_IF => &[1],
+                MOV => &[1, 2],
+                ADD | SUB | MUL | DIV | MOD => &[1, 2, 3],
+                BLOCK | LOOP | END | BR => &[],
+                _ => return Err(StructuredError::BadOpcode { pc, op }),
+            };
+            for &field in regs {
+                let reg = (word >> (8 * field)) as u8;
+                if reg as usize >= NREGS {
+                    return Err(StructuredError::BadRegister { pc, reg });
+                }
+            }
+            match op {
+                BLOCK => open.push(Open::Block { start: pc, pending: Vec::new() }),
+                // the LOOP itself is a no-op, branching to the instruction after it saves a dispatch
+                LOOP => open.push(Open::Loop(pc as u32 + 1)),
+                END => match open.pop() {
+                    Some(Open::Block { pending, .. }) => {
+                        for at in pending {
+                            targets[at] = pc as u32 + 1;
+                        }
+                    }
+                    Some(Open::Loop(_)) => {}
+                    None => return Err(StructuredError::UnmatchedEnd { pc }),
+                },
+                BR | BR_IF => {
+                    let depth = (word >> 16) as u16;
+                    let Some(index) = open.len().checked_sub(depth as usize + 1) else {
+                        return Err(StructuredError::BadDepth { pc, depth, open: open.len() });
+                    };
+                    match &mut open[index] {
+                        Open::Loop(target) => targets[pc] = *target,
+                        Open::Block { pending, .. } => pending.push(pc),
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(unclosed) = open.first() {
+            let pc = match unclosed {
+                Open::Loop(after) => *after as usize - 1,
+                Open::Block { start, .. } => *start,
+            };
+            return Err(StructuredError::Unclosed { pc });
+        }
+        let last_pc = code.len() - 1;
+        if (code[last_pc] & 0xFF) as u8 != HALT {
+            return Err(StructuredError::FallsOffEnd { last_pc });
+        }
+        Ok(StructuredProgram { code, targets })
+    }
+
+    pub fn code(&self) -> &[u32] {
+        &self.code
+    }
+}
+
+// one instruction, $pc already points past it. $tail runs after every handler that
+// doesn't halt, empty for central dispatch, a whole inline dispatch for the threaded one
+macro_rules! handle_structured {
+    ($targets:expr, $regs:expr, $pc:expr, $op:expr, $dst:expr, $a:expr, $b:expr, $tail:block) => {
+        match $op {
+            HALT => return $regs[$dst],
+            LOADI => {
+                $regs[$dst] = imm16($a, $b);
+                $tail
+            }
+            ADD => {
+                apply::add(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            SUB => {
+                apply::sub(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            MUL => {
+                apply::mul(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            DIV => {
+                apply::div(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            MOD => {
+                apply::rem(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            INC => {
+                apply::inc(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            DEC => {
+                apply::dec(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            MOV => {
+                apply::mov(&mut $regs, $dst, $a, $b);
+                $tail
+            }
+            BLOCK | LOOP | END => $tail,
+            BR => {
+                $pc = fetch!($targets, $pc - 1) as usize;
+                $tail
+            }
+            BR_IF => {
+                if $regs[$dst] != 0 {
+                    $pc = fetch!($targets, $pc - 1) as usize;
+                }
+                $tail
+            }
+            _ => return -1,
+        }
+    };
+}
+
+macro_rules! decode {
+    ($code:expr, $pc:expr) => {{
+        let instr = fetch!($code, $pc);
+        $pc += 1;
+        ((instr & 0xFF) as u8, ((instr >> 8) & 0xFF) as usize, ((instr >> 16) & 0xFF) as u8, (instr >> 24) as u8)
+    }};
+}
+
+#[inline(never)]
+pub fn run_structured_central(program: &StructuredProgram, args: &[i64]) -> i64 {
+    let (code, targets) = (&program.code[..], &program.targets[..]);
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+
+    loop {
+        let (op, dst, a, b) = decode!(code, pc);
+        handle_structured!(targets, regs, pc, op, dst, a, b, {});
+    }
+}
+
+// 2 levels, like run_threaded and run_bytes_threaded
+#[inline(never)]
+pub fn run_structured_threaded(program: &StructuredProgram, args: &[i64]) -> i64 {
+    let (code, targets) = (&program.code[..], &program.targets[..]);
+    let mut regs = seed_regs(args);
+    let mut pc: usize = 0;
+
+    loop {
+        let (op, dst, a, b) = decode!(code, pc);
+        handle_structured!(targets, regs, pc, op, dst, a, b, {
+            let (op2, dst2, a2, b2) = decode!(code, pc);
+            handle_structured!(targets, regs, pc, op2, dst2, a2, b2, {});
+        });
+    }
+}
+
+// the goto kernels (kernels.rs) rewritten with blocks, same registers, same results
+pub const KERNELS: &[&str] = &["sum-poly", "poly:<degree>", "loop-nest:<depth>", "fsm:<states>"];
+
+// like kernels::kernel, the same names take the same arguments
+pub fn kernel(name: &str, n: i64) -> Option<(Vec<u32>, Vec<i64>)> {
+    let (_, args) = crate::kernels::kernel(name, n)?;
+    let (base, param) = match name.split_once(':') {
+        Some((base, param)) => (base, Some(param.parse::<u32>().ok()?)),
+        None => (name, None),
+    };
+    let code = match (base, param) {
+        ("sum-poly", None) => poly_loop(&[(MUL, 4, 3, 3), (SUB, 5, 4, 3), (ADD, 5, 5, 2), (ADD, 1, 1, 5)]),
+        ("poly", Some(degree)) => poly(degree),
+        ("loop-nest", Some(depth)) => loop_nest(depth),
+        ("fsm", Some(states)) => fsm(states),
+        _ => return None,
+    };
+    Some((code, args))
+}
+
+fn loadi(dst: u8, imm: u16) -> u32 {
+    encode(LOADI, dst, imm as u8, (imm >> 8) as u8)
+}
+
+fn br_if(reg: u8, depth: u16) -> u32 {
+    encode(BR_IF, reg, depth as u8, (depth >> 8) as u8)
+}
+
+fn op0(op: u8) -> u32 {
+    encode(op, 0, 0, 0)
+}
+
+// sum-poly: r1 = 0, r2 = 1, then `loop { r3 = r0; body; r0--; br_if r0 0 }`
+fn poly_loop(body: &[(u8, u8, u8, u8)]) -> Vec<u32> {
+    let mut code = vec![loadi(1, 0), loadi(2, 1), op0(LOOP), encode(MOV, 3, 0, 0)];
+    code.extend(body.iter().map(|&(op, dst, a, b)| encode(op, dst, a, b)));
+    code.extend([encode(DEC, 0, 0, 0), br_if(0, 0), op0(END), encode(HALT, 1, 0, 0)]);
+    code
+}
+
+fn poly(degree: u32) -> Vec<u32> {
+    let mut code = vec![loadi(1, 0), op0(LOOP), encode(MOV, 2, 0, 0), loadi(3, crate::kernels::poly_coeff(degree))];
+    for k in (0..degree).rev() {
+        code.extend([encode(MUL, 3, 3, 2), loadi(4, crate::kernels::poly_coeff(k)), encode(ADD, 3, 3, 4)]);
+    }
+    code.extend([encode(ADD, 1, 1, 3), encode(DEC, 0, 0, 0), br_if(0, 0), op0(END), encode(HALT, 1, 0, 0)]);
+    code
+}
+
+fn loop_nest(depth: u32) -> Vec<u32> {
+    let mut code = vec![loadi(1, 0)];
+    for level in 0..depth {
+        code.extend([encode(MOV, 2 + level as u8, 0, 0), op0(LOOP)]);
+    }
+    code.push(encode(INC, 1, 0, 0));
+    for level in (0..depth).rev() {
+        let counter = 2 + level as u8;
+        code.extend([encode(DEC, counter, 0, 0), br_if(counter, 0), op0(END)]);
+    }
+    code.push(encode(HALT, 1, 0, 0));
+    code
+}
+
+// same machine as kernels::fsm. the compare chain becomes one BLOCK per state inside
+// an outer `done` block: a state that doesn't match leaves its own block (br_if 0),
+// one that does runs and leaves `done` (br 1)
+fn fsm(states: u32) -> Vec<u32> {
+    use crate::kernels::{FSM_MOD, FSM_MUL};
+    const ACC: u8 = 1;
+    const STATE: u8 = 2;
+    const X: u8 = 3;
+    const TMP: u8 = 4;
+    const MULTIPLIER: u8 = 5;
+    const NSTATES: u8 = 6;
+    const TMP2: u8 = 7;
+    const MODULUS: u8 = 8;
+
+    let mut code = vec![
+        loadi(ACC, 0),
+        loadi(STATE, 0),
+        loadi(X, 1),
+        loadi(MULTIPLIER, FSM_MUL as u16),
+        loadi(NSTATES, states as u16),
+        loadi(MODULUS, FSM_MOD as u16),
+        op0(LOOP),
+        encode(MUL, X, X, MULTIPLIER),
+        encode(MOD, X, X, MODULUS),
+        op0(BLOCK),
+    ];
+    for k in 0..states {
+        let last = k + 1 == states;
+        if !last {
+            code.extend([op0(BLOCK), loadi(TMP, k as u16), encode(SUB, TMP, STATE, TMP), br_if(TMP, 0)]);
+        }
+        code.extend([
+            loadi(TMP2, (k + 1) as u16),
+            encode(MUL, TMP2, TMP2, X),
+            encode(ADD, ACC, ACC, TMP2),
+            loadi(TMP, k as u16),
+            encode(ADD, STATE, X, TMP),
+            encode(MOD, STATE, STATE, NSTATES),
+        ]);
+        if !last {
+            code.extend([encode(BR, 0, 1, 0), op0(END)]);
+        }
+    }
+    code.extend([op0(END), encode(DEC, 0, 0, 0), br_if(0, 0), op0(END), encode(HALT, ACC, 0, 0)]);
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_kernels_match_goto_kernels() {
+        for name in ["sum-poly", "poly:0", "poly:5", "loop-nest:1", "loop-nest:3", "fsm:1", "fsm:2", "fsm:9"] {
+            for n in [1, 7, 100] {
+                let (goto, args) = crate::kernels::kernel(name, n).unwrap();
+                let expected = crate::run_central(&crate::verify(&goto).unwrap(), &args);
+                let (code, args) = kernel(name, n).unwrap();
+                let program = StructuredProgram::new(code).unwrap();
+                assert_eq!(run_structured_central(&program, &args), expected, "{name} n = {n}");
+                assert_eq!(run_structured_threaded(&program, &args), expected, "{name} n = {n}");
+            }
+        }
+        assert!(kernel("fib", 10).is_none());
+    }
+
+    #[test]
+    fn validation() {
+        let halt = encode(HALT, 0, 0, 0);
+        let br = |depth: u16| encode(BR, 0, depth as u8, 0);
+        assert!(StructuredProgram::new(vec![op0(BLOCK), br(0), op0(END), halt]).is_ok());
+        assert_eq!(
+            StructuredProgram::new(vec![op0(BLOCK), br(1), op0(END), halt]).unwrap_err(),
+            StructuredError::BadDepth { pc: 1, depth: 1, open: 1 }
+        );
+        assert_eq!(StructuredProgram::new(vec![op0(END), halt]).unwrap_err(), StructuredError::UnmatchedEnd { pc: 0 });
+        assert_eq!(StructuredProgram::new(vec![op0(LOOP), halt]).unwrap_err(), StructuredError::Unclosed { pc: 0 });
+        assert_eq!(
+            StructuredProgram::new(vec![op0(BLOCK), op0(END)]).unwrap_err(),
+            StructuredError::FallsOffEnd { last_pc: 1 }
+        );
+        assert_eq!(StructuredProgram::new(vec![encode(INC, 16, 0, 0), halt]).unwrap_err(), StructuredError::BadRegister {
+            pc: 0,
+            reg: 16
+        });
+        assert_eq!(StructuredProgram::new(vec![]).unwrap_err(), StructuredError::Empty);
+    }
+}
@@ -0,0 +1,254 @@
+// Threaded dispatch the compiler has to keep: guaranteed tail calls
+// (`--features nightly-tailcall`, needs a nightly rustc)
+//
+// everything in lib.rs gets its threading from LLVM choosing not to tail-merge the
+// duplicated matches, which holds until a handler edit, a new rustc or a different
+// -tail-merge-threshold decides otherwise (verify-threading is there to notice). this
+// variant doesn't ask: every opcode is its own function, like the fn-pointer table, but
+// instead of returning to a loop each handler ends with
+//
+//   become HANDLERS[next opcode](st, pc, instr)
+//
+// and `become` (the explicit_tail_calls feature) is a guaranteed tail call: the frame
+// is reused, it's a jump and never a call, in debug builds too. so the indirect jump at
+// the end of each handler is there by construction, one per opcode, which is what
+// computed goto gives C. pc and the instruction word travel in argument registers, the
+// rest of the machine in TailState
+//
+// on stable this module isn't compiled and `tailcall` is in UNAVAILABLE_VARIANTS. its
+// stable counterpart is the fn-pointer table (run_table), the same handlers returning
+// to a loop: the difference between the two rows is what the guarantee is worth
+
+use crate::memory::Memory;
+use crate::semantics::{self, apply, for_each_alu_op};
+use crate::{NREGS, Stack, VerifiedProgram, address, branch_taken, fetch, fused_binops, imm16, operands, seed_regs};
+use crate::{
+    OP_ADD, OP_ADDADD, OP_CALL, OP_CLZ, OP_CMP, OP_CTZ, OP_DEC, OP_DECJNZ, OP_DIV, OP_HALT, OP_INC, OP_JEQ, OP_JGE,
+    OP_JLT, OP_JMP, OP_JMPNZ, OP_JNE, OP_LOAD, OP_LOADI, OP_MEMCPY, OP_MEMSET, OP_MOD, OP_MOV, OP_MUL, OP_MULSUB,
+    OP_POP, OP_POPCNT, OP_PUSH, OP_RET, OP_STORE, OP_SUB,
+};
+
+struct TailState<'a> {
+    code: &'a [u32],
+    regs: [i64; NREGS],
+    stack: Stack,
+    mem: Memory,
+}
+
+// `pc` is already past `instr`, like everywhere else
+type Handler = fn(&mut TailState, usize, u32) -> i64;
+
+// the end of every handler that carries on: fetch the instruction at $pc and jump to
+// its handler
+macro_rules! dispatch {
+    ($st:ident, $pc:expr) => {{
+        let pc = $pc;
+        let instr = fetch!($st.code, pc);
+        become HANDLERS[(instr & 0xFF) as usize]($st, pc + 1, instr)
+    }};
+}
+
+// one handler per op in the semantics table
+macro_rules! tail_alu {
+    ($($shape:ident $name:ident = $op:ident, $mnemonic:literal, |$($arg:ident),+| $body:expr, $text:literal;)*) => {
+        mod alu {
+            use super::*;
+
+            $(
+                pub(super) fn $name(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+                    let (dst, a, b) = operands(instr);
+                    apply::$name(&mut st.regs, dst, a, b);
+                    dispatch!(st, pc)
+                }
+            )*
+        }
+    };
+}
+
+for_each_alu_op!(tail_alu);
+
+fn halt(st: &mut TailState, _pc: usize, instr: u32) -> i64 {
+    st.regs[operands(instr).0]
+}
+
+fn loadi(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    st.regs[dst] = imm16(a, b);
+    dispatch!(st, pc)
+}
+
+// the branch family, one function each like every other opcode
+macro_rules! tail_branch {
+    ($($name:ident: $op:expr;)*) => {
+        $(
+            fn $name(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+                let (dst, a, b) = operands(instr);
+                let next = if branch_taken($op, st.regs[dst]) { imm16(a, b) as usize } else { pc };
+                dispatch!(st, next)
+            }
+        )*
+    };
+}
+
+tail_branch! {
+    jmpnz: OP_JMPNZ;
+    jmp: OP_JMP;
+    jeq: OP_JEQ;
+    jne: OP_JNE;
+    jlt: OP_JLT;
+    jge: OP_JGE;
+}
+
+fn call(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (_, a, b) = operands(instr);
+    if !st.stack.call(pc) {
+        return -1;
+    }
+    dispatch!(st, imm16(a, b) as usize)
+}
+
+fn ret(st: &mut TailState, _pc: usize, _instr: u32) -> i64 {
+    match st.stack.ret() {
+        Some(ret) => dispatch!(st, ret),
+        None => -1,
+    }
+}
+
+fn push(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    if !st.stack.push(st.regs[operands(instr).0]) {
+        return -1;
+    }
+    dispatch!(st, pc)
+}
+
+fn pop(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let Some(v) = st.stack.pop() else { return -1 };
+    st.regs[operands(instr).0] = v;
+    dispatch!(st, pc)
+}
+
+fn load(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    let Some(&v) = st.mem.get(address(st.regs[a as usize], b)) else { return -1 };
+    st.regs[dst] = v;
+    dispatch!(st, pc)
+}
+
+fn store(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    let value = st.regs[dst];
+    let Ok(slot) = st.mem.get_mut(address(st.regs[a as usize], b)) else { return -1 };
+    *slot = value;
+    dispatch!(st, pc)
+}
+
+fn memcpy(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    if st.mem.copy(st.regs[dst], st.regs[a as usize], st.regs[b as usize]).is_err() {
+        return -1;
+    }
+    dispatch!(st, pc)
+}
+
+fn memset(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    if st.mem.fill(st.regs[dst], st.regs[a as usize], st.regs[b as usize]).is_err() {
+        return -1;
+    }
+    dispatch!(st, pc)
+}
+
+// superinstructions, see fuse.rs. each one steps over the second half of its pair
+fn addadd(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    fused_binops(&mut st.regs, dst as u8, a, b, semantics::add, semantics::add);
+    dispatch!(st, pc + 1)
+}
+
+fn mulsub(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    fused_binops(&mut st.regs, dst as u8, a, b, semantics::mul, semantics::sub);
+    dispatch!(st, pc + 1)
+}
+
+fn decjnz(st: &mut TailState, pc: usize, instr: u32) -> i64 {
+    let (dst, a, b) = operands(instr);
+    apply::dec(&mut st.regs, dst, a, b);
+    let next = if st.regs[dst] != 0 { imm16(a, b) as usize } else { pc + 1 };
+    dispatch!(st, next)
+}
+
+// unknown opcodes, WAITEVENT, TRAP and RESOLVE_CALL: there's no host to post events,
+// handle traps or resolve calls in a run-to-completion variant
+fn bad(_st: &mut TailState, _pc: usize, _instr: u32) -> i64 {
+    -1
+}
+
+static HANDLERS: [Handler; 256] = {
+    let mut t: [Handler; 256] = [bad; 256];
+    t[OP_HALT as usize] = halt;
+    t[OP_LOADI as usize] = loadi;
+    t[OP_ADD as usize] = alu::add;
+    t[OP_SUB as usize] = alu::sub;
+    t[OP_MUL as usize] = alu::mul;
+    t[OP_DIV as usize] = alu::div;
+    t[OP_MOD as usize] = alu::rem;
+    t[OP_INC as usize] = alu::inc;
+    t[OP_DEC as usize] = alu::dec;
+    t[OP_JMPNZ as usize] = jmpnz;
+    t[OP_MOV as usize] = alu::mov;
+    t[OP_CLZ as usize] = alu::clz;
+    t[OP_CTZ as usize] = alu::ctz;
+    t[OP_POPCNT as usize] = alu::popcnt;
+    t[OP_CALL as usize] = call;
+    t[OP_RET as usize] = ret;
+    t[OP_PUSH as usize] = push;
+    t[OP_POP as usize] = pop;
+    t[OP_LOAD as usize] = load;
+    t[OP_STORE as usize] = store;
+    t[OP_MEMCPY as usize] = memcpy;
+    t[OP_MEMSET as usize] = memset;
+    t[OP_CMP as usize] = alu::cmp;
+    t[OP_JMP as usize] = jmp;
+    t[OP_JEQ as usize] = jeq;
+    t[OP_JNE as usize] = jne;
+    t[OP_JLT as usize] = jlt;
+    t[OP_JGE as usize] = jge;
+    t[OP_ADDADD as usize] = addadd;
+    t[OP_MULSUB as usize] = mulsub;
+    t[OP_DECJNZ as usize] = decjnz;
+    t
+};
+
+// an ordinary call into the first handler, st lives in this frame. from there on it's
+// jumps until a handler returns
+#[inline(never)]
+pub fn run_tailcall(program: &VerifiedProgram, args: &[i64]) -> i64 {
+    let mut st =
+        TailState { code: program.code(), regs: seed_regs(args), stack: Stack::default(), mem: program.memory() };
+    let instr = fetch!(st.code, 0);
+    HANDLERS[(instr & 0xFF) as usize](&mut st, 1, instr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{kernels, run_central, verify};
+
+    // millions of dispatches on a 64 KiB stack, in the unoptimized test build: if any
+    // handler called the next one instead of jumping to it this would overflow long
+    // before the end
+    #[test]
+    fn long_runs_stay_in_one_frame() {
+        let (code, args) = kernels::kernel("sum-poly", 200_000).unwrap();
+        let program = verify(&code).unwrap();
+        let expected = run_central(&program, &args);
+        let result = std::thread::Builder::new()
+            .stack_size(64 << 10)
+            .spawn(move || run_tailcall(&program, &args))
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+}
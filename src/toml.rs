@@ -0,0 +1,172 @@
+// The small TOML subset the checked-in files are written in: experiment descriptors
+// (src/experiment.rs) and Vm configs (config.rs)
+//
+//   name = "n-sweep"            # a comment
+//   sizes = [100, 1_000]
+//
+// one key per line, values are integers, "strings" or single-line [arrays]. no
+// tables, no escapes, no nesting, which is all a flat descriptor needs. what the keys
+// mean and which ones are allowed is up to whoever reads the pairs
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+    List(Vec<Value>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(_) => f.write_str("an integer"),
+            Value::Str(_) => f.write_str("a string"),
+            Value::List(_) => f.write_str("an array"),
+        }
+    }
+}
+
+// one `key = value` line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pair {
+    // 1-based, for error messages
+    pub line: usize,
+    pub key: String,
+    pub value: Value,
+}
+
+// every `key = value` in `text`, in order. errors say which line
+pub fn pairs(text: &str) -> Result<Vec<Pair>, String> {
+    let mut pairs = Vec::new();
+    for (i, raw) in text.lines().enumerate() {
+        let line = strip_comment(raw).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let err = |msg: String| format!("line {}: {msg}", i + 1);
+        let (key, value) = line.split_once('=').ok_or_else(|| err("expected `key = value`".into()))?;
+        let value = parse_value(value.trim()).map_err(err)?;
+        pairs.push(Pair { line: i + 1, key: key.trim().to_string(), value });
+    }
+    Ok(pairs)
+}
+
+// a '#' only starts a comment outside of a string
+fn strip_comment(line: &str) -> &str {
+    let mut in_str = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_str = !in_str,
+            '#' if !in_str => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(s: &str) -> Result<Value, String> {
+    if let Some(inner) = s.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or("unterminated array")?;
+        let items = split_items(inner)?;
+        return items.into_iter().map(parse_value).collect::<Result<_, _>>().map(Value::List);
+    }
+    if let Some(inner) = s.strip_prefix('"') {
+        let inner = inner.strip_suffix('"').ok_or("unterminated string")?;
+        if inner.contains('"') {
+            return Err("escaped quotes aren't supported".into());
+        }
+        return Ok(Value::Str(inner.to_string()));
+    }
+    s.replace('_', "").parse().map(Value::Int).map_err(|_| format!("can't parse value `{s}`"))
+}
+
+// splits the inside of an array on commas that aren't inside a string
+// (a trailing comma is fine)
+fn split_items(s: &str) -> Result<Vec<&str>, String> {
+    let mut items = Vec::new();
+    let mut in_str = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_str = !in_str,
+            '[' | ']' if !in_str => return Err("nested arrays aren't supported".into()),
+            ',' if !in_str => {
+                items.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = s[start..].trim();
+    if !last.is_empty() {
+        items.push(last);
+    }
+    if items.iter().any(|it| it.is_empty()) {
+        return Err("empty array item".into());
+    }
+    Ok(items)
+}
+
+// the expect_* take the key for the error message and say what it got instead
+
+pub fn expect_str(key: &str, v: Value) -> Result<String, String> {
+    match v {
+        Value::Str(s) => Ok(s),
+        other => Err(format!("`{key}` should be a string, got {other}")),
+    }
+}
+
+pub fn expect_positive(key: &str, v: Value) -> Result<u64, String> {
+    match v {
+        Value::Int(n) if n > 0 => Ok(n as u64),
+        Value::Int(n) => Err(format!("`{key}` has to be positive, got {n}")),
+        other => Err(format!("`{key}` should be an integer, got {other}")),
+    }
+}
+
+pub fn expect_list(key: &str, v: Value) -> Result<Vec<Value>, String> {
+    match v {
+        Value::List(items) => Ok(items),
+        other => Err(format!("`{key}` should be an array, got {other}")),
+    }
+}
+
+pub fn expect_strs(key: &str, v: Value) -> Result<Vec<String>, String> {
+    expect_list(key, v)?.into_iter().map(|it| expect_str(key, it)).collect()
+}
+
+pub fn expect_ints(key: &str, v: Value) -> Result<Vec<i64>, String> {
+    expect_list(key, v)?
+        .into_iter()
+        .map(|it| match it {
+            Value::Int(n) => Ok(n),
+            other => Err(format!("`{key}` should only hold integers, got {other}")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_and_commas() {
+        assert_eq!(strip_comment(r#"a = "x#y" # z"#), r#"a = "x#y" "#);
+        assert_eq!(split_items(r#""a,b", "c","#).unwrap(), [r#""a,b""#, r#""c""#]);
+        assert_eq!(parse_value("[1, 2,]").unwrap(), Value::List(vec![Value::Int(1), Value::Int(2)]));
+        assert!(split_items("1,,2").unwrap_err().contains("empty array item"));
+        assert!(parse_value("[[1]]").unwrap_err().contains("nested"));
+        assert!(parse_value(r#""open"#).unwrap_err().contains("unterminated string"));
+        assert!(parse_value("[1, 2").unwrap_err().contains("unterminated array"));
+    }
+
+    #[test]
+    fn pairs_keep_their_line() {
+        let pairs = pairs("# header\n\nname = \"x\"\n  sizes = [1, 2_000]  # trailing\n").unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!((pairs[0].line, pairs[0].key.as_str()), (3, "name"));
+        assert_eq!(pairs[1].value, Value::List(vec![Value::Int(1), Value::Int(2000)]));
+        assert_eq!(super::pairs("a = 1\nb 2").unwrap_err(), "line 2: expected `key = value`");
+    }
+}
@@ -0,0 +1,218 @@
+// Bytecode verifier: the one thing standing between the unchecked fetches and UB
+//
+// every variant reads code[pc] with get_unchecked (unless safe-only) and trusts jump
+// targets blindly, so they only take a VerifiedProgram, and the only way to get one
+// is through here. on top of what Program::new already checks (opcodes decode,
+// registers exist) this makes sure pc can never leave the code:
+//
+//   - every jump and CALL target is inside the code
+//   - the last instruction is HALT, RET or JMP, so falling through can't run off the
+//     end (RET either jumps or stops with -1 on an empty call stack, JMP always jumps).
+//     unless the program says what running off the end does (FallOffEnd): then the
+//     lowered code gets a HALT r0 or an OP_PAST_END word after the last instruction,
+//     and falling through lands on that
+//   - every superinstruction is followed by the second half of its pair (fuse.rs),
+//     which is never a HALT, RET or JMP, so the pc + 2 it steps to is inside too
+//
+// together that's enough: from any pc inside the code the next one is either pc + 1
+// (not past the end, the last instruction doesn't fall through or there's the extra
+// word to fall onto, which doesn't fall through itself), pc + 2 after a
+// superinstruction, a checked target, or for RET the pc after some CALL that ran,
+// which is pc + 1 again
+
+use std::fmt;
+
+use crate::memory::{Memory, MemoryImage};
+use crate::{FallOffEnd, OP_HALT, OP_JMP, OP_PAST_END, OP_RET, Program, ProgramError, encode, fuse, has_target};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    Program(ProgramError),
+    JumpOutOfRange { pc: usize, target: usize, len: usize },
+    FallsOffEnd { last_pc: usize },
+    // the superinstruction at pc isn't followed by the second half of its pair
+    BrokenPair { pc: usize },
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::Program(e) => e.fmt(f),
+            VerifyError::JumpOutOfRange { pc, target, len } => {
+                write!(f, "pc {pc}: jump to {target}, past the end ({len} instructions)")
+            }
+            VerifyError::FallsOffEnd { last_pc } => {
+                write!(
+                    f,
+                    "pc {last_pc}: last instruction isn't HALT, RET or JMP, execution could run off the end \
+                     (see FallOffEnd)"
+                )
+            }
+            VerifyError::BrokenPair { pc } => {
+                write!(f, "pc {pc}: superinstruction isn't followed by the second half of its pair")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+impl From<ProgramError> for VerifyError {
+    fn from(e: ProgramError) -> VerifyError {
+        VerifyError::Program(e)
+    }
+}
+
+// a program that passed verification, plus its code lowered to start at pc 0 (see
+// Program::lowered), which is what the variants run
+#[derive(Clone, Debug)]
+pub struct VerifiedProgram {
+    program: Program,
+    code: Vec<u32>,
+    // 0 words when the code never touches memory, so those programs don't allocate any
+    memory: MemoryImage,
+}
+
+impl VerifiedProgram {
+    pub fn code(&self) -> &[u32] {
+        &self.code
+    }
+
+    // how much linear memory a run gets, in i64 words, zeroed but for the program's
+    // data segments at the start of every run
+    pub fn memory_words(&self) -> usize {
+        self.memory.words()
+    }
+
+    pub(crate) fn memory_image(&self) -> &MemoryImage {
+        &self.memory
+    }
+
+    pub(crate) fn memory(&self) -> Memory {
+        self.memory.fresh()
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    // Program::patch plus everything verification checked about that one instruction:
+    // its target, the last instruction still not falling through, and superinstruction
+    // pairs staying whole, both the one it might start and the one it might be the
+    // second half of. on success the lowered code has the new word too and memory
+    // exists if it's the first instruction to need it. on error nothing changed
+    pub fn patch(&mut self, pc: usize, word: u32) -> Result<u32, VerifyError> {
+        let code = self.program.code();
+        let len = code.len();
+        if pc < len {
+            let target = (word >> 16) as usize;
+            if has_target((word & 0xFF) as u8) && target >= len {
+                return Err(VerifyError::JumpOutOfRange { pc, target, len });
+            }
+            if pc == len - 1 && !ends_the_code(&self.program, word) {
+                return Err(VerifyError::FallsOffEnd { last_pc: pc });
+            }
+            if let Some([_, second]) = fuse::halves(word)
+                && code.get(pc + 1) != Some(&second)
+            {
+                return Err(VerifyError::BrokenPair { pc });
+            }
+            if pc > 0
+                && word != code[pc]
+                && fuse::halves(code[pc - 1]).is_some_and(|[_, second]| second == code[pc])
+            {
+                return Err(VerifyError::BrokenPair { pc: pc - 1 });
+            }
+        }
+        let had_memory = self.program.has_feature("memory");
+        let old = self.program.patch(pc, word)?;
+        let (at, lowered) = self.program.lowered_at(pc);
+        self.code[at] = lowered;
+        if !had_memory && self.program.has_feature("memory") {
+            self.memory = MemoryImage::new(self.program.memory_words(), self.program.segments());
+        }
+        Ok(old)
+    }
+}
+
+// whether `last` can be the last instruction of `program`: one that doesn't fall
+// through, or anything when the program says what falling off the end does
+fn ends_the_code(program: &Program, last: u32) -> bool {
+    program.fall_off_end() != FallOffEnd::Reject || matches!((last & 0xFF) as u8, OP_HALT | OP_RET | OP_JMP)
+}
+
+pub fn verify(code: &[u32]) -> Result<VerifiedProgram, VerifyError> {
+    Program::new(code.to_vec())?.verify()
+}
+
+impl Program {
+    pub fn verify(self) -> Result<VerifiedProgram, VerifyError> {
+        let code = self.code();
+        let len = code.len();
+        for (pc, &word) in code.iter().enumerate() {
+            if has_target((word & 0xFF) as u8) {
+                let target = (word >> 16) as usize;
+                if target >= len {
+                    return Err(VerifyError::JumpOutOfRange { pc, target, len });
+                }
+            }
+            if let Some([_, second]) = fuse::halves(word)
+                && code.get(pc + 1) != Some(&second)
+            {
+                return Err(VerifyError::BrokenPair { pc });
+            }
+        }
+        // Program::new refuses empty code
+        if !ends_the_code(&self, code[len - 1]) {
+            return Err(VerifyError::FallsOffEnd { last_pc: len - 1 });
+        }
+        let memory = if self.has_feature("memory") {
+            MemoryImage::new(self.memory_words(), self.segments())
+        } else {
+            MemoryImage::default()
+        };
+        let mut code = self.lowered();
+        match self.fall_off_end() {
+            FallOffEnd::Reject => {}
+            FallOffEnd::Halt => code.push(encode(OP_HALT, 0, 0, 0)),
+            FallOffEnd::Trap => code.push(OP_PAST_END as u32),
+        }
+        Ok(VerifiedProgram { program: self, code, memory })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Instruction, Opcode, kernels};
+
+    #[test]
+    fn verifier_checks_control_flow() {
+        let halt = Instruction::new(Opcode::Halt, 0, 0, 0).encode();
+        let jmp = |target| Instruction::with_imm(Opcode::Jmpnz, 0, target).encode();
+        let inc = Instruction::new(Opcode::Inc, 0, 0, 0).encode();
+
+        assert!(verify(&[inc, jmp(0), halt]).is_ok());
+        assert_eq!(verify(&[jmp(3), inc, halt]).unwrap_err(), VerifyError::JumpOutOfRange { pc: 0, target: 3, len: 3 });
+        assert_eq!(verify(&[halt, inc]).unwrap_err(), VerifyError::FallsOffEnd { last_pc: 1 });
+        assert_eq!(verify(&[halt, jmp(0)]).unwrap_err(), VerifyError::FallsOffEnd { last_pc: 1 });
+        let call = |target| Instruction::with_imm(Opcode::Call, 0, target).encode();
+        let ret = Instruction::new(Opcode::Ret, 0, 0, 0).encode();
+        assert!(verify(&[call(2), halt, ret]).is_ok());
+        assert_eq!(verify(&[call(3), halt, ret]).unwrap_err(), VerifyError::JumpOutOfRange { pc: 0, target: 3, len: 3 });
+        let goto = |target| Instruction::with_imm(Opcode::Jmp, 0, target).encode();
+        assert!(verify(&[halt, goto(0)]).is_ok());
+        assert_eq!(verify(&[goto(2), halt]).unwrap_err(), VerifyError::JumpOutOfRange { pc: 0, target: 2, len: 2 });
+        assert!(matches!(verify(&[0xEE, halt]), Err(VerifyError::Program(ProgramError::BadOpcode { .. }))));
+        assert_eq!(verify(&[]).unwrap_err(), VerifyError::Program(ProgramError::Empty));
+
+        for name in ["sum-poly", "filter", "poly:4", "loop-nest:3", "fsm:7", "fib"] {
+            let (code, _) = kernels::kernel(name, 10).unwrap();
+            assert!(verify(&code).is_ok(), "{name}");
+        }
+    }
+}
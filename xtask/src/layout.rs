@@ -0,0 +1,49 @@
+// state-layout: `--state-layout` (src/layout.rs) built with each VmState layout of
+// src/state.rs, the compiler's, `ordered-state`, `aligned-state` and both, then the
+// ns per step and thread side by side. a layout that keeps Vms off each
+// other's cache lines stays flat as threads get added where the others climb
+
+use crate::pgo::{build, rows};
+use crate::workspace_root;
+
+// (column, target dir, features)
+const LAYOUTS: &[(&str, &str, &[&str])] = &[
+    ("default", "release", &[]),
+    ("ordered", "state-ordered", &["ordered-state"]),
+    ("aligned", "state-aligned-only", &["aligned-state"]),
+    ("ordered+aligned", "state-aligned", &["ordered-state", "aligned-state"]),
+];
+
+pub fn state_layout() -> Result<(), String> {
+    let root = workspace_root();
+
+    println!("== building");
+    let bins = LAYOUTS
+        .iter()
+        .map(|&(_, target, features)| build(&root, target, "", features))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    println!("== benchmarking");
+    let results = bins
+        .iter()
+        .map(|bin| rows(bin, &["--state-layout"], "ns/step"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    println!();
+    print!("{:>12}", "ns/step");
+    for (name, _, _) in LAYOUTS {
+        print!("  {name:>15}");
+    }
+    println!();
+    for (label, _) in &results[0] {
+        print!("{label:>12}");
+        for rows in &results {
+            match rows.iter().find(|(l, _)| l == label) {
+                Some((_, ns)) => print!("  {ns:>15.2}"),
+                None => print!("  {:>15}", "-"),
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+// Dev tasks for rust-goto, run with `cargo xtask <task>`
+
+// verify-threading: builds the benchmark in release, emits the asm, and counts the
+// indirect jumps / jump tables inside each run_* function (src/analyze.rs). If a
+// threaded variant ends up with a single dispatch site, LLVM tail-merged our
+// duplicated matches back into a central loop and the whole point of the project is
+// gone, so we fail loudly.
+// It also checks that run_hooked with a no-op hook is no bigger than run_central, and
+// that the benchmark binary only reaches the variants through opaque function
+// pointers (no per-variant copies of the timing loop, no merged variants).
+//
+// pgo: see pgo.rs
+// sparse-opcodes: see sparse.rs
+// state-layout: see layout.rs
+
+mod layout;
+mod pgo;
+mod sparse;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use rust_goto::analyze::{DISPATCH_FUNCTIONS, direct_calls, dispatch_stats, emit_asm, function_body};
+
+// (hooked with a no-op hook, plain version): the hooks are supposed to compile
+// away entirely, so the hooked copy can't be meaningfully bigger than the plain one.
+// the slack is for register allocation: the two copies spill differently, and with
+// the superinstructions' extra registers that alone is 5-6% of the lines, while a hook
+// that didn't compile away costs a call per instruction and shows up far past this
+const ZERO_COST: &[(&str, &str)] = &[("run_central_nohook", "run_central")];
+const ZERO_COST_SLACK: f64 = 1.10;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("verify-threading") => verify_threading(),
+        Some("pgo") => pgo::pgo(),
+        Some("sparse-opcodes") => sparse::sparse_opcodes(),
+        Some("state-layout") => layout::state_layout(),
+        _ => {
+            eprintln!("usage: cargo xtask <task>");
+            eprintln!();
+            eprintln!("tasks:");
+            eprintln!("  verify-threading   check that the threaded variants kept their duplicated dispatch");
+            eprintln!("  pgo                build with profile-guided optimization and compare against plain release");
+            eprintln!("  sparse-opcodes     compare dense and sparse opcode numbering, asm and timings");
+            eprintln!("  state-layout       compare VmState layouts, stepping one Vm per thread");
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    // xtask lives one level below the workspace root
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn verify_threading() -> Result<(), String> {
+    let root = workspace_root();
+    let asm_path = emit_asm(&root, "--lib", &root.join("target"), &[])?;
+    let asm = fs::read_to_string(&asm_path)
+        .map_err(|e| format!("can't read {}: {e}", asm_path.display()))?;
+
+    println!("asm: {}", asm_path.display());
+    println!();
+    println!("{:>23}  {:>6}  {:>11}  {:>11}", "function", "lines", "jump tables", "indirect jmp");
+
+    let mut failures = Vec::new();
+    let mut all_stats = Vec::new();
+    for &(name, threaded) in DISPATCH_FUNCTIONS {
+        let Some(body) = function_body(&asm, name) else {
+            println!("{name:>23}  (not found)");
+            failures.push(format!("{name} not found in the asm, was it inlined or renamed?"));
+            continue;
+        };
+        let stats = dispatch_stats(body);
+        all_stats.push((name, stats));
+        println!(
+            "{name:>23}  {:>6}  {:>11}  {:>11}",
+            stats.lines, stats.jump_tables, stats.indirect_jumps
+        );
+        if threaded && !stats.is_threaded() {
+            failures.push(format!(
+                "{name} has {} indirect jump(s), the duplicated dispatch collapsed into a central loop",
+                stats.indirect_jumps
+            ));
+        }
+    }
+
+    let find = |name: &str| all_stats.iter().find(|(n, _)| *n == name).map(|&(_, s)| s);
+    for &(hooked, plain) in ZERO_COST {
+        let (Some(h), Some(p)) = (find(hooked), find(plain)) else { continue };
+        if h.indirect_jumps != p.indirect_jumps || h.lines as f64 > p.lines as f64 * ZERO_COST_SLACK {
+            failures.push(format!(
+                "{hooked} ({} lines, {} indirect jmp) isn't zero-cost compared to {plain} ({} lines, {} indirect jmp)",
+                h.lines, h.indirect_jumps, p.lines, p.indirect_jumps
+            ));
+        }
+    }
+
+    // the library asm is from before LTO, what the harness does to the variants only
+    // shows up in the binary. separate target dir so the two .s files can't be mixed up
+    let bin_asm_path = emit_asm(&root, "--bin", &root.join("target/verify-opaque"), &[])?;
+    let bin_asm = fs::read_to_string(&bin_asm_path)
+        .map_err(|e| format!("can't read {}: {e}", bin_asm_path.display()))?;
+    println!();
+    println!("binary asm: {}", bin_asm_path.display());
+    for &(name, _) in DISPATCH_FUNCTIONS {
+        if function_body(&bin_asm, name).is_none() {
+            failures.push(format!("{name} has no body of its own in the binary, merged with another variant?"));
+        }
+        let calls = direct_calls(&bin_asm, name);
+        if calls > 0 {
+            failures.push(format!(
+                "{name} is called directly {calls} time(s) in the binary, the harness got specialized for it"
+            ));
+        }
+    }
+
+    println!();
+    if failures.is_empty() {
+        println!("ok: every threaded variant kept more than one dispatch site, no-op hooks compile away");
+        println!("ok: the harness only calls variants through opaque pointers");
+        Ok(())
+    } else {
+        for f in &failures {
+            eprintln!("FAIL: {f}");
+        }
+        Err(format!("{} variant(s) failed the threading check", failures.len()))
+    }
+}
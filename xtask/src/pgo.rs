@@ -0,0 +1,169 @@
+// pgo: is profile-guided optimization redundant with the duplicated-match trick, or
+// does it stack with it? builds the benchmark twice more next to the normal release
+// build:
+//
+//   1. instrumented (-Cprofile-generate) into target/pgo-gen, trained by running
+//      every kernel through every variant
+//   2. llvm-profdata merge
+//   3. optimized with the profile (-Cprofile-use) into target/pgo-use
+//
+// then runs the normal and the PGO binary and prints the two side by side
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::workspace_root;
+
+// every kernel shape, short budget: we want coverage of all the handlers and
+// dispatch sites, not precise timings
+//
+// (descriptor arrays are one line, see experiment.rs)
+const TRAINING: &str = r#"name = "pgo-training"
+programs = ["sum-poly", "filter", "poly:1", "poly:8", "loop-nest:1", "loop-nest:3", "fsm:2", "fsm:16", "fib", "sieve", "array-sum", "collatz"]
+sizes = [100, 1000]
+budget_ms = 50
+"#;
+
+pub fn pgo() -> Result<(), String> {
+    let root = workspace_root();
+    let profdata_tool = find_llvm_profdata()?;
+    let profiles = root.join("target/pgo-profiles");
+    let _ = fs::remove_dir_all(&profiles);
+    fs::create_dir_all(&profiles).map_err(|e| format!("can't create {}: {e}", profiles.display()))?;
+
+    println!("== building instrumented binary");
+    let flag = format!("-Cprofile-generate={}", profiles.display());
+    let gen_bin = build(&root, "pgo-gen", &flag, &[])?;
+
+    println!("== training");
+    // not in the profile dir, llvm-profdata would try to merge it
+    let training = root.join("target/pgo-training.toml");
+    fs::write(&training, TRAINING).map_err(|e| format!("can't write {}: {e}", training.display()))?;
+    let status = Command::new(&gen_bin)
+        .arg("run-experiment")
+        .arg(&training)
+        .stdout(Stdio::null())
+        .status()
+        .map_err(|e| format!("can't run {}: {e}", gen_bin.display()))?;
+    if !status.success() {
+        return Err(format!("training run failed ({status})"));
+    }
+
+    println!("== merging profiles");
+    let merged = root.join("target/pgo-merged.profdata");
+    let status = Command::new(&profdata_tool)
+        .arg("merge")
+        .arg("-o")
+        .arg(&merged)
+        .arg(&profiles)
+        .status()
+        .map_err(|e| format!("can't run {}: {e}", profdata_tool.display()))?;
+    if !status.success() {
+        return Err(format!(
+            "llvm-profdata merge failed ({status}), {} has to come from the same LLVM as rustc \
+             (see `rustc -vV`), `rustup component add llvm-tools` gets the matching one",
+            profdata_tool.display()
+        ));
+    }
+
+    println!("== building with the profile");
+    let flag = format!("-Cprofile-use={}", merged.display());
+    let pgo_bin = build(&root, "pgo-use", &flag, &[])?;
+    let plain_bin = build(&root, "release", "", &[])?;
+
+    println!("== benchmarking");
+    let plain = bench_rows(&plain_bin)?;
+    let pgo = bench_rows(&pgo_bin)?;
+
+    println!();
+    print_comparison("release ns", "release+pgo", &plain, &pgo);
+    Ok(())
+}
+
+// one row per variant in `base`, with the speedup of `other` over it
+pub fn print_comparison(base_name: &str, other_name: &str, base: &[(String, f64)], other: &[(String, f64)]) {
+    println!("{:>24}  {base_name:>12}  {other_name:>12}  {:>8}", "variant", "speedup");
+    for (label, ns) in base {
+        match other.iter().find(|(l, _)| l == label) {
+            Some((_, other_ns)) => println!("{label:>24}  {ns:>12.1}  {other_ns:>12.1}  {:>7.2}x", ns / other_ns),
+            None => println!("{label:>24}  {ns:>12.1}  {:>12}", "-"),
+        }
+    }
+}
+
+// llvm-profdata has to match rustc's LLVM closely enough, the rustup one
+// (`rustup component add llvm-tools`) is the safest bet, then whatever is on PATH
+fn find_llvm_profdata() -> Result<PathBuf, String> {
+    if let Ok(path) = env::var("LLVM_PROFDATA") {
+        return Ok(PathBuf::from(path));
+    }
+    if let Ok(out) = Command::new("rustc").args(["--print", "target-libdir"]).output() {
+        let libdir = PathBuf::from(String::from_utf8_lossy(&out.stdout).trim());
+        if let Some(candidate) = libdir.parent().map(|p| p.join("bin/llvm-profdata"))
+            && candidate.exists()
+        {
+            return Ok(candidate);
+        }
+    }
+    if Command::new("llvm-profdata").arg("--version").output().is_ok() {
+        return Ok(PathBuf::from("llvm-profdata"));
+    }
+    Err("llvm-profdata not found: `rustup component add llvm-tools`, or set LLVM_PROFDATA".into())
+}
+
+// release build of the benchmark into its own target dir, so the builds don't keep
+// invalidating each other
+pub fn build(root: &Path, target: &str, rustflags: &str, features: &[&str]) -> Result<PathBuf, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut flags = env::var("RUSTFLAGS").unwrap_or_default();
+    if !rustflags.is_empty() {
+        flags = format!("{flags} {rustflags}").trim().to_string();
+    }
+    let target_dir = if target == "release" { root.join("target") } else { root.join("target").join(target) };
+    let status = Command::new(cargo)
+        .current_dir(root)
+        .env("RUSTFLAGS", flags)
+        .arg("build")
+        .args(["--release", "--package", "rust-goto", "--bin", "rust-goto", "--target-dir"])
+        .arg(&target_dir)
+        .args(features.iter().flat_map(|f| ["--features", f]))
+        .status()
+        .map_err(|e| format!("failed to run cargo: {e}"))?;
+    if !status.success() {
+        return Err(format!("{target} build failed ({status})"));
+    }
+    Ok(target_dir.join("release/rust-goto"))
+}
+
+// runs the default benchmark and picks up the `label: N ns/iter` rows
+pub fn bench_rows(bin: &Path) -> Result<Vec<(String, f64)>, String> {
+    rows(bin, &[], "ns/iter")
+}
+
+// runs `bin args` and picks up the `label: N <unit>` rows
+pub fn rows(bin: &Path, args: &[&str], unit: &str) -> Result<Vec<(String, f64)>, String> {
+    let out = Command::new(bin)
+        .args(args)
+        .output()
+        .map_err(|e| format!("can't run {}: {e}", bin.display()))?;
+    if !out.status.success() {
+        return Err(format!("{} failed ({})", bin.display(), out.status));
+    }
+    let text = String::from_utf8_lossy(&out.stdout);
+    Ok(text
+        .lines()
+        .filter_map(|line| {
+            let (label, rest) = line.split_once(':')?;
+            let ns = parse_ns(rest.trim(), unit)?;
+            Some((label.trim().to_string(), ns))
+        })
+        .collect())
+}
+
+// "12345.6 ns/iter  (result = ...)" -> 12345.6 for unit "ns/iter"
+fn parse_ns(s: &str, unit: &str) -> Option<f64> {
+    let (num, rest) = s.split_once(' ')?;
+    rest.trim_start().starts_with(unit).then(|| num.parse().ok())?
+}
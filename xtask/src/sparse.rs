@@ -0,0 +1,50 @@
+// sparse-opcodes: the same variants built with the default dense opcode numbering and
+// with `--features sparse-opcodes`, where the gaps are too big for a jump table.
+// prints what that does to the dispatch sites in the asm, then the timings side by side
+
+use std::fs;
+
+use rust_goto::analyze::{DISPATCH_FUNCTIONS, dispatch_stats, emit_asm, function_body};
+
+use crate::pgo::{bench_rows, build, print_comparison};
+use crate::workspace_root;
+
+pub fn sparse_opcodes() -> Result<(), String> {
+    let root = workspace_root();
+
+    println!("== emitting asm");
+    let dense_path = emit_asm(&root, "--lib", &root.join("target"), &[])?;
+    let sparse_path = emit_asm(&root, "--lib", &root.join("target/sparse"), &["sparse-opcodes"])?;
+    let read = |path: &std::path::Path| fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()));
+    let (dense_asm, sparse_asm) = (read(&dense_path)?, read(&sparse_path)?);
+
+    println!();
+    println!("{:>20}  {:>13}  {:>13}  {:>13}", "function", "lines", "jump tables", "indirect jmp");
+    println!("{:>20}  {:>13}  {:>13}  {:>13}", "", "dense/sparse", "dense/sparse", "dense/sparse");
+    for &(name, _) in DISPATCH_FUNCTIONS {
+        let (Some(d), Some(s)) = (function_body(&dense_asm, name), function_body(&sparse_asm, name)) else {
+            println!("{name:>20}  (not found)");
+            continue;
+        };
+        let (d, s) = (dispatch_stats(d), dispatch_stats(s));
+        println!(
+            "{name:>20}  {:>13}  {:>13}  {:>13}",
+            format!("{}/{}", d.lines, s.lines),
+            format!("{}/{}", d.jump_tables, s.jump_tables),
+            format!("{}/{}", d.indirect_jumps, s.indirect_jumps)
+        );
+    }
+
+    println!();
+    println!("== building");
+    let dense_bin = build(&root, "release", "", &[])?;
+    let sparse_bin = build(&root, "sparse", "", &["sparse-opcodes"])?;
+
+    println!("== benchmarking");
+    let dense = bench_rows(&dense_bin)?;
+    let sparse = bench_rows(&sparse_bin)?;
+
+    println!();
+    print_comparison("dense ns", "sparse ns", &dense, &sparse);
+    Ok(())
+}